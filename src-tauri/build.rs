@@ -1,3 +1,21 @@
 ﻿fn main() {
+    println!("cargo:rustc-env=VOICEREADER_TARGET_TRIPLE={}", std::env::var("TARGET").unwrap_or_default());
+    println!("cargo:rustc-env=VOICEREADER_POCKET_TTS_VERSION={}", locked_version("pocket-tts"));
+    println!("cargo:rerun-if-changed=Cargo.lock");
     tauri_build::build()
 }
+
+/// Version of `package` resolved in Cargo.lock, or "unknown" without a lockfile.
+fn locked_version(package: &str) -> String {
+    let lock = std::fs::read_to_string("Cargo.lock").unwrap_or_default();
+    let name_line = format!("name = \"{package}\"");
+    let mut lines = lock.lines();
+    while let Some(line) = lines.next() {
+        if line.trim() == name_line {
+            if let Some(version) = lines.next().and_then(|line| line.trim().strip_prefix("version = ")) {
+                return version.trim_matches('"').to_string();
+            }
+        }
+    }
+    "unknown".to_string()
+}
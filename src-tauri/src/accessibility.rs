@@ -0,0 +1,356 @@
+const MAX_SPOKEN_VALUE_CHARS: usize = 200;
+
+#[derive(Default)]
+pub struct FocusContext {
+    pub window_title: Option<String>,
+    pub control_role: Option<String>,
+    pub control_name: Option<String>,
+    pub control_value: Option<String>,
+    /// The control is a password field; its value is never read.
+    pub secure: bool,
+}
+
+impl FocusContext {
+    /// Short orientation phrase, e.g. "Untitled - Notepad window. Edit field: Text editor. Value: Hello".
+    pub fn spoken_summary(&self) -> Option<String> {
+        let mut parts: Vec<String> = Vec::new();
+        if let Some(title) = self.window_title.as_deref() {
+            parts.push(format!("{title} window"));
+        }
+        match (self.control_role.as_deref(), self.control_name.as_deref()) {
+            (Some(role), Some(name)) => parts.push(format!("{role}: {name}")),
+            (Some(role), None) => parts.push(role.to_string()),
+            (None, Some(name)) => parts.push(name.to_string()),
+            (None, None) => {}
+        }
+        if self.secure {
+            parts.push("Secure field".to_string());
+        } else if let Some(value) = self.control_value.as_deref() {
+            parts.push(format!("Value: {}", truncate_chars(value, MAX_SPOKEN_VALUE_CHARS)));
+        }
+
+        if parts.is_empty() {
+            None
+        } else {
+            Some(format!("{}.", parts.join(". ")))
+        }
+    }
+}
+
+pub fn query_focus_context(window_title: Option<String>) -> FocusContext {
+    let mut context = focused_control();
+    context.window_title = window_title;
+    context.secure = secure_control();
+    if context.secure {
+        context.control_value = None;
+    }
+    context
+}
+
+/// Text selected in the focused control, read without touching the clipboard.
+/// `None` when nothing is selected, the control does not expose its
+/// selection or it is a password field.
+pub fn focused_selection() -> Option<String> {
+    if secure_control() {
+        return None;
+    }
+    selected_text()
+}
+
+/// Whether the focused control is a password field: a UI Automation password
+/// element or `ES_PASSWORD` edit on Windows, an `AXSecureTextField` on macOS.
+/// Always false on Linux, where no accessibility API is queried.
+pub fn focused_is_secure() -> bool {
+    secure_control()
+}
+
+fn truncate_chars(value: &str, max_chars: usize) -> String {
+    if value.chars().count() <= max_chars {
+        return value.to_string();
+    }
+    let mut truncated: String = value.chars().take(max_chars).collect();
+    truncated.push_str("...");
+    truncated
+}
+
+#[cfg(any(target_os = "windows", target_os = "macos"))]
+fn non_empty(value: String) -> Option<String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+/// Focused child window of the foreground window.
+#[cfg(target_os = "windows")]
+fn focused_hwnd() -> Option<windows_sys::Win32::Foundation::HWND> {
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        GetForegroundWindow, GetGUIThreadInfo, GetWindowThreadProcessId, GUITHREADINFO,
+    };
+
+    let foreground = unsafe { GetForegroundWindow() };
+    if foreground.is_null() {
+        return None;
+    }
+    let thread_id = unsafe { GetWindowThreadProcessId(foreground, std::ptr::null_mut()) };
+    let mut info: GUITHREADINFO = unsafe { std::mem::zeroed() };
+    info.cbSize = std::mem::size_of::<GUITHREADINFO>() as u32;
+    if unsafe { GetGUIThreadInfo(thread_id, &mut info) } == 0 || info.hwndFocus.is_null() {
+        return None;
+    }
+    Some(info.hwndFocus)
+}
+
+#[cfg(target_os = "windows")]
+fn window_class_name(hwnd: windows_sys::Win32::Foundation::HWND) -> String {
+    use windows_sys::Win32::UI::WindowsAndMessaging::GetClassNameW;
+
+    let mut class_buf = [0u16; 256];
+    let class_len = unsafe { GetClassNameW(hwnd, class_buf.as_mut_ptr(), class_buf.len() as i32) };
+    if class_len > 0 {
+        String::from_utf16_lossy(&class_buf[..class_len as usize])
+    } else {
+        String::new()
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn window_text_utf16(hwnd: windows_sys::Win32::Foundation::HWND) -> Vec<u16> {
+    use windows_sys::Win32::UI::WindowsAndMessaging::{SendMessageW, WM_GETTEXT, WM_GETTEXTLENGTH};
+
+    let text_len = unsafe { SendMessageW(hwnd, WM_GETTEXTLENGTH, 0, 0) };
+    if text_len <= 0 {
+        return Vec::new();
+    }
+    let mut text_buf = vec![0u16; text_len as usize + 1];
+    let copied = unsafe { SendMessageW(hwnd, WM_GETTEXT, text_buf.len(), text_buf.as_mut_ptr() as isize) };
+    text_buf.truncate(copied.max(0) as usize);
+    text_buf
+}
+
+#[cfg(target_os = "windows")]
+fn is_edit_class(class_name: &str) -> bool {
+    let lowered = class_name.to_ascii_lowercase();
+    lowered == "edit" || lowered.starts_with("richedit")
+}
+
+#[cfg(target_os = "windows")]
+fn focused_control() -> FocusContext {
+    let Some(focus) = focused_hwnd() else {
+        return FocusContext::default();
+    };
+    let class_name = window_class_name(focus);
+    let text = String::from_utf16_lossy(&window_text_utf16(focus));
+
+    let lowered = class_name.to_ascii_lowercase();
+    let role = if is_edit_class(&class_name) {
+        Some("Edit field")
+    } else if lowered == "button" {
+        Some("Button")
+    } else if lowered == "combobox" {
+        Some("Combo box")
+    } else if lowered == "listbox" || lowered == "syslistview32" {
+        Some("List")
+    } else if lowered == "systreeview32" {
+        Some("Tree")
+    } else {
+        None
+    };
+
+    // Edit controls expose their contents through the window text; other
+    // standard controls use it as the caption.
+    let is_edit = role == Some("Edit field");
+    FocusContext {
+        window_title: None,
+        control_role: role.map(str::to_string),
+        control_name: if is_edit { None } else { non_empty(text.clone()) },
+        control_value: if is_edit { non_empty(text) } else { None },
+        secure: false,
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn selected_text() -> Option<String> {
+    uia::selected_text().or_else(edit_control_selection)
+}
+
+#[cfg(target_os = "windows")]
+fn secure_control() -> bool {
+    uia::focused_is_password() || focused_hwnd().is_some_and(is_password_edit)
+}
+
+#[cfg(target_os = "windows")]
+fn is_password_edit(hwnd: windows_sys::Win32::Foundation::HWND) -> bool {
+    use windows_sys::Win32::UI::WindowsAndMessaging::{GetWindowLongW, ES_PASSWORD, GWL_STYLE};
+
+    is_edit_class(&window_class_name(hwnd)) && unsafe { GetWindowLongW(hwnd, GWL_STYLE) } & ES_PASSWORD != 0
+}
+
+/// Selection of a standard edit control. `EM_GETSEL` packs both offsets into
+/// the return value, so selections past 65535 UTF-16 units are not reported.
+#[cfg(target_os = "windows")]
+fn edit_control_selection() -> Option<String> {
+    use windows_sys::Win32::UI::WindowsAndMessaging::{SendMessageW, EM_GETSEL};
+
+    let focus = focused_hwnd()?;
+    if !is_edit_class(&window_class_name(focus)) {
+        return None;
+    }
+    let packed = unsafe { SendMessageW(focus, EM_GETSEL, 0, 0) } as usize;
+    let start = packed & 0xFFFF;
+    let end = (packed >> 16) & 0xFFFF;
+    let text = window_text_utf16(focus);
+    if start >= end || end > text.len() {
+        return None;
+    }
+    non_empty(String::from_utf16_lossy(&text[start..end]))
+}
+
+/// Selection through the UI Automation text pattern, which browsers, Office
+/// and WPF or UWP apps expose although their controls are not edit windows.
+#[cfg(target_os = "windows")]
+mod uia {
+    use windows::Win32::System::Com::{
+        CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_INPROC_SERVER, COINIT_MULTITHREADED,
+    };
+    use windows::Win32::UI::Accessibility::{
+        CUIAutomation, IUIAutomation, IUIAutomationTextPattern, UIA_TextPatternId,
+    };
+
+    use super::non_empty;
+
+    /// Initializes COM for the call; the calling thread may be a pooled one.
+    pub fn selected_text() -> Option<String> {
+        with_com(text_pattern_selection)
+    }
+
+    pub fn focused_is_password() -> bool {
+        with_com(|| unsafe {
+            let automation: IUIAutomation = CoCreateInstance(&CUIAutomation, None, CLSCTX_INPROC_SERVER).ok()?;
+            let focused = automation.GetFocusedElement().ok()?;
+            focused.CurrentIsPassword().ok().map(|password| password.as_bool())
+        })
+        .unwrap_or(false)
+    }
+
+    fn with_com<T>(query: impl FnOnce() -> Option<T>) -> Option<T> {
+        let initialized = unsafe { CoInitializeEx(None, COINIT_MULTITHREADED) }.is_ok();
+        let result = query();
+        if initialized {
+            unsafe { CoUninitialize() };
+        }
+        result
+    }
+
+    /// Joins the ranges of a multiple selection with line breaks.
+    fn text_pattern_selection() -> Option<String> {
+        unsafe {
+            let automation: IUIAutomation = CoCreateInstance(&CUIAutomation, None, CLSCTX_INPROC_SERVER).ok()?;
+            let focused = automation.GetFocusedElement().ok()?;
+            let pattern: IUIAutomationTextPattern = focused.GetCurrentPatternAs(UIA_TextPatternId).ok()?;
+            let ranges = pattern.GetSelection().ok()?;
+            let mut parts = Vec::new();
+            for index in 0..ranges.Length().ok()? {
+                parts.push(ranges.GetElement(index).ok()?.GetText(-1).ok()?.to_string());
+            }
+            non_empty(parts.join("\n"))
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod ax {
+    use core_foundation::base::{CFType, CFTypeRef, TCFType};
+    use core_foundation::string::{CFString, CFStringRef};
+    use std::ffi::c_void;
+
+    use super::non_empty;
+
+    #[link(name = "ApplicationServices", kind = "framework")]
+    extern "C" {
+        fn AXUIElementCreateSystemWide() -> *const c_void;
+        fn AXUIElementCopyAttributeValue(
+            element: *const c_void,
+            attribute: CFStringRef,
+            value: *mut CFTypeRef,
+        ) -> i32;
+    }
+
+    pub fn copy_attribute(element: &CFType, name: &str) -> Option<CFType> {
+        let attribute = CFString::new(name);
+        let mut value: CFTypeRef = std::ptr::null();
+        let status = unsafe {
+            AXUIElementCopyAttributeValue(
+                element.as_CFTypeRef(),
+                attribute.as_concrete_TypeRef(),
+                &mut value,
+            )
+        };
+        if status != 0 || value.is_null() {
+            return None;
+        }
+        Some(unsafe { CFType::wrap_under_create_rule(value) })
+    }
+
+    pub fn string_attribute(element: &CFType, name: &str) -> Option<String> {
+        copy_attribute(element, name)?
+            .downcast::<CFString>()
+            .and_then(|value| non_empty(value.to_string()))
+    }
+
+    pub fn focused_element() -> Option<CFType> {
+        let system_wide = unsafe { AXUIElementCreateSystemWide() };
+        if system_wide.is_null() {
+            return None;
+        }
+        let system_wide = unsafe { CFType::wrap_under_create_rule(system_wide as CFTypeRef) };
+        copy_attribute(&system_wide, "AXFocusedUIElement")
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn focused_control() -> FocusContext {
+    use ax::string_attribute;
+
+    let Some(focused) = ax::focused_element() else {
+        return FocusContext::default();
+    };
+
+    FocusContext {
+        window_title: None,
+        control_role: string_attribute(&focused, "AXRoleDescription"),
+        control_name: string_attribute(&focused, "AXTitle")
+            .or_else(|| string_attribute(&focused, "AXDescription")),
+        control_value: string_attribute(&focused, "AXValue"),
+        secure: false,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn secure_control() -> bool {
+    ax::focused_element()
+        .and_then(|focused| ax::string_attribute(&focused, "AXSubrole"))
+        .is_some_and(|subrole| subrole == "AXSecureTextField")
+}
+
+#[cfg(target_os = "macos")]
+fn selected_text() -> Option<String> {
+    ax::string_attribute(&ax::focused_element()?, "AXSelectedText")
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn focused_control() -> FocusContext {
+    FocusContext::default()
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn selected_text() -> Option<String> {
+    None
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn secure_control() -> bool {
+    false
+}
@@ -0,0 +1,136 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+const MIN_ACRONYM_LEN: usize = 2;
+const MAX_ACRONYM_LEN: usize = 5;
+const MAX_LEXICON_WORDS: usize = 500;
+
+/// All-caps tokens that are read as words, not letters: acronyms said as
+/// words and everyday words capitalized for emphasis.
+const BUILTIN_WORDS: &[&str] = &[
+    "AIDS", "AJAX", "ASCII", "AWOL", "CRUD", "COBOL", "COVID", "DOS", "FEMA", "FIFA", "GIF", "IKEA", "JPEG", "JSON",
+    "LAN", "LASER", "LEGO", "NAND", "NASA", "NATO", "OPEC", "PIN", "POTUS", "RADAR", "RAM", "REST", "ROM", "SCUBA",
+    "SIM", "SONAR", "SWAT", "TOML", "UEFA", "UNIX", "WAN", "YAML", "ALL", "ALSO", "AND", "ANY", "ARE", "BAD", "BE",
+    "BEST", "BIG", "BUT", "CAN", "DO", "DONT", "EVER", "EACH", "FREE", "FROM", "GET", "GO", "GOOD", "HAS", "HAVE",
+    "HE", "HOW", "HUGE", "IF", "IN", "IS", "JUST", "LAST", "LIKE", "MAY", "ME", "MORE", "MOST", "MUST", "MY",
+    "NEVER", "NEW", "NO", "NONE", "NOT", "NOTE", "NOW", "OF", "OFF", "ON", "ONE", "ONLY", "OR", "OUT", "SHALL",
+    "SHE", "SO", "STILL", "STOP", "THAT", "THE", "THEN", "THIS", "TO", "TRUE", "UP", "VERY", "WANT", "WAS", "WE",
+    "WHAT", "WHEN", "WHERE", "WHO", "WHY", "WILL", "WITH", "YES", "YOU", "YOUR",
+];
+
+/// Reads short all-caps tokens letter by letter (`HTTP` as `H T T P`)
+/// instead of leaving the model to guess between spelling and a word.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AcronymSettings {
+    pub enabled: bool,
+    /// Extra all-caps tokens read as words, on top of the built-in list.
+    #[serde(default)]
+    pub words: Vec<String>,
+}
+
+impl AcronymSettings {
+    pub fn validate(&self) -> Result<()> {
+        if self.words.len() > MAX_LEXICON_WORDS {
+            return Err(anyhow!("Acronym lexicon is limited to {MAX_LEXICON_WORDS} words"));
+        }
+        if let Some(word) = self
+            .words
+            .iter()
+            .find(|word| word.trim().is_empty() || !word.trim().chars().all(char::is_alphabetic))
+        {
+            return Err(anyhow!("Acronym lexicon entry {word:?} must be a single word of letters"));
+        }
+        Ok(())
+    }
+
+    fn is_word(&self, token: &str) -> bool {
+        BUILTIN_WORDS.contains(&token) || self.words.iter().any(|word| word.trim().eq_ignore_ascii_case(token))
+    }
+}
+
+/// Spells out acronyms in `text`. Lines without any lowercase letter are
+/// left alone, since all-caps headings and shouting are words, not acronyms.
+pub fn spell_acronyms(text: &str, settings: &AcronymSettings) -> String {
+    if !settings.enabled {
+        return text.to_string();
+    }
+    text.split_inclusive('\n')
+        .map(|line| {
+            if line.chars().any(char::is_lowercase) {
+                spell_line(line, settings)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect()
+}
+
+fn spell_line(line: &str, settings: &AcronymSettings) -> String {
+    let mut out = String::with_capacity(line.len() + 16);
+    let mut token = String::new();
+    for ch in line.chars() {
+        if ch.is_alphanumeric() {
+            token.push(ch);
+            continue;
+        }
+        push_token(&mut out, &token, settings);
+        token.clear();
+        out.push(ch);
+    }
+    push_token(&mut out, &token, settings);
+    out
+}
+
+fn push_token(out: &mut String, token: &str, settings: &AcronymSettings) {
+    if !is_acronym(token, settings) {
+        out.push_str(token);
+        return;
+    }
+    for (idx, letter) in token.chars().enumerate() {
+        if idx > 0 {
+            out.push(' ');
+        }
+        out.push(letter);
+    }
+}
+
+fn is_acronym(token: &str, settings: &AcronymSettings) -> bool {
+    (MIN_ACRONYM_LEN..=MAX_ACRONYM_LEN).contains(&token.len())
+        && token.chars().all(|ch| ch.is_ascii_uppercase())
+        && !is_roman_numeral(token)
+        && !settings.is_word(token)
+}
+
+/// `II` to `XXXIX`, as in "Henry VIII" or "Chapter XII". Larger numerals
+/// would also catch acronyms such as `CD` or `DC`.
+fn is_roman_numeral(token: &str) -> bool {
+    let value = token.chars().try_fold((0u32, 0u32), |(total, previous), ch| {
+        let value = match ch {
+            'I' => 1,
+            'V' => 5,
+            'X' => 10,
+            _ => return None,
+        };
+        // Subtractive pairs such as IV add the difference.
+        Some(if previous > 0 && previous < value {
+            (total + value - 2 * previous, value)
+        } else {
+            (total + value, value)
+        })
+    });
+    let Some((value, _)) = value else {
+        return false;
+    };
+    to_roman(value) == token
+}
+
+fn to_roman(mut value: u32) -> String {
+    let mut out = String::new();
+    for (step, numeral) in [(10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I")] {
+        while value >= step {
+            out.push_str(numeral);
+            value -= step;
+        }
+    }
+    out
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+/// Audio chunks a job may have emitted but not yet had played.
+const MAX_UNACKED_CHUNKS: u64 = 8;
+const LOOKAHEAD_RANGE_MS: (u64, u64) = (500, 120_000);
+
+/// Keeps chunk emission near real time, so a fast machine does not queue
+/// minutes of audio that a cancel would then have to flush.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ChunkPacing {
+    /// How far emitted audio may run ahead of real time; 0 turns pacing off.
+    pub lookahead_ms: u64,
+}
+
+impl Default for ChunkPacing {
+    fn default() -> Self {
+        Self { lookahead_ms: 3_000 }
+    }
+}
+
+impl ChunkPacing {
+    pub fn validate(&self) -> Result<()> {
+        let (min, max) = LOOKAHEAD_RANGE_MS;
+        if self.lookahead_ms != 0 && !(min..=max).contains(&self.lookahead_ms) {
+            return Err(anyhow!("lookahead_ms must be 0 (off) or between {min} and {max}"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct JobCredit {
+    emitted: u64,
+    /// Chunks the player has finished, counted in arrival order. `None`
+    /// until the first ack, which keeps players that never ack unthrottled.
+    acked: Option<u64>,
+    /// When the first chunk went out; the pacing clock starts here.
+    first_emitted_at: Option<Instant>,
+    /// Playback length of everything emitted so far.
+    emitted_audio: Duration,
+    /// Time spent paused, which the pacing clock does not count.
+    paused: Duration,
+}
+
+/// Credit-based flow control for `AUDIO_CHUNK` events, so fast synthesis
+/// does not flood the webview with audio it cannot play yet.
+#[derive(Default)]
+pub struct AudioCredits {
+    jobs: HashMap<String, JobCredit>,
+    pacing: ChunkPacing,
+    paused_since: Option<Instant>,
+}
+
+impl AudioCredits {
+    pub fn pacing(&self) -> ChunkPacing {
+        self.pacing
+    }
+
+    pub fn set_pacing(&mut self, pacing: ChunkPacing) {
+        self.pacing = pacing;
+    }
+
+    pub fn record_emitted(&mut self, job_id: &str, samples: usize, sample_rate: u32) {
+        let credit = self.jobs.entry(job_id.to_string()).or_default();
+        credit.emitted += 1;
+        credit.first_emitted_at.get_or_insert_with(Instant::now);
+        if sample_rate > 0 {
+            credit.emitted_audio += Duration::from_secs_f64(samples as f64 / sample_rate as f64);
+        }
+    }
+
+    /// `chunk_index` is the 0-based arrival order of the chunk that finished
+    /// playing. Acks for unknown or finished jobs are ignored.
+    pub fn ack(&mut self, job_id: &str, chunk_index: u64) {
+        if let Some(credit) = self.jobs.get_mut(job_id) {
+            let played = (chunk_index + 1).min(credit.emitted);
+            credit.acked = Some(credit.acked.map_or(played, |acked| acked.max(played)));
+        }
+    }
+
+    /// Stops or restarts the pacing clock of every job while playback is paused.
+    pub fn set_paused(&mut self, paused: bool) {
+        match (paused, self.paused_since) {
+            (true, None) => self.paused_since = Some(Instant::now()),
+            (false, Some(since)) => {
+                let held = since.elapsed();
+                for credit in self.jobs.values_mut() {
+                    credit.paused += held;
+                }
+                self.paused_since = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// True when another chunk of `job_id` may be emitted now.
+    pub fn has_credit(&self, job_id: &str) -> bool {
+        let Some(credit) = self.jobs.get(job_id) else {
+            return true;
+        };
+        let unacked_full = credit
+            .acked
+            .is_some_and(|acked| credit.emitted - acked >= MAX_UNACKED_CHUNKS);
+        !unacked_full && self.within_lookahead(credit)
+    }
+
+    fn within_lookahead(&self, credit: &JobCredit) -> bool {
+        let Some(first_emitted_at) = credit.first_emitted_at else {
+            return true;
+        };
+        if self.pacing.lookahead_ms == 0 {
+            return true;
+        }
+        let now = self.paused_since.unwrap_or_else(Instant::now);
+        let played = now
+            .saturating_duration_since(first_emitted_at)
+            .saturating_sub(credit.paused);
+        credit.emitted_audio <= played + Duration::from_millis(self.pacing.lookahead_ms)
+    }
+
+    pub fn remove(&mut self, job_id: &str) {
+        self.jobs.remove(job_id);
+    }
+}
@@ -0,0 +1,230 @@
+use std::fs::File;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context, Result};
+
+const WAV_BITS_PER_SAMPLE: u16 = 16;
+const WAV_CHANNELS: u16 = 1;
+const SOFTWARE_TAG: &str = "VoiceReader";
+/// RIFF header plus the `fmt ` chunk and the `data` chunk header.
+const WAV_HEADER_LEN: usize = 44;
+
+#[derive(Clone)]
+pub struct SyntheticProvenance {
+    pub model_id: String,
+    pub voice_id: String,
+    pub created_at: String,
+}
+
+impl SyntheticProvenance {
+    pub fn new(model_id: &str, voice_id: &str) -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        Self {
+            model_id: model_id.to_string(),
+            voice_id: voice_id.to_string(),
+            created_at: secs.to_string(),
+        }
+    }
+
+    fn comment(&self) -> String {
+        format!(
+            "SYNTHETIC SPEECH: generated by {SOFTWARE_TAG} text-to-speech (model={}, voice={}, created_unix={})",
+            self.model_id, self.voice_id, self.created_at
+        )
+    }
+}
+
+/// Encodes mono PCM16 as a RIFF/WAVE file.
+///
+/// When `provenance` is set, a `LIST/INFO` chunk is appended that marks the
+/// audio as synthetic (`ICMT`, `IGNR`) and names the generating software
+/// (`ISFT`). Players ignore unknown chunks, so tagged files stay playable
+/// everywhere.
+pub fn encode_wav_pcm16(samples: &[i16], sample_rate: u32, provenance: Option<&SyntheticProvenance>) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let info_chunk = provenance.map(build_info_chunk).unwrap_or_default();
+
+    let mut out = Vec::with_capacity(WAV_HEADER_LEN + data_len as usize + info_chunk.len());
+    out.extend_from_slice(&wav_header(sample_rate, data_len, info_chunk.len() as u32));
+    for sample in samples {
+        out.extend_from_slice(&sample.to_le_bytes());
+    }
+
+    out.extend_from_slice(&info_chunk);
+    out
+}
+
+/// Writes a WAV file as audio arrives, for exports that run alongside
+/// playback. The header is written with empty sizes and patched by `finish`;
+/// a file that is never finished still holds the audio but reports no length.
+pub struct StreamingWavWriter {
+    path: PathBuf,
+    file: BufWriter<File>,
+    provenance: Option<SyntheticProvenance>,
+    sample_rate: u32,
+    samples_written: u64,
+}
+
+impl StreamingWavWriter {
+    pub fn create(path: &Path, provenance: Option<SyntheticProvenance>) -> Result<Self> {
+        create_parent_dir(path)?;
+        let mut file =
+            BufWriter::new(File::create(path).with_context(|| format!("Failed to create {}", path.display()))?);
+        // The sample rate is not known until the first chunk arrives.
+        file.write_all(&wav_header(0, 0, 0))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            provenance,
+            sample_rate: 0,
+            samples_written: 0,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn tagged_synthetic(&self) -> bool {
+        self.provenance.is_some()
+    }
+
+    pub fn append(&mut self, samples: &[i16], sample_rate: u32) -> Result<()> {
+        if sample_rate == 0 {
+            return Err(anyhow!("Cannot write WAV with a zero sample rate"));
+        }
+        if self.sample_rate == 0 {
+            self.sample_rate = sample_rate;
+        } else if self.sample_rate != sample_rate {
+            return Err(anyhow!(
+                "Audio changed sample rate from {} to {sample_rate} Hz part-way through the export",
+                self.sample_rate
+            ));
+        }
+        if (self.samples_written + samples.len() as u64) * 2 > u32::MAX as u64 {
+            return Err(anyhow!("Export exceeds the 4 GiB WAV size limit"));
+        }
+        let mut bytes = Vec::with_capacity(samples.len() * 2);
+        for sample in samples {
+            bytes.extend_from_slice(&sample.to_le_bytes());
+        }
+        self.file
+            .write_all(&bytes)
+            .with_context(|| format!("Failed to write {}", self.path.display()))?;
+        self.samples_written += samples.len() as u64;
+        Ok(())
+    }
+
+    pub fn duration_ms(&self) -> u64 {
+        if self.sample_rate == 0 {
+            return 0;
+        }
+        self.samples_written * 1000 / self.sample_rate as u64
+    }
+
+    /// Appends the provenance chunk and fills in the header sizes.
+    pub fn finish(mut self) -> Result<()> {
+        let info_chunk = self.provenance.as_ref().map(build_info_chunk).unwrap_or_default();
+        let data_len = (self.samples_written * 2) as u32;
+        // Without audio the sample rate is unknown; any valid rate keeps the file readable.
+        let sample_rate = if self.sample_rate == 0 { 24_000 } else { self.sample_rate };
+        let context = || format!("Failed to finish {}", self.path.display());
+        self.file.write_all(&info_chunk).with_context(context)?;
+        self.file.seek(SeekFrom::Start(0)).with_context(context)?;
+        self.file
+            .write_all(&wav_header(sample_rate, data_len, info_chunk.len() as u32))
+            .with_context(context)?;
+        self.file.flush().with_context(context)?;
+        Ok(())
+    }
+}
+
+pub fn write_wav_file(
+    path: &Path,
+    samples: &[i16],
+    sample_rate: u32,
+    provenance: Option<&SyntheticProvenance>,
+) -> Result<()> {
+    if sample_rate == 0 {
+        return Err(anyhow!("Cannot write WAV with a zero sample rate"));
+    }
+    create_parent_dir(path)?;
+    let encoded = encode_wav_pcm16(samples, sample_rate, provenance);
+    std::fs::write(path, encoded).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+/// Header for a WAV of unknown length, streamed as it is produced. The sizes
+/// are set to the maximum, which players read as "until the stream ends".
+pub fn wav_stream_header(sample_rate: u32) -> Vec<u8> {
+    // Leaves the RIFF size at exactly `u32::MAX`.
+    wav_header(sample_rate, u32::MAX - (WAV_HEADER_LEN as u32 - 8), 0)
+}
+
+fn create_parent_dir(path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create export directory {}", parent.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// RIFF, `fmt ` and `data` chunk headers for `data_len` bytes of PCM
+/// followed by `trailer_len` bytes of extra chunks.
+fn wav_header(sample_rate: u32, data_len: u32, trailer_len: u32) -> Vec<u8> {
+    let block_align = WAV_CHANNELS * (WAV_BITS_PER_SAMPLE / 8);
+    let byte_rate = sample_rate * block_align as u32;
+    let riff_len = 4 + (8 + 16) + (8 + data_len) + trailer_len;
+
+    let mut out = Vec::with_capacity(WAV_HEADER_LEN);
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&riff_len.to_le_bytes());
+    out.extend_from_slice(b"WAVE");
+
+    out.extend_from_slice(b"fmt ");
+    out.extend_from_slice(&16u32.to_le_bytes());
+    out.extend_from_slice(&1u16.to_le_bytes());
+    out.extend_from_slice(&WAV_CHANNELS.to_le_bytes());
+    out.extend_from_slice(&sample_rate.to_le_bytes());
+    out.extend_from_slice(&byte_rate.to_le_bytes());
+    out.extend_from_slice(&block_align.to_le_bytes());
+    out.extend_from_slice(&WAV_BITS_PER_SAMPLE.to_le_bytes());
+
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&data_len.to_le_bytes());
+    out
+}
+
+fn build_info_chunk(provenance: &SyntheticProvenance) -> Vec<u8> {
+    let mut body = Vec::new();
+    body.extend_from_slice(b"INFO");
+    push_info_entry(&mut body, b"ISFT", SOFTWARE_TAG);
+    push_info_entry(&mut body, b"IGNR", "Synthetic Speech");
+    push_info_entry(&mut body, b"ICMT", &provenance.comment());
+
+    let mut chunk = Vec::with_capacity(8 + body.len());
+    chunk.extend_from_slice(b"LIST");
+    chunk.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    chunk.extend_from_slice(&body);
+    chunk
+}
+
+fn push_info_entry(body: &mut Vec<u8>, id: &[u8; 4], value: &str) {
+    // INFO strings are NUL-terminated and each sub-chunk is padded to an even length.
+    let mut data = value.as_bytes().to_vec();
+    data.push(0);
+    body.extend_from_slice(id);
+    body.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    body.extend_from_slice(&data);
+    if data.len() % 2 == 1 {
+        body.push(0);
+    }
+}
@@ -0,0 +1,133 @@
+use anyhow::{anyhow, Result};
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine as _;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// Output rates a client may ask for. Lower rates cut IPC traffic at the
+/// cost of treble; `None` keeps the model's own rate.
+pub const SUPPORTED_SAMPLE_RATES: [u32; 7] = [8_000, 16_000, 22_050, 24_000, 32_000, 44_100, 48_000];
+
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum AudioEncoding {
+    #[default]
+    #[serde(rename = "pcm_s16le")]
+    PcmS16le,
+    /// Twice the size of s16 but needs no conversion before Web Audio.
+    #[serde(rename = "pcm_f32le")]
+    PcmF32le,
+}
+
+impl AudioEncoding {
+    const ALL: [Self; 2] = [Self::PcmS16le, Self::PcmF32le];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PcmS16le => "pcm_s16le",
+            Self::PcmF32le => "pcm_f32le",
+        }
+    }
+}
+
+/// Format of the `audio` object in `AUDIO_CHUNK` events sent to the
+/// frontend. Engines always produce mono s16; conversion happens on the way
+/// out, so both build variants offer the same formats.
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct StreamAudioFormat {
+    pub encoding: AudioEncoding,
+    pub sample_rate: Option<u32>,
+}
+
+impl StreamAudioFormat {
+    pub fn validate(&self) -> Result<()> {
+        if let Some(rate) = self.sample_rate {
+            if !SUPPORTED_SAMPLE_RATES.contains(&rate) {
+                return Err(anyhow!(
+                    "Unsupported sample rate {rate} Hz; use one of {SUPPORTED_SAMPLE_RATES:?}"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The `audio` object of an `AUDIO_CHUNK` event for `pcm`.
+    pub fn audio_payload(&self, pcm: &[i16], sample_rate: u32) -> Value {
+        let target_rate = self.sample_rate.filter(|_| sample_rate > 0).unwrap_or(sample_rate);
+        let resampled;
+        let pcm = if target_rate == sample_rate {
+            pcm
+        } else {
+            resampled = resample_linear(pcm, sample_rate, target_rate);
+            &resampled
+        };
+        let bytes = match self.encoding {
+            AudioEncoding::PcmS16le => pcm.iter().flat_map(|sample| sample.to_le_bytes()).collect::<Vec<_>>(),
+            AudioEncoding::PcmF32le => pcm
+                .iter()
+                .flat_map(|sample| (*sample as f32 / 32_768.0).to_le_bytes())
+                .collect(),
+        };
+        json!({
+            "format": self.encoding.as_str(),
+            "sample_rate": target_rate,
+            "channels": 1,
+            "data_base64": BASE64_STANDARD.encode(&bytes),
+        })
+    }
+
+    /// Re-encodes the `audio` object of an engine `AUDIO_CHUNK` event, which
+    /// is always `pcm_s16le`. Events in any other shape are left alone.
+    #[cfg(feature = "build-full")]
+    pub fn convert_event(&self, event: &mut Value) {
+        if *self == Self::default() {
+            return;
+        }
+        let Some(audio) = event.get_mut("audio") else {
+            return;
+        };
+        if audio.get("format").and_then(Value::as_str) != Some(AudioEncoding::PcmS16le.as_str()) {
+            return;
+        }
+        let sample_rate = audio.get("sample_rate").and_then(Value::as_u64).unwrap_or(0) as u32;
+        let Some(bytes) = audio
+            .get("data_base64")
+            .and_then(Value::as_str)
+            .and_then(|encoded| BASE64_STANDARD.decode(encoded).ok())
+        else {
+            return;
+        };
+        let pcm = bytes
+            .chunks_exact(2)
+            .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+            .collect::<Vec<_>>();
+        *audio = self.audio_payload(&pcm, sample_rate);
+    }
+}
+
+/// What `set_audio_format` accepts, for `get_capabilities`.
+pub fn capabilities() -> Value {
+    json!({
+        "encodings": AudioEncoding::ALL.map(AudioEncoding::as_str),
+        "sample_rates": SUPPORTED_SAMPLE_RATES,
+        "channels": [1],
+    })
+}
+
+/// Linear interpolation. Each chunk is resampled on its own, which is fine
+/// for speech since chunks start and end on near-silence.
+fn resample_linear(pcm: &[i16], from_rate: u32, to_rate: u32) -> Vec<i16> {
+    if pcm.is_empty() || from_rate == 0 || to_rate == 0 {
+        return pcm.to_vec();
+    }
+    let out_len = ((pcm.len() as u64 * to_rate as u64).div_ceil(from_rate as u64)) as usize;
+    let step = from_rate as f64 / to_rate as f64;
+    (0..out_len)
+        .map(|idx| {
+            let position = idx as f64 * step;
+            let left = (position as usize).min(pcm.len() - 1);
+            let right = (left + 1).min(pcm.len() - 1);
+            let frac = position - left as f64;
+            (pcm[left] as f64 + (pcm[right] as f64 - pcm[left] as f64) * frac).round() as i16
+        })
+        .collect()
+}
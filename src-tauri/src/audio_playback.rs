@@ -0,0 +1,359 @@
+use std::collections::VecDeque;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Child;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::sox::{resolve_sox_path_cached, spawn_pcm_player};
+
+/// Audio written to SoX ahead of the playback clock. A pause or seek drops
+/// it and resumes from the clock, so it is kept small.
+const PLAY_AHEAD_MS: u64 = 150;
+/// How often the player writes audio and reports progress.
+const FEED_INTERVAL_MS: u64 = 20;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaybackMode {
+    /// The frontend decodes `AUDIO_CHUNK` events and plays them with WebAudio.
+    #[default]
+    Webview,
+    /// Audio stays in the app and plays through SoX on the default output
+    /// device; `AUDIO_CHUNK` events are not sent to the webview.
+    Native,
+}
+
+#[derive(Serialize)]
+pub struct PlaybackStatus {
+    pub mode: PlaybackMode,
+    /// Native playback goes through SoX and needs it installed or bundled.
+    pub sox_found: bool,
+}
+
+pub enum PlaybackEvent {
+    /// The `ordinal`-th chunk of the job (0-based, in arrival order) has played.
+    ChunkPlayed { job_id: String, ordinal: u64 },
+    /// All of the job's audio has played, or it had none.
+    JobPlayed { job_id: String },
+    Failed(String),
+}
+
+enum PlayerCommand {
+    Chunk {
+        job_id: String,
+        pcm: Vec<i16>,
+        sample_rate: u32,
+    },
+    EndJob(String),
+    StopJob(String),
+    StopAll,
+    SetPaused(bool),
+    Seek(i64),
+}
+
+/// Plays job audio from the app process, one job after another in the order
+/// they started. Playback runs on its own thread; every call returns at once.
+pub struct NativePlayer {
+    commands: Sender<PlayerCommand>,
+}
+
+impl NativePlayer {
+    /// `on_event` runs on the player thread.
+    pub fn start(on_event: impl Fn(PlaybackEvent) + Send + 'static) -> Result<Self> {
+        let sox = resolve_sox_path_cached().ok_or_else(|| anyhow!("SoX was not found; native playback goes through it"))?;
+        let (commands, received) = mpsc::channel();
+        let player = Player {
+            sox,
+            on_event: Box::new(on_event),
+            jobs: VecDeque::new(),
+            cursor: 0,
+            paused: false,
+            output: None,
+        };
+        std::thread::Builder::new()
+            .name("native-playback".to_string())
+            .spawn(move || player.run(received))
+            .context("Failed to start native playback thread")?;
+        Ok(Self { commands })
+    }
+
+    pub fn play(&self, job_id: &str, pcm: &[i16], sample_rate: u32) {
+        let _ = self.commands.send(PlayerCommand::Chunk {
+            job_id: job_id.to_string(),
+            pcm: pcm.to_vec(),
+            sample_rate,
+        });
+    }
+
+    /// No more audio is coming for `job_id`; the next job starts once it has played.
+    pub fn end_job(&self, job_id: &str) {
+        let _ = self.commands.send(PlayerCommand::EndJob(job_id.to_string()));
+    }
+
+    /// Drops `job_id`'s audio, played or not.
+    pub fn stop_job(&self, job_id: &str) {
+        let _ = self.commands.send(PlayerCommand::StopJob(job_id.to_string()));
+    }
+
+    pub fn stop_all(&self) {
+        let _ = self.commands.send(PlayerCommand::StopAll);
+    }
+
+    pub fn set_paused(&self, paused: bool) {
+        let _ = self.commands.send(PlayerCommand::SetPaused(paused));
+    }
+
+    /// Moves within the playing job by `offset_ms`, back when negative. The
+    /// job's audio is kept until it ends, so any part received can be replayed.
+    pub fn seek(&self, offset_ms: i64) {
+        let _ = self.commands.send(PlayerCommand::Seek(offset_ms));
+    }
+}
+
+struct JobAudio {
+    job_id: String,
+    sample_rate: u32,
+    samples: Vec<i16>,
+    /// End of each chunk in `samples`, in arrival order.
+    chunk_ends: Vec<usize>,
+    /// Chunks reported as played.
+    reported: usize,
+    ended: bool,
+}
+
+/// A running SoX process and the clock that says how much of what it was
+/// given has been heard.
+struct Output {
+    child: Child,
+    sample_rate: u32,
+    /// Position in the job of the first sample written since `started`.
+    start: usize,
+    started: Instant,
+}
+
+impl Output {
+    fn clock(&self) -> usize {
+        self.start + (self.started.elapsed().as_secs_f64() * self.sample_rate as f64) as usize
+    }
+}
+
+struct Player {
+    sox: PathBuf,
+    on_event: Box<dyn Fn(PlaybackEvent) + Send>,
+    /// The front job is the one playing.
+    jobs: VecDeque<JobAudio>,
+    /// Next sample of the front job to write.
+    cursor: usize,
+    paused: bool,
+    output: Option<Output>,
+}
+
+impl Player {
+    fn run(mut self, commands: Receiver<PlayerCommand>) {
+        loop {
+            match commands.recv_timeout(Duration::from_millis(FEED_INTERVAL_MS)) {
+                Ok(command) => self.apply(command),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+            while let Ok(command) = commands.try_recv() {
+                self.apply(command);
+            }
+            if let Err(err) = self.feed() {
+                (self.on_event)(PlaybackEvent::Failed(format!("{err:#}")));
+                self.stop_all();
+            }
+            self.report_progress();
+        }
+        self.kill_output();
+    }
+
+    fn apply(&mut self, command: PlayerCommand) {
+        match command {
+            PlayerCommand::Chunk {
+                job_id,
+                pcm,
+                sample_rate,
+            } => {
+                if sample_rate == 0 || pcm.is_empty() {
+                    return;
+                }
+                let job = match self.jobs.iter().position(|job| job.job_id == job_id) {
+                    Some(index) => &mut self.jobs[index],
+                    None => {
+                        self.jobs.push_back(JobAudio {
+                            job_id,
+                            sample_rate,
+                            samples: Vec::new(),
+                            chunk_ends: Vec::new(),
+                            reported: 0,
+                            ended: false,
+                        });
+                        self.jobs.back_mut().expect("job was just queued")
+                    }
+                };
+                if sample_rate == job.sample_rate {
+                    job.samples.extend_from_slice(&pcm);
+                } else {
+                    job.samples.extend(resample(&pcm, sample_rate, job.sample_rate));
+                }
+                job.chunk_ends.push(job.samples.len());
+            }
+            PlayerCommand::EndJob(job_id) => match self.jobs.iter_mut().find(|job| job.job_id == job_id) {
+                Some(job) => job.ended = true,
+                None => (self.on_event)(PlaybackEvent::JobPlayed { job_id }),
+            },
+            PlayerCommand::StopJob(job_id) => {
+                if self.jobs.front().is_some_and(|job| job.job_id == job_id) {
+                    self.kill_output();
+                    self.cursor = 0;
+                }
+                self.jobs.retain(|job| job.job_id != job_id);
+            }
+            PlayerCommand::StopAll => self.stop_all(),
+            PlayerCommand::SetPaused(paused) => {
+                if paused && !self.paused {
+                    self.cursor = self.heard();
+                    self.kill_output();
+                }
+                self.paused = paused;
+            }
+            PlayerCommand::Seek(offset_ms) => {
+                let Some(job) = self.jobs.front() else {
+                    return;
+                };
+                let offset = offset_ms * job.sample_rate as i64 / 1000;
+                let target = (self.heard() as i64 + offset).clamp(0, job.samples.len() as i64);
+                self.kill_output();
+                self.cursor = target as usize;
+            }
+        }
+    }
+
+    /// Writes the front job's audio up to `PLAY_AHEAD_MS` past the clock.
+    fn feed(&mut self) -> Result<()> {
+        if self.paused {
+            return Ok(());
+        }
+        let Some((sample_rate, len)) = self.jobs.front().map(|job| (job.sample_rate, job.samples.len())) else {
+            return Ok(());
+        };
+        if self.output.as_ref().is_some_and(|output| output.sample_rate != sample_rate) {
+            self.kill_output();
+        }
+        if self.cursor >= len {
+            return Ok(());
+        }
+        let output = match self.output.as_mut() {
+            Some(output) => output,
+            None => self.output.insert(Output {
+                child: spawn_pcm_player(&self.sox, sample_rate, &["-d"])
+                    .context("Failed to start SoX on the default output device")?,
+                sample_rate,
+                start: self.cursor,
+                started: Instant::now(),
+            }),
+        };
+        // Starved while waiting for synthesis: the gap played as silence, so
+        // the clock restarts from what is left to play.
+        if output.clock() > self.cursor {
+            output.start = self.cursor;
+            output.started = Instant::now();
+        }
+        let ahead = (PLAY_AHEAD_MS * sample_rate as u64 / 1000) as usize;
+        let until = (output.clock() + ahead).min(len);
+        if until <= self.cursor {
+            return Ok(());
+        }
+        let bytes: Vec<u8> = self.jobs[0].samples[self.cursor..until]
+            .iter()
+            .flat_map(|sample| sample.to_le_bytes())
+            .collect();
+        let stdin = output.child.stdin.as_mut().context("SoX input is closed")?;
+        stdin
+            .write_all(&bytes)
+            .and_then(|()| stdin.flush())
+            .context("SoX stopped taking audio")?;
+        self.cursor = until;
+        Ok(())
+    }
+
+    /// Reports chunks the clock has passed, and moves on to the next job
+    /// once the front one has ended and played out.
+    fn report_progress(&mut self) {
+        let heard = self.heard();
+        let Some(job) = self.jobs.front_mut() else {
+            return;
+        };
+        while job.reported < job.chunk_ends.len() && job.chunk_ends[job.reported] <= heard {
+            (self.on_event)(PlaybackEvent::ChunkPlayed {
+                job_id: job.job_id.clone(),
+                ordinal: job.reported as u64,
+            });
+            job.reported += 1;
+        }
+        if !job.ended || heard < job.samples.len() {
+            return;
+        }
+        let job_id = job.job_id.clone();
+        self.jobs.pop_front();
+        self.cursor = 0;
+        (self.on_event)(PlaybackEvent::JobPlayed { job_id });
+        if self.jobs.is_empty() {
+            self.close_output();
+        }
+    }
+
+    /// Position in the front job that has been heard.
+    fn heard(&self) -> usize {
+        match self.output.as_ref() {
+            Some(output) if !self.paused => output.clock().min(self.cursor),
+            _ => self.cursor,
+        }
+    }
+
+    fn stop_all(&mut self) {
+        self.kill_output();
+        self.cursor = 0;
+        for job in self.jobs.drain(..) {
+            (self.on_event)(PlaybackEvent::JobPlayed { job_id: job.job_id });
+        }
+    }
+
+    /// Stops at once, dropping what SoX still holds.
+    fn kill_output(&mut self) {
+        if let Some(mut output) = self.output.take() {
+            let _ = output.child.kill();
+            let _ = output.child.wait();
+        }
+    }
+
+    /// Lets SoX play out what it holds; reaped off the player thread.
+    fn close_output(&mut self) {
+        if let Some(mut output) = self.output.take() {
+            drop(output.child.stdin.take());
+            std::thread::spawn(move || {
+                let _ = output.child.wait();
+            });
+        }
+    }
+}
+
+/// Linear resampling for the rare chunk whose rate differs from the job's.
+fn resample(pcm: &[i16], from: u32, to: u32) -> Vec<i16> {
+    let len = (pcm.len() as u64 * to as u64 / from as u64) as usize;
+    let step = from as f64 / to as f64;
+    (0..len)
+        .map(|index| {
+            let position = index as f64 * step;
+            let base = position.floor() as usize;
+            let next = (base + 1).min(pcm.len() - 1);
+            let fraction = position - base as f64;
+            (pcm[base] as f64 * (1.0 - fraction) + pcm[next] as f64 * fraction).round() as i16
+        })
+        .collect()
+}
@@ -0,0 +1,249 @@
+use std::net::{SocketAddr, UdpSocket};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::audio_export::StreamingWavWriter;
+
+const MAX_AUDIO_SINKS: usize = 4;
+/// Dynamic RTP payload type announced as `L16/<rate>/1` in the receiver's SDP.
+const RTP_PAYLOAD_TYPE: u8 = 96;
+/// 20 ms at 24 kHz; keeps packets well under a typical MTU.
+const RTP_SAMPLES_PER_PACKET: usize = 480;
+/// How far ahead of real time packets may be sent, so the receiver's jitter
+/// buffer stays full without being flooded.
+const RTP_SEND_AHEAD_MS: u64 = 200;
+
+/// Somewhere a job's audio goes besides the app's own player, which is fed
+/// through `voicereader:ws-event` as before. Every configured sink receives
+/// the same PCM, so a read can play locally while it is recorded or streamed
+/// to another device.
+pub trait AudioSink: Send {
+    /// Where the audio goes, for error messages.
+    fn label(&self) -> String;
+    fn write(&mut self, pcm: &[i16], sample_rate: u32) -> Result<()>;
+    /// Called once the job has produced all its audio. A sink that plays in
+    /// real time goes on playing what it has queued; see `is_playing`.
+    fn finish(&mut self) -> Result<()>;
+    /// Called when the job is canceled, before or after `finish`: audio not
+    /// played yet is dropped and playback stops now.
+    fn abort(&mut self) -> Result<()>;
+    /// Holds playback, keeping what is queued, until called with `false`.
+    fn set_paused(&mut self, _paused: bool) {}
+    /// Whether audio is still playing after `finish`. Such a sink is kept
+    /// until it is done, so a cancel or pause still reaches it.
+    fn is_playing(&self) -> bool {
+        false
+    }
+}
+
+/// Records a job to a WAV file; a canceled job leaves a shorter but valid file.
+struct WavSink {
+    label: String,
+    writer: Option<StreamingWavWriter>,
+}
+
+impl AudioSink for WavSink {
+    fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    fn write(&mut self, pcm: &[i16], sample_rate: u32) -> Result<()> {
+        match self.writer.as_mut() {
+            Some(writer) => writer.append(pcm, sample_rate),
+            None => Err(anyhow!("{} is already finished", self.label)),
+        }
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        self.writer.take().map_or(Ok(()), StreamingWavWriter::finish)
+    }
+
+    fn abort(&mut self) -> Result<()> {
+        self.finish()
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AudioSinkConfig {
+    /// Records every job to `<dir>/<job id>.wav`.
+    WavDir { dir: String },
+    /// Streams 16-bit mono PCM as RTP over UDP to `address` (`host:port`), in
+    /// real time. Receivers need an SDP such as
+    /// `m=audio 5004 RTP/AVP 96` / `a=rtpmap:96 L16/24000/1`.
+    Rtp { address: String },
+}
+
+impl AudioSinkConfig {
+    fn validate(&self) -> Result<()> {
+        match self {
+            Self::WavDir { dir } => {
+                if !PathBuf::from(dir.trim()).is_absolute() {
+                    return Err(anyhow!("WAV sink directory {dir:?} must be an absolute path"));
+                }
+            }
+            Self::Rtp { address } => {
+                address
+                    .trim()
+                    .parse::<SocketAddr>()
+                    .map_err(|_| anyhow!("RTP sink address {address:?} must be an IP address and port"))?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn open(&self, job_id: &str) -> Result<Box<dyn AudioSink>> {
+        match self {
+            Self::WavDir { dir } => {
+                let path = PathBuf::from(dir.trim()).join(format!("{job_id}.wav"));
+                Ok(Box::new(WavSink {
+                    label: path.display().to_string(),
+                    writer: Some(StreamingWavWriter::create(&path, None)?),
+                }))
+            }
+            Self::Rtp { address } => Ok(Box::new(RtpSink::connect(address.trim())?)),
+        }
+    }
+}
+
+/// Sinks every job fans out to; empty by default.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AudioSinkSettings {
+    pub sinks: Vec<AudioSinkConfig>,
+}
+
+impl AudioSinkSettings {
+    pub fn validate(&self) -> Result<()> {
+        if self.sinks.len() > MAX_AUDIO_SINKS {
+            return Err(anyhow!("At most {MAX_AUDIO_SINKS} audio sinks can be configured"));
+        }
+        self.sinks.iter().try_for_each(AudioSinkConfig::validate)
+    }
+}
+
+/// Hands audio to a sender thread that paces packets at playback speed;
+/// synthesis usually runs ahead of real time, and a burst would overrun the
+/// receiver.
+struct RtpSink {
+    address: String,
+    /// `None` once finished; dropping the sender lets the thread run out.
+    audio: Option<Sender<(Vec<i16>, u32)>>,
+    /// Tells the sender thread to drop what is queued and stop.
+    aborted: Arc<AtomicBool>,
+    /// Set by the sender thread once it has sent everything.
+    done: Arc<AtomicBool>,
+}
+
+impl RtpSink {
+    fn connect(address: &str) -> Result<Self> {
+        let target: SocketAddr = address.parse().with_context(|| format!("Invalid RTP address {address}"))?;
+        let bind = if target.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" };
+        let socket = UdpSocket::bind(bind).context("Failed to open RTP socket")?;
+        socket
+            .connect(target)
+            .with_context(|| format!("Failed to address RTP stream to {address}"))?;
+        let (audio, queued) = mpsc::channel();
+        let aborted = Arc::new(AtomicBool::new(false));
+        let done = Arc::new(AtomicBool::new(false));
+        let (aborted_for_sender, done_for_sender) = (aborted.clone(), done.clone());
+        std::thread::Builder::new()
+            .name("rtp-sink".to_string())
+            .spawn(move || {
+                send_rtp(&socket, queued, &aborted_for_sender);
+                done_for_sender.store(true, Ordering::SeqCst);
+            })
+            .context("Failed to start RTP sender thread")?;
+        Ok(Self {
+            address: address.to_string(),
+            audio: Some(audio),
+            aborted,
+            done,
+        })
+    }
+}
+
+impl AudioSink for RtpSink {
+    fn label(&self) -> String {
+        format!("rtp://{}", self.address)
+    }
+
+    fn write(&mut self, pcm: &[i16], sample_rate: u32) -> Result<()> {
+        self.audio
+            .as_ref()
+            .ok_or_else(|| anyhow!("RTP stream is already finished"))?
+            .send((pcm.to_vec(), sample_rate))
+            .map_err(|_| anyhow!("RTP sender stopped"))
+    }
+
+    /// Audio already queued keeps streaming after the job ends.
+    fn finish(&mut self) -> Result<()> {
+        self.audio = None;
+        Ok(())
+    }
+
+    fn abort(&mut self) -> Result<()> {
+        self.aborted.store(true, Ordering::SeqCst);
+        self.audio = None;
+        Ok(())
+    }
+
+    fn is_playing(&self) -> bool {
+        !self.done.load(Ordering::SeqCst)
+    }
+}
+
+fn send_rtp(socket: &UdpSocket, queued: Receiver<(Vec<i16>, u32)>, aborted: &AtomicBool) {
+    let ssrc: u32 = rand::random();
+    let mut sequence: u16 = rand::random();
+    let mut timestamp: u32 = rand::random();
+    // Set on the first packet of each talkspurt.
+    let mut marker = true;
+    let started = Instant::now();
+    // Playback time of everything sent so far.
+    let mut sent = Duration::ZERO;
+    let ahead = Duration::from_millis(RTP_SEND_AHEAD_MS);
+    for (pcm, sample_rate) in queued {
+        if sample_rate == 0 {
+            continue;
+        }
+        for samples in pcm.chunks(RTP_SAMPLES_PER_PACKET) {
+            if aborted.load(Ordering::SeqCst) {
+                return;
+            }
+            // After a gap between chunks, skip the timestamps ahead so the
+            // receiver plays the gap as silence instead of bursting to catch up.
+            let elapsed = started.elapsed();
+            if sent + ahead < elapsed {
+                let gap = elapsed - sent;
+                timestamp = timestamp.wrapping_add((gap.as_secs_f64() * sample_rate as f64) as u32);
+                sent = elapsed;
+                marker = true;
+            }
+            if let Some(wait) = sent.checked_sub(elapsed + ahead) {
+                std::thread::sleep(wait);
+            }
+            let mut packet = Vec::with_capacity(12 + samples.len() * 2);
+            packet.push(0x80);
+            packet.push(if marker { 0x80 | RTP_PAYLOAD_TYPE } else { RTP_PAYLOAD_TYPE });
+            packet.extend_from_slice(&sequence.to_be_bytes());
+            packet.extend_from_slice(&timestamp.to_be_bytes());
+            packet.extend_from_slice(&ssrc.to_be_bytes());
+            // L16 is big-endian.
+            for sample in samples {
+                packet.extend_from_slice(&sample.to_be_bytes());
+            }
+            // UDP is best effort; a lost packet is a short gap, not an error.
+            let _ = socket.send(&packet);
+            marker = false;
+            sequence = sequence.wrapping_add(1);
+            timestamp = timestamp.wrapping_add(samples.len() as u32);
+            sent += Duration::from_secs_f64(samples.len() as f64 / sample_rate as f64);
+        }
+    }
+}
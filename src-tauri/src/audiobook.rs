@@ -0,0 +1,187 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::audio_export::{write_wav_file, StreamingWavWriter, SyntheticProvenance};
+
+const MAX_SECTIONS: usize = 500;
+const MAX_TITLE_CHARS_IN_FILE_NAME: usize = 60;
+/// Silence between chapters in a single-file audiobook.
+const CHAPTER_GAP_MS: u64 = 1_000;
+pub const SINGLE_FILE_NAME: &str = "audiobook.wav";
+pub const MANIFEST_FILE_NAME: &str = "chapters.json";
+
+#[derive(Clone, Deserialize)]
+pub struct AudiobookSection {
+    /// Defaults to "Chapter <n>".
+    #[serde(default)]
+    pub title: Option<String>,
+    pub text: String,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AudiobookLayout {
+    /// One `audiobook.wav` with the chapters back to back.
+    #[default]
+    SingleFile,
+    /// One numbered WAV per chapter.
+    PerChapter,
+}
+
+#[derive(Clone, Serialize)]
+pub struct ChapterMarker {
+    pub index: usize,
+    pub title: String,
+    /// File name within the output directory.
+    pub file: String,
+    /// Where the chapter starts in `file`; 0 unless the layout is a single file.
+    pub start_ms: u64,
+    pub duration_ms: u64,
+}
+
+/// Written to `chapters.json` next to the audio.
+#[derive(Clone, Serialize)]
+pub struct ChapterManifest {
+    pub layout: AudiobookLayout,
+    pub sample_rate: u32,
+    pub duration_ms: u64,
+    pub chapters: Vec<ChapterMarker>,
+}
+
+pub fn validate_sections(sections: &[AudiobookSection]) -> Result<()> {
+    if sections.is_empty() {
+        return Err(anyhow!("An audiobook needs at least one section"));
+    }
+    if sections.len() > MAX_SECTIONS {
+        return Err(anyhow!("An audiobook can have at most {MAX_SECTIONS} sections"));
+    }
+    if let Some(index) = sections.iter().position(|section| section.text.trim().is_empty()) {
+        return Err(anyhow!("Section {} has no text", index + 1));
+    }
+    Ok(())
+}
+
+pub fn section_title(section: &AudiobookSection, index: usize) -> String {
+    section
+        .title
+        .as_deref()
+        .map(str::trim)
+        .filter(|title| !title.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("Chapter {}", index + 1))
+}
+
+/// `03 - Title.wav`, numbered so file managers list chapters in order.
+fn chapter_file_name(index: usize, total: usize, title: &str) -> String {
+    let width = total.to_string().len().max(2);
+    let safe: String = title
+        .chars()
+        .map(|ch| if ch.is_alphanumeric() || " -_.,'()".contains(ch) { ch } else { '_' })
+        .take(MAX_TITLE_CHARS_IN_FILE_NAME)
+        .collect();
+    // Windows drops trailing dots and spaces from file names.
+    let safe = safe.trim().trim_end_matches('.');
+    if safe.is_empty() {
+        format!("{:0width$}.wav", index + 1)
+    } else {
+        format!("{:0width$} - {safe}.wav", index + 1)
+    }
+}
+
+/// Collects synthesized chapters into the chosen layout and the manifest.
+pub struct AudiobookWriter {
+    dir: PathBuf,
+    layout: AudiobookLayout,
+    total: usize,
+    provenance: Option<SyntheticProvenance>,
+    /// Open only for the single-file layout.
+    single: Option<StreamingWavWriter>,
+    sample_rate: u32,
+    chapters: Vec<ChapterMarker>,
+}
+
+impl AudiobookWriter {
+    pub fn create(
+        dir: &Path,
+        layout: AudiobookLayout,
+        total: usize,
+        provenance: Option<SyntheticProvenance>,
+    ) -> Result<Self> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create audiobook directory {}", dir.display()))?;
+        let single = match layout {
+            AudiobookLayout::SingleFile => {
+                Some(StreamingWavWriter::create(&dir.join(SINGLE_FILE_NAME), provenance.clone())?)
+            }
+            AudiobookLayout::PerChapter => None,
+        };
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            layout,
+            total,
+            provenance,
+            single,
+            sample_rate: 0,
+            chapters: Vec::new(),
+        })
+    }
+
+    /// Adds the next chapter and returns the file it went to.
+    pub fn add_chapter(&mut self, title: &str, pcm: &[i16], sample_rate: u32) -> Result<PathBuf> {
+        if sample_rate == 0 {
+            return Err(anyhow!("Chapter \"{title}\" has no audio"));
+        }
+        let index = self.chapters.len();
+        let duration_ms = pcm.len() as u64 * 1000 / sample_rate as u64;
+        let (file, start_ms) = match self.single.as_mut() {
+            Some(writer) => {
+                if index > 0 {
+                    let gap = vec![0i16; (CHAPTER_GAP_MS * sample_rate as u64 / 1000) as usize];
+                    writer.append(&gap, sample_rate)?;
+                }
+                let start_ms = writer.duration_ms();
+                writer.append(pcm, sample_rate)?;
+                (SINGLE_FILE_NAME.to_string(), start_ms)
+            }
+            None => {
+                let file = chapter_file_name(index, self.total, title);
+                write_wav_file(&self.dir.join(&file), pcm, sample_rate, self.provenance.as_ref())?;
+                (file, 0)
+            }
+        };
+        self.sample_rate = sample_rate;
+        let path = self.dir.join(&file);
+        self.chapters.push(ChapterMarker {
+            index,
+            title: title.to_string(),
+            file,
+            start_ms,
+            duration_ms,
+        });
+        Ok(path)
+    }
+
+    /// Finishes the audio and writes `chapters.json`.
+    pub fn finish(self) -> Result<ChapterManifest> {
+        let duration_ms = match self.single {
+            Some(writer) => {
+                let duration_ms = writer.duration_ms();
+                writer.finish()?;
+                duration_ms
+            }
+            None => self.chapters.iter().map(|chapter| chapter.duration_ms).sum(),
+        };
+        let manifest = ChapterManifest {
+            layout: self.layout,
+            sample_rate: self.sample_rate,
+            duration_ms,
+            chapters: self.chapters,
+        };
+        let path = self.dir.join(MANIFEST_FILE_NAME);
+        let serialized = serde_json::to_string_pretty(&manifest)?;
+        std::fs::write(&path, serialized).with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(manifest)
+    }
+}
@@ -0,0 +1,176 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Runs kept in the history file; the oldest are dropped first.
+const MAX_HISTORY_RUNS: usize = 100;
+
+const QUICK_CORPUS: &str = "The quick brown fox jumps over the lazy dog. \
+Pack my box with five dozen liquor jugs.";
+
+const STANDARD_CORPUS: &str = "Reading aloud is a simple idea with a long history. \
+Before printed books were common, most people heard texts rather than read them, \
+and a good reader was valued for clear pacing and steady breath. \
+Today the same skill helps anyone who prefers to listen: on a walk, while cooking, \
+or when their eyes need a rest after a long day at the screen. \
+A useful voice should handle numbers like 3.5 percent, dates like March 14, 2024, \
+and short questions without sounding rushed. Does it pause at commas? \
+Does it stop cleanly at the end of a sentence? Those details decide whether \
+a listener keeps going or gives up after a paragraph.";
+
+const LONG_CORPUS_TAIL: &str = " \
+The second part of this passage is deliberately plain. It describes a small town \
+beside a river, where the bridge was rebuilt three times in one century. \
+The first bridge was wooden and washed away in a spring flood. \
+The second was stone, narrow enough that carts had to take turns. \
+The third, finished in 1962, carries two lanes of traffic and a footpath \
+that people still use to watch the water in the evening. \
+None of this is remarkable, which is the point: long, ordinary text is what \
+most people ask a reader to get through, and it is where slow synthesis \
+or uneven chunking becomes obvious.";
+
+/// Fixed corpora, so runs compare machines and settings rather than text.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BenchmarkProfile {
+    /// Two short sentences; mostly measures startup and first-chunk latency.
+    Quick,
+    /// One paragraph with numbers, dates and questions.
+    Standard,
+    /// Standard plus a second paragraph, for sustained throughput.
+    Long,
+}
+
+impl BenchmarkProfile {
+    pub fn corpus(self) -> String {
+        match self {
+            Self::Quick => QUICK_CORPUS.to_string(),
+            Self::Standard => STANDARD_CORPUS.to_string(),
+            Self::Long => format!("{STANDARD_CORPUS}{LONG_CORPUS_TAIL}"),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BenchmarkResult {
+    /// RFC 3339 local time the run finished.
+    pub recorded_at: String,
+    pub profile: BenchmarkProfile,
+    pub build_variant: String,
+    pub model: String,
+    pub voice_id: String,
+    pub rate: f32,
+    pub chunk_max_chars: u32,
+    pub time_to_first_chunk_ms: u64,
+    pub wall_secs: f64,
+    /// Length of the synthesized audio at 1.0x.
+    pub audio_secs: f64,
+    /// Synthesis wall time / audio time at 1.0x; below 1.0 is faster than real time.
+    pub rtf: f64,
+    /// Peak resident memory of the synthesizing process since it started,
+    /// in MiB. `None` where the platform does not expose it.
+    pub peak_memory_mb: Option<f64>,
+}
+
+/// Past benchmark runs, oldest first.
+#[derive(Default, Serialize, Deserialize)]
+pub struct BenchmarkHistory {
+    runs: Vec<BenchmarkResult>,
+}
+
+impl BenchmarkHistory {
+    /// A missing file is an empty history; a corrupt one is an error so a
+    /// later save does not discard earlier runs.
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(body) => serde_json::from_str(&body).with_context(|| format!("Failed to parse {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(anyhow!("Failed to read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create benchmarks directory {}", parent.display()))?;
+        }
+        let serialized = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, serialized)
+            .with_context(|| format!("Failed to write benchmarks file {}", path.display()))
+    }
+
+    pub fn runs(&self) -> &[BenchmarkResult] {
+        &self.runs
+    }
+
+    pub fn push(&mut self, result: BenchmarkResult) {
+        self.runs.push(result);
+        if self.runs.len() > MAX_HISTORY_RUNS {
+            let excess = self.runs.len() - MAX_HISTORY_RUNS;
+            self.runs.drain(..excess);
+        }
+    }
+}
+
+/// Peak resident memory of process `pid` (this process when `None`).
+#[cfg(target_os = "linux")]
+pub fn peak_memory_mb(pid: Option<u32>) -> Option<f64> {
+    let path = match pid {
+        Some(pid) => format!("/proc/{pid}/status"),
+        None => "/proc/self/status".to_string(),
+    };
+    let status = std::fs::read_to_string(path).ok()?;
+    let kib: f64 = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kib / 1024.0)
+}
+
+/// Peak resident memory of this process; other processes are not readable
+/// without extra entitlements, so `pid` yields `None`.
+#[cfg(target_os = "macos")]
+pub fn peak_memory_mb(pid: Option<u32>) -> Option<f64> {
+    if pid.is_some() {
+        return None;
+    }
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } != 0 {
+        return None;
+    }
+    // ru_maxrss is in bytes on macOS (kilobytes elsewhere).
+    Some(usage.ru_maxrss as f64 / (1024.0 * 1024.0))
+}
+
+/// Peak working set of process `pid` (this process when `None`).
+#[cfg(target_os = "windows")]
+pub fn peak_memory_mb(pid: Option<u32>) -> Option<f64> {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::ProcessStatus::{K32GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+    use windows_sys::Win32::System::Threading::{GetCurrentProcess, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION};
+
+    let process = match pid {
+        Some(pid) => unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid) },
+        None => unsafe { GetCurrentProcess() },
+    };
+    if process.is_null() {
+        return None;
+    }
+    let mut counters: PROCESS_MEMORY_COUNTERS = unsafe { std::mem::zeroed() };
+    let size = std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32;
+    let queried = unsafe { K32GetProcessMemoryInfo(process, &mut counters, size) };
+    if pid.is_some() {
+        unsafe { CloseHandle(process) };
+    }
+    (queried != 0).then(|| counters.PeakWorkingSetSize as f64 / (1024.0 * 1024.0))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+pub fn peak_memory_mb(_pid: Option<u32>) -> Option<f64> {
+    None
+}
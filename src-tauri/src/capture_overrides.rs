@@ -0,0 +1,74 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::job_history::CaptureMethod;
+
+const MAX_OVERRIDES: usize = 64;
+
+/// Selection capture methods this platform has, in the order they are tried
+/// when no override applies.
+pub fn default_capture_order() -> Vec<CaptureMethod> {
+    if cfg!(target_os = "windows") {
+        vec![CaptureMethod::Accessibility, CaptureMethod::ClipboardProbe]
+    } else if cfg!(target_os = "macos") {
+        vec![CaptureMethod::ClipboardProbe]
+    } else {
+        vec![CaptureMethod::PrimarySelection, CaptureMethod::ClipboardProbe]
+    }
+}
+
+/// Capture methods used for one app instead of the default order, e.g.
+/// only the clipboard probe for a terminal whose UI Automation selection is
+/// wrong.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaptureOverride {
+    /// Foreground app name as capture metadata reports it (`WindowsTerminal`,
+    /// `Terminal`), matched case-insensitively.
+    pub app: String,
+    /// Tried in this order; methods left out are never used for the app.
+    pub methods: Vec<CaptureMethod>,
+}
+
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CaptureOverrides(Vec<CaptureOverride>);
+
+impl CaptureOverrides {
+    pub fn validate(&self) -> Result<()> {
+        if self.0.len() > MAX_OVERRIDES {
+            return Err(anyhow!("At most {MAX_OVERRIDES} capture overrides are supported"));
+        }
+        let supported = default_capture_order();
+        for (index, entry) in self.0.iter().enumerate() {
+            let app = entry.app.trim();
+            if app.is_empty() {
+                return Err(anyhow!("Capture override {index} has no app name"));
+            }
+            if self.0[..index].iter().any(|earlier| earlier.app.trim().eq_ignore_ascii_case(app)) {
+                return Err(anyhow!("{app} has more than one capture override"));
+            }
+            if entry.methods.is_empty() {
+                return Err(anyhow!("Capture override for {app} lists no methods"));
+            }
+            for (position, method) in entry.methods.iter().enumerate() {
+                if !supported.contains(method) {
+                    return Err(anyhow!(
+                        "Capture override for {app} uses a method this platform does not capture selections with"
+                    ));
+                }
+                if entry.methods[..position].contains(method) {
+                    return Err(anyhow!("Capture override for {app} lists a method twice"));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The methods to try for `app`: its override or else the default order.
+    pub fn methods_for(&self, app: Option<&str>) -> Vec<CaptureMethod> {
+        app.map(str::trim)
+            .and_then(|app| self.0.iter().find(|entry| entry.app.trim().eq_ignore_ascii_case(app)))
+            .map(|entry| entry.methods.clone())
+            .unwrap_or_else(default_capture_order)
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::job_history::CaptureMethod;
+
+/// Apps tracked by name; past this the one with the fewest captures is
+/// dropped to make room.
+const MAX_TRACKED_APPS: usize = 64;
+/// Stands in for the app name where the platform does not report one.
+const UNKNOWN_APP: &str = "(unknown)";
+
+#[derive(Clone, Copy, Default)]
+struct Tally {
+    attempts: u64,
+    successes: u64,
+}
+
+impl Tally {
+    fn record(&mut self, succeeded: bool) {
+        self.attempts += 1;
+        if succeeded {
+            self.successes += 1;
+        }
+    }
+}
+
+#[derive(Default)]
+struct AppTally {
+    captures: u64,
+    failed_captures: u64,
+    methods: HashMap<CaptureMethod, Tally>,
+}
+
+#[derive(Serialize)]
+pub struct MethodStats {
+    pub method: CaptureMethod,
+    pub attempts: u64,
+    pub successes: u64,
+    pub failures: u64,
+    /// Share of attempts that found text, from 0.0 to 1.0.
+    pub success_rate: f64,
+}
+
+impl MethodStats {
+    fn new(method: CaptureMethod, tally: Tally) -> Self {
+        Self {
+            method,
+            attempts: tally.attempts,
+            successes: tally.successes,
+            failures: tally.attempts - tally.successes,
+            success_rate: tally.successes as f64 / tally.attempts.max(1) as f64,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct AppCaptureStats {
+    pub app: String,
+    /// Selection captures started in this app.
+    pub captures: u64,
+    /// Captures where every method came back empty.
+    pub failed_captures: u64,
+    /// Each method tried, in fallback order; a capture counts for every
+    /// method it tried before one found text.
+    pub methods: Vec<MethodStats>,
+}
+
+#[derive(Serialize)]
+pub struct CaptureStatsReport {
+    pub methods: Vec<MethodStats>,
+    /// Most captured first.
+    pub apps: Vec<AppCaptureStats>,
+}
+
+/// Success and failure counts of selection capture since the app started,
+/// per method and per foreground app, to show which apps need a different
+/// fallback order.
+#[derive(Default)]
+pub struct CaptureStats {
+    methods: HashMap<CaptureMethod, Tally>,
+    apps: HashMap<String, AppTally>,
+}
+
+impl CaptureStats {
+    /// Records one capture: the methods it tried, in order, and whether each
+    /// found text.
+    pub fn record(&mut self, app: Option<&str>, attempts: &[(CaptureMethod, bool)]) {
+        if attempts.is_empty() {
+            return;
+        }
+        for (method, succeeded) in attempts {
+            self.methods.entry(*method).or_default().record(*succeeded);
+        }
+
+        let app = app.map(str::trim).filter(|app| !app.is_empty()).unwrap_or(UNKNOWN_APP);
+        if !self.apps.contains_key(app) && self.apps.len() >= MAX_TRACKED_APPS {
+            if let Some(least) = self
+                .apps
+                .iter()
+                .min_by_key(|(_, tally)| tally.captures)
+                .map(|(name, _)| name.clone())
+            {
+                self.apps.remove(&least);
+            }
+        }
+        let tally = self.apps.entry(app.to_string()).or_default();
+        tally.captures += 1;
+        if !attempts.iter().any(|(_, succeeded)| *succeeded) {
+            tally.failed_captures += 1;
+        }
+        for (method, succeeded) in attempts {
+            tally.methods.entry(*method).or_default().record(*succeeded);
+        }
+    }
+
+    pub fn report(&self) -> CaptureStatsReport {
+        let mut apps: Vec<AppCaptureStats> = self
+            .apps
+            .iter()
+            .map(|(app, tally)| AppCaptureStats {
+                app: app.clone(),
+                captures: tally.captures,
+                failed_captures: tally.failed_captures,
+                methods: method_stats(&tally.methods),
+            })
+            .collect();
+        apps.sort_by(|a, b| b.captures.cmp(&a.captures).then_with(|| a.app.cmp(&b.app)));
+        CaptureStatsReport {
+            methods: method_stats(&self.methods),
+            apps,
+        }
+    }
+}
+
+/// Most attempted first, which is also the fallback order.
+fn method_stats(tallies: &HashMap<CaptureMethod, Tally>) -> Vec<MethodStats> {
+    let mut stats: Vec<MethodStats> = tallies
+        .iter()
+        .map(|(method, tally)| MethodStats::new(*method, *tally))
+        .collect();
+    stats.sort_by_key(|stats| std::cmp::Reverse(stats.attempts));
+    stats
+}
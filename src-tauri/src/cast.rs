@@ -0,0 +1,410 @@
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::audio_export::wav_stream_header;
+use crate::audio_sinks::AudioSink;
+use crate::feeds::{element_text, find_element};
+
+pub const CAST_KIND_DLNA: &str = "dlna";
+const SSDP_ADDRESS: &str = "239.255.255.250:1900";
+const AV_TRANSPORT_SEARCH_TARGET: &str = "urn:schemas-upnp-org:service:AVTransport:1";
+const DESCRIPTION_TIMEOUT_SECS: u64 = 3;
+const CONTROL_TIMEOUT_SECS: u64 = 10;
+/// Audio kept for a renderer that has not connected yet; older audio is
+/// dropped so a renderer that never comes does not grow memory.
+const MAX_PENDING_SECS: usize = 30;
+/// How long the stream waits for a renderer after the job has ended.
+const LATE_CONNECT_GRACE_SECS: u64 = 30;
+const ACCEPT_POLL_MS: u64 = 100;
+const MAX_REQUEST_HEAD_BYTES: usize = 8 * 1024;
+
+/// A network speaker found by `discover_renderers`. Only DLNA/UPnP media
+/// renderers are supported: Chromecast and AirPlay need their own
+/// encrypted protocols, though many of those speakers also answer DLNA.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct CastTarget {
+    /// The renderer's UDN, stable across restarts and address changes.
+    pub id: String,
+    pub name: String,
+    pub kind: String,
+    pub control_url: String,
+    pub service_type: String,
+}
+
+/// Sends an SSDP search for media renderers and collects the description
+/// URLs that answer within `timeout`. Blocking.
+pub fn search_renderers(timeout: Duration) -> Result<Vec<String>> {
+    let socket = UdpSocket::bind("0.0.0.0:0").context("Failed to open discovery socket")?;
+    let mx = timeout.as_secs().clamp(1, 5);
+    let search = format!(
+        "M-SEARCH * HTTP/1.1\r\nHOST: {SSDP_ADDRESS}\r\nMAN: \"ssdp:discover\"\r\nMX: {mx}\r\nST: {AV_TRANSPORT_SEARCH_TARGET}\r\n\r\n"
+    );
+    socket
+        .send_to(search.as_bytes(), SSDP_ADDRESS)
+        .context("Failed to send discovery request")?;
+    let deadline = Instant::now() + timeout;
+    let mut locations: Vec<String> = Vec::new();
+    let mut buffer = [0u8; 2048];
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()).filter(|left| !left.is_zero()) {
+        socket.set_read_timeout(Some(remaining)).context("Failed to wait for discovery replies")?;
+        let Ok((len, _)) = socket.recv_from(&mut buffer) else {
+            break;
+        };
+        let reply = String::from_utf8_lossy(&buffer[..len]);
+        let location = reply.lines().find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.trim().eq_ignore_ascii_case("location").then(|| value.trim().to_string())
+        });
+        if let Some(location) = location.filter(|location| !locations.contains(location)) {
+            locations.push(location);
+        }
+    }
+    Ok(locations)
+}
+
+/// Reads a renderer's device description; `None` when it has no
+/// AVTransport service.
+pub async fn describe_renderer(client: &Client, location: &str) -> Result<Option<CastTarget>> {
+    let body = client
+        .get(location)
+        .timeout(Duration::from_secs(DESCRIPTION_TIMEOUT_SECS))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .with_context(|| format!("Failed to fetch device description {location}"))?
+        .text()
+        .await
+        .with_context(|| format!("Failed to read device description {location}"))?;
+
+    let mut offset = 0;
+    while let Some((_, body_start, body_end)) = find_element(&body, "service", offset) {
+        offset = body_end;
+        let service = &body[body_start..body_end];
+        let Some(service_type) = element_text(service, "serviceType").filter(|kind| kind.contains(":AVTransport:"))
+        else {
+            continue;
+        };
+        let Some(control_path) = element_text(service, "controlURL") else {
+            continue;
+        };
+        let base = element_text(&body, "URLBase").unwrap_or_else(|| location.to_string());
+        let control_url = reqwest::Url::parse(&base)
+            .and_then(|base| base.join(&control_path))
+            .with_context(|| format!("Invalid control URL {control_path}"))?
+            .to_string();
+        let name = element_text(&body, "friendlyName").unwrap_or_else(|| "Network speaker".to_string());
+        return Ok(Some(CastTarget {
+            id: element_text(&body, "UDN").unwrap_or_else(|| location.to_string()),
+            name,
+            kind: CAST_KIND_DLNA.to_string(),
+            control_url,
+            service_type,
+        }));
+    }
+    Ok(None)
+}
+
+/// Points the renderer at `url` and starts playback.
+pub async fn play_url(client: &Client, target: &CastTarget, url: &str) -> Result<()> {
+    let metadata = format!(
+        r#"<DIDL-Lite xmlns="urn:schemas-upnp-org:metadata-1-0/DIDL-Lite/" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:upnp="urn:schemas-upnp-org:metadata-1-0/upnp/"><item id="0" parentID="-1" restricted="1"><dc:title>VoiceReader</dc:title><upnp:class>object.item.audioItem</upnp:class><res protocolInfo="http-get:*:audio/wav:*">{}</res></item></DIDL-Lite>"#,
+        escape_xml(url)
+    );
+    let arguments = format!(
+        "<InstanceID>0</InstanceID><CurrentURI>{}</CurrentURI><CurrentURIMetaData>{}</CurrentURIMetaData>",
+        escape_xml(url),
+        escape_xml(&metadata)
+    );
+    soap_action(client, target, "SetAVTransportURI", &arguments).await?;
+    soap_action(client, target, "Play", "<InstanceID>0</InstanceID><Speed>1</Speed>").await
+}
+
+async fn soap_action(client: &Client, target: &CastTarget, action: &str, arguments: &str) -> Result<()> {
+    let service = &target.service_type;
+    let envelope = format!(
+        r#"<?xml version="1.0" encoding="utf-8"?><s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/" s:encodingStyle="http://schemas.xmlsoap.org/soap/encoding/"><s:Body><u:{action} xmlns:u="{service}">{arguments}</u:{action}></s:Body></s:Envelope>"#
+    );
+    let response = client
+        .post(&target.control_url)
+        .timeout(Duration::from_secs(CONTROL_TIMEOUT_SECS))
+        .header("Content-Type", r#"text/xml; charset="utf-8""#)
+        .header("SOAPAction", format!("\"{service}#{action}\""))
+        .body(envelope)
+        .send()
+        .await
+        .with_context(|| format!("{action} request to {} failed", target.name))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        let detail = element_text(&body, "errorDescription").unwrap_or(body);
+        return Err(anyhow!("{} rejected {action} with status {status}: {detail}", target.name));
+    }
+    Ok(())
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[derive(Default)]
+struct StreamBuffer {
+    pending: VecDeque<i16>,
+    sample_rate: u32,
+    ended: bool,
+    /// Bumped per GET, so a renderer that reconnects takes the stream over
+    /// from its previous connection.
+    generation: u64,
+}
+
+/// Streams one job's audio to a renderer as an open-ended WAV served over
+/// HTTP. Renderers pull at playback speed, so audio is queued here until
+/// they read it.
+pub struct CastSink {
+    target: CastTarget,
+    audio: Option<Sender<(Vec<i16>, u32)>>,
+    buffer: Arc<(Mutex<StreamBuffer>, Condvar)>,
+    /// Set once the stream has been served and closed.
+    done: Arc<AtomicBool>,
+}
+
+impl CastSink {
+    /// Starts serving on the interface that reaches `target` and returns the
+    /// sink with the URL to hand to the renderer.
+    pub fn start(target: &CastTarget, job_id: &str) -> Result<(Self, String)> {
+        let local_ip = local_ip_towards(&target.control_url)?;
+        let listener = TcpListener::bind((local_ip, 0)).context("Failed to open cast stream port")?;
+        listener
+            .set_nonblocking(true)
+            .context("Failed to configure cast stream port")?;
+        let port = listener.local_addr().context("Failed to read cast stream port")?.port();
+        let path = format!("/cast/{job_id}.wav");
+        let url = format!("http://{}/{}", SocketAddr::new(local_ip, port), path.trim_start_matches('/'));
+
+        let buffer = Arc::new((Mutex::new(StreamBuffer::default()), Condvar::new()));
+        let (audio, queued) = mpsc::channel();
+        let feeder_buffer = buffer.clone();
+        std::thread::Builder::new()
+            .name("cast-feed".to_string())
+            .spawn(move || feed_stream(queued, &feeder_buffer))
+            .context("Failed to start cast feed thread")?;
+        let done = Arc::new(AtomicBool::new(false));
+        let server_buffer = buffer.clone();
+        let server_done = done.clone();
+        std::thread::Builder::new()
+            .name("cast-serve".to_string())
+            .spawn(move || {
+                serve_stream(listener, &path, &server_buffer);
+                server_done.store(true, Ordering::SeqCst);
+            })
+            .context("Failed to start cast server thread")?;
+        Ok((
+            Self {
+                target: target.clone(),
+                audio: Some(audio),
+                buffer,
+                done,
+            },
+            url,
+        ))
+    }
+
+    /// Sends a transport action to the renderer in the background.
+    fn send_action(&self, action: &'static str, arguments: &'static str) {
+        let target = self.target.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(err) = soap_action(&Client::new(), &target, action, arguments).await {
+                eprintln!("Cast {action} failed: {err:#}");
+            }
+        });
+    }
+}
+
+impl AudioSink for CastSink {
+    fn label(&self) -> String {
+        format!("cast to {}", self.target.name)
+    }
+
+    fn write(&mut self, pcm: &[i16], sample_rate: u32) -> Result<()> {
+        self.audio
+            .as_ref()
+            .ok_or_else(|| anyhow!("Cast stream stopped"))?
+            .send((pcm.to_vec(), sample_rate))
+            .map_err(|_| anyhow!("Cast stream stopped"))
+    }
+
+    /// The renderer keeps reading what is queued; the stream closes after.
+    fn finish(&mut self) -> Result<()> {
+        self.audio = None;
+        Ok(())
+    }
+
+    /// Closes the stream with nothing more to send and tells the renderer to
+    /// stop, since it plays out what it has already buffered otherwise.
+    fn abort(&mut self) -> Result<()> {
+        self.audio = None;
+        let (lock, ready) = &*self.buffer;
+        if let Ok(mut stream) = lock.lock() {
+            stream.pending.clear();
+            stream.ended = true;
+            // Ends the connection the renderer is reading from.
+            stream.generation += 1;
+            ready.notify_all();
+        }
+        self.send_action("Stop", "<InstanceID>0</InstanceID>");
+        Ok(())
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        if paused {
+            self.send_action("Pause", "<InstanceID>0</InstanceID>");
+        } else {
+            self.send_action("Play", "<InstanceID>0</InstanceID><Speed>1</Speed>");
+        }
+    }
+
+    fn is_playing(&self) -> bool {
+        !self.done.load(Ordering::SeqCst)
+    }
+}
+
+/// The address of this machine on the network the renderer is on.
+fn local_ip_towards(control_url: &str) -> Result<std::net::IpAddr> {
+    let url = reqwest::Url::parse(control_url).with_context(|| format!("Invalid control URL {control_url}"))?;
+    let host = url.host_str().ok_or_else(|| anyhow!("Control URL {control_url} has no host"))?;
+    let remote = (host, url.port_or_known_default().unwrap_or(80))
+        .to_socket_addrs()
+        .with_context(|| format!("Failed to resolve {host}"))?
+        .next()
+        .ok_or_else(|| anyhow!("{host} has no address"))?;
+    let probe = UdpSocket::bind(if remote.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" })
+        .context("Failed to pick a network interface")?;
+    // Connecting a UDP socket sends nothing; it only selects the route.
+    probe
+        .connect(remote)
+        .with_context(|| format!("No route to {host}"))?;
+    Ok(probe.local_addr().context("Failed to pick a network interface")?.ip())
+}
+
+fn feed_stream(queued: Receiver<(Vec<i16>, u32)>, buffer: &(Mutex<StreamBuffer>, Condvar)) {
+    let (lock, ready) = buffer;
+    for (pcm, sample_rate) in queued {
+        let Ok(mut stream) = lock.lock() else {
+            return;
+        };
+        if stream.sample_rate == 0 {
+            stream.sample_rate = sample_rate;
+        }
+        stream.pending.extend(pcm);
+        let limit = stream.sample_rate as usize * MAX_PENDING_SECS;
+        let excess = stream.pending.len().saturating_sub(limit);
+        stream.pending.drain(..excess);
+        ready.notify_all();
+    }
+    // The sink was dropped: the job is over.
+    if let Ok(mut stream) = lock.lock() {
+        stream.ended = true;
+        ready.notify_all();
+    }
+}
+
+fn serve_stream(listener: TcpListener, path: &str, buffer: &Arc<(Mutex<StreamBuffer>, Condvar)>) {
+    let mut ended_at: Option<Instant> = None;
+    let mut connections = Vec::new();
+    loop {
+        match listener.accept() {
+            Ok((connection, _)) => {
+                let path = path.to_string();
+                let buffer = buffer.clone();
+                connections.push(std::thread::spawn(move || {
+                    let _ = handle_connection(connection, &path, &buffer);
+                }));
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(Duration::from_millis(ACCEPT_POLL_MS));
+            }
+            Err(_) => return,
+        }
+        connections.retain(|connection| !connection.is_finished());
+        let ended = buffer.0.lock().map_or(true, |stream| stream.ended);
+        if ended && connections.is_empty() {
+            // Served to the end, or give a slow renderer a moment to arrive.
+            let since = *ended_at.get_or_insert_with(Instant::now);
+            let drained = buffer.0.lock().map_or(true, |stream| stream.pending.is_empty());
+            if drained || since.elapsed() >= Duration::from_secs(LATE_CONNECT_GRACE_SECS) {
+                return;
+            }
+        }
+    }
+}
+
+fn handle_connection(
+    mut connection: TcpStream,
+    path: &str,
+    buffer: &(Mutex<StreamBuffer>, Condvar),
+) -> std::io::Result<()> {
+    connection.set_nonblocking(false)?;
+    connection.set_read_timeout(Some(Duration::from_secs(5)))?;
+    let mut head = Vec::new();
+    let mut byte = [0u8; 1];
+    while !head.ends_with(b"\r\n\r\n") && head.len() < MAX_REQUEST_HEAD_BYTES {
+        if connection.read(&mut byte)? == 0 {
+            return Ok(());
+        }
+        head.push(byte[0]);
+    }
+    let head = String::from_utf8_lossy(&head);
+    let mut request_line = head.lines().next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default();
+    let target = request_line.next().unwrap_or_default();
+    if target != path || !matches!(method, "GET" | "HEAD") {
+        connection.write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")?;
+        return Ok(());
+    }
+    connection.write_all(
+        b"HTTP/1.1 200 OK\r\nContent-Type: audio/wav\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n",
+    )?;
+    if method == "HEAD" {
+        return Ok(());
+    }
+
+    let (lock, ready) = buffer;
+    let generation = {
+        let mut stream = lock.lock().map_err(|_| std::io::ErrorKind::Other)?;
+        stream.generation += 1;
+        stream.generation
+    };
+    let mut header_sent = false;
+    loop {
+        let (samples, sample_rate) = {
+            let mut stream = lock.lock().map_err(|_| std::io::ErrorKind::Other)?;
+            while stream.pending.is_empty() && !stream.ended && stream.generation == generation {
+                stream = ready.wait(stream).map_err(|_| std::io::ErrorKind::Other)?;
+            }
+            if stream.generation != generation || (stream.pending.is_empty() && stream.ended) {
+                return Ok(());
+            }
+            (stream.pending.drain(..).collect::<Vec<_>>(), stream.sample_rate)
+        };
+        if !header_sent {
+            connection.write_all(&wav_stream_header(sample_rate))?;
+            header_sent = true;
+        }
+        let mut bytes = Vec::with_capacity(samples.len() * 2);
+        for sample in samples {
+            bytes.extend_from_slice(&sample.to_le_bytes());
+        }
+        connection.write_all(&bytes)?;
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::VecDeque;
+
+use serde_json::Value;
+
+/// Chunks kept per job for replay; a gap older than this cannot be filled.
+const MAX_JOURNALED_CHUNKS: usize = 32;
+/// Jobs whose chunks are kept, most recent first; older ones are dropped.
+const MAX_JOURNALED_JOBS: usize = 2;
+
+struct JobJournal {
+    job_id: String,
+    /// Bumped on every replay, so chunks still in flight from before it can be
+    /// told apart from the replayed ones.
+    epoch: u64,
+    next_seq: u64,
+    /// `AUDIO_CHUNK` events as sent, oldest first.
+    chunks: VecDeque<(u64, Value)>,
+}
+
+/// Numbers the `AUDIO_CHUNK` events sent to the webview and keeps the latest
+/// ones, so a player that sees a gap in `chunk_seq` (events dropped or
+/// reordered, e.g. across a webview reload) can ask for them again instead of
+/// playing garbled audio.
+#[derive(Default)]
+pub struct ChunkJournal {
+    jobs: VecDeque<JobJournal>,
+}
+
+impl ChunkJournal {
+    /// Adds `chunk_seq` and `stream_epoch` to `event` and keeps a copy.
+    pub fn stamp(&mut self, job_id: &str, event: &mut Value) {
+        let journal = self.journal_mut(job_id);
+        let seq = journal.next_seq;
+        journal.next_seq += 1;
+        if let Some(fields) = event.as_object_mut() {
+            fields.insert("chunk_seq".to_string(), Value::from(seq));
+            fields.insert("stream_epoch".to_string(), Value::from(journal.epoch));
+        }
+        if journal.chunks.len() >= MAX_JOURNALED_CHUNKS {
+            journal.chunks.pop_front();
+        }
+        journal.chunks.push_back((seq, event.clone()));
+    }
+
+    /// The job's chunks from `from_seq` on, restamped with a new epoch. Starts
+    /// at the oldest kept chunk when `from_seq` is older; `None` when the job
+    /// is unknown or nothing from `from_seq` on was sent yet.
+    pub fn replay(&mut self, job_id: &str, from_seq: u64) -> Option<Vec<Value>> {
+        let journal = self.jobs.iter_mut().find(|journal| journal.job_id == job_id)?;
+        if from_seq >= journal.next_seq {
+            return None;
+        }
+        journal.epoch += 1;
+        let epoch = journal.epoch;
+        let replayed = journal
+            .chunks
+            .iter_mut()
+            .filter(|(seq, _)| *seq >= from_seq)
+            .map(|(_, event)| {
+                if let Some(fields) = event.as_object_mut() {
+                    fields.insert("stream_epoch".to_string(), Value::from(epoch));
+                }
+                event.clone()
+            })
+            .collect();
+        Some(replayed)
+    }
+
+    fn journal_mut(&mut self, job_id: &str) -> &mut JobJournal {
+        match self.jobs.iter().position(|journal| journal.job_id == job_id) {
+            Some(index) => &mut self.jobs[index],
+            None => {
+                if self.jobs.len() >= MAX_JOURNALED_JOBS {
+                    self.jobs.pop_back();
+                }
+                self.jobs.push_front(JobJournal {
+                    job_id: job_id.to_string(),
+                    epoch: 0,
+                    next_seq: 0,
+                    chunks: VecDeque::new(),
+                });
+                &mut self.jobs[0]
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt;
+
+use serde::Serialize;
+
+/// Machine-readable reason a command failed, so the UI and automation
+/// clients can offer a fix instead of showing the text.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// The engine is not running and could not be started.
+    EngineNotReady,
+    /// The engine was reached but failed or rejected the request.
+    EngineRequestFailed,
+    /// The model is not downloaded or not loaded.
+    ModelMissing,
+    VoiceNotFound,
+    /// The accelerator is taken by another action or another app.
+    HotkeyConflict,
+    /// A job, schedule, phrase or other item named by id does not exist.
+    NotFound,
+    /// Another job or download is in progress.
+    Busy,
+    InvalidArgument,
+    /// Not available in this build variant or on this platform.
+    Unsupported,
+    Internal,
+}
+
+impl ErrorCode {
+    /// Whether the same call can succeed after the user acts, e.g. by
+    /// starting the engine, downloading the model or changing the input.
+    pub fn recoverable(self) -> bool {
+        !matches!(self, Self::Unsupported | Self::Internal)
+    }
+
+    /// Code for an engine error response, by the engine's own `code`.
+    pub fn from_engine(code: &str) -> Self {
+        match code {
+            "VOICE_NOT_FOUND" => Self::VoiceNotFound,
+            "MODEL_NOT_READY" => Self::ModelMissing,
+            "JOB_NOT_FOUND" | "CLIENT_NOT_FOUND" => Self::NotFound,
+            "JOB_IN_PROGRESS" | "PREFETCH_BUSY" => Self::Busy,
+            "EMPTY_TEXT" | "INVALID_AUDIO" | "INVALID_REQUEST" => Self::InvalidArgument,
+            _ => Self::EngineRequestFailed,
+        }
+    }
+}
+
+/// Error carrying a code through `anyhow` chains. Commands turn it into a
+/// `CommandError` with that code, wherever it sits in the chain.
+#[derive(Debug)]
+pub struct CodedError {
+    pub code: ErrorCode,
+    message: String,
+    detail: Option<String>,
+}
+
+impl CodedError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            detail: None,
+        }
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+impl fmt::Display for CodedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CodedError {}
+
+/// Error returned by every Tauri command, serialized as
+/// `{ code, message, recoverable, detail }`.
+#[derive(Debug, Serialize)]
+pub struct CommandError {
+    pub code: ErrorCode,
+    pub message: String,
+    pub recoverable: bool,
+    /// More context where there is some, e.g. the engine's error response.
+    pub detail: Option<String>,
+}
+
+impl CommandError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            recoverable: code.recoverable(),
+            detail: None,
+        }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::InvalidArgument, message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::NotFound, message)
+    }
+
+    pub fn unsupported(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Unsupported, message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Internal, message)
+    }
+
+    pub fn lock_poisoned() -> Self {
+        Self::new(ErrorCode::Internal, "State lock poisoned")
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+/// The message is the whole chain, as before codes were added. Errors
+/// without a `CodedError` in their chain are `INTERNAL`.
+impl From<anyhow::Error> for CommandError {
+    fn from(err: anyhow::Error) -> Self {
+        let message = format!("{err:#}");
+        match err.downcast_ref::<CodedError>() {
+            Some(coded) => Self {
+                detail: coded.detail.clone(),
+                ..Self::new(coded.code, message)
+            },
+            None => Self::new(ErrorCode::Internal, message),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use anyhow::{anyhow, Result};
+
+/// Longest `Name:` prefix treated as a speaker label, in characters.
+const MAX_SPEAKER_LABEL_CHARS: usize = 32;
+/// More words than this before a colon reads as a sentence, not a name.
+const MAX_SPEAKER_LABEL_WORDS: usize = 3;
+
+/// Reads quoted speech and `Name:` lines with a second voice.
+#[derive(Clone, Default)]
+pub struct DialogueSettings {
+    pub enabled: bool,
+    /// Voice for narration; `None` uses the selected voice.
+    pub narration_voice_id: Option<String>,
+    pub dialogue_voice_id: Option<String>,
+}
+
+impl DialogueSettings {
+    pub fn validate(&self) -> Result<()> {
+        if self.enabled && self.dialogue_voice_id.is_none() {
+            return Err(anyhow!("Dialogue mode needs a dialogue voice"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SpeakerRole {
+    Narration,
+    Dialogue,
+}
+
+pub struct DialogueSegment {
+    pub text: String,
+    pub role: SpeakerRole,
+}
+
+/// Splits `text` into alternating narration and dialogue segments.
+///
+/// Dialogue is text inside straight or curly double quotes or guillemets,
+/// and whole lines that start with a short `Name:` label (the label itself is
+/// not spoken). A quote left open at the end of a paragraph closes there,
+/// matching the convention for speech that runs over several paragraphs.
+/// Segments without any letters or digits are dropped.
+pub fn split_dialogue(text: &str) -> Vec<DialogueSegment> {
+    let normalized = text.replace("\r\n", "\n").replace('\r', "\n");
+    let mut segments: Vec<DialogueSegment> = Vec::new();
+    let mut open_quote: Option<char> = None;
+    for line in normalized.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            open_quote = None;
+            continue;
+        }
+        if open_quote.is_none() {
+            if let Some(speech) = strip_speaker_label(line) {
+                push_segment(&mut segments, speech, SpeakerRole::Dialogue);
+                continue;
+            }
+        }
+        open_quote = split_quotes(line, open_quote, &mut segments);
+    }
+    segments
+}
+
+/// Splits one line on quotes, starting inside a quote when `closing` is set.
+/// Returns the quote still open at the end of the line.
+fn split_quotes(line: &str, mut closing: Option<char>, segments: &mut Vec<DialogueSegment>) -> Option<char> {
+    let mut current = String::new();
+    for ch in line.chars() {
+        match closing {
+            None => {
+                if let Some(close) = closing_quote(ch) {
+                    push_segment(segments, &std::mem::take(&mut current), SpeakerRole::Narration);
+                    closing = Some(close);
+                } else {
+                    current.push(ch);
+                }
+            }
+            Some(close) => {
+                if ch == close {
+                    push_segment(segments, &std::mem::take(&mut current), SpeakerRole::Dialogue);
+                    closing = None;
+                } else {
+                    current.push(ch);
+                }
+            }
+        }
+    }
+    let role = if closing.is_some() {
+        SpeakerRole::Dialogue
+    } else {
+        SpeakerRole::Narration
+    };
+    push_segment(segments, &current, role);
+    closing
+}
+
+fn closing_quote(ch: char) -> Option<char> {
+    match ch {
+        '"' => Some('"'),
+        '\u{201C}' => Some('\u{201D}'),
+        '\u{00AB}' => Some('\u{00BB}'),
+        _ => None,
+    }
+}
+
+/// The rest of `line` when it starts with a speaker label such as `Anna:`.
+fn strip_speaker_label(line: &str) -> Option<&str> {
+    let (label, speech) = line.trim_start().split_once(':')?;
+    let label = label.trim();
+    let speech = speech.trim();
+    let looks_like_name = !label.is_empty()
+        && label.chars().count() <= MAX_SPEAKER_LABEL_CHARS
+        && label.split_whitespace().count() <= MAX_SPEAKER_LABEL_WORDS
+        && label.chars().next().is_some_and(char::is_uppercase)
+        && label
+            .chars()
+            .all(|ch| ch.is_alphabetic() || ch == ' ' || ch == '.' || ch == '-' || ch == '\'');
+    (looks_like_name && !speech.is_empty()).then_some(speech)
+}
+
+/// Appends `text`, merging it into the previous segment when the role matches.
+fn push_segment(segments: &mut Vec<DialogueSegment>, text: &str, role: SpeakerRole) {
+    let text = text.trim();
+    if !text.chars().any(char::is_alphanumeric) {
+        return;
+    }
+    match segments.last_mut() {
+        Some(last) if last.role == role => {
+            last.text.push(' ');
+            last.text.push_str(text);
+        }
+        _ => segments.push(DialogueSegment {
+            text: text.to_string(),
+            role,
+        }),
+    }
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use reqwest::{Client, Method};
+use serde::{Deserialize, Serialize};
+
+const CONNECT_TIMEOUT_RANGE_MS: (u64, u64) = (100, 60_000);
+const REQUEST_TIMEOUT_RANGE_MS: (u64, u64) = (1_000, 600_000);
+const MAX_RETRIES_LIMIT: u32 = 5;
+const RETRY_BASE_DELAY_MS: u64 = 250;
+/// How long an idle connection is kept for reuse; shorter than the sidecar's
+/// keep-alive (`ENGINE_KEEP_ALIVE_SECS` in `main.py`) so a connection is never
+/// reused just as the engine closes it.
+const POOL_IDLE_TIMEOUT_SECS: u64 = 60;
+const TCP_KEEPALIVE_SECS: u64 = 30;
+
+/// Timeouts and retries for requests to the engine sidecar, so a hung engine
+/// fails a command instead of blocking it forever.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct EngineHttpSettings {
+    pub connect_timeout_ms: u64,
+    /// Limit on a whole request, response included. Model downloads and
+    /// activation can take minutes and are exempt.
+    pub request_timeout_ms: u64,
+    /// Extra attempts after a failure that may be transient.
+    pub max_retries: u32,
+}
+
+impl Default for EngineHttpSettings {
+    fn default() -> Self {
+        Self {
+            connect_timeout_ms: 2_000,
+            request_timeout_ms: 60_000,
+            max_retries: 2,
+        }
+    }
+}
+
+impl EngineHttpSettings {
+    pub fn validate(&self) -> Result<()> {
+        let (min, max) = CONNECT_TIMEOUT_RANGE_MS;
+        if !(min..=max).contains(&self.connect_timeout_ms) {
+            return Err(anyhow!("connect_timeout_ms must be between {min} and {max}"));
+        }
+        let (min, max) = REQUEST_TIMEOUT_RANGE_MS;
+        if !(min..=max).contains(&self.request_timeout_ms) {
+            return Err(anyhow!("request_timeout_ms must be between {min} and {max}"));
+        }
+        if self.max_retries > MAX_RETRIES_LIMIT {
+            return Err(anyhow!("max_retries must be at most {MAX_RETRIES_LIMIT}"));
+        }
+        Ok(())
+    }
+
+    pub fn connect_timeout(&self) -> Duration {
+        Duration::from_millis(self.connect_timeout_ms)
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+
+    /// A client meant to be shared: it keeps connections to the engine open
+    /// between requests. The request timeout is set per request, since model
+    /// downloads are exempt.
+    pub fn build_client(&self) -> Result<Client> {
+        Client::builder()
+            .connect_timeout(self.connect_timeout())
+            .pool_idle_timeout(Duration::from_secs(POOL_IDLE_TIMEOUT_SECS))
+            .tcp_keepalive(Duration::from_secs(TCP_KEEPALIVE_SECS))
+            .build()
+            .context("Failed to create engine HTTP client")
+    }
+}
+
+/// Whether a failed attempt may be repeated. A request that never reached
+/// the engine always can; a timed-out one only if repeating it is harmless,
+/// since the engine may still act on it (a second `/speak` would read twice).
+pub fn is_retryable(err: &reqwest::Error, method: &Method) -> bool {
+    err.is_connect() || (err.is_timeout() && matches!(*method, Method::GET | Method::HEAD))
+}
+
+/// Wait before retry `attempt` (1-based), doubling each time.
+pub fn retry_delay(attempt: u32) -> Duration {
+    Duration::from_millis(RETRY_BASE_DELAY_MS << (attempt.saturating_sub(1)).min(4))
+}
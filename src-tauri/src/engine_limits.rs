@@ -0,0 +1,219 @@
+#[cfg(feature = "build-full")]
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "build-full")]
+use std::process::{Child, Command};
+
+#[cfg(feature = "build-full")]
+const MIN_ENGINE_MEMORY_MB: u64 = 512;
+#[cfg(feature = "build-full")]
+const MAX_AFFINITY_CPUS: u32 = 64;
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnginePriority {
+    Idle,
+    BelowNormal,
+    Normal,
+}
+
+/// Optional constraints for the Python engine process.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct EngineResourceLimits {
+    /// Logical CPU indices the engine may run on. Windows and Linux only.
+    pub cpu_affinity: Option<Vec<u32>>,
+    pub priority: Option<EnginePriority>,
+    /// Memory cap in MiB. Windows enforces it with a job object; Unix caps
+    /// the address space (RLIMIT_AS), which CUDA runtimes reserve generously,
+    /// so leave it unset when running Qwen on GPU.
+    pub max_memory_mb: Option<u64>,
+}
+
+#[cfg(feature = "build-full")]
+impl EngineResourceLimits {
+    pub fn validate(&self) -> Result<()> {
+        if let Some(cpus) = self.cpu_affinity.as_ref() {
+            if cpus.is_empty() {
+                return Err(anyhow!("cpu_affinity must list at least one CPU"));
+            }
+            let available = std::thread::available_parallelism()
+                .map(|n| n.get() as u32)
+                .unwrap_or(1)
+                .min(MAX_AFFINITY_CPUS);
+            if let Some(cpu) = cpus.iter().find(|cpu| **cpu >= available) {
+                return Err(anyhow!("CPU {cpu} is out of range (this machine has {available} usable CPUs)"));
+            }
+        }
+        if let Some(mb) = self.max_memory_mb {
+            if mb < MIN_ENGINE_MEMORY_MB {
+                return Err(anyhow!("max_memory_mb must be at least {MIN_ENGINE_MEMORY_MB}"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Applies the limits that must be set inside the child before exec (Unix).
+/// Each limit is best-effort: a failure leaves that knob at its default
+/// rather than preventing the engine from starting.
+#[cfg(feature = "build-full")]
+pub fn apply_before_spawn(command: &mut Command, limits: &EngineResourceLimits) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+
+        let nice = limits.priority.and_then(|priority| match priority {
+            EnginePriority::Idle => Some(19),
+            EnginePriority::BelowNormal => Some(10),
+            // Leave niceness inherited; lowering it needs privileges.
+            EnginePriority::Normal => None,
+        });
+        let memory_bytes = limits.max_memory_mb.map(|mb| mb.saturating_mul(1024 * 1024));
+        #[cfg(target_os = "linux")]
+        let affinity = limits.cpu_affinity.clone();
+
+        // Only async-signal-safe libc calls run between fork and exec.
+        unsafe {
+            command.pre_exec(move || {
+                if let Some(nice) = nice {
+                    let _ = libc::setpriority(libc::PRIO_PROCESS, 0, nice);
+                }
+                if let Some(bytes) = memory_bytes {
+                    let limit = libc::rlimit {
+                        rlim_cur: bytes as libc::rlim_t,
+                        rlim_max: bytes as libc::rlim_t,
+                    };
+                    let _ = libc::setrlimit(libc::RLIMIT_AS, &limit);
+                }
+                #[cfg(target_os = "linux")]
+                if let Some(cpus) = affinity.as_ref() {
+                    let mut set: libc::cpu_set_t = std::mem::zeroed();
+                    for cpu in cpus {
+                        libc::CPU_SET(*cpu as usize, &mut set);
+                    }
+                    let _ = libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set);
+                }
+                Ok(())
+            });
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = (command, limits);
+    }
+}
+
+/// The job object holding the engine (Windows). Dropping it closes the
+/// handle, and KILL_ON_JOB_CLOSE takes the engine with it; keep it for as
+/// long as the child it was assigned to. Holds nothing on other platforms.
+#[cfg(feature = "build-full")]
+pub struct EngineJob {
+    #[cfg(target_os = "windows")]
+    handle: windows_sys::Win32::Foundation::HANDLE,
+}
+
+// The handle is only closed, once, from Drop.
+#[cfg(all(feature = "build-full", target_os = "windows"))]
+unsafe impl Send for EngineJob {}
+
+#[cfg(all(feature = "build-full", target_os = "windows"))]
+impl Drop for EngineJob {
+    fn drop(&mut self) {
+        unsafe {
+            windows_sys::Win32::Foundation::CloseHandle(self.handle);
+        }
+    }
+}
+
+/// Puts the engine into a job object (Windows) that kills it when the
+/// returned handle is dropped or the app exits, however it exits. The memory
+/// cap is enforced by the same job.
+#[cfg(feature = "build-full")]
+pub fn assign_engine_job(child: &Child, limits: &EngineResourceLimits) -> Result<EngineJob> {
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::io::AsRawHandle;
+        use windows_sys::Win32::System::JobObjects::{
+            AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation,
+            SetInformationJobObject, JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+            JOB_OBJECT_LIMIT_PROCESS_MEMORY,
+        };
+
+        let handle = unsafe { CreateJobObjectW(std::ptr::null(), std::ptr::null()) };
+        if handle.is_null() {
+            return Err(anyhow!("CreateJobObjectW failed: {}", std::io::Error::last_os_error()));
+        }
+        // Owned from here, so every early return closes it.
+        let job = EngineJob { handle };
+        let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = unsafe { std::mem::zeroed() };
+        info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+        if let Some(mb) = limits.max_memory_mb {
+            info.BasicLimitInformation.LimitFlags |= JOB_OBJECT_LIMIT_PROCESS_MEMORY;
+            info.ProcessMemoryLimit = mb.saturating_mul(1024 * 1024) as usize;
+        }
+        let configured = unsafe {
+            SetInformationJobObject(
+                job.handle,
+                JobObjectExtendedLimitInformation,
+                &info as *const JOBOBJECT_EXTENDED_LIMIT_INFORMATION as *const std::ffi::c_void,
+                std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+            )
+        };
+        if configured == 0 {
+            return Err(anyhow!("SetInformationJobObject failed: {}", std::io::Error::last_os_error()));
+        }
+        if unsafe { AssignProcessToJobObject(job.handle, child.as_raw_handle()) } == 0 {
+            return Err(anyhow!("AssignProcessToJobObject failed: {}", std::io::Error::last_os_error()));
+        }
+        Ok(job)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (child, limits);
+        Ok(EngineJob {})
+    }
+}
+
+/// Applies the priority and affinity limits that are set on a running
+/// process (Windows).
+#[cfg(feature = "build-full")]
+pub fn apply_after_spawn(child: &Child, limits: &EngineResourceLimits) -> Result<()> {
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::io::AsRawHandle;
+        use windows_sys::Win32::System::Threading::{
+            SetPriorityClass, SetProcessAffinityMask, BELOW_NORMAL_PRIORITY_CLASS, IDLE_PRIORITY_CLASS,
+            NORMAL_PRIORITY_CLASS,
+        };
+
+        let process = child.as_raw_handle();
+
+        if let Some(priority) = limits.priority {
+            let class = match priority {
+                EnginePriority::Idle => IDLE_PRIORITY_CLASS,
+                EnginePriority::BelowNormal => BELOW_NORMAL_PRIORITY_CLASS,
+                EnginePriority::Normal => NORMAL_PRIORITY_CLASS,
+            };
+            if unsafe { SetPriorityClass(process, class) } == 0 {
+                return Err(anyhow!("SetPriorityClass failed: {}", std::io::Error::last_os_error()));
+            }
+        }
+
+        if let Some(cpus) = limits.cpu_affinity.as_ref() {
+            let mask = cpus.iter().fold(0usize, |mask, cpu| mask | (1usize << cpu));
+            if unsafe { SetProcessAffinityMask(process, mask) } == 0 {
+                return Err(anyhow!("SetProcessAffinityMask failed: {}", std::io::Error::last_os_error()));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (child, limits);
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(feature = "build-full")]
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "build-full")]
+const MAX_ENGINE_PROFILES: usize = 8;
+#[cfg(feature = "build-full")]
+const MAX_PROFILE_NAME_CHARS: usize = 64;
+/// Ports below this need privileges on Unix.
+#[cfg(feature = "build-full")]
+const MIN_PROFILE_PORT: u16 = 1024;
+
+/// A named engine launch configuration, such as a battery-friendly Kyutai on
+/// the CPU and a high-quality Qwen on an external GPU. Unset fields keep what
+/// the engine overrides or built-in defaults say.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct EngineProfile {
+    pub name: String,
+    /// Torch device map for Qwen, e.g. `cuda:1` for a second GPU or `cpu`.
+    #[serde(default)]
+    pub device: Option<String>,
+    /// Synthesis backend the engine loads: `auto`, `qwen`, `kyutai` or `mock`.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Fixed localhost port; a free one is picked when unset.
+    #[serde(default)]
+    pub port: Option<u16>,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct EngineProfiles {
+    pub profiles: Vec<EngineProfile>,
+    /// Name of the profile the engine launches with; `None` for no profile.
+    #[serde(default)]
+    pub active: Option<String>,
+}
+
+#[cfg(feature = "build-full")]
+impl EngineProfile {
+    /// Trims the text fields and drops empty ones.
+    pub fn normalized(self) -> Self {
+        let trim = |value: Option<String>| value.map(|raw| raw.trim().to_string()).filter(|value| !value.is_empty());
+        Self {
+            name: self.name.trim().to_string(),
+            device: trim(self.device),
+            model: trim(self.model).map(|model| model.to_ascii_lowercase()),
+            port: self.port,
+        }
+    }
+
+    fn validate(&self, synth_backends: &[&str]) -> Result<()> {
+        if self.name.is_empty() {
+            return Err(anyhow!("Engine profile names must not be empty"));
+        }
+        if self.name.chars().count() > MAX_PROFILE_NAME_CHARS {
+            return Err(anyhow!(
+                "Engine profile name {:?} is longer than {MAX_PROFILE_NAME_CHARS} characters",
+                self.name
+            ));
+        }
+        if let Some(device) = self.device.as_deref() {
+            let valid = device
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, ':' | ',' | '_' | '-'));
+            if !valid {
+                return Err(anyhow!("Device {device:?} of profile {:?} is not a device name such as cuda:0 or cpu", self.name));
+            }
+        }
+        if let Some(model) = self.model.as_deref() {
+            if !synth_backends.contains(&model) {
+                return Err(anyhow!(
+                    "Model of profile {:?} must be one of: {}",
+                    self.name,
+                    synth_backends.join(", ")
+                ));
+            }
+        }
+        if let Some(port) = self.port {
+            if port < MIN_PROFILE_PORT {
+                return Err(anyhow!("Port of profile {:?} must be at least {MIN_PROFILE_PORT}", self.name));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "build-full")]
+impl EngineProfiles {
+    pub fn validate(&self, synth_backends: &[&str]) -> Result<()> {
+        if self.profiles.len() > MAX_ENGINE_PROFILES {
+            return Err(anyhow!("At most {MAX_ENGINE_PROFILES} engine profiles can be defined"));
+        }
+        for (index, profile) in self.profiles.iter().enumerate() {
+            profile.validate(synth_backends)?;
+            if self.profiles[..index].iter().any(|other| other.name == profile.name) {
+                return Err(anyhow!("Engine profile {:?} is defined twice", profile.name));
+            }
+        }
+        if let Some(active) = self.active.as_deref() {
+            if self.find(active).is_none() {
+                return Err(anyhow!("No engine profile is named {active:?}"));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn find(&self, name: &str) -> Option<&EngineProfile> {
+        self.profiles.iter().find(|profile| profile.name == name)
+    }
+
+    pub fn active_profile(&self) -> Option<&EngineProfile> {
+        self.active.as_deref().and_then(|name| self.find(name))
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+const EVENT_PREFIX: &str = "voicereader:";
+/// The window that schedules playback.
+const PLAYER_WINDOW_LABEL: &str = "main";
+/// Carries every AUDIO_CHUNK, so by default only the player window gets it.
+const STREAM_EVENT: &str = "voicereader:ws-event";
+
+/// Per-window event subscriptions. An event nobody subscribed to is broadcast
+/// to every window as before; once a window subscribes to it, it only goes to
+/// subscribed windows.
+pub struct EventRoutes {
+    subscribers: Mutex<HashMap<String, HashSet<String>>>,
+}
+
+impl Default for EventRoutes {
+    fn default() -> Self {
+        let mut subscribers = HashMap::new();
+        subscribers.insert(
+            STREAM_EVENT.to_string(),
+            HashSet::from([PLAYER_WINDOW_LABEL.to_string()]),
+        );
+        Self {
+            subscribers: Mutex::new(subscribers),
+        }
+    }
+}
+
+impl EventRoutes {
+    /// Replaces the subscriptions of `window_label` with `kinds` and returns
+    /// the full event names. Kinds may omit the `voicereader:` prefix.
+    pub fn subscribe(&self, window_label: &str, kinds: &[String]) -> Result<Vec<String>> {
+        let mut subscribers = self
+            .subscribers
+            .lock()
+            .map_err(|_| anyhow!("Event routes lock poisoned"))?;
+        for labels in subscribers.values_mut() {
+            labels.remove(window_label);
+        }
+        subscribers.retain(|_, labels| !labels.is_empty());
+
+        let events: Vec<String> = kinds
+            .iter()
+            .map(|kind| kind.trim())
+            .filter(|kind| !kind.is_empty())
+            .map(|kind| {
+                if kind.starts_with(EVENT_PREFIX) {
+                    kind.to_string()
+                } else {
+                    format!("{EVENT_PREFIX}{kind}")
+                }
+            })
+            .collect();
+        for event in &events {
+            subscribers
+                .entry(event.clone())
+                .or_default()
+                .insert(window_label.to_string());
+        }
+        Ok(events)
+    }
+
+    fn targets(&self, event: &str) -> Option<Vec<String>> {
+        let subscribers = self.subscribers.lock().ok()?;
+        subscribers
+            .get(event)
+            .map(|labels| labels.iter().cloned().collect())
+    }
+}
+
+/// `emit_all` that honours the managed [`EventRoutes`].
+pub trait EmitRouted {
+    fn emit_routed<S: Serialize + Clone>(&self, event: &str, payload: S) -> tauri::Result<()>;
+}
+
+impl EmitRouted for AppHandle {
+    fn emit_routed<S: Serialize + Clone>(&self, event: &str, payload: S) -> tauri::Result<()> {
+        let targets = self
+            .try_state::<EventRoutes>()
+            .and_then(|routes| routes.targets(event));
+        let Some(labels) = targets else {
+            return self.emit_all(event, payload);
+        };
+        for label in labels {
+            if let Some(window) = self.get_window(&label) {
+                window.emit(event, payload.clone())?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::readability::{decode_entities, visible_text};
+
+/// Items kept per feed; the oldest are dropped first.
+const MAX_ITEMS_PER_FEED: usize = 200;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FeedSubscription {
+    pub id: String,
+    pub url: String,
+    pub title: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FeedItem {
+    /// Feed id plus a hash of the entry's guid, stable across refreshes.
+    pub id: String,
+    pub feed_id: String,
+    pub title: String,
+    /// Article URL; `None` for entries that carry their whole text.
+    pub link: Option<String>,
+    /// As the feed wrote it (RFC 822 for RSS, RFC 3339 for Atom).
+    pub published: Option<String>,
+    /// Plain text of the entry's description or content.
+    pub summary: String,
+    #[serde(default)]
+    pub read: bool,
+}
+
+/// Subscriptions and their items with read state, newest items first.
+#[derive(Default, Serialize, Deserialize)]
+pub struct FeedStore {
+    feeds: Vec<FeedSubscription>,
+    items: Vec<FeedItem>,
+}
+
+impl FeedStore {
+    /// A missing file is an empty store; a corrupt one is an error so a
+    /// later save does not drop the subscriptions.
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(body) => serde_json::from_str(&body).with_context(|| format!("Failed to parse {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(anyhow!("Failed to read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create feeds directory {}", parent.display()))?;
+        }
+        let serialized = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, serialized).with_context(|| format!("Failed to write feeds file {}", path.display()))
+    }
+
+    pub fn feeds(&self) -> &[FeedSubscription] {
+        &self.feeds
+    }
+
+    pub fn items(&self) -> &[FeedItem] {
+        &self.items
+    }
+
+    pub fn item_mut(&mut self, id: &str) -> Option<&mut FeedItem> {
+        self.items.iter_mut().find(|item| item.id == id)
+    }
+
+    pub fn feed_by_url(&self, url: &str) -> Option<&FeedSubscription> {
+        self.feeds.iter().find(|feed| feed.url == url)
+    }
+
+    pub fn add_feed(&mut self, feed: FeedSubscription) {
+        self.feeds.push(feed);
+    }
+
+    /// Removes a subscription with its items.
+    pub fn remove_feed(&mut self, id: &str) -> Option<FeedSubscription> {
+        let index = self.feeds.iter().position(|feed| feed.id == id)?;
+        self.items.retain(|item| item.feed_id != id);
+        Some(self.feeds.remove(index))
+    }
+
+    /// Adds the entries of `parsed` not seen before as unread items of
+    /// `feed_id`, and refreshes the feed title. Returns how many were new.
+    pub fn merge(&mut self, feed_id: &str, parsed: ParsedFeed) -> usize {
+        if let Some(feed) = self.feeds.iter_mut().find(|feed| feed.id == feed_id) {
+            if !parsed.title.is_empty() {
+                feed.title = parsed.title;
+            }
+        }
+        let mut added = 0;
+        // Feeds list newest first; insert in reverse to keep that order on top.
+        for entry in parsed.entries.into_iter().rev() {
+            let id = format!("{feed_id}-{:016x}", fnv1a(&entry.guid));
+            if self.items.iter().any(|item| item.id == id) {
+                continue;
+            }
+            self.items.insert(
+                0,
+                FeedItem {
+                    id,
+                    feed_id: feed_id.to_string(),
+                    title: entry.title,
+                    link: entry.link,
+                    published: entry.published,
+                    summary: entry.summary,
+                    read: false,
+                },
+            );
+            added += 1;
+        }
+
+        let mut kept = 0;
+        self.items.retain(|item| {
+            if item.feed_id != feed_id {
+                return true;
+            }
+            kept += 1;
+            kept <= MAX_ITEMS_PER_FEED
+        });
+        added
+    }
+}
+
+pub struct ParsedEntry {
+    /// `guid`/`id`, else the link, else the title: whatever identifies the entry.
+    pub guid: String,
+    pub title: String,
+    pub link: Option<String>,
+    pub published: Option<String>,
+    pub summary: String,
+}
+
+pub struct ParsedFeed {
+    pub title: String,
+    pub entries: Vec<ParsedEntry>,
+}
+
+/// Reads an RSS 2.0 (`<item>`) or Atom (`<entry>`) document. Only the
+/// fields needed to list and read entries are picked out; namespaced
+/// extensions are ignored.
+pub fn parse_feed(xml: &str) -> Result<ParsedFeed> {
+    let (entry_tag, is_atom) = if find_element(xml, "feed", 0).is_some() && find_element(xml, "entry", 0).is_some() {
+        ("entry", true)
+    } else {
+        ("item", false)
+    };
+    if !is_atom && find_element(xml, "rss", 0).is_none() && find_element(xml, "channel", 0).is_none() {
+        return Err(anyhow!("Not an RSS or Atom feed"));
+    }
+
+    let header_end = find_element(xml, entry_tag, 0).map_or(xml.len(), |(start, ..)| start);
+    let title = element_text(&xml[..header_end], "title").unwrap_or_default();
+
+    let mut entries = Vec::new();
+    let mut offset = 0;
+    while let Some((_, body_start, body_end)) = find_element(xml, entry_tag, offset) {
+        let body = &xml[body_start..body_end];
+        offset = body_end;
+        let link = if is_atom { atom_link(body) } else { element_text(body, "link") }.filter(|link| !link.is_empty());
+        let title = element_text(body, "title").unwrap_or_default();
+        let summary_markup = ["content", "content:encoded", "description", "summary"]
+            .iter()
+            .find_map(|tag| element_text(body, tag))
+            .unwrap_or_default();
+        let guid = element_text(body, if is_atom { "id" } else { "guid" })
+            .filter(|guid| !guid.is_empty())
+            .or_else(|| link.clone())
+            .unwrap_or_else(|| title.clone());
+        let published = ["pubDate", "published", "updated", "dc:date"]
+            .iter()
+            .find_map(|tag| element_text(body, tag));
+        entries.push(ParsedEntry {
+            guid,
+            title,
+            link,
+            published,
+            summary: visible_text(&summary_markup),
+        });
+    }
+    Ok(ParsedFeed { title, entries })
+}
+
+/// Finds the next `<tag ...>...</tag>` at or after `from`. Returns the
+/// start of the opening tag and the bounds of the body; a self-closing
+/// element has an empty body.
+pub fn find_element(xml: &str, tag: &str, from: usize) -> Option<(usize, usize, usize)> {
+    let open = format!("<{tag}");
+    let mut search = from;
+    loop {
+        let start = search + xml[search..].find(&open)?;
+        let after = start + open.len();
+        // `<title` must not match `<titles`.
+        let boundary = xml[after..].chars().next()?;
+        if !(boundary == '>' || boundary == '/' || boundary.is_whitespace()) {
+            search = after;
+            continue;
+        }
+        let open_end = after + xml[after..].find('>')?;
+        if xml[..open_end].ends_with('/') {
+            return Some((start, open_end + 1, open_end + 1));
+        }
+        let body_start = open_end + 1;
+        let body_end = body_start + xml[body_start..].find(&format!("</{tag}>"))?;
+        return Some((start, body_start, body_end));
+    }
+}
+
+/// Text of the first `tag` element in `xml`, with CDATA unwrapped and
+/// entities decoded.
+pub fn element_text(xml: &str, tag: &str) -> Option<String> {
+    let (_, body_start, body_end) = find_element(xml, tag, 0)?;
+    let body = xml[body_start..body_end].trim();
+    let text = match body.strip_prefix("<![CDATA[").and_then(|inner| inner.strip_suffix("]]>")) {
+        Some(inner) => inner.to_string(),
+        None => decode_entities(body),
+    };
+    Some(text.trim().to_string())
+}
+
+/// `href` of the entry's alternate link (or a link without `rel`).
+fn atom_link(entry: &str) -> Option<String> {
+    let mut offset = 0;
+    let mut fallback = None;
+    while let Some((start, body_start, _)) = find_element(entry, "link", offset) {
+        offset = body_start;
+        let tag = &entry[start..body_start];
+        let Some(href) = attribute(tag, "href") else {
+            continue;
+        };
+        match attribute(tag, "rel").as_deref() {
+            None | Some("alternate") => return Some(href),
+            _ => {
+                fallback.get_or_insert(href);
+            }
+        }
+    }
+    fallback
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let pattern = format!("{name}=");
+    let start = tag
+        .match_indices(&pattern)
+        .map(|(start, _)| start)
+        .find(|start| tag[..*start].ends_with(char::is_whitespace))?
+        + pattern.len();
+    let value = &tag[start..];
+    let quote = value.chars().next().filter(|ch| *ch == '"' || *ch == '\'')?;
+    let end = value[1..].find(quote)?;
+    Some(decode_entities(&value[1..end + 1]))
+}
+
+/// FNV-1a; stable across builds, unlike the std hasher.
+fn fnv1a(text: &str) -> u64 {
+    text.bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Actions bound through `set_hotkey_binding`. Read-selection is always
+/// bound; the others are optional.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BindableAction {
+    ReadSelection,
+    Stop,
+    PauseResume,
+    SpeedUp,
+    SpeedDown,
+    ReadClipboard,
+}
+
+impl BindableAction {
+    pub const ALL: [Self; 6] = [
+        Self::ReadSelection,
+        Self::Stop,
+        Self::PauseResume,
+        Self::SpeedUp,
+        Self::SpeedDown,
+        Self::ReadClipboard,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ReadSelection => "read-selection",
+            Self::Stop => "stop",
+            Self::PauseResume => "pause/resume",
+            Self::SpeedUp => "speed-up",
+            Self::SpeedDown => "speed-down",
+            Self::ReadClipboard => "read-clipboard",
+        }
+    }
+}
+
+/// Normalized accelerator of each bound action, e.g.
+/// `{"read_selection": "Ctrl+Shift+S", "stop": "Ctrl+Shift+X"}`.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct HotkeyMap(BTreeMap<BindableAction, String>);
+
+impl HotkeyMap {
+    pub fn new(read_selection: String) -> Self {
+        Self(BTreeMap::from([(BindableAction::ReadSelection, read_selection)]))
+    }
+
+    pub fn read_selection(&self) -> &str {
+        self.get(BindableAction::ReadSelection).unwrap_or_default()
+    }
+
+    pub fn get(&self, action: BindableAction) -> Option<&str> {
+        self.0.get(&action).map(String::as_str)
+    }
+
+    /// Binds `action` or, with `None`, clears it. Read-selection cannot be
+    /// cleared and keeps its accelerator.
+    pub fn set(&mut self, action: BindableAction, accelerator: Option<String>) {
+        match accelerator {
+            Some(accelerator) => {
+                self.0.insert(action, accelerator);
+            }
+            None if action != BindableAction::ReadSelection => {
+                self.0.remove(&action);
+            }
+            None => {}
+        }
+    }
+
+    /// The action `accelerator` is bound to, if any.
+    pub fn action_for(&self, accelerator: &str) -> Option<BindableAction> {
+        self.bindings()
+            .find(|(_, bound)| *bound == accelerator)
+            .map(|(action, _)| action)
+    }
+
+    pub fn bindings(&self) -> impl Iterator<Item = (BindableAction, &str)> {
+        self.0.iter().map(|(action, accelerator)| (*action, accelerator.as_str()))
+    }
+}
@@ -0,0 +1,128 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Entries kept in the history file; the oldest are dropped first.
+const MAX_HISTORY_ENTRIES: usize = 500;
+
+/// How the text of a job was obtained.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureMethod {
+    /// Simulated copy of the selection, detected through a clipboard probe.
+    ClipboardProbe,
+    /// Read from the focused control through the accessibility API.
+    Accessibility,
+    /// Read from the PRIMARY selection on Linux, without a simulated copy.
+    PrimarySelection,
+    /// Recognized from a screenshot of the focused window.
+    Ocr,
+    /// Clipboard contents as they were, without a simulated copy.
+    Clipboard,
+    File,
+    Url,
+    /// Piped into the app's standard input.
+    Stdin,
+    /// Pushed by a browser or editor extension.
+    Extension,
+    /// Passed in by the caller: typed text, saved phrases, practice sentences.
+    Direct,
+}
+
+/// Where the text of a job came from, captured before the job starts.
+#[derive(Clone)]
+pub struct JobOrigin {
+    pub window_title: String,
+    pub app_name: Option<String>,
+    pub capture_method: CaptureMethod,
+    /// Path or URL the text was read from.
+    pub location: Option<String>,
+}
+
+impl JobOrigin {
+    pub fn direct() -> Self {
+        Self::with_method(CaptureMethod::Direct)
+    }
+
+    /// Origin without a window, for text that did not come from the screen.
+    pub fn with_method(capture_method: CaptureMethod) -> Self {
+        Self {
+            window_title: String::new(),
+            app_name: None,
+            capture_method,
+            location: None,
+        }
+    }
+
+    pub fn metadata(&self, text: &str) -> CaptureMetadata {
+        CaptureMetadata {
+            app_name: self.app_name.clone(),
+            window_title: self.window_title.clone(),
+            capture_method: self.capture_method,
+            location: self.location.clone(),
+            text_length: text.chars().count(),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CaptureMetadata {
+    /// Foreground application, where the platform reports it.
+    pub app_name: Option<String>,
+    pub window_title: String,
+    pub capture_method: CaptureMethod,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// Characters of the text as it was handed to the job.
+    pub text_length: usize,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct JobHistoryEntry {
+    pub job_id: String,
+    /// RFC 3339 local time the job started.
+    pub started_at: String,
+    pub source: String,
+    pub text: String,
+    pub capture: CaptureMetadata,
+}
+
+/// Jobs started so far, oldest first.
+#[derive(Default, Serialize, Deserialize)]
+pub struct JobHistory {
+    entries: Vec<JobHistoryEntry>,
+}
+
+impl JobHistory {
+    /// A missing file is an empty history; a corrupt one is an error so a
+    /// later save does not discard earlier entries.
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(body) => serde_json::from_str(&body).with_context(|| format!("Failed to parse {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(anyhow!("Failed to read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create history directory {}", parent.display()))?;
+        }
+        let serialized = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, serialized).with_context(|| format!("Failed to write history file {}", path.display()))
+    }
+
+    pub fn entries(&self) -> &[JobHistoryEntry] {
+        &self.entries
+    }
+
+    pub fn push(&mut self, entry: JobHistoryEntry) {
+        self.entries.push(entry);
+        if self.entries.len() > MAX_HISTORY_ENTRIES {
+            let excess = self.entries.len() - MAX_HISTORY_ENTRIES;
+            self.entries.drain(..excess);
+        }
+    }
+}
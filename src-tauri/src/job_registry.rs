@@ -0,0 +1,84 @@
+use std::collections::VecDeque;
+
+use anyhow::{anyhow, Result};
+
+use crate::job_history::JobOrigin;
+use crate::read_queue::{QueuedRead, ReadPriority};
+
+/// Jobs kept for `respeak`, most recent first; older ones are dropped.
+const MAX_REGISTERED_JOBS: usize = 16;
+
+struct RegisteredJob {
+    job_id: String,
+    /// The text as sent for synthesis, after translation and reading order.
+    text: String,
+    voice_id: String,
+    rate: Option<f32>,
+    origin: JobOrigin,
+    /// Char offset into `text` of every chunk spoken so far, in order.
+    chunk_starts: Vec<usize>,
+}
+
+/// Text and chunk boundaries of recent speak jobs, so one chunk (usually a
+/// single sentence) can be synthesized again without replaying the passage.
+#[derive(Default)]
+pub struct JobRegistry {
+    jobs: VecDeque<RegisteredJob>,
+}
+
+impl JobRegistry {
+    pub fn register(&mut self, job_id: &str, text: &str, voice_id: &str, rate: Option<f32>, origin: &JobOrigin) {
+        self.jobs.retain(|job| job.job_id != job_id);
+        if self.jobs.len() >= MAX_REGISTERED_JOBS {
+            self.jobs.pop_back();
+        }
+        self.jobs.push_front(RegisteredJob {
+            job_id: job_id.to_string(),
+            text: text.to_string(),
+            voice_id: voice_id.to_string(),
+            rate,
+            origin: origin.clone(),
+            chunk_starts: Vec::new(),
+        });
+    }
+
+    /// Records audio for the chunk starting at `text_offset`. A chunk can come
+    /// as several audio pieces; only the first one starts a new chunk.
+    pub fn record_chunk(&mut self, job_id: &str, text_offset: usize) {
+        if let Some(job) = self.jobs.iter_mut().find(|job| job.job_id == job_id) {
+            if job.chunk_starts.last().is_none_or(|last| text_offset > *last) {
+                job.chunk_starts.push(text_offset);
+            }
+        }
+    }
+
+    /// A read of chunk `chunk_index` of `job_id` with the job's voice and
+    /// rate, in the interactive lane so it interrupts the current read.
+    pub fn respeak(&self, job_id: &str, chunk_index: usize) -> Result<QueuedRead> {
+        let job = self
+            .jobs
+            .iter()
+            .find(|job| job.job_id == job_id)
+            .ok_or_else(|| anyhow!("Job {job_id} is not among the {MAX_REGISTERED_JOBS} most recent jobs"))?;
+        let start = *job.chunk_starts.get(chunk_index).ok_or_else(|| {
+            anyhow!("Job {job_id} has no chunk {chunk_index}; {} were spoken", job.chunk_starts.len())
+        })?;
+        let end = job.chunk_starts.get(chunk_index + 1).copied().unwrap_or(usize::MAX);
+        let chunk: String = job.text.chars().skip(start).take(end.saturating_sub(start)).collect();
+        let chunk = chunk.trim();
+        if chunk.is_empty() {
+            return Err(anyhow!("Chunk {chunk_index} of job {job_id} has no text"));
+        }
+        Ok(QueuedRead {
+            text: chunk.to_string(),
+            priority: ReadPriority::Interactive,
+            source: "respeak_sentence".to_string(),
+            origin: job.origin.clone(),
+            voice_id: Some(job.voice_id.clone()),
+            rate: job.rate,
+            preprocessed: true,
+            expand_placeholders: false,
+            reading_order: false,
+        })
+    }
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// How long a cancel keeps a job's events suppressed while the job is still
+/// registered; long enough for any engine to confirm the cancel.
+const SUPPRESSION_TTL: Duration = Duration::from_secs(10 * 60);
+/// How long suppression outlives the job, for events already in flight when
+/// it finished.
+const FINISHED_GRACE: Duration = Duration::from_secs(30);
+
+/// Jobs whose remaining events are dropped because they were canceled. Each
+/// entry expires on its own, so pruning old cancels never lifts suppression
+/// of another job canceled at the same time.
+#[derive(Default)]
+pub struct SuppressedJobs {
+    /// When each entry stops applying.
+    jobs: HashMap<String, Instant>,
+}
+
+impl SuppressedJobs {
+    pub fn suppress(&mut self, job_id: &str) {
+        let now = Instant::now();
+        self.jobs.retain(|_, expires| *expires > now);
+        self.jobs.insert(job_id.to_string(), now + SUPPRESSION_TTL);
+    }
+
+    pub fn is_suppressed(&self, job_id: &str) -> bool {
+        self.jobs
+            .get(job_id)
+            .is_some_and(|expires| *expires > Instant::now())
+    }
+
+    /// The job has left the registry; its entry lapses after a short grace.
+    pub fn job_finished(&mut self, job_id: &str) {
+        if let Some(expires) = self.jobs.get_mut(job_id) {
+            *expires = (*expires).min(Instant::now() + FINISHED_GRACE);
+        }
+    }
+
+    /// A job id is being registered; nothing of it is suppressed yet.
+    pub fn remove(&mut self, job_id: &str) {
+        self.jobs.remove(job_id);
+    }
+
+    pub fn clear(&mut self) {
+        self.jobs.clear();
+    }
+}
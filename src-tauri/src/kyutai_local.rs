@@ -1,13 +1,12 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeSet, HashMap, VecDeque};
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use std::process::{Child, ChildStdin, Command, Stdio};
-use std::sync::OnceLock;
 use std::sync::mpsc::{self, Receiver};
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::thread::JoinHandle;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, Context, Result};
 use pocket_tts::{ModelState, TTSModel};
@@ -15,15 +14,37 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use uuid::Uuid;
 
+use crate::command_error::{CodedError, ErrorCode};
+use crate::language_hint::is_unspaced_language;
+use crate::pcm_cache::PcmCache;
+use crate::peak_limiter::{LimiterReport, LimiterState, PeakLimiter};
+use crate::reading_order::{split_paragraphs, split_sentences};
+use crate::silence_trim::SilenceTrim;
+use crate::sox::resolve_sox_path_cached;
+use crate::voice_blend::blend_prompt_files;
+
 const DEFAULT_VOICE_ID: &str = "0";
 const META_FILE_NAME: &str = "meta.json";
 const REF_AUDIO_FILE_NAME: &str = "reference.wav";
+/// Voice prompt embeddings of a blended voice, named as the sidecar names them.
+const PROMPT_FILE_NAME: &str = "prompt.safetensors";
 const LOCAL_CONFIG_VARIANT: &str = "voicereader-pocket-tts-local";
 const RUNTIME_CONFIG_DIR_NAME: &str = "pocket-tts-runtime";
 const MAX_SENTENCES_PER_CHUNK: usize = 1;
 const FIRST_CHUNK_MAX_SENTENCES: usize = 1;
 const FIRST_CHUNK_MAX_CHARS: usize = 200;
 const RATE_CONTROL_POLL_SAMPLES: usize = 960;
+/// Most chunks synthesized ahead of playback; `set_speak_settings` rejects more.
+pub const MAX_PREFETCH_DEPTH: usize = 8;
+// ~0.5 s at 24 kHz; streamed frames are batched to at least this before SoX.
+const STREAM_FEED_MIN_SAMPLES: usize = 12_000;
+const SOX_DRAIN_MARKER_MS: u32 = 100;
+const SOX_FLUSH_TIMEOUT_MS: u64 = 500;
+const VOLUME_RAMP_MS: u32 = 20;
+const PAUSE_POLL_MS: u64 = 20;
+const AUDIO_CACHE_DIR_NAME: &str = "audio-cache";
+/// About 90 minutes of 24 kHz mono speech.
+const AUDIO_CACHE_MAX_BYTES: u64 = 256 * 1024 * 1024;
 
 #[derive(Clone)]
 pub enum LocalJobEndState {
@@ -31,6 +52,29 @@ pub enum LocalJobEndState {
     Canceled,
 }
 
+#[derive(Clone)]
+pub struct StreamSettings {
+    pub chunk_max_chars: u32,
+    /// Language tag the caller gave for the text; `None` leaves sentence
+    /// splitting to the model.
+    pub language: Option<String>,
+    /// Output gain as `f32::to_bits`, shared so the volume can change mid-job.
+    pub volume: Arc<AtomicU32>,
+    /// Chunks that may be synthesized ahead of playback; `0` derives it from the core count.
+    pub prefetch_depth: usize,
+    pub silence_trim: Option<SilenceTrim>,
+    pub peak_limiter: PeakLimiter,
+    /// What the limiter has done so far in this job; `None` while it is off
+    /// or before the first chunk.
+    pub limiter_report: Arc<Mutex<Option<LimiterReport>>>,
+    /// Set while the job is paused; synthesis holds until it clears or the
+    /// job is canceled.
+    pub paused: Arc<AtomicBool>,
+    /// Microseconds synthesis spent held, by pauses here and by the caller
+    /// waiting on playback, so its timing can leave them out.
+    pub held_micros: Arc<AtomicU64>,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SavedVoiceMeta {
     pub voice_id: String,
@@ -49,6 +93,15 @@ struct SoxTempoStream {
     stdout_join: Option<JoinHandle<()>>,
     pending: Vec<u8>,
     frame_samples: usize,
+    rate: f32,
+    sample_rate: u32,
+    /// Speech samples written since the stream started (drain markers excluded).
+    speech_in_samples: u64,
+    /// Output bytes accepted into `pending` since the stream started.
+    speech_out_bytes: u64,
+    /// Output bytes still owed to earlier drain markers; dropped on arrival.
+    discard_bytes: usize,
+    flushed_in_samples: u64,
 }
 
 impl SoxTempoStream {
@@ -139,6 +192,12 @@ impl SoxTempoStream {
             stdout_join: Some(join),
             pending: Vec::new(),
             frame_samples,
+            rate,
+            sample_rate,
+            speech_in_samples: 0,
+            speech_out_bytes: 0,
+            discard_bytes: 0,
+            flushed_in_samples: 0,
         })
     }
 
@@ -146,6 +205,12 @@ impl SoxTempoStream {
         if samples.is_empty() {
             return Ok(());
         }
+        self.write_stdin(samples)?;
+        self.speech_in_samples += samples.len() as u64;
+        Ok(())
+    }
+
+    fn write_stdin(&mut self, samples: &[i16]) -> Result<()> {
         let stdin = self.stdin.as_mut().ok_or_else(|| anyhow!("SoX stdin closed"))?;
         stdin
             .write_all(&pcm_i16_to_le_bytes(samples))
@@ -154,13 +219,63 @@ impl SoxTempoStream {
         Ok(())
     }
 
+    fn accept_output(&mut self, mut bytes: &[u8]) {
+        if self.discard_bytes > 0 {
+            let skipped = usize::min(self.discard_bytes, bytes.len());
+            self.discard_bytes -= skipped;
+            bytes = &bytes[skipped..];
+        }
+        self.speech_out_bytes += bytes.len() as u64;
+        self.pending.extend_from_slice(bytes);
+    }
+
     fn drain_available_frames(&mut self) -> Vec<Vec<i16>> {
         while let Ok(bytes) = self.stdout_rx.try_recv() {
-            self.pending.extend_from_slice(&bytes);
+            self.accept_output(&bytes);
         }
         self.take_ready_frames()
     }
 
+    /// Returns everything SoX has produced for the speech pushed so far, so a
+    /// sentence ends on its own frame instead of being split across the next
+    /// sentence's first frame.
+    ///
+    /// SoX's tempo effect holds back a window of input until more arrives, so
+    /// a short run of silence (the drain marker) is written to push the
+    /// sentence tail out.  The marker's own output is cut from this flush and
+    /// dropped when it arrives later.  Falls back to whatever is buffered if
+    /// SoX does not catch up within `SOX_FLUSH_TIMEOUT_MS`.
+    fn flush_sentence(&mut self) -> Vec<i16> {
+        if self.speech_in_samples == self.flushed_in_samples {
+            return Vec::new();
+        }
+        self.flushed_in_samples = self.speech_in_samples;
+
+        let marker_samples = (self.sample_rate * SOX_DRAIN_MARKER_MS / 1000) as usize;
+        if self.write_stdin(&vec![0i16; marker_samples]).is_err() {
+            return bytes_to_pcm_i16_drain_all(&mut self.pending);
+        }
+
+        let expected_out_bytes = (self.speech_in_samples as f64 / self.rate as f64) as u64 * 2;
+        let deadline = Instant::now() + Duration::from_millis(SOX_FLUSH_TIMEOUT_MS);
+        while self.speech_out_bytes < expected_out_bytes {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.stdout_rx.recv_timeout(remaining) {
+                Ok(bytes) => self.accept_output(&bytes),
+                Err(_) => break,
+            }
+        }
+
+        let marker_out_bytes = ((marker_samples as f64 / self.rate as f64) as usize) * 2;
+        let overshoot = self.speech_out_bytes.saturating_sub(expected_out_bytes) as usize;
+        let overshoot = usize::min(overshoot, usize::min(marker_out_bytes, self.pending.len()));
+        self.pending.truncate(self.pending.len() - overshoot);
+        self.speech_out_bytes -= overshoot as u64;
+        self.discard_bytes += marker_out_bytes - overshoot;
+
+        bytes_to_pcm_i16_drain_all(&mut self.pending)
+    }
+
     fn finish_and_drain(&mut self) -> Vec<Vec<i16>> {
         self.stdin.take();
         let _ = self.child.wait();
@@ -168,7 +283,7 @@ impl SoxTempoStream {
             let _ = join.join();
         }
         while let Ok(bytes) = self.stdout_rx.try_recv() {
-            self.pending.extend_from_slice(&bytes);
+            self.accept_output(&bytes);
         }
 
         let mut frames = self.take_ready_frames();
@@ -210,6 +325,8 @@ pub struct LocalKyutaiRuntime {
     model_dir: PathBuf,
     model_id: String,
     state_cache: HashMap<String, ModelState>,
+    /// `None` when the cache directory could not be created.
+    pcm_cache: Option<Arc<PcmCache>>,
 }
 
 impl LocalKyutaiRuntime {
@@ -244,6 +361,9 @@ impl LocalKyutaiRuntime {
             model_dir: model_dir.to_path_buf(),
             model_id: model_id.to_string(),
             state_cache: HashMap::new(),
+            pcm_cache: PcmCache::open(&data_dir.join(AUDIO_CACHE_DIR_NAME), AUDIO_CACHE_MAX_BYTES)
+                .ok()
+                .map(Arc::new),
         };
 
         // Prime voice state and first inference to reduce first-playback clipping on cold start.
@@ -258,7 +378,8 @@ impl LocalKyutaiRuntime {
         Ok(runtime)
     }
 
-    pub fn health_payload(&self, selected_preset: &str) -> Value {
+    pub fn health_payload(&self, selected_preset: &str, preset_languages: &[String]) -> Value {
+        let languages = self.supported_languages(preset_languages);
         let sox_detail = resolve_sox_path_cached()
             .map(|path| format!("sox={}", path.display()))
             .unwrap_or_else(|| "sox=unavailable(resample_fallback_pitch_shift)".to_string());
@@ -270,7 +391,7 @@ impl LocalKyutaiRuntime {
                 "supports_voice_clone": true,
                 "supports_audio_chunk_stream": true,
                 "supports_true_streaming_inference": false,
-                "languages": ["en"]
+                "languages": languages
             },
             "runtime": {
                 "backend": "kyutai_pocket_tts_rust",
@@ -357,6 +478,50 @@ impl LocalKyutaiRuntime {
         Ok(meta)
     }
 
+    /// Saves a new voice whose prompt embeddings are interpolated between
+    /// `voice_a` and `voice_b`, each a preset name or a blended voice id.
+    /// `ratio` is the weight of `voice_b`. Cloned voices keep only their
+    /// reference audio, so they cannot be blended.
+    pub fn blend_voices(&mut self, voice_a: &str, voice_b: &str, ratio: f32) -> Result<SavedVoiceMeta> {
+        let prompt_a = self.prompt_path(voice_a)?;
+        let prompt_b = self.prompt_path(voice_b)?;
+
+        let voice_id = Uuid::new_v4().to_string();
+        let voice_dir = self.voice_dir(&voice_id);
+        std::fs::create_dir_all(&voice_dir)
+            .with_context(|| format!("Failed to create voice directory {}", voice_dir.display()))?;
+        let prompt_path = voice_dir.join(PROMPT_FILE_NAME);
+        let state = blend_prompt_files(&prompt_a, &prompt_b, ratio, &prompt_path).and_then(|_| {
+            self.model
+                .get_voice_state_from_prompt_file(&prompt_path)
+                .with_context(|| format!("Failed to load blended voice prompt {}", prompt_path.display()))
+        });
+        let state = match state {
+            Ok(state) => state,
+            Err(err) => {
+                let _ = std::fs::remove_dir_all(&voice_dir);
+                return Err(err);
+            }
+        };
+        self.state_cache.insert(format!("voice:{voice_id}"), state);
+
+        let percent_b = (ratio * 100.0).round() as u32;
+        let meta = SavedVoiceMeta {
+            voice_id: voice_id.clone(),
+            display_name: format!("{} / {} blend", self.voice_label(voice_a), self.voice_label(voice_b)),
+            created_at: now_unix_timestamp_string(),
+            tts_model_id: self.model_id.clone(),
+            language_hint: self.blend_language_hint(voice_a),
+            description: Some(format!(
+                "{}% {voice_a}, {percent_b}% {voice_b}",
+                100u32.saturating_sub(percent_b)
+            )),
+            ref_text: None,
+        };
+        self.write_voice_meta(&meta)?;
+        Ok(meta)
+    }
+
     pub fn update_voice(
         &mut self,
         voice_id: &str,
@@ -380,7 +545,10 @@ impl LocalKyutaiRuntime {
         }
         let voice_dir = self.voice_dir(voice_id);
         if !voice_dir.exists() {
-            return Err(anyhow!("VOICE_NOT_FOUND: {voice_id}"));
+            return Err(anyhow!(CodedError::new(
+                ErrorCode::VoiceNotFound,
+                format!("VOICE_NOT_FOUND: {voice_id}")
+            )));
         }
         self.state_cache.remove(&format!("voice:{voice_id}"));
         std::fs::remove_dir_all(&voice_dir)
@@ -388,7 +556,15 @@ impl LocalKyutaiRuntime {
         Ok(())
     }
 
-    /// Synthesizes `text` in chunks and streams PCM audio via `on_chunk`.
+    /// Loads a voice's state into the cache so its first chunk does not wait
+    /// on it; cloned voices take seconds to load from reference audio.
+    pub fn preload_voice(&mut self, voice_id: &str, selected_preset: &str) -> Result<()> {
+        self.resolve_voice_state(voice_id, selected_preset).map(|_| ())
+    }
+
+    /// Synthesizes `text` in chunks and streams PCM audio via `on_chunk`, which
+    /// receives `(chunk_index, text_offset, pcm, sample_rate)`; `text_offset`
+    /// is the char offset in `text` of the sentence group the audio belongs to.
     ///
     /// Returns `(end_state, had_audio)`.  `had_audio` is `true` if at least one
     /// non-empty PCM chunk was emitted, which the caller needs to decide whether
@@ -400,10 +576,13 @@ impl LocalKyutaiRuntime {
     ///   • Up to N background threads — pre-generating upcoming chunks
     ///   • SoX subprocess — tempo-stretches PCM in the background
     ///
-    /// N = min(available_cores - 1, 4).  On an 8-core machine, up to 4 chunks
+    /// N = `settings.prefetch_depth` when set (capped at 8), otherwise
+    /// min(available_cores - 1, 4).  On an 8-core machine, up to 4 chunks
     /// are generated concurrently in a sliding window, so by the time the main
     /// thread finishes SoX + emit for chunk i, chunks i+1..i+4 are already
     /// generated (or nearly so) and ready to emit with no pause.
+    /// The first chunk is streamed instead, so first audio does not wait for
+    /// a whole chunk to be generated.
     ///
     /// # `on_chunk` requirements
     /// The bound `Fn + Send + 'static` (instead of `FnMut`) is intentional:
@@ -417,14 +596,13 @@ impl LocalKyutaiRuntime {
         voice_id: &str,
         selected_preset: &str,
         text: &str,
-        chunk_max_chars: u32,
-        volume: f32,
+        settings: &StreamSettings,
         cancel: &AtomicBool,
         active_rate_steps: &AtomicU32,
         on_chunk: F,
     ) -> Result<(LocalJobEndState, bool)>
     where
-        F: Fn(usize, &[i16], u32) -> Result<()> + Send + 'static,
+        F: Fn(usize, usize, &[i16], u32) -> Result<()> + Send + 'static,
     {
         let mut chunk_index: usize = 0;
         let mut had_audio = false;
@@ -437,53 +615,93 @@ impl LocalKyutaiRuntime {
         } else {
             None
         };
-        let chunk_size = usize::min(usize::max(chunk_max_chars as usize, 100), FIRST_CHUNK_MAX_CHARS);
-        let split = self.model.split_into_best_sentences(text);
+        let chunk_size = usize::min(usize::max(settings.chunk_max_chars as usize, 100), FIRST_CHUNK_MAX_CHARS);
+        // The model's splitter is tuned for space-separated text; Chinese and
+        // Japanese sentences end in a full-width terminator with no space.
+        let split = if settings.language.as_deref().is_some_and(is_unspaced_language) {
+            split_paragraphs(text).iter().flat_map(|paragraph| split_sentences(paragraph)).collect()
+        } else {
+            self.model.split_into_best_sentences(text)
+        };
         let text_chunks: Vec<String> =
             cap_chunks_by_chars(split, text, chunk_size, MAX_SENTENCES_PER_CHUNK);
-
-        // Inline helper to track had_audio and forward to on_chunk.
+        let chunk_offsets = chunk_char_offsets(text, &text_chunks);
+        let mut text_offset = 0usize;
+
+        // Gain is applied on the way out, after SoX, so live volume changes
+        // take effect at the next emitted frame.
+        let mut gain = GainRamp::new(f32::from_bits(settings.volume.load(Ordering::SeqCst)), self.sample_rate);
+        let mut limiter = settings
+            .peak_limiter
+            .enabled
+            .then(|| LimiterState::new(settings.peak_limiter, self.sample_rate));
+
+        // Inline helper to track had_audio, apply gain and the limiter and
+        // forward to on_chunk.
         macro_rules! emit {
             ($idx:expr, $pcm:expr, $sr:expr) => {{
                 had_audio = true;
-                on_chunk($idx, $pcm, $sr)?;
+                let target = f32::from_bits(settings.volume.load(Ordering::SeqCst));
+                let pcm = match limiter.as_mut() {
+                    Some(limiter) => {
+                        let pcm = limiter.process(&gain.scale($pcm, target));
+                        if let Ok(mut report) = settings.limiter_report.lock() {
+                            *report = Some(limiter.report());
+                        }
+                        pcm
+                    }
+                    None => gain.apply($pcm, target),
+                };
+                on_chunk($idx, text_offset, &pcm, $sr)?;
             }};
         }
 
         // Resolve the voice state once (cached after first call).
         let voice_state = self.resolve_voice_state(voice_id, selected_preset)?;
-        let gain: f32 = volume.clamp(0.0, 2.0);
+        let voice_key = voice_state_key(voice_id, selected_preset);
+        let chunk_keys: Vec<String> = text_chunks
+            .iter()
+            .map(|chunk| PcmCache::key(&self.model_id, &voice_key, self.sample_rate, chunk))
+            .collect();
 
         if rate_active {
             // ------------------------------------------------------------------
             // rate != 1.0  — parallel look-ahead generation
             // ------------------------------------------------------------------
-            // We use model.generate() (full batch) so SoX receives a contiguous
-            // PCM batch.  Feeding SoX tiny per-token buffers via generate_stream
-            // causes output starvation at the frontend (see docs/learnings.md §1).
+            // The first chunk is generated on the main thread with
+            // model.generate_stream() so audio starts after the first streamed
+            // batch rather than after the whole chunk.  Streamed frames are
+            // grouped into STREAM_FEED_MIN_SAMPLES batches before reaching SoX:
+            // feeding it tiny per-token buffers causes output starvation at the
+            // frontend (see docs/learnings.md §1).
             //
-            // TTSModel::generate() takes &self (shared ref) and ModelState is
-            // Clone, so we can run the *next* chunk's generation on a background
-            // thread while the main thread pushes the current chunk to SoX,
-            // drains, and emits.  This overlaps T_gen(N+1) with SoX(N) + emit(N),
-            // eliminating most of the inter-chunk gap.
+            // Later chunks use model.generate() (full batch).  TTSModel::generate()
+            // takes &self (shared ref) and ModelState is Clone, so those chunks
+            // run on background threads while the main thread pushes the current
+            // chunk to SoX, drains, and emits.  This overlaps T_gen(N+1) with
+            // SoX(N) + emit(N), eliminating most of the inter-chunk gap.
             //
-            //   Main thread:   [gen C0] [sox+emit C0 | join C1] [sox+emit C1 | join C2] …
-            //   Look-ahead:              [gen C1]                [gen C2]
+            //   Main thread:   [stream+sox+emit C0 | join C1] [sox+emit C1 | join C2] …
+            //   Look-ahead:    [gen C1]                       [gen C2]
             // ------------------------------------------------------------------
 
             type GenResult = Result<Vec<i16>>;
             type LookAhead = JoinHandle<GenResult>;
 
-            /// Spawn a thread that runs model.generate() and returns PCM i16.
+            /// Spawn a thread that returns the chunk's PCM i16 from the audio
+            /// cache, or runs model.generate() and caches the result.
             fn spawn_generate(
                 model: &Arc<TTSModel>,
                 text: String,
                 voice_state: ModelState,
-                gain: f32,
+                cache: Option<Arc<PcmCache>>,
+                cache_key: String,
             ) -> LookAhead {
                 let model = Arc::clone(model);
                 std::thread::spawn(move || -> GenResult {
+                    if let Some(pcm) = cache.as_ref().and_then(|cache| cache.get(&cache_key)) {
+                        return Ok(pcm);
+                    }
                     let tensor = model
                         .generate(&text, &voice_state)
                         .context("Pocket-TTS generation failed (look-ahead)")?;
@@ -494,8 +712,10 @@ impl LocalKyutaiRuntime {
                         .context("Failed to convert look-ahead tensor to f32")?;
                     let mut pcm = Vec::with_capacity(values.len());
                     for sample in values {
-                        let scaled = (sample * gain).clamp(-1.0, 1.0);
-                        pcm.push((scaled * 32767.0) as i16);
+                        pcm.push((sample.clamp(-1.0, 1.0) * 32767.0) as i16);
+                    }
+                    if let Some(cache) = cache.as_ref() {
+                        cache.put(&cache_key, &pcm);
                     }
                     Ok(pcm)
                 })
@@ -503,28 +723,37 @@ impl LocalKyutaiRuntime {
 
             // Determine how many chunks to generate concurrently.
             // Each generate() call uses MKL/BLAS internally (multi-threaded),
-            // so we cap concurrency to avoid thread contention.  Reserve 1
-            // core for the main thread (SoX + emit) and split the rest among
-            // concurrent generate() calls, with a ceiling of 4.
-            let cores = std::thread::available_parallelism()
-                .map(|n| n.get())
-                .unwrap_or(1);
-            let look_ahead_depth = cores.saturating_sub(1).max(1).min(4);
-
-            // Pre-submit up to `look_ahead_depth` chunks.
+            // so by default we cap concurrency to avoid thread contention.
+            // Reserve 1 core for the main thread (SoX + emit) and split the
+            // rest among concurrent generate() calls, with a ceiling of 4.
+            // Users on slow machines can raise the depth to trade memory for
+            // a deeper buffer of ready audio.
+            let look_ahead_depth = if settings.prefetch_depth > 0 {
+                settings.prefetch_depth.min(MAX_PREFETCH_DEPTH)
+            } else {
+                let cores = std::thread::available_parallelism()
+                    .map(|n| n.get())
+                    .unwrap_or(1);
+                cores.saturating_sub(1).max(1).min(4)
+            };
+
+            // Pre-submit up to `look_ahead_depth` chunks after the streamed one.
             let mut queue: VecDeque<LookAhead> = VecDeque::new();
-            let mut next_to_submit = 0usize;
+            let mut next_to_submit = 1usize;
             while next_to_submit < text_chunks.len() && queue.len() < look_ahead_depth {
                 queue.push_back(spawn_generate(
                     &self.model,
                     text_chunks[next_to_submit].clone(),
                     voice_state.clone(),
-                    gain,
+                    self.pcm_cache.clone(),
+                    chunk_keys[next_to_submit].clone(),
                 ));
                 next_to_submit += 1;
             }
 
-            for _i in 0..text_chunks.len() {
+            for (chunk_position, chunk_offset) in chunk_offsets.iter().enumerate() {
+                text_offset = *chunk_offset;
+                hold_while_paused(settings, cancel);
                 if cancel.load(Ordering::SeqCst) {
                     drop(queue);
                     if let Some(stream) = sox_stream.as_mut() {
@@ -533,43 +762,62 @@ impl LocalKyutaiRuntime {
                     return Ok((LocalJobEndState::Canceled, had_audio));
                 }
 
-                // Await the PCM from the earliest queued generation thread.
-                let pcm = match queue.pop_front() {
-                    Some(handle) => handle
-                        .join()
-                        .map_err(|_| anyhow!("Look-ahead generation thread panicked"))??,
-                    None => Vec::new(),
+                // Raw PCM of a streamed first chunk, collected for the cache.
+                let mut streamed_pcm: Option<Vec<i16>> = None;
+                let batches: Box<dyn Iterator<Item = Result<Vec<i16>>> + '_> = if chunk_position == 0 {
+                    match self.pcm_cache.as_ref().and_then(|cache| cache.get(&chunk_keys[0])) {
+                        Some(pcm) => Box::new(std::iter::once(Ok(pcm))),
+                        None => {
+                            streamed_pcm = self.pcm_cache.as_ref().map(|_| Vec::new());
+                            Box::new(stream_pcm_batches(&self.model, &text_chunks[0], &voice_state))
+                        }
+                    }
+                } else {
+                    // Await the PCM from the earliest queued generation thread.
+                    let pcm = match queue.pop_front() {
+                        Some(handle) => handle
+                            .join()
+                            .map_err(|_| anyhow!("Look-ahead generation thread panicked"))??,
+                        None => Vec::new(),
+                    };
+
+                    // Refill the queue: submit the next unstarted chunk so
+                    // look_ahead_depth threads stay in flight.
+                    if next_to_submit < text_chunks.len() {
+                        queue.push_back(spawn_generate(
+                            &self.model,
+                            text_chunks[next_to_submit].clone(),
+                            voice_state.clone(),
+                            self.pcm_cache.clone(),
+                            chunk_keys[next_to_submit].clone(),
+                        ));
+                        next_to_submit += 1;
+                    }
+                    Box::new(std::iter::once(Ok(pcm)))
                 };
 
-                // Refill the queue: submit the next unstarted chunk so
-                // look_ahead_depth threads stay in flight.
-                if next_to_submit < text_chunks.len() {
-                    queue.push_back(spawn_generate(
-                        &self.model,
-                        text_chunks[next_to_submit].clone(),
-                        voice_state.clone(),
-                        gain,
-                    ));
-                    next_to_submit += 1;
-                }
-
-                if cancel.load(Ordering::SeqCst) {
-                    drop(queue);
-                    if let Some(stream) = sox_stream.as_mut() {
-                        stream.abort();
+                // Streamed batches: only the head is known to be the start of
+                // the sentence, and it lasts until the first batch with sound.
+                let mut trimming_head = true;
+                for pcm in batches {
+                    let mut pcm = pcm?;
+                    if let Some(raw) = streamed_pcm.as_mut() {
+                        raw.extend_from_slice(&pcm);
+                    }
+                    if let Some(trim) = settings.silence_trim.as_ref() {
+                        let keep = trim.keep_range(&pcm, self.sample_rate);
+                        if chunk_position == 0 {
+                            if trimming_head && keep.is_empty() {
+                                pcm.clear();
+                            } else if trimming_head {
+                                pcm.drain(..keep.start);
+                                trimming_head = false;
+                            }
+                        } else {
+                            pcm.truncate(keep.end);
+                            pcm.drain(..keep.start);
+                        }
                     }
-                    return Ok((LocalJobEndState::Canceled, had_audio));
-                }
-
-                if pcm.is_empty() {
-                    continue;
-                }
-
-                let mut cursor = 0usize;
-                let mut segment_start = 0usize;
-                let mut segment_rate = rate_clamped;
-
-                while cursor < pcm.len() {
                     if cancel.load(Ordering::SeqCst) {
                         drop(queue);
                         if let Some(stream) = sox_stream.as_mut() {
@@ -578,15 +826,56 @@ impl LocalKyutaiRuntime {
                         return Ok((LocalJobEndState::Canceled, had_audio));
                     }
 
-                    let next_cursor = usize::min(cursor + RATE_CONTROL_POLL_SAMPLES, pcm.len());
-                    let desired_rate = (active_rate_steps.load(Ordering::SeqCst).clamp(1, 16) as f32) / 4.0;
-                    if (desired_rate - segment_rate).abs() > f32::EPSILON {
-                        if cursor > segment_start {
-                            let segment = &pcm[segment_start..cursor];
+                    if pcm.is_empty() {
+                        continue;
+                    }
+
+                    let mut cursor = 0usize;
+                    let mut segment_start = 0usize;
+                    let mut segment_rate = rate_clamped;
+
+                    while cursor < pcm.len() {
+                        hold_while_paused(settings, cancel);
+                        if cancel.load(Ordering::SeqCst) {
+                            drop(queue);
+                            if let Some(stream) = sox_stream.as_mut() {
+                                stream.abort();
+                            }
+                            return Ok((LocalJobEndState::Canceled, had_audio));
+                        }
+
+                        let next_cursor = usize::min(cursor + RATE_CONTROL_POLL_SAMPLES, pcm.len());
+                        let desired_rate = (active_rate_steps.load(Ordering::SeqCst).clamp(1, 16) as f32) / 4.0;
+                        if (desired_rate - segment_rate).abs() > f32::EPSILON {
+                            if cursor > segment_start {
+                                let segment = &pcm[segment_start..cursor];
+                                if let Some(rate_stream) = sox_stream.as_mut() {
+                                    rate_stream.push_samples(segment)?;
+                                    let mut combined: Vec<i16> = Vec::new();
+                                    for adjusted in rate_stream.drain_available_frames() {
+                                        if !adjusted.is_empty() {
+                                            combined.extend_from_slice(&adjusted);
+                                        }
+                                    }
+                                    if !combined.is_empty() {
+                                        emit!(chunk_index, &combined, self.sample_rate);
+                                        chunk_index += 1;
+                                    }
+                                } else if (segment_rate - 1.0).abs() <= f32::EPSILON {
+                                    emit!(chunk_index, segment, self.sample_rate);
+                                    chunk_index += 1;
+                                } else {
+                                    let resampled = resample_pcm_by_rate(segment, segment_rate);
+                                    if !resampled.is_empty() {
+                                        emit!(chunk_index, &resampled, self.sample_rate);
+                                        chunk_index += 1;
+                                    }
+                                }
+                            }
+
                             if let Some(rate_stream) = sox_stream.as_mut() {
-                                rate_stream.push_samples(segment)?;
                                 let mut combined: Vec<i16> = Vec::new();
-                                for adjusted in rate_stream.drain_available_frames() {
+                                for adjusted in rate_stream.finish_and_drain() {
                                     if !adjusted.is_empty() {
                                         combined.extend_from_slice(&adjusted);
                                     }
@@ -595,21 +884,27 @@ impl LocalKyutaiRuntime {
                                     emit!(chunk_index, &combined, self.sample_rate);
                                     chunk_index += 1;
                                 }
-                            } else if (segment_rate - 1.0).abs() <= f32::EPSILON {
-                                emit!(chunk_index, segment, self.sample_rate);
-                                chunk_index += 1;
-                            } else {
-                                let resampled = resample_pcm_by_rate(segment, segment_rate);
-                                if !resampled.is_empty() {
-                                    emit!(chunk_index, &resampled, self.sample_rate);
-                                    chunk_index += 1;
-                                }
                             }
+
+                            rate_clamped = desired_rate;
+                            segment_rate = desired_rate;
+                            sox_stream = if (rate_clamped - 1.0).abs() > f32::EPSILON {
+                                SoxTempoStream::new(rate_clamped, self.sample_rate)
+                            } else {
+                                None
+                            };
+                            segment_start = cursor;
                         }
 
+                        cursor = next_cursor;
+                    }
+
+                    if cursor > segment_start {
+                        let segment = &pcm[segment_start..cursor];
                         if let Some(rate_stream) = sox_stream.as_mut() {
+                            rate_stream.push_samples(segment)?;
                             let mut combined: Vec<i16> = Vec::new();
-                            for adjusted in rate_stream.finish_and_drain() {
+                            for adjusted in rate_stream.drain_available_frames() {
                                 if !adjusted.is_empty() {
                                     combined.extend_from_slice(&adjusted);
                                 }
@@ -618,42 +913,28 @@ impl LocalKyutaiRuntime {
                                 emit!(chunk_index, &combined, self.sample_rate);
                                 chunk_index += 1;
                             }
-                        }
-
-                        rate_clamped = desired_rate;
-                        segment_rate = desired_rate;
-                        sox_stream = if (rate_clamped - 1.0).abs() > f32::EPSILON {
-                            SoxTempoStream::new(rate_clamped, self.sample_rate)
+                        } else if (segment_rate - 1.0).abs() <= f32::EPSILON {
+                            emit!(chunk_index, segment, self.sample_rate);
+                            chunk_index += 1;
                         } else {
-                            None
-                        };
-                        segment_start = cursor;
+                            let resampled = resample_pcm_by_rate(segment, segment_rate);
+                            if !resampled.is_empty() {
+                                emit!(chunk_index, &resampled, self.sample_rate);
+                                chunk_index += 1;
+                            }
+                        }
                     }
-
-                    cursor = next_cursor;
                 }
-
-                if cursor > segment_start {
-                    let segment = &pcm[segment_start..cursor];
+                if let (Some(cache), Some(raw)) = (self.pcm_cache.as_ref(), streamed_pcm.take()) {
+                    cache.put(&chunk_keys[chunk_position], &raw);
+                }
+                // End the sentence on a frame boundary; the last one is
+                // flushed by finish_and_drain() below.
+                if chunk_position + 1 < text_chunks.len() {
                     if let Some(rate_stream) = sox_stream.as_mut() {
-                        rate_stream.push_samples(segment)?;
-                        let mut combined: Vec<i16> = Vec::new();
-                        for adjusted in rate_stream.drain_available_frames() {
-                            if !adjusted.is_empty() {
-                                combined.extend_from_slice(&adjusted);
-                            }
-                        }
-                        if !combined.is_empty() {
-                            emit!(chunk_index, &combined, self.sample_rate);
-                            chunk_index += 1;
-                        }
-                    } else if (segment_rate - 1.0).abs() <= f32::EPSILON {
-                        emit!(chunk_index, segment, self.sample_rate);
-                        chunk_index += 1;
-                    } else {
-                        let resampled = resample_pcm_by_rate(segment, segment_rate);
-                        if !resampled.is_empty() {
-                            emit!(chunk_index, &resampled, self.sample_rate);
+                        let tail = rate_stream.flush_sentence();
+                        if !tail.is_empty() {
+                            emit!(chunk_index, &tail, self.sample_rate);
                             chunk_index += 1;
                         }
                     }
@@ -678,28 +959,30 @@ impl LocalKyutaiRuntime {
     }
 
     fn resolve_voice_state(&mut self, voice_id: &str, selected_preset: &str) -> Result<ModelState> {
-        let cache_key = if voice_id == DEFAULT_VOICE_ID {
-            format!("preset:{selected_preset}")
-        } else {
-            format!("voice:{voice_id}")
-        };
+        let cache_key = voice_state_key(voice_id, selected_preset);
 
         if !self.state_cache.contains_key(&cache_key) {
             let state = if voice_id == DEFAULT_VOICE_ID {
                 self.load_preset_voice_state(selected_preset)?
             } else {
                 let voice_meta = self.read_voice_meta(voice_id)?;
+                let prompt_path = self.voice_dir(&voice_meta.voice_id).join(PROMPT_FILE_NAME);
                 let ref_audio_path = self.voice_dir(&voice_meta.voice_id).join(REF_AUDIO_FILE_NAME);
-                if !ref_audio_path.exists() {
+                if prompt_path.exists() {
+                    self.model
+                        .get_voice_state_from_prompt_file(&prompt_path)
+                        .with_context(|| format!("Failed to load blended voice from {}", prompt_path.display()))?
+                } else if ref_audio_path.exists() {
+                    self.model
+                        .get_voice_state(&ref_audio_path)
+                        .with_context(|| format!("Failed to load saved voice from {}", ref_audio_path.display()))?
+                } else {
                     return Err(anyhow!(
                         "Saved voice {} is missing reference audio at {}",
                         voice_id,
                         ref_audio_path.display()
                     ));
                 }
-                self.model
-                    .get_voice_state(&ref_audio_path)
-                    .with_context(|| format!("Failed to load saved voice from {}", ref_audio_path.display()))?
             };
             self.state_cache.insert(cache_key.clone(), state);
         }
@@ -710,11 +993,42 @@ impl LocalKyutaiRuntime {
             .ok_or_else(|| anyhow!("Failed to resolve voice state for {voice_id}"))
     }
 
+    fn preset_prompt_path(&self, preset: &str) -> PathBuf {
+        self.model_dir.join("embeddings").join(format!("{preset}.safetensors"))
+    }
+
+    /// Prompt embeddings of a preset name or a blended voice id.
+    fn prompt_path(&self, voice: &str) -> Result<PathBuf> {
+        let preset_path = self.preset_prompt_path(voice);
+        // Presets are bare names; only those can be joined under embeddings/.
+        if !voice.contains(['/', '\\', '.']) && preset_path.exists() {
+            return Ok(preset_path);
+        }
+        let meta = self.read_voice_meta(voice)?;
+        let prompt_path = self.voice_dir(&meta.voice_id).join(PROMPT_FILE_NAME);
+        if !prompt_path.exists() {
+            return Err(anyhow!(
+                "Saved voice {} has no prompt embeddings; only presets and blended voices can be blended",
+                meta.display_name
+            ));
+        }
+        Ok(prompt_path)
+    }
+
+    fn voice_label(&self, voice: &str) -> String {
+        self.read_voice_meta(voice)
+            .map(|meta| meta.display_name)
+            .unwrap_or_else(|_| voice.to_string())
+    }
+
+    fn blend_language_hint(&self, voice: &str) -> String {
+        self.read_voice_meta(voice)
+            .map(|meta| meta.language_hint)
+            .unwrap_or_else(|_| "auto".to_string())
+    }
+
     fn load_preset_voice_state(&self, selected_preset: &str) -> Result<ModelState> {
-        let preset_path = self
-            .model_dir
-            .join("embeddings")
-            .join(format!("{selected_preset}.safetensors"));
+        let preset_path = self.preset_prompt_path(selected_preset);
         if !preset_path.exists() {
             return Err(anyhow!(
                 "Unsupported Kyutai preset voice: {selected_preset} (missing {})",
@@ -726,6 +1040,27 @@ impl LocalKyutaiRuntime {
             .with_context(|| format!("Failed to load Kyutai preset prompt {}", preset_path.display()))
     }
 
+    /// Union of preset languages and saved voices' `language_hint`, ignoring "auto".
+    fn supported_languages(&self, preset_languages: &[String]) -> Vec<String> {
+        let mut languages: BTreeSet<String> = preset_languages
+            .iter()
+            .map(|lang| lang.trim().to_ascii_lowercase())
+            .filter(|lang| !lang.is_empty())
+            .collect();
+        if let Ok(saved) = self.list_saved_voices() {
+            for voice in saved {
+                let hint = voice.language_hint.trim().to_ascii_lowercase();
+                if !hint.is_empty() && hint != "auto" {
+                    languages.insert(hint);
+                }
+            }
+        }
+        if languages.is_empty() {
+            languages.insert("en".to_string());
+        }
+        languages.into_iter().collect()
+    }
+
     fn list_saved_voices(&self) -> Result<Vec<SavedVoiceMeta>> {
         if !self.voices_dir.exists() {
             return Ok(Vec::new());
@@ -756,7 +1091,10 @@ impl LocalKyutaiRuntime {
     fn read_voice_meta(&self, voice_id: &str) -> Result<SavedVoiceMeta> {
         let meta_path = self.voice_dir(voice_id).join(META_FILE_NAME);
         if !meta_path.exists() {
-            return Err(anyhow!("VOICE_NOT_FOUND: {voice_id}"));
+            return Err(anyhow!(CodedError::new(
+                ErrorCode::VoiceNotFound,
+                format!("VOICE_NOT_FOUND: {voice_id}")
+            )));
         }
         let body = std::fs::read_to_string(&meta_path)
             .with_context(|| format!("Failed to read {}", meta_path.display()))?;
@@ -781,6 +1119,38 @@ impl LocalKyutaiRuntime {
     }
 }
 
+/// Identifies a voice: the preset for the default voice, else the saved voice.
+fn voice_state_key(voice_id: &str, selected_preset: &str) -> String {
+    if voice_id == DEFAULT_VOICE_ID {
+        format!("preset:{selected_preset}")
+    } else {
+        format!("voice:{voice_id}")
+    }
+}
+
+/// Char offset in `text` where each chunk starts, found by searching for the
+/// chunk's first word past the previous match. Chunks are trimmed and
+/// regrouped sentences, so they are not exact substrings of `text`.
+fn chunk_char_offsets(text: &str, chunks: &[String]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(chunks.len());
+    let mut byte_cursor = 0usize;
+    let mut char_cursor = 0usize;
+    for chunk in chunks {
+        let first_word = chunk.split_whitespace().next().unwrap_or_default();
+        match text[byte_cursor..].find(first_word).filter(|_| !first_word.is_empty()) {
+            Some(found) => {
+                char_cursor += text[byte_cursor..byte_cursor + found].chars().count();
+                offsets.push(char_cursor);
+                // Step past the word so a repeated opening word matches the next occurrence.
+                byte_cursor += found + first_word.len();
+                char_cursor += first_word.chars().count();
+            }
+            None => offsets.push(offsets.last().copied().unwrap_or(0)),
+        }
+    }
+    offsets
+}
+
 fn cap_chunks_by_chars(
     split: Vec<String>,
     original_text: &str,
@@ -915,6 +1285,107 @@ fn split_long_segment_by_words(input: &str, max_chars: usize) -> Vec<String> {
     output
 }
 
+/// Output gain that moves to a new target over `VOLUME_RAMP_MS` instead of
+/// stepping, which would click mid-waveform.
+struct GainRamp {
+    current: f32,
+    ramp_samples: usize,
+}
+
+impl GainRamp {
+    fn new(gain: f32, sample_rate: u32) -> Self {
+        Self {
+            current: gain.clamp(0.0, 2.0),
+            ramp_samples: ((sample_rate * VOLUME_RAMP_MS / 1000) as usize).max(1),
+        }
+    }
+
+    fn apply(&mut self, pcm: &[i16], target: f32) -> Vec<i16> {
+        if (self.current - 1.0).abs() <= f32::EPSILON && (target.clamp(0.0, 2.0) - 1.0).abs() <= f32::EPSILON {
+            return pcm.to_vec();
+        }
+        self.scale(pcm, target)
+            .into_iter()
+            .map(|sample| sample.round().clamp(-32768.0, 32767.0) as i16)
+            .collect()
+    }
+
+    /// The gained samples before rounding and clamping, for the limiter.
+    fn scale(&mut self, pcm: &[i16], target: f32) -> Vec<f32> {
+        let target = target.clamp(0.0, 2.0);
+        let step = (target - self.current) / self.ramp_samples as f32;
+        pcm.iter()
+            .map(|sample| {
+                if (target - self.current).abs() > step.abs() {
+                    self.current += step;
+                } else {
+                    self.current = target;
+                }
+                *sample as f32 * self.current
+            })
+            .collect()
+    }
+}
+
+/// Blocks while `settings.paused` is set. Look-ahead generations already in
+/// flight finish, but nothing new is started or emitted until it clears.
+fn hold_while_paused(settings: &StreamSettings, cancel: &AtomicBool) {
+    let started = Instant::now();
+    while settings.paused.load(Ordering::SeqCst) && !cancel.load(Ordering::SeqCst) {
+        std::thread::sleep(Duration::from_millis(PAUSE_POLL_MS));
+    }
+    settings
+        .held_micros
+        .fetch_add(started.elapsed().as_micros() as u64, Ordering::Relaxed);
+}
+
+/// Runs `generate_stream` and groups its per-frame tensors into PCM batches of
+/// at least `STREAM_FEED_MIN_SAMPLES` (the final batch may be shorter).
+fn stream_pcm_batches<'a>(
+    model: &'a TTSModel,
+    text: &'a str,
+    voice_state: &'a ModelState,
+) -> impl Iterator<Item = Result<Vec<i16>>> + 'a {
+    let mut frames = model.generate_stream(text, voice_state);
+    let mut finished = false;
+    std::iter::from_fn(move || {
+        if finished {
+            return None;
+        }
+        let mut batch: Vec<i16> = Vec::with_capacity(STREAM_FEED_MIN_SAMPLES);
+        while batch.len() < STREAM_FEED_MIN_SAMPLES {
+            let tensor = match frames.next() {
+                Some(Ok(tensor)) => tensor,
+                Some(Err(err)) => {
+                    finished = true;
+                    return Some(Err(err).context("Pocket-TTS streaming generation failed"));
+                }
+                None => {
+                    finished = true;
+                    break;
+                }
+            };
+            let values = match tensor
+                .flatten_all()
+                .and_then(|flat| flat.to_vec1::<f32>())
+                .context("Failed to convert streamed tensor to f32")
+            {
+                Ok(values) => values,
+                Err(err) => {
+                    finished = true;
+                    return Some(Err(err));
+                }
+            };
+            batch.extend(values.into_iter().map(|sample| (sample.clamp(-1.0, 1.0) * 32767.0) as i16));
+        }
+        if batch.is_empty() {
+            None
+        } else {
+            Some(Ok(batch))
+        }
+    })
+}
+
 fn resample_pcm_by_rate(input: &[i16], rate: f32) -> Vec<i16> {
     if input.is_empty() {
         return Vec::new();
@@ -1034,145 +1505,6 @@ fn write_normalized_reference_wav(ref_wav_path: &Path, wav_bytes: &[u8]) -> Resu
     Ok(())
 }
 
-fn resolve_sox_path_cached() -> Option<PathBuf> {
-    static SOX_PATH_CACHE: OnceLock<Option<PathBuf>> = OnceLock::new();
-    SOX_PATH_CACHE.get_or_init(resolve_sox_path).clone()
-}
-
-fn resolve_sox_path() -> Option<PathBuf> {
-    if let Some(path) = std::env::var_os("VOICEREADER_SOX_PATH").map(PathBuf::from) {
-        if path.exists() {
-            return Some(path);
-        }
-    }
-    if let Some(path) = find_bundled_sox_near_current_executable() {
-        return Some(path);
-    }
-    if command_exists("sox") {
-        return Some(PathBuf::from("sox"));
-    }
-    find_sox_in_windows_winget_location()
-}
-
-fn find_bundled_sox_near_current_executable() -> Option<PathBuf> {
-    let sox_name = if cfg!(target_os = "windows") {
-        "sox.exe"
-    } else {
-        "sox"
-    };
-
-    let mut roots: Vec<PathBuf> = Vec::new();
-    if let Ok(exe) = std::env::current_exe() {
-        if let Some(parent) = exe.parent() {
-            roots.push(parent.to_path_buf());
-            if let Some(grand_parent) = parent.parent() {
-                roots.push(grand_parent.to_path_buf());
-                if let Some(great_grand_parent) = grand_parent.parent() {
-                    roots.push(great_grand_parent.to_path_buf());
-                }
-            }
-        }
-    }
-    if let Ok(cwd) = std::env::current_dir() {
-        roots.push(cwd);
-    }
-
-    let mut seen: HashSet<PathBuf> = HashSet::new();
-    for root in roots {
-        if !seen.insert(root.clone()) {
-            continue;
-        }
-        let candidates = [
-            root.join("binaries").join("sox").join(sox_name),
-            root.join("resources").join("binaries").join("sox").join(sox_name),
-            root.join("binaries").join(sox_name),
-            root.join("resources").join("binaries").join(sox_name),
-            root.join("sox").join(sox_name),
-            root.join("resources").join("sox").join(sox_name),
-            root.join(sox_name),
-        ];
-        for candidate in candidates {
-            if candidate.exists() {
-                return Some(candidate);
-            }
-        }
-    }
-    None
-}
-
-fn command_exists(command: &str) -> bool {
-    Command::new(command)
-        .arg("--version")
-        .stdout(Stdio::null())
-        .stderr(Stdio::null())
-        .status()
-        .is_ok()
-}
-
-fn find_sox_in_windows_winget_location() -> Option<PathBuf> {
-    if !cfg!(target_os = "windows") {
-        return None;
-    }
-
-    let local_app_data = std::env::var_os("LOCALAPPDATA")?;
-    let root = PathBuf::from(local_app_data)
-        .join("Microsoft")
-        .join("WinGet")
-        .join("Packages");
-    if !root.exists() {
-        return None;
-    }
-
-    let mut candidates: Vec<PathBuf> = std::fs::read_dir(&root)
-        .ok()?
-        .filter_map(|entry| {
-            let path = entry.ok()?.path();
-            if !path.is_dir() {
-                return None;
-            }
-            let name = path.file_name()?.to_string_lossy().to_string();
-            if name.starts_with("ChrisBagwell.SoX_") {
-                Some(path)
-            } else {
-                None
-            }
-        })
-        .collect();
-    candidates.sort();
-
-    for candidate in candidates {
-        if let Ok(entries) = std::fs::read_dir(&candidate) {
-            let mut nested_bins: Vec<PathBuf> = entries
-                .filter_map(|entry| {
-                    let path = entry.ok()?.path();
-                    if !path.is_dir() {
-                        return None;
-                    }
-                    let name = path.file_name()?.to_string_lossy().to_string();
-                    if name.starts_with("sox-") {
-                        let binary = path.join("sox.exe");
-                        if binary.exists() {
-                            return Some(binary);
-                        }
-                    }
-                    None
-                })
-                .collect();
-            nested_bins.sort();
-            if let Some(binary) = nested_bins.into_iter().next() {
-                return Some(binary);
-            }
-        }
-
-        let direct_binary = candidate.join("sox.exe");
-        if direct_binary.exists() {
-            return Some(direct_binary);
-        }
-    }
-
-    None
-}
-
 fn pcm_i16_to_le_bytes(samples: &[i16]) -> Vec<u8> {
     let mut bytes = Vec::with_capacity(samples.len() * 2);
     for sample in samples {
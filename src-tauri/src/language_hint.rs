@@ -0,0 +1,31 @@
+use anyhow::{anyhow, Result};
+
+/// Languages written without spaces after sentence ends, split on the
+/// full-width terminators (`。！？`) instead of the model's splitter.
+#[cfg(feature = "build-base")]
+const UNSPACED_LANGUAGES: [&str; 3] = ["zh", "ja", "yue"];
+
+/// Checks a caller's language hint: a BCP 47 style tag such as `en`,
+/// `de-CH` or `zh-Hant-TW`. `_` is accepted in place of `-`.
+pub fn validate_language_hint(tag: &str) -> Result<()> {
+    let normalized = tag.trim().replace('_', "-");
+    let mut subtags = normalized.split('-');
+    let primary = subtags.next().unwrap_or_default();
+    let valid = (2..=3).contains(&primary.len())
+        && primary.chars().all(|c| c.is_ascii_alphabetic())
+        && subtags.all(|subtag| (1..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric()));
+    if !valid {
+        return Err(anyhow!("language must be a language tag such as en or de-CH, got {}", tag.trim()));
+    }
+    Ok(())
+}
+
+/// Whether sentences in `tag` end without a following space, so only
+/// full-width terminators mark where one ends.
+#[cfg(feature = "build-base")]
+pub fn is_unspaced_language(tag: &str) -> bool {
+    let primary = tag.trim().split(['-', '_']).next().unwrap_or_default();
+    UNSPACED_LANGUAGES
+        .iter()
+        .any(|language| language.eq_ignore_ascii_case(primary))
+}
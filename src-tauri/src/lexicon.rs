@@ -0,0 +1,519 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+const MAX_LEXICON_ENTRIES: usize = 1_000;
+
+/// How a lexicon entry's pronunciation is written.
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Notation {
+    /// Spoken as written, e.g. `win` for "Nguyen".
+    #[default]
+    Respelling,
+    /// IPA, with or without slashes, e.g. `/ŋwiən/`.
+    Ipa,
+    /// Hanyu pinyin with tone marks or numbers, e.g. `Xiǎohuì` or `xiao3hui4`.
+    Pinyin,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct LexiconEntry {
+    /// Matched as a whole word, ignoring case.
+    pub word: String,
+    #[serde(default)]
+    pub notation: Notation,
+    pub pronunciation: String,
+}
+
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PronunciationLexicon {
+    pub entries: Vec<LexiconEntry>,
+}
+
+impl PronunciationLexicon {
+    pub fn validate(&self) -> Result<()> {
+        if self.entries.len() > MAX_LEXICON_ENTRIES {
+            return Err(anyhow!("Lexicon is limited to {MAX_LEXICON_ENTRIES} entries"));
+        }
+        let mut seen = HashSet::new();
+        for entry in &self.entries {
+            let word = entry.word.trim();
+            if word.is_empty() || !word.chars().all(char::is_alphanumeric) {
+                return Err(anyhow!("Lexicon word {:?} must be a single word", entry.word));
+            }
+            if !seen.insert(word.to_lowercase()) {
+                return Err(anyhow!("Lexicon lists {word:?} more than once"));
+            }
+            for backend in [RespellingBackend::Kyutai, RespellingBackend::Qwen] {
+                respell(entry, backend).map_err(|err| anyhow!("Lexicon entry {word:?}: {err}"))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The model that will read the respelling. Kyutai only reads English
+/// spelling; Qwen is also trained on Mandarin and gets pinyin with tone marks.
+#[derive(Clone, Copy, PartialEq)]
+pub enum RespellingBackend {
+    Kyutai,
+    Qwen,
+}
+
+/// Replaces lexicon words in `text` with their pronunciation for `backend`.
+pub fn apply_lexicon(text: &str, lexicon: &PronunciationLexicon, backend: RespellingBackend) -> String {
+    if lexicon.entries.is_empty() {
+        return text.to_string();
+    }
+    let spoken = lexicon
+        .entries
+        .iter()
+        .filter_map(|entry| Some((entry.word.trim().to_lowercase(), respell(entry, backend).ok()?)))
+        .collect::<HashMap<_, _>>();
+
+    let push_token = |out: &mut String, token: &str| match spoken.get(&token.to_lowercase()) {
+        Some(respelling) => out.push_str(&match_case(token, respelling)),
+        None => out.push_str(token),
+    };
+    let mut out = String::with_capacity(text.len());
+    let mut token = String::new();
+    for ch in text.chars() {
+        if ch.is_alphanumeric() {
+            token.push(ch);
+            continue;
+        }
+        push_token(&mut out, &token);
+        token.clear();
+        out.push(ch);
+    }
+    push_token(&mut out, &token);
+    out
+}
+
+fn respell(entry: &LexiconEntry, backend: RespellingBackend) -> Result<String> {
+    let pronunciation = entry.pronunciation.trim();
+    if pronunciation.is_empty() {
+        return Err(anyhow!("pronunciation cannot be empty"));
+    }
+    match (entry.notation, backend) {
+        (Notation::Respelling, _) => Ok(pronunciation.to_string()),
+        (Notation::Ipa, _) => ipa_to_respelling(pronunciation),
+        (Notation::Pinyin, RespellingBackend::Kyutai) => pinyin_to_respelling(pronunciation),
+        (Notation::Pinyin, RespellingBackend::Qwen) => {
+            // Parsed anyway so an entry that is not pinyin fails validation.
+            pinyin_to_respelling(pronunciation)?;
+            Ok(pinyin_tone_marks(pronunciation))
+        }
+    }
+}
+
+/// Capitalizes the respelling when the word in the text is capitalized.
+fn match_case(word: &str, respelling: &str) -> String {
+    let mut chars = respelling.chars();
+    match (word.chars().next(), chars.next()) {
+        (Some(first), Some(lead)) if first.is_uppercase() => lead.to_uppercase().chain(chars).collect(),
+        _ => respelling.to_string(),
+    }
+}
+
+/// IPA symbols and their English spelling, two-symbol sequences first.
+const IPA_SPELLINGS: &[(&str, &str)] = &[
+    ("tʃ", "ch"),
+    ("dʒ", "j"),
+    ("tɕ", "ch"),
+    ("dʑ", "j"),
+    ("ʈʂ", "ch"),
+    ("ts", "ts"),
+    ("dz", "dz"),
+    ("aɪ", "eye"),
+    ("ai", "eye"),
+    ("aʊ", "ow"),
+    ("au", "ow"),
+    ("eɪ", "ay"),
+    ("ei", "ay"),
+    ("oʊ", "oh"),
+    ("əʊ", "oh"),
+    ("ou", "oh"),
+    ("ɔɪ", "oy"),
+    ("ɪə", "eer"),
+    ("ɛə", "air"),
+    ("ʊə", "oor"),
+    ("p", "p"),
+    ("b", "b"),
+    ("t", "t"),
+    ("d", "d"),
+    ("k", "k"),
+    ("c", "k"),
+    ("g", "g"),
+    ("ɡ", "g"),
+    ("ɟ", "g"),
+    ("ʔ", ""),
+    ("f", "f"),
+    ("v", "v"),
+    ("β", "v"),
+    ("ʋ", "v"),
+    ("θ", "th"),
+    ("ð", "th"),
+    ("s", "s"),
+    ("z", "z"),
+    ("ʃ", "sh"),
+    ("ɕ", "sh"),
+    ("ʂ", "sh"),
+    ("ʒ", "zh"),
+    ("ʑ", "zh"),
+    ("ʐ", "zh"),
+    ("x", "kh"),
+    ("χ", "kh"),
+    ("ɣ", "g"),
+    ("h", "h"),
+    ("ɦ", "h"),
+    ("ç", "h"),
+    ("m", "m"),
+    ("ɱ", "m"),
+    ("n", "n"),
+    ("ŋ", "ng"),
+    ("ɲ", "ny"),
+    ("l", "l"),
+    ("ɫ", "l"),
+    ("ʎ", "ly"),
+    ("r", "r"),
+    ("ɹ", "r"),
+    ("ɾ", "r"),
+    ("ɻ", "r"),
+    ("ʁ", "r"),
+    ("ʀ", "r"),
+    ("j", "y"),
+    ("w", "w"),
+    ("ɥ", "w"),
+    ("i", "ee"),
+    ("ɪ", "i"),
+    ("ɨ", "i"),
+    ("ᵻ", "i"),
+    ("e", "ay"),
+    ("ɛ", "e"),
+    ("æ", "a"),
+    ("a", "ah"),
+    ("ɑ", "ah"),
+    ("ɒ", "o"),
+    ("ɔ", "aw"),
+    ("o", "oh"),
+    ("ʊ", "uu"),
+    ("u", "oo"),
+    ("ɯ", "oo"),
+    ("ʉ", "oo"),
+    ("ʌ", "u"),
+    ("ə", "uh"),
+    ("ɐ", "uh"),
+    ("ɘ", "uh"),
+    ("ɤ", "uh"),
+    ("ɜ", "ur"),
+    ("ɝ", "ur"),
+    ("ɚ", "er"),
+    ("ɵ", "er"),
+    ("ø", "er"),
+    ("œ", "er"),
+    ("y", "ew"),
+    ("ʏ", "ew"),
+];
+
+/// Length, aspiration, nasalization, tone letters and the tie bar; they have
+/// no English spelling.
+const IPA_IGNORED: &[char] = &[
+    'ː', 'ˑ', 'ʰ', 'ʲ', 'ʷ', '\u{303}', '\u{329}', '\u{32f}', '\u{361}', '\u{35c}', '˞', '˥', '˦', '˧', '˨', '˩',
+];
+
+/// English respelling of an IPA transcription, with syllables split at
+/// stress marks and dots (`/ˈʃjaʊ.hweɪ/` as `shyow-hway`).
+fn ipa_to_respelling(ipa: &str) -> Result<String> {
+    let mut words = Vec::new();
+    for word in ipa.split_whitespace() {
+        let mut syllables = vec![String::new()];
+        let chars = word
+            .chars()
+            .filter(|ch| !IPA_IGNORED.contains(ch) && !ch.is_ascii_digit())
+            .collect::<Vec<_>>();
+        let mut idx = 0;
+        while idx < chars.len() {
+            if matches!(chars[idx], 'ˈ' | 'ˌ' | '.' | '-' | '‿') {
+                syllables.push(String::new());
+                idx += 1;
+                continue;
+            }
+            if matches!(chars[idx], '/' | '[' | ']') {
+                idx += 1;
+                continue;
+            }
+            let (len, spelling) = [2, 1]
+                .into_iter()
+                .filter(|len| idx + len <= chars.len())
+                .find_map(|len| {
+                    let symbol = chars[idx..idx + len].iter().collect::<String>();
+                    IPA_SPELLINGS
+                        .iter()
+                        .find(|(ipa, _)| *ipa == symbol)
+                        .map(|(_, spelling)| (len, *spelling))
+                })
+                .ok_or_else(|| anyhow!("unsupported IPA symbol {:?}", chars[idx]))?;
+            if let Some(syllable) = syllables.last_mut() {
+                syllable.push_str(spelling);
+            }
+            idx += len;
+        }
+        syllables.retain(|syllable| !syllable.is_empty());
+        if !syllables.is_empty() {
+            words.push(syllables.join("-"));
+        }
+    }
+    if words.is_empty() {
+        return Err(anyhow!("IPA pronunciation has no sounds"));
+    }
+    Ok(words.join(" "))
+}
+
+const PINYIN_INITIALS: &[(&str, &str)] = &[
+    ("zh", "j"),
+    ("ch", "ch"),
+    ("sh", "sh"),
+    ("b", "b"),
+    ("p", "p"),
+    ("m", "m"),
+    ("f", "f"),
+    ("d", "d"),
+    ("t", "t"),
+    ("n", "n"),
+    ("l", "l"),
+    ("g", "g"),
+    ("k", "k"),
+    ("h", "h"),
+    ("j", "j"),
+    ("q", "ch"),
+    ("x", "sh"),
+    ("r", "r"),
+    ("z", "dz"),
+    ("c", "ts"),
+    ("s", "s"),
+];
+
+/// Finals after a consonant initial. `v` stands for `ü`.
+const PINYIN_FINALS: &[(&str, &str)] = &[
+    ("a", "ah"),
+    ("o", "aw"),
+    ("e", "uh"),
+    ("ai", "eye"),
+    ("ei", "ay"),
+    ("ao", "ow"),
+    ("ou", "oh"),
+    ("an", "ahn"),
+    ("en", "un"),
+    ("ang", "ahng"),
+    ("eng", "ung"),
+    ("ong", "oong"),
+    ("i", "ee"),
+    ("ia", "yah"),
+    ("ie", "yeh"),
+    ("iao", "yow"),
+    ("iu", "yoh"),
+    ("ian", "yen"),
+    ("in", "een"),
+    ("iang", "yahng"),
+    ("ing", "eeng"),
+    ("iong", "yoong"),
+    ("u", "oo"),
+    ("ua", "wah"),
+    ("uo", "waw"),
+    ("uai", "why"),
+    ("ui", "way"),
+    ("uan", "wahn"),
+    ("un", "wun"),
+    ("uang", "wahng"),
+    ("v", "ew"),
+    ("ve", "yweh"),
+    ("ue", "yweh"),
+];
+
+/// Syllables without a consonant initial, spelled with `y`/`w` or bare.
+const PINYIN_STANDALONE: &[(&str, &str)] = &[
+    ("a", "ah"),
+    ("o", "aw"),
+    ("e", "uh"),
+    ("ai", "eye"),
+    ("ei", "ay"),
+    ("ao", "ow"),
+    ("ou", "oh"),
+    ("an", "ahn"),
+    ("en", "un"),
+    ("ang", "ahng"),
+    ("eng", "ung"),
+    ("er", "ar"),
+    ("yi", "ee"),
+    ("yin", "een"),
+    ("ying", "eeng"),
+    ("ya", "yah"),
+    ("yao", "yow"),
+    ("yan", "yen"),
+    ("yang", "yahng"),
+    ("ye", "yeh"),
+    ("yo", "yaw"),
+    ("yong", "yoong"),
+    ("you", "yoh"),
+    ("yu", "ew"),
+    ("yue", "yweh"),
+    ("yuan", "ywen"),
+    ("yun", "ewn"),
+    ("wu", "woo"),
+    ("wa", "wah"),
+    ("wo", "waw"),
+    ("wai", "why"),
+    ("wei", "way"),
+    ("wan", "wahn"),
+    ("wen", "wun"),
+    ("wang", "wahng"),
+    ("weng", "wung"),
+];
+
+/// English respelling of pinyin, one hyphenated word per pinyin word
+/// (`Zhāng Xiǎohuì` as `jahng shyow-hway`). Tones are dropped.
+fn pinyin_to_respelling(pinyin: &str) -> Result<String> {
+    let plain = pinyin
+        .chars()
+        .map(|ch| match ch {
+            '0'..='5' | '\'' | '’' => '\'',
+            other => strip_tone_mark(other.to_lowercase().next().unwrap_or(other)),
+        })
+        .collect::<String>();
+    let mut words = Vec::new();
+    for word in plain.split_whitespace() {
+        let mut syllables = Vec::new();
+        for run in word.split('\'').filter(|run| !run.is_empty()) {
+            syllables.extend(
+                segment_pinyin(run).ok_or_else(|| anyhow!("{run:?} is not valid pinyin"))?,
+            );
+        }
+        if !syllables.is_empty() {
+            words.push(syllables.join("-"));
+        }
+    }
+    if words.is_empty() {
+        return Err(anyhow!("pinyin pronunciation has no syllables"));
+    }
+    Ok(words.join(" "))
+}
+
+/// Splits a run of pinyin letters into respelled syllables, preferring
+/// longer finals and backing off when the rest does not parse.
+fn segment_pinyin(run: &str) -> Option<Vec<String>> {
+    if run.is_empty() {
+        return Some(Vec::new());
+    }
+    let mut candidates = PINYIN_STANDALONE
+        .iter()
+        .filter(|(syllable, _)| run.starts_with(syllable))
+        .map(|(syllable, spelling)| (syllable.len(), spelling.to_string()))
+        .collect::<Vec<_>>();
+    if let Some((initial, initial_spelling)) = PINYIN_INITIALS.iter().find(|(initial, _)| run.starts_with(initial)) {
+        let rest = &run[initial.len()..];
+        for (final_, final_spelling) in PINYIN_FINALS {
+            if rest.starts_with(final_) {
+                let final_spelling = pinyin_final_after(initial, final_).unwrap_or(final_spelling);
+                candidates.push((initial.len() + final_.len(), format!("{initial_spelling}{final_spelling}")));
+            }
+        }
+    }
+    candidates.sort_by_key(|(len, _)| std::cmp::Reverse(*len));
+    candidates.into_iter().find_map(|(len, spelling)| {
+        let mut rest = segment_pinyin(&run[len..])?;
+        rest.insert(0, spelling);
+        Some(rest)
+    })
+}
+
+/// Finals that sound different after some initials: `u` is `ü` after
+/// `j`, `q` and `x`, and `i` is a buzzed vowel after sibilants.
+fn pinyin_final_after(initial: &str, final_: &str) -> Option<&'static str> {
+    match (initial, final_) {
+        ("j" | "q" | "x", "u") => Some("ew"),
+        ("j" | "q" | "x", "uan") => Some("ywen"),
+        ("j" | "q" | "x", "un") => Some("ewn"),
+        ("z" | "c" | "s", "i") => Some("uh"),
+        ("zh" | "ch" | "sh" | "r", "i") => Some("ur"),
+        _ => None,
+    }
+}
+
+const TONE_MARKS: [(char, [char; 4]); 6] = [
+    ('a', ['ā', 'á', 'ǎ', 'à']),
+    ('e', ['ē', 'é', 'ě', 'è']),
+    ('i', ['ī', 'í', 'ǐ', 'ì']),
+    ('o', ['ō', 'ó', 'ǒ', 'ò']),
+    ('u', ['ū', 'ú', 'ǔ', 'ù']),
+    ('ü', ['ǖ', 'ǘ', 'ǚ', 'ǜ']),
+];
+
+/// `ǎ` as `a`; `ü` and its toned forms as `v`.
+fn strip_tone_mark(ch: char) -> char {
+    if matches!(ch, 'ü' | 'ǖ' | 'ǘ' | 'ǚ' | 'ǜ') {
+        return 'v';
+    }
+    TONE_MARKS
+        .iter()
+        .find(|(_, marked)| marked.contains(&ch))
+        .map_or(ch, |(base, _)| *base)
+}
+
+/// Pinyin with tone numbers written as marks (`xiao3hui4` as `xiǎohuì`).
+/// Text already written with marks comes back unchanged.
+fn pinyin_tone_marks(pinyin: &str) -> String {
+    let mut out = String::with_capacity(pinyin.len());
+    let mut syllable = String::new();
+    for ch in pinyin.chars() {
+        match ch {
+            '1'..='4' => {
+                out.push_str(&mark_tone(&syllable, ch as usize - '1' as usize));
+                syllable.clear();
+            }
+            '5' | '0' => {
+                out.push_str(&syllable);
+                syllable.clear();
+            }
+            'v' => syllable.push('ü'),
+            'V' => syllable.push('Ü'),
+            ch if ch.is_alphabetic() => syllable.push(ch),
+            other => {
+                out.push_str(&syllable);
+                syllable.clear();
+                out.push(other);
+            }
+        }
+    }
+    out.push_str(&syllable);
+    out
+}
+
+/// Marks `a` or `e` if present, the `o` of `ou`, else the last vowel.
+fn mark_tone(syllable: &str, tone: usize) -> String {
+    let chars = syllable.chars().collect::<Vec<_>>();
+    let lower = syllable.to_lowercase().chars().collect::<Vec<_>>();
+    let is_vowel = |ch: &char| TONE_MARKS.iter().any(|(base, _)| base == ch);
+    let target = lower
+        .iter()
+        .position(|ch| *ch == 'a' || *ch == 'e')
+        .or_else(|| lower.windows(2).position(|pair| pair == ['o', 'u']))
+        .or_else(|| lower.iter().rposition(is_vowel));
+    let Some(target) = target.filter(|idx| *idx < chars.len()) else {
+        return syllable.to_string();
+    };
+    let Some((_, marked)) = TONE_MARKS.iter().find(|(base, _)| *base == lower[target]) else {
+        return syllable.to_string();
+    };
+    let mut marked_char = marked[tone];
+    if chars[target].is_uppercase() {
+        marked_char = marked_char.to_uppercase().next().unwrap_or(marked_char);
+    }
+    chars
+        .iter()
+        .enumerate()
+        .map(|(idx, ch)| if idx == target { marked_char } else { *ch })
+        .collect()
+}
@@ -2,6 +2,63 @@ pub fn run() {
     voicereader_core::run_app();
 }
 
+mod accessibility;
+mod acronyms;
+mod audio_credits;
+mod audio_export;
+mod audio_format;
+mod audio_playback;
+mod audio_sinks;
+mod audiobook;
+mod benchmark;
+mod capture_overrides;
+mod capture_stats;
+mod cast;
+mod chunk_journal;
+mod command_error;
+mod dialogue;
+mod engine_http;
+mod engine_limits;
+mod engine_profiles;
+mod event_routing;
+mod feeds;
+mod hotkey_map;
+mod job_history;
+mod job_registry;
+mod job_suppression;
+mod language_hint;
+mod lexicon;
+mod maintenance;
+mod number_format;
+mod peak_limiter;
+mod phrases;
+mod pipe_endpoint;
+mod practice;
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+mod primary_selection;
+mod quiet_hours;
+mod read_estimate;
+mod read_queue;
+mod readback;
+mod reading_order;
+mod readability;
+mod schedules;
+mod selection_toggle;
+mod silence_trim;
+mod sox;
+mod storage_usage;
+mod summarization;
+mod teleprompter;
+mod text_sources;
+mod transcript;
+mod translation;
+mod virtual_mic;
 mod voicereader_core;
+mod webhooks;
+mod window_ocr;
 #[cfg(feature = "build-base")]
 mod kyutai_local;
+#[cfg(feature = "build-base")]
+mod pcm_cache;
+#[cfg(feature = "build-base")]
+mod voice_blend;
@@ -0,0 +1,144 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+
+const VOICES_DIR_NAME: &str = "voices";
+const META_FILE_NAME: &str = "meta.json";
+const RUNTIME_CONFIG_DIR_NAME: &str = "pocket-tts-runtime";
+/// Keys the local runtime rewrites to absolute model paths.
+const RUNTIME_CONFIG_PATH_KEYS: [&str; 3] = ["weights_path", "weights_path_without_voice_cloning", "tokenizer_path"];
+/// Leftovers of interrupted writes and downloads.
+pub const TEMP_FILE_SUFFIXES: [&str; 4] = [".tmp", ".part", ".partial", ".incomplete"];
+/// Model downloads live here and resume from their `.part` files, however
+/// old, so the temp file sweep leaves these directories alone.
+const DOWNLOAD_DIR_NAMES: [&str; 2] = ["models", "hf-cache"];
+/// Anything touched more recently may still be in use (a clone being
+/// written, a download in progress), so it is left alone.
+const MIN_ORPHAN_AGE: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Default, Serialize)]
+pub struct MaintenanceReport {
+    pub removed_voice_dirs: Vec<String>,
+    pub removed_runtime_configs: Vec<String>,
+    pub removed_temp_files: Vec<String>,
+    /// Entries that should have been removed but could not be.
+    pub errors: Vec<String>,
+}
+
+impl MaintenanceReport {
+    pub fn removed_count(&self) -> usize {
+        self.removed_voice_dirs.len() + self.removed_runtime_configs.len() + self.removed_temp_files.len()
+    }
+}
+
+/// Removes orphaned engine data under `data_dir`:
+/// - voice directories whose `meta.json` is missing or unreadable,
+/// - local runtime configs whose weights or tokenizer path no longer exists
+///   (the runtime rewrites them on the next start),
+/// - temp files left behind by interrupted writes, outside the model
+///   download directories.
+pub fn run_maintenance(data_dir: &Path) -> Result<MaintenanceReport> {
+    let mut report = MaintenanceReport::default();
+    let now = SystemTime::now();
+
+    let voices_dir = data_dir.join(VOICES_DIR_NAME);
+    for voice_dir in list_dir(&voices_dir)? {
+        if !voice_dir.is_dir() || !is_stale(&voice_dir, now) || has_valid_meta(&voice_dir) {
+            continue;
+        }
+        match std::fs::remove_dir_all(&voice_dir) {
+            Ok(()) => report.removed_voice_dirs.push(voice_dir.display().to_string()),
+            Err(err) => report.errors.push(format!("{}: {err}", voice_dir.display())),
+        }
+    }
+
+    let runtime_config_dir = data_dir.join(RUNTIME_CONFIG_DIR_NAME).join("config");
+    for config in list_dir(&runtime_config_dir)? {
+        if config.extension().and_then(|ext| ext.to_str()) != Some("yaml") || !has_missing_asset(&config) {
+            continue;
+        }
+        match std::fs::remove_file(&config) {
+            Ok(()) => report.removed_runtime_configs.push(config.display().to_string()),
+            Err(err) => report.errors.push(format!("{}: {err}", config.display())),
+        }
+    }
+
+    let mut pending = vec![data_dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in list_dir(&dir)? {
+            // Do not follow links out of the data directory.
+            let Ok(metadata) = std::fs::symlink_metadata(&entry) else {
+                continue;
+            };
+            if metadata.is_dir() {
+                let is_download_dir = dir == data_dir
+                    && entry
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| DOWNLOAD_DIR_NAMES.contains(&name));
+                if !is_download_dir {
+                    pending.push(entry);
+                }
+                continue;
+            }
+            let name = entry.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+            if !TEMP_FILE_SUFFIXES.iter().any(|suffix| name.ends_with(suffix)) || !is_stale(&entry, now) {
+                continue;
+            }
+            match std::fs::remove_file(&entry) {
+                Ok(()) => report.removed_temp_files.push(entry.display().to_string()),
+                Err(err) => report.errors.push(format!("{}: {err}", entry.display())),
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+fn list_dir(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let entries = std::fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))?;
+    Ok(entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect())
+}
+
+fn is_stale(path: &Path, now: SystemTime) -> bool {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| now.duration_since(modified).ok())
+        .is_some_and(|age| age >= MIN_ORPHAN_AGE)
+}
+
+fn has_valid_meta(voice_dir: &Path) -> bool {
+    std::fs::read_to_string(voice_dir.join(META_FILE_NAME))
+        .ok()
+        .and_then(|body| serde_json::from_str::<Value>(&body).ok())
+        .is_some_and(|meta| meta.get("voice_id").and_then(Value::as_str).is_some())
+}
+
+/// True when a rewritten model path in the config points at a file that is gone.
+fn has_missing_asset(config: &Path) -> bool {
+    let Ok(body) = std::fs::read_to_string(config) else {
+        return false;
+    };
+    body.lines().any(|line| {
+        let Some((key, value)) = line.trim().split_once(':') else {
+            return false;
+        };
+        if !RUNTIME_CONFIG_PATH_KEYS.contains(&key) {
+            return false;
+        }
+        let value = value.trim();
+        let path = value
+            .strip_prefix('\'')
+            .and_then(|inner| inner.strip_suffix('\''))
+            .map(|inner| inner.replace("''", "'"))
+            .unwrap_or_else(|| value.to_string());
+        !path.is_empty() && !Path::new(&path).exists()
+    })
+}
@@ -0,0 +1,466 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+const NBSP: char = '\u{a0}';
+const NARROW_NBSP: char = '\u{202f}';
+/// Characters that can separate digit groups or decimals in some locale.
+const SEPARATORS: [char; 7] = ['.', ',', '\'', '\u{2019}', ' ', NBSP, NARROW_NBSP];
+
+/// How numbers in the text are written. Independent of the UI language: a
+/// user with an English UI can read German documents.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct NumberSettings {
+    /// BCP 47 tag such as `en-US`, `de-DE` or `fr-CH`. `None` leaves
+    /// numbers as written.
+    pub locale: Option<String>,
+    /// Read unit symbols after a number as words, in the locale's language
+    /// (`5 km` as `5 kilometers`). English, German, French and Spanish only.
+    pub verbalize_units: bool,
+}
+
+impl Default for NumberSettings {
+    fn default() -> Self {
+        Self {
+            locale: None,
+            verbalize_units: true,
+        }
+    }
+}
+
+impl NumberSettings {
+    pub fn validate(&self) -> Result<()> {
+        if let Some(locale) = self.locale.as_deref() {
+            NumberConventions::for_locale(locale)?;
+        }
+        Ok(())
+    }
+}
+
+/// Separators of one locale, and the language unit names are read in.
+#[derive(Clone, Copy)]
+struct NumberConventions {
+    decimal: char,
+    groups: &'static [char],
+    unit_language: Option<usize>,
+}
+
+impl NumberConventions {
+    fn for_locale(locale: &str) -> Result<Self> {
+        let normalized = locale.trim().replace('_', "-").to_ascii_lowercase();
+        let mut parts = normalized.split('-');
+        let language = parts.next().unwrap_or_default();
+        let region = parts.find(|part| part.len() == 2).unwrap_or_default();
+        let (decimal, groups): (char, &'static [char]) = match (language, region) {
+            ("de" | "it" | "rm", "ch" | "li") => ('.', &['\'', '\u{2019}']),
+            ("es", "mx" | "us" | "pr" | "gt" | "hn" | "ni" | "pa" | "sv" | "do") => ('.', &[',']),
+            ("en" | "ja" | "zh" | "ko" | "he" | "th" | "hi" | "ms" | "ga" | "mt", _) => ('.', &[',']),
+            ("de" | "nl" | "it" | "es" | "pt" | "id" | "tr" | "da" | "el" | "ro" | "hr" | "sl" | "sr", _) => {
+                (',', &['.'])
+            }
+            (
+                "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "nb" | "nn" | "no" | "fi" | "uk" | "hu" | "bg" | "lt" | "lv"
+                | "et",
+                _,
+            ) => (',', &[' ', NBSP, NARROW_NBSP]),
+            _ => return Err(anyhow!("Unsupported number locale {}", locale.trim())),
+        };
+        Ok(Self {
+            decimal,
+            groups,
+            unit_language: UNIT_LANGUAGES.iter().position(|known| *known == language),
+        })
+    }
+}
+
+/// Rewrites numbers written in `settings.locale` so the voice cannot misread
+/// them: group separators are dropped and the decimal separator is kept, so
+/// `1.234,5` in German becomes `1234,5` while `1.234` in US English stays a
+/// decimal. Runs that do not follow the locale (dates, versions, phone
+/// numbers) are left alone. Unit symbols after numbers become words when
+/// enabled.
+pub fn normalize_numbers(text: &str, settings: &NumberSettings) -> String {
+    let Some(conventions) = settings
+        .locale
+        .as_deref()
+        .and_then(|locale| NumberConventions::for_locale(locale).ok())
+    else {
+        return text.to_string();
+    };
+    let units = settings.verbalize_units.then_some(conventions.unit_language).flatten();
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut idx = 0;
+    while idx < chars.len() {
+        let starts_number = chars[idx].is_ascii_digit() && (idx == 0 || !chars[idx - 1].is_alphanumeric());
+        if !starts_number {
+            out.push(chars[idx]);
+            idx += 1;
+            continue;
+        }
+
+        let start = idx;
+        while idx < chars.len() {
+            let separated_digit =
+                SEPARATORS.contains(&chars[idx]) && chars.get(idx + 1).is_some_and(char::is_ascii_digit);
+            if chars[idx].is_ascii_digit() {
+                idx += 1;
+            } else if separated_digit {
+                idx += 2;
+            } else {
+                break;
+            }
+        }
+        let run: String = chars[start..idx].iter().collect();
+        let number = canonical_number(&run, conventions).unwrap_or(run);
+        out.push_str(&number);
+
+        if let Some(language) = units {
+            if let Some((unit_len, name)) = unit_after(&chars[idx..], &number, conventions, language) {
+                out.push(' ');
+                out.push_str(name);
+                idx += unit_len;
+            }
+        }
+    }
+    out
+}
+
+/// `run` without group separators, or `None` when it is not a number in
+/// this locale or is already canonical.
+fn canonical_number(run: &str, conventions: NumberConventions) -> Option<String> {
+    let mut segments = vec![String::new()];
+    let mut separators = Vec::new();
+    for ch in run.chars() {
+        if ch.is_ascii_digit() {
+            segments.last_mut()?.push(ch);
+        } else {
+            separators.push(ch);
+            segments.push(String::new());
+        }
+    }
+    if separators.is_empty() {
+        return None;
+    }
+
+    let has_decimal = separators.last() == Some(&conventions.decimal)
+        && separators.iter().filter(|sep| **sep == conventions.decimal).count() == 1;
+    let fraction = has_decimal.then(|| segments.pop()).flatten();
+    if has_decimal {
+        separators.pop();
+    }
+    if let Some(group) = separators.first() {
+        let grouped = conventions.groups.contains(group)
+            && separators.iter().all(|sep| sep == group)
+            && (1..=3).contains(&segments[0].len())
+            && segments[1..].iter().all(|segment| segment.len() == 3);
+        if !grouped {
+            return None;
+        }
+    }
+
+    let mut number = segments.concat();
+    if let Some(fraction) = fraction {
+        number.push(conventions.decimal);
+        number.push_str(&fraction);
+    }
+    (number != run).then_some(number)
+}
+
+/// A unit symbol right after a number (optionally after one space), as
+/// `(chars consumed, spoken name)`.
+fn unit_after(
+    rest: &[char],
+    number: &str,
+    conventions: NumberConventions,
+    language: usize,
+) -> Option<(usize, &'static str)> {
+    let spaced = rest.first().is_some_and(|ch| matches!(*ch, ' ' | NBSP | NARROW_NBSP));
+    let offset = usize::from(spaced);
+    let candidates = &rest[offset..];
+    UNITS.iter().find_map(|(symbol, names)| {
+        let symbol_len = symbol.chars().count();
+        let matches = candidates.len() >= symbol_len
+            && candidates.iter().zip(symbol.chars()).all(|(ch, expected)| *ch == expected)
+            && !candidates.get(symbol_len).is_some_and(|next| next.is_alphanumeric());
+        // `5m` is as likely to be five million as five meters.
+        let attached_ok = spaced || symbol_len > 1 || !symbol.chars().all(char::is_alphabetic);
+        if !matches || !attached_ok {
+            return None;
+        }
+        let (singular, plural) = names[language];
+        let name = if is_singular(number, conventions, language) { singular } else { plural };
+        Some((offset + symbol_len, name))
+    })
+}
+
+/// English, German and Spanish use the singular for exactly one; French for
+/// anything below two.
+fn is_singular(number: &str, conventions: NumberConventions, language: usize) -> bool {
+    let integer = number.split(conventions.decimal).next().unwrap_or(number);
+    let has_fraction = number.contains(conventions.decimal);
+    if UNIT_LANGUAGES[language] == "fr" {
+        return matches!(integer, "0" | "1");
+    }
+    integer == "1" && !has_fraction
+}
+
+const UNIT_LANGUAGES: [&str; 4] = ["en", "de", "fr", "es"];
+
+type UnitNames = [(&'static str, &'static str); 4];
+
+/// Longer symbols first, so `km/h` wins over `km` and `kWh` over `kW`.
+const UNITS: [(&str, UnitNames); 28] = [
+    (
+        "km/h",
+        [
+            ("kilometer per hour", "kilometers per hour"),
+            ("Kilometer pro Stunde", "Kilometer pro Stunde"),
+            ("kilomètre par heure", "kilomètres par heure"),
+            ("kilómetro por hora", "kilómetros por hora"),
+        ],
+    ),
+    (
+        "m/s",
+        [
+            ("meter per second", "meters per second"),
+            ("Meter pro Sekunde", "Meter pro Sekunde"),
+            ("mètre par seconde", "mètres par seconde"),
+            ("metro por segundo", "metros por segundo"),
+        ],
+    ),
+    (
+        "mph",
+        [
+            ("mile per hour", "miles per hour"),
+            ("Meile pro Stunde", "Meilen pro Stunde"),
+            ("mile par heure", "miles par heure"),
+            ("milla por hora", "millas por hora"),
+        ],
+    ),
+    (
+        "kWh",
+        [
+            ("kilowatt hour", "kilowatt hours"),
+            ("Kilowattstunde", "Kilowattstunden"),
+            ("kilowattheure", "kilowattheures"),
+            ("kilovatio hora", "kilovatios hora"),
+        ],
+    ),
+    (
+        "GHz",
+        [
+            ("gigahertz", "gigahertz"),
+            ("Gigahertz", "Gigahertz"),
+            ("gigahertz", "gigahertz"),
+            ("gigahercio", "gigahercios"),
+        ],
+    ),
+    (
+        "MHz",
+        [
+            ("megahertz", "megahertz"),
+            ("Megahertz", "Megahertz"),
+            ("mégahertz", "mégahertz"),
+            ("megahercio", "megahercios"),
+        ],
+    ),
+    (
+        "min",
+        [
+            ("minute", "minutes"),
+            ("Minute", "Minuten"),
+            ("minute", "minutes"),
+            ("minuto", "minutos"),
+        ],
+    ),
+    (
+        "°C",
+        [
+            ("degree Celsius", "degrees Celsius"),
+            ("Grad Celsius", "Grad Celsius"),
+            ("degré Celsius", "degrés Celsius"),
+            ("grado Celsius", "grados Celsius"),
+        ],
+    ),
+    (
+        "°F",
+        [
+            ("degree Fahrenheit", "degrees Fahrenheit"),
+            ("Grad Fahrenheit", "Grad Fahrenheit"),
+            ("degré Fahrenheit", "degrés Fahrenheit"),
+            ("grado Fahrenheit", "grados Fahrenheit"),
+        ],
+    ),
+    (
+        "km",
+        [
+            ("kilometer", "kilometers"),
+            ("Kilometer", "Kilometer"),
+            ("kilomètre", "kilomètres"),
+            ("kilómetro", "kilómetros"),
+        ],
+    ),
+    (
+        "cm",
+        [
+            ("centimeter", "centimeters"),
+            ("Zentimeter", "Zentimeter"),
+            ("centimètre", "centimètres"),
+            ("centímetro", "centímetros"),
+        ],
+    ),
+    (
+        "mm",
+        [
+            ("millimeter", "millimeters"),
+            ("Millimeter", "Millimeter"),
+            ("millimètre", "millimètres"),
+            ("milímetro", "milímetros"),
+        ],
+    ),
+    (
+        "kg",
+        [
+            ("kilogram", "kilograms"),
+            ("Kilogramm", "Kilogramm"),
+            ("kilogramme", "kilogrammes"),
+            ("kilogramo", "kilogramos"),
+        ],
+    ),
+    (
+        "mg",
+        [
+            ("milligram", "milligrams"),
+            ("Milligramm", "Milligramm"),
+            ("milligramme", "milligrammes"),
+            ("miligramo", "miligramos"),
+        ],
+    ),
+    (
+        "ml",
+        [
+            ("milliliter", "milliliters"),
+            ("Milliliter", "Milliliter"),
+            ("millilitre", "millilitres"),
+            ("mililitro", "mililitros"),
+        ],
+    ),
+    (
+        "mL",
+        [
+            ("milliliter", "milliliters"),
+            ("Milliliter", "Milliliter"),
+            ("millilitre", "millilitres"),
+            ("mililitro", "mililitros"),
+        ],
+    ),
+    (
+        "kW",
+        [
+            ("kilowatt", "kilowatts"),
+            ("Kilowatt", "Kilowatt"),
+            ("kilowatt", "kilowatts"),
+            ("kilovatio", "kilovatios"),
+        ],
+    ),
+    (
+        "Hz",
+        [
+            ("hertz", "hertz"),
+            ("Hertz", "Hertz"),
+            ("hertz", "hertz"),
+            ("hercio", "hercios"),
+        ],
+    ),
+    (
+        "kB",
+        [
+            ("kilobyte", "kilobytes"),
+            ("Kilobyte", "Kilobyte"),
+            ("kilooctet", "kilooctets"),
+            ("kilobyte", "kilobytes"),
+        ],
+    ),
+    (
+        "MB",
+        [
+            ("megabyte", "megabytes"),
+            ("Megabyte", "Megabyte"),
+            ("mégaoctet", "mégaoctets"),
+            ("megabyte", "megabytes"),
+        ],
+    ),
+    (
+        "GB",
+        [
+            ("gigabyte", "gigabytes"),
+            ("Gigabyte", "Gigabyte"),
+            ("gigaoctet", "gigaoctets"),
+            ("gigabyte", "gigabytes"),
+        ],
+    ),
+    (
+        "TB",
+        [
+            ("terabyte", "terabytes"),
+            ("Terabyte", "Terabyte"),
+            ("téraoctet", "téraoctets"),
+            ("terabyte", "terabytes"),
+        ],
+    ),
+    (
+        "%",
+        [
+            ("percent", "percent"),
+            ("Prozent", "Prozent"),
+            ("pour cent", "pour cent"),
+            ("por ciento", "por ciento"),
+        ],
+    ),
+    (
+        "m",
+        [
+            ("meter", "meters"),
+            ("Meter", "Meter"),
+            ("mètre", "mètres"),
+            ("metro", "metros"),
+        ],
+    ),
+    (
+        "g",
+        [
+            ("gram", "grams"),
+            ("Gramm", "Gramm"),
+            ("gramme", "grammes"),
+            ("gramo", "gramos"),
+        ],
+    ),
+    (
+        "l",
+        [
+            ("liter", "liters"),
+            ("Liter", "Liter"),
+            ("litre", "litres"),
+            ("litro", "litros"),
+        ],
+    ),
+    (
+        "L",
+        [
+            ("liter", "liters"),
+            ("Liter", "Liter"),
+            ("litre", "litres"),
+            ("litro", "litros"),
+        ],
+    ),
+    (
+        "h",
+        [
+            ("hour", "hours"),
+            ("Stunde", "Stunden"),
+            ("heure", "heures"),
+            ("hora", "horas"),
+        ],
+    ),
+];
@@ -0,0 +1,151 @@
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+
+const CACHE_FILE_EXTENSION: &str = "pcm";
+/// Marks a cache file; followed by the key length, the key, then PCM.
+const CACHE_FILE_MAGIC: &[u8; 4] = b"VRPC";
+
+/// Synthesized PCM per chunk on disk, so re-reading the same sentence with
+/// the same voice skips generation. Least recently used entries are evicted
+/// once the cache grows past its size limit.
+///
+/// Keys are hashed into file names and stored in full in each file, so a
+/// hash collision reads as a miss rather than the wrong audio.
+pub struct PcmCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    total_bytes: Mutex<u64>,
+}
+
+impl PcmCache {
+    pub fn open(dir: &Path, max_bytes: u64) -> Result<Self> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create audio cache directory {}", dir.display()))?;
+        let total_bytes = cache_files(dir).iter().map(|(_, len, _)| len).sum();
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            max_bytes,
+            total_bytes: Mutex::new(total_bytes),
+        })
+    }
+
+    /// Key for a chunk from everything that changes its audio.
+    pub fn key(model_id: &str, voice_key: &str, sample_rate: u32, text: &str) -> String {
+        format!("{model_id}\u{1f}{voice_key}\u{1f}{sample_rate}\u{1f}{}", text.trim())
+    }
+
+    /// Cached PCM for `key`; refreshes the entry's last use.
+    pub fn get(&self, key: &str) -> Option<Vec<i16>> {
+        let path = self.path_for(key);
+        let mut bytes = Vec::new();
+        File::open(&path).ok()?.read_to_end(&mut bytes).ok()?;
+        let pcm_bytes = strip_header(&bytes, key)?;
+        if let Ok(file) = File::options().write(true).open(&path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(
+            pcm_bytes
+                .chunks_exact(2)
+                .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+                .collect(),
+        )
+    }
+
+    /// Stores `pcm` under `key`. Failures are ignored: the cache only saves
+    /// time and a full disk should not stop speech.
+    pub fn put(&self, key: &str, pcm: &[i16]) {
+        if pcm.is_empty() {
+            return;
+        }
+        let mut bytes = Vec::with_capacity(CACHE_FILE_MAGIC.len() + 4 + key.len() + pcm.len() * 2);
+        bytes.extend_from_slice(CACHE_FILE_MAGIC);
+        bytes.extend_from_slice(&(key.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(key.as_bytes());
+        for sample in pcm {
+            bytes.extend_from_slice(&sample.to_le_bytes());
+        }
+        if bytes.len() as u64 > self.max_bytes {
+            return;
+        }
+
+        let path = self.path_for(key);
+        let previous_len = std::fs::metadata(&path).map_or(0, |metadata| metadata.len());
+        // Written aside and renamed so a concurrent read never sees half a file;
+        // storage maintenance removes the `.tmp` if the app dies in between.
+        let temp_path = path.with_extension(format!("{CACHE_FILE_EXTENSION}.{:08x}.tmp", rand::random::<u32>()));
+        let written = File::create(&temp_path)
+            .and_then(|mut file| file.write_all(&bytes))
+            .and_then(|()| std::fs::rename(&temp_path, &path));
+        if written.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+            return;
+        }
+
+        let Ok(mut total_bytes) = self.total_bytes.lock() else {
+            return;
+        };
+        *total_bytes = (*total_bytes + bytes.len() as u64).saturating_sub(previous_len);
+        if *total_bytes > self.max_bytes {
+            *total_bytes = self.evict();
+        }
+    }
+
+    /// Removes the least recently used files until the cache is under 90% of
+    /// its limit, so the next few writes do not each trigger a scan. Returns
+    /// the new total size.
+    fn evict(&self) -> u64 {
+        let mut files = cache_files(&self.dir);
+        files.sort_by_key(|(_, _, modified)| *modified);
+        let mut total: u64 = files.iter().map(|(_, len, _)| len).sum();
+        let target = self.max_bytes / 10 * 9;
+        for (path, len, _) in files {
+            if total <= target {
+                break;
+            }
+            if std::fs::remove_file(&path).is_ok() {
+                total -= len;
+            }
+        }
+        total
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}.{CACHE_FILE_EXTENSION}", fnv1a(key)))
+    }
+}
+
+/// PCM bytes of a cache file, if it was written for `key`.
+fn strip_header<'a>(bytes: &'a [u8], key: &str) -> Option<&'a [u8]> {
+    let rest = bytes.strip_prefix(CACHE_FILE_MAGIC.as_slice())?;
+    let (len, rest) = rest.split_first_chunk::<4>()?;
+    let (stored_key, pcm) = rest.split_at_checked(u32::from_le_bytes(*len) as usize)?;
+    (stored_key == key.as_bytes()).then_some(pcm)
+}
+
+/// Cache files in `dir` with their size and last use.
+fn cache_files(dir: &Path) -> Vec<(PathBuf, u64, SystemTime)> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(CACHE_FILE_EXTENSION) {
+                return None;
+            }
+            let metadata = std::fs::metadata(&path).ok()?;
+            Some((path, metadata.len(), metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH)))
+        })
+        .collect()
+}
+
+/// FNV-1a; stable across builds, unlike the std hasher.
+fn fnv1a(text: &str) -> u64 {
+    text.bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
@@ -0,0 +1,176 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+const MIN_CEILING_DB: f32 = -12.0;
+/// Gain is pulled down this long before a peak so it never steps.
+#[cfg(feature = "build-base")]
+const ATTACK_MS: u32 = 2;
+/// Time constant of the recovery back to unity gain after a peak.
+#[cfg(feature = "build-base")]
+const RELEASE_MS: f32 = 80.0;
+/// Gain above this counts as unity: it does not show in the report, and the
+/// release snaps to 1.0 so the exponential tail ends.
+#[cfg(feature = "build-base")]
+const UNITY_GAIN: f32 = 0.9999;
+
+/// True-peak limiter run after the output gain, so a volume above 1.0 on a
+/// hot voice cannot clip or reach the headphones at full scale.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PeakLimiter {
+    pub enabled: bool,
+    /// Highest level let through, in dBTP. Peaks between samples count, as
+    /// they come out of the DAC above the sample values.
+    pub ceiling_db: f32,
+}
+
+impl Default for PeakLimiter {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ceiling_db: -1.0,
+        }
+    }
+}
+
+impl PeakLimiter {
+    pub fn validate(&self) -> Result<()> {
+        if !(MIN_CEILING_DB..=0.0).contains(&self.ceiling_db) {
+            return Err(anyhow!("ceiling_db must be in [{MIN_CEILING_DB}, 0.0]"));
+        }
+        Ok(())
+    }
+}
+
+/// How hard a job's audio was limited; sent with `JOB_DONE` under
+/// `metrics.limiter`.
+#[cfg(feature = "build-base")]
+#[derive(Clone, Copy, Serialize)]
+pub struct LimiterReport {
+    pub ceiling_db: f32,
+    /// Deepest gain reduction, in dB; 0 when nothing reached the ceiling.
+    pub max_gain_reduction_db: f32,
+    /// Audio played with its gain reduced, in milliseconds.
+    pub limited_ms: u64,
+}
+
+/// Limiter state carried across the chunks of one job.
+#[cfg(feature = "build-base")]
+pub struct LimiterState {
+    ceiling_db: f32,
+    /// Ceiling on the i16 sample scale.
+    ceiling: f32,
+    attack_samples: usize,
+    release: f32,
+    sample_rate: u32,
+    gain: f32,
+    /// Last two input samples of the previous chunk, for the peak between
+    /// chunks.
+    history: [f32; 2],
+    min_gain: f32,
+    limited_samples: u64,
+}
+
+#[cfg(feature = "build-base")]
+impl LimiterState {
+    pub fn new(limiter: PeakLimiter, sample_rate: u32) -> Self {
+        let rate = sample_rate.max(1);
+        Self {
+            ceiling_db: limiter.ceiling_db,
+            ceiling: i16::MAX as f32 * 10f32.powf(limiter.ceiling_db / 20.0),
+            attack_samples: ((rate * ATTACK_MS / 1000) as usize).max(1),
+            release: (-1000.0 / (RELEASE_MS * rate as f32)).exp(),
+            sample_rate: rate,
+            gain: 1.0,
+            history: [0.0; 2],
+            min_gain: 1.0,
+            limited_samples: 0,
+        }
+    }
+
+    /// Limits `samples`, already scaled by the output gain but not yet
+    /// clamped, and quantizes them.
+    pub fn process(&mut self, samples: &[f32]) -> Vec<i16> {
+        let len = samples.len();
+        if len == 0 {
+            return Vec::new();
+        }
+        let at = |index: isize| -> f32 {
+            if index < 0 {
+                self.history[(index + 2) as usize]
+            } else {
+                samples[(index as usize).min(len - 1)]
+            }
+        };
+        // The gain each sample needs on its own, from the true peak between it
+        // and the previous sample.
+        let mut envelope: Vec<f32> = (0..len as isize)
+            .map(|index| {
+                let peak = true_peak(at(index - 2), at(index - 1), at(index), at(index + 1));
+                if peak > self.ceiling {
+                    self.ceiling / peak
+                } else {
+                    1.0
+                }
+            })
+            .collect();
+        // Walking back from each peak, let the gain rise by at most one attack
+        // step per sample, so it is already down when the peak arrives.
+        let step = 1.0 / self.attack_samples as f32;
+        for index in (0..len - 1).rev() {
+            envelope[index] = envelope[index].min(envelope[index + 1] + step);
+        }
+
+        let output = samples
+            .iter()
+            .zip(&envelope)
+            .map(|(sample, target)| {
+                let target = target.min(1.0);
+                self.gain = if target < self.gain {
+                    target
+                } else {
+                    target - (target - self.gain) * self.release
+                };
+                if self.gain > UNITY_GAIN {
+                    self.gain = 1.0;
+                } else {
+                    self.limited_samples += 1;
+                }
+                self.min_gain = self.min_gain.min(self.gain);
+                // Truncated rather than rounded, so the ceiling itself is never exceeded.
+                (sample * self.gain).clamp(-self.ceiling, self.ceiling) as i16
+            })
+            .collect();
+        self.history = if len >= 2 {
+            [samples[len - 2], samples[len - 1]]
+        } else {
+            [self.history[1], samples[0]]
+        };
+        output
+    }
+
+    pub fn report(&self) -> LimiterReport {
+        LimiterReport {
+            ceiling_db: self.ceiling_db,
+            max_gain_reduction_db: if self.min_gain < 1.0 {
+                -20.0 * self.min_gain.log10()
+            } else {
+                0.0
+            },
+            limited_ms: self.limited_samples * 1000 / self.sample_rate as u64,
+        }
+    }
+}
+
+/// Largest magnitude of `p2` and of the waveform between `p1` and `p2`,
+/// estimated at three points with a Catmull-Rom spline.
+#[cfg(feature = "build-base")]
+fn true_peak(p0: f32, p1: f32, p2: f32, p3: f32) -> f32 {
+    [0.25f32, 0.5, 0.75].iter().fold(p2.abs(), |peak, t| {
+        let value = 0.5
+            * (2.0 * p1
+                + (p2 - p0) * t
+                + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t * t
+                + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t * t * t);
+        peak.max(value.abs())
+    })
+}
@@ -0,0 +1,99 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+const MAX_PHRASE_CHARS: usize = 2000;
+pub const CLIPBOARD_PLACEHOLDER: &str = "{clipboard}";
+
+/// A saved utterance that can be spoken with one command or hotkey.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct QuickPhrase {
+    /// Empty when saving a new phrase; an id is assigned on save.
+    #[serde(default)]
+    pub id: String,
+    pub label: String,
+    pub text: String,
+    /// Voice to speak with; `None` uses the selected voice.
+    #[serde(default)]
+    pub voice_id: Option<String>,
+    #[serde(default)]
+    pub hotkey: Option<String>,
+}
+
+impl QuickPhrase {
+    pub fn validate(&self) -> Result<()> {
+        if self.label.trim().is_empty() {
+            return Err(anyhow!("Phrase label cannot be empty"));
+        }
+        let text = self.text.trim();
+        if text.is_empty() {
+            return Err(anyhow!("Phrase text cannot be empty"));
+        }
+        if text.chars().count() > MAX_PHRASE_CHARS {
+            return Err(anyhow!("Phrase text is limited to {MAX_PHRASE_CHARS} characters"));
+        }
+        Ok(())
+    }
+}
+
+/// Expands `{time}`, `{date}` and `{clipboard}`; other braces are left as
+/// typed. The clipboard goes in last so its own contents are not expanded.
+pub fn expand_placeholders(text: &str, now: &DateTime<Local>, clipboard: Option<&str>) -> String {
+    text.replace("{time}", &now.format("%-I:%M %p").to_string())
+        .replace("{date}", &now.format("%A, %B %-d, %Y").to_string())
+        .replace(CLIPBOARD_PLACEHOLDER, clipboard.unwrap_or_default())
+}
+
+/// Saved phrases, in the order they were first saved.
+#[derive(Default, Serialize, Deserialize)]
+pub struct PhraseStore {
+    phrases: Vec<QuickPhrase>,
+}
+
+impl PhraseStore {
+    /// A missing file is an empty store. A corrupt one is an error, so a
+    /// later save does not silently discard the user's phrases.
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(body) => serde_json::from_str(&body).with_context(|| format!("Failed to parse {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(anyhow!("Failed to read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create phrases directory {}", parent.display()))?;
+        }
+        let serialized = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, serialized).with_context(|| format!("Failed to write phrases file {}", path.display()))
+    }
+
+    pub fn phrases(&self) -> &[QuickPhrase] {
+        &self.phrases
+    }
+
+    pub fn get(&self, id: &str) -> Option<&QuickPhrase> {
+        self.phrases.iter().find(|phrase| phrase.id == id)
+    }
+
+    /// Replaces the phrase with the same id, or appends it. Returns the
+    /// replaced phrase.
+    pub fn upsert(&mut self, phrase: QuickPhrase) -> Option<QuickPhrase> {
+        match self.phrases.iter_mut().find(|existing| existing.id == phrase.id) {
+            Some(existing) => Some(std::mem::replace(existing, phrase)),
+            None => {
+                self.phrases.push(phrase);
+                None
+            }
+        }
+    }
+
+    pub fn remove(&mut self, id: &str) -> Option<QuickPhrase> {
+        let index = self.phrases.iter().position(|phrase| phrase.id == id)?;
+        Some(self.phrases.remove(index))
+    }
+}
@@ -0,0 +1,377 @@
+use std::io::{ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Longest message read from one writer; the rest is discarded.
+const MAX_MESSAGE_BYTES: u64 = 1024 * 1024;
+#[cfg(unix)]
+const PIPE_FILE_NAME: &str = "voicereader.pipe";
+#[cfg(all(unix, feature = "build-full"))]
+const API_SOCKET_FILE_NAME: &str = "voicereader-api.sock";
+/// Leading character that marks a message as a notification, spoken over a
+/// normal read instead of queued behind it. `printf '\aBuild finished'`
+/// writes one, as does `voicereader notify "Build finished"`.
+const NOTIFY_MARKER: char = '\u{7}';
+/// How long `send_notification` waits for a listener busy with a message.
+const SEND_TIMEOUT_MS: u64 = 10_000;
+/// How long `is_listening` waits; a running app answers at once now that
+/// messages are read aloud off the listener's thread.
+const PROBE_TIMEOUT_MS: u64 = 300;
+const SEND_RETRY_MS: u64 = 100;
+/// How long a FIFO without a reader is retried. The listener reopens it
+/// right after each message, so a longer wait means the app is gone and
+/// left the FIFO behind.
+const READERLESS_GRACE_MS: u64 = 300;
+
+/// Local text endpoint for scripts and other apps, without a TCP port: a
+/// FIFO on Linux and macOS, a named pipe on Windows. Everything a writer sends
+/// before closing the pipe is read aloud as one message, so
+/// `echo "Build finished" > "$XDG_RUNTIME_DIR/voicereader.pipe"` just works.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PipeEndpointSettings {
+    pub enabled: bool,
+}
+
+pub enum PipeMessage {
+    Read(String),
+    Notify(String),
+}
+
+/// Where the endpoint listens: on Unix the runtime directory when there is
+/// one, else the per-user temp directory (macOS) or `/tmp` with the user id in
+/// the name; on Windows a per-user pipe name. Needs no app handle, so the
+/// `notify` command line finds the same place.
+#[cfg(unix)]
+pub fn endpoint_path() -> PathBuf {
+    runtime_path(PIPE_FILE_NAME, "pipe")
+}
+
+/// Unix socket the Full build's engine serves its HTTP and WebSocket API on,
+/// besides its TCP port, next to the text endpoint. Windows has no
+/// equivalent: the engine's server cannot listen on a named pipe.
+#[cfg(all(unix, feature = "build-full"))]
+pub fn api_socket_path() -> PathBuf {
+    runtime_path(API_SOCKET_FILE_NAME, "api.sock")
+}
+
+#[cfg(unix)]
+fn runtime_path(file_name: &str, fallback_extension: &str) -> PathBuf {
+    match std::env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from).filter(|dir| dir.is_dir()) {
+        Some(dir) => dir.join(file_name),
+        None => std::env::temp_dir().join(format!("voicereader-{}.{fallback_extension}", unsafe { libc::getuid() })),
+    }
+}
+
+#[cfg(windows)]
+pub fn endpoint_path() -> PathBuf {
+    let user = std::env::var("USERNAME").unwrap_or_else(|_| "user".to_string());
+    PathBuf::from(format!(r"\\.\pipe\voicereader-{user}"))
+}
+
+/// Writes a notification to a running app's endpoint.
+pub fn send_notification(path: &Path, text: &str) -> Result<()> {
+    send_message(path, &format!("{NOTIFY_MARKER}{text}"), SEND_TIMEOUT_MS)
+}
+
+/// Writes text to a running app's endpoint to be read like any pipe message.
+pub fn forward_text(path: &Path, text: &str) -> Result<()> {
+    send_message(path, text, SEND_TIMEOUT_MS)
+}
+
+/// Whether a running app listens on the endpoint. Checking sends it an
+/// empty message, which it ignores.
+pub fn is_listening(path: &Path) -> bool {
+    send_message(path, "", PROBE_TIMEOUT_MS).is_ok()
+}
+
+fn send_message(path: &Path, message: &str, timeout_ms: u64) -> Result<()> {
+    let started = Instant::now();
+    let mut pipe = loop {
+        let err = match platform::connect(path) {
+            Ok(pipe) => break pipe,
+            Err(err) => err,
+        };
+        let patience = if platform::is_readerless(&err) {
+            READERLESS_GRACE_MS.min(timeout_ms)
+        } else {
+            timeout_ms
+        };
+        // Not found means nothing listens; otherwise the listener is between
+        // two messages.
+        if err.kind() == ErrorKind::NotFound || started.elapsed() >= Duration::from_millis(patience) {
+            return Err(anyhow!(
+                "VoiceReader is not listening on {} ({err}). Enable the pipe endpoint in settings.",
+                path.display()
+            ));
+        }
+        std::thread::sleep(Duration::from_millis(SEND_RETRY_MS));
+    };
+    pipe.write_all(message.as_bytes())
+        .with_context(|| format!("Failed to write to {}", path.display()))
+}
+
+/// A running endpoint. Messages are handed to the callback on a thread of
+/// their own, one at a time and in arrival order, so the listener is back
+/// waiting for the next writer while one is being read aloud.
+pub struct PipeEndpoint {
+    path: PathBuf,
+    stop: Arc<AtomicBool>,
+}
+
+impl PipeEndpoint {
+    pub fn start(path: PathBuf, on_message: impl Fn(PipeMessage) + Send + 'static) -> Result<Self> {
+        let listener = platform::Listener::bind(&path)?;
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = stop.clone();
+        let (messages, received) = mpsc::channel();
+        std::thread::Builder::new()
+            .name("pipe-endpoint-reader".to_string())
+            .spawn(move || received.into_iter().for_each(on_message))
+            .context("Failed to start pipe endpoint thread")?;
+        std::thread::Builder::new()
+            .name("pipe-endpoint".to_string())
+            .spawn(move || {
+                listen(listener, &stop_flag, |message| {
+                    let _ = messages.send(message);
+                })
+            })
+            .context("Failed to start pipe endpoint thread")?;
+        Ok(Self { path, stop })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Stops listening. A writer connecting at the same moment may still be
+    /// read before the thread exits.
+    pub fn stop(self) {
+        self.stop.store(true, Ordering::SeqCst);
+        platform::wake(&self.path);
+    }
+}
+
+fn listen(mut listener: platform::Listener, stop: &AtomicBool, on_message: impl Fn(PipeMessage)) {
+    while !stop.load(Ordering::SeqCst) {
+        let connection = listener.accept();
+        if stop.load(Ordering::SeqCst) {
+            break;
+        }
+        let mut reader = match connection {
+            Ok(reader) => reader,
+            Err(err) => {
+                eprintln!("Pipe endpoint stopped: {err:#}");
+                break;
+            }
+        };
+        let mut message = Vec::new();
+        let read = (&mut reader).take(MAX_MESSAGE_BYTES).read_to_end(&mut message);
+        // Drain an oversized message so the writer is not left blocked.
+        let _ = std::io::copy(&mut reader, &mut std::io::sink());
+        drop(reader);
+        if let Err(err) = read {
+            eprintln!("Failed to read from pipe endpoint: {err}");
+            continue;
+        }
+        let text = String::from_utf8_lossy(&message);
+        let text = text.trim();
+        if text.trim_start_matches(NOTIFY_MARKER).trim().is_empty() {
+            continue;
+        }
+        on_message(match text.strip_prefix(NOTIFY_MARKER) {
+            Some(notification) => PipeMessage::Notify(notification.trim().to_string()),
+            None => PipeMessage::Read(text.to_string()),
+        });
+    }
+}
+
+#[cfg(unix)]
+mod platform {
+    use std::ffi::CString;
+    use std::fs::{File, OpenOptions};
+    use std::os::unix::ffi::OsStrExt;
+    use std::os::unix::fs::{FileTypeExt, MetadataExt, OpenOptionsExt};
+    use std::os::unix::io::AsRawFd;
+    use std::path::{Path, PathBuf};
+
+    use anyhow::{anyhow, Context, Result};
+
+    pub struct Listener {
+        path: PathBuf,
+    }
+
+    impl Listener {
+        pub fn bind(path: &Path) -> Result<Self> {
+            let uid = unsafe { libc::getuid() };
+            match std::fs::symlink_metadata(path) {
+                // Ours and owner-only, as created below.
+                Ok(meta) if meta.file_type().is_fifo() && meta.uid() == uid && meta.mode() & 0o077 == 0 => {}
+                Ok(meta) if !meta.file_type().is_fifo() => {
+                    return Err(anyhow!("{} exists and is not a pipe", path.display()))
+                }
+                // The fallback path is predictable, so another user may have
+                // made it first to read what is written here.
+                Ok(meta) if meta.uid() != uid => {
+                    return Err(anyhow!("Pipe {} belongs to another user", path.display()))
+                }
+                Ok(_) => {
+                    std::fs::remove_file(path)
+                        .with_context(|| format!("Failed to replace pipe {} open to other users", path.display()))?;
+                    create_fifo(path)?;
+                }
+                Err(_) => {
+                    if let Some(parent) = path.parent() {
+                        std::fs::create_dir_all(parent)
+                            .with_context(|| format!("Failed to create {}", parent.display()))?;
+                    }
+                    create_fifo(path)?;
+                }
+            }
+            Ok(Self {
+                path: path.to_path_buf(),
+            })
+        }
+
+        /// Blocks until a writer opens the FIFO.
+        pub fn accept(&mut self) -> Result<File> {
+            File::open(&self.path).with_context(|| format!("Failed to open pipe {}", self.path.display()))
+        }
+    }
+
+    fn create_fifo(path: &Path) -> Result<()> {
+        let c_path = CString::new(path.as_os_str().as_bytes()).context("Pipe path contains a NUL byte")?;
+        // Owner-only: anyone who can write here can make the app speak.
+        if unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) } != 0 {
+            return Err(anyhow!(
+                "Failed to create pipe {}: {}",
+                path.display(),
+                std::io::Error::last_os_error()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Opens the FIFO for writing without waiting for a reader: with none,
+    /// this fails at once.
+    pub fn connect(path: &Path) -> std::io::Result<File> {
+        let pipe = OpenOptions::new().write(true).custom_flags(libc::O_NONBLOCK).open(path)?;
+        // Back to blocking writes, so a full pipe waits instead of failing.
+        let fd = pipe.as_raw_fd();
+        unsafe {
+            let flags = libc::fcntl(fd, libc::F_GETFL);
+            libc::fcntl(fd, libc::F_SETFL, flags & !libc::O_NONBLOCK);
+        }
+        Ok(pipe)
+    }
+
+    /// A FIFO that exists but has no reader open.
+    pub fn is_readerless(err: &std::io::Error) -> bool {
+        err.raw_os_error() == Some(libc::ENXIO)
+    }
+
+    /// Unblocks a listener waiting in `accept`, then removes the FIFO so a
+    /// listener about to wait fails instead.
+    pub fn wake(path: &Path) {
+        let _ = OpenOptions::new().write(true).custom_flags(libc::O_NONBLOCK).open(path);
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::fs::{File, OpenOptions};
+    use std::os::windows::ffi::OsStrExt;
+    use std::os::windows::io::FromRawHandle;
+    use std::path::Path;
+
+    use anyhow::{anyhow, Result};
+    use windows_sys::Win32::Foundation::{
+        CloseHandle, GetLastError, ERROR_PIPE_CONNECTED, HANDLE, INVALID_HANDLE_VALUE,
+    };
+    use windows_sys::Win32::Storage::FileSystem::{FILE_FLAG_FIRST_PIPE_INSTANCE, PIPE_ACCESS_INBOUND};
+    use windows_sys::Win32::System::Pipes::{
+        ConnectNamedPipe, CreateNamedPipeW, PIPE_READMODE_BYTE, PIPE_REJECT_REMOTE_CLIENTS, PIPE_TYPE_BYTE,
+        PIPE_UNLIMITED_INSTANCES, PIPE_WAIT,
+    };
+
+    const PIPE_BUFFER_BYTES: u32 = 64 * 1024;
+
+    pub struct Listener {
+        name: Vec<u16>,
+        /// The instance clients connect to next.
+        handle: HANDLE,
+    }
+
+    // The handle is only used by the listener thread.
+    unsafe impl Send for Listener {}
+
+    impl Listener {
+        pub fn bind(path: &Path) -> Result<Self> {
+            let name: Vec<u16> = path.as_os_str().encode_wide().chain(std::iter::once(0)).collect();
+            // The first instance fails if another process already owns the name.
+            let handle = create_instance(&name, FILE_FLAG_FIRST_PIPE_INSTANCE)
+                .map_err(|err| anyhow!("Failed to create pipe {}: {err}", path.display()))?;
+            Ok(Self { name, handle })
+        }
+
+        /// Blocks until a client connects, then prepares the next instance.
+        pub fn accept(&mut self) -> Result<File> {
+            let connected = unsafe { ConnectNamedPipe(self.handle, std::ptr::null_mut()) } != 0
+                || unsafe { GetLastError() } == ERROR_PIPE_CONNECTED;
+            let connect_error = std::io::Error::last_os_error();
+            let next = create_instance(&self.name, 0).map_err(|err| anyhow!("Failed to reopen pipe: {err}"))?;
+            let current = std::mem::replace(&mut self.handle, next);
+            if !connected {
+                unsafe { CloseHandle(current) };
+                return Err(anyhow!("Pipe connection failed: {connect_error}"));
+            }
+            // A client closing its end reads as end of file.
+            Ok(unsafe { File::from_raw_handle(current as _) })
+        }
+    }
+
+    impl Drop for Listener {
+        fn drop(&mut self) {
+            unsafe { CloseHandle(self.handle) };
+        }
+    }
+
+    fn create_instance(name: &[u16], extra_flags: u32) -> std::io::Result<HANDLE> {
+        let handle = unsafe {
+            CreateNamedPipeW(
+                name.as_ptr(),
+                PIPE_ACCESS_INBOUND | extra_flags,
+                PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT | PIPE_REJECT_REMOTE_CLIENTS,
+                PIPE_UNLIMITED_INSTANCES,
+                0,
+                PIPE_BUFFER_BYTES,
+                0,
+                std::ptr::null(),
+            )
+        };
+        if handle == INVALID_HANDLE_VALUE {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok(handle)
+    }
+
+    pub fn connect(path: &Path) -> std::io::Result<File> {
+        OpenOptions::new().write(true).open(path)
+    }
+
+    /// Named pipes go away with the process that created them.
+    pub fn is_readerless(_err: &std::io::Error) -> bool {
+        false
+    }
+
+    /// Unblocks a listener waiting in `accept` by connecting to it.
+    pub fn wake(path: &Path) {
+        let _ = connect(path);
+    }
+}
@@ -0,0 +1,111 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct PracticeScore {
+    /// 0-100, word-level Dice similarity between the target and the attempt.
+    pub score: f32,
+    pub expected_words: usize,
+    pub matched_words: usize,
+    /// Target words that were not heard, in order.
+    pub missed_words: Vec<String>,
+    /// Heard words that are not part of the target, in order.
+    pub extra_words: Vec<String>,
+    /// What the attempt was scored as, recognized from the recording when
+    /// one was given.
+    pub transcript: String,
+}
+
+/// Compares a practice attempt against the target sentence.
+///
+/// Both sides are normalized (case, punctuation) and aligned with a longest
+/// common subsequence over words, so a skipped or inserted word only costs
+/// itself rather than shifting every word after it. Scripts written without
+/// spaces (CJK) are compared per character.
+pub fn score_attempt(expected: &str, heard: &str) -> PracticeScore {
+    let expected_tokens = tokenize(expected);
+    let heard_tokens = tokenize(heard);
+    let (missed_words, extra_words, matched_words) = align(&expected_tokens, &heard_tokens);
+
+    let total = expected_tokens.len() + heard_tokens.len();
+    let score = if total == 0 {
+        0.0
+    } else {
+        let dice = 2.0 * matched_words as f32 / total as f32;
+        (dice * 1000.0).round() / 10.0
+    };
+
+    PracticeScore {
+        score,
+        expected_words: expected_tokens.len(),
+        matched_words,
+        missed_words,
+        extra_words,
+        transcript: heard.trim().to_string(),
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    for word in text.split_whitespace() {
+        let mut current = String::new();
+        for ch in word.chars() {
+            if is_unspaced_script(ch) {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                tokens.push(ch.to_string());
+            } else if ch.is_alphanumeric() || ch == '\'' {
+                current.extend(ch.to_lowercase());
+            }
+        }
+        let current = current.trim_matches('\'').to_string();
+        if !current.is_empty() {
+            tokens.push(current);
+        }
+    }
+    tokens
+}
+
+pub fn is_unspaced_script(ch: char) -> bool {
+    matches!(
+        ch as u32,
+        0x3040..=0x30FF // Hiragana, Katakana
+            | 0x3400..=0x4DBF // CJK Extension A
+            | 0x4E00..=0x9FFF // CJK Unified Ideographs
+            | 0xF900..=0xFAFF // CJK Compatibility Ideographs
+    )
+}
+
+fn align(expected: &[String], heard: &[String]) -> (Vec<String>, Vec<String>, usize) {
+    let rows = expected.len();
+    let cols = heard.len();
+    let mut lcs = vec![vec![0usize; cols + 1]; rows + 1];
+    for i in (0..rows).rev() {
+        for j in (0..cols).rev() {
+            lcs[i][j] = if expected[i] == heard[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut missed = Vec::new();
+    let mut extra = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < rows && j < cols {
+        if expected[i] == heard[j] {
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            missed.push(expected[i].clone());
+            i += 1;
+        } else {
+            extra.push(heard[j].clone());
+            j += 1;
+        }
+    }
+    missed.extend(expected[i..].iter().cloned());
+    extra.extend(heard[j..].iter().cloned());
+    (missed, extra, lcs[0][0])
+}
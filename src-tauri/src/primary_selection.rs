@@ -0,0 +1,76 @@
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// A selection owner that never answers must not hold up the hotkey.
+const TOOL_TIMEOUT: Duration = Duration::from_millis(750);
+const TOOL_POLL: Duration = Duration::from_millis(10);
+
+/// Text in the PRIMARY selection, which X11 and most Wayland compositors
+/// update as soon as text is selected, so no copy has to be simulated.
+/// Read with wl-paste on Wayland and xclip or xsel on X11; `None` when the
+/// selection is empty or none of them is installed.
+pub fn read_primary_selection() -> Option<String> {
+    let mut readers: Vec<(&str, &[&str])> = Vec::new();
+    if is_wayland() {
+        readers.push(("wl-paste", &["--primary", "--no-newline", "--type", "text"]));
+    }
+    if is_x11() {
+        readers.push(("xclip", &["-o", "-selection", "primary"]));
+        readers.push(("xsel", &["--primary", "--output"]));
+    }
+    readers.into_iter().find_map(|(program, args)| {
+        let output = run_tool(program, args)?;
+        let text = String::from_utf8_lossy(&output).trim().to_string();
+        (!text.is_empty()).then_some(text)
+    })
+}
+
+/// Sends Ctrl+C to the focused window, with wtype on Wayland and xdotool on
+/// X11 (which also reaches XWayland windows). False when neither worked.
+pub fn send_copy_shortcut() -> bool {
+    (is_wayland() && run_tool("wtype", &["-M", "ctrl", "-k", "c", "-m", "ctrl"]).is_some())
+        || (is_x11() && run_tool("xdotool", &["key", "--clearmodifiers", "ctrl+c"]).is_some())
+}
+
+fn is_wayland() -> bool {
+    std::env::var_os("WAYLAND_DISPLAY").is_some_and(|display| !display.is_empty())
+}
+
+fn is_x11() -> bool {
+    std::env::var_os("DISPLAY").is_some_and(|display| !display.is_empty())
+}
+
+/// Stdout of `program` when it exits successfully within `TOOL_TIMEOUT`.
+fn run_tool(program: &str, args: &[&str]) -> Option<Vec<u8>> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    // Drained on its own thread so a large selection cannot fill the pipe and stall.
+    let stdout = child.stdout.take().map(|mut stdout| {
+        std::thread::spawn(move || {
+            let mut out = Vec::new();
+            let _ = stdout.read_to_end(&mut out);
+            out
+        })
+    });
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() < TOOL_TIMEOUT => std::thread::sleep(TOOL_POLL),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+        }
+    };
+    let output = stdout.and_then(|reader| reader.join().ok()).unwrap_or_default();
+    status.success().then_some(output)
+}
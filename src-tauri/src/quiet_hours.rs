@@ -0,0 +1,146 @@
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Datelike, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::read_queue::QueuedRead;
+use crate::schedules::{local_datetime, parse_time};
+
+/// Captures past this are refused rather than held, so a hotkey pressed all
+/// night does not wake up to an hour of audio.
+const MAX_HELD_READS: usize = 32;
+
+/// What a hotkey capture does during quiet hours.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuietHoursMode {
+    /// Held silently and read once quiet hours end.
+    Queue,
+    /// Refused with a notice.
+    Reject,
+}
+
+/// A daily window, in local time, during which hotkey captures do not play
+/// right away. A window whose end is before its start runs past midnight.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuietHoursSettings {
+    pub enabled: bool,
+    /// `HH:MM`.
+    pub start: String,
+    /// `HH:MM`.
+    pub end: String,
+    /// Days the window starts on, 0 for Monday to 6 for Sunday; empty for
+    /// every day.
+    #[serde(default)]
+    pub days: Vec<u32>,
+    pub mode: QuietHoursMode,
+}
+
+impl Default for QuietHoursSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start: "22:00".to_string(),
+            end: "07:00".to_string(),
+            days: Vec::new(),
+            mode: QuietHoursMode::Queue,
+        }
+    }
+}
+
+impl QuietHoursSettings {
+    pub fn validate(&self) -> Result<()> {
+        if parse_time(&self.start)? == parse_time(&self.end)? {
+            return Err(anyhow!("Quiet hours must start and end at different times"));
+        }
+        if let Some(day) = self.days.iter().find(|day| **day > 6) {
+            return Err(anyhow!("Quiet hours days must be 0 (Monday) to 6 (Sunday) (got {day})"));
+        }
+        Ok(())
+    }
+
+    /// End of the quiet window `now` falls in; `None` outside quiet hours or
+    /// when they are off.
+    pub fn active_until(&self, now: DateTime<Local>) -> Option<DateTime<Local>> {
+        if !self.enabled {
+            return None;
+        }
+        let (start, end) = (parse_time(&self.start).ok()?, parse_time(&self.end).ok()?);
+        let (today, time) = (now.date_naive(), now.time());
+        let started_on = if start < end {
+            (start <= time && time < end).then_some(today)?
+        } else if time >= start {
+            today
+        } else if time < end {
+            today.pred_opt()?
+        } else {
+            return None;
+        };
+        if !self.days.is_empty() && !self.days.contains(&started_on.weekday().num_days_from_monday()) {
+            return None;
+        }
+        let ends_on = if start < end { started_on } else { started_on.succ_opt()? };
+        local_datetime(ends_on, end)
+    }
+}
+
+/// Returned in place of a job id when a capture was held for later; hotkey
+/// flows treat it as success.
+#[derive(Debug)]
+pub struct HeldForQuietHours;
+
+impl fmt::Display for HeldForQuietHours {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Held until quiet hours end")
+    }
+}
+
+impl std::error::Error for HeldForQuietHours {}
+
+/// Quiet hours settings and the captures held during them. Held reads live
+/// in memory only and are dropped if the app quits first.
+#[derive(Default)]
+pub struct QuietHours {
+    settings: QuietHoursSettings,
+    held: Vec<QueuedRead>,
+}
+
+impl QuietHours {
+    pub fn settings(&self) -> QuietHoursSettings {
+        self.settings.clone()
+    }
+
+    pub fn set_settings(&mut self, settings: QuietHoursSettings) {
+        self.settings = settings;
+    }
+
+    pub fn mode(&self) -> QuietHoursMode {
+        self.settings.mode
+    }
+
+    pub fn active_until(&self, now: DateTime<Local>) -> Option<DateTime<Local>> {
+        self.settings.active_until(now)
+    }
+
+    pub fn held_count(&self) -> usize {
+        self.held.len()
+    }
+
+    /// Holds `read` until quiet hours end; returns how many are now held.
+    pub fn hold(&mut self, read: QueuedRead) -> Result<usize> {
+        if self.held.len() >= MAX_HELD_READS {
+            return Err(anyhow!("{MAX_HELD_READS} reads are already held for after quiet hours"));
+        }
+        self.held.push(read);
+        Ok(self.held.len())
+    }
+
+    /// The held reads, oldest first, once quiet hours are over at `now`.
+    pub fn release_due(&mut self, now: DateTime<Local>) -> Vec<QueuedRead> {
+        if self.held.is_empty() || self.active_until(now).is_some() {
+            return Vec::new();
+        }
+        std::mem::take(&mut self.held)
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::practice::is_unspaced_script;
+use crate::reading_order::{split_paragraphs, split_sentences};
+
+/// Typical narration pace at 1.0x for space-separated scripts.
+pub const WORDS_PER_MINUTE: f64 = 150.0;
+/// Typical narration pace at 1.0x for CJK text, in characters per second.
+const UNSPACED_CHARS_PER_SECOND: f64 = 4.0;
+/// Jobs shorter than this are too dominated by startup cost to measure RTF.
+const MIN_MEASURED_AUDIO_SECS: f64 = 2.0;
+/// Weight of the newest job in the rolling RTF.
+const RTF_SMOOTHING: f64 = 0.3;
+
+/// Rolling real-time factor (synthesis wall time / audio time at 1.0x) per
+/// model, measured from completed read jobs.
+#[derive(Default)]
+pub struct SynthesisMetrics {
+    rtf_by_model: HashMap<String, f64>,
+}
+
+impl SynthesisMetrics {
+    pub fn record(&mut self, model: &str, wall_secs: f64, audio_secs: f64) {
+        if audio_secs < MIN_MEASURED_AUDIO_SECS || wall_secs <= 0.0 {
+            return;
+        }
+        let sample = wall_secs / audio_secs;
+        self.rtf_by_model
+            .entry(model.to_string())
+            .and_modify(|rtf| *rtf = *rtf * (1.0 - RTF_SMOOTHING) + sample * RTF_SMOOTHING)
+            .or_insert(sample);
+    }
+
+    pub fn rtf(&self, model: &str) -> Option<f64> {
+        self.rtf_by_model.get(model).copied()
+    }
+}
+
+#[derive(Serialize)]
+pub struct ReadEstimate {
+    /// Listening time at the given rate.
+    pub audio_secs: f64,
+    pub chunk_count: usize,
+    /// Time to synthesize everything; playback overlaps with it.
+    pub synthesis_secs: f64,
+    pub rtf: f64,
+    /// `false` when `rtf` is the model's default because no read has been
+    /// measured yet.
+    pub rtf_measured: bool,
+}
+
+/// Estimates how long `text` takes to listen to and to synthesize.
+pub fn estimate_read(text: &str, chunk_max_chars: usize, rate: f32, rtf: f64, rtf_measured: bool) -> ReadEstimate {
+    let mut words = 0usize;
+    let mut unspaced_chars = 0usize;
+    for token in text.split_whitespace() {
+        let unspaced = token.chars().filter(|ch| is_unspaced_script(*ch)).count();
+        unspaced_chars += unspaced;
+        if unspaced < token.chars().count() && token.chars().any(char::is_alphanumeric) {
+            words += 1;
+        }
+    }
+    let natural_secs = words as f64 * 60.0 / WORDS_PER_MINUTE + unspaced_chars as f64 / UNSPACED_CHARS_PER_SECOND;
+
+    let chunk_max_chars = chunk_max_chars.max(1);
+    let chunk_count = split_paragraphs(text)
+        .iter()
+        .flat_map(|paragraph| split_sentences(paragraph))
+        .map(|sentence| sentence.chars().count().div_ceil(chunk_max_chars))
+        .sum();
+
+    ReadEstimate {
+        audio_secs: natural_secs / rate.max(0.25) as f64,
+        chunk_count,
+        synthesis_secs: natural_secs * rtf,
+        rtf,
+        rtf_measured,
+    }
+}
@@ -0,0 +1,482 @@
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::job_history::JobOrigin;
+
+/// How far playback trails the first emitted chunk (frontend prebuffer plus
+/// output latency).
+const PLAYBACK_LAG_SECS: f64 = 0.5;
+/// Characters of a queued read's text shown by `list_jobs`.
+const PREVIEW_CHARS: usize = 80;
+const TRAINING_STEP_RANGE: (f32, f32) = (0.01, 0.5);
+const TRAINING_INTERVAL_MINUTES_RANGE: (u32, u32) = (1, 240);
+const TRAINING_MAX_RATE_RANGE: (f32, f32) = (0.5, 4.0);
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadPriority {
+    #[default]
+    Normal,
+    /// Short utterances (announcements, "where am I") that interrupt a normal
+    /// read, which then resumes.
+    Interactive,
+}
+
+#[derive(Clone)]
+pub struct QueuedRead {
+    pub text: String,
+    pub priority: ReadPriority,
+    pub source: String,
+    pub origin: JobOrigin,
+    /// Voice to read with; `None` uses the selected voice.
+    pub voice_id: Option<String>,
+    /// Rate override for this read; `None` uses the configured rate.
+    pub rate: Option<f32>,
+    /// The text already went through translation and reading order, as for
+    /// the remainder of an interrupted read.
+    pub preprocessed: bool,
+    /// Expand `{time}`, `{date}` and `{clipboard}` before speaking.
+    pub expand_placeholders: bool,
+    /// Apply the reading order settings; set for selection reads.
+    pub reading_order: bool,
+}
+
+/// What the caption overlay shows: the chunk playing now and the word within it.
+#[derive(Clone, PartialEq, Serialize)]
+pub struct Caption {
+    pub job_id: String,
+    pub sentence: String,
+    pub word_index: usize,
+}
+
+/// What a selection read does when another read is playing.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueuePolicy {
+    /// Stop the playing read and read the new one; queued reads follow it.
+    #[default]
+    Interrupt,
+    /// Queue the new read after the playing one and any already queued.
+    Enqueue,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DequeueReason {
+    Started,
+    /// Removed with `cancel_job`.
+    Canceled,
+    /// Dropped with the rest of the queue when the active job was canceled.
+    Cleared,
+}
+
+/// Sent on the job event stream alongside `JOB_STARTED` and the rest.
+#[derive(Clone, Serialize)]
+#[serde(tag = "type")]
+pub enum QueueEvent {
+    #[serde(rename = "JOB_QUEUED")]
+    Queued {
+        job_id: String,
+        source: String,
+        priority: ReadPriority,
+        /// Place in the queue, 0 being next.
+        position: usize,
+    },
+    /// A read that starts gets a new job id, announced by its `JOB_STARTED`.
+    #[serde(rename = "JOB_DEQUEUED")]
+    Dequeued { job_id: String, reason: DequeueReason },
+}
+
+#[derive(Serialize)]
+pub struct QueuedJob {
+    pub job_id: String,
+    pub source: String,
+    pub priority: ReadPriority,
+    pub chars: usize,
+    /// The start of the text, for a queue list.
+    pub preview: String,
+}
+
+struct QueueEntry {
+    job_id: String,
+    item: QueuedRead,
+}
+
+/// Reads waiting for the current one to finish. Interactive items run before
+/// normal ones; order is first-in first-out within a priority.
+///
+/// Each read gets a `queued-N` job id while it waits. Changes are kept as
+/// events until `take_events`, so they go out in the order they happened.
+#[derive(Default)]
+pub struct ReadQueue {
+    items: VecDeque<QueueEntry>,
+    next_id: u64,
+    events: Vec<QueueEvent>,
+}
+
+impl ReadQueue {
+    pub fn push(&mut self, item: QueuedRead) {
+        let position = match item.priority {
+            ReadPriority::Interactive => self
+                .items
+                .iter()
+                .position(|queued| queued.item.priority == ReadPriority::Normal)
+                .unwrap_or(self.items.len()),
+            ReadPriority::Normal => self.items.len(),
+        };
+        self.insert(position, item);
+    }
+
+    /// Puts an interrupted read back ahead of everything else of its priority.
+    pub fn push_front(&mut self, item: QueuedRead) {
+        let position = match item.priority {
+            ReadPriority::Interactive => 0,
+            ReadPriority::Normal => self
+                .items
+                .iter()
+                .position(|queued| queued.item.priority == ReadPriority::Normal)
+                .unwrap_or(self.items.len()),
+        };
+        self.insert(position, item);
+    }
+
+    fn insert(&mut self, position: usize, item: QueuedRead) {
+        self.next_id += 1;
+        let job_id = format!("queued-{}", self.next_id);
+        self.events.push(QueueEvent::Queued {
+            job_id: job_id.clone(),
+            source: item.source.clone(),
+            priority: item.priority,
+            position,
+        });
+        self.items.insert(position, QueueEntry { job_id, item });
+    }
+
+    /// Takes the next read to start.
+    pub fn pop(&mut self) -> Option<QueuedRead> {
+        let entry = self.items.pop_front()?;
+        self.dequeued(entry.job_id, DequeueReason::Started);
+        Some(entry.item)
+    }
+
+    pub fn peek(&self) -> Option<&QueuedRead> {
+        self.items.front().map(|entry| &entry.item)
+    }
+
+    /// Removes one waiting read; false when `job_id` is not queued.
+    pub fn remove(&mut self, job_id: &str) -> bool {
+        let Some(index) = self.items.iter().position(|entry| entry.job_id == job_id) else {
+            return false;
+        };
+        if let Some(entry) = self.items.remove(index) {
+            self.dequeued(entry.job_id, DequeueReason::Canceled);
+        }
+        true
+    }
+
+    pub fn clear(&mut self) {
+        while let Some(entry) = self.items.pop_front() {
+            self.dequeued(entry.job_id, DequeueReason::Cleared);
+        }
+    }
+
+    fn dequeued(&mut self, job_id: String, reason: DequeueReason) {
+        self.events.push(QueueEvent::Dequeued { job_id, reason });
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Waiting reads, next first.
+    pub fn jobs(&self) -> Vec<QueuedJob> {
+        self.items
+            .iter()
+            .map(|entry| QueuedJob {
+                job_id: entry.job_id.clone(),
+                source: entry.item.source.clone(),
+                priority: entry.item.priority,
+                chars: entry.item.text.chars().count(),
+                preview: entry.item.text.chars().take(PREVIEW_CHARS).collect(),
+            })
+            .collect()
+    }
+
+    pub fn take_events(&mut self) -> Vec<QueueEvent> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+/// Maps the audio emitted for a read back to its text, so an interrupted read
+/// can resume at the sentence that was playing.
+///
+/// Playback position is estimated from wall time since the first chunk,
+/// minus time spent paused; audio is generated ahead of playback, so the
+/// emitted total alone would overshoot.
+pub struct ReadProgress {
+    pub job_id: String,
+    pub priority: ReadPriority,
+    text: String,
+    voice_id: String,
+    rate: Option<f32>,
+    source: String,
+    origin: JobOrigin,
+    first_chunk_at: Option<Instant>,
+    paused_at: Option<Instant>,
+    paused_secs: f64,
+    emitted_secs: f64,
+    /// (audio start in seconds, char offset into `text`) where the offset changes.
+    timeline: Vec<(f64, usize)>,
+}
+
+impl ReadProgress {
+    pub fn new(
+        job_id: &str,
+        text: &str,
+        voice_id: &str,
+        rate: Option<f32>,
+        source: &str,
+        origin: &JobOrigin,
+    ) -> Self {
+        Self {
+            job_id: job_id.to_string(),
+            priority: ReadPriority::Normal,
+            text: text.to_string(),
+            voice_id: voice_id.to_string(),
+            rate,
+            source: source.to_string(),
+            origin: origin.clone(),
+            first_chunk_at: None,
+            paused_at: None,
+            paused_secs: 0.0,
+            emitted_secs: 0.0,
+            timeline: Vec::new(),
+        }
+    }
+
+    pub fn record_chunk(&mut self, text_offset: usize, samples: usize, sample_rate: u32) {
+        if sample_rate == 0 || samples == 0 {
+            return;
+        }
+        self.first_chunk_at.get_or_insert_with(Instant::now);
+        if self.timeline.last().map(|(_, offset)| *offset) != Some(text_offset) {
+            self.timeline.push((self.emitted_secs, text_offset));
+        }
+        self.emitted_secs += samples as f64 / sample_rate as f64;
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        match (paused, self.paused_at) {
+            (true, None) => self.paused_at = Some(Instant::now()),
+            (false, Some(since)) => {
+                self.paused_secs += since.elapsed().as_secs_f64();
+                self.paused_at = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn played_secs(&self) -> f64 {
+        let Some(first) = self.first_chunk_at else {
+            return 0.0;
+        };
+        let paused_now = self.paused_at.map(|since| since.elapsed().as_secs_f64()).unwrap_or(0.0);
+        (first.elapsed().as_secs_f64() - self.paused_secs - paused_now - PLAYBACK_LAG_SECS).max(0.0)
+    }
+
+    pub fn voice_id(&self) -> &str {
+        &self.voice_id
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Seconds of audio emitted so far, at the rate it was produced with.
+    pub fn emitted_secs(&self) -> f64 {
+        self.emitted_secs
+    }
+
+    /// Seconds of already emitted audio that have not played yet.
+    pub fn remaining_playback_secs(&self) -> f64 {
+        if self.first_chunk_at.is_none() {
+            return 0.0;
+        }
+        (self.emitted_secs + PLAYBACK_LAG_SECS - self.played_secs()).max(0.0)
+    }
+
+    /// The unread part of the text, starting at the chunk that is playing now.
+    pub fn remainder(&self) -> Option<QueuedRead> {
+        let played = self.played_secs();
+        let offset = self
+            .timeline
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= played)
+            .map(|(_, offset)| *offset)
+            .unwrap_or(0);
+        let rest: String = self.text.chars().skip(offset).collect();
+        let rest = rest.trim();
+        if rest.is_empty() {
+            return None;
+        }
+        Some(QueuedRead {
+            text: rest.to_string(),
+            priority: self.priority,
+            source: self.source.clone(),
+            origin: self.origin.clone(),
+            voice_id: Some(self.voice_id.clone()),
+            rate: self.rate,
+            preprocessed: true,
+            expand_placeholders: false,
+            reading_order: false,
+        })
+    }
+
+    /// The chunk playing now and the word being spoken. There are no word
+    /// timestamps, so the word is interpolated by character position across
+    /// the chunk's audio; the sentence itself follows the real chunk timing.
+    pub fn caption(&self) -> Option<Caption> {
+        if self.remaining_playback_secs() <= 0.0 {
+            return None;
+        }
+        let played = self.played_secs().min(self.emitted_secs);
+        let index = self.timeline.iter().rposition(|(start, _)| *start <= played)?;
+        let (start_secs, start_char) = self.timeline[index];
+        let (end_secs, end_char) = self
+            .timeline
+            .get(index + 1)
+            .copied()
+            .unwrap_or((self.emitted_secs, usize::MAX));
+        let sentence: String = self
+            .text
+            .chars()
+            .skip(start_char)
+            .take(end_char.saturating_sub(start_char))
+            .collect();
+        let sentence = sentence.trim().to_string();
+        let word_count = sentence.split_whitespace().count();
+        if word_count == 0 {
+            return None;
+        }
+
+        let fraction = ((played - start_secs) / (end_secs - start_secs).max(f64::EPSILON)).clamp(0.0, 1.0);
+        let target_char = (fraction * sentence.chars().count() as f64) as usize;
+        let mut seen_chars = 0usize;
+        let word_index = sentence
+            .split_whitespace()
+            .position(|word| {
+                seen_chars += word.chars().count() + 1;
+                seen_chars > target_char
+            })
+            .unwrap_or(word_count - 1);
+        Some(Caption {
+            job_id: self.job_id.clone(),
+            sentence,
+            word_index,
+        })
+    }
+}
+
+/// Listening-speed training: the rate goes up by `step` after every
+/// `interval_minutes` of listening, until it reaches `max_rate`.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpeedTrainingSettings {
+    pub enabled: bool,
+    pub step: f32,
+    pub interval_minutes: u32,
+    pub max_rate: f32,
+    /// Say the new rate before the next read.
+    pub announce: bool,
+}
+
+impl Default for SpeedTrainingSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            step: 0.1,
+            interval_minutes: 10,
+            max_rate: 2.0,
+            announce: true,
+        }
+    }
+}
+
+impl SpeedTrainingSettings {
+    pub fn validate(&self) -> Result<()> {
+        let (min_step, max_step) = TRAINING_STEP_RANGE;
+        if !(min_step..=max_step).contains(&self.step) {
+            return Err(anyhow!("step must be between {min_step} and {max_step}"));
+        }
+        let (min_interval, max_interval) = TRAINING_INTERVAL_MINUTES_RANGE;
+        if !(min_interval..=max_interval).contains(&self.interval_minutes) {
+            return Err(anyhow!("interval_minutes must be between {min_interval} and {max_interval}"));
+        }
+        let (min_rate, max_rate) = TRAINING_MAX_RATE_RANGE;
+        if !(min_rate..=max_rate).contains(&self.max_rate) {
+            return Err(anyhow!("max_rate must be between {min_rate} and {max_rate}"));
+        }
+        Ok(())
+    }
+}
+
+/// Counts listening toward the next training step. Only reads that played
+/// to the end are counted, once they have.
+#[derive(Default)]
+pub struct SpeedTraining {
+    settings: SpeedTrainingSettings,
+    listened_secs: f64,
+}
+
+impl SpeedTraining {
+    pub fn settings(&self) -> SpeedTrainingSettings {
+        self.settings
+    }
+
+    /// Also restarts the count toward the next step.
+    pub fn set_settings(&mut self, settings: SpeedTrainingSettings) {
+        self.settings = settings;
+        self.listened_secs = 0.0;
+    }
+
+    /// Seconds of listening left until the next step; `None` when training
+    /// is off or `rate` is already at the cap.
+    pub fn next_step_in_secs(&self, rate: f32) -> Option<f64> {
+        if !self.settings.enabled || rate >= self.settings.max_rate {
+            return None;
+        }
+        Some((self.interval_secs() - self.listened_secs).max(0.0))
+    }
+
+    /// Counts `secs` of listening at `rate` and returns the raised rate when
+    /// a step is due.
+    pub fn record_listened(&mut self, secs: f64, rate: f32) -> Option<f32> {
+        self.next_step_in_secs(rate)?;
+        self.listened_secs += secs;
+        if self.listened_secs < self.interval_secs() {
+            return None;
+        }
+        self.listened_secs = 0.0;
+        let raised = ((rate + self.settings.step) * 100.0).round() / 100.0;
+        Some(raised.min(self.settings.max_rate))
+    }
+
+    /// Read before the next read after a step, when announcements are on.
+    pub fn announcement(&self, rate: f32) -> Option<String> {
+        if !self.settings.announce {
+            return None;
+        }
+        Some(if rate >= self.settings.max_rate {
+            format!("Reading speed {rate} times. Speed training has reached its maximum.")
+        } else {
+            format!("Reading speed {rate} times.")
+        })
+    }
+
+    fn interval_secs(&self) -> f64 {
+        self.settings.interval_minutes as f64 * 60.0
+    }
+}
@@ -0,0 +1,488 @@
+/// Elements whose content is never read.
+const STRIPPED_TAGS: [&str; 12] = [
+    "script", "style", "noscript", "template", "svg", "canvas", "iframe", "form", "button", "select", "input",
+    "textarea",
+];
+/// Page furniture around an article; dropped before scoring.
+const BOILERPLATE_TAGS: [&str; 4] = ["nav", "header", "footer", "aside"];
+const VOID_TAGS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr",
+];
+/// Elements whose text is skipped by the parser rather than parsed as markup.
+const RAW_TEXT_TAGS: [&str; 3] = ["script", "style", "textarea"];
+const BLOCK_TAGS: [&str; 27] = [
+    "address", "article", "blockquote", "body", "dd", "div", "dl", "dt", "figcaption", "figure", "h1", "h2", "h3",
+    "h4", "h5", "h6", "hr", "li", "main", "ol", "p", "pre", "section", "table", "td", "tr", "ul",
+];
+/// Class and id fragments of sidebars, comments, share bars and ads.
+const UNLIKELY_HINTS: [&str; 20] = [
+    "banner", "breadcrumb", "combx", "comment", "community", "cookie", "disqus", "footer", "header", "menu",
+    "modal", "nav", "popup", "promo", "related", "share", "sidebar", "social", "sponsor", "subscribe",
+];
+/// Class and id fragments of article bodies; they override an unlikely hint.
+const POSITIVE_HINTS: [&str; 8] = ["article", "body", "content", "entry", "main", "post", "story", "text"];
+/// Paragraphs shorter than this do not count towards a candidate's score.
+const MIN_PARAGRAPH_CHARS: usize = 25;
+/// Extracted text shorter than this is not trusted as the article.
+const MIN_ARTICLE_CHARS: usize = 200;
+
+/// The main article of a web page.
+pub struct Article {
+    pub title: Option<String>,
+    /// Paragraphs separated by blank lines.
+    pub text: String,
+}
+
+/// Finds the main article in `html` the way reader modes do: paragraphs
+/// score their enclosing elements by length and comma count, class and id
+/// names nudge the scores, link-heavy elements are discounted, and the
+/// best element is read together with siblings that score close to it.
+/// Returns `None` when no element holds enough prose.
+pub fn extract_article(html: &str) -> Option<Article> {
+    let document = Document::parse(html);
+    let removed = document.removed_nodes(true);
+
+    let mut scores = vec![0.0f32; document.nodes.len()];
+    let mut initialized = vec![false; document.nodes.len()];
+    for index in 0..document.nodes.len() {
+        if removed[index] || !document.is_paragraph(index, &removed) {
+            continue;
+        }
+        let text = document.inner_text(index, &removed);
+        let length = text.chars().count();
+        if length < MIN_PARAGRAPH_CHARS {
+            continue;
+        }
+        let content_score = 1.0 + text.matches([',', '\u{FF0C}']).count() as f32 + (length as f32 / 100.0).min(3.0);
+        let mut ancestor = document.nodes[index].parent;
+        for level in 0..3 {
+            let Some(candidate) = ancestor else {
+                break;
+            };
+            if !initialized[candidate] {
+                scores[candidate] = document.base_score(candidate);
+                initialized[candidate] = true;
+            }
+            let divider = match level {
+                0 => 1.0,
+                1 => 2.0,
+                _ => level as f32 * 3.0,
+            };
+            scores[candidate] += content_score / divider;
+            ancestor = document.nodes[candidate].parent;
+        }
+    }
+
+    let top = (0..document.nodes.len())
+        .filter(|index| initialized[*index])
+        .map(|index| (index, scores[index] * (1.0 - document.link_density(index, &removed))))
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+    let (top_index, top_score) = top;
+
+    // Siblings that score close to the top candidate, or read like prose,
+    // belong to the same article (a lead paragraph outside the body div).
+    let threshold = (top_score * 0.2).max(10.0);
+    let siblings = match document.nodes[top_index].parent {
+        Some(parent) => document.nodes[parent].children.clone(),
+        None => vec![top_index],
+    };
+    let mut text = String::new();
+    for sibling in siblings {
+        if removed[sibling] {
+            continue;
+        }
+        let include = sibling == top_index
+            || (initialized[sibling] && scores[sibling] * (1.0 - document.link_density(sibling, &removed)) >= threshold)
+            || (document.nodes[sibling].tag == "p" && {
+                let paragraph = document.inner_text(sibling, &removed);
+                let link_density = document.link_density(sibling, &removed);
+                let length = paragraph.chars().count();
+                (length > 80 && link_density < 0.25) || (link_density == 0.0 && paragraph.trim_end().ends_with('.'))
+            });
+        if include {
+            document.render(sibling, &removed, &mut text);
+        }
+    }
+
+    let text = tidy_paragraphs(&text);
+    if text.chars().count() < MIN_ARTICLE_CHARS {
+        return None;
+    }
+    Some(Article {
+        title: document.title(),
+        text,
+    })
+}
+
+/// All visible text of `html` as paragraphs, for pages where no article
+/// stands out. Only scripts, styles and form controls are left out.
+pub fn visible_text(html: &str) -> String {
+    let document = Document::parse(html);
+    let removed = document.removed_nodes(false);
+    let mut text = String::new();
+    document.render(0, &removed, &mut text);
+    tidy_paragraphs(&text)
+}
+
+struct Node {
+    /// Lowercase element name; empty for text nodes.
+    tag: String,
+    /// Lowercase `id` and `class` values, for the hint lists.
+    hint: String,
+    text: String,
+    parent: Option<usize>,
+    children: Vec<usize>,
+}
+
+/// A forgiving parse of an HTML page into an element tree. Unclosed
+/// elements are closed by their parent's end tag, so malformed markup
+/// still yields a usable tree.
+struct Document {
+    /// Node 0 is a synthetic root.
+    nodes: Vec<Node>,
+    title: Option<String>,
+}
+
+impl Document {
+    fn parse(html: &str) -> Self {
+        let mut document = Self {
+            nodes: vec![Node {
+                tag: "#root".to_string(),
+                hint: String::new(),
+                text: String::new(),
+                parent: None,
+                children: Vec::new(),
+            }],
+            title: None,
+        };
+        let mut open: Vec<usize> = vec![0];
+        let mut rest = html;
+        while !rest.is_empty() {
+            let Some(start) = rest.find('<') else {
+                document.push_text(*open.last().unwrap_or(&0), rest);
+                break;
+            };
+            document.push_text(*open.last().unwrap_or(&0), &rest[..start]);
+            rest = &rest[start..];
+
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+                continue;
+            }
+            if rest.starts_with("<!") || rest.starts_with("<?") {
+                rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+                continue;
+            }
+            // A bare `<` in text, as in `a < b`.
+            if !rest[1..].starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '/') {
+                document.push_text(*open.last().unwrap_or(&0), "<");
+                rest = &rest[1..];
+                continue;
+            }
+            let Some(tag_end) = find_tag_end(rest) else {
+                break;
+            };
+            let tag = &rest[1..tag_end];
+            rest = &rest[tag_end + 1..];
+
+            if let Some(closing) = tag.strip_prefix('/') {
+                let name = tag_name(closing);
+                if let Some(position) = open.iter().rposition(|index| document.nodes[*index].tag == name) {
+                    if position > 0 {
+                        open.truncate(position);
+                    }
+                }
+                continue;
+            }
+
+            let name = tag_name(tag);
+            if name.is_empty() {
+                document.push_text(*open.last().unwrap_or(&0), "<");
+                continue;
+            }
+            // A new block implicitly ends an open paragraph, and a list item
+            // ends the previous one.
+            let current = *open.last().unwrap_or(&0);
+            let current_tag = document.nodes[current].tag.as_str();
+            if (current_tag == "p" && BLOCK_TAGS.contains(&name.as_str())) || (current_tag == "li" && name == "li") {
+                open.pop();
+            }
+            let parent = *open.last().unwrap_or(&0);
+            let index = document.nodes.len();
+            document.nodes.push(Node {
+                hint: attribute_hint(tag),
+                tag: name.clone(),
+                text: String::new(),
+                parent: Some(parent),
+                children: Vec::new(),
+            });
+            document.nodes[parent].children.push(index);
+
+            if RAW_TEXT_TAGS.contains(&name.as_str()) || name == "title" {
+                let end = find_ignore_ascii_case(rest, &format!("</{name}")).unwrap_or(rest.len());
+                if name == "title" && document.title.is_none() {
+                    document.title = Some(decode_entities(&rest[..end]).split_whitespace().collect::<Vec<_>>().join(" "));
+                }
+                rest = &rest[end..];
+                rest = rest.find('>').map_or("", |close| &rest[close + 1..]);
+                continue;
+            }
+            if !VOID_TAGS.contains(&name.as_str()) && !tag.trim_end().ends_with('/') {
+                open.push(index);
+            }
+        }
+        document
+    }
+
+    fn push_text(&mut self, parent: usize, raw: &str) {
+        if raw.is_empty() {
+            return;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            tag: String::new(),
+            hint: String::new(),
+            text: decode_entities(raw),
+            parent: Some(parent),
+            children: Vec::new(),
+        });
+        self.nodes[parent].children.push(index);
+    }
+
+    /// Marks nodes that are not read, with their descendants. Boilerplate
+    /// and unlikely candidates are only dropped for article extraction.
+    fn removed_nodes(&self, drop_boilerplate: bool) -> Vec<bool> {
+        let mut removed = vec![false; self.nodes.len()];
+        // Children always come after their parent, so one pass propagates.
+        for index in 1..self.nodes.len() {
+            let node = &self.nodes[index];
+            let parent_removed = node.parent.is_some_and(|parent| removed[parent]);
+            let stripped = STRIPPED_TAGS.contains(&node.tag.as_str());
+            let boilerplate = drop_boilerplate
+                && (BOILERPLATE_TAGS.contains(&node.tag.as_str())
+                    || (!matches!(node.tag.as_str(), "html" | "body" | "article" | "main")
+                        && UNLIKELY_HINTS.iter().any(|hint| node.hint.contains(hint))
+                        && !POSITIVE_HINTS.iter().any(|hint| node.hint.contains(hint))));
+            removed[index] = parent_removed || stripped || boilerplate;
+        }
+        removed
+    }
+
+    /// Paragraph-like elements, including divs used as paragraphs.
+    fn is_paragraph(&self, index: usize, removed: &[bool]) -> bool {
+        let node = &self.nodes[index];
+        match node.tag.as_str() {
+            "p" | "pre" | "td" | "blockquote" => true,
+            "div" => !node.children.iter().any(|child| {
+                !removed[*child] && BLOCK_TAGS.contains(&self.nodes[*child].tag.as_str())
+            }),
+            _ => false,
+        }
+    }
+
+    fn base_score(&self, index: usize) -> f32 {
+        let node = &self.nodes[index];
+        let tag_score = match node.tag.as_str() {
+            "div" | "article" | "main" => 5.0,
+            "pre" | "td" | "blockquote" => 3.0,
+            "address" | "ol" | "ul" | "dl" | "dd" | "dt" | "li" | "form" => -3.0,
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th" => -5.0,
+            _ => 0.0,
+        };
+        let mut class_score = 0.0;
+        if POSITIVE_HINTS.iter().any(|hint| node.hint.contains(hint)) {
+            class_score += 25.0;
+        }
+        if UNLIKELY_HINTS.iter().any(|hint| node.hint.contains(hint)) {
+            class_score -= 25.0;
+        }
+        tag_score + class_score
+    }
+
+    fn inner_text(&self, index: usize, removed: &[bool]) -> String {
+        let mut text = String::new();
+        self.collect_text(index, removed, &mut text);
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn collect_text(&self, index: usize, removed: &[bool], text: &mut String) {
+        if removed[index] {
+            return;
+        }
+        let node = &self.nodes[index];
+        if node.tag.is_empty() {
+            text.push_str(&node.text);
+            text.push(' ');
+            return;
+        }
+        for child in &node.children {
+            self.collect_text(*child, removed, text);
+        }
+    }
+
+    /// Share of the text of `index` that sits inside links.
+    fn link_density(&self, index: usize, removed: &[bool]) -> f32 {
+        let total = self.inner_text(index, removed).chars().count();
+        if total == 0 {
+            return 0.0;
+        }
+        let mut linked = 0;
+        let mut stack = vec![index];
+        while let Some(current) = stack.pop() {
+            if removed[current] {
+                continue;
+            }
+            if self.nodes[current].tag == "a" {
+                linked += self.inner_text(current, removed).chars().count();
+                continue;
+            }
+            stack.extend(self.nodes[current].children.iter().copied());
+        }
+        linked as f32 / total as f32
+    }
+
+    /// Appends the text of `index`, with blank lines around blocks.
+    fn render(&self, index: usize, removed: &[bool], text: &mut String) {
+        if removed[index] {
+            return;
+        }
+        let node = &self.nodes[index];
+        if node.tag.is_empty() {
+            text.push_str(&node.text);
+            return;
+        }
+        let block = BLOCK_TAGS.contains(&node.tag.as_str()) || node.tag == "br";
+        if block {
+            text.push_str("\n\n");
+        }
+        for child in &node.children {
+            self.render(*child, removed, text);
+        }
+        if block {
+            text.push_str("\n\n");
+        }
+    }
+
+    /// Page title without a trailing site name, such as `Story - Site`.
+    fn title(&self) -> Option<String> {
+        let title = self.title.as_deref()?.trim();
+        let headline = [" | ", " - ", " \u{2013} ", " \u{2014} "]
+            .iter()
+            .filter_map(|separator| title.split_once(separator).map(|(head, _)| head.trim()))
+            .min_by_key(|head| head.len())
+            .filter(|head| head.split_whitespace().count() >= 3)
+            .unwrap_or(title);
+        (!headline.is_empty()).then(|| headline.to_string())
+    }
+}
+
+/// Index of the `>` that ends the tag starting at `html[0]`, skipping
+/// `>` inside quoted attribute values.
+fn find_tag_end(html: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    for (index, ch) in html.char_indices().skip(1) {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn tag_name(tag: &str) -> String {
+    tag.trim_start()
+        .chars()
+        .take_while(|ch| ch.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase()
+}
+
+fn find_ignore_ascii_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Lowercase `id` and `class` attribute values of a start tag.
+fn attribute_hint(tag: &str) -> String {
+    let lowered = tag.to_ascii_lowercase();
+    let mut hint = String::new();
+    for attribute in ["id=", "class="] {
+        // Only whole attribute names: not `data-id=` or `subclass=`.
+        let Some(start) = lowered
+            .match_indices(attribute)
+            .map(|(start, _)| start)
+            .find(|start| lowered[..*start].ends_with(char::is_whitespace))
+        else {
+            continue;
+        };
+        let value = &lowered[start + attribute.len()..];
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or(""),
+            _ => value.split_whitespace().next().unwrap_or(""),
+        };
+        hint.push_str(value);
+        hint.push(' ');
+    }
+    hint
+}
+
+/// Collapses whitespace inside paragraphs and keeps one blank line between them.
+fn tidy_paragraphs(text: &str) -> String {
+    text.split("\n\n")
+        .map(|paragraph| paragraph.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end + 1]);
+        let replacement = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            "mdash" => Some('\u{2014}'),
+            "ndash" => Some('\u{2013}'),
+            "hellip" => Some('\u{2026}'),
+            "rsquo" => Some('\u{2019}'),
+            "lsquo" => Some('\u{2018}'),
+            "rdquo" => Some('\u{201D}'),
+            "ldquo" => Some('\u{201C}'),
+            _ => {
+                let code = match entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => entity.strip_prefix('#').and_then(|dec| dec.parse().ok()),
+                };
+                code.and_then(char::from_u32)
+            }
+        });
+        match (entity, replacement) {
+            (Some(entity), Some(ch)) => {
+                decoded.push(ch);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
@@ -0,0 +1,247 @@
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::reading_order::{split_paragraphs, split_sentences};
+
+/// Placeholder in command arguments replaced with the exported WAV path.
+const AUDIO_PLACEHOLDER: &str = "{audio}";
+const COMMAND_POLL_MS: u64 = 100;
+/// Recognition gets at least this long, and twice the audio length when
+/// that is longer; CPU whisper runs well under real time on base models.
+const MIN_RECOGNITION_SECS: u64 = 120;
+/// Transcript words searched past the previous match for each sentence, on
+/// top of twice the sentence length, so a skipped sentence does not pull the
+/// alignment ahead.
+const ALIGN_SLACK_WORDS: usize = 20;
+/// Below this share of its words heard, a sentence counts as skipped rather
+/// than garbled.
+const SKIPPED_BELOW: f32 = 0.2;
+
+/// Local speech recognition run over exported audio, such as whisper.cpp's
+/// `whisper-cli -m ggml-base.en.bin -nt -np -f {audio}`. The transcript is
+/// the command's stdout. Verification is off while `program` is empty.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReadbackSettings {
+    pub program: String,
+    pub args: Vec<String>,
+    /// Sentences with a smaller share of their words in the transcript are
+    /// flagged.
+    pub min_match: f32,
+}
+
+impl Default for ReadbackSettings {
+    fn default() -> Self {
+        Self {
+            program: String::new(),
+            args: Vec::new(),
+            min_match: 0.6,
+        }
+    }
+}
+
+impl ReadbackSettings {
+    pub fn is_enabled(&self) -> bool {
+        !self.program.trim().is_empty()
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if !(0.0..=1.0).contains(&self.min_match) {
+            return Err(anyhow!("Read-back match threshold must be between 0 and 1"));
+        }
+        if self.is_enabled() && !self.args.iter().any(|arg| arg.contains(AUDIO_PLACEHOLDER)) {
+            return Err(anyhow!("Read-back arguments must include {AUDIO_PLACEHOLDER} for the audio file"));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadbackIssue {
+    /// Next to nothing of the sentence was heard.
+    Skipped,
+    /// Part of the sentence was heard.
+    Garbled,
+}
+
+#[derive(Clone, Serialize)]
+pub struct FlaggedSentence {
+    /// Position among the source text's sentences.
+    pub index: usize,
+    pub text: String,
+    /// Share of the sentence's words found in the transcript.
+    pub match_ratio: f32,
+    pub issue: ReadbackIssue,
+}
+
+#[derive(Clone, Serialize)]
+pub struct ReadbackReport {
+    pub sentences_checked: usize,
+    pub flagged: Vec<FlaggedSentence>,
+    pub transcript: String,
+}
+
+/// Transcribes `audio_path` with the configured recognizer and flags the
+/// sentences of `source_text` that the audio does not contain.
+pub fn verify_export(
+    settings: &ReadbackSettings,
+    audio_path: &Path,
+    audio_duration_ms: u64,
+    source_text: &str,
+) -> Result<ReadbackReport> {
+    let timeout = Duration::from_secs(MIN_RECOGNITION_SECS.max(audio_duration_ms * 2 / 1000));
+    let transcript = run_recognizer(settings, audio_path, timeout)?;
+    Ok(compare_transcript(source_text, &transcript, settings.min_match))
+}
+
+/// Transcribes a short recording, such as a spoken practice attempt, with
+/// the configured recognizer. Annotations like `[BLANK_AUDIO]` are dropped.
+pub fn transcribe_recording(settings: &ReadbackSettings, audio_path: &Path) -> Result<String> {
+    let transcript = run_recognizer(settings, audio_path, Duration::from_secs(MIN_RECOGNITION_SECS))?;
+    Ok(strip_annotations(&transcript).split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+fn run_recognizer(settings: &ReadbackSettings, audio_path: &Path, timeout: Duration) -> Result<String> {
+    let program = settings.program.trim();
+    let audio = audio_path.to_string_lossy();
+    let mut child = Command::new(program)
+        .args(settings.args.iter().map(|arg| arg.replace(AUDIO_PLACEHOLDER, &audio)))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run read-back command {program}"))?;
+
+    // Drained on their own threads so a chatty recognizer cannot fill a pipe and stall.
+    let stdout = child.stdout.take().map(|mut stdout| {
+        std::thread::spawn(move || {
+            let mut out = Vec::new();
+            let _ = stdout.read_to_end(&mut out);
+            out
+        })
+    });
+    let stderr = child.stderr.take().map(|mut stderr| {
+        std::thread::spawn(move || {
+            let mut out = Vec::new();
+            let _ = stderr.read_to_end(&mut out);
+            out
+        })
+    });
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().context("Failed to wait for read-back command")? {
+            break status;
+        }
+        if started.elapsed() >= timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(anyhow!("Read-back command {program} timed out after {}s", timeout.as_secs()));
+        }
+        std::thread::sleep(Duration::from_millis(COMMAND_POLL_MS));
+    };
+
+    let stdout = stdout.and_then(|reader| reader.join().ok()).unwrap_or_default();
+    let stderr = stderr.and_then(|reader| reader.join().ok()).unwrap_or_default();
+    if !status.success() {
+        let stderr = String::from_utf8_lossy(&stderr);
+        return Err(anyhow!("Read-back command {program} failed ({status}): {}", stderr.trim()));
+    }
+    Ok(String::from_utf8_lossy(&stdout).trim().to_string())
+}
+
+/// Aligns each source sentence against the transcript in order, looking a
+/// little past where the previous sentence matched.
+fn compare_transcript(source_text: &str, transcript: &str, min_match: f32) -> ReadbackReport {
+    let heard = words(&strip_annotations(transcript));
+    let sentences = split_paragraphs(source_text)
+        .iter()
+        .flat_map(|paragraph| split_sentences(paragraph))
+        .collect::<Vec<_>>();
+
+    let mut cursor = 0;
+    let mut flagged = Vec::new();
+    let mut sentences_checked = 0;
+    for (index, sentence) in sentences.iter().enumerate() {
+        let expected = words(sentence);
+        if expected.is_empty() {
+            continue;
+        }
+        sentences_checked += 1;
+        let window_end = heard.len().min(cursor + expected.len() * 2 + ALIGN_SLACK_WORDS);
+        let (matched, last_match) = align(&expected, &heard[cursor.min(window_end)..window_end]);
+        if let Some(last_match) = last_match {
+            cursor += last_match + 1;
+        }
+        let match_ratio = matched as f32 / expected.len() as f32;
+        if match_ratio < min_match {
+            flagged.push(FlaggedSentence {
+                index,
+                text: sentence.trim().to_string(),
+                match_ratio,
+                issue: if match_ratio < SKIPPED_BELOW {
+                    ReadbackIssue::Skipped
+                } else {
+                    ReadbackIssue::Garbled
+                },
+            });
+        }
+    }
+
+    ReadbackReport {
+        sentences_checked,
+        flagged,
+        transcript: transcript.trim().to_string(),
+    }
+}
+
+/// Longest common subsequence of `expected` in `heard`: the number of words
+/// matched and the position in `heard` of the last one.
+fn align(expected: &[String], heard: &[String]) -> (usize, Option<usize>) {
+    let cols = heard.len() + 1;
+    let mut table = vec![0usize; (expected.len() + 1) * cols];
+    for (row, word) in expected.iter().enumerate() {
+        for (col, candidate) in heard.iter().enumerate() {
+            table[(row + 1) * cols + col + 1] = if word == candidate {
+                table[row * cols + col] + 1
+            } else {
+                table[row * cols + col + 1].max(table[(row + 1) * cols + col])
+            };
+        }
+    }
+    let matched = table[expected.len() * cols + heard.len()];
+    if matched == 0 {
+        return (0, None);
+    }
+    // The first column where the full count is reached ends the alignment.
+    let last_match = (1..cols).find(|col| table[expected.len() * cols + col] == matched).map(|col| col - 1);
+    (matched, last_match)
+}
+
+/// Lowercased words with punctuation removed, so `Hello,` matches `hello`.
+fn words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| word.chars().filter(|ch| ch.is_alphanumeric()).flat_map(char::to_lowercase).collect::<String>())
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Drops recognizer annotations such as `[BLANK_AUDIO]` or `(music)`.
+fn strip_annotations(transcript: &str) -> String {
+    let mut out = String::with_capacity(transcript.len());
+    let mut depth = 0usize;
+    for ch in transcript.chars() {
+        match ch {
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => out.push(ch),
+            _ => {}
+        }
+    }
+    out
+}
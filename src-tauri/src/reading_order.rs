@@ -0,0 +1,171 @@
+const PARAGRAPH_ANNOUNCEMENT: &str = "New paragraph.";
+const SENTENCE_TERMINATORS: [char; 6] = ['.', '!', '?', '。', '！', '？'];
+const WIDE_TERMINATORS: [char; 3] = ['。', '！', '？'];
+const CLOSING_MARKS: [char; 6] = ['"', '\'', ')', ']', '”', '’'];
+const TRAILING_WORD_PUNCTUATION: [char; 4] = [',', ';', ':', '-'];
+
+#[derive(Clone, Copy, Default)]
+pub struct ReadingOrder {
+    /// Speak "New paragraph." between paragraphs.
+    pub announce_paragraphs: bool,
+    /// Read only the first sentence of each paragraph.
+    pub first_sentence_only: bool,
+}
+
+/// Rewrites `text` before it reaches the chunker.
+///
+/// Paragraph boundaries are blank lines; when a selection has none, every
+/// non-empty line is treated as its own paragraph (lists, UI labels, chat logs).
+/// Returns the text unchanged when no option is enabled so normal reads keep
+/// their original punctuation and spacing.
+pub fn apply_reading_order(text: &str, order: ReadingOrder) -> String {
+    if !order.announce_paragraphs && !order.first_sentence_only {
+        return text.to_string();
+    }
+
+    let paragraphs: Vec<String> = split_paragraphs(text)
+        .into_iter()
+        .filter_map(|paragraph| {
+            if order.first_sentence_only {
+                split_sentences(&paragraph).into_iter().next()
+            } else {
+                Some(paragraph)
+            }
+        })
+        .collect();
+
+    let separator = if order.announce_paragraphs {
+        format!(" {PARAGRAPH_ANNOUNCEMENT} ")
+    } else {
+        " ".to_string()
+    };
+    paragraphs
+        .iter()
+        .map(|paragraph| ensure_terminated(paragraph))
+        .collect::<Vec<_>>()
+        .join(&separator)
+}
+
+#[derive(Clone, Copy)]
+pub struct SkimSettings {
+    /// Words kept from the start of each sentence; `0` keeps the whole sentence.
+    pub words_per_sentence: u32,
+    /// Read every k-th sentence; `1` reads all of them.
+    pub sentence_stride: u32,
+    /// Playback rate used for skim reads instead of the normal speak rate.
+    pub rate: f32,
+}
+
+impl Default for SkimSettings {
+    fn default() -> Self {
+        Self {
+            words_per_sentence: 6,
+            sentence_stride: 1,
+            rate: 2.5,
+        }
+    }
+}
+
+/// Condenses `text` for a quick pass: samples every `sentence_stride`-th
+/// sentence and cuts each one to its first `words_per_sentence` words.
+/// Sentences without spaces (CJK) are kept whole.
+pub fn skim_text(text: &str, settings: SkimSettings) -> String {
+    let stride = settings.sentence_stride.max(1) as usize;
+    let max_words = settings.words_per_sentence as usize;
+    split_paragraphs(text)
+        .iter()
+        .flat_map(|paragraph| split_sentences(paragraph))
+        .enumerate()
+        .filter(|(index, _)| index % stride == 0)
+        .map(|(_, sentence)| {
+            let words: Vec<&str> = sentence.split_whitespace().collect();
+            if max_words == 0 || words.len() <= max_words {
+                ensure_terminated(&sentence)
+            } else {
+                let head = words[..max_words]
+                    .join(" ")
+                    .trim_end_matches(|c| TRAILING_WORD_PUNCTUATION.contains(&c))
+                    .to_string();
+                ensure_terminated(&head)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn split_paragraphs(text: &str) -> Vec<String> {
+    let normalized = text.replace("\r\n", "\n").replace('\r', "\n");
+    let mut paragraphs = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in normalized.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current.join(" "));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current.join(" "));
+    }
+
+    if paragraphs.len() == 1 && normalized.trim().contains('\n') {
+        return normalized
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect();
+    }
+    paragraphs
+}
+
+pub fn split_sentences(paragraph: &str) -> Vec<String> {
+    let chars: Vec<char> = paragraph.chars().collect();
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut index = 0;
+    while index < chars.len() {
+        let ch = chars[index];
+        if SENTENCE_TERMINATORS.contains(&ch) {
+            let mut end = index + 1;
+            while end < chars.len() && (SENTENCE_TERMINATORS.contains(&chars[end]) || CLOSING_MARKS.contains(&chars[end])) {
+                end += 1;
+            }
+            let at_boundary = end >= chars.len() || chars[end].is_whitespace() || WIDE_TERMINATORS.contains(&ch);
+            if at_boundary {
+                let sentence: String = chars[start..end].iter().collect();
+                let sentence = sentence.trim();
+                if !sentence.is_empty() {
+                    sentences.push(sentence.to_string());
+                }
+                start = end;
+            }
+            index = end;
+            continue;
+        }
+        index += 1;
+    }
+    let tail: String = chars[start..].iter().collect();
+    let tail = tail.trim();
+    if !tail.is_empty() {
+        sentences.push(tail.to_string());
+    }
+    sentences
+}
+
+fn ensure_terminated(sentence: &str) -> String {
+    let ends_cleanly = sentence
+        .trim_end_matches(|c| CLOSING_MARKS.contains(&c))
+        .ends_with(|c: char| SENTENCE_TERMINATORS.contains(&c) || c == ':' || c == ';');
+    if ends_cleanly {
+        sentence.to_string()
+    } else {
+        // Headings and list items rarely end in punctuation; add a stop so the
+        // chunker and the voice treat them as a separate sentence.
+        format!("{sentence}.")
+    }
+}
@@ -0,0 +1,176 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use serde::{Deserialize, Serialize};
+
+/// Runs missed by more than this (the app was closed) are skipped rather
+/// than read late.
+const MISSED_RUN_GRACE_MINUTES: i64 = 15;
+const MAX_INTERVAL_MINUTES: u32 = 7 * 24 * 60;
+
+/// What a schedule reads.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ScheduleTarget {
+    File { path: String },
+    Url { url: String },
+    Phrase { id: String },
+}
+
+/// When a schedule runs. Times are local.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ScheduleTiming {
+    /// Once, at `YYYY-MM-DDTHH:MM` or an RFC 3339 timestamp.
+    Once { at: String },
+    /// Every day at `HH:MM`.
+    Daily { time: String },
+    /// Every `minutes`, starting one interval from now.
+    Every { minutes: u32 },
+}
+
+impl ScheduleTiming {
+    /// First run after `now`; errors on malformed times or a one-off time
+    /// already past.
+    pub fn first_run(&self, now: DateTime<Local>) -> Result<DateTime<Local>> {
+        match self {
+            Self::Once { at } => {
+                let at = parse_local_datetime(at)?;
+                if at <= now {
+                    return Err(anyhow!("Scheduled time {} is in the past", at.format("%Y-%m-%d %H:%M")));
+                }
+                Ok(at)
+            }
+            Self::Daily { time } => Ok(next_daily(parse_time(time)?, now)),
+            Self::Every { minutes } => {
+                if !(1..=MAX_INTERVAL_MINUTES).contains(minutes) {
+                    return Err(anyhow!("Interval must be between 1 and {MAX_INTERVAL_MINUTES} minutes"));
+                }
+                Ok(now + Duration::minutes(*minutes as i64))
+            }
+        }
+    }
+
+    /// Run after `now` for a recurring schedule; `None` for a one-off.
+    fn following_run(&self, now: DateTime<Local>) -> Option<DateTime<Local>> {
+        match self {
+            Self::Once { .. } => None,
+            Self::Daily { time } => parse_time(time).ok().map(|time| next_daily(time, now)),
+            Self::Every { minutes } => Some(now + Duration::minutes(*minutes as i64)),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ScheduledRead {
+    pub id: String,
+    pub label: String,
+    pub target: ScheduleTarget,
+    pub timing: ScheduleTiming,
+    /// RFC 3339 local time of the next run.
+    pub next_run: String,
+}
+
+/// Schedules, in the order they were created.
+#[derive(Default, Serialize, Deserialize)]
+pub struct ScheduleStore {
+    schedules: Vec<ScheduledRead>,
+}
+
+impl ScheduleStore {
+    /// A missing file is an empty store; a corrupt one is an error so a
+    /// later save does not drop the schedules.
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(body) => serde_json::from_str(&body).with_context(|| format!("Failed to parse {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(anyhow!("Failed to read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create schedules directory {}", parent.display()))?;
+        }
+        let serialized = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, serialized).with_context(|| format!("Failed to write schedules file {}", path.display()))
+    }
+
+    pub fn schedules(&self) -> &[ScheduledRead] {
+        &self.schedules
+    }
+
+    pub fn add(&mut self, schedule: ScheduledRead) {
+        self.schedules.push(schedule);
+    }
+
+    pub fn remove(&mut self, id: &str) -> Option<ScheduledRead> {
+        let index = self.schedules.iter().position(|schedule| schedule.id == id)?;
+        Some(self.schedules.remove(index))
+    }
+
+    /// Takes the schedules due at `now` and moves each to its next run,
+    /// dropping one-offs. Runs missed by more than the grace period are
+    /// moved on without being returned.
+    pub fn take_due(&mut self, now: DateTime<Local>) -> Vec<ScheduledRead> {
+        let mut due = Vec::new();
+        self.schedules.retain_mut(|schedule| {
+            // An unreadable time (edited by hand) is treated as missed.
+            let next_run = DateTime::parse_from_rfc3339(&schedule.next_run)
+                .map(|next_run| next_run.with_timezone(&Local))
+                .ok();
+            if next_run.is_some_and(|next_run| next_run > now) {
+                return true;
+            }
+            if next_run.is_some_and(|next_run| now - next_run <= Duration::minutes(MISSED_RUN_GRACE_MINUTES)) {
+                due.push(schedule.clone());
+            }
+            match schedule.timing.following_run(now) {
+                Some(next_run) => {
+                    schedule.next_run = next_run.to_rfc3339();
+                    true
+                }
+                None => false,
+            }
+        });
+        due
+    }
+}
+
+pub fn parse_time(time: &str) -> Result<NaiveTime> {
+    NaiveTime::parse_from_str(time.trim(), "%H:%M").map_err(|_| anyhow!("Time must be HH:MM (got {time})"))
+}
+
+pub fn parse_local_datetime(at: &str) -> Result<DateTime<Local>> {
+    let at = at.trim();
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(at) {
+        return Ok(parsed.with_timezone(&Local));
+    }
+    let naive = NaiveDateTime::parse_from_str(at, "%Y-%m-%dT%H:%M")
+        .or_else(|_| NaiveDateTime::parse_from_str(at, "%Y-%m-%d %H:%M"))
+        .map_err(|_| anyhow!("Time must be YYYY-MM-DDTHH:MM or RFC 3339 (got {at})"))?;
+    local_datetime(naive.date(), naive.time())
+        .ok_or_else(|| anyhow!("{at} does not exist in the local time zone"))
+}
+
+/// `time` today if still ahead, else tomorrow.
+fn next_daily(time: NaiveTime, now: DateTime<Local>) -> DateTime<Local> {
+    let mut date = now.date_naive();
+    loop {
+        if let Some(candidate) = local_datetime(date, time).filter(|candidate| *candidate > now) {
+            return candidate;
+        }
+        date = date.succ_opt().unwrap_or(date);
+    }
+}
+
+/// A wall-clock time skipped by a DST change resolves to an hour later.
+pub fn local_datetime(date: NaiveDate, time: NaiveTime) -> Option<DateTime<Local>> {
+    let naive = date.and_time(time);
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .or_else(|| Local.from_local_datetime(&(naive + Duration::hours(1))).earliest())
+}
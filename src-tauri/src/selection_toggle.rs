@@ -0,0 +1,50 @@
+use std::time::{Duration, Instant};
+
+/// A capture still running after this long is assumed lost, so the hotkey
+/// cannot get stuck ignoring presses.
+const CAPTURE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// What a press of the read-selection hotkey does.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TogglePress {
+    /// Capture the selection and read it.
+    Capture,
+    /// Stop the read that is speaking.
+    Stop,
+    /// A capture is already underway; the press is dropped.
+    Ignore,
+}
+
+/// Read-selection hotkey state. From idle a press captures and reads the
+/// selection; while a read is speaking, the same press stops it instead.
+/// Presses during a capture are ignored, so a double press does not copy
+/// twice.
+#[derive(Default)]
+pub struct SelectionToggle {
+    capturing_since: Option<Instant>,
+}
+
+impl SelectionToggle {
+    /// Moves to the next state for a press. `speaking` is whether a read is
+    /// generating or still playing.
+    pub fn press(&mut self, speaking: bool) -> TogglePress {
+        if self
+            .capturing_since
+            .is_some_and(|since| since.elapsed() < CAPTURE_TIMEOUT)
+        {
+            return TogglePress::Ignore;
+        }
+        if speaking {
+            self.capturing_since = None;
+            return TogglePress::Stop;
+        }
+        self.capturing_since = Some(Instant::now());
+        TogglePress::Capture
+    }
+
+    /// Ends the capture a `Capture` press started, whether or not it found
+    /// text. The read it started, if any, is now what counts as speaking.
+    pub fn capture_finished(&mut self) {
+        self.capturing_since = None;
+    }
+}
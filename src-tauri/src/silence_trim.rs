@@ -0,0 +1,51 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+const MAX_KEEP_MS_LIMIT: u32 = 2000;
+
+/// Silence gate applied to each synthesized chunk before rate and volume
+/// processing.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SilenceTrim {
+    /// Peak amplitude (0.0-1.0 of full scale) at or below which audio counts
+    /// as silence.
+    pub threshold: f32,
+    /// Silence left in place at each end of a chunk, in milliseconds, so
+    /// sentences do not run into each other.
+    pub max_keep_ms: u32,
+}
+
+impl Default for SilenceTrim {
+    fn default() -> Self {
+        Self {
+            threshold: 0.01,
+            max_keep_ms: 150,
+        }
+    }
+}
+
+impl SilenceTrim {
+    pub fn validate(&self) -> Result<()> {
+        if !(self.threshold > 0.0 && self.threshold <= 0.5) {
+            return Err(anyhow!("threshold must be in (0.0, 0.5]"));
+        }
+        if self.max_keep_ms > MAX_KEEP_MS_LIMIT {
+            return Err(anyhow!("max_keep_ms must be in [0, {MAX_KEEP_MS_LIMIT}]"));
+        }
+        Ok(())
+    }
+
+    /// Returns the range of `pcm` to keep: everything between the first and
+    /// last sample above the threshold, padded by up to `max_keep_ms` of the
+    /// surrounding silence. An all-silent chunk collapses to an empty range.
+    #[cfg(feature = "build-base")]
+    pub fn keep_range(&self, pcm: &[i16], sample_rate: u32) -> std::ops::Range<usize> {
+        let limit = (self.threshold * i16::MAX as f32) as i32;
+        let is_sound = |sample: &i16| (*sample as i32).abs() > limit;
+        let (Some(first), Some(last)) = (pcm.iter().position(is_sound), pcm.iter().rposition(is_sound)) else {
+            return 0..0;
+        };
+        let keep = (sample_rate as u64 * self.max_keep_ms as u64 / 1000) as usize;
+        first.saturating_sub(keep)..usize::min(pcm.len(), last + 1 + keep)
+    }
+}
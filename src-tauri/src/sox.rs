@@ -0,0 +1,191 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+
+/// Finds SoX, preferring `VOICEREADER_SOX_PATH`, then a copy bundled next to
+/// the app, then `PATH`, then a winget install. Looked up once per run.
+pub fn resolve_sox_path_cached() -> Option<PathBuf> {
+    static SOX_PATH_CACHE: OnceLock<Option<PathBuf>> = OnceLock::new();
+    SOX_PATH_CACHE.get_or_init(resolve_sox_path).clone()
+}
+
+pub fn sox_found() -> bool {
+    resolve_sox_path_cached().is_some()
+}
+
+/// Starts SoX reading raw 16-bit mono PCM at `sample_rate` from stdin and
+/// writing it to `output`, SoX output arguments such as `["-d"]` for the
+/// default device. Its buffer holds about 20 ms, so audio is played as soon
+/// as it is written rather than once a larger block has filled.
+pub fn spawn_pcm_player(sox: &Path, sample_rate: u32, output: &[&str]) -> Result<Child> {
+    let buffer_bytes = (sample_rate as usize / 50 * 2).max(64);
+    let mut command = Command::new(sox);
+    command
+        .arg("-q")
+        .arg("--buffer")
+        .arg(buffer_bytes.to_string())
+        .arg("-t")
+        .arg("raw")
+        .arg("-r")
+        .arg(sample_rate.to_string())
+        .arg("-e")
+        .arg("signed-integer")
+        .arg("-b")
+        .arg("16")
+        .arg("-c")
+        .arg("1")
+        .arg("-L")
+        .arg("-")
+        .args(output);
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to start SoX")
+}
+
+fn resolve_sox_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("VOICEREADER_SOX_PATH").map(PathBuf::from) {
+        if path.exists() {
+            return Some(path);
+        }
+    }
+    if let Some(path) = find_bundled_sox_near_current_executable() {
+        return Some(path);
+    }
+    if command_exists("sox") {
+        return Some(PathBuf::from("sox"));
+    }
+    find_sox_in_windows_winget_location()
+}
+
+fn find_bundled_sox_near_current_executable() -> Option<PathBuf> {
+    let sox_name = if cfg!(target_os = "windows") {
+        "sox.exe"
+    } else {
+        "sox"
+    };
+
+    let mut roots: Vec<PathBuf> = Vec::new();
+    if let Ok(exe) = std::env::current_exe() {
+        if let Some(parent) = exe.parent() {
+            roots.push(parent.to_path_buf());
+            if let Some(grand_parent) = parent.parent() {
+                roots.push(grand_parent.to_path_buf());
+                if let Some(great_grand_parent) = grand_parent.parent() {
+                    roots.push(great_grand_parent.to_path_buf());
+                }
+            }
+        }
+    }
+    if let Ok(cwd) = std::env::current_dir() {
+        roots.push(cwd);
+    }
+
+    let mut seen: HashSet<PathBuf> = HashSet::new();
+    for root in roots {
+        if !seen.insert(root.clone()) {
+            continue;
+        }
+        let candidates = [
+            root.join("binaries").join("sox").join(sox_name),
+            root.join("resources").join("binaries").join("sox").join(sox_name),
+            root.join("binaries").join(sox_name),
+            root.join("resources").join("binaries").join(sox_name),
+            root.join("sox").join(sox_name),
+            root.join("resources").join("sox").join(sox_name),
+            root.join(sox_name),
+        ];
+        for candidate in candidates {
+            if candidate.exists() {
+                return Some(candidate);
+            }
+        }
+    }
+    None
+}
+
+fn command_exists(command: &str) -> bool {
+    Command::new(command)
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok()
+}
+
+fn find_sox_in_windows_winget_location() -> Option<PathBuf> {
+    if !cfg!(target_os = "windows") {
+        return None;
+    }
+
+    let local_app_data = std::env::var_os("LOCALAPPDATA")?;
+    let root = PathBuf::from(local_app_data)
+        .join("Microsoft")
+        .join("WinGet")
+        .join("Packages");
+    if !root.exists() {
+        return None;
+    }
+
+    let mut candidates: Vec<PathBuf> = std::fs::read_dir(&root)
+        .ok()?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if !path.is_dir() {
+                return None;
+            }
+            let name = path.file_name()?.to_string_lossy().to_string();
+            if name.starts_with("ChrisBagwell.SoX_") {
+                Some(path)
+            } else {
+                None
+            }
+        })
+        .collect();
+    candidates.sort();
+
+    for candidate in candidates {
+        if let Ok(entries) = std::fs::read_dir(&candidate) {
+            let mut nested_bins: Vec<PathBuf> = entries
+                .filter_map(|entry| {
+                    let path = entry.ok()?.path();
+                    if !path.is_dir() {
+                        return None;
+                    }
+                    let name = path.file_name()?.to_string_lossy().to_string();
+                    if name.starts_with("sox-") {
+                        let binary = path.join("sox.exe");
+                        if binary.exists() {
+                            return Some(binary);
+                        }
+                    }
+                    None
+                })
+                .collect();
+            nested_bins.sort();
+            if let Some(binary) = nested_bins.into_iter().next() {
+                return Some(binary);
+            }
+        }
+
+        let direct_binary = candidate.join("sox.exe");
+        if direct_binary.exists() {
+            return Some(direct_binary);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,238 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::Metadata;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::maintenance::TEMP_FILE_SUFFIXES;
+
+const HASH_BUFFER_BYTES: usize = 1024 * 1024;
+
+#[derive(Serialize)]
+pub struct DirUsage {
+    pub label: String,
+    pub path: String,
+    pub bytes: u64,
+    pub files: usize,
+}
+
+/// One file's content stored more than once.
+#[derive(Serialize)]
+pub struct DuplicateSet {
+    pub sha256: String,
+    pub size_bytes: u64,
+    /// The first path is the copy kept when deduplicating.
+    pub paths: Vec<String>,
+}
+
+#[derive(Default, Serialize)]
+pub struct DedupReport {
+    pub linked_files: usize,
+    pub reclaimed_bytes: u64,
+    /// Duplicates that could not be replaced, e.g. on another drive.
+    pub errors: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct StorageUsage {
+    pub dirs: Vec<DirUsage>,
+    /// Bytes on disk, counting a file reachable from several places once.
+    pub total_bytes: u64,
+    pub duplicates: Vec<DuplicateSet>,
+    /// What deduplicating would free.
+    pub reclaimable_bytes: u64,
+    /// Set when this call also deduplicated; the rest of the report
+    /// describes storage afterwards.
+    pub dedup: Option<DedupReport>,
+}
+
+struct ScannedFile {
+    path: PathBuf,
+    size: u64,
+}
+
+/// Disk usage of the model folders (`roots`, as label and path) and the
+/// files duplicated between them: Qwen and Kyutai snapshots ship identical
+/// tokenizers and configs, and a model present both in `models_dir` and the
+/// Hugging Face cache is stored twice. Files are compared by SHA-256, hashing
+/// only those whose size matches another's. With `deduplicate`, every
+/// duplicate is replaced by a hard link to the first copy.
+pub fn storage_usage(roots: &[(&str, &Path)], deduplicate: bool) -> Result<StorageUsage> {
+    let dedup = if deduplicate {
+        let (_, files) = scan(roots)?;
+        Some(link_duplicates(&find_duplicates(&files)?))
+    } else {
+        None
+    };
+    let (dirs, files) = scan(roots)?;
+    let duplicates = find_duplicates(&files)?;
+    Ok(StorageUsage {
+        total_bytes: dirs.iter().map(|dir| dir.bytes).sum(),
+        reclaimable_bytes: duplicates
+            .iter()
+            .map(|set| set.size_bytes * (set.paths.len() as u64 - 1))
+            .sum(),
+        dirs,
+        duplicates,
+        dedup,
+    })
+}
+
+/// Walks each root without following symlinks; the Hugging Face cache links
+/// its snapshots to blobs, which are counted where they live. A file already
+/// seen (a hard link, or a root nested in another) is counted once, under the
+/// first root that reaches it.
+fn scan(roots: &[(&str, &Path)]) -> Result<(Vec<DirUsage>, Vec<ScannedFile>)> {
+    let mut seen = HashSet::new();
+    let mut files = Vec::new();
+    let mut dirs = Vec::new();
+    for (label, root) in roots {
+        let mut usage = DirUsage {
+            label: label.to_string(),
+            path: root.display().to_string(),
+            bytes: 0,
+            files: 0,
+        };
+        let mut pending = vec![root.to_path_buf()];
+        while let Some(dir) = pending.pop() {
+            if !dir.is_dir() {
+                continue;
+            }
+            let entries = std::fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))?;
+            for entry in entries.filter_map(|entry| entry.ok()) {
+                let path = entry.path();
+                let Ok(metadata) = std::fs::symlink_metadata(&path) else {
+                    continue;
+                };
+                if metadata.is_dir() {
+                    pending.push(path);
+                    continue;
+                }
+                if !metadata.is_file() || !seen.insert(file_key(&path, &metadata)) {
+                    continue;
+                }
+                usage.bytes += metadata.len();
+                usage.files += 1;
+                let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+                // Partial downloads change under us and are never worth linking.
+                if metadata.len() > 0 && !TEMP_FILE_SUFFIXES.iter().any(|suffix| name.ends_with(suffix)) {
+                    files.push(ScannedFile {
+                        path,
+                        size: metadata.len(),
+                    });
+                }
+            }
+        }
+        dirs.push(usage);
+    }
+    Ok((dirs, files))
+}
+
+#[cfg(unix)]
+fn file_key(_path: &Path, metadata: &Metadata) -> (u64, u64) {
+    use std::os::unix::fs::MetadataExt;
+    (metadata.dev(), metadata.ino())
+}
+
+/// Without inode numbers, hard links look like separate files, so linked
+/// copies keep showing as duplicates; nested roots are still caught by the
+/// canonical path.
+#[cfg(not(unix))]
+fn file_key(path: &Path, _metadata: &Metadata) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn find_duplicates(files: &[ScannedFile]) -> Result<Vec<DuplicateSet>> {
+    let mut by_size: HashMap<u64, Vec<&ScannedFile>> = HashMap::new();
+    for file in files {
+        by_size.entry(file.size).or_default().push(file);
+    }
+    let mut duplicates = Vec::new();
+    for (size, candidates) in by_size {
+        if candidates.len() < 2 {
+            continue;
+        }
+        // Keeps scan order within each hash, so the first root's copy wins.
+        let mut by_hash: Vec<(String, Vec<String>)> = Vec::new();
+        for file in candidates {
+            let hash = sha256_file(&file.path)?;
+            let path = file.path.display().to_string();
+            match by_hash.iter_mut().find(|(existing, _)| *existing == hash) {
+                Some((_, paths)) => paths.push(path),
+                None => by_hash.push((hash, vec![path])),
+            }
+        }
+        duplicates.extend(
+            by_hash
+                .into_iter()
+                .filter(|(_, paths)| paths.len() > 1)
+                .map(|(sha256, paths)| DuplicateSet {
+                    sha256,
+                    size_bytes: size,
+                    paths,
+                }),
+        );
+    }
+    duplicates.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes).then_with(|| a.paths.cmp(&b.paths)));
+    Ok(duplicates)
+}
+
+fn sha256_file(path: &Path) -> Result<String> {
+    let mut file = std::fs::File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; HASH_BUFFER_BYTES];
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
+
+/// Hard links rather than symlinks: deleting either folder later leaves the
+/// other's copy intact, and no loader sees a link it might not follow.
+fn link_duplicates(duplicates: &[DuplicateSet]) -> DedupReport {
+    let mut report = DedupReport::default();
+    for set in duplicates {
+        let Some((original, copies)) = set.paths.split_first() else {
+            continue;
+        };
+        for copy in copies {
+            match replace_with_link(Path::new(original), Path::new(copy)) {
+                Ok(()) => {
+                    report.linked_files += 1;
+                    report.reclaimed_bytes += set.size_bytes;
+                }
+                Err(err) => report.errors.push(format!("{copy}: {err:#}")),
+            }
+        }
+    }
+    report
+}
+
+/// Links `original` next to `copy`, then renames the link over it, so
+/// `copy` never goes missing even if this fails half-way.
+fn replace_with_link(original: &Path, copy: &Path) -> Result<()> {
+    let name = copy.file_name().and_then(|name| name.to_str()).unwrap_or("file");
+    // A `.tmp` leftover is cleaned up by storage maintenance.
+    let link = copy.with_file_name(format!("{name}.dedup.tmp"));
+    let _ = std::fs::remove_file(&link);
+    std::fs::hard_link(original, &link)
+        .with_context(|| format!("Failed to link {}", original.display()))?;
+    if let Err(err) = std::fs::rename(&link, copy) {
+        let _ = std::fs::remove_file(&link);
+        return Err(err).with_context(|| format!("Failed to replace {}", copy.display()));
+    }
+    Ok(())
+}
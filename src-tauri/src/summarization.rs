@@ -0,0 +1,231 @@
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+const SUMMARY_TIMEOUT_SECS: u64 = 120;
+const COMMAND_POLL_MS: u64 = 50;
+/// Placeholder in command arguments replaced with the prompt. Without it the
+/// prompt is written to the command's stdin.
+const PROMPT_PLACEHOLDER: &str = "{prompt}";
+
+/// Where summaries come from. Both are meant for local models; nothing is
+/// summarized unless one is configured.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SummaryProvider {
+    #[default]
+    Disabled,
+    /// A local binary such as llama.cpp's `llama-cli -m model.gguf
+    /// --no-display-prompt -p {prompt}`; the summary is its stdout.
+    Command { program: String, args: Vec<String> },
+    /// OpenAI-compatible `POST {endpoint}/chat/completions`, as served by
+    /// `llama-server`, Ollama or LM Studio.
+    ChatCompletions {
+        endpoint: String,
+        api_key: Option<String>,
+        model: String,
+    },
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct SummarySettings {
+    pub provider: SummaryProvider,
+    /// Sentences asked for; the model may return fewer.
+    pub max_sentences: u32,
+    /// Text shorter than this is read in full instead of summarized.
+    pub min_chars: usize,
+}
+
+impl Default for SummarySettings {
+    fn default() -> Self {
+        Self {
+            provider: SummaryProvider::Disabled,
+            max_sentences: 3,
+            min_chars: 600,
+        }
+    }
+}
+
+impl SummarySettings {
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self.provider, SummaryProvider::Disabled)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        match &self.provider {
+            SummaryProvider::Disabled => return Ok(()),
+            SummaryProvider::Command { program, .. } => {
+                if program.trim().is_empty() {
+                    return Err(anyhow!("Summary command cannot be empty"));
+                }
+            }
+            SummaryProvider::ChatCompletions { endpoint, model, .. } => {
+                let endpoint = endpoint.trim();
+                if !endpoint.starts_with("http://") && !endpoint.starts_with("https://") {
+                    return Err(anyhow!("Summary endpoint must start with http:// or https://"));
+                }
+                if model.trim().is_empty() {
+                    return Err(anyhow!("Summary model cannot be empty"));
+                }
+            }
+        }
+        if !(1..=10).contains(&self.max_sentences) {
+            return Err(anyhow!("Summary length must be between 1 and 10 sentences"));
+        }
+        Ok(())
+    }
+}
+
+/// Summarizes `text` for listening. Returns `None` when summarization is
+/// disabled or the text is short enough to read in full.
+pub async fn summarize_text(settings: &SummarySettings, text: &str) -> Result<Option<String>> {
+    if !settings.is_enabled() || text.chars().count() < settings.min_chars {
+        return Ok(None);
+    }
+    let instructions = format!(
+        "Summarize the user's text in at most {} sentences of plain spoken English, to be read aloud. \
+         Do not use formulas, symbols, markdown, lists or headings; describe any math in words. \
+         Reply with the summary only.",
+        settings.max_sentences
+    );
+
+    let summary = match &settings.provider {
+        SummaryProvider::Disabled => return Ok(None),
+        SummaryProvider::Command { program, args } => {
+            let prompt = format!("{instructions}\n\nText:\n{text}\n\nSummary:");
+            let program = program.trim().to_string();
+            let args = args.clone();
+            tauri::async_runtime::spawn_blocking(move || run_summary_command(&program, &args, &prompt))
+                .await
+                .map_err(|err| anyhow!("Summary command task failed: {err}"))??
+        }
+        SummaryProvider::ChatCompletions {
+            endpoint,
+            api_key,
+            model,
+        } => {
+            let url = format!("{}/chat/completions", endpoint.trim().trim_end_matches('/'));
+            let body = json!({
+                "model": model,
+                "temperature": 0.2,
+                "messages": [
+                    { "role": "system", "content": instructions },
+                    { "role": "user", "content": text },
+                ],
+            });
+            let mut request = Client::new()
+                .post(&url)
+                .timeout(Duration::from_secs(SUMMARY_TIMEOUT_SECS))
+                .json(&body);
+            if let Some(key) = api_key.as_deref().filter(|key| !key.is_empty()) {
+                request = request.header("Authorization", format!("Bearer {key}"));
+            }
+            let response = request
+                .send()
+                .await
+                .with_context(|| format!("Summary request failed for {url}"))?;
+            let status = response.status();
+            if !status.is_success() {
+                let body_text = response.text().await.unwrap_or_default();
+                return Err(anyhow!("Summary request to {url} failed with status {status}: {body_text}"));
+            }
+            let payload = response
+                .json::<Value>()
+                .await
+                .with_context(|| format!("Failed to decode summary response for {url}"))?;
+            payload
+                .pointer("/choices/0/message/content")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .ok_or_else(|| anyhow!("Summary response from {url} has no message content"))?
+        }
+    };
+
+    let summary = plain_speech(&summary);
+    if summary.is_empty() {
+        return Err(anyhow!("Summary provider returned empty text"));
+    }
+    Ok(Some(summary))
+}
+
+/// Runs the summary command, killing it after `SUMMARY_TIMEOUT_SECS`.
+fn run_summary_command(program: &str, args: &[String], prompt: &str) -> Result<String> {
+    let uses_placeholder = args.iter().any(|arg| arg.contains(PROMPT_PLACEHOLDER));
+    let mut command = Command::new(program);
+    command
+        .args(args.iter().map(|arg| arg.replace(PROMPT_PLACEHOLDER, prompt)))
+        .stdin(if uses_placeholder { Stdio::null() } else { Stdio::piped() })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("Failed to run summary command {program}"))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        let prompt = prompt.to_string();
+        std::thread::spawn(move || {
+            let _ = stdin.write_all(prompt.as_bytes());
+        });
+    }
+    // Drained on their own threads so a chatty model cannot fill a pipe and stall.
+    let stdout = child.stdout.take().map(|mut stdout| {
+        std::thread::spawn(move || {
+            let mut out = Vec::new();
+            let _ = stdout.read_to_end(&mut out);
+            out
+        })
+    });
+    let stderr = child.stderr.take().map(|mut stderr| {
+        std::thread::spawn(move || {
+            let mut out = Vec::new();
+            let _ = stderr.read_to_end(&mut out);
+            out
+        })
+    });
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().context("Failed to wait for summary command")? {
+            break status;
+        }
+        if started.elapsed() >= Duration::from_secs(SUMMARY_TIMEOUT_SECS) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(anyhow!("Summary command {program} timed out after {SUMMARY_TIMEOUT_SECS}s"));
+        }
+        std::thread::sleep(Duration::from_millis(COMMAND_POLL_MS));
+    };
+
+    let stdout = stdout.and_then(|reader| reader.join().ok()).unwrap_or_default();
+    let stderr = stderr.and_then(|reader| reader.join().ok()).unwrap_or_default();
+    if !status.success() {
+        let stderr = String::from_utf8_lossy(&stderr);
+        return Err(anyhow!("Summary command {program} failed ({status}): {}", stderr.trim()));
+    }
+    Ok(String::from_utf8_lossy(&stdout).to_string())
+}
+
+/// Strips the markdown models add despite being asked not to, so the
+/// summary does not read out asterisks and hashes.
+fn plain_speech(summary: &str) -> String {
+    summary
+        .lines()
+        .map(|line| {
+            let line = line.trim().trim_start_matches('#').trim_start();
+            let line = line
+                .strip_prefix("- ")
+                .or_else(|| line.strip_prefix("* "))
+                .unwrap_or(line);
+            line.replace(['*', '`'], "")
+        })
+        .filter(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim()
+        .to_string()
+}
@@ -0,0 +1,119 @@
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+
+use crate::read_estimate::WORDS_PER_MINUTE;
+
+const MIN_TARGET_WPM: f32 = 60.0;
+const MAX_TARGET_WPM: f32 = 400.0;
+/// Pacing aims to be back on schedule this many words ahead, so one slow
+/// sentence is made up over the next few rather than all at once.
+const LOOKAHEAD_WORDS: usize = 40;
+/// Playback rates pacing may pick; beyond these the speech gets hard to follow.
+const MIN_PACING_RATE: f32 = 0.5;
+const MAX_PACING_RATE: f32 = 2.5;
+
+/// Target pace from `start_word` (0-based) until the next segment.
+#[derive(Clone, Deserialize)]
+pub struct PaceSegment {
+    pub start_word: usize,
+    pub wpm: f32,
+}
+
+/// When each word of a text should be spoken under a words-per-minute
+/// schedule, computed upfront so the rate can be corrected chunk by chunk.
+pub struct PacingPlan {
+    /// Char offset where each word starts.
+    word_offsets: Vec<usize>,
+    /// Scheduled seconds at the start of each word, plus the end of the text.
+    target_secs: Vec<f64>,
+}
+
+impl PacingPlan {
+    /// `schedule` must start at word 0 with strictly increasing `start_word`.
+    pub fn new(text: &str, schedule: &[PaceSegment]) -> Result<Self> {
+        let Some(first) = schedule.first() else {
+            return Err(anyhow!("Pacing schedule needs at least one segment"));
+        };
+        if first.start_word != 0 {
+            return Err(anyhow!("The first pacing segment must start at word 0"));
+        }
+        if schedule.windows(2).any(|pair| pair[1].start_word <= pair[0].start_word) {
+            return Err(anyhow!("Pacing segments must be ordered by increasing start_word"));
+        }
+        if let Some(segment) = schedule
+            .iter()
+            .find(|segment| !(MIN_TARGET_WPM..=MAX_TARGET_WPM).contains(&segment.wpm))
+        {
+            return Err(anyhow!(
+                "wpm must be in [{MIN_TARGET_WPM}, {MAX_TARGET_WPM}] (got {} at word {})",
+                segment.wpm,
+                segment.start_word
+            ));
+        }
+
+        let word_offsets = word_offsets(text);
+        if word_offsets.is_empty() {
+            return Err(anyhow!("Text has no words to pace"));
+        }
+        let mut target_secs = Vec::with_capacity(word_offsets.len() + 1);
+        let mut elapsed = 0.0;
+        let mut segment = 0;
+        for word in 0..word_offsets.len() {
+            while schedule.get(segment + 1).is_some_and(|next| next.start_word <= word) {
+                segment += 1;
+            }
+            target_secs.push(elapsed);
+            elapsed += 60.0 / schedule[segment].wpm as f64;
+        }
+        target_secs.push(elapsed);
+        Ok(Self {
+            word_offsets,
+            target_secs,
+        })
+    }
+
+    /// Scheduled length of the whole text.
+    pub fn total_secs(&self) -> f64 {
+        self.target_secs.last().copied().unwrap_or(0.0)
+    }
+
+    /// Rate for the audio starting at char `text_offset` when `emitted_secs`
+    /// of audio have been produced so far. Speech at 1.0x is assumed to run
+    /// at the typical narration pace; the error of that guess is corrected
+    /// by the next call, since `emitted_secs` is measured.
+    pub fn rate_at(&self, text_offset: usize, emitted_secs: f64) -> f32 {
+        let word = self
+            .word_offsets
+            .partition_point(|offset| *offset <= text_offset)
+            .saturating_sub(1);
+        let goal = (word + LOOKAHEAD_WORDS).min(self.word_offsets.len());
+        let natural_secs = (goal - word) as f64 * 60.0 / WORDS_PER_MINUTE;
+        let available_secs = self.target_secs[goal] - emitted_secs;
+        if available_secs <= 0.0 {
+            return MAX_PACING_RATE;
+        }
+        ((natural_secs / available_secs) as f32).clamp(MIN_PACING_RATE, MAX_PACING_RATE)
+    }
+}
+
+/// Char offsets of whitespace-separated tokens that contain a letter or digit.
+fn word_offsets(text: &str) -> Vec<usize> {
+    let mut offsets = Vec::new();
+    let mut token_start: Option<usize> = None;
+    let mut token_has_word_char = false;
+    for (index, ch) in text.chars().enumerate() {
+        if ch.is_whitespace() {
+            if let (Some(start), true) = (token_start.take(), token_has_word_char) {
+                offsets.push(start);
+            }
+            token_has_word_char = false;
+            continue;
+        }
+        token_start.get_or_insert(index);
+        token_has_word_char |= ch.is_alphanumeric();
+    }
+    if let (Some(start), true) = (token_start, token_has_word_char) {
+        offsets.push(start);
+    }
+    offsets
+}
@@ -0,0 +1,285 @@
+use std::collections::BTreeMap;
+use std::io::{IsTerminal, Read};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use futures_util::future::BoxFuture;
+use serde::Serialize;
+use tauri::{AppHandle, ClipboardManager};
+
+use crate::event_routing::EmitRouted;
+use crate::job_history::{CaptureMethod, JobOrigin};
+use crate::readability::{extract_article, visible_text};
+
+pub const SELECTION_SOURCE: &str = "selection";
+pub const WINDOW_OCR_SOURCE: &str = "window_ocr";
+pub const CLIPBOARD_SOURCE: &str = "clipboard";
+pub const FILE_SOURCE: &str = "file";
+pub const URL_SOURCE: &str = "url";
+pub const STDIN_SOURCE: &str = "stdin";
+pub const EXTENSION_SOURCE: &str = "extension";
+
+/// Largest file, download or piped input read as text.
+const MAX_SOURCE_BYTES: usize = 4 * 1024 * 1024;
+const URL_FETCH_TIMEOUT_SECS: u64 = 20;
+/// Download progress is reported at most once per this many bytes.
+const URL_PROGRESS_STEP_BYTES: u64 = 64 * 1024;
+
+#[derive(Clone, Serialize)]
+struct UrlFetchProgressPayload<'a> {
+    url: &'a str,
+    /// `downloading`, then `extracting` once the body is in.
+    stage: &'static str,
+    received_bytes: u64,
+    total_bytes: Option<u64>,
+}
+
+/// Text taken from a source, with where it came from for the job metadata.
+pub struct AcquiredText {
+    pub text: String,
+    pub origin: JobOrigin,
+}
+
+/// Somewhere text to read can come from. Text from every source goes
+/// through the same preprocess, chunk and speak pipeline.
+pub trait TextSource: Send + Sync {
+    /// Registry key.
+    fn name(&self) -> &'static str;
+
+    /// Takes the text to read. `argument` is source specific (a path, a URL,
+    /// pushed text); `Ok(None)` means the source had nothing to read.
+    fn acquire<'a>(&'a self, app: &'a AppHandle, argument: Option<&'a str>) -> BoxFuture<'a, Result<Option<AcquiredText>>>;
+}
+
+/// Text sources by name, managed as app state.
+#[derive(Default)]
+pub struct TextSourceRegistry {
+    sources: BTreeMap<&'static str, Box<dyn TextSource>>,
+}
+
+impl TextSourceRegistry {
+    /// Registry with the sources that need nothing from the engine state.
+    pub fn with_standard_sources() -> Self {
+        let mut registry = Self::default();
+        registry.register(ClipboardSource);
+        registry.register(FileSource);
+        registry.register(UrlSource);
+        registry.register(StdinSource);
+        registry.register(ExtensionSource);
+        registry
+    }
+
+    /// Adds `source`, replacing any source registered under the same name.
+    pub fn register(&mut self, source: impl TextSource + 'static) {
+        self.sources.insert(source.name(), Box::new(source));
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.sources.keys().copied().collect()
+    }
+
+    /// Text from the source called `name`; blank text counts as nothing to read.
+    pub async fn acquire(&self, app: &AppHandle, name: &str, argument: Option<&str>) -> Result<Option<AcquiredText>> {
+        let source = self.sources.get(name).ok_or_else(|| {
+            anyhow!("Unknown text source {name}; expected one of {}", self.names().join(", "))
+        })?;
+        let acquired = source.acquire(app, argument).await?;
+        Ok(acquired.filter(|acquired| !acquired.text.trim().is_empty()))
+    }
+}
+
+fn origin(capture_method: CaptureMethod, location: Option<&str>) -> JobOrigin {
+    JobOrigin {
+        location: location.map(str::to_string),
+        ..JobOrigin::with_method(capture_method)
+    }
+}
+
+fn required_argument<'a>(argument: Option<&'a str>, source: &str, what: &str) -> Result<&'a str> {
+    argument
+        .map(str::trim)
+        .filter(|argument| !argument.is_empty())
+        .ok_or_else(|| anyhow!("The {source} source needs a {what}"))
+}
+
+/// Decodes `bytes` as UTF-8, replacing invalid sequences and dropping a BOM.
+fn decode_text(bytes: &[u8], label: &str) -> Result<String> {
+    if bytes.len() > MAX_SOURCE_BYTES {
+        return Err(anyhow!(
+            "{label} is larger than {} MB",
+            MAX_SOURCE_BYTES / (1024 * 1024)
+        ));
+    }
+    let text = String::from_utf8_lossy(bytes);
+    Ok(text.strip_prefix('\u{FEFF}').unwrap_or(&text).to_string())
+}
+
+/// The clipboard as it is, without simulating a copy.
+struct ClipboardSource;
+
+impl TextSource for ClipboardSource {
+    fn name(&self) -> &'static str {
+        CLIPBOARD_SOURCE
+    }
+
+    fn acquire<'a>(&'a self, app: &'a AppHandle, _argument: Option<&'a str>) -> BoxFuture<'a, Result<Option<AcquiredText>>> {
+        Box::pin(async move {
+            let text = app
+                .clipboard_manager()
+                .read_text()
+                .map_err(|err| anyhow!("Failed to read the clipboard: {err}"))?;
+            Ok(text.map(|text| AcquiredText {
+                text,
+                origin: origin(CaptureMethod::Clipboard, None),
+            }))
+        })
+    }
+}
+
+/// A text file; the argument is its path.
+struct FileSource;
+
+impl TextSource for FileSource {
+    fn name(&self) -> &'static str {
+        FILE_SOURCE
+    }
+
+    fn acquire<'a>(&'a self, _app: &'a AppHandle, argument: Option<&'a str>) -> BoxFuture<'a, Result<Option<AcquiredText>>> {
+        Box::pin(async move {
+            let path = required_argument(argument, FILE_SOURCE, "file path")?;
+            let bytes = std::fs::read(path).with_context(|| format!("Failed to read {path}"))?;
+            Ok(Some(AcquiredText {
+                text: decode_text(&bytes, path)?,
+                origin: origin(CaptureMethod::File, Some(path)),
+            }))
+        })
+    }
+}
+
+/// A document downloaded over HTTP(S); the argument is its URL. HTML pages
+/// are reduced to their main article, led by its title, or to all visible
+/// text when no article stands out. Progress is emitted as
+/// `voicereader:url-fetch-progress`.
+struct UrlSource;
+
+impl TextSource for UrlSource {
+    fn name(&self) -> &'static str {
+        URL_SOURCE
+    }
+
+    fn acquire<'a>(&'a self, app: &'a AppHandle, argument: Option<&'a str>) -> BoxFuture<'a, Result<Option<AcquiredText>>> {
+        Box::pin(async move {
+            let url = required_argument(argument, URL_SOURCE, "URL")?;
+            if !(url.starts_with("http://") || url.starts_with("https://")) {
+                return Err(anyhow!("Only http and https URLs can be read (got {url})"));
+            }
+            let mut response = reqwest::Client::new()
+                .get(url)
+                .timeout(Duration::from_secs(URL_FETCH_TIMEOUT_SECS))
+                .send()
+                .await
+                .with_context(|| format!("Failed to fetch {url}"))?
+                .error_for_status()
+                .with_context(|| format!("Failed to fetch {url}"))?;
+            let is_html = response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.contains("html"));
+            let total_bytes = response.content_length();
+            let progress = |stage, received_bytes| {
+                let _ = app.emit_routed(
+                    "voicereader:url-fetch-progress",
+                    UrlFetchProgressPayload {
+                        url,
+                        stage,
+                        received_bytes,
+                        total_bytes,
+                    },
+                );
+            };
+            progress("downloading", 0);
+            let mut body = Vec::new();
+            let mut reported = 0u64;
+            while let Some(chunk) = response.chunk().await.with_context(|| format!("Failed to download {url}"))? {
+                body.extend_from_slice(&chunk);
+                if body.len() > MAX_SOURCE_BYTES {
+                    break;
+                }
+                if body.len() as u64 - reported >= URL_PROGRESS_STEP_BYTES {
+                    reported = body.len() as u64;
+                    progress("downloading", reported);
+                }
+            }
+            let text = decode_text(&body, url)?;
+            let mut origin = origin(CaptureMethod::Url, Some(url));
+            if !is_html {
+                return Ok(Some(AcquiredText { text, origin }));
+            }
+
+            progress("extracting", body.len() as u64);
+            let text = match extract_article(&text) {
+                Some(article) => {
+                    origin.window_title = article.title.clone().unwrap_or_default();
+                    match article.title {
+                        Some(title) => format!("{title}\n\n{}", article.text),
+                        None => article.text,
+                    }
+                }
+                None => visible_text(&text),
+            };
+            Ok(Some(AcquiredText { text, origin }))
+        })
+    }
+}
+
+/// Text piped into the app at launch, such as `echo hi | voicereader`.
+/// Drained by the first read.
+struct StdinSource;
+
+impl TextSource for StdinSource {
+    fn name(&self) -> &'static str {
+        STDIN_SOURCE
+    }
+
+    fn acquire<'a>(&'a self, _app: &'a AppHandle, _argument: Option<&'a str>) -> BoxFuture<'a, Result<Option<AcquiredText>>> {
+        Box::pin(async move {
+            if std::io::stdin().is_terminal() {
+                return Err(anyhow!("Standard input is a terminal; pipe text into the app to read it"));
+            }
+            let bytes = tauri::async_runtime::spawn_blocking(|| -> std::io::Result<Vec<u8>> {
+                let mut bytes = Vec::new();
+                std::io::stdin()
+                    .lock()
+                    .take(MAX_SOURCE_BYTES as u64 + 1)
+                    .read_to_end(&mut bytes)?;
+                Ok(bytes)
+            })
+            .await
+            .map_err(|err| anyhow!("Standard input task failed: {err}"))?
+            .context("Failed to read standard input")?;
+            Ok(Some(AcquiredText {
+                text: decode_text(&bytes, "Standard input")?,
+                origin: origin(CaptureMethod::Stdin, None),
+            }))
+        })
+    }
+}
+
+/// Text pushed by a browser or editor extension; the argument is the text.
+struct ExtensionSource;
+
+impl TextSource for ExtensionSource {
+    fn name(&self) -> &'static str {
+        EXTENSION_SOURCE
+    }
+
+    fn acquire<'a>(&'a self, _app: &'a AppHandle, argument: Option<&'a str>) -> BoxFuture<'a, Result<Option<AcquiredText>>> {
+        Box::pin(async move {
+            Ok(argument.map(|text| AcquiredText {
+                text: text.to_string(),
+                origin: origin(CaptureMethod::Extension, None),
+            }))
+        })
+    }
+}
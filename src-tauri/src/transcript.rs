@@ -0,0 +1,204 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveTime};
+use serde::Deserialize;
+
+use crate::job_history::{CaptureMethod, JobHistoryEntry};
+use crate::schedules::{local_datetime, parse_local_datetime};
+
+/// Inclusive start and end of a range; `None` is unbounded.
+type RangeBounds = (Option<DateTime<Local>>, Option<DateTime<Local>>);
+
+/// Which reads a transcript covers.
+#[derive(Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TranscriptRange {
+    /// Since the app was started.
+    Session,
+    /// Since local midnight.
+    Today,
+    /// The last `hours` hours.
+    Last { hours: u32 },
+    /// From `from` up to `to` (or now). Either may be a date (`YYYY-MM-DD`,
+    /// the whole day for `to`), `YYYY-MM-DDTHH:MM` or RFC 3339.
+    Between { from: String, to: Option<String> },
+    All,
+}
+
+impl TranscriptRange {
+    fn bounds(&self, now: DateTime<Local>, session_started_at: DateTime<Local>) -> Result<RangeBounds> {
+        Ok(match self {
+            Self::Session => (Some(session_started_at), None),
+            Self::Today => (local_datetime(now.date_naive(), NaiveTime::MIN), None),
+            Self::Last { hours } => (Some(now - Duration::hours(*hours as i64)), None),
+            Self::Between { from, to } => {
+                let from = parse_bound(from, false)?;
+                let to = to.as_deref().map(|to| parse_bound(to, true)).transpose()?;
+                if to.is_some_and(|to| to < from) {
+                    return Err(anyhow!("Transcript range ends before it starts"));
+                }
+                (Some(from), to)
+            }
+            Self::All => (None, None),
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum TranscriptFormat {
+    Text,
+    Markdown,
+}
+
+impl TranscriptFormat {
+    /// Markdown for `.md`/`.markdown` paths, plain text otherwise.
+    pub fn for_path(path: &Path) -> Self {
+        let markdown = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown"));
+        if markdown {
+            Self::Markdown
+        } else {
+            Self::Text
+        }
+    }
+}
+
+/// History entries that started within `range`, oldest first.
+pub fn select_entries<'a>(
+    entries: &'a [JobHistoryEntry],
+    range: &TranscriptRange,
+    now: DateTime<Local>,
+    session_started_at: DateTime<Local>,
+) -> Result<Vec<&'a JobHistoryEntry>> {
+    let (from, to) = range.bounds(now, session_started_at)?;
+    Ok(entries
+        .iter()
+        .filter(|entry| {
+            // Entries with an unreadable time only appear in unbounded exports.
+            let Some(started_at) = started_at(entry) else {
+                return from.is_none() && to.is_none();
+            };
+            from.is_none_or(|from| started_at >= from) && to.is_none_or(|to| started_at <= to)
+        })
+        .collect())
+}
+
+/// A log of what was read: one block per job with its start time, where the
+/// text came from and the text itself. Markdown groups the jobs by day.
+pub fn render_transcript(entries: &[&JobHistoryEntry], format: TranscriptFormat, exported_at: DateTime<Local>) -> String {
+    let mut out = String::new();
+    let exported = exported_at.format("%Y-%m-%d %H:%M");
+    match format {
+        TranscriptFormat::Markdown => {
+            out.push_str("# Reading transcript\n\n");
+            out.push_str(&format!("_Exported {exported}, {} read(s)._\n", entries.len()));
+        }
+        TranscriptFormat::Text => {
+            out.push_str(&format!("Reading transcript, exported {exported}, {} read(s)\n", entries.len()));
+        }
+    }
+
+    let mut current_day = None;
+    for entry in entries {
+        let started = started_at(entry);
+        let source = describe_source(entry);
+        let text = entry.text.trim();
+        match format {
+            TranscriptFormat::Markdown => {
+                let day = started.map(|started| started.date_naive());
+                if current_day != Some(day) {
+                    current_day = Some(day);
+                    let heading = day.map_or_else(|| "Unknown date".to_string(), |day| day.format("%Y-%m-%d").to_string());
+                    out.push_str(&format!("\n## {heading}\n"));
+                }
+                let time = started.map_or_else(|| "--:--".to_string(), |started| started.format("%H:%M:%S").to_string());
+                out.push_str(&format!("\n### {time} · {}\n\n", escape_markdown(&source)));
+                for line in text.lines() {
+                    if line.trim().is_empty() {
+                        out.push_str(">\n");
+                    } else {
+                        out.push_str(&format!("> {line}\n"));
+                    }
+                }
+            }
+            TranscriptFormat::Text => {
+                let time = started.map_or_else(
+                    || entry.started_at.clone(),
+                    |started| started.format("%Y-%m-%d %H:%M:%S").to_string(),
+                );
+                out.push_str(&format!("\n[{time}] {source}\n{text}\n"));
+            }
+        }
+    }
+    out
+}
+
+pub fn write_transcript(path: &Path, body: &str) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create transcript directory {}", parent.display()))?;
+    }
+    std::fs::write(path, body).with_context(|| format!("Failed to write transcript {}", path.display()))
+}
+
+fn started_at(entry: &JobHistoryEntry) -> Option<DateTime<Local>> {
+    DateTime::parse_from_rfc3339(&entry.started_at)
+        .ok()
+        .map(|started_at| started_at.with_timezone(&Local))
+}
+
+/// A date alone is the start of that day, or its end when it closes the range.
+fn parse_bound(bound: &str, end: bool) -> Result<DateTime<Local>> {
+    let Ok(date) = NaiveDate::parse_from_str(bound.trim(), "%Y-%m-%d") else {
+        return parse_local_datetime(bound);
+    };
+    let date = if end { date.succ_opt().unwrap_or(date) } else { date };
+    let start = local_datetime(date, NaiveTime::MIN).ok_or_else(|| anyhow!("{bound} does not exist in the local time zone"))?;
+    Ok(if end { start - Duration::seconds(1) } else { start })
+}
+
+/// How the text was captured, plus the app and window or path it came from,
+/// e.g. `Selection (hotkey), Firefox: Release notes`.
+fn describe_source(entry: &JobHistoryEntry) -> String {
+    let capture = &entry.capture;
+    let method = match capture.capture_method {
+        CaptureMethod::ClipboardProbe => "Selection",
+        CaptureMethod::Accessibility => "Selection (accessibility)",
+        CaptureMethod::PrimarySelection => "Selection (primary)",
+        CaptureMethod::Ocr => "Window OCR",
+        CaptureMethod::Clipboard => "Clipboard",
+        CaptureMethod::File => "File",
+        CaptureMethod::Url => "URL",
+        CaptureMethod::Stdin => "Standard input",
+        CaptureMethod::Extension => "Extension",
+        CaptureMethod::Direct => "Direct",
+    };
+    let mut description = format!("{method} ({})", entry.source);
+    let window = [capture.app_name.as_deref(), Some(capture.window_title.as_str())]
+        .into_iter()
+        .flatten()
+        .filter(|part| !part.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join(": ");
+    if !window.is_empty() {
+        description.push_str(&format!(", {window}"));
+    }
+    if let Some(location) = capture.location.as_deref() {
+        description.push_str(&format!(", {location}"));
+    }
+    description
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.chars()
+        .fold(String::with_capacity(text.len()), |mut out, ch| {
+            if matches!(ch, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#') {
+                out.push('\\');
+            }
+            out.push(ch);
+            out
+        })
+}
@@ -0,0 +1,158 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+const TRANSLATION_TIMEOUT_SECS: u64 = 30;
+
+/// Where translation requests go. Both HTTP shapes work against local servers
+/// (LibreTranslate, Ollama, llama.cpp) as well as hosted APIs.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TranslationProvider {
+    #[default]
+    Disabled,
+    /// LibreTranslate-compatible `POST {endpoint}/translate`.
+    LibreTranslate {
+        endpoint: String,
+        api_key: Option<String>,
+    },
+    /// OpenAI-compatible `POST {endpoint}/chat/completions`.
+    ChatCompletions {
+        endpoint: String,
+        api_key: Option<String>,
+        model: String,
+    },
+}
+
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TranslationSettings {
+    pub provider: TranslationProvider,
+    /// Language code of the captured text; `None` lets the provider detect it.
+    pub source_language: Option<String>,
+    pub target_language: String,
+    /// Voice used for translated speech; `None` keeps the selected voice.
+    pub target_voice_id: Option<String>,
+}
+
+impl TranslationSettings {
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self.provider, TranslationProvider::Disabled)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let endpoint = match &self.provider {
+            TranslationProvider::Disabled => return Ok(()),
+            TranslationProvider::LibreTranslate { endpoint, .. } => endpoint,
+            TranslationProvider::ChatCompletions { endpoint, model, .. } => {
+                if model.trim().is_empty() {
+                    return Err(anyhow!("Translation model cannot be empty"));
+                }
+                endpoint
+            }
+        };
+        let endpoint = endpoint.trim();
+        if !endpoint.starts_with("http://") && !endpoint.starts_with("https://") {
+            return Err(anyhow!("Translation endpoint must start with http:// or https://"));
+        }
+        if self.target_language.trim().is_empty() {
+            return Err(anyhow!("Translation target language cannot be empty"));
+        }
+        Ok(())
+    }
+}
+
+/// Translates `text` with the configured provider. Returns `None` when
+/// translation is disabled so callers can fall through to the original text.
+pub async fn translate_text(settings: &TranslationSettings, text: &str) -> Result<Option<String>> {
+    if !settings.is_enabled() {
+        return Ok(None);
+    }
+    let client = Client::builder()
+        .timeout(Duration::from_secs(TRANSLATION_TIMEOUT_SECS))
+        .build()
+        .context("Failed to build translation HTTP client")?;
+    let source = settings
+        .source_language
+        .as_deref()
+        .map(str::trim)
+        .filter(|lang| !lang.is_empty());
+    let target = settings.target_language.trim();
+
+    let translated = match &settings.provider {
+        TranslationProvider::Disabled => return Ok(None),
+        TranslationProvider::LibreTranslate { endpoint, api_key } => {
+            let url = format!("{}/translate", endpoint.trim().trim_end_matches('/'));
+            let mut body = json!({
+                "q": text,
+                "source": source.unwrap_or("auto"),
+                "target": target,
+                "format": "text",
+            });
+            if let Some(key) = api_key.as_deref().filter(|key| !key.is_empty()) {
+                body["api_key"] = json!(key);
+            }
+            let payload = post_json(&client, &url, None, body).await?;
+            payload
+                .get("translatedText")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .ok_or_else(|| anyhow!("Translation response from {url} has no translatedText"))?
+        }
+        TranslationProvider::ChatCompletions {
+            endpoint,
+            api_key,
+            model,
+        } => {
+            let url = format!("{}/chat/completions", endpoint.trim().trim_end_matches('/'));
+            let from = source.map(|lang| format!(" from {lang}")).unwrap_or_default();
+            let body = json!({
+                "model": model,
+                "temperature": 0,
+                "messages": [
+                    {
+                        "role": "system",
+                        "content": format!(
+                            "Translate the user's text{from} into {target}. Reply with the translation only, keeping paragraph breaks."
+                        ),
+                    },
+                    { "role": "user", "content": text },
+                ],
+            });
+            let payload = post_json(&client, &url, api_key.as_deref(), body).await?;
+            payload
+                .pointer("/choices/0/message/content")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .ok_or_else(|| anyhow!("Translation response from {url} has no message content"))?
+        }
+    };
+
+    let translated = translated.trim().to_string();
+    if translated.is_empty() {
+        return Err(anyhow!("Translation provider returned empty text"));
+    }
+    Ok(Some(translated))
+}
+
+async fn post_json(client: &Client, url: &str, bearer: Option<&str>, body: Value) -> Result<Value> {
+    let mut request = client.post(url).json(&body);
+    if let Some(key) = bearer.filter(|key| !key.is_empty()) {
+        request = request.header("Authorization", format!("Bearer {key}"));
+    }
+    let response = request
+        .send()
+        .await
+        .with_context(|| format!("Translation request failed for {url}"))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body_text = response.text().await.unwrap_or_default();
+        return Err(anyhow!("Translation request to {url} failed with status {status}: {body_text}"));
+    }
+    response
+        .json::<Value>()
+        .await
+        .with_context(|| format!("Failed to decode translation response for {url}"))
+}
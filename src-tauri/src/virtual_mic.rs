@@ -0,0 +1,291 @@
+use std::io::{Read, Write};
+use std::path::Path;
+use std::process::{Child, ChildStdin};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+
+use crate::audio_sinks::AudioSink;
+use crate::sox::{resolve_sox_path_cached, spawn_pcm_player};
+
+const MAX_DEVICE_NAME_CHARS: usize = 128;
+/// Audio is written to SoX in slices this long, so a pause or cancel takes
+/// effect between them.
+const WRITE_SLICE_MS: usize = 100;
+const CONTROL_POLL_MS: u64 = 20;
+
+/// Shown before the virtual microphone is first turned on in a session.
+pub const CONSENT_NOTICE: &str = "Reads will also play into a virtual microphone, so other apps \
+    such as calls hear them as if you were speaking. Only use voices you have the right to use, \
+    cloned ones included, and make sure the people listening know the speech is synthesized. \
+    Your consent lasts until the app quits.";
+
+#[derive(Serialize)]
+pub struct VirtualMicStatus {
+    pub enabled: bool,
+    pub device: String,
+    /// Turning the mode on needs `consent: true`, after showing `consent_notice`.
+    pub consent_needed: bool,
+    pub consent_notice: &'static str,
+    /// SoX plays into the device; without it the mode cannot be turned on.
+    pub sox_found: bool,
+}
+
+/// Playback side of the usual virtual cable on each platform; calls pick
+/// its recording side as their microphone. SoX matches the start of the name.
+pub fn default_device() -> &'static str {
+    if cfg!(target_os = "windows") {
+        // VB-Audio Virtual Cable.
+        "CABLE Input"
+    } else if cfg!(target_os = "macos") {
+        // BlackHole; Loopback devices are named by the user.
+        "BlackHole 2ch"
+    } else {
+        // A PulseAudio null sink, whose monitor is the microphone.
+        "virtual_mic"
+    }
+}
+
+pub fn validate_device(device: &str) -> Result<()> {
+    let device = device.trim();
+    if device.is_empty() {
+        return Err(anyhow!("Virtual microphone device name must not be empty"));
+    }
+    if device.chars().count() > MAX_DEVICE_NAME_CHARS {
+        return Err(anyhow!(
+            "Virtual microphone device name must be at most {MAX_DEVICE_NAME_CHARS} characters"
+        ));
+    }
+    if device.chars().any(char::is_control) {
+        return Err(anyhow!("Virtual microphone device name must not contain control characters"));
+    }
+    Ok(())
+}
+
+/// SoX output type that can address a playback device by name.
+fn device_driver() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "waveaudio"
+    } else if cfg!(target_os = "macos") {
+        "coreaudio"
+    } else {
+        "pulseaudio"
+    }
+}
+
+/// Plays a job's audio into a virtual audio device through SoX. Playback runs
+/// in real time, so it happens on a thread of its own; writes never wait.
+pub struct VirtualMicSink {
+    device: String,
+    audio: Option<Sender<(Vec<i16>, u32)>>,
+    /// Why the player thread stopped, once it has.
+    failure: Arc<Mutex<Option<String>>>,
+    control: Arc<PlayerControl>,
+}
+
+/// Shared between the sink and its player thread.
+#[derive(Default)]
+struct PlayerControl {
+    paused: AtomicBool,
+    aborted: AtomicBool,
+    /// Set once the player thread has played everything and exited.
+    done: AtomicBool,
+    /// The running SoX process, kept here so a cancel can kill it while the
+    /// thread is blocked writing to it.
+    child: Mutex<Option<Child>>,
+}
+
+impl PlayerControl {
+    fn is_aborted(&self) -> bool {
+        self.aborted.load(Ordering::SeqCst)
+    }
+
+    /// Waits out a pause; false once the sink is aborted.
+    fn wait_while_paused(&self) -> bool {
+        while self.paused.load(Ordering::SeqCst) && !self.is_aborted() {
+            std::thread::sleep(Duration::from_millis(CONTROL_POLL_MS));
+        }
+        !self.is_aborted()
+    }
+
+    fn kill_player(&self) {
+        let child = self.child.lock().ok().and_then(|mut slot| slot.take());
+        if let Some(mut child) = child {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+impl VirtualMicSink {
+    pub fn open(device: &str) -> Result<Self> {
+        let sox = resolve_sox_path_cached()
+            .ok_or_else(|| anyhow!("SoX was not found; the virtual microphone plays through it"))?;
+        let (audio, queued) = mpsc::channel();
+        let failure = Arc::new(Mutex::new(None));
+        let failure_for_player = failure.clone();
+        let control = Arc::new(PlayerControl::default());
+        let control_for_player = control.clone();
+        let device_for_player = device.to_string();
+        std::thread::Builder::new()
+            .name("virtual-mic".to_string())
+            .spawn(move || {
+                if let Err(err) = play_into_device(&sox, &device_for_player, queued, &control_for_player) {
+                    if let Ok(mut slot) = failure_for_player.lock() {
+                        *slot = Some(format!("{err:#}"));
+                    }
+                }
+                control_for_player.done.store(true, Ordering::SeqCst);
+            })
+            .context("Failed to start virtual microphone thread")?;
+        Ok(Self {
+            device: device.to_string(),
+            audio: Some(audio),
+            failure,
+            control,
+        })
+    }
+}
+
+impl AudioSink for VirtualMicSink {
+    fn label(&self) -> String {
+        format!("virtual microphone {}", self.device)
+    }
+
+    fn write(&mut self, pcm: &[i16], sample_rate: u32) -> Result<()> {
+        if let Some(reason) = self.failure.lock().ok().and_then(|slot| slot.clone()) {
+            return Err(anyhow!(reason));
+        }
+        self.audio
+            .as_ref()
+            .ok_or_else(|| anyhow!("Virtual microphone player stopped"))?
+            .send((pcm.to_vec(), sample_rate))
+            .map_err(|_| anyhow!("Virtual microphone player stopped"))
+    }
+
+    /// Audio already queued keeps playing after the job ends.
+    fn finish(&mut self) -> Result<()> {
+        self.audio = None;
+        Ok(())
+    }
+
+    /// Drops what is queued and kills SoX, so the call stops hearing it now.
+    fn abort(&mut self) -> Result<()> {
+        self.control.aborted.store(true, Ordering::SeqCst);
+        self.audio = None;
+        self.control.kill_player();
+        Ok(())
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        self.control.paused.store(paused, Ordering::SeqCst);
+    }
+
+    fn is_playing(&self) -> bool {
+        !self.control.done.load(Ordering::SeqCst)
+    }
+}
+
+/// One SoX process per sample rate; a change of rate mid-job restarts it.
+/// Writes go in short slices and are held while paused.
+fn play_into_device(
+    sox: &Path,
+    device: &str,
+    queued: Receiver<(Vec<i16>, u32)>,
+    control: &PlayerControl,
+) -> Result<()> {
+    let mut player: Option<(ChildStdin, u32)> = None;
+    for (pcm, sample_rate) in queued {
+        if control.is_aborted() {
+            break;
+        }
+        if sample_rate == 0 || pcm.is_empty() {
+            continue;
+        }
+        if player.as_ref().is_some_and(|(_, rate)| *rate != sample_rate) {
+            if let Some((stdin, _)) = player.take() {
+                close_player(stdin, control, device)?;
+            }
+        }
+        if player.is_none() {
+            let mut child = spawn_pcm_player(sox, sample_rate, &["-t", device_driver(), device])
+                .with_context(|| format!("Failed to start SoX for {device}"))?;
+            let stdin = child
+                .stdin
+                .take()
+                .ok_or_else(|| anyhow!("SoX for {device} has no input"))?;
+            if let Ok(mut slot) = control.child.lock() {
+                *slot = Some(child);
+            }
+            // A cancel that came while SoX was starting did not see it.
+            if control.is_aborted() {
+                control.kill_player();
+                return Ok(());
+            }
+            player = Some((stdin, sample_rate));
+        }
+        let Some((stdin, _)) = player.as_mut() else {
+            continue;
+        };
+        let slice_len = (sample_rate as usize * WRITE_SLICE_MS / 1000).max(1);
+        for slice in pcm.chunks(slice_len) {
+            if !control.wait_while_paused() {
+                break;
+            }
+            let bytes: Vec<u8> = slice.iter().flat_map(|sample| sample.to_le_bytes()).collect();
+            if let Err(err) = stdin.write_all(&bytes) {
+                if control.is_aborted() {
+                    return Ok(());
+                }
+                // SoX exits when it cannot open the device; its own message says why.
+                if let Some((stdin, _)) = player.take() {
+                    close_player(stdin, control, device)?;
+                }
+                return Err(err).with_context(|| format!("SoX stopped taking audio for {device}"));
+            }
+        }
+    }
+    match player {
+        Some((stdin, _)) if !control.is_aborted() => close_player(stdin, control, device),
+        _ => Ok(()),
+    }
+}
+
+/// Ends input and waits for SoX to play what it has buffered. The process
+/// stays reachable meanwhile, so a cancel can still kill it.
+fn close_player(stdin: ChildStdin, control: &PlayerControl, device: &str) -> Result<()> {
+    drop(stdin);
+    let (status, mut child) = loop {
+        {
+            let mut slot = control
+                .child
+                .lock()
+                .map_err(|_| anyhow!("Virtual microphone state lock poisoned"))?;
+            // Killed by a cancel.
+            let Some(mut child) = slot.take() else {
+                return Ok(());
+            };
+            match child.try_wait() {
+                Ok(Some(status)) => break (status, child),
+                Ok(None) => *slot = Some(child),
+                Err(err) => return Err(err).context("Failed to wait for SoX"),
+            }
+        }
+        std::thread::sleep(Duration::from_millis(CONTROL_POLL_MS));
+    };
+    if status.success() {
+        return Ok(());
+    }
+    let mut stderr = String::new();
+    if let Some(mut pipe) = child.stderr.take() {
+        let _ = pipe.read_to_string(&mut stderr);
+    }
+    match stderr.trim() {
+        "" => Err(anyhow!("SoX could not play into {device} ({status})")),
+        detail => Err(anyhow!("SoX could not play into {device}: {detail}")),
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use serde_json::{Map, Value};
+
+const METADATA_KEY: &str = "__metadata__";
+/// Serialized headers are padded with spaces to this alignment, as the
+/// safetensors writers do.
+const HEADER_ALIGNMENT: usize = 8;
+
+struct TensorEntry {
+    dtype: String,
+    shape: Vec<u64>,
+    data: Vec<u8>,
+}
+
+/// A safetensors file held in memory, tensors keyed by name.
+struct PromptTensors {
+    metadata: Option<Value>,
+    tensors: BTreeMap<String, TensorEntry>,
+}
+
+/// Writes the element-wise interpolation `a + (b - a) * ratio` of two voice
+/// prompt files to `output`. Both prompts must hold the same tensor names,
+/// dtypes and shapes; F32 and BF16 tensors are supported.
+pub fn blend_prompt_files(a: &Path, b: &Path, ratio: f32, output: &Path) -> Result<()> {
+    let prompt_a = read_prompt(a)?;
+    let prompt_b = read_prompt(b)?;
+    if !prompt_a.tensors.keys().eq(prompt_b.tensors.keys()) {
+        return Err(anyhow!(
+            "Voice prompts {} and {} hold different tensors and cannot be blended",
+            a.display(),
+            b.display()
+        ));
+    }
+
+    let mut blended = BTreeMap::new();
+    for (name, tensor_a) in &prompt_a.tensors {
+        let tensor_b = &prompt_b.tensors[name];
+        if tensor_a.dtype != tensor_b.dtype || tensor_a.shape != tensor_b.shape {
+            return Err(anyhow!(
+                "Voice prompt tensor {name} differs in dtype or shape ({} {:?} vs {} {:?})",
+                tensor_a.dtype,
+                tensor_a.shape,
+                tensor_b.dtype,
+                tensor_b.shape
+            ));
+        }
+        let data = lerp_bytes(&tensor_a.dtype, &tensor_a.data, &tensor_b.data, ratio)
+            .with_context(|| format!("Failed to blend voice prompt tensor {name}"))?;
+        blended.insert(
+            name.clone(),
+            TensorEntry {
+                dtype: tensor_a.dtype.clone(),
+                shape: tensor_a.shape.clone(),
+                data,
+            },
+        );
+    }
+
+    write_prompt(
+        output,
+        &PromptTensors {
+            metadata: prompt_a.metadata,
+            tensors: blended,
+        },
+    )
+}
+
+fn lerp_bytes(dtype: &str, a: &[u8], b: &[u8], ratio: f32) -> Result<Vec<u8>> {
+    let lerp = |x: f32, y: f32| x + (y - x) * ratio;
+    match dtype {
+        "F32" => Ok(a
+            .chunks_exact(4)
+            .zip(b.chunks_exact(4))
+            .flat_map(|(x, y)| {
+                let x = f32::from_le_bytes([x[0], x[1], x[2], x[3]]);
+                let y = f32::from_le_bytes([y[0], y[1], y[2], y[3]]);
+                lerp(x, y).to_le_bytes()
+            })
+            .collect()),
+        // BF16 is the upper half of an f32; rounding to nearest even on the way back.
+        "BF16" => Ok(a
+            .chunks_exact(2)
+            .zip(b.chunks_exact(2))
+            .flat_map(|(x, y)| {
+                let x = f32::from_bits((u16::from_le_bytes([x[0], x[1]]) as u32) << 16);
+                let y = f32::from_bits((u16::from_le_bytes([y[0], y[1]]) as u32) << 16);
+                let bits = lerp(x, y).to_bits();
+                let rounded = bits.wrapping_add(0x7FFF + ((bits >> 16) & 1));
+                ((rounded >> 16) as u16).to_le_bytes()
+            })
+            .collect()),
+        other => Err(anyhow!("Unsupported voice prompt dtype {other}; expected F32 or BF16")),
+    }
+}
+
+fn read_prompt(path: &Path) -> Result<PromptTensors> {
+    let bytes = std::fs::read(path).with_context(|| format!("Failed to read voice prompt {}", path.display()))?;
+    let invalid = || anyhow!("Voice prompt {} is not a valid safetensors file", path.display());
+    let header_len = bytes
+        .get(..8)
+        .map(|len| u64::from_le_bytes(len.try_into().unwrap_or_default()) as usize)
+        .ok_or_else(invalid)?;
+    let data_start = 8usize.checked_add(header_len).ok_or_else(invalid)?;
+    let header_bytes = bytes.get(8..data_start).ok_or_else(invalid)?;
+    let header: Map<String, Value> = serde_json::from_slice(header_bytes)
+        .with_context(|| format!("Failed to parse voice prompt header in {}", path.display()))?;
+    let data = &bytes[data_start..];
+
+    let mut metadata = None;
+    let mut tensors = BTreeMap::new();
+    for (name, info) in header {
+        if name == METADATA_KEY {
+            metadata = Some(info);
+            continue;
+        }
+        let dtype = info.get("dtype").and_then(Value::as_str).ok_or_else(invalid)?;
+        let shape = info
+            .get("shape")
+            .and_then(Value::as_array)
+            .ok_or_else(invalid)?
+            .iter()
+            .map(|dim| dim.as_u64().ok_or_else(invalid))
+            .collect::<Result<Vec<u64>>>()?;
+        let offsets = info
+            .get("data_offsets")
+            .and_then(Value::as_array)
+            .filter(|offsets| offsets.len() == 2)
+            .ok_or_else(invalid)?;
+        let begin = offsets[0].as_u64().ok_or_else(invalid)? as usize;
+        let end = offsets[1].as_u64().ok_or_else(invalid)? as usize;
+        let tensor_data = data.get(begin..end).ok_or_else(invalid)?;
+        tensors.insert(
+            name,
+            TensorEntry {
+                dtype: dtype.to_string(),
+                shape,
+                data: tensor_data.to_vec(),
+            },
+        );
+    }
+    Ok(PromptTensors { metadata, tensors })
+}
+
+fn write_prompt(path: &Path, prompt: &PromptTensors) -> Result<()> {
+    let mut header = Map::new();
+    if let Some(metadata) = prompt.metadata.as_ref() {
+        header.insert(METADATA_KEY.to_string(), metadata.clone());
+    }
+    let mut offset = 0usize;
+    for (name, tensor) in &prompt.tensors {
+        let end = offset + tensor.data.len();
+        header.insert(
+            name.clone(),
+            serde_json::json!({
+                "dtype": tensor.dtype,
+                "shape": tensor.shape,
+                "data_offsets": [offset, end],
+            }),
+        );
+        offset = end;
+    }
+
+    let mut header_bytes = serde_json::to_vec(&header)?;
+    let padded_len = header_bytes.len().div_ceil(HEADER_ALIGNMENT) * HEADER_ALIGNMENT;
+    header_bytes.resize(padded_len, b' ');
+
+    let mut output = Vec::with_capacity(8 + header_bytes.len() + offset);
+    output.extend_from_slice(&(header_bytes.len() as u64).to_le_bytes());
+    output.extend_from_slice(&header_bytes);
+    for tensor in prompt.tensors.values() {
+        output.extend_from_slice(&tensor.data);
+    }
+    std::fs::write(path, output).with_context(|| format!("Failed to write voice prompt {}", path.display()))
+}
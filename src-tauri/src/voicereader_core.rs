@@ -1,27 +1,26 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::{Path, PathBuf};
 use std::process::Child;
-use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, Context, Result};
 #[cfg(feature = "build-full")]
 use std::process::Command;
 #[cfg(feature = "build-full")]
 use std::process::Stdio;
-#[cfg(feature = "build-base")]
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
-#[cfg(feature = "build-base")]
 use base64::Engine as _;
+use futures_util::future::BoxFuture;
 #[cfg(feature = "build-full")]
 use futures_util::StreamExt;
 use rand::{distributions::Alphanumeric, Rng};
-use reqwest::{Client, Method};
+use reqwest::{Client, Method, StatusCode};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use tauri::{
-    AppHandle, ClipboardManager, GlobalShortcutManager, Manager, RunEvent, State, WindowBuilder,
-    WindowUrl,
+    AppHandle, ClipboardManager, GlobalShortcutManager, Manager, PhysicalPosition, PhysicalSize, RunEvent, State,
+    Window, WindowBuilder, WindowUrl,
 };
 use tokio::time::{sleep, Duration};
 #[cfg(feature = "build-full")]
@@ -35,11 +34,74 @@ use tokio_tungstenite::tungstenite::Message;
 #[cfg(feature = "build-base")]
 use uuid::Uuid;
 
+use std::sync::atomic::{AtomicBool, Ordering};
 #[cfg(feature = "build-base")]
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::atomic::{AtomicU32, AtomicU64};
 
+use crate::accessibility::{focused_is_secure, focused_selection, query_focus_context};
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+use crate::primary_selection::{read_primary_selection, send_copy_shortcut};
+use crate::acronyms::{spell_acronyms, AcronymSettings};
+use crate::audio_export::{write_wav_file, StreamingWavWriter, SyntheticProvenance};
+use crate::audio_format::{self, StreamAudioFormat};
+use crate::audio_playback::{NativePlayer, PlaybackEvent, PlaybackMode, PlaybackStatus};
+use crate::audio_sinks::{AudioSink, AudioSinkSettings};
+use crate::audiobook::{section_title, validate_sections, AudiobookLayout, AudiobookSection, AudiobookWriter, ChapterManifest, MANIFEST_FILE_NAME};
+use crate::capture_overrides::{default_capture_order, CaptureOverrides};
+use crate::capture_stats::{CaptureStats, CaptureStatsReport};
+use crate::command_error::{CodedError, CommandError, ErrorCode};
+use crate::cast::{describe_renderer, play_url, search_renderers, CastSink, CastTarget};
+use crate::engine_http::{is_retryable, retry_delay, EngineHttpSettings};
+use crate::engine_limits::EngineResourceLimits;
+use crate::engine_profiles::{EngineProfile, EngineProfiles};
+use crate::event_routing::{EmitRouted, EventRoutes};
+use crate::feeds::{parse_feed, FeedItem, FeedStore, FeedSubscription, ParsedFeed};
+use crate::hotkey_map::{BindableAction, HotkeyMap};
+use crate::schedules::{ScheduleStore, ScheduleTarget, ScheduleTiming, ScheduledRead};
+use crate::job_history::{CaptureMethod, CaptureMetadata, JobHistory, JobHistoryEntry, JobOrigin};
+use crate::job_registry::JobRegistry;
+use crate::job_suppression::SuppressedJobs;
+use crate::language_hint::validate_language_hint;
+use crate::lexicon::{apply_lexicon, LexiconEntry, PronunciationLexicon, RespellingBackend};
+use crate::number_format::{normalize_numbers, NumberSettings};
+use crate::peak_limiter::PeakLimiter;
+use crate::selection_toggle::{SelectionToggle, TogglePress};
+use crate::silence_trim::SilenceTrim;
+use crate::summarization::{summarize_text, SummarySettings};
+use crate::teleprompter::{PaceSegment, PacingPlan};
+use crate::transcript::{render_transcript, select_entries, write_transcript, TranscriptFormat, TranscriptRange};
+use crate::text_sources::{
+    AcquiredText, TextSource, TextSourceRegistry, CLIPBOARD_SOURCE, FILE_SOURCE, SELECTION_SOURCE, URL_SOURCE,
+    WINDOW_OCR_SOURCE,
+};
+use crate::webhooks::{deliver, webhook_body, JobEventKind, WebhookSettings};
+use crate::window_ocr::recognize_focused_window;
+#[cfg(feature = "build-full")]
+use crate::engine_limits::{apply_after_spawn, apply_before_spawn, assign_engine_job, EngineJob};
+use crate::audio_credits::{AudioCredits, ChunkPacing};
+use crate::chunk_journal::ChunkJournal;
+use crate::benchmark::{peak_memory_mb, BenchmarkHistory, BenchmarkProfile, BenchmarkResult};
+use crate::dialogue::{split_dialogue, DialogueSegment, DialogueSettings, SpeakerRole};
+use crate::maintenance::{run_maintenance as run_storage_maintenance, MaintenanceReport};
+use crate::storage_usage::{storage_usage, StorageUsage};
+use crate::phrases::{expand_placeholders, PhraseStore, QuickPhrase, CLIPBOARD_PLACEHOLDER};
+use crate::pipe_endpoint::{
+    endpoint_path, forward_text, is_listening, send_notification, PipeEndpoint, PipeEndpointSettings, PipeMessage,
+};
+use crate::practice::{score_attempt, PracticeScore};
+use crate::quiet_hours::{HeldForQuietHours, QuietHours, QuietHoursMode, QuietHoursSettings};
+use crate::read_estimate::{estimate_read as estimate_read_text, ReadEstimate, SynthesisMetrics};
+use crate::read_queue::{
+    Caption, QueuePolicy, QueuedJob, QueuedRead, ReadPriority, ReadProgress, ReadQueue, SpeedTraining,
+    SpeedTrainingSettings,
+};
+use crate::readback::{transcribe_recording, verify_export, ReadbackReport, ReadbackSettings};
+use crate::reading_order::{apply_reading_order, skim_text, split_paragraphs, split_sentences, ReadingOrder, SkimSettings};
+use crate::sox::sox_found;
+use crate::translation::{translate_text, TranslationSettings};
+use crate::virtual_mic::{self, VirtualMicSink, VirtualMicStatus, CONSENT_NOTICE};
 #[cfg(feature = "build-base")]
-use crate::kyutai_local::{LocalJobEndState, LocalKyutaiRuntime};
+use crate::kyutai_local::{LocalJobEndState, LocalKyutaiRuntime, StreamSettings, MAX_PREFETCH_DEPTH};
 
 #[cfg(all(feature = "build-full", feature = "build-base"))]
 compile_error!("features `build-full` and `build-base` are mutually exclusive");
@@ -53,12 +115,40 @@ const MODEL_KYUTAI: &str = "kyutai_pocket_tts";
 const QWEN_CUSTOM_REPO: &str = "Qwen/Qwen3-TTS-12Hz-0.6B-CustomVoice";
 const QWEN_BASE_REPO: &str = "Qwen/Qwen3-TTS-12Hz-0.6B-Base";
 const KYUTAI_REPO: &str = "Verylicious/pocket-tts-ungated";
+const MODEL_REPO_REVISION: &str = "main";
+const PREFETCH_PROGRESS_POLL_SECS: u64 = 3;
+/// Spoken prefetch milestones are this many percent apart.
+const PREFETCH_ANNOUNCE_STEP_PERCENT: u64 = 25;
+#[cfg(feature = "build-full")]
+const ENGINE_STARTUP_PORT_ATTEMPTS: u32 = 3;
+/// How long an exited engine's stderr reader gets to reach EOF before its
+/// tail is read. Bounded, since a grandchild can hold the pipe open.
 #[cfg(feature = "build-full")]
-const TERMINAL_EVENTS: [&str; 3] = ["JOB_DONE", "JOB_CANCELED", "JOB_ERROR"];
+const ENGINE_STDERR_SETTLE_MS: u64 = 500;
+#[cfg(feature = "build-full")]
+const TERMINAL_EVENTS: [&str; 4] = ["JOB_DONE", "JOB_PARTIAL_DONE", "JOB_CANCELED", "JOB_ERROR"];
+/// How long a canceled job's stream is drained for the sidecar's terminal event.
+#[cfg(feature = "build-full")]
+const CANCEL_CONFIRM_TIMEOUT_MS: u64 = 5000;
+#[cfg(feature = "build-full")]
+const RELAY_RECONNECT_ATTEMPTS: u32 = 4;
+/// Doubled on each attempt: 250ms, 500ms, 1s, 2s.
+#[cfg(feature = "build-full")]
+const RELAY_RECONNECT_BASE_DELAY_MS: u64 = 250;
+#[cfg(feature = "build-base")]
+const PREEMPT_TEARDOWN_TIMEOUT_MS: u64 = 3000;
+const MAX_SKIM_WORDS_PER_SENTENCE: u32 = 50;
+const MAX_SKIM_SENTENCE_STRIDE: u32 = 10;
+const MAX_ECHO_PAUSE_MS: u32 = 5000;
 const SELECTION_COPY_TIMEOUT_MS: u64 = 500;
 const SELECTION_COPY_POLL_MS: u64 = 25;
+/// Accessibility calls wait on the target app, which may be hung.
+const SELECTION_ACCESSIBILITY_TIMEOUT_MS: u64 = 500;
 const HOTKEY_MODIFIER_RELEASE_TIMEOUT_MS: u64 = 350;
 const HOTKEY_MODIFIER_RELEASE_POLL_MS: u64 = 10;
+/// Modifiers tried, in order, to derive the queue-selection variant of the
+/// read hotkey; the first one the read hotkey does not already use is added.
+const QUEUE_HOTKEY_MODIFIERS: [&str; 2] = ["Shift", "Alt"];
 #[cfg(target_os = "windows")]
 const DEFAULT_FALLBACK_HOTKEY: &str = "Alt+S";
 
@@ -68,8 +158,82 @@ const DEFAULT_FALLBACK_HOTKEY: &str = "Option+S";
 #[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
 const DEFAULT_FALLBACK_HOTKEY: &str = "Ctrl+Shift+S";
 const SETTINGS_FILE_NAME: &str = "settings.json";
+/// Written to `settings.json` as `schema_version`. Files without one predate
+/// versioning and count as 0; a newer file is left untouched so fields this
+/// build does not know are not lost.
+const SETTINGS_SCHEMA_VERSION: u32 = 1;
+const PHRASES_FILE_NAME: &str = "phrases.json";
+const BENCHMARKS_FILE_NAME: &str = "benchmarks.json";
+const JOB_HISTORY_FILE_NAME: &str = "history.json";
+const FEEDS_FILE_NAME: &str = "feeds.json";
+const FEED_FETCH_TIMEOUT_SECS: u64 = 20;
+const SCHEDULES_FILE_NAME: &str = "schedules.json";
+const SCHEDULE_POLL_INTERVAL_SECS: u64 = 15;
+/// Rate change per press of the speed-up and speed-down hotkeys.
+const HOTKEY_RATE_STEP: f32 = 0.25;
+/// Reads held for quiet hours start at most this long after they end.
+const QUIET_HOURS_POLL_INTERVAL_SECS: u64 = 30;
+/// Sources of reads started by a global hotkey; only these honor quiet hours.
+const HOTKEY_SOURCE_PREFIX: &str = "hotkey_";
+/// Command-line flag that speaks stdin as it arrives.
+const STDIN_PIPE_FLAG: &str = "--pipe";
+/// Lines queued together from stdin stop growing past this length.
+const STDIN_CHUNK_MAX_BYTES: usize = 2000;
+/// `voicereader notify "Build finished"` speaks through the running app.
+const NOTIFY_COMMAND: &str = "notify";
+const SETTINGS_WATCH_INTERVAL_MS: u64 = 1000;
+const DEFAULT_RTF_KYUTAI: f64 = 0.4;
+const DEFAULT_RTF_QWEN: f64 = 1.2;
+const LOCAL_CHUNK_MAX_CHARS: u32 = 200;
+#[cfg(feature = "build-full")]
+const ENGINE_STDERR_TAIL_LINES: usize = 40;
+#[cfg(feature = "build-full")]
+const ENGINE_EXIT_POLL_MS: u64 = 1000;
+#[cfg(feature = "build-full")]
+const CRASH_REPORT_DIR_NAME: &str = "crash-reports";
+#[cfg(feature = "build-full")]
+const ENGINE_HEARTBEAT_INTERVAL_MS: u64 = 10_000;
+#[cfg(feature = "build-full")]
+const ENGINE_HEARTBEAT_TIMEOUT_MS: u64 = 5_000;
+#[cfg(feature = "build-full")]
+const ENGINE_HEARTBEAT_MAX_MISSES: u32 = 3;
+
+/// Most recent sidecar request, recorded so crash reports can name it.
+static ENGINE_REQUEST_IN_FLIGHT: Mutex<Option<String>> = Mutex::new(None);
+/// Timeouts and retries for sidecar requests; kept here rather than in
+/// `EngineState` because `request_json` has no state handle. `None` means the
+/// defaults.
+static ENGINE_HTTP_SETTINGS: Mutex<Option<EngineHttpSettings>> = Mutex::new(None);
+/// One client for every sidecar request, so connections stay open between
+/// requests instead of each speak paying for a new one. Rebuilt when the
+/// connect timeout changes.
+static ENGINE_HTTP_CLIENT: Mutex<Option<Client>> = Mutex::new(None);
+#[cfg(feature = "build-full")]
+const ENGINE_SYNTH_BACKENDS: [&str; 4] = ["auto", "qwen", "kyutai", "mock"];
+#[cfg(feature = "build-full")]
+const QWEN_ATTN_IMPLEMENTATIONS: [&str; 3] = ["flash_attention_2", "sdpa", "eager"];
+#[cfg(feature = "build-full")]
+const QWEN_DTYPES: [&str; 3] = ["bfloat16", "float16", "float32"];
 const TOOLBAR_WINDOW_LABEL: &str = "toolbar";
 const TOOLBAR_WINDOW_PATH: &str = "toolbar.html";
+const CAPTION_WINDOW_LABEL: &str = "caption";
+const CAPTION_WINDOW_PATH: &str = "caption.html";
+const CAPTION_WINDOW_WIDTH: f64 = 760.0;
+const CAPTION_WINDOW_HEIGHT: f64 = 120.0;
+const CAPTION_BOTTOM_MARGIN: f64 = 48.0;
+const CAPTION_TICK_MS: u64 = 100;
+const AUDIO_CREDIT_POLL_MS: u64 = 20;
+const CAST_DISCOVERY_DEFAULT_MS: u64 = 3_000;
+const CAST_DISCOVERY_MIN_MS: u64 = 1_000;
+const CAST_DISCOVERY_MAX_MS: u64 = 10_000;
+/// Smallest rate change pacing passes on to a running job.
+const TELEPROMPTER_RATE_STEP: f32 = 0.05;
+const FOLLOW_SELECTION_POLL_MS: u64 = 250;
+/// A changed selection must stay put this long before it is read, so
+/// dragging across a paragraph reads it once.
+const FOLLOW_SELECTION_DEBOUNCE_MS: u64 = 700;
+/// Minimum time between two follow-selection reads.
+const FOLLOW_SELECTION_MIN_INTERVAL_MS: u64 = 2_000;
 
 #[cfg(feature = "build-full")]
 const BUILD_VARIANT: &str = "full";
@@ -85,21 +249,115 @@ struct SharedState {
     inner: Arc<Mutex<EngineState>>,
 }
 
+#[derive(Clone)]
+struct EchoSettings {
+    /// Voice for the original-language sentence; `None` uses the selected voice.
+    source_voice_id: Option<String>,
+    pause_ms: u32,
+}
+
+/// A read whose rate follows a words-per-minute schedule.
+struct TeleprompterJob {
+    job_id: String,
+    plan: PacingPlan,
+    /// Rate last applied to the job.
+    rate: f32,
+}
+
 #[derive(Clone)]
 struct SpeakSettingsState {
     rate: f32,
     volume: f32,
     chunk_max_chars: u32,
+    prefetch_depth: u32,
+    /// `None` leaves generated silence untouched.
+    silence_trim: Option<SilenceTrim>,
+    peak_limiter: PeakLimiter,
+}
+
+/// One-off settings for a single speak job. Unset fields fall back to the
+/// current global settings, which are never modified.
+#[derive(Clone, Default, Deserialize)]
+struct SpeakOverrides {
+    voice_id: Option<String>,
+    rate: Option<f32>,
+    volume: Option<f32>,
+    /// Must name the active model: the engine keeps one model loaded, so a
+    /// different one would need a global switch via `select_model`.
+    model: Option<String>,
+    /// Language tag of the text (`de`, `ja-JP`) instead of detection. Sent
+    /// to the sidecar, picks the base runtime's sentence splitting, and
+    /// sets the number locale when it names a supported one.
+    language: Option<String>,
+    /// Text was already translated and reordered (resumed remainders).
+    #[serde(skip)]
+    preprocessed: bool,
+    /// Expand `{time}`, `{date}` and `{clipboard}` first (quick phrases).
+    #[serde(default)]
+    expand_placeholders: bool,
+    /// Apply the reading order settings (paragraph announcements, first
+    /// sentence only). Set for selection reads only.
+    #[serde(skip)]
+    reading_order: bool,
+    /// Also write the job's audio to a WAV file as it plays.
+    #[serde(skip)]
+    export_tee: Option<ExportTee>,
+}
+
+#[derive(Clone)]
+struct ExportTee {
+    path: PathBuf,
+    tag_synthetic: bool,
+}
+
+impl SpeakOverrides {
+    fn validate(&self, selected_model: &str) -> Result<()> {
+        if let Some(rate) = self.rate {
+            if !(0.25..=4.0).contains(&rate) {
+                return Err(anyhow!("rate must be in [0.25, 4.0]"));
+            }
+        }
+        if let Some(volume) = self.volume {
+            if !(0.0..=2.0).contains(&volume) {
+                return Err(anyhow!("volume must be in [0.0, 2.0]"));
+            }
+        }
+        if let Some(voice_id) = self.voice_id.as_deref() {
+            if voice_id.trim().is_empty() {
+                return Err(anyhow!("voice_id cannot be empty"));
+            }
+        }
+        if let Some(language) = self.language.as_deref() {
+            validate_language_hint(language)?;
+        }
+        if let Some(model) = self.model.as_deref().map(str::trim) {
+            if model != selected_model {
+                return Err(anyhow!(
+                    "Model override {model} does not match the active model ({selected_model}). Switch models with select_model first."
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
 struct EngineState {
     child: Option<Child>,
+    /// Job object holding `child` (Windows); replaced or dropped with it.
+    #[cfg(feature = "build-full")]
+    engine_job: Option<EngineJob>,
     #[cfg(feature = "build-base")]
     local_kyutai: Option<Arc<Mutex<LocalKyutaiRuntime>>>,
     #[cfg(feature = "build-base")]
     active_cancel_flag: Option<Arc<AtomicBool>>,
     #[cfg(feature = "build-base")]
     active_rate_steps: Option<Arc<AtomicU32>>,
+    #[cfg(feature = "build-base")]
+    active_volume: Option<Arc<AtomicU32>>,
+    #[cfg(feature = "build-base")]
+    active_job_task: Option<tauri::async_runtime::JoinHandle<()>>,
+    /// Pause control of the running job, set by `pause_active_job`.
+    active_pause_flag: Option<Arc<AtomicBool>>,
     token: String,
     port: u16,
     base_url: String,
@@ -110,23 +368,123 @@ struct EngineState {
     selected_model: String,
     selected_qwen_speaker: String,
     selected_kyutai_voice: String,
-    hotkey: String,
+    /// Global hotkeys by action; read-selection is always bound.
+    hotkeys: HotkeyMap,
+    /// Tried in order when the read hotkey cannot be registered.
+    fallback_hotkeys: Vec<String>,
+    /// Read hotkey actually bound with the OS, if any.
+    registered_hotkey: Option<String>,
+    /// False while global hotkeys are suspended; bindings are kept but not registered.
+    hotkeys_enabled: bool,
+    /// Re-read the selection of the focused app whenever it changes.
+    follow_selection: bool,
+    /// Bumped on every toggle so a superseded watcher stops.
+    follow_selection_generation: u64,
+    orientation_hotkey: Option<String>,
+    /// Reads the focused window through OCR.
+    read_window_hotkey: Option<String>,
+    /// Speaks a summary of the selection instead of the full text.
+    summary_hotkey: Option<String>,
     speak_settings: SpeakSettingsState,
+    reading_order: ReadingOrder,
+    number_format: NumberSettings,
+    acronyms: AcronymSettings,
+    lexicon: PronunciationLexicon,
+    readback: ReadbackSettings,
+    webhooks: WebhookSettings,
+    /// Metadata of started jobs that have not fired their terminal webhook.
+    webhook_jobs: HashMap<String, Value>,
+    pipe_endpoint: PipeEndpointSettings,
+    /// Running while the pipe endpoint is enabled and could be created.
+    pipe_listener: Option<PipeEndpoint>,
+    skim_settings: SkimSettings,
+    translation: TranslationSettings,
+    summary: SummarySettings,
+    echo_settings: EchoSettings,
+    dialogue_settings: DialogueSettings,
+    /// Locally managed segmented job (echo or dialogue reading), if running.
+    active_echo_job: Option<(String, Arc<AtomicBool>)>,
     last_job_id: Option<String>,
-    suppressed_job_ids: HashSet<String>,
+    suppressed_jobs: SuppressedJobs,
     startup_error: Option<String>,
+    init_gate: Arc<tokio::sync::Mutex<()>>,
+    init_generation: u64,
+    last_init_error: Option<String>,
+    engine_stderr_tail: Arc<Mutex<VecDeque<String>>>,
+    engine_exit_expected: bool,
+    pending_crash: Option<EngineCrashReport>,
+    synthesis_metrics: SynthesisMetrics,
+    audio_credits: AudioCredits,
+    chunk_journal: ChunkJournal,
+    read_queue: ReadQueue,
+    /// What the read-selection hotkey does while a read is playing.
+    queue_policy: QueuePolicy,
+    speed_training: SpeedTraining,
+    quiet_hours: QuietHours,
+    /// Playback position of the latest speak job, for interrupt-and-resume.
+    read_progress: Option<ReadProgress>,
+    /// Whether the read-selection hotkey captures or stops.
+    selection_toggle: SelectionToggle,
+    capture_stats: CaptureStats,
+    /// Capture methods for specific apps, in place of the default order.
+    capture_overrides: CaptureOverrides,
+    /// Text and chunks of recent jobs, for `respeak_sentence`.
+    job_registry: JobRegistry,
+    teleprompter: Option<TeleprompterJob>,
+    /// Start of the `session` range for transcript exports.
+    session_started_at: chrono::DateTime<chrono::Local>,
+    /// WAV files written alongside playback, by job id.
+    export_tees: HashMap<String, StreamingWavWriter>,
+    audio_sinks: AudioSinkSettings,
+    /// Sinks opened for each running job from `audio_sinks` and `cast_target`.
+    job_sinks: HashMap<String, Vec<Box<dyn AudioSink>>>,
+    /// Finished sinks still playing queued audio, by job, so a cancel or
+    /// pause after synthesis has ended still stops them.
+    draining_sinks: Vec<(String, Box<dyn AudioSink>)>,
+    /// Network speaker every job is also streamed to.
+    cast_target: Option<CastTarget>,
+    /// Found by the last `list_cast_targets`, for `set_cast_target`.
+    cast_targets_found: Vec<CastTarget>,
+    /// Device every job also plays into while the virtual microphone is on.
+    /// Never persisted: each session turns it on again, with consent.
+    virtual_mic: Option<String>,
+    /// Device chosen for the virtual microphone, kept across sessions.
+    virtual_mic_device: String,
+    /// Set once the consent notice was accepted in this session.
+    virtual_mic_consented: bool,
+    /// Chosen by the frontend with `set_audio_format`.
+    audio_format: StreamAudioFormat,
+    playback_mode: PlaybackMode,
+    /// Running while `playback_mode` is `Native`.
+    native_player: Option<NativePlayer>,
+}
+
+#[derive(Clone, Serialize)]
+struct EngineCrashReport {
+    crash_id: String,
+    occurred_at_unix: u64,
+    exit_code: Option<i32>,
+    in_flight_request: Option<String>,
+    stderr_tail: Vec<String>,
 }
 
 impl Default for EngineState {
     fn default() -> Self {
         Self {
             child: None,
+            #[cfg(feature = "build-full")]
+            engine_job: None,
             #[cfg(feature = "build-base")]
             local_kyutai: None,
             #[cfg(feature = "build-base")]
             active_cancel_flag: None,
             #[cfg(feature = "build-base")]
             active_rate_steps: None,
+            #[cfg(feature = "build-base")]
+            active_volume: None,
+            #[cfg(feature = "build-base")]
+            active_job_task: None,
+            active_pause_flag: None,
             token: String::new(),
             port: 0,
             base_url: String::new(),
@@ -137,22 +495,203 @@ impl Default for EngineState {
             selected_model: MODEL_KYUTAI.to_string(),
             selected_qwen_speaker: "Ryan".to_string(),
             selected_kyutai_voice: "alba".to_string(),
-            hotkey: default_hotkey(),
+            hotkeys: HotkeyMap::new(default_hotkey()),
+            fallback_hotkeys: vec![DEFAULT_FALLBACK_HOTKEY.to_string()],
+            registered_hotkey: None,
+            hotkeys_enabled: true,
+            follow_selection: false,
+            follow_selection_generation: 0,
+            orientation_hotkey: None,
+            read_window_hotkey: None,
+            summary_hotkey: None,
             speak_settings: SpeakSettingsState {
                 rate: 1.5,
                 volume: 1.0,
                 chunk_max_chars: 200,
+                prefetch_depth: 0,
+                silence_trim: None,
+                peak_limiter: PeakLimiter::default(),
             },
+            reading_order: ReadingOrder::default(),
+            number_format: NumberSettings::default(),
+            acronyms: AcronymSettings::default(),
+            lexicon: PronunciationLexicon::default(),
+            readback: ReadbackSettings::default(),
+            webhooks: WebhookSettings::default(),
+            webhook_jobs: HashMap::new(),
+            pipe_endpoint: PipeEndpointSettings::default(),
+            pipe_listener: None,
+            skim_settings: SkimSettings::default(),
+            translation: TranslationSettings::default(),
+            summary: SummarySettings::default(),
+            echo_settings: EchoSettings {
+                source_voice_id: None,
+                pause_ms: 600,
+            },
+            dialogue_settings: DialogueSettings::default(),
+            active_echo_job: None,
             last_job_id: None,
-            suppressed_job_ids: HashSet::new(),
+            suppressed_jobs: SuppressedJobs::default(),
             startup_error: None,
+            init_gate: Arc::new(tokio::sync::Mutex::new(())),
+            init_generation: 0,
+            last_init_error: None,
+            engine_stderr_tail: Arc::new(Mutex::new(VecDeque::new())),
+            engine_exit_expected: false,
+            pending_crash: None,
+            synthesis_metrics: SynthesisMetrics::default(),
+            audio_credits: AudioCredits::default(),
+            chunk_journal: ChunkJournal::default(),
+            read_queue: ReadQueue::default(),
+            queue_policy: QueuePolicy::default(),
+            speed_training: SpeedTraining::default(),
+            quiet_hours: QuietHours::default(),
+            read_progress: None,
+            selection_toggle: SelectionToggle::default(),
+            capture_stats: CaptureStats::default(),
+            capture_overrides: CaptureOverrides::default(),
+            job_registry: JobRegistry::default(),
+            teleprompter: None,
+            session_started_at: chrono::Local::now(),
+            export_tees: HashMap::new(),
+            audio_sinks: AudioSinkSettings::default(),
+            job_sinks: HashMap::new(),
+            draining_sinks: Vec::new(),
+            cast_target: None,
+            cast_targets_found: Vec::new(),
+            virtual_mic: None,
+            virtual_mic_device: virtual_mic::default_device().to_string(),
+            virtual_mic_consented: false,
+            audio_format: StreamAudioFormat::default(),
+            playback_mode: PlaybackMode::default(),
+            native_player: None,
         }
     }
 }
 
 #[derive(Default, Serialize, Deserialize)]
 struct AppSettingsFile {
+    #[serde(default)]
+    schema_version: u32,
+    #[serde(default)]
+    hotkeys: Option<HotkeyMap>,
+    /// Read-selection hotkey from before `hotkeys`; migrated on load.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     hotkey: Option<String>,
+    #[serde(default)]
+    fallback_hotkeys: Option<Vec<String>>,
+    orientation_hotkey: Option<String>,
+    #[serde(default)]
+    read_window_hotkey: Option<String>,
+    #[serde(default)]
+    summary_hotkey: Option<String>,
+    /// Read-clipboard hotkey from before `hotkeys`; migrated on load.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    clipboard_hotkey: Option<String>,
+    #[serde(default)]
+    translation: Option<TranslationSettings>,
+    #[serde(default)]
+    summary: Option<SummarySettings>,
+    #[serde(default)]
+    number_format: Option<NumberSettings>,
+    #[serde(default)]
+    acronyms: Option<AcronymSettings>,
+    #[serde(default)]
+    lexicon: Option<PronunciationLexicon>,
+    #[serde(default)]
+    readback: Option<ReadbackSettings>,
+    #[serde(default)]
+    webhooks: Option<WebhookSettings>,
+    #[serde(default)]
+    audio_sinks: Option<AudioSinkSettings>,
+    #[serde(default)]
+    cast_target: Option<CastTarget>,
+    #[serde(default)]
+    virtual_mic_device: Option<String>,
+    #[serde(default)]
+    playback_mode: Option<PlaybackMode>,
+    #[serde(default)]
+    chunk_pacing: Option<ChunkPacing>,
+    #[serde(default)]
+    speed_training: Option<SpeedTrainingSettings>,
+    #[serde(default)]
+    queue_policy: Option<QueuePolicy>,
+    #[serde(default)]
+    quiet_hours: Option<QuietHoursSettings>,
+    #[serde(default)]
+    peak_limiter: Option<PeakLimiter>,
+    /// Absent or `null` leaves silence untouched.
+    #[serde(default)]
+    silence_trim: Option<SilenceTrim>,
+    #[serde(default)]
+    capture_overrides: Option<CaptureOverrides>,
+    #[serde(default)]
+    pipe_endpoint: Option<PipeEndpointSettings>,
+    #[serde(default)]
+    engine_http: Option<EngineHttpSettings>,
+    #[serde(default)]
+    rate: Option<f32>,
+    #[serde(default)]
+    volume: Option<f32>,
+    #[serde(default)]
+    chunk_max_chars: Option<u32>,
+    /// Only written and read by Base builds; Full builds always prefetch one chunk.
+    #[serde(default)]
+    prefetch_depth: Option<u32>,
+    #[serde(default)]
+    selected_model: Option<String>,
+    #[serde(default)]
+    selected_voice_id: Option<String>,
+    #[serde(default)]
+    qwen_speaker: Option<String>,
+    #[serde(default)]
+    kyutai_preset: Option<String>,
+    #[serde(default)]
+    engine_env_overrides: Option<EngineEnvOverrides>,
+    #[serde(default)]
+    engine_resource_limits: Option<EngineResourceLimits>,
+    #[serde(default)]
+    engine_profiles: Option<EngineProfiles>,
+}
+
+/// Sidecar tuning knobs passed as environment variables at engine launch.
+/// `None` keeps the built-in default.
+#[derive(Clone, Default, Serialize, Deserialize)]
+struct EngineEnvOverrides {
+    synth_backend: Option<String>,
+    qwen_attn_implementation: Option<String>,
+    qwen_speaker: Option<String>,
+    qwen_device_map: Option<String>,
+    qwen_dtype: Option<String>,
+}
+
+#[derive(Clone)]
+enum HotkeyAction {
+    ReadSelection,
+    QueueSelection,
+    AnnounceFocus,
+    ReadWindow,
+    SummarizeSelection,
+    ReadClipboard,
+    Stop,
+    PauseResume,
+    SpeedUp,
+    SpeedDown,
+    /// Speaks the saved phrase with this id.
+    SpeakPhrase(String),
+}
+
+impl From<BindableAction> for HotkeyAction {
+    fn from(action: BindableAction) -> Self {
+        match action {
+            BindableAction::ReadSelection => Self::ReadSelection,
+            BindableAction::Stop => Self::Stop,
+            BindableAction::PauseResume => Self::PauseResume,
+            BindableAction::SpeedUp => Self::SpeedUp,
+            BindableAction::SpeedDown => Self::SpeedDown,
+            BindableAction::ReadClipboard => Self::ReadClipboard,
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -173,9 +712,23 @@ struct SpeakerPreset {
 #[derive(Serialize)]
 struct BootstrapPayload {
     hotkey: String,
+    /// Read hotkey plus one more modifier; appends the selection to the read queue.
+    queue_hotkey: Option<String>,
+    /// Read hotkey bound with the OS; `None` when every candidate failed.
+    registered_hotkey: Option<String>,
+    fallback_hotkeys: Vec<String>,
+    hotkeys_enabled: bool,
+    orientation_hotkey: Option<String>,
+    read_window_hotkey: Option<String>,
+    summary_hotkey: Option<String>,
+    /// Every action hotkey, read-selection included.
+    hotkeys: HotkeyMap,
     selected_voice_id: String,
     selected_model: String,
     selected_speaker: String,
+    /// As restored from the settings file, so the UI does not overwrite
+    /// them with its defaults.
+    speak_settings: SpeakSettingsPayload,
     startup_error: Option<String>,
     build_variant: String,
     qwen_enabled: bool,
@@ -185,6 +738,25 @@ struct BootstrapPayload {
     voices: Value,
 }
 
+#[derive(Serialize)]
+struct AboutModel {
+    id: String,
+    repo: String,
+    /// Downloads are not pinned to a commit, so this is the branch fetched.
+    revision: String,
+}
+
+#[derive(Serialize)]
+struct AboutPayload {
+    app_version: String,
+    build_variant: String,
+    features: Vec<String>,
+    models: Vec<AboutModel>,
+    /// Only linked into Base builds; `None` otherwise.
+    pocket_tts_version: Option<String>,
+    target_triple: String,
+}
+
 #[derive(Serialize)]
 struct EngineRuntimePayload {
     running: bool,
@@ -201,6 +773,16 @@ struct GenericResult {
     message: String,
 }
 
+#[derive(Serialize)]
+struct PipeEndpointStatus {
+    enabled: bool,
+    /// FIFO path or Windows pipe name, while listening.
+    path: Option<String>,
+    /// Unix socket the engine API is served on as well as TCP (Full build on
+    /// Linux/macOS), whether or not the text endpoint is enabled.
+    api_socket: Option<String>,
+}
+
 #[derive(Serialize)]
 struct SpeakRateResult {
     ok: bool,
@@ -222,6 +804,20 @@ struct HotkeyResult {
     hotkey: String,
 }
 
+#[derive(Serialize)]
+struct FallbackHotkeysResult {
+    ok: bool,
+    message: String,
+    fallback_hotkeys: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct OptionalHotkeyResult {
+    ok: bool,
+    message: String,
+    hotkey: Option<String>,
+}
+
 #[derive(Serialize)]
 struct SelectModelResult {
     selected_model: String,
@@ -232,6 +828,18 @@ struct SelectModelResult {
     health: Value,
 }
 
+#[derive(Serialize)]
+struct ExportSpeechResult {
+    ok: bool,
+    message: String,
+    path: String,
+    duration_ms: u64,
+    tagged_synthetic: bool,
+    /// Sentences the exported audio is missing, when read-back verification
+    /// is configured.
+    readback: Option<ReadbackReport>,
+}
+
 #[derive(Clone, Serialize)]
 struct JobStartedPayload {
     job_id: String,
@@ -239,6 +847,28 @@ struct JobStartedPayload {
     source: String,
     source_window: String,
     rate: f32,
+    capture: CaptureMetadata,
+}
+
+#[cfg(feature = "build-full")]
+#[derive(Clone, Serialize)]
+struct EngineCrashedPayload {
+    crash_id: String,
+    exit_code: Option<i32>,
+    report_path: Option<String>,
+    message: String,
+}
+
+#[cfg(feature = "build-full")]
+#[derive(Clone, Serialize)]
+struct EngineStatusPayload {
+    status: &'static str,
+    message: String,
+}
+
+#[derive(Clone, Serialize)]
+struct SettingsChangedPayload {
+    changed: Vec<String>,
 }
 
 #[derive(Clone, Serialize)]
@@ -246,21 +876,143 @@ struct JobCancelRequestedPayload {
     job_id: String,
 }
 
+#[derive(Clone, Serialize)]
+struct JobCancelConfirmedPayload {
+    job_id: String,
+    /// True when the sidecar did not report the job ended in time; generation
+    /// may still be winding down, but nothing more is forwarded.
+    timed_out: bool,
+}
+
+#[derive(Clone, Serialize)]
+struct ReadQueueChangedPayload {
+    pending: usize,
+}
+
+#[derive(Serialize)]
+struct ActiveJobPayload {
+    job_id: String,
+    /// Set for reads; `None` for other jobs such as echo playback.
+    source: Option<String>,
+    /// Still synthesizing; false once only queued audio is left to play.
+    generating: bool,
+}
+
+#[derive(Serialize)]
+struct JobListPayload {
+    active: Option<ActiveJobPayload>,
+    /// Next first.
+    queued: Vec<QueuedJob>,
+    policy: QueuePolicy,
+}
+
 #[derive(Clone, Serialize)]
 struct HotkeyUpdatedPayload {
     hotkey: String,
 }
 
+#[derive(Clone, Serialize)]
+struct HotkeysEnabledPayload {
+    enabled: bool,
+}
+
+#[derive(Clone, Serialize)]
+struct QuietHoursHeldPayload {
+    held: usize,
+    /// RFC 3339 end of the current quiet window.
+    until: String,
+}
+
+#[derive(Clone, Serialize)]
+struct QuietHoursReleasedPayload {
+    released: usize,
+}
+
+#[derive(Clone, Serialize)]
+struct QuietHoursStatus {
+    settings: QuietHoursSettings,
+    /// RFC 3339 end of the current quiet window; `None` outside one.
+    active_until: Option<String>,
+    /// Captures waiting for quiet hours to end.
+    held: usize,
+}
+
+/// The speak settings the UI edits directly.
+#[derive(Serialize)]
+struct SpeakSettingsPayload {
+    rate: f32,
+    volume: f32,
+    chunk_max_chars: u32,
+}
+
+impl From<&SpeakSettingsState> for SpeakSettingsPayload {
+    fn from(settings: &SpeakSettingsState) -> Self {
+        Self {
+            rate: settings.rate,
+            volume: settings.volume,
+            chunk_max_chars: settings.chunk_max_chars,
+        }
+    }
+}
+
 #[derive(Clone, Serialize)]
 struct RateUpdatedPayload {
     rate: f32,
 }
 
+#[derive(Clone, Serialize)]
+struct SpeedTrainingStatus {
+    settings: SpeedTrainingSettings,
+    /// Listening until the next rate step; `None` when training is off or
+    /// the rate is already at the cap.
+    next_step_in_secs: Option<u64>,
+}
+
+#[derive(Clone, Serialize)]
+struct VolumeUpdatedPayload {
+    volume: f32,
+}
+
 #[derive(Clone, Serialize)]
 struct ErrorPayload {
     message: String,
 }
 
+#[derive(Serialize)]
+struct AudiobookExportResult {
+    ok: bool,
+    message: String,
+    dir: String,
+    manifest_path: String,
+    manifest: ChapterManifest,
+}
+
+#[derive(Clone, Serialize)]
+struct ExportProgressPayload {
+    export_id: String,
+    /// Sections written so far.
+    completed: usize,
+    total: usize,
+    /// Title of the section being synthesized; absent once all are written.
+    current: Option<String>,
+}
+
+#[derive(Clone, Serialize)]
+struct ExportFinishedPayload {
+    job_id: String,
+    path: String,
+    duration_ms: u64,
+    tagged_synthetic: bool,
+}
+
+#[derive(Clone, Serialize)]
+struct ScheduleFiredPayload {
+    id: String,
+    label: String,
+    /// `None` when the read was queued behind another job.
+    job_id: Option<String>,
+}
+
 #[derive(Deserialize)]
 #[cfg(feature = "build-full")]
 struct SpeakHttpResponse {
@@ -281,6 +1033,31 @@ struct VoiceSummaryHttpResponse {
     display_name: String,
 }
 
+/// A token issued to another local app for the engine API. The token itself
+/// is only returned by `create_api_client`.
+#[derive(Clone, Serialize, Deserialize)]
+struct ApiClient {
+    client_id: String,
+    name: String,
+    scopes: Vec<String>,
+    created_at: String,
+}
+
+#[derive(Deserialize)]
+struct ListApiClientsHttpResponse {
+    clients: Vec<ApiClient>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CreatedApiClient {
+    #[serde(flatten)]
+    client: ApiClient,
+    token: String,
+    /// Where the client reaches the engine; the port changes between launches.
+    #[serde(default)]
+    base_url: String,
+}
+
 #[derive(Deserialize)]
 struct PrefetchModelsHttpResponse {
     mode: String,
@@ -290,11 +1067,57 @@ struct PrefetchModelsHttpResponse {
     hf_cache_dir: String,
 }
 
+/// Download status of one model repo, as tracked by the sidecar.
+#[derive(Serialize, Deserialize)]
+struct ModelDownloadState {
+    repo_id: String,
+    /// `complete`, `partial` (resumes on the next prefetch), `present`
+    /// (downloaded before tracking) or `missing`.
+    status: String,
+    downloaded_bytes: u64,
+    total_bytes: Option<u64>,
+    updated_at: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ModelDownloadsHttpResponse {
+    repos: Vec<ModelDownloadState>,
+}
+
+#[derive(Deserialize)]
+struct PrefetchProgressHttpResponse {
+    active: bool,
+    repo_id: Option<String>,
+    downloaded_bytes: u64,
+    /// Unknown when the Hub could not be asked for the repo size.
+    total_bytes: Option<u64>,
+}
+
 #[derive(Serialize)]
 struct EngineStoragePathsPayload {
     data_dir: String,
     models_dir: String,
     hf_cache_dir: String,
+    voices_dir: String,
+    logs_dir: String,
+}
+
+/// Folders `open_in_file_manager` can reveal.
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum StorageTarget {
+    DataDir,
+    ModelsDir,
+    HfCacheDir,
+    VoicesDir,
+    Logs,
+}
+
+#[derive(Serialize)]
+struct CastTargetsResult {
+    targets: Vec<CastTarget>,
+    /// Id of the current cast target, found or not.
+    selected: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -405,76 +1228,476 @@ const KYUTAI_VOICE_PRESETS: [SpeakerPresetRow; 8] = [
     },
 ];
 
+/// Runs a command-line subcommand that talks to the already running app
+/// instead of starting another one. Returns the exit code if there was one.
+fn run_cli_command() -> Option<i32> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == STDIN_PIPE_FLAG) {
+        return forward_stdin(&endpoint_path());
+    }
+    if args.first().map(String::as_str) != Some(NOTIFY_COMMAND) {
+        return None;
+    }
+    let text = args[1..].join(" ");
+    if text.trim().is_empty() {
+        eprintln!("Usage: voicereader {NOTIFY_COMMAND} \"Build finished\"");
+        return Some(2);
+    }
+    match send_notification(&endpoint_path(), text.trim()) {
+        Ok(()) => Some(0),
+        Err(err) => {
+            eprintln!("{err:#}");
+            Some(1)
+        }
+    }
+}
+
+/// `voicereader --pipe` while the app runs with its pipe endpoint on: hands
+/// stdin to it chunk by chunk and exits when stdin ends. `None` when nothing
+/// listens, so this process starts the app and reads stdin itself.
+fn forward_stdin(path: &Path) -> Option<i32> {
+    if !is_listening(path) {
+        return None;
+    }
+    let (sender, receiver) = std::sync::mpsc::channel::<String>();
+    spawn_stdin_lines(move |line| sender.send(line).is_ok());
+    while let Ok(first) = receiver.recv() {
+        let chunk = join_stdin_lines(first, || receiver.try_recv().ok());
+        if chunk.trim().is_empty() {
+            continue;
+        }
+        if let Err(err) = forward_text(path, &chunk) {
+            eprintln!("{err:#}");
+            return Some(1);
+        }
+    }
+    Some(0)
+}
+
 pub fn run_app() {
+    if let Some(exit_code) = run_cli_command() {
+        std::process::exit(exit_code);
+    }
+
     let state = SharedState {
         inner: Arc::new(Mutex::new(EngineState::default())),
     };
 
     let app = tauri::Builder::default()
         .manage(state)
+        .manage(EventRoutes::default())
+        .manage(text_source_registry())
         .setup(|app| {
             let handle = app.handle();
             let state = app.state::<SharedState>();
-            if let Some(saved_hotkey) = load_saved_hotkey(&handle) {
+            if let Some(saved_hotkeys) = load_saved_hotkeys(&handle) {
                 if let Ok(mut guard) = state.inner.lock() {
-                    guard.hotkey = saved_hotkey;
+                    guard.hotkeys = saved_hotkeys;
                 }
             }
-            let init_result = tauri::async_runtime::block_on(async {
-                initialize_engine_if_needed(&handle, &state.inner).await
-            });
-            if let Err(err) = init_result {
-                let msg = format!("Engine startup failed during setup: {err:#}");
-                eprintln!("{msg}");
+            if let Some(saved_fallback_hotkeys) = load_saved_fallback_hotkeys(&handle) {
                 if let Ok(mut guard) = state.inner.lock() {
-                    guard.startup_error = Some(msg);
+                    guard.fallback_hotkeys = saved_fallback_hotkeys;
                 }
             }
-
-            if let Err(err) = register_hotkey(&handle, state.inner.clone()) {
-                let msg = format!("Global hotkey registration failed: {err:#}");
-                eprintln!("{msg}");
+            if let Some(saved_orientation_hotkey) = load_saved_orientation_hotkey(&handle) {
                 if let Ok(mut guard) = state.inner.lock() {
-                    guard.startup_error = match guard.startup_error.take() {
-                        Some(existing) => Some(format!("{existing}\n{msg}")),
-                        None => Some(msg),
-                    };
+                    guard.orientation_hotkey = Some(saved_orientation_hotkey);
                 }
             }
-
-            if let Err(err) = create_toolbar_window(&handle) {
-                let msg = format!("Toolbar window startup failed: {err:#}");
-                eprintln!("{msg}");
+            if let Some(saved_read_window_hotkey) = load_saved_read_window_hotkey(&handle) {
                 if let Ok(mut guard) = state.inner.lock() {
-                    guard.startup_error = match guard.startup_error.take() {
-                        Some(existing) => Some(format!("{existing}\n{msg}")),
-                        None => Some(msg),
-                    };
+                    guard.read_window_hotkey = Some(saved_read_window_hotkey);
                 }
             }
-            Ok(())
-        })
-        .invoke_handler(tauri::generate_handler![
-            app_bootstrap,
-            engine_health,
-            engine_list_voices,
-            engine_runtime_status,
-            engine_storage_paths,
-            prefetch_models,
-            restart_engine,
-            select_model,
-            set_selected_voice,
-            clone_voice_from_audio,
-            update_saved_voice,
-            delete_saved_voice,
-            set_preset_speaker,
-            set_speak_settings,
-            cycle_speak_rate,
-            set_hotkey,
-            speak_text,
-            trigger_read_selection,
-            cancel_active_job,
-        ])
+            if let Some(saved_summary_hotkey) = load_saved_summary_hotkey(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.summary_hotkey = Some(saved_summary_hotkey);
+                }
+            }
+            if let Some(saved_translation) = load_saved_translation_settings(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.translation = saved_translation;
+                }
+            }
+            if let Some(saved_summary) = load_saved_summary_settings(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.summary = saved_summary;
+                }
+            }
+            if let Some(saved_number_format) = load_saved_number_format(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.number_format = saved_number_format;
+                }
+            }
+            if let Some(saved_acronyms) = load_saved_acronym_settings(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.acronyms = saved_acronyms;
+                }
+            }
+            if let Some(saved_lexicon) = load_saved_lexicon(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.lexicon = saved_lexicon;
+                }
+            }
+            if let Some(saved_readback) = load_saved_readback_settings(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.readback = saved_readback;
+                }
+            }
+            if let Some(saved_webhooks) = load_saved_webhooks(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.webhooks = saved_webhooks;
+                }
+            }
+            if let Some(saved_audio_sinks) = load_saved_audio_sinks(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.audio_sinks = saved_audio_sinks;
+                }
+            }
+            if let Some(saved_cast_target) = load_saved_cast_target(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.cast_target = Some(saved_cast_target);
+                }
+            }
+            if let Some(saved_virtual_mic_device) = load_saved_virtual_mic_device(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.virtual_mic_device = saved_virtual_mic_device;
+                }
+            }
+            if load_saved_playback_mode(&handle) == Some(PlaybackMode::Native) {
+                match start_native_player(&handle) {
+                    Ok(player) => {
+                        if let Ok(mut guard) = state.inner.lock() {
+                            guard.playback_mode = PlaybackMode::Native;
+                            guard.native_player = Some(player);
+                        }
+                    }
+                    Err(err) => eprintln!("Native playback not started, using the webview: {err:#}"),
+                }
+            }
+            if let Some(saved_chunk_pacing) = load_saved_chunk_pacing(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.audio_credits.set_pacing(saved_chunk_pacing);
+                }
+            }
+            if let Some(saved_speed_training) = load_saved_speed_training(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.speed_training.set_settings(saved_speed_training);
+                }
+            }
+            if let Some(saved_queue_policy) = load_saved_queue_policy(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.queue_policy = saved_queue_policy;
+                }
+            }
+            if let Some(saved_quiet_hours) = load_saved_quiet_hours(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.quiet_hours.set_settings(saved_quiet_hours);
+                }
+            }
+            if let Some(saved_peak_limiter) = load_saved_peak_limiter(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.speak_settings.peak_limiter = saved_peak_limiter;
+                }
+            }
+            if let Some(saved_capture_overrides) = load_saved_capture_overrides(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.capture_overrides = saved_capture_overrides;
+                }
+            }
+            if let Ok(mut guard) = state.inner.lock() {
+                load_saved_speak_state(&handle, &mut guard);
+            }
+            if let Some(saved_pipe_endpoint) = load_saved_pipe_endpoint(&handle) {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.pipe_endpoint = saved_pipe_endpoint;
+                }
+            }
+            if let Some(saved_engine_http) = load_saved_engine_http(&handle) {
+                apply_engine_http_settings(saved_engine_http);
+            }
+            let init_result = tauri::async_runtime::block_on(async {
+                initialize_engine_if_needed(&handle, &state.inner).await
+            });
+            if let Err(err) = init_result {
+                let msg = format!("Engine startup failed during setup: {err:#}");
+                eprintln!("{msg}");
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.startup_error = Some(msg);
+                }
+            } else {
+                spawn_startup_maintenance(state.inner.clone());
+            }
+
+            if let Err(err) = register_hotkey(&handle, state.inner.clone()) {
+                let msg = format!("Global hotkey registration failed: {err:#}");
+                eprintln!("{msg}");
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.startup_error = match guard.startup_error.take() {
+                        Some(existing) => Some(format!("{existing}\n{msg}")),
+                        None => Some(msg),
+                    };
+                }
+            }
+
+            if let Err(err) = register_queue_hotkey(&handle, state.inner.clone()) {
+                let msg = format!("Queue-selection hotkey registration failed: {err:#}");
+                eprintln!("{msg}");
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.startup_error = match guard.startup_error.take() {
+                        Some(existing) => Some(format!("{existing}\n{msg}")),
+                        None => Some(msg),
+                    };
+                }
+            }
+
+            if let Err(err) = register_orientation_hotkey(&handle, state.inner.clone()) {
+                let msg = format!("Orientation hotkey registration failed: {err:#}");
+                eprintln!("{msg}");
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.startup_error = match guard.startup_error.take() {
+                        Some(existing) => Some(format!("{existing}\n{msg}")),
+                        None => Some(msg),
+                    };
+                }
+            }
+
+            if let Err(err) = register_read_window_hotkey(&handle, state.inner.clone()) {
+                let msg = format!("Read-window hotkey registration failed: {err:#}");
+                eprintln!("{msg}");
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.startup_error = match guard.startup_error.take() {
+                        Some(existing) => Some(format!("{existing}\n{msg}")),
+                        None => Some(msg),
+                    };
+                }
+            }
+
+            if let Err(err) = register_summary_hotkey(&handle, state.inner.clone()) {
+                let msg = format!("Summary hotkey registration failed: {err:#}");
+                eprintln!("{msg}");
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.startup_error = match guard.startup_error.take() {
+                        Some(existing) => Some(format!("{existing}\n{msg}")),
+                        None => Some(msg),
+                    };
+                }
+            }
+
+            if let Err(err) = register_action_hotkeys(&handle, state.inner.clone()) {
+                let msg = format!("Action hotkey registration failed: {err:#}");
+                eprintln!("{msg}");
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.startup_error = match guard.startup_error.take() {
+                        Some(existing) => Some(format!("{existing}\n{msg}")),
+                        None => Some(msg),
+                    };
+                }
+            }
+
+            if let Err(err) = register_phrase_hotkeys(&handle, state.inner.clone()) {
+                let msg = format!("Phrase hotkey registration failed: {err:#}");
+                eprintln!("{msg}");
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.startup_error = match guard.startup_error.take() {
+                        Some(existing) => Some(format!("{existing}\n{msg}")),
+                        None => Some(msg),
+                    };
+                }
+            }
+
+            spawn_settings_watcher(handle.clone());
+            spawn_schedule_runner(handle.clone(), state.inner.clone());
+            spawn_quiet_hours_release(handle.clone(), state.inner.clone());
+
+            if let Err(err) = apply_pipe_endpoint(&handle, &state.inner) {
+                let msg = format!("Pipe endpoint startup failed: {err:#}");
+                eprintln!("{msg}");
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.startup_error = match guard.startup_error.take() {
+                        Some(existing) => Some(format!("{existing}\n{msg}")),
+                        None => Some(msg),
+                    };
+                }
+            }
+            if std::env::args().skip(1).any(|arg| arg == STDIN_PIPE_FLAG) {
+                spawn_stdin_reader(handle.clone(), state.inner.clone());
+            }
+
+            // Paused time does not count as played when resuming an interrupted read.
+            let pause_state = state.inner.clone();
+            handle.listen_global("voicereader:toolbar-paused", move |event| {
+                let paused = event
+                    .payload()
+                    .and_then(|payload| serde_json::from_str::<Value>(payload).ok())
+                    .and_then(|payload| payload.get("paused").and_then(Value::as_bool));
+                if let (Some(paused), Ok(mut guard)) = (paused, pause_state.lock()) {
+                    if let Some(progress) = guard.read_progress.as_mut() {
+                        progress.set_paused(paused);
+                    }
+                    if let Some(player) = guard.native_player.as_ref() {
+                        player.set_paused(paused);
+                    }
+                    guard.audio_credits.set_paused(paused);
+                }
+            });
+
+            if let Err(err) = create_toolbar_window(&handle) {
+                let msg = format!("Toolbar window startup failed: {err:#}");
+                eprintln!("{msg}");
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.startup_error = match guard.startup_error.take() {
+                        Some(existing) => Some(format!("{existing}\n{msg}")),
+                        None => Some(msg),
+                    };
+                }
+            }
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            app_bootstrap,
+            engine_health,
+            get_capabilities,
+            set_audio_format,
+            get_playback_mode,
+            set_playback_mode,
+            get_chunk_pacing,
+            set_chunk_pacing,
+            get_speed_training,
+            set_speed_training,
+            get_quiet_hours,
+            set_quiet_hours,
+            seek_playback,
+            get_about,
+            engine_list_voices,
+            engine_runtime_status,
+            engine_storage_paths,
+            open_in_file_manager,
+            run_maintenance,
+            get_storage_usage,
+            prefetch_models,
+            cancel_prefetch,
+            get_model_downloads,
+            list_api_clients,
+            create_api_client,
+            revoke_api_client,
+            restart_engine,
+            select_model,
+            set_selected_voice,
+            clone_voice_from_audio,
+            create_blended_voice,
+            update_saved_voice,
+            delete_saved_voice,
+            set_preset_speaker,
+            get_speak_settings,
+            set_speak_settings,
+            get_silence_trim,
+            set_silence_trim,
+            get_peak_limiter,
+            set_peak_limiter,
+            estimate_read,
+            run_benchmark,
+            list_benchmarks,
+            list_job_history,
+            get_capture_stats,
+            get_capture_overrides,
+            set_capture_overrides,
+            export_session_transcript,
+            set_live_rate,
+            set_live_volume,
+            set_reading_order,
+            set_skim_settings,
+            get_translation_settings,
+            set_translation_settings,
+            get_summary_settings,
+            set_summary_settings,
+            get_number_format,
+            set_number_format,
+            get_acronym_settings,
+            set_acronym_settings,
+            get_lexicon,
+            set_lexicon,
+            get_readback_settings,
+            set_readback_settings,
+            get_webhooks,
+            set_webhooks,
+            get_audio_sinks,
+            set_audio_sinks,
+            list_cast_targets,
+            set_cast_target,
+            get_virtual_mic,
+            set_virtual_mic,
+            get_pipe_endpoint,
+            set_pipe_endpoint,
+            get_engine_http_settings,
+            set_engine_http_settings,
+            set_echo_settings,
+            set_dialogue_settings,
+            set_follow_selection,
+            get_engine_env_overrides,
+            set_engine_env_overrides,
+            get_engine_profiles,
+            set_engine_profiles,
+            set_active_engine_profile,
+            get_engine_resource_limits,
+            set_engine_resource_limits,
+            cycle_speak_rate,
+            set_hotkey,
+            set_hotkey_binding,
+            set_fallback_hotkeys,
+            set_hotkey_enabled,
+            set_orientation_hotkey,
+            set_read_window_hotkey,
+            set_summary_hotkey,
+            read_clipboard,
+            speak_text,
+            speak_interactive,
+            speak_teleprompter,
+            trigger_read_selection,
+            trigger_queue_selection,
+            trigger_read_window,
+            trigger_summarize_selection,
+            speak_from_source,
+            speak_url,
+            list_feeds,
+            subscribe_feed,
+            unsubscribe_feed,
+            refresh_feeds,
+            list_feed_items,
+            mark_feed_item_read,
+            speak_feed_item,
+            schedule_read,
+            list_schedules,
+            cancel_schedule,
+            list_text_sources,
+            trigger_skim_selection,
+            speak_echo_text,
+            practice_sentence,
+            score_practice_attempt,
+            trigger_echo_selection,
+            trigger_announce_focus,
+            list_phrases,
+            save_phrase,
+            delete_phrase,
+            speak_phrase,
+            respeak_sentence,
+            cancel_active_job,
+            list_jobs,
+            cancel_job,
+            get_queue_policy,
+            set_queue_policy,
+            pause_active_job,
+            resume_active_job,
+            ack_audio,
+            resync_audio,
+            export_speech_to_file,
+            synthesize_to_file,
+            export_audiobook,
+            open_caption_overlay,
+            close_caption_overlay,
+            subscribe_events,
+        ])
         .build(tauri::generate_context!())
         .unwrap_or_else(|err| panic!("Failed to build VoiceReader app: {err}"));
 
@@ -507,11 +1730,146 @@ fn create_toolbar_window(app: &AppHandle) -> Result<()> {
     Ok(())
 }
 
+/// Opens the always-on-top caption overlay, or moves it if it is already
+/// open. `monitor` indexes the available monitors; `None` uses the monitor
+/// the main window is on.
+#[tauri::command]
+fn open_caption_overlay(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    monitor: Option<usize>,
+) -> Result<GenericResult, CommandError> {
+    let existing = app.get_window(CAPTION_WINDOW_LABEL);
+    let created = existing.is_none();
+    let window = match existing {
+        Some(window) => window,
+        None => WindowBuilder::new(
+            &app,
+            CAPTION_WINDOW_LABEL,
+            WindowUrl::App(CAPTION_WINDOW_PATH.into()),
+        )
+        .title("VoiceReader Captions")
+        .inner_size(CAPTION_WINDOW_WIDTH, CAPTION_WINDOW_HEIGHT)
+        .resizable(false)
+        .decorations(false)
+        .transparent(true)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .focused(false)
+        .visible(false)
+        .build()
+        .map_err(|err| CommandError::internal(format!("Failed to build caption window: {err}")))?,
+    };
+
+    if let Err(err) = place_caption_window(&app, &window, monitor) {
+        if created {
+            let _ = window.close();
+        }
+        return Err(to_cmd_error(err));
+    }
+    // Click-through, so the overlay never steals input from what is being read.
+    let _ = window.set_ignore_cursor_events(true);
+    let _ = window.show();
+    if created {
+        spawn_caption_ticker(app.clone(), state.inner.clone());
+    }
+    Ok(GenericResult {
+        ok: true,
+        message: "Caption overlay opened".to_string(),
+    })
+}
+
+#[tauri::command]
+fn close_caption_overlay(app: AppHandle) -> Result<GenericResult, CommandError> {
+    if let Some(window) = app.get_window(CAPTION_WINDOW_LABEL) {
+        window
+            .close()
+            .map_err(|err| CommandError::internal(format!("Failed to close caption window: {err}")))?;
+    }
+    Ok(GenericResult {
+        ok: true,
+        message: "Caption overlay closed".to_string(),
+    })
+}
+
+/// Limits which windows receive the given events (names with or without the
+/// `voicereader:` prefix); an empty list drops all of the window's
+/// subscriptions. Audio stream events go to the main window by default.
+#[tauri::command]
+fn subscribe_events(
+    routes: State<'_, EventRoutes>,
+    window_label: String,
+    kinds: Vec<String>,
+) -> Result<GenericResult, CommandError> {
+    let window_label = window_label.trim();
+    if window_label.is_empty() {
+        return Err(CommandError::invalid("window_label cannot be empty"));
+    }
+    let events = routes.subscribe(window_label, &kinds).map_err(to_cmd_error)?;
+    let message = if events.is_empty() {
+        format!("Cleared event subscriptions for {window_label}")
+    } else {
+        format!("{window_label} subscribed to {}", events.join(", "))
+    };
+    Ok(GenericResult { ok: true, message })
+}
+
+/// Centers the caption window near the bottom of the chosen monitor.
+fn place_caption_window(app: &AppHandle, window: &Window, monitor: Option<usize>) -> Result<()> {
+    let target = match monitor {
+        Some(index) => window
+            .available_monitors()?
+            .into_iter()
+            .nth(index)
+            .ok_or_else(|| anyhow!("Monitor {index} does not exist"))?,
+        None => match app.get_window("main").and_then(|main| main.current_monitor().ok().flatten()) {
+            Some(current) => current,
+            None => window
+                .primary_monitor()?
+                .ok_or_else(|| anyhow!("No monitor is available for the caption overlay"))?,
+        },
+    };
+    let scale = target.scale_factor();
+    let width = (CAPTION_WINDOW_WIDTH * scale) as i32;
+    let height = (CAPTION_WINDOW_HEIGHT * scale) as i32;
+    let origin = target.position();
+    let size = target.size();
+    let x = origin.x + (size.width as i32 - width) / 2;
+    let y = origin.y + size.height as i32 - height - (CAPTION_BOTTOM_MARGIN * scale) as i32;
+    window.set_size(PhysicalSize::new(width as u32, height as u32))?;
+    window.set_position(PhysicalPosition::new(x, y))?;
+    Ok(())
+}
+
+/// Feeds the caption overlay until it closes. Captions follow the estimated
+/// playback position of the current read and are only sent when they change.
+fn spawn_caption_ticker(app: AppHandle, state: Arc<Mutex<EngineState>>) {
+    tauri::async_runtime::spawn(async move {
+        let mut last: Option<Caption> = None;
+        while let Some(window) = app.get_window(CAPTION_WINDOW_LABEL) {
+            let caption = current_caption(&state);
+            if caption != last {
+                let _ = match caption.as_ref() {
+                    Some(caption) => window.emit("voicereader:caption", caption),
+                    None => window.emit("voicereader:caption-cleared", json!({})),
+                };
+                last = caption;
+            }
+            sleep(Duration::from_millis(CAPTION_TICK_MS)).await;
+        }
+    });
+}
+
+fn current_caption(state: &Arc<Mutex<EngineState>>) -> Option<Caption> {
+    let guard = state.lock().ok()?;
+    guard.read_progress.as_ref().and_then(ReadProgress::caption)
+}
+
 #[tauri::command]
-async fn app_bootstrap(app: AppHandle, state: State<'_, SharedState>) -> Result<BootstrapPayload, String> {
+async fn app_bootstrap(app: AppHandle, state: State<'_, SharedState>) -> Result<BootstrapPayload, CommandError> {
     let mut startup_error: Option<String> = None;
     if let Err(err) = ensure_engine_ready(&app, &state.inner).await {
-        let msg = to_cmd_error(err);
+        let msg = format!("{err:#}");
         startup_error = Some(msg.clone());
         if let Ok(mut guard) = state.inner.lock() {
             guard.startup_error = Some(msg);
@@ -521,7 +1879,7 @@ async fn app_bootstrap(app: AppHandle, state: State<'_, SharedState>) -> Result<
     let health = match engine_health_inner(&state.inner).await {
         Ok(payload) => payload,
         Err(err) => {
-            let msg = to_cmd_error(err);
+            let msg = format!("{err:#}");
             if startup_error.is_none() {
                 startup_error = Some(msg.clone());
             }
@@ -535,7 +1893,7 @@ async fn app_bootstrap(app: AppHandle, state: State<'_, SharedState>) -> Result<
     let voices = match engine_list_voices_inner(&state.inner).await {
         Ok(payload) => payload,
         Err(err) => {
-            let msg = to_cmd_error(err);
+            let msg = format!("{err:#}");
             if startup_error.is_none() {
                 startup_error = Some(msg.clone());
             }
@@ -547,22 +1905,39 @@ async fn app_bootstrap(app: AppHandle, state: State<'_, SharedState>) -> Result<
     };
 
     let snapshot = {
-        let guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
         (
-            guard.hotkey.clone(),
+            guard.hotkeys.read_selection().to_string(),
             guard.selected_voice_id.clone(),
             guard.selected_model.clone(),
             active_speaker_for_model(&guard),
             guard.startup_error.clone(),
+            guard.orientation_hotkey.clone(),
+            guard.registered_hotkey.clone(),
+            guard.fallback_hotkeys.clone(),
+            guard.hotkeys_enabled,
+            guard.read_window_hotkey.clone(),
+            guard.summary_hotkey.clone(),
+            guard.hotkeys.clone(),
+            SpeakSettingsPayload::from(&guard.speak_settings),
         )
     };
     let selected_model = snapshot.2.clone();
 
     Ok(BootstrapPayload {
+        queue_hotkey: queue_hotkey_variant(&snapshot.0),
+        registered_hotkey: snapshot.6,
+        fallback_hotkeys: snapshot.7,
+        hotkeys_enabled: snapshot.8,
         hotkey: snapshot.0,
+        orientation_hotkey: snapshot.5,
+        read_window_hotkey: snapshot.9,
+        summary_hotkey: snapshot.10,
+        hotkeys: snapshot.11,
         selected_voice_id: snapshot.1,
         selected_model,
         selected_speaker: snapshot.3,
+        speak_settings: snapshot.12,
         startup_error: snapshot.4.or(startup_error),
         build_variant: build_variant_name().to_string(),
         qwen_enabled: qwen_modes_enabled(),
@@ -574,44 +1949,403 @@ async fn app_bootstrap(app: AppHandle, state: State<'_, SharedState>) -> Result<
 }
 
 #[tauri::command]
-async fn engine_health(app: AppHandle, state: State<'_, SharedState>) -> Result<Value, String> {
+async fn engine_health(app: AppHandle, state: State<'_, SharedState>) -> Result<Value, CommandError> {
     ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
     engine_health_inner(&state.inner).await.map_err(to_cmd_error)
 }
 
+/// Build provenance for bug reports and the about dialog.
+#[tauri::command]
+fn get_about(app: AppHandle) -> AboutPayload {
+    let features = [
+        ("build-full", cfg!(feature = "build-full")),
+        ("build-base", cfg!(feature = "build-base")),
+        ("custom-protocol", cfg!(feature = "custom-protocol")),
+    ];
+    let mut models = vec![AboutModel {
+        id: MODEL_KYUTAI.to_string(),
+        repo: KYUTAI_REPO.to_string(),
+        revision: MODEL_REPO_REVISION.to_string(),
+    }];
+    if qwen_modes_enabled() {
+        for (id, repo) in [(MODEL_CUSTOM, QWEN_CUSTOM_REPO), (MODEL_BASE, QWEN_BASE_REPO)] {
+            models.push(AboutModel {
+                id: id.to_string(),
+                repo: repo.to_string(),
+                revision: MODEL_REPO_REVISION.to_string(),
+            });
+        }
+    }
+
+    AboutPayload {
+        app_version: app.package_info().version.to_string(),
+        build_variant: build_variant_name().to_string(),
+        features: features
+            .into_iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| name.to_string())
+            .collect(),
+        models,
+        pocket_tts_version: cfg!(feature = "build-base").then(|| env!("VOICEREADER_POCKET_TTS_VERSION").to_string()),
+        target_triple: env!("VOICEREADER_TARGET_TRIPLE").to_string(),
+    }
+}
+
 #[tauri::command]
-async fn engine_list_voices(app: AppHandle, state: State<'_, SharedState>) -> Result<Value, String> {
+async fn get_capabilities(app: AppHandle, state: State<'_, SharedState>) -> Result<Value, CommandError> {
     ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
-    engine_list_voices_inner(&state.inner).await.map_err(to_cmd_error)
+    let health = engine_health_inner(&state.inner).await.map_err(to_cmd_error)?;
+    let mut capabilities = health.get("capabilities").cloned().unwrap_or_else(|| json!({}));
+    // Chunks are re-encoded on the way to the frontend, so these do not depend on the engine.
+    if let Some(capabilities) = capabilities.as_object_mut() {
+        let selected = {
+            let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+            guard.audio_format
+        };
+        let mut audio_formats = audio_format::capabilities();
+        audio_formats["selected"] = json!(selected);
+        capabilities.insert("audio_formats".to_string(), audio_formats);
+    }
+    Ok(capabilities)
 }
 
+/// Picks the encoding and sample rate of `AUDIO_CHUNK` audio sent to the
+/// frontend, from the options `get_capabilities` lists under `audio_formats`.
+/// Applies from the next chunk, including for a job already playing.
 #[tauri::command]
-fn engine_runtime_status(state: State<'_, SharedState>) -> Result<EngineRuntimePayload, String> {
-    let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
-    let (running, pid) = runtime_snapshot(&mut guard);
-    let active_speaker = active_speaker_for_model(&guard);
-    Ok(EngineRuntimePayload {
-        running,
-        pid,
-        base_url: guard.base_url.clone(),
-        selected_voice_id: guard.selected_voice_id.clone(),
-        selected_model: guard.selected_model.clone(),
-        selected_speaker: active_speaker,
+fn set_audio_format(state: State<'_, SharedState>, format: StreamAudioFormat) -> Result<GenericResult, CommandError> {
+    format.validate().map_err(to_invalid_error)?;
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.audio_format = format;
+    }
+    let rate = format
+        .sample_rate
+        .map_or_else(|| "the model's sample rate".to_string(), |rate| format!("{rate} Hz"));
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Streaming audio as {} at {rate}", format.encoding.as_str()),
     })
 }
 
 #[tauri::command]
-async fn engine_storage_paths(
+fn get_playback_mode(state: State<'_, SharedState>) -> Result<PlaybackStatus, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(PlaybackStatus {
+        mode: guard.playback_mode,
+        sox_found: sox_found(),
+    })
+}
+
+/// Chooses where job audio plays. `native` plays it from the app process and
+/// stops sending `AUDIO_CHUNK` audio to the webview; leaving it stops any
+/// audio the native player still holds.
+#[tauri::command]
+fn set_playback_mode(app: AppHandle, state: State<'_, SharedState>, mode: PlaybackMode) -> Result<GenericResult, CommandError> {
+    let running = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.native_player.is_some()
+    };
+    let player = match mode {
+        PlaybackMode::Native if !running => Some(start_native_player(&app).map_err(to_cmd_error)?),
+        _ => None,
+    };
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.playback_mode = mode;
+        match mode {
+            PlaybackMode::Native => {
+                if let Some(player) = player {
+                    guard.native_player = Some(player);
+                }
+            }
+            PlaybackMode::Webview => guard.native_player = None,
+        }
+    }
+
+    if let Err(err) = persist_playback_mode(&app, mode) {
+        emit_error(&app, &format!("Playback mode updated but could not persist settings: {err:#}"));
+    }
+
+    Ok(GenericResult {
+        ok: true,
+        message: match mode {
+            PlaybackMode::Native => "Audio plays from the app".to_string(),
+            PlaybackMode::Webview => "Audio plays in the webview".to_string(),
+        },
+    })
+}
+
+/// Skips `offset_ms` forward, or back when negative, within the read that is
+/// playing. Needs native playback, which keeps each read's audio until it ends.
+#[tauri::command]
+fn seek_playback(state: State<'_, SharedState>, offset_ms: i64) -> Result<GenericResult, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    let Some(player) = guard.native_player.as_ref() else {
+        return Err(CommandError::invalid("Seeking needs native playback; see set_playback_mode"));
+    };
+    player.seek(offset_ms);
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Skipped {:+.1}s", offset_ms as f64 / 1000.0),
+    })
+}
+
+#[tauri::command]
+fn get_chunk_pacing(state: State<'_, SharedState>) -> Result<ChunkPacing, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.audio_credits.pacing())
+}
+
+/// Limits how far emitted audio may run ahead of real time, in ms; 0 lets
+/// synthesis emit as fast as it runs.
+#[tauri::command]
+fn set_chunk_pacing(app: AppHandle, state: State<'_, SharedState>, lookahead_ms: u64) -> Result<GenericResult, CommandError> {
+    let pacing = ChunkPacing { lookahead_ms };
+    pacing.validate().map_err(to_invalid_error)?;
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.audio_credits.set_pacing(pacing);
+    }
+    if let Err(err) = persist_chunk_pacing(&app, pacing) {
+        emit_error(&app, &format!("Chunk pacing updated but could not persist settings: {err:#}"));
+    }
+    Ok(GenericResult {
+        ok: true,
+        message: if lookahead_ms == 0 {
+            "Chunk pacing off".to_string()
+        } else {
+            format!("Audio is emitted at most {lookahead_ms} ms ahead of playback")
+        },
+    })
+}
+
+#[tauri::command]
+fn get_speed_training(state: State<'_, SharedState>) -> Result<SpeedTrainingStatus, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(SpeedTrainingStatus {
+        settings: guard.speed_training.settings(),
+        next_step_in_secs: guard
+            .speed_training
+            .next_step_in_secs(guard.speak_settings.rate)
+            .map(|secs| secs.ceil() as u64),
+    })
+}
+
+/// Raises the speak rate by `step` after every `interval_minutes` of reads
+/// played to the end, up to `max_rate`. Saving restarts the count.
+#[tauri::command]
+fn set_speed_training(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    settings: SpeedTrainingSettings,
+) -> Result<GenericResult, CommandError> {
+    settings.validate().map_err(to_invalid_error)?;
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.speed_training.set_settings(settings);
+    }
+    if let Err(err) = persist_speed_training(&app, settings) {
+        emit_error(&app, &format!("Speed training updated but could not persist settings: {err:#}"));
+    }
+    Ok(GenericResult {
+        ok: true,
+        message: if settings.enabled {
+            format!(
+                "Rate goes up by {} every {} minutes of listening, up to {}",
+                settings.step, settings.interval_minutes, settings.max_rate
+            )
+        } else {
+            "Speed training off".to_string()
+        },
+    })
+}
+
+#[tauri::command]
+fn get_quiet_hours(state: State<'_, SharedState>) -> Result<QuietHoursStatus, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(QuietHoursStatus {
+        settings: guard.quiet_hours.settings(),
+        active_until: guard
+            .quiet_hours
+            .active_until(chrono::Local::now())
+            .map(|until| until.to_rfc3339()),
+        held: guard.quiet_hours.held_count(),
+    })
+}
+
+/// Between `start` and `end` on the chosen days, hotkey reads are held until
+/// quiet hours end (`queue`) or refused (`reject`). Reads started from the
+/// window, phrases, schedules and scripts are not affected.
+#[tauri::command]
+fn set_quiet_hours(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    settings: QuietHoursSettings,
+) -> Result<GenericResult, CommandError> {
+    settings.validate().map_err(to_invalid_error)?;
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.quiet_hours.set_settings(settings.clone());
+    }
+    if let Err(err) = persist_quiet_hours(&app, &settings) {
+        emit_error(&app, &format!("Quiet hours updated but could not persist settings: {err:#}"));
+    }
+    Ok(GenericResult {
+        ok: true,
+        message: if settings.enabled {
+            let action = match settings.mode {
+                QuietHoursMode::Queue => "held until they end",
+                QuietHoursMode::Reject => "refused",
+            };
+            format!("Quiet hours {}-{}: hotkey reads are {action}", settings.start.trim(), settings.end.trim())
+        } else {
+            "Quiet hours off".to_string()
+        },
+    })
+}
+
+fn start_native_player(app: &AppHandle) -> Result<NativePlayer> {
+    let app = app.clone();
+    NativePlayer::start(move |event| match event {
+        // Played audio returns synthesis credit, as the webview's ack_audio does.
+        PlaybackEvent::ChunkPlayed { job_id, ordinal } => {
+            if let Some(state) = app.try_state::<SharedState>() {
+                if let Ok(mut guard) = state.inner.lock() {
+                    guard.audio_credits.ack(&job_id, ordinal);
+                }
+            }
+        }
+        PlaybackEvent::JobPlayed { job_id } => {
+            let _ = app.emit_routed("voicereader:ws-event", json!({ "type": "PLAYBACK_DONE", "job_id": job_id }));
+        }
+        PlaybackEvent::Failed(message) => emit_error(&app, &format!("Native playback failed: {message}")),
+    })
+}
+
+/// Runs `action` on the native player when playback runs in the app.
+/// Returns whether it did.
+fn with_native_player(app: &AppHandle, action: impl FnOnce(&NativePlayer)) -> bool {
+    let Some(state) = app.try_state::<SharedState>() else {
+        return false;
+    };
+    let Ok(guard) = state.inner.lock() else {
+        return false;
+    };
+    match guard.native_player.as_ref() {
+        Some(player) => {
+            action(player);
+            true
+        }
+        None => false,
+    }
+}
+
+#[tauri::command]
+async fn engine_list_voices(app: AppHandle, state: State<'_, SharedState>) -> Result<Value, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    engine_list_voices_inner(&state.inner).await.map_err(to_cmd_error)
+}
+
+#[tauri::command]
+fn engine_runtime_status(state: State<'_, SharedState>) -> Result<EngineRuntimePayload, CommandError> {
+    let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    let (running, pid) = runtime_snapshot(&mut guard);
+    let active_speaker = active_speaker_for_model(&guard);
+    Ok(EngineRuntimePayload {
+        running,
+        pid,
+        base_url: guard.base_url.clone(),
+        selected_voice_id: guard.selected_voice_id.clone(),
+        selected_model: guard.selected_model.clone(),
+        selected_speaker: active_speaker,
+    })
+}
+
+#[tauri::command]
+async fn engine_storage_paths(
     app: AppHandle,
     state: State<'_, SharedState>,
-) -> Result<EngineStoragePathsPayload, String> {
+) -> Result<EngineStoragePathsPayload, CommandError> {
     ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
 
-    let guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
     Ok(EngineStoragePathsPayload {
         data_dir: guard.data_dir.clone(),
         models_dir: guard.models_dir.clone(),
         hf_cache_dir: guard.hf_cache_dir.clone(),
+        voices_dir: storage_target_path(&guard, StorageTarget::VoicesDir).display().to_string(),
+        logs_dir: storage_target_path(&guard, StorageTarget::Logs).display().to_string(),
+    })
+}
+
+/// Removes orphaned voices, stale runtime configs and leftover temp files
+/// from the engine data directory and reports what was removed.
+#[tauri::command]
+async fn run_maintenance(app: AppHandle, state: State<'_, SharedState>) -> Result<MaintenanceReport, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let data_dir = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        PathBuf::from(&guard.data_dir)
+    };
+    tauri::async_runtime::spawn_blocking(move || run_storage_maintenance(&data_dir))
+        .await
+        .map_err(|err| CommandError::internal(format!("Maintenance task failed: {err}")))?
+        .map_err(to_cmd_error)
+}
+
+/// Disk usage of the model folders and the files duplicated between them.
+/// With `deduplicate`, duplicates are replaced by hard links first.
+#[tauri::command]
+async fn get_storage_usage(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    deduplicate: Option<bool>,
+) -> Result<StorageUsage, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let (models_dir, hf_cache_dir) = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        (
+            storage_target_path(&guard, StorageTarget::ModelsDir),
+            storage_target_path(&guard, StorageTarget::HfCacheDir),
+        )
+    };
+    if models_dir.as_os_str().is_empty() {
+        return Err(CommandError::new(ErrorCode::EngineNotReady, "Engine storage paths are not known yet"));
+    }
+    let deduplicate = deduplicate.unwrap_or(false);
+    tauri::async_runtime::spawn_blocking(move || {
+        storage_usage(
+            &[("models", models_dir.as_path()), ("hf_cache", hf_cache_dir.as_path())],
+            deduplicate,
+        )
+    })
+    .await
+    .map_err(|err| CommandError::internal(format!("Storage scan failed: {err}")))?
+    .map_err(to_cmd_error)
+}
+
+/// Reveals one of the engine storage folders in the OS file manager,
+/// creating it first if the engine has not written to it yet.
+#[tauri::command]
+async fn open_in_file_manager(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    target: StorageTarget,
+) -> Result<GenericResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let path = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        storage_target_path(&guard, target)
+    };
+    if path.as_os_str().is_empty() {
+        return Err(CommandError::new(ErrorCode::EngineNotReady, "Engine storage paths are not known yet"));
+    }
+    std::fs::create_dir_all(&path).map_err(|err| CommandError::internal(format!("Failed to create {}: {err}", path.display())))?;
+    reveal_in_file_manager(&path).map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Opened {}", path.display()),
     })
 }
 
@@ -620,9 +2354,11 @@ async fn prefetch_models(
     app: AppHandle,
     state: State<'_, SharedState>,
     mode: String,
-) -> Result<PrefetchModelsResult, String> {
+    repos: Option<Vec<String>>,
+    announce: Option<bool>,
+) -> Result<PrefetchModelsResult, CommandError> {
     if !qwen_modes_enabled() {
-        return Err("Qwen model downloads are available in Full build only.".to_string());
+        return Err(CommandError::unsupported("Qwen model downloads are available in Full build only."));
     }
 
     ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
@@ -631,35 +2367,92 @@ async fn prefetch_models(
         normalized_mode.as_str(),
         "qwen_custom" | "qwen_base" | "qwen_all" | "all"
     ) {
-        return Err("mode must be one of: qwen_custom, qwen_base, qwen_all, all".to_string());
+        return Err(CommandError::invalid("mode must be one of: qwen_custom, qwen_base, qwen_all, all"));
+    }
+    // An explicit repo selection overrides `mode`.
+    if let Some(repos) = repos.as_ref() {
+        if repos.is_empty() {
+            return Err(CommandError::invalid("repos must list at least one model repo"));
+        }
+        if let Some(unknown) = repos
+            .iter()
+            .find(|repo| ![QWEN_CUSTOM_REPO, QWEN_BASE_REPO, KYUTAI_REPO].contains(&repo.as_str()))
+        {
+            return Err(CommandError::invalid(format!("Unknown model repo: {unknown}")));
+        }
     }
 
     let (base_url, token) = {
-        let guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
         (guard.base_url.clone(), guard.token.clone())
     };
 
-    let response_payload = request_json(
+    // Milestones are spoken with the model that is already loaded, so users
+    // can walk away during multi-GB downloads.
+    let announce = announce.unwrap_or(false);
+    let announcer = announce.then(|| {
+        tauri::async_runtime::spawn(announce_prefetch_progress(
+            app.clone(),
+            state.inner.clone(),
+            base_url.clone(),
+            token.clone(),
+        ))
+    });
+    let response_payload = request_json_long(
         Method::POST,
         &format!("{base_url}/v1/models/prefetch"),
         &token,
-        Some(json!({ "mode": normalized_mode })),
+        Some(json!({ "mode": normalized_mode, "repos": repos })),
     )
-    .await
-    .map_err(to_cmd_error)?;
+    .await;
+    if let Some(announcer) = announcer {
+        announcer.abort();
+        let outcome = match &response_payload {
+            Ok(_) => "complete",
+            Err(err) if format!("{err:#}").contains("PREFETCH_CANCELED") => "canceled",
+            Err(_) => "failed",
+        };
+        speak_prefetch_announcement(&app, &state.inner, format!("Model download {outcome}")).await;
+    }
+    let response_payload = response_payload.map_err(to_cmd_error)?;
     let response: PrefetchModelsHttpResponse =
         serde_json::from_value(response_payload).map_err(|err| to_cmd_error(err.into()))?;
 
     {
-        let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
         guard.data_dir = response.data_dir.clone();
         guard.models_dir = response.models_dir.clone();
         guard.hf_cache_dir = response.hf_cache_dir.clone();
     }
 
+    // Repos downloaded side by side share tokenizers and configs.
+    let (models_dir, hf_cache_dir) = (PathBuf::from(&response.models_dir), PathBuf::from(&response.hf_cache_dir));
+    let dedup = tauri::async_runtime::spawn_blocking(move || {
+        storage_usage(
+            &[("models", models_dir.as_path()), ("hf_cache", hf_cache_dir.as_path())],
+            true,
+        )
+    })
+    .await;
+    let linked = match dedup {
+        Ok(Ok(usage)) => usage.dedup.map(|report| report.linked_files).unwrap_or(0),
+        Ok(Err(err)) => {
+            eprintln!("Model storage dedup failed: {err:#}");
+            0
+        }
+        Err(err) => {
+            eprintln!("Model storage dedup failed: {err}");
+            0
+        }
+    };
+
     Ok(PrefetchModelsResult {
         ok: true,
-        message: format!("Prefetch complete ({})", response.mode),
+        message: if linked > 0 {
+            format!("Prefetch complete ({}); {linked} duplicate files linked", response.mode)
+        } else {
+            format!("Prefetch complete ({})", response.mode)
+        },
         mode: response.mode,
         downloaded: response.downloaded,
         data_dir: response.data_dir,
@@ -668,23 +2461,202 @@ async fn prefetch_models(
     })
 }
 
+/// Stops a running `prefetch_models`. Files downloaded so far are kept and
+/// the rest resumes on the next prefetch.
+#[tauri::command]
+async fn cancel_prefetch(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, CommandError> {
+    if !qwen_modes_enabled() {
+        return Err(CommandError::unsupported("Qwen model downloads are available in Full build only."));
+    }
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let (base_url, token) = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        (guard.base_url.clone(), guard.token.clone())
+    };
+    let response = request_json(
+        Method::POST,
+        &format!("{base_url}/v1/models/prefetch/cancel"),
+        &token,
+        None,
+    )
+    .await
+    .map_err(to_cmd_error)?;
+    let canceled = response.get("canceled").and_then(Value::as_bool).unwrap_or(false);
+    Ok(GenericResult {
+        ok: canceled,
+        message: if canceled {
+            "Model download canceled; it resumes where it stopped next time".to_string()
+        } else {
+            "No model download is running".to_string()
+        },
+    })
+}
+
+/// Per-repo download status, including partial downloads left by a
+/// canceled or interrupted prefetch.
 #[tauri::command]
-async fn restart_engine(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, String> {
-    shutdown_engine(&state.inner).await;
-    initialize_engine_if_needed(&app, &state.inner)
+async fn get_model_downloads(app: AppHandle, state: State<'_, SharedState>) -> Result<Vec<ModelDownloadState>, CommandError> {
+    if !qwen_modes_enabled() {
+        return Err(CommandError::unsupported("Qwen model downloads are available in Full build only."));
+    }
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let (base_url, token) = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        (guard.base_url.clone(), guard.token.clone())
+    };
+    let payload = request_json(Method::GET, &format!("{base_url}/v1/models/downloads"), &token, None)
         .await
         .map_err(to_cmd_error)?;
+    let response: ModelDownloadsHttpResponse =
+        serde_json::from_value(payload).map_err(|err| to_cmd_error(err.into()))?;
+    Ok(response.repos)
+}
 
-    let selected_model = {
-        let guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
-        guard.selected_model.clone()
+/// Polls the sidecar's prefetch progress and speaks each milestone and each
+/// finished repo. Runs until aborted.
+async fn announce_prefetch_progress(app: AppHandle, state: Arc<Mutex<EngineState>>, base_url: String, token: String) {
+    let url = format!("{base_url}/v1/models/prefetch/progress");
+    // Repo being downloaded and its last announced milestone.
+    let mut current: Option<(String, u64)> = None;
+    loop {
+        sleep(Duration::from_secs(PREFETCH_PROGRESS_POLL_SECS)).await;
+        // Not `request_json`: polling must not replace the prefetch as the
+        // request named in crash reports.
+        let Ok(payload) = send_json_request(Method::GET, &url, &token, None).await else {
+            continue;
+        };
+        let Ok(progress) = serde_json::from_value::<PrefetchProgressHttpResponse>(payload) else {
+            continue;
+        };
+        let Some(repo_id) = progress.repo_id.filter(|_| progress.active) else {
+            continue;
+        };
+
+        let last_milestone = match current.take() {
+            Some((previous, milestone)) if previous == repo_id => milestone,
+            Some((previous, _)) => {
+                let text = format!("{} downloaded", model_repo_label(&previous));
+                speak_prefetch_announcement(&app, &state, text).await;
+                0
+            }
+            None => 0,
+        };
+        // 100 percent is left to the "downloaded" announcement.
+        let percent = progress
+            .total_bytes
+            .filter(|total| *total > 0)
+            .map_or(0, |total| (progress.downloaded_bytes * 100 / total).min(99));
+        let milestone = percent / PREFETCH_ANNOUNCE_STEP_PERCENT * PREFETCH_ANNOUNCE_STEP_PERCENT;
+        if milestone > last_milestone {
+            let text = format!("{} {milestone} percent downloaded", model_repo_label(&repo_id));
+            speak_prefetch_announcement(&app, &state, text).await;
+        }
+        current = Some((repo_id, milestone.max(last_milestone)));
+    }
+}
+
+fn model_repo_label(repo_id: &str) -> &str {
+    match repo_id {
+        QWEN_CUSTOM_REPO => "Qwen custom voice model",
+        QWEN_BASE_REPO => "Qwen base model",
+        KYUTAI_REPO => "Kyutai Pocket model",
+        _ => repo_id,
+    }
+}
+
+/// Speaks a prefetch milestone in the interactive lane. Failures are only
+/// logged, since the download itself goes on.
+async fn speak_prefetch_announcement(app: &AppHandle, state: &Arc<Mutex<EngineState>>, text: String) {
+    let read = QueuedRead {
+        text,
+        priority: ReadPriority::Interactive,
+        source: "prefetch_progress".to_string(),
+        origin: JobOrigin::direct(),
+        voice_id: None,
+        rate: None,
+        preprocessed: false,
+        expand_placeholders: false,
+        reading_order: false,
     };
+    if let Err(err) = enqueue_read(app, state, read).await {
+        eprintln!("Prefetch announcement failed: {err:#}");
+    }
+}
 
-    if selected_model == MODEL_CUSTOM {
-        let _ = apply_custom_model_activation(&state.inner).await;
-    } else if selected_model == MODEL_KYUTAI {
-        let _ = apply_kyutai_model_activation(&state.inner).await;
+/// Base URL and launch token of the sidecar, for the API client commands,
+/// which only exist in the Full build.
+async fn api_client_endpoint(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<(String, String)> {
+    if !cfg!(feature = "build-full") {
+        return Err(anyhow!("The engine API is available in Full build only."));
     }
+    ensure_engine_ready(app, state).await?;
+    let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+    Ok((guard.base_url.clone(), guard.token.clone()))
+}
+
+#[tauri::command]
+async fn list_api_clients(app: AppHandle, state: State<'_, SharedState>) -> Result<Vec<ApiClient>, CommandError> {
+    let (base_url, token) = api_client_endpoint(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let response_payload = request_json(Method::GET, &format!("{base_url}/v1/clients"), &token, None)
+        .await
+        .map_err(to_cmd_error)?;
+    let response: ListApiClientsHttpResponse =
+        serde_json::from_value(response_payload).map_err(|err| to_cmd_error(err.into()))?;
+    Ok(response.clients)
+}
+
+/// Issues a token for another app. `scopes` is any of `speak`,
+/// `manage_voices` and `admin`; the token is shown once and cannot be
+/// listed later.
+#[tauri::command]
+async fn create_api_client(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    name: String,
+    scopes: Vec<String>,
+) -> Result<CreatedApiClient, CommandError> {
+    let trimmed_name = name.trim();
+    if trimmed_name.is_empty() {
+        return Err(CommandError::invalid("name cannot be empty"));
+    }
+    let (base_url, token) = api_client_endpoint(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let response_payload = request_json(
+        Method::POST,
+        &format!("{base_url}/v1/clients"),
+        &token,
+        Some(json!({ "name": trimmed_name, "scopes": scopes })),
+    )
+    .await
+    .map_err(to_cmd_error)?;
+    let mut created: CreatedApiClient =
+        serde_json::from_value(response_payload).map_err(|err| to_cmd_error(err.into()))?;
+    created.base_url = base_url;
+    Ok(created)
+}
+
+#[tauri::command]
+async fn revoke_api_client(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    client_id: String,
+) -> Result<GenericResult, CommandError> {
+    let client_id = client_id.trim();
+    if client_id.is_empty() {
+        return Err(CommandError::invalid("client_id cannot be empty"));
+    }
+    let (base_url, token) = api_client_endpoint(&app, &state.inner).await.map_err(to_cmd_error)?;
+    request_json(Method::DELETE, &format!("{base_url}/v1/clients/{client_id}"), &token, None)
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: format!("API client {client_id} revoked"),
+    })
+}
+
+#[tauri::command]
+async fn restart_engine(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, CommandError> {
+    restart_engine_inner(&app, &state.inner).await.map_err(to_cmd_error)?;
 
     #[cfg(feature = "build-base")]
     let message = "Kyutai runtime restarted and ready".to_string();
@@ -704,25 +2676,26 @@ async fn select_model(
     app: AppHandle,
     state: State<'_, SharedState>,
     model: String,
-) -> Result<SelectModelResult, String> {
+) -> Result<SelectModelResult, CommandError> {
     ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
     let normalized = model.trim().to_string();
     if !qwen_modes_enabled() && normalized != MODEL_KYUTAI {
-        return Err("Qwen model modes are available in Full build only.".to_string());
+        return Err(CommandError::unsupported("Qwen model modes are available in Full build only."));
     }
 
     match normalized.as_str() {
         MODEL_CUSTOM => {
             {
-                let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+                let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
                 guard.selected_model = MODEL_CUSTOM.to_string();
             }
+            persist_speak_state(&app, &state.inner);
             let _ = apply_custom_model_activation(&state.inner)
                 .await
                 .map_err(to_cmd_error)?;
             let health = engine_health_inner(&state.inner).await.map_err(to_cmd_error)?;
             let selected_speaker = {
-                let guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+                let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
                 guard.selected_qwen_speaker.clone()
             };
             Ok(SelectModelResult {
@@ -736,14 +2709,15 @@ async fn select_model(
         }
         MODEL_BASE => {
             {
-                let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+                let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
                 guard.selected_model = MODEL_BASE.to_string();
             }
+            persist_speak_state(&app, &state.inner);
             let health = engine_health_inner(&state.inner).await.map_err(to_cmd_error)?;
             Ok(SelectModelResult {
                 selected_model: MODEL_BASE.to_string(),
                 selected_speaker: {
-                    let guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+                    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
                     guard.selected_qwen_speaker.clone()
                 },
                 preset_speakers: speaker_presets(MODEL_CUSTOM),
@@ -754,9 +2728,10 @@ async fn select_model(
         }
         MODEL_KYUTAI => {
             {
-                let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+                let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
                 guard.selected_model = MODEL_KYUTAI.to_string();
             }
+            persist_speak_state(&app, &state.inner);
             let _ = apply_kyutai_model_activation(&state.inner)
                 .await
                 .map_err(to_cmd_error)?;
@@ -764,7 +2739,7 @@ async fn select_model(
             Ok(SelectModelResult {
                 selected_model: MODEL_KYUTAI.to_string(),
                 selected_speaker: {
-                    let guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+                    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
                     guard.selected_kyutai_voice.clone()
                 },
                 preset_speakers: speaker_presets(MODEL_KYUTAI),
@@ -773,19 +2748,26 @@ async fn select_model(
                 health,
             })
         }
-        _ => Err("Unknown model id".to_string()),
+        _ => Err(CommandError::invalid("Unknown model id")),
     }
 }
 
 #[tauri::command]
-fn set_selected_voice(state: State<'_, SharedState>, voice_id: String) -> Result<GenericResult, String> {
+fn set_selected_voice(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    voice_id: String,
+) -> Result<GenericResult, CommandError> {
     let normalized = voice_id.trim().to_string();
     if normalized.is_empty() {
-        return Err("voice_id cannot be empty".to_string());
+        return Err(CommandError::invalid("voice_id cannot be empty"));
     }
 
-    let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
-    guard.selected_voice_id = normalized.clone();
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.selected_voice_id = normalized.clone();
+    }
+    persist_speak_state(&app, &state.inner);
     Ok(GenericResult {
         ok: true,
         message: format!("Selected voice set to {normalized}"),
@@ -800,36 +2782,35 @@ async fn clone_voice_from_audio(
     wav_base64: String,
     language: Option<String>,
     ref_text: Option<String>,
-) -> Result<CloneVoiceResult, String> {
+) -> Result<CloneVoiceResult, CommandError> {
     ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
 
     let normalized_name = display_name.trim().to_string();
     if normalized_name.is_empty() {
-        return Err("display_name cannot be empty".to_string());
+        return Err(CommandError::invalid("display_name cannot be empty"));
     }
     if wav_base64.trim().is_empty() {
-        return Err("wav_base64 cannot be empty".to_string());
+        return Err(CommandError::invalid("wav_base64 cannot be empty"));
     }
 
     let selected_model = {
         let guard = state
             .inner
             .lock()
-            .map_err(|_| "State lock poisoned".to_string())?;
+            .map_err(|_| CommandError::lock_poisoned())?;
         guard.selected_model.clone()
     };
     if selected_model != MODEL_KYUTAI {
-        return Err(
-            "Voice cloning is currently enabled for Kyutai mode only. Switch model to Kyutai Pocket TTS first."
-                .to_string(),
-        );
+        return Err(CommandError::invalid(
+            "Voice cloning is currently enabled for Kyutai mode only. Switch model to Kyutai Pocket TTS first.",
+        ));
     }
 
     #[cfg(feature = "build-base")]
     {
         let wav_bytes = BASE64_STANDARD
             .decode(wav_base64.trim())
-            .map_err(|err| format!("Invalid wav_base64 payload: {err}"))?;
+            .map_err(|err| CommandError::invalid(format!("Invalid wav_base64 payload: {err}")))?;
         let language_hint = normalize_optional_text(language);
         let ref_text = normalize_optional_text(ref_text);
 
@@ -837,17 +2818,17 @@ async fn clone_voice_from_audio(
             let guard = state
                 .inner
                 .lock()
-                .map_err(|_| "State lock poisoned".to_string())?;
+                .map_err(|_| CommandError::lock_poisoned())?;
             guard
                 .local_kyutai
                 .clone()
-                .ok_or_else(|| "Kyutai Rust runtime is not initialized".to_string())?
+                .ok_or_else(|| CommandError::new(ErrorCode::EngineNotReady, "Kyutai Rust runtime is not initialized"))?
         };
 
         let cloned_meta = {
             let mut runtime_guard = runtime
                 .lock()
-                .map_err(|_| "Kyutai runtime lock poisoned".to_string())?;
+                .map_err(|_| CommandError::internal("Kyutai runtime lock poisoned"))?;
             runtime_guard
                 .clone_voice(&normalized_name, &wav_bytes, language_hint, ref_text)
                 .map_err(to_cmd_error)?
@@ -857,9 +2838,10 @@ async fn clone_voice_from_audio(
             let mut guard = state
                 .inner
                 .lock()
-                .map_err(|_| "State lock poisoned".to_string())?;
+                .map_err(|_| CommandError::lock_poisoned())?;
             guard.selected_voice_id = cloned_meta.voice_id.clone();
         }
+        persist_speak_state(&app, &state.inner);
 
         return Ok(CloneVoiceResult {
             ok: true,
@@ -877,7 +2859,7 @@ async fn clone_voice_from_audio(
         let guard = state
             .inner
             .lock()
-            .map_err(|_| "State lock poisoned".to_string())?;
+            .map_err(|_| CommandError::lock_poisoned())?;
         (guard.base_url.clone(), guard.token.clone())
     };
 
@@ -911,9 +2893,10 @@ async fn clone_voice_from_audio(
         let mut guard = state
             .inner
             .lock()
-            .map_err(|_| "State lock poisoned".to_string())?;
+            .map_err(|_| CommandError::lock_poisoned())?;
         guard.selected_voice_id = clone_response.voice_id.clone();
     }
+    persist_speak_state(&app, &state.inner);
 
     Ok(CloneVoiceResult {
         ok: true,
@@ -927,32 +2910,118 @@ async fn clone_voice_from_audio(
 
     #[cfg(not(any(feature = "build-base", feature = "build-full")))]
     {
-        Err("Unsupported build variant for voice cloning".to_string())
+        Err(CommandError::unsupported("Unsupported build variant for voice cloning"))
     }
 }
 
+/// Kyutai only: saves a voice interpolated between two presets or blended
+/// voices, `ratio` being the weight of `voice_b`.
 #[tauri::command]
-async fn update_saved_voice(
+async fn create_blended_voice(
     app: AppHandle,
     state: State<'_, SharedState>,
-    voice_id: String,
-    display_name: String,
-    language: Option<String>,
-    description: Option<String>,
-) -> Result<GenericResult, String> {
+    voice_a: String,
+    voice_b: String,
+    ratio: f32,
+) -> Result<CloneVoiceResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+
+    if !(0.0..=1.0).contains(&ratio) {
+        return Err(CommandError::invalid("ratio must be in [0, 1]"));
+    }
+    let (selected_model, selected_preset) = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        (guard.selected_model.clone(), guard.selected_kyutai_voice.clone())
+    };
+    if selected_model != MODEL_KYUTAI {
+        return Err(CommandError::invalid(
+            "Voice blending is available in Kyutai mode only. Switch model to Kyutai Pocket TTS first.",
+        ));
+    }
+    // The built-in default voice is whichever preset is selected.
+    let resolve = |voice: String| {
+        let voice = voice.trim().to_string();
+        if voice == "0" {
+            selected_preset.clone()
+        } else {
+            voice
+        }
+    };
+    let (voice_a, voice_b) = (resolve(voice_a), resolve(voice_b));
+    if voice_a.is_empty() || voice_b.is_empty() {
+        return Err(CommandError::invalid("voice_a and voice_b cannot be empty"));
+    }
+    if voice_a == voice_b {
+        return Err(CommandError::invalid("voice_a and voice_b must be different voices"));
+    }
+
+    let (voice_id, display_name) = blend_kyutai_voices(&state.inner, &voice_a, &voice_b, ratio).map_err(to_cmd_error)?;
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.selected_voice_id = voice_id.clone();
+    }
+    persist_speak_state(&app, &state.inner);
+    Ok(CloneVoiceResult {
+        ok: true,
+        message: format!("Blended voice saved: {display_name} ({voice_id})"),
+        voice_id,
+    })
+}
+
+/// Returns the new voice's `(voice_id, display_name)`.
+#[cfg(feature = "build-base")]
+fn blend_kyutai_voices(
+    state: &Arc<Mutex<EngineState>>,
+    voice_a: &str,
+    voice_b: &str,
+    ratio: f32,
+) -> Result<(String, String)> {
+    let runtime = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard
+            .local_kyutai
+            .clone()
+            .ok_or_else(|| anyhow!("Kyutai Rust runtime is not initialized"))?
+    };
+    let mut runtime_guard = runtime
+        .lock()
+        .map_err(|_| anyhow!("Kyutai runtime lock poisoned"))?;
+    let meta = runtime_guard.blend_voices(voice_a, voice_b, ratio)?;
+    Ok((meta.voice_id, meta.display_name))
+}
+
+#[cfg(not(feature = "build-base"))]
+fn blend_kyutai_voices(
+    _state: &Arc<Mutex<EngineState>>,
+    _voice_a: &str,
+    _voice_b: &str,
+    _ratio: f32,
+) -> Result<(String, String)> {
+    Err(anyhow!("Voice blending needs the in-process Kyutai runtime of the base build"))
+}
+
+#[tauri::command]
+async fn update_saved_voice(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    voice_id: String,
+    display_name: String,
+    language: Option<String>,
+    description: Option<String>,
+) -> Result<GenericResult, CommandError> {
     ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
 
     let normalized_voice_id = voice_id.trim().to_string();
     if normalized_voice_id.is_empty() {
-        return Err("voice_id cannot be empty".to_string());
+        return Err(CommandError::invalid("voice_id cannot be empty"));
     }
     if normalized_voice_id == "0" {
-        return Err("Built-in default voice cannot be edited".to_string());
+        return Err(CommandError::invalid("Built-in default voice cannot be edited"));
     }
 
     let normalized_name = display_name.trim().to_string();
     if normalized_name.is_empty() {
-        return Err("display_name cannot be empty".to_string());
+        return Err(CommandError::invalid("display_name cannot be empty"));
     }
 
     #[cfg(feature = "build-base")]
@@ -961,17 +3030,17 @@ async fn update_saved_voice(
             let guard = state
                 .inner
                 .lock()
-                .map_err(|_| "State lock poisoned".to_string())?;
+                .map_err(|_| CommandError::lock_poisoned())?;
             guard
                 .local_kyutai
                 .clone()
-                .ok_or_else(|| "Kyutai Rust runtime is not initialized".to_string())?
+                .ok_or_else(|| CommandError::new(ErrorCode::EngineNotReady, "Kyutai Rust runtime is not initialized"))?
         };
 
         let updated = {
             let mut runtime_guard = runtime
                 .lock()
-                .map_err(|_| "Kyutai runtime lock poisoned".to_string())?;
+                .map_err(|_| CommandError::internal("Kyutai runtime lock poisoned"))?;
             runtime_guard
                 .update_voice(
                     &normalized_voice_id,
@@ -997,7 +3066,7 @@ async fn update_saved_voice(
         let guard = state
             .inner
             .lock()
-            .map_err(|_| "State lock poisoned".to_string())?;
+            .map_err(|_| CommandError::lock_poisoned())?;
         (guard.base_url.clone(), guard.token.clone())
     };
 
@@ -1028,7 +3097,7 @@ async fn update_saved_voice(
 
     #[cfg(not(any(feature = "build-base", feature = "build-full")))]
     {
-        Err("Unsupported build variant for saved-voice update".to_string())
+        Err(CommandError::unsupported("Unsupported build variant for saved-voice update"))
     }
 }
 
@@ -1037,15 +3106,15 @@ async fn delete_saved_voice(
     app: AppHandle,
     state: State<'_, SharedState>,
     voice_id: String,
-) -> Result<GenericResult, String> {
+) -> Result<GenericResult, CommandError> {
     ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
 
     let normalized_voice_id = voice_id.trim().to_string();
     if normalized_voice_id.is_empty() {
-        return Err("voice_id cannot be empty".to_string());
+        return Err(CommandError::invalid("voice_id cannot be empty"));
     }
     if normalized_voice_id == "0" {
-        return Err("Built-in default voice cannot be deleted".to_string());
+        return Err(CommandError::invalid("Built-in default voice cannot be deleted"));
     }
 
     #[cfg(feature = "build-base")]
@@ -1054,16 +3123,16 @@ async fn delete_saved_voice(
             let guard = state
                 .inner
                 .lock()
-                .map_err(|_| "State lock poisoned".to_string())?;
+                .map_err(|_| CommandError::lock_poisoned())?;
             guard
                 .local_kyutai
                 .clone()
-                .ok_or_else(|| "Kyutai Rust runtime is not initialized".to_string())?
+                .ok_or_else(|| CommandError::new(ErrorCode::EngineNotReady, "Kyutai Rust runtime is not initialized"))?
         };
         {
             let mut runtime_guard = runtime
                 .lock()
-                .map_err(|_| "Kyutai runtime lock poisoned".to_string())?;
+                .map_err(|_| CommandError::internal("Kyutai runtime lock poisoned"))?;
             runtime_guard
                 .delete_voice(&normalized_voice_id)
                 .map_err(to_cmd_error)?;
@@ -1073,11 +3142,12 @@ async fn delete_saved_voice(
             let mut guard = state
                 .inner
                 .lock()
-                .map_err(|_| "State lock poisoned".to_string())?;
+                .map_err(|_| CommandError::lock_poisoned())?;
             if guard.selected_voice_id == normalized_voice_id {
                 guard.selected_voice_id = "0".to_string();
             }
         }
+        persist_speak_state(&app, &state.inner);
 
         return Ok(GenericResult {
             ok: true,
@@ -1091,7 +3161,7 @@ async fn delete_saved_voice(
         let guard = state
             .inner
             .lock()
-            .map_err(|_| "State lock poisoned".to_string())?;
+            .map_err(|_| CommandError::lock_poisoned())?;
         (guard.base_url.clone(), guard.token.clone())
     };
 
@@ -1108,11 +3178,12 @@ async fn delete_saved_voice(
         let mut guard = state
             .inner
             .lock()
-            .map_err(|_| "State lock poisoned".to_string())?;
+            .map_err(|_| CommandError::lock_poisoned())?;
         if guard.selected_voice_id == normalized_voice_id {
             guard.selected_voice_id = "0".to_string();
         }
     }
+    persist_speak_state(&app, &state.inner);
 
     Ok(GenericResult {
         ok: true,
@@ -1122,7 +3193,7 @@ async fn delete_saved_voice(
 
     #[cfg(not(any(feature = "build-base", feature = "build-full")))]
     {
-        Err("Unsupported build variant for saved-voice delete".to_string())
+        Err(CommandError::unsupported("Unsupported build variant for saved-voice delete"))
     }
 }
 
@@ -1131,27 +3202,28 @@ async fn set_preset_speaker(
     app: AppHandle,
     state: State<'_, SharedState>,
     speaker_id: String,
-) -> Result<SelectModelResult, String> {
+) -> Result<SelectModelResult, CommandError> {
     ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
 
     let selected_model = {
-        let guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
         guard.selected_model.clone()
     };
 
     match selected_model.as_str() {
         MODEL_CUSTOM => {
             if !qwen_modes_enabled() {
-                return Err("Qwen preset speakers are available in Full build only.".to_string());
+                return Err(CommandError::unsupported("Qwen preset speakers are available in Full build only."));
             }
             if !QWEN_SPEAKER_PRESETS.iter().any(|row| row.id == speaker_id) {
-                return Err("Unsupported Qwen speaker id".to_string());
+                return Err(CommandError::invalid("Unsupported Qwen speaker id"));
             }
 
             {
-                let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+                let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
                 guard.selected_qwen_speaker = speaker_id.clone();
             }
+            persist_speak_state(&app, &state.inner);
 
             let _ = apply_custom_model_activation(&state.inner)
                 .await
@@ -1168,13 +3240,14 @@ async fn set_preset_speaker(
         }
         MODEL_KYUTAI => {
             if !KYUTAI_VOICE_PRESETS.iter().any(|row| row.id == speaker_id) {
-                return Err("Unsupported Kyutai voice prompt".to_string());
+                return Err(CommandError::invalid("Unsupported Kyutai voice prompt"));
             }
 
             {
-                let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+                let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
                 guard.selected_kyutai_voice = speaker_id.clone();
             }
+            persist_speak_state(&app, &state.inner);
 
             let _ = apply_kyutai_model_activation(&state.inner)
                 .await
@@ -1203,6 +3276,12 @@ async fn set_preset_speaker(
     }
 }
 
+#[tauri::command]
+fn get_speak_settings(state: State<'_, SharedState>) -> Result<SpeakSettingsPayload, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(SpeakSettingsPayload::from(&guard.speak_settings))
+}
+
 #[tauri::command]
 fn set_speak_settings(
     app: AppHandle,
@@ -1210,38 +3289,60 @@ fn set_speak_settings(
     rate: f32,
     volume: f32,
     chunk_max_chars: u32,
-) -> Result<GenericResult, String> {
+    prefetch_depth: Option<u32>,
+) -> Result<GenericResult, CommandError> {
     if !(0.25..=4.0).contains(&rate) {
-        return Err("rate must be in [0.25, 4.0]".to_string());
+        return Err(CommandError::invalid("rate must be in [0.25, 4.0]"));
     }
     if !(0.0..=2.0).contains(&volume) {
-        return Err("volume must be in [0.0, 2.0]".to_string());
+        return Err(CommandError::invalid("volume must be in [0.0, 2.0]"));
     }
     if !(100..=2000).contains(&chunk_max_chars) {
-        return Err("chunk_max_chars must be in [100, 2000]".to_string());
+        return Err(CommandError::invalid("chunk_max_chars must be in [100, 2000]"));
+    }
+    if let Some(depth) = prefetch_depth {
+        // The sidecar always synthesizes one chunk ahead: its model takes one
+        // chunk at a time.
+        #[cfg(feature = "build-full")]
+        if depth != 0 {
+            return Err(CommandError::unsupported(
+                "prefetch_depth is Base build only; the Full build engine always synthesizes one chunk ahead",
+            ));
+        }
+        #[cfg(feature = "build-base")]
+        if depth as usize > MAX_PREFETCH_DEPTH {
+            return Err(CommandError::invalid(format!("prefetch_depth must be in [0, {MAX_PREFETCH_DEPTH}] (0 = automatic)")));
+        }
     }
 
     {
-        let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        let prefetch_depth = prefetch_depth.unwrap_or(guard.speak_settings.prefetch_depth);
+        let (silence_trim, peak_limiter) = (guard.speak_settings.silence_trim, guard.speak_settings.peak_limiter);
         guard.speak_settings = SpeakSettingsState {
             rate,
             volume,
             chunk_max_chars,
+            prefetch_depth,
+            silence_trim,
+            peak_limiter,
         };
         #[cfg(feature = "build-base")]
         if let Some(active_steps) = guard.active_rate_steps.as_ref() {
             active_steps.store(rate_to_steps(rate), Ordering::SeqCst);
         }
     }
+    persist_speak_state(&app, &state.inner);
 
-    let _ = app.emit_all("voicereader:rate-updated", RateUpdatedPayload { rate });
+    let _ = app.emit_routed("voicereader:rate-updated", RateUpdatedPayload { rate });
 
     #[cfg(feature = "build-full")]
     {
         let state_clone = state.inner.clone();
         let requested_rate = rate;
         tauri::async_runtime::spawn(async move {
-            if let Err(err) = update_active_job_playback_rate_full(&state_clone, requested_rate).await {
+            let playback = json!({ "rate": clamp_speak_rate(requested_rate) });
+            if let Err(err) = update_active_job_playback_full(&state_clone, playback).await {
                 eprintln!("Active job rate update failed: {err:#}");
             }
         });
@@ -1253,1461 +3354,8149 @@ fn set_speak_settings(
     })
 }
 
+/// Synthesizes a fixed corpus with the current model, voice and speak
+/// settings, without playback, and appends the timings to the benchmark
+/// history.
 #[tauri::command]
-fn cycle_speak_rate(app: AppHandle, state: State<'_, SharedState>) -> Result<SpeakRateResult, String> {
-    let next_rate = {
-        let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
-        let current_steps = (clamp_speak_rate(guard.speak_settings.rate) * 4.0).round() as i32;
-        let clamped_steps = current_steps.clamp(1, 16);
-        let next_steps = if clamped_steps >= 16 { 1 } else { clamped_steps + 1 };
-        let next_rate = next_steps as f32 / 4.0;
-        guard.speak_settings.rate = next_rate;
-        #[cfg(feature = "build-base")]
-        if let Some(active_steps) = guard.active_rate_steps.as_ref() {
-            active_steps.store(next_steps as u32, Ordering::SeqCst);
-        }
-        next_rate
+async fn run_benchmark(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    profile: BenchmarkProfile,
+) -> Result<BenchmarkResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let (voice_id, model, settings) = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        (
+            guard.selected_voice_id.clone(),
+            guard.selected_model.clone(),
+            guard.speak_settings.clone(),
+        )
     };
+    if model != MODEL_CUSTOM && model != MODEL_KYUTAI {
+        return Err(CommandError::invalid(format!(
+            "Current model mode ({model}) cannot be benchmarked. Switch to qwen_custom_voice or kyutai_pocket_tts."
+        )));
+    }
 
-    let _ = app.emit_all(
-        "voicereader:rate-updated",
-        RateUpdatedPayload { rate: next_rate },
-    );
+    let first_chunk_at = Arc::new(OnceLock::new());
+    let started = Instant::now();
+    let (pcm, sample_rate) = synthesize_voice_pcm(
+        &state.inner,
+        profile.corpus(),
+        voice_id.clone(),
+        settings.clone(),
+        Arc::new(AtomicBool::new(false)),
+        Some(first_chunk_at.clone()),
+    )
+    .await
+    .map_err(to_cmd_error)?;
+    let wall = started.elapsed();
+    if pcm.is_empty() || sample_rate == 0 {
+        return Err(CommandError::new(ErrorCode::EngineRequestFailed, "Benchmark produced no audio"));
+    }
+    let first_chunk = first_chunk_at.get().map_or(wall, |at| at.duration_since(started));
+    let audio_secs = pcm.len() as f64 / sample_rate as f64 * settings.rate as f64;
+
+    // The sidecar does the work in Full builds; Base synthesizes in-process.
+    let engine_pid = {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.synthesis_metrics.record(&model, wall.as_secs_f64(), audio_secs);
+        guard.child.as_ref().map(Child::id)
+    };
+    let result = BenchmarkResult {
+        recorded_at: chrono::Local::now().to_rfc3339(),
+        profile,
+        build_variant: build_variant_name().to_string(),
+        model,
+        voice_id,
+        rate: settings.rate,
+        chunk_max_chars: settings.chunk_max_chars,
+        time_to_first_chunk_ms: first_chunk.as_millis() as u64,
+        wall_secs: wall.as_secs_f64(),
+        audio_secs,
+        rtf: wall.as_secs_f64() / audio_secs,
+        peak_memory_mb: peak_memory_mb(engine_pid),
+    };
 
-    #[cfg(feature = "build-full")]
-    {
-        let state_clone = state.inner.clone();
-        tauri::async_runtime::spawn(async move {
-            if let Err(err) = update_active_job_playback_rate_full(&state_clone, next_rate).await {
-                eprintln!("Active job rate update failed: {err:#}");
-            }
-        });
+    if let Err(err) = append_benchmark(&app, &result) {
+        emit_error(&app, &format!("Benchmark finished but could not be saved: {err:#}"));
     }
-
-    Ok(SpeakRateResult {
-        ok: true,
-        message: format!("Playback rate set to {:.2}x", next_rate),
-        rate: next_rate,
-    })
+    Ok(result)
 }
 
 #[tauri::command]
-fn set_hotkey(
-    app: AppHandle,
-    state: State<'_, SharedState>,
-    hotkey: String,
-) -> Result<HotkeyResult, String> {
-    let normalized = normalize_hotkey(&hotkey).map_err(to_cmd_error)?;
-    if is_hotkey_os_reserved(&normalized) {
-        return Err(
-            "Alt+Space (Windows) and Cmd+Space (macOS) are OS-reserved. Use another hotkey."
-                .to_string(),
-        );
-    }
-
-    let previous = {
-        let guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
-        guard.hotkey.clone()
-    };
+fn list_benchmarks(app: AppHandle) -> Result<Vec<BenchmarkResult>, CommandError> {
+    let path = benchmarks_path(&app).ok_or_else(|| CommandError::internal("Unable to resolve benchmarks path"))?;
+    let history = BenchmarkHistory::load(&path).map_err(to_cmd_error)?;
+    Ok(history.runs().to_vec())
+}
 
-    if normalized == previous {
-        return Ok(HotkeyResult {
-            ok: true,
-            message: "Hotkey unchanged".to_string(),
-            hotkey: normalized,
-        });
-    }
+/// Started jobs, oldest first, with the capture metadata of their text.
+#[tauri::command]
+fn list_job_history(app: AppHandle) -> Result<Vec<JobHistoryEntry>, CommandError> {
+    let path = job_history_path(&app).ok_or_else(|| CommandError::internal("Unable to resolve history path"))?;
+    let history = JobHistory::load(&path).map_err(to_cmd_error)?;
+    Ok(history.entries().to_vec())
+}
 
-    let mut manager = app.global_shortcut_manager();
-    let _ = manager.unregister(&previous);
+/// How often each selection capture method found text since the app
+/// started, overall and per foreground app.
+#[tauri::command]
+fn get_capture_stats(state: State<'_, SharedState>) -> Result<CaptureStatsReport, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.capture_stats.report())
+}
 
-    if let Err(err) = register_hotkey_binding(&app, state.inner.clone(), &normalized) {
-        let _ = register_hotkey_binding(&app, state.inner.clone(), &previous);
-        return Err(to_cmd_error(err.context("Failed to register selected hotkey")));
-    }
+#[tauri::command]
+fn get_capture_overrides(state: State<'_, SharedState>) -> Result<CaptureOverrides, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.capture_overrides.clone())
+}
 
+/// Sets, per foreground app, which selection capture methods to try and in
+/// what order. Apps without an override keep the platform's default order.
+#[tauri::command]
+fn set_capture_overrides(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    overrides: CaptureOverrides,
+) -> Result<GenericResult, CommandError> {
+    overrides.validate().map_err(to_invalid_error)?;
     {
-        let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
-        guard.hotkey = normalized.clone();
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.capture_overrides = overrides.clone();
     }
-
-    if let Err(err) = persist_hotkey(&app, &normalized) {
-        let _ = app.emit_all(
-            "voicereader:error",
-            ErrorPayload {
-                message: format!("Hotkey set but could not persist settings: {err:#}"),
-            },
-        );
+    if let Err(err) = persist_capture_overrides(&app, &overrides) {
+        emit_error(&app, &format!("Capture overrides updated but could not persist settings: {err:#}"));
     }
-
-    let _ = app.emit_all(
-        "voicereader:hotkey-updated",
-        HotkeyUpdatedPayload {
-            hotkey: normalized.clone(),
-        },
-    );
-
-    Ok(HotkeyResult {
+    Ok(GenericResult {
         ok: true,
-        message: format!("Global hotkey updated to {normalized}"),
-        hotkey: normalized,
+        message: "Capture overrides updated".to_string(),
     })
 }
 
+/// Writes what was read within `range` to `path`, with start times and
+/// sources. A `.md` path gets Markdown, anything else plain text.
 #[tauri::command]
-async fn speak_text(
+fn export_session_transcript(
     app: AppHandle,
     state: State<'_, SharedState>,
-    text: String,
-) -> Result<GenericResult, String> {
-    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
-    let job_id = speak_and_stream(&app, &state.inner, text, "manual", "")
-        .await
-        .map_err(to_cmd_error)?;
+    range: TranscriptRange,
+    path: String,
+) -> Result<GenericResult, CommandError> {
+    let trimmed_path = path.trim();
+    if trimmed_path.is_empty() {
+        return Err(CommandError::invalid("path cannot be empty"));
+    }
+    let target = PathBuf::from(trimmed_path);
+    let session_started_at = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.session_started_at
+    };
+
+    let history_path = job_history_path(&app).ok_or_else(|| CommandError::internal("Unable to resolve history path"))?;
+    let history = JobHistory::load(&history_path).map_err(to_cmd_error)?;
+    let now = chrono::Local::now();
+    let entries = select_entries(history.entries(), &range, now, session_started_at).map_err(to_cmd_error)?;
+    let body = render_transcript(&entries, TranscriptFormat::for_path(&target), now);
+    write_transcript(&target, &body).map_err(to_cmd_error)?;
     Ok(GenericResult {
         ok: true,
-        message: format!("Speak job started: {job_id}"),
+        message: format!("Exported {} read(s) to {}", entries.len(), target.display()),
     })
 }
 
+/// Estimates listening time, chunk count and synthesis time for `text` with
+/// the current model and speak settings, so the UI can show the length of a
+/// read before starting it.
 #[tauri::command]
-async fn trigger_read_selection(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, String> {
-    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
-    read_selection_and_speak_inner(&app, &state.inner)
-        .await
-        .map_err(to_cmd_error)?;
+fn estimate_read(state: State<'_, SharedState>, text: String) -> Result<ReadEstimate, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    let model = guard.selected_model.clone();
+    let measured = guard.synthesis_metrics.rtf(&model);
+    let settings = &guard.speak_settings;
+    let chunk_max_chars = if model == MODEL_KYUTAI && cfg!(feature = "build-base") {
+        // The local runtime caps chunks to keep first audio fast.
+        settings.chunk_max_chars.min(LOCAL_CHUNK_MAX_CHARS)
+    } else {
+        settings.chunk_max_chars
+    };
+    Ok(estimate_read_text(
+        &text,
+        chunk_max_chars as usize,
+        settings.rate,
+        measured.unwrap_or_else(|| default_rtf(&model)),
+        measured.is_some(),
+    ))
+}
+
+/// Used until a read with the model has been measured.
+fn default_rtf(model: &str) -> f64 {
+    if model == MODEL_KYUTAI {
+        DEFAULT_RTF_KYUTAI
+    } else {
+        DEFAULT_RTF_QWEN
+    }
+}
+
+#[tauri::command]
+fn get_silence_trim(state: State<'_, SharedState>) -> Result<Option<SilenceTrim>, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.speak_settings.silence_trim)
+}
+
+/// Enables trimming of leading and trailing silence in each synthesized chunk,
+/// or disables it when `trim` is `None`. Applies from the next job.
+#[tauri::command]
+fn set_silence_trim(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    trim: Option<SilenceTrim>,
+) -> Result<GenericResult, CommandError> {
+    if let Some(trim) = trim.as_ref() {
+        trim.validate().map_err(to_invalid_error)?;
+    }
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.speak_settings.silence_trim = trim;
+    }
+    persist_speak_state(&app, &state.inner);
     Ok(GenericResult {
         ok: true,
-        message: "Read-selection hotkey flow triggered".to_string(),
+        message: if trim.is_some() {
+            "Silence trimming enabled".to_string()
+        } else {
+            "Silence trimming disabled".to_string()
+        },
     })
 }
 
 #[tauri::command]
-async fn cancel_active_job(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, String> {
-    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
-
-    let job_id = {
-        let guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
-        guard.last_job_id.clone()
-    };
-
-    let Some(job_id) = job_id else {
-        return Ok(GenericResult {
-            ok: true,
-            message: "No active job to cancel".to_string(),
-        });
-    };
+fn get_peak_limiter(state: State<'_, SharedState>) -> Result<PeakLimiter, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.speak_settings.peak_limiter)
+}
 
+/// Sets the ceiling of the output limiter, which keeps volume boosts and hot
+/// voices from clipping, or turns it off. Applies from the next job.
+#[tauri::command]
+fn set_peak_limiter(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    limiter: PeakLimiter,
+) -> Result<GenericResult, CommandError> {
+    limiter.validate().map_err(to_invalid_error)?;
     {
-        let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
-        guard.suppressed_job_ids.insert(job_id.clone());
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.speak_settings.peak_limiter = limiter;
     }
-    let _ = app.emit_all(
-        "voicereader:job-cancel-requested",
-        JobCancelRequestedPayload {
-            job_id: job_id.clone(),
+    if let Err(err) = persist_peak_limiter(&app, limiter) {
+        emit_error(&app, &format!("Peak limiter updated but could not persist settings: {err:#}"));
+    }
+    Ok(GenericResult {
+        ok: true,
+        message: if limiter.enabled {
+            format!("Peak limiter ceiling set to {:.1} dBTP", limiter.ceiling_db)
+        } else {
+            "Peak limiter disabled".to_string()
         },
-    );
+    })
+}
 
-    #[cfg(feature = "build-base")]
-    {
-        let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
-        if let Some(cancel_flag) = guard.active_cancel_flag.as_ref() {
-            cancel_flag.store(true, Ordering::SeqCst);
-        }
-        guard.active_rate_steps = None;
-        if guard.last_job_id.as_deref() == Some(job_id.as_str()) {
-            guard.last_job_id = None;
-        }
-        return Ok(GenericResult {
-            ok: true,
-            message: format!("Cancel request sent for job {job_id}"),
-        });
+/// Changes only the speak rate, including for the job that is currently
+/// speaking. The base runtime switches within the current chunk; the sidecar
+/// picks it up from the next chunk.
+#[tauri::command]
+fn set_live_rate(app: AppHandle, state: State<'_, SharedState>, rate: f32) -> Result<GenericResult, CommandError> {
+    if !(0.25..=4.0).contains(&rate) {
+        return Err(CommandError::invalid("rate must be in [0.25, 4.0]"));
     }
 
-    #[cfg(feature = "build-full")]
     {
-    let (base_url, token) = {
-        let guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
-        (guard.base_url.clone(), guard.token.clone())
-    };
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.speak_settings.rate = rate;
+        #[cfg(feature = "build-base")]
+        if let Some(active_steps) = guard.active_rate_steps.as_ref() {
+            active_steps.store(rate_to_steps(rate), Ordering::SeqCst);
+        }
+    }
+    persist_speak_state(&app, &state.inner);
 
-    let _ = request_json(
-        Method::POST,
-        &format!("{base_url}/v1/cancel"),
-        &token,
-        Some(json!({ "job_id": job_id })),
-    )
-    .await
-    .map_err(to_cmd_error)?;
+    let _ = app.emit_routed("voicereader:rate-updated", RateUpdatedPayload { rate });
 
+    #[cfg(feature = "build-full")]
     {
-        let mut guard = state.inner.lock().map_err(|_| "State lock poisoned".to_string())?;
-        if guard.last_job_id.as_deref() == Some(job_id.as_str()) {
-            guard.last_job_id = None;
-        }
+        let state_clone = state.inner.clone();
+        tauri::async_runtime::spawn(async move {
+            let playback = json!({ "rate": clamp_speak_rate(rate) });
+            if let Err(err) = update_active_job_playback_full(&state_clone, playback).await {
+                eprintln!("Active job rate update failed: {err:#}");
+            }
+        });
     }
 
     Ok(GenericResult {
         ok: true,
-        message: format!("Cancel request sent for job {job_id}"),
+        message: "Rate updated".to_string(),
     })
-    }
-
-    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
-    {
-        Err("Unsupported build variant for cancel operation".to_string())
-    }
 }
 
-fn register_hotkey(app: &AppHandle, state: Arc<Mutex<EngineState>>) -> Result<()> {
-    let hotkey = {
-        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-        guard.hotkey.clone()
+/// Moves the speak rate `delta` from the nearest quarter step, including for
+/// the job that is speaking.
+fn step_speak_rate(app: &AppHandle, delta: f32) -> Result<()> {
+    let state = app.state::<SharedState>();
+    let rate = {
+        let guard = state.inner.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        clamp_speak_rate((guard.speak_settings.rate * 4.0).round() / 4.0 + delta)
     };
+    set_live_rate(app.clone(), state, rate).map(|_| ()).map_err(|err| anyhow!(err))
+}
 
-    if is_hotkey_os_reserved(&hotkey) {
-        return Err(anyhow!(
-            "Selected hotkey {hotkey} is OS-reserved. Use a non-reserved combination."
-        ));
-    }
-
-    if register_hotkey_binding(app, state.clone(), &hotkey).is_ok() {
-        return Ok(());
+/// Changes the volume, including for the job that is currently speaking. The
+/// new gain is ramped in over a few milliseconds so the change does not click.
+#[tauri::command]
+fn set_live_volume(app: AppHandle, state: State<'_, SharedState>, volume: f32) -> Result<GenericResult, CommandError> {
+    if !(0.0..=2.0).contains(&volume) {
+        return Err(CommandError::invalid("volume must be in [0.0, 2.0]"));
     }
 
-    if hotkey != DEFAULT_FALLBACK_HOTKEY {
-        register_hotkey_binding(app, state.clone(), DEFAULT_FALLBACK_HOTKEY)
-            .with_context(|| format!("Failed to register fallback hotkey {DEFAULT_FALLBACK_HOTKEY}"))?;
-        if let Ok(mut guard) = state.lock() {
-            guard.hotkey = DEFAULT_FALLBACK_HOTKEY.to_string();
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.speak_settings.volume = volume;
+        #[cfg(feature = "build-base")]
+        if let Some(active_volume) = guard.active_volume.as_ref() {
+            active_volume.store(volume.to_bits(), Ordering::SeqCst);
         }
-        let _ = persist_hotkey(app, DEFAULT_FALLBACK_HOTKEY);
-        let _ = app.emit_all(
-            "voicereader:hotkey-updated",
-            HotkeyUpdatedPayload {
-                hotkey: DEFAULT_FALLBACK_HOTKEY.to_string(),
-            },
-        );
-        return Ok(());
     }
+    persist_speak_state(&app, &state.inner);
 
-    Err(anyhow!("Failed to register global hotkey {hotkey}"))
-}
+    let _ = app.emit_routed("voicereader:volume-updated", VolumeUpdatedPayload { volume });
 
-fn register_hotkey_binding(app: &AppHandle, state: Arc<Mutex<EngineState>>, hotkey: &str) -> Result<()> {
-    let hotkey = normalize_hotkey(hotkey)?;
-    let app_handle = app.clone();
-    app.global_shortcut_manager()
-        .register(&hotkey, move || {
-            let app_clone = app_handle.clone();
-            if should_ignore_hotkey_while_app_focused(&app_clone) {
-                return;
+    #[cfg(feature = "build-full")]
+    {
+        let state_clone = state.inner.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(err) = update_active_job_playback_full(&state_clone, json!({ "volume": volume })).await {
+                eprintln!("Active job volume update failed: {err:#}");
             }
-            let state_clone = state.clone();
-            tauri::async_runtime::spawn(async move {
-                if let Err(err) = read_selection_and_speak_inner(&app_clone, &state_clone).await {
-                    emit_error(&app_clone, &format!("Hotkey flow failed: {err:#}"));
-                }
-            });
-        })
-        .with_context(|| format!("Failed to register global hotkey {hotkey}"))?;
+        });
+    }
 
-    Ok(())
+    Ok(GenericResult {
+        ok: true,
+        message: "Volume updated".to_string(),
+    })
 }
 
-fn should_ignore_hotkey_while_app_focused(app: &AppHandle) -> bool {
-    let Some(window) = app.get_window("main") else {
-        return false;
+/// Applies to reads of the selection; other reads and file exports always
+/// speak the whole text as it is.
+#[tauri::command]
+fn set_reading_order(
+    state: State<'_, SharedState>,
+    announce_paragraphs: bool,
+    first_sentence_only: bool,
+) -> Result<GenericResult, CommandError> {
+    let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    guard.reading_order = ReadingOrder {
+        announce_paragraphs,
+        first_sentence_only,
     };
-    window.is_focused().unwrap_or(false)
+    Ok(GenericResult {
+        ok: true,
+        message: "Reading order updated".to_string(),
+    })
 }
 
-async fn read_selection_and_speak_inner(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
-    ensure_engine_ready(app, state).await?;
+#[tauri::command]
+fn set_skim_settings(
+    state: State<'_, SharedState>,
+    words_per_sentence: u32,
+    sentence_stride: u32,
+    rate: f32,
+) -> Result<GenericResult, CommandError> {
+    if words_per_sentence > MAX_SKIM_WORDS_PER_SENTENCE {
+        return Err(CommandError::invalid(format!(
+            "words_per_sentence must be in [0, {MAX_SKIM_WORDS_PER_SENTENCE}] (0 = whole sentence)"
+        )));
+    }
+    if !(1..=MAX_SKIM_SENTENCE_STRIDE).contains(&sentence_stride) {
+        return Err(CommandError::invalid(format!("sentence_stride must be in [1, {MAX_SKIM_SENTENCE_STRIDE}]")));
+    }
+    if !(0.25..=4.0).contains(&rate) {
+        return Err(CommandError::invalid("rate must be in [0.25, 4.0]"));
+    }
 
-    // Capture source window before simulated Ctrl+C changes focus state.
-    let source_window = get_foreground_window_title().unwrap_or_default();
-    let text = capture_selected_text_from_active_app(app).await;
-    let Some(text) = text else {
-        let _ = app.emit_all(
-            "voicereader:selection-empty",
-            json!({ "reason": "no_selection_detected" }),
-        );
-        return Ok(());
+    let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    guard.skim_settings = SkimSettings {
+        words_per_sentence,
+        sentence_stride,
+        rate,
     };
-
-    let _ = speak_and_stream(
-        app,
-        state,
-        text,
-        "hotkey_selection_capture",
-        &source_window,
-    )
-    .await?;
-    Ok(())
+    Ok(GenericResult {
+        ok: true,
+        message: "Skim settings updated".to_string(),
+    })
 }
 
-async fn capture_selected_text_from_active_app(app: &AppHandle) -> Option<String> {
-    let previous_clipboard = app.clipboard_manager().read_text().ok().flatten();
-    let probe_clipboard_value = build_selection_probe_value();
-    let probe_set = app
-        .clipboard_manager()
-        .write_text(probe_clipboard_value.clone())
-        .is_ok();
-
-    // Hotkey callback can run while Ctrl/Shift is still physically down.
-    // Wait briefly so simulated copy does not become Ctrl+Shift+C in target apps.
-    wait_for_hotkey_modifiers_release().await;
+#[tauri::command]
+fn get_translation_settings(state: State<'_, SharedState>) -> Result<TranslationSettings, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.translation.clone())
+}
 
-    if !trigger_system_copy_shortcut() {
-        restore_clipboard_text(app, previous_clipboard, probe_set);
-        return None;
+#[tauri::command]
+fn set_translation_settings(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    settings: TranslationSettings,
+) -> Result<GenericResult, CommandError> {
+    settings.validate().map_err(to_invalid_error)?;
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.translation = settings.clone();
     }
 
-    let previous_trimmed = previous_clipboard.as_deref().map(str::trim);
-    let started = Instant::now();
-    let mut captured: Option<String> = None;
-
-    while started.elapsed() < Duration::from_millis(SELECTION_COPY_TIMEOUT_MS) {
-        let current_clipboard = app.clipboard_manager().read_text().ok().flatten();
-        let normalized_current = normalized_clipboard_text(current_clipboard);
-        if let Some(current_text) = normalized_current {
-            let changed = if probe_set {
-                current_text != probe_clipboard_value
-            } else {
-                previous_trimmed.map_or(true, |prev| current_text != prev)
-            };
-            if changed {
-                captured = Some(current_text);
-                break;
-            }
-        }
-        sleep(Duration::from_millis(SELECTION_COPY_POLL_MS)).await;
+    if let Err(err) = persist_translation_settings(&app, &settings) {
+        emit_error(&app, &format!("Translation settings updated but could not persist settings: {err:#}"));
     }
 
-    restore_clipboard_text(app, previous_clipboard, probe_set);
-
-    captured
+    let message = if settings.is_enabled() {
+        format!("Translation enabled (target: {})", settings.target_language.trim())
+    } else {
+        "Translation disabled".to_string()
+    };
+    Ok(GenericResult { ok: true, message })
 }
 
-fn normalized_clipboard_text(raw: Option<String>) -> Option<String> {
-    raw.map(|value| value.trim().to_string())
-        .filter(|value| !value.is_empty())
+#[tauri::command]
+fn get_number_format(state: State<'_, SharedState>) -> Result<NumberSettings, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.number_format.clone())
 }
 
-fn restore_clipboard_text(app: &AppHandle, previous_clipboard: Option<String>, probe_set: bool) {
-    if let Some(previous_text) = previous_clipboard {
-        let _ = app.clipboard_manager().write_text(previous_text);
-    } else if probe_set {
-        // We temporarily set a probe value; clear it back to empty when clipboard had no text before.
-        let _ = app.clipboard_manager().write_text(String::new());
+/// Sets the locale numbers in read text are written in, separate from the
+/// UI language, so `1.234` reads as a thousand-odd for a German locale and
+/// as a decimal for a US one.
+#[tauri::command]
+fn set_number_format(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    settings: NumberSettings,
+) -> Result<GenericResult, CommandError> {
+    let settings = NumberSettings {
+        locale: settings
+            .locale
+            .map(|locale| locale.trim().to_string())
+            .filter(|locale| !locale.is_empty()),
+        ..settings
+    };
+    settings.validate().map_err(to_invalid_error)?;
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.number_format = settings.clone();
     }
-}
 
-fn build_selection_probe_value() -> String {
-    let suffix: String = rand::thread_rng()
-        .sample_iter(&Alphanumeric)
-        .take(12)
-        .map(char::from)
-        .collect();
-    format!("__voicereader_selection_probe_{suffix}__")
+    if let Err(err) = persist_number_format(&app, &settings) {
+        emit_error(&app, &format!("Number format updated but could not persist settings: {err:#}"));
+    }
+
+    let message = match settings.locale.as_deref() {
+        Some(locale) => format!("Numbers are read as written in {locale}"),
+        None => "Numbers are read as written".to_string(),
+    };
+    Ok(GenericResult { ok: true, message })
 }
 
-async fn wait_for_hotkey_modifiers_release() {
-    let started = Instant::now();
-    while started.elapsed() < Duration::from_millis(HOTKEY_MODIFIER_RELEASE_TIMEOUT_MS) {
-        if !hotkey_modifiers_pressed() {
-            return;
-        }
-        sleep(Duration::from_millis(HOTKEY_MODIFIER_RELEASE_POLL_MS)).await;
-    }
+#[tauri::command]
+fn get_acronym_settings(state: State<'_, SharedState>) -> Result<AcronymSettings, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.acronyms.clone())
 }
 
-fn hotkey_modifiers_pressed() -> bool {
-    #[cfg(target_os = "windows")]
+/// Toggles reading short all-caps tokens letter by letter, with `words`
+/// listing tokens that are still read as words.
+#[tauri::command]
+fn set_acronym_settings(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    settings: AcronymSettings,
+) -> Result<GenericResult, CommandError> {
+    let settings = AcronymSettings {
+        words: settings
+            .words
+            .iter()
+            .map(|word| word.trim().to_ascii_uppercase())
+            .filter(|word| !word.is_empty())
+            .collect(),
+        ..settings
+    };
+    settings.validate().map_err(to_invalid_error)?;
     {
-        return hotkey_modifiers_pressed_windows();
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.acronyms = settings.clone();
     }
 
-    #[cfg(target_os = "macos")]
-    {
-        return hotkey_modifiers_pressed_macos();
+    if let Err(err) = persist_acronym_settings(&app, &settings) {
+        emit_error(&app, &format!("Acronym settings updated but could not persist settings: {err:#}"));
     }
 
-    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
-    {
-        false
-    }
+    let message = if settings.enabled {
+        "Acronyms are spelled out".to_string()
+    } else {
+        "Acronyms are read as written".to_string()
+    };
+    Ok(GenericResult { ok: true, message })
 }
 
-#[cfg(target_os = "windows")]
-fn hotkey_modifiers_pressed_windows() -> bool {
-    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{
-        GetAsyncKeyState, VK_CONTROL, VK_LWIN, VK_MENU, VK_RWIN, VK_SHIFT,
+#[tauri::command]
+fn get_lexicon(state: State<'_, SharedState>) -> Result<PronunciationLexicon, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.lexicon.clone())
+}
+
+/// Replaces the pronunciation lexicon. IPA and pinyin entries are checked
+/// here, so a typo fails the call instead of being read out later.
+#[tauri::command]
+fn set_lexicon(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    lexicon: PronunciationLexicon,
+) -> Result<GenericResult, CommandError> {
+    let lexicon = PronunciationLexicon {
+        entries: lexicon
+            .entries
+            .into_iter()
+            .map(|entry| LexiconEntry {
+                word: entry.word.trim().to_string(),
+                pronunciation: entry.pronunciation.trim().to_string(),
+                ..entry
+            })
+            .collect(),
     };
+    lexicon.validate().map_err(to_invalid_error)?;
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.lexicon = lexicon.clone();
+    }
 
-    fn is_pressed(vk: i32) -> bool {
-        unsafe { (GetAsyncKeyState(vk) as u16 & 0x8000) != 0 }
+    if let Err(err) = persist_lexicon(&app, &lexicon) {
+        emit_error(&app, &format!("Lexicon updated but could not persist settings: {err:#}"));
     }
 
-    is_pressed(VK_CONTROL as i32)
-        || is_pressed(VK_SHIFT as i32)
-        || is_pressed(VK_MENU as i32)
-        || is_pressed(VK_LWIN as i32)
-        || is_pressed(VK_RWIN as i32)
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Lexicon has {} entries", lexicon.entries.len()),
+    })
 }
 
-fn trigger_system_copy_shortcut() -> bool {
-    #[cfg(target_os = "windows")]
-    {
-        return trigger_copy_shortcut_windows();
-    }
+#[tauri::command]
+fn get_readback_settings(state: State<'_, SharedState>) -> Result<ReadbackSettings, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.readback.clone())
+}
 
-    #[cfg(target_os = "macos")]
+/// Configures the recognizer `export_speech_to_file` runs over each
+/// exported file; an empty program turns verification off.
+#[tauri::command]
+fn set_readback_settings(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    settings: ReadbackSettings,
+) -> Result<GenericResult, CommandError> {
+    let settings = ReadbackSettings {
+        program: settings.program.trim().to_string(),
+        ..settings
+    };
+    settings.validate().map_err(to_invalid_error)?;
     {
-        return trigger_copy_shortcut_macos();
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.readback = settings.clone();
     }
 
-    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
-    {
-        false
+    if let Err(err) = persist_readback_settings(&app, &settings) {
+        emit_error(&app, &format!("Read-back settings updated but could not persist settings: {err:#}"));
     }
-}
 
-#[cfg(target_os = "windows")]
-fn trigger_copy_shortcut_windows() -> bool {
-    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{
-        SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP, VK_CONTROL,
+    let message = if settings.is_enabled() {
+        format!("Exports are verified with {}", settings.program)
+    } else {
+        "Export verification disabled".to_string()
     };
+    Ok(GenericResult { ok: true, message })
+}
 
-    const KEY_C: u16 = 0x43;
+#[tauri::command]
+fn get_webhooks(state: State<'_, SharedState>) -> Result<WebhookSettings, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.webhooks.clone())
+}
 
-    fn keyboard_input(vk: u16, flags: u32) -> INPUT {
-        INPUT {
-            r#type: INPUT_KEYBOARD,
-            Anonymous: INPUT_0 {
-                ki: KEYBDINPUT {
-                    wVk: vk,
-                    wScan: 0,
-                    dwFlags: flags,
-                    time: 0,
-                    dwExtraInfo: 0,
-                },
-            },
+/// Replaces the job webhooks; an empty `hooks` list turns them off.
+#[tauri::command]
+fn set_webhooks(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    settings: WebhookSettings,
+) -> Result<GenericResult, CommandError> {
+    settings.validate().map_err(to_invalid_error)?;
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.webhooks = settings.clone();
+        if settings.hooks.is_empty() {
+            guard.webhook_jobs.clear();
         }
     }
 
-    let inputs = [
-        keyboard_input(VK_CONTROL as u16, 0),
-        keyboard_input(KEY_C, 0),
-        keyboard_input(KEY_C, KEYEVENTF_KEYUP),
-        keyboard_input(VK_CONTROL as u16, KEYEVENTF_KEYUP),
-    ];
+    if let Err(err) = persist_webhooks(&app, &settings) {
+        emit_error(&app, &format!("Webhooks updated but could not persist settings: {err:#}"));
+    }
 
-    let sent = unsafe {
-        SendInput(
-            inputs.len() as u32,
-            inputs.as_ptr(),
-            std::mem::size_of::<INPUT>() as i32,
-        )
-    };
+    Ok(GenericResult {
+        ok: true,
+        message: format!("{} job webhooks configured", settings.hooks.len()),
+    })
+}
 
-    sent == inputs.len() as u32
+#[tauri::command]
+fn get_audio_sinks(state: State<'_, SharedState>) -> Result<AudioSinkSettings, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.audio_sinks.clone())
 }
 
-#[cfg(target_os = "macos")]
-fn hotkey_modifiers_pressed_macos() -> bool {
-    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
-    use core_graphics::event::CGEventFlags;
+/// Replaces the sinks every job's audio is copied to, from the next job on;
+/// an empty `sinks` list leaves only the app's own player.
+#[tauri::command]
+fn set_audio_sinks(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    settings: AudioSinkSettings,
+) -> Result<GenericResult, CommandError> {
+    settings.validate().map_err(to_invalid_error)?;
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.audio_sinks = settings.clone();
+    }
 
-    let source_state = CGEventSourceStateID::CombinedSessionState;
-    let flags = CGEventSource::flags_state(source_state);
+    if let Err(err) = persist_audio_sinks(&app, &settings) {
+        emit_error(&app, &format!("Audio sinks updated but could not persist settings: {err:#}"));
+    }
 
-    flags.contains(CGEventFlags::CGEventFlagCommand)
-        || flags.contains(CGEventFlags::CGEventFlagShift)
-        || flags.contains(CGEventFlags::CGEventFlagControl)
-        || flags.contains(CGEventFlags::CGEventFlagAlternate)
+    Ok(GenericResult {
+        ok: true,
+        message: format!("{} audio sinks configured", settings.sinks.len()),
+    })
 }
 
-#[cfg(target_os = "macos")]
-fn trigger_copy_shortcut_macos() -> bool {
-    use core_graphics::event::{CGEvent, CGEventFlags, CGEventTapLocation, CGKeyCode};
-    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
-
-    // Virtual key code for 'C' on macOS.
-    const VK_C: CGKeyCode = 0x08;
-
-    let source = match CGEventSource::new(CGEventSourceStateID::HIDSystemState) {
-        Ok(src) => src,
-        Err(_) => return false,
-    };
+/// Searches the local network for speakers that can play reads, for
+/// `set_cast_target`.
+#[tauri::command]
+async fn list_cast_targets(state: State<'_, SharedState>, timeout_ms: Option<u64>) -> Result<CastTargetsResult, CommandError> {
+    let timeout = Duration::from_millis(
+        timeout_ms
+            .unwrap_or(CAST_DISCOVERY_DEFAULT_MS)
+            .clamp(CAST_DISCOVERY_MIN_MS, CAST_DISCOVERY_MAX_MS),
+    );
+    let locations = tauri::async_runtime::spawn_blocking(move || search_renderers(timeout))
+        .await
+        .map_err(|err| CommandError::internal(format!("Speaker search failed: {err}")))?
+        .map_err(to_cmd_error)?;
+    let client = Client::new();
+    let mut targets: Vec<CastTarget> = Vec::new();
+    for location in locations {
+        // Unreachable or non-renderer devices are left out of the list.
+        if let Ok(Some(target)) = describe_renderer(&client, &location).await {
+            if !targets.iter().any(|known| known.id == target.id) {
+                targets.push(target);
+            }
+        }
+    }
 
-    let key_down = match CGEvent::new_keyboard_event(source.clone(), VK_C, true) {
-        Ok(evt) => evt,
-        Err(_) => return false,
-    };
-    let key_up = match CGEvent::new_keyboard_event(source, VK_C, false) {
-        Ok(evt) => evt,
-        Err(_) => return false,
-    };
+    let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    guard.cast_targets_found = targets.clone();
+    Ok(CastTargetsResult {
+        targets,
+        selected: guard.cast_target.as_ref().map(|target| target.id.clone()),
+    })
+}
 
-    // Hold Cmd while pressing C (simulates Cmd+C).
-    key_down.set_flags(CGEventFlags::CGEventFlagCommand);
-    key_up.set_flags(CGEventFlags::CGEventFlagCommand);
+/// Streams every job to the speaker with `target_id` as well as playing it
+/// locally; `None` stops casting.
+#[tauri::command]
+fn set_cast_target(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    target_id: Option<String>,
+) -> Result<GenericResult, CommandError> {
+    let target = {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        let target = match target_id.as_deref() {
+            None => None,
+            Some(id) => Some(
+                guard
+                    .cast_targets_found
+                    .iter()
+                    .chain(guard.cast_target.iter())
+                    .find(|target| target.id == id)
+                    .cloned()
+                    .ok_or_else(|| CommandError::not_found(format!("Unknown cast target {id}; search with list_cast_targets first")))?,
+            ),
+        };
+        guard.cast_target = target.clone();
+        target
+    };
 
-    key_down.post(CGEventTapLocation::HID);
-    key_up.post(CGEventTapLocation::HID);
+    if let Err(err) = persist_cast_target(&app, target.as_ref()) {
+        emit_error(&app, &format!("Cast target updated but could not persist settings: {err:#}"));
+    }
 
-    true
+    Ok(GenericResult {
+        ok: true,
+        message: match target {
+            Some(target) => format!("Reads are also cast to {}", target.name),
+            None => "Casting stopped".to_string(),
+        },
+    })
 }
 
-#[cfg(target_os = "macos")]
-fn get_frontmost_app_name_macos() -> Option<String> {
-    use objc::{class, msg_send, sel, sel_impl};
-    use objc::runtime::Object;
-    use core_foundation::string::CFString;
-    use core_foundation::base::{CFType, TCFType};
+#[tauri::command]
+fn get_virtual_mic(state: State<'_, SharedState>) -> Result<VirtualMicStatus, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(VirtualMicStatus {
+        enabled: guard.virtual_mic.is_some(),
+        device: guard.virtual_mic_device.clone(),
+        consent_needed: !guard.virtual_mic_consented,
+        consent_notice: CONSENT_NOTICE,
+        sox_found: sox_found(),
+    })
+}
 
-    unsafe {
-        let workspace: *mut Object = msg_send![class!(NSWorkspace), sharedWorkspace];
-        if workspace.is_null() {
-            return None;
-        }
-        let app: *mut Object = msg_send![workspace, frontmostApplication];
-        if app.is_null() {
-            return None;
+/// Turns the virtual microphone output on or off; while on, every job also
+/// plays into `device` (or the one chosen before), where calls and recorders
+/// pick it up as microphone input. The first time in a session this needs
+/// `consent: true`, sent only after the user accepted `consent_notice`.
+#[tauri::command]
+fn set_virtual_mic(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    enabled: bool,
+    device: Option<String>,
+    consent: Option<bool>,
+) -> Result<GenericResult, CommandError> {
+    let device = device.map(|device| device.trim().to_string());
+    if let Some(device) = device.as_deref() {
+        virtual_mic::validate_device(device).map_err(to_invalid_error)?;
+    }
+    if enabled && !sox_found() {
+        return Err(CommandError::invalid("SoX was not found; install it to use the virtual microphone"));
+    }
+    let device = {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        if enabled && !guard.virtual_mic_consented {
+            if consent != Some(true) {
+                return Err(CommandError::invalid("The virtual microphone needs consent for this session first"));
+            }
+            guard.virtual_mic_consented = true;
         }
-        let name: *mut Object = msg_send![app, localizedName];
-        if name.is_null() {
-            return None;
+        if let Some(device) = device.clone() {
+            guard.virtual_mic_device = device;
         }
+        guard.virtual_mic = enabled.then(|| guard.virtual_mic_device.clone());
+        guard.virtual_mic_device.clone()
+    };
 
-        // NSString -> CFString -> Rust String
-        let cf_str = CFString::wrap_under_get_rule(name as *const _);
-        let result = cf_str.to_string();
-        if result.is_empty() {
-            None
-        } else {
-            Some(result)
-        }
+    if let Err(err) = persist_virtual_mic_device(&app, &device) {
+        emit_error(&app, &format!("Virtual microphone updated but could not persist settings: {err:#}"));
     }
+
+    Ok(GenericResult {
+        ok: true,
+        message: if enabled {
+            format!("Reads also play into {device} from the next one on")
+        } else {
+            "Virtual microphone off".to_string()
+        },
+    })
 }
 
-#[cfg(target_os = "windows")]
-fn get_foreground_window_title() -> Option<String> {
-    use windows_sys::Win32::UI::WindowsAndMessaging::{
-        GetForegroundWindow, GetWindowTextLengthW, GetWindowTextW,
+#[tauri::command]
+fn get_pipe_endpoint(state: State<'_, SharedState>) -> Result<PipeEndpointStatus, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(PipeEndpointStatus {
+        enabled: guard.pipe_endpoint.enabled,
+        path: guard
+            .pipe_listener
+            .as_ref()
+            .map(|listener| listener.path().to_string_lossy().to_string()),
+        api_socket: engine_api_socket().map(|path| path.to_string_lossy().to_string()),
+    })
+}
+
+/// Turns the local pipe endpoint on or off. Text written to the pipe is
+/// queued like any other read.
+#[tauri::command]
+fn set_pipe_endpoint(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    settings: PipeEndpointSettings,
+) -> Result<GenericResult, CommandError> {
+    let previous = {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        std::mem::replace(&mut guard.pipe_endpoint, settings.clone())
+    };
+    let path = match apply_pipe_endpoint(&app, &state.inner) {
+        Ok(path) => path,
+        Err(err) => {
+            if let Ok(mut guard) = state.inner.lock() {
+                guard.pipe_endpoint = previous;
+            }
+            let _ = apply_pipe_endpoint(&app, &state.inner);
+            return Err(to_cmd_error(err));
+        }
     };
 
-    let hwnd = unsafe { GetForegroundWindow() };
-    if hwnd.is_null() {
-        return None;
+    if let Err(err) = persist_pipe_endpoint(&app, &settings) {
+        emit_error(&app, &format!("Pipe endpoint updated but could not persist settings: {err:#}"));
     }
 
-    let title_len = unsafe { GetWindowTextLengthW(hwnd) };
-    if title_len <= 0 {
-        return None;
+    let message = match path {
+        Some(path) => format!("Listening for text on {}", path.display()),
+        None => "Pipe endpoint disabled".to_string(),
+    };
+    Ok(GenericResult { ok: true, message })
+}
+
+#[tauri::command]
+fn get_engine_http_settings() -> EngineHttpSettings {
+    engine_http_settings()
+}
+
+/// Sets the timeouts and retries for engine requests; takes effect with the
+/// next request.
+#[tauri::command]
+fn set_engine_http_settings(app: AppHandle, settings: EngineHttpSettings) -> Result<GenericResult, CommandError> {
+    settings.validate().map_err(to_invalid_error)?;
+    apply_engine_http_settings(settings.clone());
+    if let Err(err) = persist_engine_http(&app, &settings) {
+        emit_error(&app, &format!("Engine timeouts updated but could not persist settings: {err:#}"));
     }
+    Ok(GenericResult {
+        ok: true,
+        message: format!(
+            "Engine requests time out after {} ms ({} ms to connect) with up to {} retries",
+            settings.request_timeout_ms, settings.connect_timeout_ms, settings.max_retries
+        ),
+    })
+}
 
-    let mut title_buf = vec![0u16; title_len as usize + 1];
-    let written = unsafe { GetWindowTextW(hwnd, title_buf.as_mut_ptr(), title_buf.len() as i32) };
-    if written <= 0 {
-        return None;
+#[tauri::command]
+fn get_summary_settings(state: State<'_, SharedState>) -> Result<SummarySettings, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.summary.clone())
+}
+
+#[tauri::command]
+fn set_summary_settings(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    settings: SummarySettings,
+) -> Result<GenericResult, CommandError> {
+    settings.validate().map_err(to_invalid_error)?;
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.summary = settings.clone();
     }
 
-    let title = String::from_utf16_lossy(&title_buf[..written as usize])
-        .trim()
-        .to_string();
-    if title.is_empty() {
-        return None;
+    if let Err(err) = persist_summary_settings(&app, &settings) {
+        emit_error(&app, &format!("Summary settings updated but could not persist settings: {err:#}"));
     }
-    Some(title)
+
+    let message = if settings.is_enabled() {
+        format!("Summaries enabled (up to {} sentences)", settings.max_sentences)
+    } else {
+        "Summaries disabled".to_string()
+    };
+    Ok(GenericResult { ok: true, message })
 }
 
-#[cfg(target_os = "macos")]
-fn get_foreground_window_title() -> Option<String> {
-    get_frontmost_app_name_macos()
+#[tauri::command]
+fn set_echo_settings(
+    state: State<'_, SharedState>,
+    source_voice_id: Option<String>,
+    pause_ms: u32,
+) -> Result<GenericResult, CommandError> {
+    if pause_ms > MAX_ECHO_PAUSE_MS {
+        return Err(CommandError::invalid(format!("pause_ms must be in [0, {MAX_ECHO_PAUSE_MS}]")));
+    }
+    let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    guard.echo_settings = EchoSettings {
+        source_voice_id: normalize_optional_text(source_voice_id),
+        pause_ms,
+    };
+    Ok(GenericResult {
+        ok: true,
+        message: "Echo settings updated".to_string(),
+    })
 }
 
-#[cfg(not(any(target_os = "windows", target_os = "macos")))]
-fn get_foreground_window_title() -> Option<String> {
-    None
+#[tauri::command]
+fn set_dialogue_settings(
+    state: State<'_, SharedState>,
+    enabled: bool,
+    narration_voice_id: Option<String>,
+    dialogue_voice_id: Option<String>,
+) -> Result<GenericResult, CommandError> {
+    let settings = DialogueSettings {
+        enabled,
+        narration_voice_id: normalize_optional_text(narration_voice_id),
+        dialogue_voice_id: normalize_optional_text(dialogue_voice_id),
+    };
+    settings.validate().map_err(to_invalid_error)?;
+    let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    guard.dialogue_settings = settings;
+    let message = if enabled {
+        "Dialogue mode enabled".to_string()
+    } else {
+        "Dialogue mode disabled".to_string()
+    };
+    Ok(GenericResult { ok: true, message })
 }
 
-async fn speak_and_stream(
-    app: &AppHandle,
-    state: &Arc<Mutex<EngineState>>,
-    text: String,
-    source: &str,
-    source_window: &str,
-) -> Result<String> {
-    let trimmed = text.trim().to_string();
-    if trimmed.is_empty() {
-        return Err(anyhow!("Speak text cannot be empty"));
+/// Opt-in: reads the selection of the focused app each time it changes,
+/// using the accessibility API rather than a simulated copy.
+#[tauri::command]
+fn set_follow_selection(app: AppHandle, state: State<'_, SharedState>, enabled: bool) -> Result<GenericResult, CommandError> {
+    if enabled && !cfg!(any(target_os = "windows", target_os = "macos")) {
+        return Err(CommandError::unsupported("Follow selection needs accessibility selection support, available on Windows and macOS"));
+    }
+    let generation = {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.follow_selection = enabled;
+        guard.follow_selection_generation += 1;
+        guard.follow_selection_generation
+    };
+    if enabled {
+        spawn_follow_selection_watcher(app, state.inner.clone(), generation);
     }
-
-    let (voice_id, selected_model, settings) = {
-        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-        (guard.selected_voice_id.clone(), guard.selected_model.clone(), guard.speak_settings.clone())
+    let message = if enabled {
+        "Follow selection enabled"
+    } else {
+        "Follow selection disabled"
     };
+    Ok(GenericResult {
+        ok: true,
+        message: message.to_string(),
+    })
+}
 
-    if selected_model != MODEL_CUSTOM && selected_model != MODEL_KYUTAI {
-        return Err(anyhow!(
-            "Current model mode ({selected_model}) is not enabled for read-aloud yet. Switch to qwen_custom_voice or kyutai_pocket_tts."
-        ));
-    }
+#[tauri::command]
+fn get_engine_env_overrides(app: AppHandle) -> Result<EngineEnvOverrides, CommandError> {
+    Ok(load_app_settings(&app)
+        .and_then(|settings| settings.engine_env_overrides)
+        .unwrap_or_default())
+}
 
+#[tauri::command]
+fn set_engine_env_overrides(app: AppHandle, overrides: EngineEnvOverrides) -> Result<GenericResult, CommandError> {
     #[cfg(feature = "build-base")]
     {
-        if selected_model != MODEL_KYUTAI {
-            return Err(anyhow!(
-                "Base build supports Kyutai Pocket TTS only. Switch model to kyutai_pocket_tts."
-            ));
-        }
-
-        let local_runtime = {
-            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-            guard
-                .local_kyutai
-                .clone()
-                .ok_or_else(|| anyhow!("Kyutai Rust runtime is not initialized"))?
-        };
-        let selected_preset = {
-            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-            guard.selected_kyutai_voice.clone()
-        };
+        let _ = (&app, &overrides);
+        return Err(CommandError::unsupported("Engine environment overrides apply to the Python sidecar, which the base build does not use"));
+    }
 
-        let previous_cancel = {
-            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-            guard.active_cancel_flag.clone()
+    #[cfg(feature = "build-full")]
+    {
+        let overrides = EngineEnvOverrides {
+            synth_backend: normalize_optional_text(overrides.synth_backend).map(|value| value.to_ascii_lowercase()),
+            qwen_attn_implementation: normalize_optional_text(overrides.qwen_attn_implementation),
+            qwen_speaker: normalize_optional_text(overrides.qwen_speaker),
+            qwen_device_map: normalize_optional_text(overrides.qwen_device_map),
+            qwen_dtype: normalize_optional_text(overrides.qwen_dtype),
         };
-        if let Some(flag) = previous_cancel {
-            flag.store(true, Ordering::SeqCst);
+        if let Some(backend) = overrides.synth_backend.as_deref() {
+            if !ENGINE_SYNTH_BACKENDS.contains(&backend) {
+                return Err(CommandError::invalid(format!("synth_backend must be one of: {}", ENGINE_SYNTH_BACKENDS.join(", "))));
+            }
         }
-
-        let job_id = Uuid::new_v4().to_string();
-        let cancel_flag = Arc::new(AtomicBool::new(false));
-        let active_rate_steps = Arc::new(AtomicU32::new(rate_to_steps(settings.rate)));
-        {
-            let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-            guard.last_job_id = Some(job_id.clone());
-            guard.active_cancel_flag = Some(cancel_flag.clone());
-            guard.active_rate_steps = Some(active_rate_steps.clone());
-            guard.suppressed_job_ids.remove(&job_id);
-            if guard.suppressed_job_ids.len() > 128 {
-                guard.suppressed_job_ids.clear();
+        if let Some(attn) = overrides.qwen_attn_implementation.as_deref() {
+            if !QWEN_ATTN_IMPLEMENTATIONS.contains(&attn) {
+                return Err(CommandError::invalid(format!(
+                    "qwen_attn_implementation must be one of: {}",
+                    QWEN_ATTN_IMPLEMENTATIONS.join(", ")
+                )));
+            }
+        }
+        if let Some(speaker) = overrides.qwen_speaker.as_deref() {
+            if !QWEN_SPEAKER_PRESETS.iter().any(|row| row.id == speaker) {
+                return Err(CommandError::invalid(format!("Unknown Qwen speaker: {speaker}")));
+            }
+        }
+        if let Some(dtype) = overrides.qwen_dtype.as_deref() {
+            if !QWEN_DTYPES.contains(&dtype) {
+                return Err(CommandError::invalid(format!("qwen_dtype must be one of: {}", QWEN_DTYPES.join(", "))));
             }
         }
 
-        let _ = app.emit_all(
-            "voicereader:job-started",
-            JobStartedPayload {
-                job_id: job_id.clone(),
-                ws_url: format!("local://stream/{job_id}"),
-                source: source.to_string(),
-                source_window: source_window.to_string(),
-                rate: settings.rate,
-            },
-        );
-
-        let app_clone = app.clone();
-        let state_clone = state.clone();
-        let job_id_clone = job_id.clone();
-        tauri::async_runtime::spawn(async move {
-            let stream_result: Result<()> = (|| {
-                let mut runtime = local_runtime
-                    .lock()
-                    .map_err(|_| anyhow!("Kyutai runtime lock poisoned"))?;
-                let _ = app_clone.emit_all(
-                    "voicereader:ws-event",
-                    json!({
-                        "type": "JOB_STARTED",
-                        "job_id": job_id_clone.clone(),
-                    }),
-                );
+        persist_app_settings(&app, |settings| {
+            settings.engine_env_overrides = Some(overrides);
+        })
+        .map_err(to_cmd_error)?;
 
-                // had_audio is now returned from stream_synthesize rather than
-                // captured via &mut in the closure, so the closure is Fn + Send + 'static.
-                // The 'static bound requires the closure to own its captures, so we move
-                // dedicated clones in rather than borrowing the outer locals.
-                let app_for_chunk = app_clone.clone();
-                let job_id_for_chunk = job_id_clone.clone();
-                let (stream_end, had_audio) = runtime.stream_synthesize(
-                    &voice_id,
-                    &selected_preset,
-                    &trimmed,
-                    settings.chunk_max_chars,
-                    settings.volume,
-                    &cancel_flag,
-                    &active_rate_steps,
-                    move |chunk_index, pcm, sample_rate| {
-                        let mut bytes = Vec::with_capacity(pcm.len() * 2);
-                        for sample in pcm {
-                            bytes.extend_from_slice(&sample.to_le_bytes());
-                        }
-                        let payload = json!({
-                            "type": "AUDIO_CHUNK",
-                            "job_id": job_id_for_chunk.clone(),
-                            "chunk_index": chunk_index,
-                            "audio": {
-                                "format": "pcm_s16le",
-                                "sample_rate": sample_rate,
-                                "channels": 1,
-                                "data_base64": BASE64_STANDARD.encode(&bytes),
-                            }
-                        });
-                        let _ = app_for_chunk.emit_all("voicereader:ws-event", payload);
-                        Ok(())
-                    },
-                )?;
+        Ok(GenericResult {
+            ok: true,
+            message: "Engine overrides saved. Restart the engine to apply them.".to_string(),
+        })
+    }
 
-                let terminal = match stream_end {
-                    LocalJobEndState::Done => "JOB_DONE",
-                    LocalJobEndState::Canceled => "JOB_CANCELED",
-                };
+    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
+    {
+        let _ = (&app, &overrides);
+        Err(CommandError::unsupported("Unsupported build variant for engine overrides"))
+    }
+}
 
-                let _ = app_clone.emit_all(
-                    "voicereader:ws-event",
-                    json!({
-                        "type": terminal,
-                        "job_id": job_id_clone.clone(),
-                        "had_audio": had_audio,
-                    }),
-                );
-                Ok(())
-            })();
+#[tauri::command]
+fn get_engine_profiles(app: AppHandle) -> Result<EngineProfiles, CommandError> {
+    Ok(load_app_settings(&app)
+        .and_then(|settings| settings.engine_profiles)
+        .unwrap_or_default())
+}
 
-            if let Err(err) = stream_result {
-                let _ = app_clone.emit_all(
-                    "voicereader:ws-event",
-                    json!({
-                        "type": "JOB_ERROR",
-                        "job_id": job_id_clone.clone(),
-                        "error": err.to_string(),
-                    }),
-                );
-                emit_error(&app_clone, &format!("Local Kyutai stream failed: {err:#}"));
-            }
+/// Replaces the profile list. The active profile stays active when a profile
+/// of that name is still listed; the running engine is not restarted.
+#[tauri::command]
+fn set_engine_profiles(app: AppHandle, profiles: Vec<EngineProfile>) -> Result<GenericResult, CommandError> {
+    #[cfg(feature = "build-base")]
+    {
+        let _ = (&app, &profiles);
+        Err(CommandError::unsupported("Engine profiles configure the Python sidecar, which the base build does not use"))
+    }
 
-            if let Ok(mut guard) = state_clone.lock() {
-                if guard.last_job_id.as_deref() == Some(job_id_clone.as_str()) {
-                    guard.last_job_id = None;
-                }
-                guard.active_cancel_flag = None;
-                guard.active_rate_steps = None;
-                guard.suppressed_job_ids.remove(&job_id_clone);
-            }
-        });
+    #[cfg(feature = "build-full")]
+    {
+    let previous = load_app_settings(&app)
+        .and_then(|settings| settings.engine_profiles)
+        .unwrap_or_default();
+    let mut next = EngineProfiles {
+        profiles: profiles.into_iter().map(EngineProfile::normalized).collect(),
+        active: None,
+    };
+    next.active = previous.active.filter(|name| next.find(name).is_some());
+    next.validate(&ENGINE_SYNTH_BACKENDS).map_err(to_invalid_error)?;
+    let count = next.profiles.len();
+    persist_app_settings(&app, |settings| {
+        settings.engine_profiles = Some(next);
+    })
+    .map_err(to_cmd_error)?;
 
-        return Ok(job_id);
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Saved {count} engine profile(s)"),
+    })
+    }
+
+    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
+    {
+        let _ = (&app, &profiles);
+        Err(CommandError::unsupported("Unsupported build variant for engine profiles"))
+    }
+}
+
+/// Makes `name` the profile the engine launches with, or none, and restarts a
+/// running engine so the switch takes effect at once.
+#[tauri::command]
+async fn set_active_engine_profile(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    name: Option<String>,
+) -> Result<GenericResult, CommandError> {
+    #[cfg(feature = "build-base")]
+    {
+        let _ = (&app, &state, &name);
+        Err(CommandError::unsupported("Engine profiles configure the Python sidecar, which the base build does not use"))
     }
 
     #[cfg(feature = "build-full")]
-    let (base_url, token) = {
-        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-        (guard.base_url.clone(), guard.token.clone())
+    {
+    let mut profiles = load_app_settings(&app)
+        .and_then(|settings| settings.engine_profiles)
+        .unwrap_or_default();
+    profiles.active = normalize_optional_text(name);
+    profiles.validate(&ENGINE_SYNTH_BACKENDS).map_err(to_invalid_error)?;
+    let active = profiles.active.clone();
+    persist_app_settings(&app, |settings| {
+        settings.engine_profiles = Some(profiles);
+    })
+    .map_err(to_cmd_error)?;
+
+    let running = {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        runtime_snapshot(&mut guard).0
     };
+    if running {
+        restart_engine_inner(&app, &state.inner).await.map_err(to_cmd_error)?;
+    }
+    let label = match active.as_deref() {
+        Some(name) => format!("Engine profile {name}"),
+        None => "No engine profile".to_string(),
+    };
+    Ok(GenericResult {
+        ok: true,
+        message: if running {
+            format!("{label} is active; the engine restarted with it")
+        } else {
+            format!("{label} is active from the next engine start")
+        },
+    })
+    }
+
+    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
+    {
+        let _ = (&app, &state, &name);
+        Err(CommandError::unsupported("Unsupported build variant for engine profiles"))
+    }
+}
+
+#[tauri::command]
+fn get_engine_resource_limits(app: AppHandle) -> Result<EngineResourceLimits, CommandError> {
+    Ok(load_app_settings(&app)
+        .and_then(|settings| settings.engine_resource_limits)
+        .unwrap_or_default())
+}
+
+#[tauri::command]
+fn set_engine_resource_limits(app: AppHandle, limits: EngineResourceLimits) -> Result<GenericResult, CommandError> {
+    #[cfg(feature = "build-base")]
+    {
+        let _ = (&app, &limits);
+        return Err(CommandError::unsupported("Engine resource limits apply to the Python sidecar, which the base build does not use"));
+    }
 
     #[cfg(feature = "build-full")]
     {
-    let speak_body = json!({
-        "voice_id": voice_id,
-        "text": trimmed,
-        "settings": {
-            "rate": settings.rate,
-            "volume": settings.volume,
-            "chunking": {
-                "max_chars": settings.chunk_max_chars,
-            }
+        limits.validate().map_err(to_invalid_error)?;
+        persist_app_settings(&app, |settings| {
+            settings.engine_resource_limits = Some(limits);
+        })
+        .map_err(to_cmd_error)?;
+
+        Ok(GenericResult {
+            ok: true,
+            message: "Engine resource limits saved. Restart the engine to apply them.".to_string(),
+        })
+    }
+
+    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
+    {
+        let _ = (&app, &limits);
+        Err(CommandError::unsupported("Unsupported build variant for engine resource limits"))
+    }
+}
+
+#[tauri::command]
+fn cycle_speak_rate(app: AppHandle, state: State<'_, SharedState>) -> Result<SpeakRateResult, CommandError> {
+    let next_rate = {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        let current_steps = (clamp_speak_rate(guard.speak_settings.rate) * 4.0).round() as i32;
+        let clamped_steps = current_steps.clamp(1, 16);
+        let next_steps = if clamped_steps >= 16 { 1 } else { clamped_steps + 1 };
+        let next_rate = next_steps as f32 / 4.0;
+        guard.speak_settings.rate = next_rate;
+        #[cfg(feature = "build-base")]
+        if let Some(active_steps) = guard.active_rate_steps.as_ref() {
+            active_steps.store(next_steps as u32, Ordering::SeqCst);
         }
-    });
+        next_rate
+    };
+    persist_speak_state(&app, &state.inner);
 
-    let speak_payload = request_json(
-        Method::POST,
-        &format!("{base_url}/v1/speak"),
-        &token,
-        Some(speak_body),
-    )
-    .await?;
+    let _ = app.emit_routed(
+        "voicereader:rate-updated",
+        RateUpdatedPayload { rate: next_rate },
+    );
 
-    let speak_response: SpeakHttpResponse = serde_json::from_value(speak_payload)
-        .context("Invalid /v1/speak response shape")?;
+    #[cfg(feature = "build-full")]
+    {
+        let state_clone = state.inner.clone();
+        tauri::async_runtime::spawn(async move {
+            let playback = json!({ "rate": clamp_speak_rate(next_rate) });
+            if let Err(err) = update_active_job_playback_full(&state_clone, playback).await {
+                eprintln!("Active job rate update failed: {err:#}");
+            }
+        });
+    }
+
+    Ok(SpeakRateResult {
+        ok: true,
+        message: format!("Playback rate set to {:.2}x", next_rate),
+        rate: next_rate,
+    })
+}
+
+/// Rebinds the read-selection hotkey; `set_hotkey_binding` with `read_selection`.
+#[tauri::command]
+fn set_hotkey(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    hotkey: String,
+) -> Result<HotkeyResult, CommandError> {
+    let result = set_hotkey_binding(app, state, BindableAction::ReadSelection, Some(hotkey))?;
+    Ok(HotkeyResult {
+        ok: result.ok,
+        message: result.message,
+        hotkey: result.hotkey.unwrap_or_default(),
+    })
+}
+
+/// Binds `accelerator` to `action`, or clears the binding when it is `None`.
+/// Read-selection cannot be cleared, and rebinding it moves its
+/// queue-selection variant along.
+#[tauri::command]
+fn set_hotkey_binding(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    action: BindableAction,
+    accelerator: Option<String>,
+) -> Result<OptionalHotkeyResult, CommandError> {
+    let normalized = match normalize_optional_text(accelerator) {
+        Some(raw) => Some(normalize_hotkey(&raw).map_err(to_cmd_error)?),
+        None if action == BindableAction::ReadSelection => {
+            return Err(CommandError::invalid("The read-selection hotkey cannot be cleared"));
+        }
+        None => None,
+    };
+    if let Some(candidate) = normalized.as_deref() {
+        if is_hotkey_os_reserved(candidate) {
+            return Err(CommandError::new(
+                ErrorCode::HotkeyConflict,
+                "Alt+Space (Windows) and Cmd+Space (macOS) are OS-reserved. Use another hotkey.",
+            ));
+        }
+    }
 
+    let (hotkeys, orientation_hotkey, read_window_hotkey, summary_hotkey) = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        (
+            guard.hotkeys.clone(),
+            guard.orientation_hotkey.clone(),
+            guard.read_window_hotkey.clone(),
+            guard.summary_hotkey.clone(),
+        )
+    };
+    let previous = hotkeys.get(action).map(str::to_string);
+    if let Some(bound) = normalized
+        .as_deref()
+        .and_then(|candidate| hotkeys.action_for(candidate))
+        .filter(|bound| *bound != action)
     {
-        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-        guard.last_job_id = Some(speak_response.job_id.clone());
-        guard.suppressed_job_ids.remove(&speak_response.job_id);
-        if guard.suppressed_job_ids.len() > 128 {
-            guard.suppressed_job_ids.clear();
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, format!("Hotkey is already bound to the {} action", bound.label())));
+    }
+    if action != BindableAction::ReadSelection
+        && normalized.is_some()
+        && normalized == queue_hotkey_variant(hotkeys.read_selection())
+    {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, "Hotkey is already bound to queue-selection"));
+    }
+    if normalized.is_some() && normalized == orientation_hotkey {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, "Hotkey is already bound to the orientation action"));
+    }
+    if normalized.is_some() && normalized == read_window_hotkey {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, "Hotkey is already bound to the read-window action"));
+    }
+    if normalized.is_some() && normalized == summary_hotkey {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, "Hotkey is already bound to the summary action"));
+    }
+    if let Some(label) = normalized.as_deref().and_then(|candidate| phrase_bound_to(&app, candidate)) {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, format!("Hotkey is already bound to the phrase \"{label}\"")));
+    }
+    if normalized == previous {
+        return Ok(OptionalHotkeyResult {
+            ok: true,
+            message: format!("Hotkey for {} unchanged", action.label()),
+            hotkey: normalized,
+        });
+    }
+
+    let mut manager = app.global_shortcut_manager();
+    if let Some(previous_hotkey) = previous.as_deref() {
+        let _ = manager.unregister(previous_hotkey);
+        if action == BindableAction::ReadSelection {
+            if let Some(previous_variant) = queue_hotkey_variant(previous_hotkey) {
+                let _ = manager.unregister(&previous_variant);
+            }
         }
     }
 
-    let _ = app.emit_all(
-        "voicereader:job-started",
-        JobStartedPayload {
-            job_id: speak_response.job_id.clone(),
-            ws_url: speak_response.ws_url.clone(),
-            source: source.to_string(),
-            source_window: source_window.to_string(),
-            rate: settings.rate,
-        },
-    );
+    if let Some(candidate) = normalized.as_deref() {
+        if let Err(err) = register_hotkey_binding(&app, state.inner.clone(), candidate, action.into()) {
+            if let Some(previous_hotkey) = previous.as_deref() {
+                let _ = register_hotkey_binding(&app, state.inner.clone(), previous_hotkey, action.into());
+            }
+            if action == BindableAction::ReadSelection {
+                let _ = register_queue_hotkey(&app, state.inner.clone());
+            }
+            return Err(to_cmd_error(err.context(format!("Failed to register {} hotkey", action.label()))));
+        }
+    }
 
-    let app_clone = app.clone();
-    let state_clone = state.clone();
-    let token_clone = token.clone();
-    let ws_url = speak_response.ws_url.clone();
-    let job_id = speak_response.job_id.clone();
-    tauri::async_runtime::spawn(async move {
-        if let Err(err) = relay_ws_events(&app_clone, &state_clone, &ws_url, &token_clone, &job_id).await {
-            emit_error(&app_clone, &format!("WS relay failed: {err:#}"));
+    let hotkeys = {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.hotkeys.set(action, normalized.clone());
+        if action == BindableAction::ReadSelection {
+            guard.registered_hotkey = normalized.clone();
         }
-    });
+        guard.hotkeys.clone()
+    };
+    if action == BindableAction::ReadSelection {
+        // The read hotkey is bound either way; a missing queue variant is only reported.
+        if let Err(err) = register_queue_hotkey(&app, state.inner.clone()) {
+            emit_error(&app, &format!("Queue-selection hotkey not registered: {err:#}"));
+        }
+    }
 
-    Ok(speak_response.job_id)
+    if let Err(err) = persist_hotkeys(&app, &hotkeys) {
+        emit_error(&app, &format!("Hotkey set but could not persist settings: {err:#}"));
+    }
+
+    if action == BindableAction::ReadSelection {
+        let _ = app.emit_routed(
+            "voicereader:hotkey-updated",
+            HotkeyUpdatedPayload {
+                hotkey: hotkeys.read_selection().to_string(),
+            },
+        );
+    }
+
+    let message = match normalized.as_deref() {
+        Some(bound) => format!("Hotkey for {} updated to {bound}", action.label()),
+        None => format!("Hotkey for {} cleared", action.label()),
+    };
+    Ok(OptionalHotkeyResult {
+        ok: true,
+        message,
+        hotkey: normalized,
+    })
+}
+
+/// Suspends or restores every global hotkey (read, queue, action,
+/// orientation and phrases) without changing the saved bindings. Not persisted: hotkeys are
+/// enabled again on the next launch.
+#[tauri::command]
+fn set_hotkey_enabled(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    enabled: bool,
+) -> Result<GenericResult, CommandError> {
+    let hotkey = {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        if guard.hotkeys_enabled == enabled {
+            return Ok(GenericResult {
+                ok: true,
+                message: format!("Global hotkeys already {}", if enabled { "enabled" } else { "disabled" }),
+            });
+        }
+        guard.hotkeys_enabled = enabled;
+        guard.hotkeys.read_selection().to_string()
+    };
+
+    let mut failures = Vec::new();
+    if enabled {
+        if let Err(err) = register_hotkey_binding(&app, state.inner.clone(), &hotkey, HotkeyAction::ReadSelection) {
+            failures.push(format!("{err:#}"));
+        }
+        let registrations = [
+            register_queue_hotkey(&app, state.inner.clone()),
+            register_orientation_hotkey(&app, state.inner.clone()),
+            register_read_window_hotkey(&app, state.inner.clone()),
+            register_summary_hotkey(&app, state.inner.clone()),
+            register_action_hotkeys(&app, state.inner.clone()),
+            register_phrase_hotkeys(&app, state.inner.clone()),
+        ];
+        failures.extend(registrations.into_iter().filter_map(|result| result.err().map(|err| format!("{err:#}"))));
+    } else if let Err(err) = app.global_shortcut_manager().unregister_all() {
+        failures.push(format!("Failed to unregister global hotkeys: {err}"));
+    }
+
+    let _ = app.emit_routed("voicereader:hotkeys-enabled-changed", HotkeysEnabledPayload { enabled });
+    if !failures.is_empty() {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, failures.join("; ")));
+    }
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Global hotkeys {}", if enabled { "enabled" } else { "disabled" }),
+    })
+}
+
+/// Sets the hotkeys tried, in order, when the read hotkey cannot be
+/// registered at startup. An empty list disables the fallback.
+#[tauri::command]
+fn set_fallback_hotkeys(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    hotkeys: Vec<String>,
+) -> Result<FallbackHotkeysResult, CommandError> {
+    let mut normalized: Vec<String> = Vec::with_capacity(hotkeys.len());
+    for raw in hotkeys {
+        let Some(raw) = normalize_optional_text(Some(raw)) else {
+            continue;
+        };
+        let candidate = normalize_hotkey(&raw).map_err(to_cmd_error)?;
+        if is_hotkey_os_reserved(&candidate) {
+            return Err(CommandError::new(ErrorCode::HotkeyConflict, format!("{candidate} is OS-reserved and cannot be a fallback hotkey")));
+        }
+        if !normalized.contains(&candidate) {
+            normalized.push(candidate);
+        }
     }
 
-    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
     {
-        Err(anyhow!("Unsupported build variant for speak and stream"))
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.fallback_hotkeys = normalized.clone();
     }
+    if let Err(err) = persist_fallback_hotkeys(&app, &normalized) {
+        emit_error(&app, &format!("Fallback hotkeys set but could not persist settings: {err:#}"));
+    }
+
+    let message = if normalized.is_empty() {
+        "Fallback hotkeys cleared".to_string()
+    } else {
+        format!("Fallback hotkeys set to {}", normalized.join(", "))
+    };
+    Ok(FallbackHotkeysResult {
+        ok: true,
+        message,
+        fallback_hotkeys: normalized,
+    })
+}
+
+#[tauri::command]
+fn set_orientation_hotkey(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    hotkey: Option<String>,
+) -> Result<OptionalHotkeyResult, CommandError> {
+    let normalized = match normalize_optional_text(hotkey) {
+        Some(raw) => Some(normalize_hotkey(&raw).map_err(to_cmd_error)?),
+        None => None,
+    };
+    if let Some(candidate) = normalized.as_deref() {
+        if is_hotkey_os_reserved(candidate) {
+            return Err(CommandError::new(
+                ErrorCode::HotkeyConflict,
+                "Alt+Space (Windows) and Cmd+Space (macOS) are OS-reserved. Use another hotkey.",
+            ));
+        }
+    }
+
+    let (previous, hotkeys, read_window_hotkey, summary_hotkey) = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        (
+            guard.orientation_hotkey.clone(),
+            guard.hotkeys.clone(),
+            guard.read_window_hotkey.clone(),
+            guard.summary_hotkey.clone(),
+        )
+    };
+    if let Some(bound) = normalized.as_deref().and_then(|candidate| hotkeys.action_for(candidate)) {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, format!("Hotkey is already bound to the {} action", bound.label())));
+    }
+    if normalized.is_some() && normalized == read_window_hotkey {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, "Hotkey is already bound to the read-window action"));
+    }
+    if normalized.is_some() && normalized == summary_hotkey {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, "Hotkey is already bound to the summary action"));
+    }
+    if normalized.is_some() && normalized == queue_hotkey_variant(hotkeys.read_selection()) {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, "Hotkey is already bound to queue-selection"));
+    }
+    if let Some(label) = normalized.as_deref().and_then(|candidate| phrase_bound_to(&app, candidate)) {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, format!("Hotkey is already bound to the phrase \"{label}\"")));
+    }
+    if normalized == previous {
+        return Ok(OptionalHotkeyResult {
+            ok: true,
+            message: "Orientation hotkey unchanged".to_string(),
+            hotkey: normalized,
+        });
+    }
+
+    let mut manager = app.global_shortcut_manager();
+    if let Some(previous_hotkey) = previous.as_deref() {
+        let _ = manager.unregister(previous_hotkey);
+    }
+
+    if let Some(candidate) = normalized.as_deref() {
+        if let Err(err) = register_hotkey_binding(&app, state.inner.clone(), candidate, HotkeyAction::AnnounceFocus) {
+            if let Some(previous_hotkey) = previous.as_deref() {
+                let _ = register_hotkey_binding(&app, state.inner.clone(), previous_hotkey, HotkeyAction::AnnounceFocus);
+            }
+            return Err(to_cmd_error(err.context("Failed to register orientation hotkey")));
+        }
+    }
+
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.orientation_hotkey = normalized.clone();
+    }
+
+    if let Err(err) = persist_orientation_hotkey(&app, normalized.as_deref()) {
+        emit_error(&app, &format!("Orientation hotkey set but could not persist settings: {err:#}"));
+    }
+
+    let message = match normalized.as_deref() {
+        Some(bound) => format!("Orientation hotkey updated to {bound}"),
+        None => "Orientation hotkey cleared".to_string(),
+    };
+    Ok(OptionalHotkeyResult {
+        ok: true,
+        message,
+        hotkey: normalized,
+    })
+}
+
+#[tauri::command]
+fn set_read_window_hotkey(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    hotkey: Option<String>,
+) -> Result<OptionalHotkeyResult, CommandError> {
+    let normalized = match normalize_optional_text(hotkey) {
+        Some(raw) => Some(normalize_hotkey(&raw).map_err(to_cmd_error)?),
+        None => None,
+    };
+    if let Some(candidate) = normalized.as_deref() {
+        if is_hotkey_os_reserved(candidate) {
+            return Err(CommandError::new(
+                ErrorCode::HotkeyConflict,
+                "Alt+Space (Windows) and Cmd+Space (macOS) are OS-reserved. Use another hotkey.",
+            ));
+        }
+    }
+
+    let (previous, hotkeys, orientation_hotkey, summary_hotkey) = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        (
+            guard.read_window_hotkey.clone(),
+            guard.hotkeys.clone(),
+            guard.orientation_hotkey.clone(),
+            guard.summary_hotkey.clone(),
+        )
+    };
+    if let Some(bound) = normalized.as_deref().and_then(|candidate| hotkeys.action_for(candidate)) {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, format!("Hotkey is already bound to the {} action", bound.label())));
+    }
+    if normalized.is_some() && normalized == queue_hotkey_variant(hotkeys.read_selection()) {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, "Hotkey is already bound to queue-selection"));
+    }
+    if normalized.is_some() && normalized == orientation_hotkey {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, "Hotkey is already bound to the orientation action"));
+    }
+    if normalized.is_some() && normalized == summary_hotkey {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, "Hotkey is already bound to the summary action"));
+    }
+    if let Some(label) = normalized.as_deref().and_then(|candidate| phrase_bound_to(&app, candidate)) {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, format!("Hotkey is already bound to the phrase \"{label}\"")));
+    }
+    if normalized == previous {
+        return Ok(OptionalHotkeyResult {
+            ok: true,
+            message: "Read-window hotkey unchanged".to_string(),
+            hotkey: normalized,
+        });
+    }
+
+    let mut manager = app.global_shortcut_manager();
+    if let Some(previous_hotkey) = previous.as_deref() {
+        let _ = manager.unregister(previous_hotkey);
+    }
+
+    if let Some(candidate) = normalized.as_deref() {
+        if let Err(err) = register_hotkey_binding(&app, state.inner.clone(), candidate, HotkeyAction::ReadWindow) {
+            if let Some(previous_hotkey) = previous.as_deref() {
+                let _ = register_hotkey_binding(&app, state.inner.clone(), previous_hotkey, HotkeyAction::ReadWindow);
+            }
+            return Err(to_cmd_error(err.context("Failed to register read-window hotkey")));
+        }
+    }
+
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.read_window_hotkey = normalized.clone();
+    }
+
+    if let Err(err) = persist_read_window_hotkey(&app, normalized.as_deref()) {
+        emit_error(&app, &format!("Read-window hotkey set but could not persist settings: {err:#}"));
+    }
+
+    let message = match normalized.as_deref() {
+        Some(bound) => format!("Read-window hotkey updated to {bound}"),
+        None => "Read-window hotkey cleared".to_string(),
+    };
+    Ok(OptionalHotkeyResult {
+        ok: true,
+        message,
+        hotkey: normalized,
+    })
+}
+
+#[tauri::command]
+fn set_summary_hotkey(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    hotkey: Option<String>,
+) -> Result<OptionalHotkeyResult, CommandError> {
+    let normalized = match normalize_optional_text(hotkey) {
+        Some(raw) => Some(normalize_hotkey(&raw).map_err(to_cmd_error)?),
+        None => None,
+    };
+    if let Some(candidate) = normalized.as_deref() {
+        if is_hotkey_os_reserved(candidate) {
+            return Err(CommandError::new(
+                ErrorCode::HotkeyConflict,
+                "Alt+Space (Windows) and Cmd+Space (macOS) are OS-reserved. Use another hotkey.",
+            ));
+        }
+    }
+
+    let (previous, hotkeys, orientation_hotkey, read_window_hotkey) = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        (
+            guard.summary_hotkey.clone(),
+            guard.hotkeys.clone(),
+            guard.orientation_hotkey.clone(),
+            guard.read_window_hotkey.clone(),
+        )
+    };
+    if let Some(bound) = normalized.as_deref().and_then(|candidate| hotkeys.action_for(candidate)) {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, format!("Hotkey is already bound to the {} action", bound.label())));
+    }
+    if normalized.is_some() && normalized == queue_hotkey_variant(hotkeys.read_selection()) {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, "Hotkey is already bound to queue-selection"));
+    }
+    if normalized.is_some() && normalized == orientation_hotkey {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, "Hotkey is already bound to the orientation action"));
+    }
+    if normalized.is_some() && normalized == read_window_hotkey {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, "Hotkey is already bound to the read-window action"));
+    }
+    if let Some(label) = normalized.as_deref().and_then(|candidate| phrase_bound_to(&app, candidate)) {
+        return Err(CommandError::new(ErrorCode::HotkeyConflict, format!("Hotkey is already bound to the phrase \"{label}\"")));
+    }
+    if normalized == previous {
+        return Ok(OptionalHotkeyResult {
+            ok: true,
+            message: "Summary hotkey unchanged".to_string(),
+            hotkey: normalized,
+        });
+    }
+
+    let mut manager = app.global_shortcut_manager();
+    if let Some(previous_hotkey) = previous.as_deref() {
+        let _ = manager.unregister(previous_hotkey);
+    }
+
+    if let Some(candidate) = normalized.as_deref() {
+        if let Err(err) = register_hotkey_binding(&app, state.inner.clone(), candidate, HotkeyAction::SummarizeSelection) {
+            if let Some(previous_hotkey) = previous.as_deref() {
+                let _ = register_hotkey_binding(&app, state.inner.clone(), previous_hotkey, HotkeyAction::SummarizeSelection);
+            }
+            return Err(to_cmd_error(err.context("Failed to register summary hotkey")));
+        }
+    }
+
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.summary_hotkey = normalized.clone();
+    }
+
+    if let Err(err) = persist_summary_hotkey(&app, normalized.as_deref()) {
+        emit_error(&app, &format!("Summary hotkey set but could not persist settings: {err:#}"));
+    }
+
+    let message = match normalized.as_deref() {
+        Some(bound) => format!("Summary hotkey updated to {bound}"),
+        None => "Summary hotkey cleared".to_string(),
+    };
+    Ok(OptionalHotkeyResult {
+        ok: true,
+        message,
+        hotkey: normalized,
+    })
+}
+
+#[tauri::command]
+async fn speak_text(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    text: String,
+    overrides: Option<SpeakOverrides>,
+) -> Result<GenericResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let overrides = overrides.unwrap_or_default();
+    let job_id = speak_and_stream_with_overrides(&app, &state.inner, text, "manual", &JobOrigin::direct(), &overrides)
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Speak job started: {job_id}"),
+    })
+}
+
+/// Reads `text` at a pace that follows `schedule` (words per minute from a
+/// given word on), for rehearsing a speech along with playback. The rate is
+/// corrected after every chunk against the schedule computed upfront.
+#[tauri::command]
+async fn speak_teleprompter(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    text: String,
+    schedule: Vec<PaceSegment>,
+) -> Result<GenericResult, CommandError> {
+    let text = text.trim().to_string();
+    let plan = PacingPlan::new(&text, &schedule).map_err(to_cmd_error)?;
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let rate = clamp_speak_rate(plan.rate_at(0, 0.0));
+    let scheduled_secs = plan.total_secs();
+    // Skip translation and reading order so chunk offsets index `text`.
+    let overrides = SpeakOverrides {
+        rate: Some(rate),
+        preprocessed: true,
+        ..SpeakOverrides::default()
+    };
+    let job_id = speak_and_stream_with_overrides(&app, &state.inner, text, "teleprompter", &JobOrigin::direct(), &overrides)
+        .await
+        .map_err(to_cmd_error)?;
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.teleprompter = Some(TeleprompterJob {
+            job_id: job_id.clone(),
+            plan,
+            rate,
+        });
+    }
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Teleprompter job started: {job_id} ({scheduled_secs:.0} s scheduled)"),
+    })
+}
+
+/// Speaks a short utterance in the interactive lane: a normal read that is
+/// playing is interrupted and resumes from its current sentence afterwards.
+#[tauri::command]
+async fn speak_interactive(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    text: String,
+) -> Result<GenericResult, CommandError> {
+    if text.trim().is_empty() {
+        return Err(CommandError::invalid("Speak text cannot be empty"));
+    }
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let started = enqueue_read(
+        &app,
+        &state.inner,
+        QueuedRead {
+            text,
+            priority: ReadPriority::Interactive,
+            source: "manual_interactive".to_string(),
+            origin: JobOrigin::direct(),
+            voice_id: None,
+            rate: None,
+            preprocessed: false,
+            expand_placeholders: false,
+            reading_order: false,
+        },
+    )
+    .await
+    .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: match started {
+            Some(job_id) => format!("Speak job started: {job_id}"),
+            None => "Queued behind the current read".to_string(),
+        },
+    })
+}
+
+#[tauri::command]
+async fn trigger_read_selection(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    read_selection_and_speak_inner(&app, &state.inner)
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: "Read-selection hotkey flow triggered".to_string(),
+    })
+}
+
+#[tauri::command]
+async fn speak_echo_text(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    text: String,
+) -> Result<GenericResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let job_id = speak_echo(&app, &state.inner, text, "manual_echo", &JobOrigin::direct())
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Echo job started: {job_id}"),
+    })
+}
+
+#[tauri::command]
+async fn trigger_echo_selection(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let Some(AcquiredText { text, origin }) = acquire_text(&app, SELECTION_SOURCE, None)
+        .await
+        .map_err(to_cmd_error)?
+    else {
+        return Ok(GenericResult {
+            ok: true,
+            message: "No selection to echo".to_string(),
+        });
+    };
+    let job_id = speak_echo(&app, &state.inner, text, "echo_selection_capture", &origin)
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Echo job started: {job_id}"),
+    })
+}
+
+#[tauri::command]
+async fn practice_sentence(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    text: String,
+) -> Result<GenericResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let job_id = speak_and_stream(&app, &state.inner, text, "practice", &JobOrigin::direct())
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Practice sentence job started: {job_id}"),
+    })
+}
+
+/// Scores a practice attempt, given either its transcript or a WAV recording
+/// of it (`audio_path`), which the read-back recognizer transcribes.
+#[tauri::command]
+async fn score_practice_attempt(
+    state: State<'_, SharedState>,
+    expected: String,
+    transcript: Option<String>,
+    audio_path: Option<String>,
+) -> Result<PracticeScore, CommandError> {
+    if expected.trim().is_empty() {
+        return Err(CommandError::invalid("Expected sentence cannot be empty"));
+    }
+    let audio_path = audio_path.map(|path| path.trim().to_string()).filter(|path| !path.is_empty());
+    let transcript = match (transcript, audio_path) {
+        (Some(transcript), None) => transcript,
+        (None, Some(audio_path)) => {
+            let audio_path = PathBuf::from(audio_path);
+            if !audio_path.is_file() {
+                return Err(CommandError::not_found(format!("Recording not found: {}", audio_path.display())));
+            }
+            let settings = {
+                let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+                guard.readback.clone()
+            };
+            if !settings.is_enabled() {
+                return Err(CommandError::invalid(
+                    "Scoring a recording needs a speech recognizer; set one up in the read-back settings",
+                ));
+            }
+            tauri::async_runtime::spawn_blocking(move || transcribe_recording(&settings, &audio_path))
+                .await
+                .map_err(|err| anyhow!("Recognition task failed: {err}"))
+                .and_then(|result| result)
+                .map_err(to_cmd_error)?
+        }
+        _ => return Err(CommandError::invalid("Give either a transcript or a recording to score")),
+    };
+    Ok(score_attempt(&expected, &transcript))
+}
+
+#[tauri::command]
+async fn trigger_queue_selection(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    queue_selection_inner(&app, &state.inner)
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: "Queue-selection flow triggered".to_string(),
+    })
+}
+
+/// Reads text from a registered source (`clipboard`, `file`, `url`,
+/// `stdin`, `extension`, `selection`, `window_ocr`). `argument` is the path,
+/// URL or pushed text for sources that take one.
+#[tauri::command]
+async fn speak_from_source(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    source: String,
+    argument: Option<String>,
+    overrides: Option<SpeakOverrides>,
+) -> Result<GenericResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let Some(AcquiredText { text, origin }) = acquire_text(&app, &source, argument.as_deref())
+        .await
+        .map_err(to_cmd_error)?
+    else {
+        return Ok(GenericResult {
+            ok: true,
+            message: format!("Nothing to read from {source}"),
+        });
+    };
+    let overrides = overrides.unwrap_or_default();
+    let job_id = speak_and_stream_with_overrides(&app, &state.inner, text, &source, &origin, &overrides)
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Speak job started: {job_id}"),
+    })
+}
+
+/// Downloads `url`, keeps the main article and reads it, title first.
+#[tauri::command]
+async fn speak_url(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    url: String,
+    overrides: Option<SpeakOverrides>,
+) -> Result<GenericResult, CommandError> {
+    speak_from_source(app, state, URL_SOURCE.to_string(), Some(url), overrides).await
+}
+
+#[tauri::command]
+fn list_text_sources(registry: State<'_, TextSourceRegistry>) -> Vec<&'static str> {
+    registry.names()
+}
+
+#[tauri::command]
+async fn trigger_summarize_selection(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    summarize_selection_inner(&app, &state.inner)
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: "Summarize-selection flow triggered".to_string(),
+    })
+}
+
+#[tauri::command]
+async fn trigger_read_window(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    read_window_inner(&app, &state.inner)
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: "Read-window flow triggered".to_string(),
+    })
+}
+
+/// Speaks the text on the clipboard without simulating a copy, so nothing
+/// is sent to the focused app.
+#[tauri::command]
+async fn read_clipboard(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, CommandError> {
+    read_clipboard_inner(&app, &state.inner)
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: "Read-clipboard flow triggered".to_string(),
+    })
+}
+
+#[tauri::command]
+async fn trigger_skim_selection(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    skim_selection_and_speak_inner(&app, &state.inner)
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: "Skim-selection flow triggered".to_string(),
+    })
+}
+
+#[tauri::command]
+async fn trigger_announce_focus(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, CommandError> {
+    announce_focus_inner(&app, &state.inner)
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: "Focus summary spoken".to_string(),
+    })
+}
+
+#[tauri::command]
+fn list_phrases(app: AppHandle) -> Result<Vec<QuickPhrase>, CommandError> {
+    let store = load_phrases(&app).map_err(to_cmd_error)?;
+    Ok(store.phrases().to_vec())
+}
+
+/// Saves a new phrase (empty id) or replaces an existing one, rebinding its
+/// hotkey when that changed.
+#[tauri::command]
+fn save_phrase(app: AppHandle, state: State<'_, SharedState>, phrase: QuickPhrase) -> Result<QuickPhrase, CommandError> {
+    let mut phrase = phrase;
+    phrase.validate().map_err(to_invalid_error)?;
+    phrase.label = phrase.label.trim().to_string();
+    phrase.text = phrase.text.trim().to_string();
+    phrase.voice_id = normalize_optional_text(phrase.voice_id);
+    phrase.hotkey = match normalize_optional_text(phrase.hotkey) {
+        Some(raw) => Some(normalize_hotkey(&raw).map_err(to_cmd_error)?),
+        None => None,
+    };
+    if phrase.id.trim().is_empty() {
+        phrase.id = format!("phrase-{:08x}", rand::random::<u32>());
+    }
+
+    let mut store = load_phrases(&app).map_err(to_cmd_error)?;
+    let previous_hotkey = store.get(&phrase.id).and_then(|existing| existing.hotkey.clone());
+    if let Some(hotkey) = phrase.hotkey.as_deref().filter(|hotkey| previous_hotkey.as_deref() != Some(*hotkey)) {
+        if is_hotkey_os_reserved(hotkey) {
+            return Err(CommandError::new(ErrorCode::HotkeyConflict, "Alt+Space (Windows) and Cmd+Space (macOS) are OS-reserved. Use another hotkey."));
+        }
+        let (hotkeys, orientation_hotkey, read_window_hotkey, summary_hotkey) = {
+            let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+            (
+                guard.hotkeys.clone(),
+                guard.orientation_hotkey.clone(),
+                guard.read_window_hotkey.clone(),
+                guard.summary_hotkey.clone(),
+            )
+        };
+        let taken = hotkeys.action_for(hotkey).is_some()
+            || Some(hotkey) == queue_hotkey_variant(hotkeys.read_selection()).as_deref()
+            || Some(hotkey) == orientation_hotkey.as_deref()
+            || Some(hotkey) == read_window_hotkey.as_deref()
+            || Some(hotkey) == summary_hotkey.as_deref()
+            || phrase_bound_to(&app, hotkey).is_some();
+        if taken {
+            return Err(CommandError::new(ErrorCode::HotkeyConflict, format!("Hotkey {hotkey} is already in use")));
+        }
+    }
+
+    if phrase.hotkey != previous_hotkey {
+        let mut manager = app.global_shortcut_manager();
+        if let Some(previous) = previous_hotkey.as_deref() {
+            let _ = manager.unregister(previous);
+        }
+        if let Some(hotkey) = phrase.hotkey.as_deref() {
+            let action = HotkeyAction::SpeakPhrase(phrase.id.clone());
+            if let Err(err) = register_hotkey_binding(&app, state.inner.clone(), hotkey, action) {
+                if let Some(previous) = previous_hotkey.as_deref() {
+                    let action = HotkeyAction::SpeakPhrase(phrase.id.clone());
+                    let _ = register_hotkey_binding(&app, state.inner.clone(), previous, action);
+                }
+                return Err(to_cmd_error(err.context("Failed to register phrase hotkey")));
+            }
+        }
+    }
+
+    store.upsert(phrase.clone());
+    let path = phrases_path(&app).ok_or_else(|| CommandError::internal("Unable to resolve phrases path"))?;
+    store.save(&path).map_err(to_cmd_error)?;
+    Ok(phrase)
+}
+
+#[tauri::command]
+fn list_feeds(app: AppHandle) -> Result<Vec<FeedSubscription>, CommandError> {
+    let store = load_feeds(&app).map_err(to_cmd_error)?;
+    Ok(store.feeds().to_vec())
+}
+
+/// Subscribes to an RSS or Atom feed and loads its current entries as unread.
+#[tauri::command]
+async fn subscribe_feed(app: AppHandle, url: String) -> Result<FeedSubscription, CommandError> {
+    let url = url.trim().to_string();
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return Err(CommandError::invalid("Feed URL must start with http:// or https://"));
+    }
+    if load_feeds(&app).map_err(to_cmd_error)?.feed_by_url(&url).is_some() {
+        return Err(CommandError::invalid(format!("Already subscribed to {url}")));
+    }
+    let parsed = fetch_feed(&url).await.map_err(to_cmd_error)?;
+
+    let mut store = load_feeds(&app).map_err(to_cmd_error)?;
+    let feed = FeedSubscription {
+        id: format!("feed-{:08x}", rand::random::<u32>()),
+        title: if parsed.title.is_empty() { url.clone() } else { parsed.title.clone() },
+        url,
+    };
+    store.add_feed(feed.clone());
+    store.merge(&feed.id, parsed);
+    save_feeds(&app, &store).map_err(to_cmd_error)?;
+    Ok(feed)
+}
+
+#[tauri::command]
+fn unsubscribe_feed(app: AppHandle, feed_id: String) -> Result<GenericResult, CommandError> {
+    let mut store = load_feeds(&app).map_err(to_cmd_error)?;
+    let Some(removed) = store.remove_feed(&feed_id) else {
+        return Err(CommandError::not_found(format!("Feed {feed_id} does not exist")));
+    };
+    save_feeds(&app, &store).map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Unsubscribed from \"{}\"", removed.title),
+    })
+}
+
+/// Fetches every subscription and adds entries not seen before. A feed that
+/// fails to load is reported without stopping the others.
+#[tauri::command]
+async fn refresh_feeds(app: AppHandle) -> Result<GenericResult, CommandError> {
+    let feeds = load_feeds(&app).map_err(to_cmd_error)?.feeds().to_vec();
+    let mut fetched = Vec::new();
+    let mut failures = Vec::new();
+    for feed in feeds {
+        match fetch_feed(&feed.url).await {
+            Ok(parsed) => fetched.push((feed.id, parsed)),
+            Err(err) => failures.push(format!("{}: {err:#}", feed.title)),
+        }
+    }
+
+    // Reload so read marks made during the fetch are kept.
+    let mut store = load_feeds(&app).map_err(to_cmd_error)?;
+    let added: usize = fetched
+        .into_iter()
+        .map(|(feed_id, parsed)| store.merge(&feed_id, parsed))
+        .sum();
+    save_feeds(&app, &store).map_err(to_cmd_error)?;
+    if !failures.is_empty() {
+        emit_error(&app, &format!("Some feeds could not be refreshed: {}", failures.join("; ")));
+    }
+    Ok(GenericResult {
+        ok: failures.is_empty(),
+        message: format!("{added} new feed item(s)"),
+    })
+}
+
+#[tauri::command]
+fn list_feed_items(
+    app: AppHandle,
+    feed_id: Option<String>,
+    unread_only: Option<bool>,
+) -> Result<Vec<FeedItem>, CommandError> {
+    let store = load_feeds(&app).map_err(to_cmd_error)?;
+    let unread_only = unread_only.unwrap_or(true);
+    Ok(store
+        .items()
+        .iter()
+        .filter(|item| feed_id.as_deref().is_none_or(|feed_id| item.feed_id == feed_id))
+        .filter(|item| !unread_only || !item.read)
+        .cloned()
+        .collect())
+}
+
+#[tauri::command]
+fn mark_feed_item_read(app: AppHandle, id: String, read: bool) -> Result<GenericResult, CommandError> {
+    let mut store = load_feeds(&app).map_err(to_cmd_error)?;
+    let item = store.item_mut(&id).ok_or_else(|| CommandError::not_found(format!("Feed item {id} does not exist")))?;
+    item.read = read;
+    save_feeds(&app, &store).map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Feed item marked {}", if read { "read" } else { "unread" }),
+    })
+}
+
+/// Queues a feed item behind whatever is playing and marks it read. The
+/// linked article is read through the URL reader; entries without a link
+/// read their own text.
+#[tauri::command]
+async fn speak_feed_item(app: AppHandle, state: State<'_, SharedState>, id: String) -> Result<GenericResult, CommandError> {
+    let item = load_feeds(&app)
+        .map_err(to_cmd_error)?
+        .items()
+        .iter()
+        .find(|item| item.id == id)
+        .cloned()
+        .ok_or_else(|| CommandError::not_found(format!("Feed item {id} does not exist")))?;
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+
+    let acquired = match item.link.as_deref() {
+        Some(link) => acquire_text(&app, URL_SOURCE, Some(link)).await.map_err(to_cmd_error)?,
+        None => None,
+    };
+    let AcquiredText { text, origin } = acquired.unwrap_or_else(|| AcquiredText {
+        text: format!("{}\n\n{}", item.title, item.summary),
+        origin: JobOrigin {
+            window_title: item.title.clone(),
+            ..JobOrigin::with_method(CaptureMethod::Url)
+        },
+    });
+    let started = enqueue_read(
+        &app,
+        &state.inner,
+        QueuedRead {
+            text,
+            priority: ReadPriority::Normal,
+            source: "feed_item".to_string(),
+            origin,
+            voice_id: None,
+            rate: None,
+            preprocessed: false,
+            expand_placeholders: false,
+            reading_order: false,
+        },
+    )
+    .await
+    .map_err(to_cmd_error)?;
+
+    let mut store = load_feeds(&app).map_err(to_cmd_error)?;
+    if let Some(item) = store.item_mut(&id) {
+        item.read = true;
+    }
+    save_feeds(&app, &store).map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: match started {
+            Some(job_id) => format!("Feed item job started: {job_id}"),
+            None => format!("Feed item \"{}\" queued", item.title),
+        },
+    })
+}
+
+/// Schedules a file, URL or phrase to be read once, daily or at an interval.
+/// Schedules persist across restarts.
+#[tauri::command]
+fn schedule_read(
+    app: AppHandle,
+    target: ScheduleTarget,
+    timing: ScheduleTiming,
+    label: Option<String>,
+) -> Result<ScheduledRead, CommandError> {
+    let default_label = match &target {
+        ScheduleTarget::File { path } => {
+            if path.trim().is_empty() {
+                return Err(CommandError::invalid("Scheduled file path is empty"));
+            }
+            Path::new(path.trim())
+                .file_name()
+                .map_or_else(|| path.trim().to_string(), |name| name.to_string_lossy().to_string())
+        }
+        ScheduleTarget::Url { url } => {
+            if !(url.starts_with("http://") || url.starts_with("https://")) {
+                return Err(CommandError::invalid("Scheduled URL must start with http:// or https://"));
+            }
+            url.clone()
+        }
+        ScheduleTarget::Phrase { id } => load_phrases(&app)
+            .map_err(to_cmd_error)?
+            .get(id)
+            .map(|phrase| phrase.label.clone())
+            .ok_or_else(|| CommandError::not_found(format!("Phrase {id} does not exist")))?,
+    };
+    let next_run = timing.first_run(chrono::Local::now()).map_err(to_cmd_error)?;
+    let schedule = ScheduledRead {
+        id: format!("schedule-{:08x}", rand::random::<u32>()),
+        label: label
+            .map(|label| label.trim().to_string())
+            .filter(|label| !label.is_empty())
+            .unwrap_or(default_label),
+        target,
+        timing,
+        next_run: next_run.to_rfc3339(),
+    };
+
+    let mut store = load_schedules(&app).map_err(to_cmd_error)?;
+    store.add(schedule.clone());
+    save_schedules(&app, &store).map_err(to_cmd_error)?;
+    Ok(schedule)
+}
+
+#[tauri::command]
+fn list_schedules(app: AppHandle) -> Result<Vec<ScheduledRead>, CommandError> {
+    let store = load_schedules(&app).map_err(to_cmd_error)?;
+    Ok(store.schedules().to_vec())
+}
+
+#[tauri::command]
+fn cancel_schedule(app: AppHandle, id: String) -> Result<GenericResult, CommandError> {
+    let mut store = load_schedules(&app).map_err(to_cmd_error)?;
+    let Some(removed) = store.remove(&id) else {
+        return Err(CommandError::not_found(format!("Schedule {id} does not exist")));
+    };
+    save_schedules(&app, &store).map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Cancelled schedule \"{}\"", removed.label),
+    })
+}
+
+#[tauri::command]
+fn delete_phrase(app: AppHandle, id: String) -> Result<GenericResult, CommandError> {
+    let mut store = load_phrases(&app).map_err(to_cmd_error)?;
+    let Some(removed) = store.remove(&id) else {
+        return Err(CommandError::not_found(format!("Phrase {id} does not exist")));
+    };
+    if let Some(hotkey) = removed.hotkey.as_deref() {
+        let _ = app.global_shortcut_manager().unregister(hotkey);
+    }
+    let path = phrases_path(&app).ok_or_else(|| CommandError::internal("Unable to resolve phrases path"))?;
+    store.save(&path).map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Deleted phrase \"{}\"", removed.label),
+    })
+}
+
+#[tauri::command]
+async fn speak_phrase(app: AppHandle, state: State<'_, SharedState>, id: String) -> Result<GenericResult, CommandError> {
+    let started = speak_phrase_inner(&app, &state.inner, &id)
+        .await
+        .map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: match started {
+            Some(job_id) => format!("Speak job started: {job_id}"),
+            None => "Queued behind the current read".to_string(),
+        },
+    })
+}
+
+/// Synthesizes chunk `chunk_index` (usually one sentence) of a recent job
+/// again with the job's voice and rate, e.g. to repeat a garbled sentence.
+/// It interrupts the current read, which resumes afterwards.
+#[tauri::command]
+async fn respeak_sentence(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    job_id: String,
+    chunk_index: usize,
+) -> Result<GenericResult, CommandError> {
+    let read = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.job_registry.respeak(&job_id, chunk_index).map_err(to_cmd_error)?
+    };
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+    let started = enqueue_read(&app, &state.inner, read).await.map_err(to_cmd_error)?;
+    Ok(GenericResult {
+        ok: true,
+        message: match started {
+            Some(job_id) => format!("Speak job started: {job_id}"),
+            None => "Queued behind the current read".to_string(),
+        },
+    })
+}
+
+#[tauri::command]
+async fn cancel_active_job(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+
+    let job_id = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.last_job_id.clone()
+    };
+
+    // Cancel stops everything, including reads waiting to resume.
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.read_queue.clear();
+        guard.read_progress = None;
+        if let Some(player) = guard.native_player.as_ref() {
+            player.stop_all();
+        }
+    }
+    abort_job_sinks(&app, None);
+    emit_read_queue_changed(&app, &state.inner);
+
+    let Some(job_id) = job_id else {
+        return Ok(GenericResult {
+            ok: true,
+            message: "No active job to cancel".to_string(),
+        });
+    };
+
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.suppressed_jobs.suppress(&job_id);
+    }
+    emit_job_cancel_requested(&app, &job_id);
+
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        let is_echo_job = guard
+            .active_echo_job
+            .as_ref()
+            .is_some_and(|(echo_job_id, _)| echo_job_id == &job_id);
+        if is_echo_job {
+            if let Some((_, cancel_flag)) = guard.active_echo_job.take() {
+                cancel_flag.store(true, Ordering::SeqCst);
+            }
+            guard.last_job_id = None;
+            return Ok(GenericResult {
+                ok: true,
+                message: format!("Cancel request sent for job {job_id}"),
+            });
+        }
+    }
+
+    #[cfg(feature = "build-base")]
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        if let Some(cancel_flag) = guard.active_cancel_flag.as_ref() {
+            cancel_flag.store(true, Ordering::SeqCst);
+        }
+        guard.active_rate_steps = None;
+        guard.active_volume = None;
+        guard.active_pause_flag = None;
+        if guard.last_job_id.as_deref() == Some(job_id.as_str()) {
+            guard.last_job_id = None;
+        }
+        return Ok(GenericResult {
+            ok: true,
+            message: format!("Cancel request sent for job {job_id}"),
+        });
+    }
+
+    #[cfg(feature = "build-full")]
+    {
+    let (base_url, token) = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        (guard.base_url.clone(), guard.token.clone())
+    };
+
+    let _ = request_json(
+        Method::POST,
+        &format!("{base_url}/v1/cancel"),
+        &token,
+        Some(json!({ "job_id": job_id })),
+    )
+    .await
+    .map_err(to_cmd_error)?;
+
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        if guard.last_job_id.as_deref() == Some(job_id.as_str()) {
+            guard.last_job_id = None;
+        }
+    }
+
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Cancel request sent for job {job_id}"),
+    })
+    }
+
+    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
+    {
+        Err(CommandError::unsupported("Unsupported build variant for cancel operation"))
+    }
+}
+
+/// The job generating or playing now and the reads queued behind it.
+#[tauri::command]
+fn list_jobs(state: State<'_, SharedState>) -> Result<JobListPayload, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    let playing = playing_read(&guard);
+    let active = guard
+        .last_job_id
+        .clone()
+        .or_else(|| playing.map(|progress| progress.job_id.clone()))
+        .map(|job_id| ActiveJobPayload {
+            source: playing
+                .filter(|progress| progress.job_id == job_id)
+                .map(|progress| progress.source().to_string()),
+            generating: guard.last_job_id.as_deref() == Some(job_id.as_str()),
+            job_id,
+        });
+    Ok(JobListPayload {
+        active,
+        queued: guard.read_queue.jobs(),
+        policy: guard.queue_policy,
+    })
+}
+
+/// Cancels one job: a queued read is dropped, and the active job is stopped
+/// and the next queued read starts. Unlike `cancel_active_job`, the rest of
+/// the queue is kept.
+#[tauri::command]
+async fn cancel_job(app: AppHandle, state: State<'_, SharedState>, job_id: String) -> Result<GenericResult, CommandError> {
+    let job_id = job_id.trim().to_string();
+    if job_id.is_empty() {
+        return Err(CommandError::invalid("job_id cannot be empty"));
+    }
+
+    let (dequeued, active) = {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        let dequeued = guard.read_queue.remove(&job_id);
+        let active = !dequeued
+            && (guard.last_job_id.as_deref() == Some(job_id.as_str())
+                || playing_read(&guard).is_some_and(|progress| progress.job_id == job_id));
+        if active {
+            if guard.read_progress.as_ref().is_some_and(|progress| progress.job_id == job_id) {
+                guard.read_progress = None;
+            }
+            if let Some(player) = guard.native_player.as_ref() {
+                player.stop_all();
+            }
+        }
+        (dequeued, active)
+    };
+    if dequeued {
+        emit_read_queue_changed(&app, &state.inner);
+        return Ok(GenericResult {
+            ok: true,
+            message: format!("Removed job {job_id} from the queue"),
+        });
+    }
+    if !active {
+        return Err(CommandError::not_found(format!("Job {job_id} is neither active nor queued")));
+    }
+
+    stop_interrupted_read(&app, &state.inner, &job_id).await.map_err(to_cmd_error)?;
+    let next = {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.read_queue.pop()
+    };
+    emit_read_queue_changed(&app, &state.inner);
+    if let Some(next) = next {
+        start_queued_read(&app, &state.inner, next).await.map_err(to_cmd_error)?;
+    }
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Cancel request sent for job {job_id}"),
+    })
+}
+
+#[tauri::command]
+fn get_queue_policy(state: State<'_, SharedState>) -> Result<QueuePolicy, CommandError> {
+    let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    Ok(guard.queue_policy)
+}
+
+/// Chooses what the read-selection hotkey does while a read is playing:
+/// `interrupt` keeps the hotkey a start/stop toggle, `enqueue` reads each new
+/// selection after the current read and whatever is already queued.
+#[tauri::command]
+fn set_queue_policy(app: AppHandle, state: State<'_, SharedState>, policy: QueuePolicy) -> Result<GenericResult, CommandError> {
+    {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.queue_policy = policy;
+    }
+    if let Err(err) = persist_queue_policy(&app, policy) {
+        emit_error(&app, &format!("Queue policy updated but could not persist settings: {err:#}"));
+    }
+    Ok(GenericResult {
+        ok: true,
+        message: match policy {
+            QueuePolicy::Interrupt => "New selections interrupt the current read".to_string(),
+            QueuePolicy::Enqueue => "New selections are read after the current read".to_string(),
+        },
+    })
+}
+
+#[tauri::command]
+fn pause_active_job(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, CommandError> {
+    set_active_job_paused(&app, &state.inner, true).map_err(to_cmd_error)
+}
+
+#[tauri::command]
+fn resume_active_job(app: AppHandle, state: State<'_, SharedState>) -> Result<GenericResult, CommandError> {
+    set_active_job_paused(&app, &state.inner, false).map_err(to_cmd_error)
+}
+
+/// Holds or releases synthesis of the running job. Chunks already sent keep
+/// playing unless the player pauses too, so `JOB_PAUSED`/`JOB_RESUMED` go out
+/// as ws-events for the frontend to follow.
+fn set_active_job_paused(app: &AppHandle, state: &Arc<Mutex<EngineState>>, paused: bool) -> Result<GenericResult> {
+    let (job_id, pause_flag) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (guard.last_job_id.clone(), guard.active_pause_flag.clone())
+    };
+    let verb = if paused { "pause" } else { "resume" };
+    let (Some(job_id), Some(pause_flag)) = (job_id, pause_flag) else {
+        return Ok(GenericResult {
+            ok: true,
+            message: format!("No active job to {verb}"),
+        });
+    };
+
+    if pause_flag.swap(paused, Ordering::SeqCst) != paused {
+        with_native_player(app, |player| player.set_paused(paused));
+        if let Ok(mut guard) = state.lock() {
+            guard.audio_credits.set_paused(paused);
+            let guard = &mut *guard;
+            let draining = guard.draining_sinks.iter_mut().map(|(_, sink)| sink);
+            for sink in guard.job_sinks.values_mut().flatten().chain(draining) {
+                sink.set_paused(paused);
+            }
+        }
+        let _ = app.emit_routed(
+            "voicereader:ws-event",
+            json!({
+                "type": if paused { "JOB_PAUSED" } else { "JOB_RESUMED" },
+                "job_id": job_id,
+            }),
+        );
+    }
+    Ok(GenericResult {
+        ok: true,
+        message: if paused {
+            format!("Job {job_id} paused")
+        } else {
+            format!("Job {job_id} resumed")
+        },
+    })
+}
+
+/// Pauses the active job, or resumes it when it is paused.
+fn toggle_pause_inner(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    let paused = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard
+            .active_pause_flag
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::SeqCst))
+    };
+    set_active_job_paused(app, state, !paused).map(|_| ())
+}
+
+/// Acknowledges that the player finished the `chunk_index`-th audio chunk of
+/// `job_id` (0-based, in arrival order), returning a credit to synthesis.
+#[tauri::command]
+fn ack_audio(state: State<'_, SharedState>, job_id: String, chunk_index: u64) -> Result<(), CommandError> {
+    let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+    guard.audio_credits.ack(&job_id, chunk_index);
+    Ok(())
+}
+
+/// Sends `job_id`'s `AUDIO_CHUNK` events again from `chunk_seq` `from_seq` on,
+/// under a new `stream_epoch`, for a player that found a gap in the sequence.
+/// Only the latest chunks are kept, so the replay may start later than asked.
+#[tauri::command]
+fn resync_audio(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    job_id: String,
+    from_seq: u64,
+) -> Result<GenericResult, CommandError> {
+    let replayed = {
+        let mut guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.chunk_journal.replay(&job_id, from_seq)
+    };
+    let Some(events) = replayed else {
+        return Ok(GenericResult {
+            ok: false,
+            message: format!("No audio of job {job_id} from chunk {from_seq} to resend"),
+        });
+    };
+    let count = events.len();
+    for event in events {
+        let _ = app.emit_routed("voicereader:ws-event", event);
+    }
+    Ok(GenericResult {
+        ok: true,
+        message: format!("Resent {count} chunk(s) of job {job_id}"),
+    })
+}
+
+#[tauri::command]
+async fn export_speech_to_file(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    text: String,
+    path: String,
+    tag_synthetic: Option<bool>,
+    tee: Option<bool>,
+) -> Result<ExportSpeechResult, CommandError> {
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+
+    let trimmed_path = path.trim();
+    if trimmed_path.is_empty() {
+        return Err(CommandError::invalid("path cannot be empty"));
+    }
+    let target = PathBuf::from(trimmed_path);
+    let tag_synthetic = tag_synthetic.unwrap_or(true);
+
+    // Tee mode reads the text aloud as a normal job and writes the file as it
+    // plays; `voicereader:export-finished` reports the final length.
+    if tee.unwrap_or(false) {
+        let overrides = SpeakOverrides {
+            export_tee: Some(ExportTee {
+                path: target.clone(),
+                tag_synthetic,
+            }),
+            ..SpeakOverrides::default()
+        };
+        let job_id = speak_and_stream_with_overrides(&app, &state.inner, text, "export_tee", &JobOrigin::direct(), &overrides)
+            .await
+            .map_err(to_cmd_error)?;
+        return Ok(ExportSpeechResult {
+            ok: true,
+            message: format!("Speak job {job_id} started; writing its audio to {}", target.display()),
+            path: target.to_string_lossy().to_string(),
+            duration_ms: 0,
+            tagged_synthetic: tag_synthetic,
+            readback: None,
+        });
+    }
+
+    let (voice_id, selected_model, readback_settings) = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        (guard.selected_voice_id.clone(), guard.selected_model.clone(), guard.readback.clone())
+    };
+
+    let (pcm, sample_rate) = synthesize_to_pcm(&state.inner, text.clone(), None)
+        .await
+        .map_err(to_cmd_error)?;
+    if pcm.is_empty() || sample_rate == 0 {
+        return Err(CommandError::new(ErrorCode::EngineRequestFailed, "Synthesis produced no audio to export"));
+    }
+
+    let provenance = tag_synthetic.then(|| SyntheticProvenance::new(&selected_model, &voice_id));
+    write_wav_file(&target, &pcm, sample_rate, provenance.as_ref()).map_err(to_cmd_error)?;
+
+    let duration_ms = (pcm.len() as u64 * 1000) / sample_rate as u64;
+    let mut message = format!("Exported {:.1}s of speech to {}", duration_ms as f64 / 1000.0, target.display());
+    let readback = if readback_settings.is_enabled() {
+        let audio_path = target.clone();
+        let verified = tauri::async_runtime::spawn_blocking(move || {
+            verify_export(&readback_settings, &audio_path, duration_ms, &text)
+        })
+        .await
+        .map_err(|err| anyhow!("Read-back task failed: {err}"))
+        .and_then(|result| result);
+        match verified {
+            Ok(report) => {
+                message.push_str(&format!(
+                    "; read-back flagged {} of {} sentences",
+                    report.flagged.len(),
+                    report.sentences_checked
+                ));
+                Some(report)
+            }
+            // The file is written either way; a failed check is reported, not fatal.
+            Err(err) => {
+                emit_error(&app, &format!("Read-back verification of {} failed: {err:#}", target.display()));
+                message.push_str("; read-back verification failed");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    Ok(ExportSpeechResult {
+        ok: true,
+        message,
+        path: target.to_string_lossy().to_string(),
+        duration_ms,
+        tagged_synthetic: tag_synthetic,
+        readback,
+    })
+}
+
+/// Synthesizes `sections` in order with the current voice into `dir`, as one
+/// WAV or one per chapter, plus a `chapters.json` manifest. Progress goes out
+/// as `voicereader:export-progress` events before each section and at the end.
+#[tauri::command]
+async fn export_audiobook(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    sections: Vec<AudiobookSection>,
+    dir: String,
+    layout: Option<AudiobookLayout>,
+    tag_synthetic: Option<bool>,
+) -> Result<AudiobookExportResult, CommandError> {
+    validate_sections(&sections).map_err(to_invalid_error)?;
+    let trimmed_dir = dir.trim();
+    if trimmed_dir.is_empty() {
+        return Err(CommandError::invalid("dir cannot be empty"));
+    }
+    let dir = PathBuf::from(trimmed_dir);
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+
+    // The voice is pinned at the start so a voice change mid-export does not
+    // switch narrators between chapters.
+    let (voice_id, selected_model) = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        (guard.selected_voice_id.clone(), guard.selected_model.clone())
+    };
+    let provenance = tag_synthetic
+        .unwrap_or(true)
+        .then(|| SyntheticProvenance::new(&selected_model, &voice_id));
+    let layout = layout.unwrap_or_default();
+    let total = sections.len();
+    let mut writer = AudiobookWriter::create(&dir, layout, total, provenance).map_err(to_cmd_error)?;
+
+    let export_id = format!("audiobook-{:016x}", rand::random::<u64>());
+    let emit_progress = |completed: usize, current: Option<String>| {
+        let _ = app.emit_routed(
+            "voicereader:export-progress",
+            ExportProgressPayload {
+                export_id: export_id.clone(),
+                completed,
+                total,
+                current,
+            },
+        );
+    };
+    for (index, section) in sections.into_iter().enumerate() {
+        let title = section_title(&section, index);
+        emit_progress(index, Some(title.clone()));
+        let (pcm, sample_rate) = synthesize_to_pcm(&state.inner, section.text, Some(voice_id.clone()))
+            .await
+            .map_err(|err| CommandError::internal(format!("Failed to synthesize \"{title}\": {err:#}")))?;
+        writer.add_chapter(&title, &pcm, sample_rate).map_err(to_cmd_error)?;
+    }
+    let manifest = writer.finish().map_err(to_cmd_error)?;
+    emit_progress(total, None);
+
+    Ok(AudiobookExportResult {
+        ok: true,
+        message: format!(
+            "Exported {total} chapter(s), {:.1} min of speech, to {}",
+            manifest.duration_ms as f64 / 60_000.0,
+            dir.display()
+        ),
+        dir: dir.to_string_lossy().to_string(),
+        manifest_path: dir.join(MANIFEST_FILE_NAME).to_string_lossy().to_string(),
+        manifest,
+    })
+}
+
+/// Writes `text` read in `voice_id` to a WAV file at `path` without playing
+/// it or emitting audio events; the selected voice is left as it is.
+#[tauri::command]
+async fn synthesize_to_file(
+    app: AppHandle,
+    state: State<'_, SharedState>,
+    text: String,
+    voice_id: String,
+    path: String,
+    tag_synthetic: Option<bool>,
+) -> Result<ExportSpeechResult, CommandError> {
+    let voice_id = voice_id.trim().to_string();
+    if voice_id.is_empty() {
+        return Err(CommandError::invalid("voice_id cannot be empty"));
+    }
+    let trimmed_path = path.trim();
+    if trimmed_path.is_empty() {
+        return Err(CommandError::invalid("path cannot be empty"));
+    }
+    let target = PathBuf::from(trimmed_path);
+    let tag_synthetic = tag_synthetic.unwrap_or(true);
+    ensure_engine_ready(&app, &state.inner).await.map_err(to_cmd_error)?;
+
+    let selected_model = {
+        let guard = state.inner.lock().map_err(|_| CommandError::lock_poisoned())?;
+        guard.selected_model.clone()
+    };
+    let (pcm, sample_rate) = synthesize_to_pcm(&state.inner, text, Some(voice_id.clone()))
+        .await
+        .map_err(to_cmd_error)?;
+    if pcm.is_empty() || sample_rate == 0 {
+        return Err(CommandError::new(ErrorCode::EngineRequestFailed, "Synthesis produced no audio to write"));
+    }
+
+    let provenance = tag_synthetic.then(|| SyntheticProvenance::new(&selected_model, &voice_id));
+    write_wav_file(&target, &pcm, sample_rate, provenance.as_ref()).map_err(to_cmd_error)?;
+
+    let duration_ms = (pcm.len() as u64 * 1000) / sample_rate as u64;
+    Ok(ExportSpeechResult {
+        ok: true,
+        message: format!(
+            "Wrote {:.1}s of speech in voice {voice_id} to {}",
+            duration_ms as f64 / 1000.0,
+            target.display()
+        ),
+        path: target.to_string_lossy().to_string(),
+        duration_ms,
+        tagged_synthetic: tag_synthetic,
+        readback: None,
+    })
+}
+
+fn register_hotkey(app: &AppHandle, state: Arc<Mutex<EngineState>>) -> Result<()> {
+    let hotkey = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.hotkeys.read_selection().to_string()
+    };
+
+    if is_hotkey_os_reserved(&hotkey) {
+        return Err(anyhow!(
+            "Selected hotkey {hotkey} is OS-reserved. Use a non-reserved combination."
+        ));
+    }
+
+    if register_hotkey_binding(app, state.clone(), &hotkey, HotkeyAction::ReadSelection).is_ok() {
+        if let Ok(mut guard) = state.lock() {
+            guard.registered_hotkey = Some(hotkey);
+        }
+        return Ok(());
+    }
+
+    let (fallbacks, hotkeys, orientation_hotkey, read_window_hotkey, summary_hotkey) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (
+            guard.fallback_hotkeys.clone(),
+            guard.hotkeys.clone(),
+            guard.orientation_hotkey.clone(),
+            guard.read_window_hotkey.clone(),
+            guard.summary_hotkey.clone(),
+        )
+    };
+    let mut failures = Vec::new();
+    for fallback in fallbacks.iter().filter(|candidate| **candidate != hotkey) {
+        if hotkeys.action_for(fallback).is_some()
+            || orientation_hotkey.as_deref() == Some(fallback.as_str())
+            || read_window_hotkey.as_deref() == Some(fallback.as_str())
+            || summary_hotkey.as_deref() == Some(fallback.as_str())
+            || phrase_bound_to(app, fallback).is_some()
+        {
+            continue;
+        }
+        if let Err(err) = register_hotkey_binding(app, state.clone(), fallback, HotkeyAction::ReadSelection) {
+            failures.push(format!("{fallback}: {err:#}"));
+            continue;
+        }
+        let hotkeys = state.lock().ok().map(|mut guard| {
+            guard.hotkeys.set(BindableAction::ReadSelection, Some(fallback.clone()));
+            guard.registered_hotkey = Some(fallback.clone());
+            guard.hotkeys.clone()
+        });
+        if let Some(hotkeys) = hotkeys {
+            let _ = persist_hotkeys(app, &hotkeys);
+        }
+        let _ = app.emit_routed(
+            "voicereader:hotkey-updated",
+            HotkeyUpdatedPayload {
+                hotkey: fallback.clone(),
+            },
+        );
+        return Ok(());
+    }
+
+    if failures.is_empty() {
+        return Err(anyhow!("Failed to register global hotkey {hotkey}"));
+    }
+    Err(anyhow!(
+        "Failed to register global hotkey {hotkey} or any fallback ({})",
+        failures.join("; ")
+    ))
+}
+
+/// Registers the queue-selection variant of the read hotkey, if it has one.
+fn register_queue_hotkey(app: &AppHandle, state: Arc<Mutex<EngineState>>) -> Result<()> {
+    let (hotkeys, orientation_hotkey, read_window_hotkey, summary_hotkey) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (
+            guard.hotkeys.clone(),
+            guard.orientation_hotkey.clone(),
+            guard.read_window_hotkey.clone(),
+            guard.summary_hotkey.clone(),
+        )
+    };
+    let Some(variant) = queue_hotkey_variant(hotkeys.read_selection()) else {
+        return Ok(());
+    };
+    if let Some(bound) = hotkeys.action_for(&variant) {
+        return Err(anyhow!("{variant} is already bound to the {} action", bound.label()));
+    }
+    if orientation_hotkey.as_deref() == Some(variant.as_str()) {
+        return Err(anyhow!("{variant} is already bound to the orientation action"));
+    }
+    if read_window_hotkey.as_deref() == Some(variant.as_str()) {
+        return Err(anyhow!("{variant} is already bound to the read-window action"));
+    }
+    if summary_hotkey.as_deref() == Some(variant.as_str()) {
+        return Err(anyhow!("{variant} is already bound to the summary action"));
+    }
+    register_hotkey_binding(app, state, &variant, HotkeyAction::QueueSelection)
+}
+
+/// Binds every saved phrase hotkey. All phrases are attempted; failures are
+/// reported together.
+fn register_phrase_hotkeys(app: &AppHandle, state: Arc<Mutex<EngineState>>) -> Result<()> {
+    let store = load_phrases(app)?;
+    let failures: Vec<String> = store
+        .phrases()
+        .iter()
+        .filter_map(|phrase| {
+            let hotkey = phrase.hotkey.as_deref()?;
+            let action = HotkeyAction::SpeakPhrase(phrase.id.clone());
+            register_hotkey_binding(app, state.clone(), hotkey, action)
+                .err()
+                .map(|err| format!("{} ({hotkey}): {err:#}", phrase.label))
+        })
+        .collect();
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(failures.join("; ")))
+    }
+}
+
+/// Label of the saved phrase bound to `hotkey`, if any.
+fn phrase_bound_to(app: &AppHandle, hotkey: &str) -> Option<String> {
+    let store = load_phrases(app).ok()?;
+    store
+        .phrases()
+        .iter()
+        .find(|phrase| phrase.hotkey.as_deref() == Some(hotkey))
+        .map(|phrase| phrase.label.clone())
+}
+
+fn register_orientation_hotkey(app: &AppHandle, state: Arc<Mutex<EngineState>>) -> Result<()> {
+    let orientation_hotkey = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.orientation_hotkey.clone()
+    };
+    let Some(orientation_hotkey) = orientation_hotkey else {
+        return Ok(());
+    };
+    register_hotkey_binding(app, state, &orientation_hotkey, HotkeyAction::AnnounceFocus)
+}
+
+fn register_read_window_hotkey(app: &AppHandle, state: Arc<Mutex<EngineState>>) -> Result<()> {
+    let read_window_hotkey = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.read_window_hotkey.clone()
+    };
+    let Some(read_window_hotkey) = read_window_hotkey else {
+        return Ok(());
+    };
+    register_hotkey_binding(app, state, &read_window_hotkey, HotkeyAction::ReadWindow)
+}
+
+fn register_summary_hotkey(app: &AppHandle, state: Arc<Mutex<EngineState>>) -> Result<()> {
+    let summary_hotkey = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.summary_hotkey.clone()
+    };
+    let Some(summary_hotkey) = summary_hotkey else {
+        return Ok(());
+    };
+    register_hotkey_binding(app, state, &summary_hotkey, HotkeyAction::SummarizeSelection)
+}
+
+/// Binds every action hotkey but read-selection, which `register_hotkey`
+/// binds with its fallbacks. All are attempted; failures are reported together.
+fn register_action_hotkeys(app: &AppHandle, state: Arc<Mutex<EngineState>>) -> Result<()> {
+    let hotkeys = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.hotkeys.clone()
+    };
+    let failures: Vec<String> = hotkeys
+        .bindings()
+        .filter(|(action, _)| *action != BindableAction::ReadSelection)
+        .filter_map(|(action, hotkey)| {
+            register_hotkey_binding(app, state.clone(), hotkey, action.into())
+                .err()
+                .map(|err| format!("{} ({hotkey}): {err:#}", action.label()))
+        })
+        .collect();
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(failures.join("; ")))
+    }
+}
+
+fn register_hotkey_binding(
+    app: &AppHandle,
+    state: Arc<Mutex<EngineState>>,
+    hotkey: &str,
+    action: HotkeyAction,
+) -> Result<()> {
+    let hotkey = normalize_hotkey(hotkey)?;
+    // While suspended the binding is only recorded; it is registered on re-enable.
+    if !state.lock().map_err(|_| anyhow!("State lock poisoned"))?.hotkeys_enabled {
+        return Ok(());
+    }
+    let app_handle = app.clone();
+    app.global_shortcut_manager()
+        .register(&hotkey, move || {
+            let app_clone = app_handle.clone();
+            if should_ignore_hotkey_while_app_focused(&app_clone) {
+                return;
+            }
+            let state_clone = state.clone();
+            let action = action.clone();
+            tauri::async_runtime::spawn(async move {
+                let result = match action {
+                    HotkeyAction::ReadSelection => toggle_read_selection(&app_clone, &state_clone).await,
+                    HotkeyAction::QueueSelection => queue_selection_inner(&app_clone, &state_clone).await,
+                    HotkeyAction::AnnounceFocus => announce_focus_inner(&app_clone, &state_clone).await,
+                    HotkeyAction::ReadWindow => read_window_inner(&app_clone, &state_clone).await,
+                    HotkeyAction::SummarizeSelection => summarize_selection_inner(&app_clone, &state_clone).await,
+                    HotkeyAction::ReadClipboard => read_clipboard_inner(&app_clone, &state_clone).await,
+                    HotkeyAction::Stop => cancel_active_job(app_clone.clone(), app_clone.state::<SharedState>())
+                        .await
+                        .map(|_| ())
+                        .map_err(|err| anyhow!(err)),
+                    HotkeyAction::PauseResume => toggle_pause_inner(&app_clone, &state_clone),
+                    HotkeyAction::SpeedUp => step_speak_rate(&app_clone, HOTKEY_RATE_STEP),
+                    HotkeyAction::SpeedDown => step_speak_rate(&app_clone, -HOTKEY_RATE_STEP),
+                    HotkeyAction::SpeakPhrase(id) => speak_phrase_inner(&app_clone, &state_clone, &id).await.map(|_| ()),
+                };
+                if let Err(err) = result {
+                    // A capture held for quiet hours is not a failure.
+                    if !err.is::<HeldForQuietHours>() {
+                        emit_error(&app_clone, &format!("Hotkey flow failed: {err:#}"));
+                    }
+                }
+            });
+        })
+        .with_context(|| format!("Failed to register global hotkey {hotkey}"))?;
+
+    Ok(())
+}
+
+/// Polls the focused app's selection and reads it once it has settled,
+/// at most once per `FOLLOW_SELECTION_MIN_INTERVAL_MS`. Each read replaces
+/// the current one, as when reading successive paragraphs.
+fn spawn_follow_selection_watcher(app: AppHandle, state: Arc<Mutex<EngineState>>, generation: u64) {
+    tauri::async_runtime::spawn(async move {
+        let debounce = Duration::from_millis(FOLLOW_SELECTION_DEBOUNCE_MS);
+        let min_interval = Duration::from_millis(FOLLOW_SELECTION_MIN_INTERVAL_MS);
+        let mut pending: Option<(String, Instant)> = None;
+        let mut last_read: Option<(String, Instant)> = None;
+        loop {
+            tokio::time::sleep(Duration::from_millis(FOLLOW_SELECTION_POLL_MS)).await;
+            let active = state
+                .lock()
+                .map(|guard| guard.follow_selection && guard.follow_selection_generation == generation)
+                .unwrap_or(false);
+            if !active {
+                break;
+            }
+            // Selecting text in our own window is not a read request.
+            if should_ignore_hotkey_while_app_focused(&app) {
+                pending = None;
+                continue;
+            }
+            // Accessibility queries can block on a hung target app.
+            let Some(selection) = tauri::async_runtime::spawn_blocking(focused_selection)
+                .await
+                .ok()
+                .flatten()
+            else {
+                pending = None;
+                continue;
+            };
+            if last_read.as_ref().is_some_and(|(text, _)| *text == selection) {
+                pending = None;
+                continue;
+            }
+            let stable_since = match pending.as_ref() {
+                Some((text, since)) if *text == selection => *since,
+                _ => {
+                    pending = Some((selection, Instant::now()));
+                    continue;
+                }
+            };
+            if stable_since.elapsed() < debounce
+                || last_read.as_ref().is_some_and(|(_, at)| at.elapsed() < min_interval)
+            {
+                continue;
+            }
+
+            pending = None;
+            last_read = Some((selection.clone(), Instant::now()));
+            let origin = foreground_origin(CaptureMethod::Accessibility);
+            let result: Result<String> = async {
+                ensure_engine_ready(&app, &state).await?;
+                speak_and_stream(&app, &state, selection, "follow_selection", &origin).await
+            }
+            .await;
+            if let Err(err) = result {
+                emit_error(&app, &format!("Follow selection read failed: {err:#}"));
+            }
+        }
+    });
+}
+
+fn should_ignore_hotkey_while_app_focused(app: &AppHandle) -> bool {
+    let Some(window) = app.get_window("main") else {
+        return false;
+    };
+    window.is_focused().unwrap_or(false)
+}
+
+async fn read_selection_and_speak_inner(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    ensure_engine_ready(app, state).await?;
+
+    let Some(AcquiredText { text, origin }) = acquire_text(app, SELECTION_SOURCE, None).await? else {
+        return Ok(());
+    };
+
+    let policy = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.queue_policy
+    };
+    match policy {
+        QueuePolicy::Interrupt => {
+            let overrides = SpeakOverrides {
+                reading_order: true,
+                ..SpeakOverrides::default()
+            };
+            let _ = speak_and_stream_with_overrides(
+                app,
+                state,
+                text,
+                "hotkey_selection_capture",
+                &origin,
+                &overrides,
+            )
+            .await?;
+        }
+        QueuePolicy::Enqueue => {
+            let _ = enqueue_read(
+                app,
+                state,
+                QueuedRead {
+                    text,
+                    priority: ReadPriority::Normal,
+                    source: "hotkey_selection_capture".to_string(),
+                    origin,
+                    voice_id: None,
+                    rate: None,
+                    preprocessed: false,
+                    expand_placeholders: false,
+                    reading_order: true,
+                },
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+/// The read-selection hotkey: reads the selection, or stops the read when one
+/// is speaking, so the same key starts and stops reading. Under the
+/// `enqueue` queue policy it never stops; each press queues the selection.
+async fn toggle_read_selection(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    let press = {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        let speaking = is_speaking(&guard) && guard.queue_policy == QueuePolicy::Interrupt;
+        guard.selection_toggle.press(speaking)
+    };
+    match press {
+        TogglePress::Capture => {
+            let result = read_selection_and_speak_inner(app, state).await;
+            if let Ok(mut guard) = state.lock() {
+                guard.selection_toggle.capture_finished();
+            }
+            result
+        }
+        TogglePress::Stop => cancel_active_job(app.clone(), app.state::<SharedState>())
+            .await
+            .map(|_| ())
+            .map_err(|err| anyhow!(err)),
+        TogglePress::Ignore => Ok(()),
+    }
+}
+
+/// Appends the selection to the read queue; it starts right away only when
+/// nothing is playing.
+async fn queue_selection_inner(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    ensure_engine_ready(app, state).await?;
+
+    let Some(AcquiredText { text, origin }) = acquire_text(app, SELECTION_SOURCE, None).await? else {
+        return Ok(());
+    };
+
+    let _ = enqueue_read(
+        app,
+        state,
+        QueuedRead {
+            text,
+            priority: ReadPriority::Normal,
+            source: "hotkey_queue_selection".to_string(),
+            origin,
+            voice_id: None,
+            rate: None,
+            preprocessed: false,
+            expand_placeholders: false,
+            reading_order: true,
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Speaks a saved phrase in the interactive lane, so it interrupts a long
+/// read and the read resumes afterwards.
+async fn speak_phrase_inner(app: &AppHandle, state: &Arc<Mutex<EngineState>>, id: &str) -> Result<Option<String>> {
+    let phrase = load_phrases(app)?
+        .get(id)
+        .cloned()
+        .ok_or_else(|| anyhow!("Phrase {id} does not exist"))?;
+    ensure_engine_ready(app, state).await?;
+    enqueue_read(
+        app,
+        state,
+        QueuedRead {
+            text: phrase.text,
+            priority: ReadPriority::Interactive,
+            source: "quick_phrase".to_string(),
+            origin: JobOrigin::direct(),
+            voice_id: phrase.voice_id,
+            rate: None,
+            preprocessed: false,
+            expand_placeholders: true,
+            reading_order: false,
+        },
+    )
+    .await
+}
+
+/// Queues a due schedule behind whatever is playing. Returns the job id if
+/// the read started right away.
+async fn run_scheduled_read(
+    app: &AppHandle,
+    state: &Arc<Mutex<EngineState>>,
+    schedule: &ScheduledRead,
+) -> Result<Option<String>> {
+    ensure_engine_ready(app, state).await?;
+    let (text, origin, voice_id, expand) = match &schedule.target {
+        ScheduleTarget::File { path } => {
+            let Some(acquired) = acquire_text(app, FILE_SOURCE, Some(path)).await? else {
+                return Ok(None);
+            };
+            (acquired.text, acquired.origin, None, false)
+        }
+        ScheduleTarget::Url { url } => {
+            let Some(acquired) = acquire_text(app, URL_SOURCE, Some(url)).await? else {
+                return Ok(None);
+            };
+            (acquired.text, acquired.origin, None, false)
+        }
+        ScheduleTarget::Phrase { id } => {
+            let phrase = load_phrases(app)?
+                .get(id)
+                .cloned()
+                .ok_or_else(|| anyhow!("Phrase {id} does not exist"))?;
+            (phrase.text, JobOrigin::direct(), phrase.voice_id, true)
+        }
+    };
+    enqueue_read(
+        app,
+        state,
+        QueuedRead {
+            text,
+            priority: ReadPriority::Normal,
+            source: "scheduled_read".to_string(),
+            origin,
+            voice_id,
+            rate: None,
+            preprocessed: false,
+            expand_placeholders: expand,
+            reading_order: false,
+        },
+    )
+    .await
+}
+
+async fn fetch_feed(url: &str) -> Result<ParsedFeed> {
+    let body = Client::new()
+        .get(url)
+        .timeout(Duration::from_secs(FEED_FETCH_TIMEOUT_SECS))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .with_context(|| format!("Failed to fetch feed {url}"))?
+        .text()
+        .await
+        .with_context(|| format!("Failed to download feed {url}"))?;
+    parse_feed(&body).with_context(|| format!("Failed to read feed {url}"))
+}
+
+async fn skim_selection_and_speak_inner(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    ensure_engine_ready(app, state).await?;
+
+    let Some(AcquiredText { text, origin }) = acquire_text(app, SELECTION_SOURCE, None).await? else {
+        return Ok(());
+    };
+    let skim = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.skim_settings
+    };
+    let condensed = skim_text(&text, skim);
+    if condensed.trim().is_empty() {
+        return Err(anyhow!("Selection has no sentences to skim"));
+    }
+
+    // Reading order stays off (the default): the condensed text is already
+    // sampled, and reading order would sample it again and announce
+    // paragraphs inside it.
+    let overrides = SpeakOverrides {
+        rate: Some(skim.rate),
+        ..SpeakOverrides::default()
+    };
+    let _ = speak_and_stream_with_overrides(
+        app,
+        state,
+        condensed,
+        "skim_selection_capture",
+        &origin,
+        &overrides,
+    )
+    .await?;
+    Ok(())
+}
+
+/// Dual-language echo: speaks each sentence in the original language, pauses,
+/// then speaks its translation, each with its own voice. Segments are
+/// synthesized one at a time and streamed as a locally managed job so the
+/// same flow works with both the in-process and the sidecar engine.
+async fn speak_echo(
+    app: &AppHandle,
+    state: &Arc<Mutex<EngineState>>,
+    text: String,
+    source: &str,
+    origin: &JobOrigin,
+) -> Result<String> {
+    let capture = origin.metadata(&text);
+    let trimmed = text.trim().to_string();
+    if trimmed.is_empty() {
+        return Err(anyhow!("Speak text cannot be empty"));
+    }
+
+    let (selected_voice_id, selected_model, settings, translation, echo) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (
+            guard.selected_voice_id.clone(),
+            guard.selected_model.clone(),
+            guard.speak_settings.clone(),
+            guard.translation.clone(),
+            guard.echo_settings.clone(),
+        )
+    };
+    if selected_model != MODEL_CUSTOM && selected_model != MODEL_KYUTAI {
+        return Err(anyhow!(
+            "Current model mode ({selected_model}) is not enabled for read-aloud yet. Switch to qwen_custom_voice or kyutai_pocket_tts."
+        ));
+    }
+    if !translation.is_enabled() {
+        return Err(anyhow!("Dual-language echo needs a translation provider. Configure one in translation settings."));
+    }
+
+    let sentences: Vec<String> = split_paragraphs(&trimmed)
+        .iter()
+        .flat_map(|paragraph| split_sentences(paragraph))
+        .collect();
+    if sentences.is_empty() {
+        return Err(anyhow!("Text has no sentences to echo"));
+    }
+    let source_voice_id = echo.source_voice_id.clone().unwrap_or_else(|| selected_voice_id.clone());
+    let target_voice_id = translation
+        .target_voice_id
+        .clone()
+        .filter(|id| !id.trim().is_empty())
+        .unwrap_or(selected_voice_id);
+
+    let started = SegmentedJobStart {
+        kind: "echo",
+        source,
+        origin,
+        capture,
+        text: &trimmed,
+        rate: settings.rate,
+    };
+    let (job_id, cancel) = begin_segmented_job(app, state, started).await?;
+    let app_clone = app.clone();
+    let state_clone = state.clone();
+    let job_id_clone = job_id.clone();
+    tauri::async_runtime::spawn(async move {
+        let segments = EchoSegments {
+            sentences,
+            source_voice_id,
+            target_voice_id,
+            pause_ms: echo.pause_ms,
+        };
+        let result = run_echo_job(&app_clone, &state_clone, &job_id_clone, segments, &translation, settings, &cancel).await;
+        finish_segmented_job(&app_clone, &state_clone, &job_id_clone, "Echo", result, &cancel);
+    });
+
+    Ok(job_id)
+}
+
+/// Reads narration and dialogue with separate voices. Like echo, each
+/// segment is synthesized on its own and streamed as a locally managed job.
+async fn speak_dialogue(
+    app: &AppHandle,
+    state: &Arc<Mutex<EngineState>>,
+    dialogue: DialogueSegments,
+    settings: SpeakSettingsState,
+    source: &str,
+    origin: &JobOrigin,
+    capture: CaptureMetadata,
+) -> Result<String> {
+    let text = dialogue
+        .segments
+        .iter()
+        .map(|segment| segment.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let started = SegmentedJobStart {
+        kind: "dialogue",
+        source,
+        origin,
+        capture,
+        text: &text,
+        rate: settings.rate,
+    };
+    let DialogueSegments {
+        segments,
+        narration_voice_id,
+        dialogue_voice_id,
+        export_tee,
+    } = dialogue;
+    let (job_id, cancel) = begin_segmented_job(app, state, started).await?;
+    if let Some(writer) = export_tee {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.export_tees.insert(job_id.clone(), writer);
+    }
+    let app_clone = app.clone();
+    let state_clone = state.clone();
+    let job_id_clone = job_id.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut chunk_index: usize = 0;
+        let mut result: Result<bool> = Ok(false);
+        for segment in segments {
+            if cancel.load(Ordering::SeqCst) {
+                break;
+            }
+            let voice_id = match segment.role {
+                SpeakerRole::Narration => narration_voice_id.clone(),
+                SpeakerRole::Dialogue => dialogue_voice_id.clone(),
+            };
+            match synthesize_voice_pcm(&state_clone, segment.text, voice_id, settings.clone(), cancel.clone(), None).await {
+                Ok((pcm, sample_rate)) => {
+                    if cancel.load(Ordering::SeqCst) || pcm.is_empty() || sample_rate == 0 {
+                        continue;
+                    }
+                    emit_audio_chunk(&app_clone, &job_id_clone, chunk_index, &pcm, sample_rate);
+                    tee_job_audio(&app_clone, &state_clone, &job_id_clone, &pcm, sample_rate);
+                    chunk_index += 1;
+                    result = Ok(true);
+                }
+                Err(err) => {
+                    result = Err(err);
+                    break;
+                }
+            }
+        }
+        finish_segmented_job(&app_clone, &state_clone, &job_id_clone, "Dialogue", result, &cancel);
+    });
+
+    Ok(job_id)
+}
+
+struct DialogueSegments {
+    segments: Vec<DialogueSegment>,
+    narration_voice_id: String,
+    dialogue_voice_id: String,
+    export_tee: Option<StreamingWavWriter>,
+}
+
+struct SegmentedJobStart<'a> {
+    /// Job id prefix and `local://` stream URL host.
+    kind: &'a str,
+    source: &'a str,
+    origin: &'a JobOrigin,
+    capture: CaptureMetadata,
+    text: &'a str,
+    rate: f32,
+}
+
+/// Takes over playback for a locally managed job that synthesizes its text
+/// segment by segment (echo and dialogue reading), and announces it with a
+/// `local://{kind}/...` stream URL.
+async fn begin_segmented_job(
+    app: &AppHandle,
+    state: &Arc<Mutex<EngineState>>,
+    started: SegmentedJobStart<'_>,
+) -> Result<(String, Arc<AtomicBool>)> {
+    let SegmentedJobStart {
+        kind,
+        source,
+        origin,
+        capture,
+        text,
+        rate,
+    } = started;
+    stop_active_echo_job(app, state)?;
+    #[cfg(feature = "build-base")]
+    preempt_active_local_job(app, state).await?;
+
+    let job_id = format!("{kind}-{:016x}", rand::random::<u64>());
+    let cancel = Arc::new(AtomicBool::new(false));
+    {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.last_job_id = Some(job_id.clone());
+        guard.active_echo_job = Some((job_id.clone(), cancel.clone()));
+        guard.suppressed_jobs.remove(&job_id);
+    }
+
+    emit_job_started(
+        app,
+        JobStartedPayload {
+            job_id: job_id.clone(),
+            ws_url: format!("local://{kind}/{job_id}"),
+            source: source.to_string(),
+            source_window: origin.window_title.clone(),
+            rate,
+            capture,
+        },
+        text,
+    );
+    let _ = app.emit_routed(
+        "voicereader:ws-event",
+        json!({ "type": "JOB_STARTED", "job_id": job_id.clone() }),
+    );
+    Ok((job_id, cancel))
+}
+
+/// Emits the terminal event of a segmented job and releases its state.
+/// `result` carries whether any audio was emitted.
+fn finish_segmented_job(
+    app: &AppHandle,
+    state: &Arc<Mutex<EngineState>>,
+    job_id: &str,
+    label: &str,
+    result: Result<bool>,
+    cancel: &AtomicBool,
+) {
+    let canceled = cancel.load(Ordering::SeqCst);
+    match result {
+        Ok(had_audio) if !canceled => {
+            emit_terminal_event(
+                app,
+                json!({ "type": "JOB_DONE", "job_id": job_id, "had_audio": had_audio }),
+            );
+        }
+        Err(err) if !canceled => {
+            emit_terminal_event(
+                app,
+                json!({ "type": "JOB_ERROR", "job_id": job_id, "error": err.to_string() }),
+            );
+            emit_error(app, &format!("{label} job failed: {err:#}"));
+        }
+        _ => {
+            emit_terminal_event(app, json!({ "type": "JOB_CANCELED", "job_id": job_id }));
+        }
+    }
+    if canceled {
+        emit_job_cancel_confirmed(app, job_id, false);
+    }
+    finish_job_tees(app, state, job_id);
+
+    if let Ok(mut guard) = state.lock() {
+        if guard.last_job_id.as_deref() == Some(job_id) {
+            guard.last_job_id = None;
+        }
+        let owns_job = guard
+            .active_echo_job
+            .as_ref()
+            .is_some_and(|(active_job_id, _)| active_job_id == job_id);
+        if owns_job {
+            guard.active_echo_job = None;
+        }
+        guard.suppressed_jobs.job_finished(job_id);
+    }
+}
+
+struct EchoSegments {
+    sentences: Vec<String>,
+    source_voice_id: String,
+    target_voice_id: String,
+    pause_ms: u32,
+}
+
+/// Returns whether any audio was emitted.
+async fn run_echo_job(
+    app: &AppHandle,
+    state: &Arc<Mutex<EngineState>>,
+    job_id: &str,
+    segments: EchoSegments,
+    translation: &TranslationSettings,
+    settings: SpeakSettingsState,
+    cancel: &Arc<AtomicBool>,
+) -> Result<bool> {
+    let mut chunk_index: usize = 0;
+    let mut had_audio = false;
+    for sentence in segments.sentences {
+        let translated = translate_text(translation, &sentence)
+            .await?
+            .unwrap_or_else(|| sentence.clone());
+        for (text, voice_id) in [
+            (sentence, &segments.source_voice_id),
+            (translated, &segments.target_voice_id),
+        ] {
+            if cancel.load(Ordering::SeqCst) {
+                return Ok(had_audio);
+            }
+            let (pcm, sample_rate) =
+                synthesize_voice_pcm(state, text, voice_id.clone(), settings.clone(), cancel.clone(), None).await?;
+            if cancel.load(Ordering::SeqCst) {
+                return Ok(had_audio);
+            }
+            if pcm.is_empty() || sample_rate == 0 {
+                continue;
+            }
+            emit_audio_chunk(app, job_id, chunk_index, &pcm, sample_rate);
+            tee_job_audio(app, state, job_id, &pcm, sample_rate);
+            chunk_index += 1;
+            had_audio = true;
+
+            let pause_samples = (sample_rate as u64 * segments.pause_ms as u64 / 1000) as usize;
+            if pause_samples > 0 {
+                let pause = vec![0i16; pause_samples];
+                emit_audio_chunk(app, job_id, chunk_index, &pause, sample_rate);
+                tee_job_audio(app, state, job_id, &pause, sample_rate);
+                chunk_index += 1;
+            }
+        }
+    }
+    Ok(had_audio)
+}
+
+/// Cancels a running echo job, if any, before another job takes over playback.
+fn stop_active_echo_job(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    let previous = {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        let previous = guard.active_echo_job.take();
+        if let Some((job_id, _)) = previous.as_ref() {
+            guard.suppressed_jobs.suppress(job_id);
+        }
+        previous
+    };
+    if let Some((job_id, cancel)) = previous {
+        cancel.store(true, Ordering::SeqCst);
+        emit_job_cancel_requested(app, &job_id);
+    }
+    Ok(())
+}
+
+/// Blocks the synthesis thread until the player has room for another chunk
+/// of `job_id`, or `cancel` is set.
+#[cfg(feature = "build-base")]
+fn wait_for_audio_credit_blocking(state: &Arc<Mutex<EngineState>>, job_id: &str, cancel: &AtomicBool) {
+    while !cancel.load(Ordering::SeqCst) && !has_audio_credit(state, job_id) {
+        std::thread::sleep(Duration::from_millis(AUDIO_CREDIT_POLL_MS));
+    }
+}
+
+/// Holds the relay until the player has room for another chunk of `job_id`,
+/// or the job is canceled. The sidecar keeps synthesizing meanwhile.
+#[cfg(feature = "build-full")]
+async fn wait_for_audio_credit(state: &Arc<Mutex<EngineState>>, job_id: &str) {
+    while !is_job_suppressed(state, job_id) && !has_audio_credit(state, job_id) {
+        tokio::time::sleep(Duration::from_millis(AUDIO_CREDIT_POLL_MS)).await;
+    }
+}
+
+/// Holds the relay while the job is paused. The engine keeps synthesizing
+/// until the socket backs up; nothing reaches the player meanwhile.
+#[cfg(feature = "build-full")]
+async fn wait_while_paused(state: &Arc<Mutex<EngineState>>, job_id: &str, paused: &AtomicBool) {
+    while paused.load(Ordering::SeqCst) && !is_job_suppressed(state, job_id) {
+        tokio::time::sleep(Duration::from_millis(AUDIO_CREDIT_POLL_MS)).await;
+    }
+}
+
+fn has_audio_credit(state: &Arc<Mutex<EngineState>>, job_id: &str) -> bool {
+    state
+        .lock()
+        .map_or(true, |guard| guard.audio_credits.has_credit(job_id))
+}
+
+fn record_audio_emitted(state: &Arc<Mutex<EngineState>>, job_id: &str, samples: usize, sample_rate: u32) {
+    if let Ok(mut guard) = state.lock() {
+        guard.audio_credits.record_emitted(job_id, samples, sample_rate);
+    }
+}
+
+fn emit_audio_chunk(app: &AppHandle, job_id: &str, chunk_index: usize, pcm: &[i16], sample_rate: u32) {
+    if with_native_player(app, |player| player.play(job_id, pcm, sample_rate)) {
+        return;
+    }
+    let mut event = json!({
+        "type": "AUDIO_CHUNK",
+        "job_id": job_id,
+        "chunk_index": chunk_index,
+        "audio": stream_audio_format(app).audio_payload(pcm, sample_rate),
+    });
+    stamp_audio_chunk(app, job_id, &mut event);
+    let _ = app.emit_routed("voicereader:ws-event", event);
+}
+
+/// Numbers an `AUDIO_CHUNK` event for the webview; see `ChunkJournal`.
+fn stamp_audio_chunk(app: &AppHandle, job_id: &str, event: &mut Value) {
+    if let Ok(mut guard) = app.state::<SharedState>().inner.lock() {
+        guard.chunk_journal.stamp(job_id, event);
+    }
+}
+
+fn stream_audio_format(app: &AppHandle) -> StreamAudioFormat {
+    app.try_state::<SharedState>()
+        .and_then(|state| state.inner.lock().ok().map(|guard| guard.audio_format))
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "build-full")]
+fn has_native_player(state: &Arc<Mutex<EngineState>>) -> bool {
+    state.lock().is_ok_and(|guard| guard.native_player.is_some())
+}
+
+#[cfg(feature = "build-full")]
+fn has_audio_tee(state: &Arc<Mutex<EngineState>>, job_id: &str) -> bool {
+    state
+        .lock()
+        .is_ok_and(|guard| guard.export_tees.contains_key(job_id) || guard.job_sinks.contains_key(job_id))
+}
+
+/// Opens the configured audio sinks and cast target for a starting job. A
+/// sink that cannot be opened is skipped; the job still plays.
+fn open_job_sinks(app: &AppHandle, job_id: &str) {
+    let state = app.state::<SharedState>();
+    let Some((configs, cast_target, virtual_mic)) = state
+        .inner
+        .lock()
+        .ok()
+        .map(|guard| (guard.audio_sinks.sinks.clone(), guard.cast_target.clone(), guard.virtual_mic.clone()))
+    else {
+        return;
+    };
+    let mut sinks = Vec::new();
+    for config in configs {
+        match config.open(job_id) {
+            Ok(sink) => sinks.push(sink),
+            Err(err) => emit_error(app, &format!("Audio sink not opened: {err:#}")),
+        }
+    }
+    if let Some(target) = cast_target {
+        match CastSink::start(&target, job_id) {
+            Ok((sink, url)) => {
+                sinks.push(Box::new(sink));
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(err) = play_url(&Client::new(), &target, &url).await {
+                        emit_error(&app, &format!("Cast to {} failed: {err:#}", target.name));
+                    }
+                });
+            }
+            Err(err) => emit_error(app, &format!("Cast to {} not started: {err:#}", target.name)),
+        }
+    }
+    if let Some(device) = virtual_mic {
+        match VirtualMicSink::open(&device) {
+            Ok(sink) => sinks.push(Box::new(sink)),
+            Err(err) => emit_error(app, &format!("Virtual microphone not opened: {err:#}")),
+        }
+    }
+    if !sinks.is_empty() {
+        if let Ok(mut guard) = state.inner.lock() {
+            guard.job_sinks.insert(job_id.to_string(), sinks);
+        }
+    }
+}
+
+/// Copies played audio to the job's export file and audio sinks. A failing
+/// sink is dropped; playback and the other sinks carry on.
+fn tee_job_audio(app: &AppHandle, state: &Arc<Mutex<EngineState>>, job_id: &str, pcm: &[i16], sample_rate: u32) {
+    let (failed_export, failed_sinks) = {
+        let Ok(mut guard) = state.lock() else {
+            return;
+        };
+        let failed_export = match guard.export_tees.get_mut(job_id).map(|writer| writer.append(pcm, sample_rate)) {
+            Some(Err(err)) => guard.export_tees.remove(job_id).map(|writer| (writer, err)),
+            _ => None,
+        };
+        let mut failed_sinks = Vec::new();
+        if let Some(sinks) = guard.job_sinks.get_mut(job_id) {
+            let mut index = 0;
+            while index < sinks.len() {
+                match sinks[index].write(pcm, sample_rate) {
+                    Ok(()) => index += 1,
+                    Err(err) => failed_sinks.push((sinks.remove(index), err)),
+                }
+            }
+        }
+        (failed_export, failed_sinks)
+    };
+    if let Some((writer, err)) = failed_export {
+        let path = writer.path().display().to_string();
+        let _ = writer.finish();
+        emit_error(app, &format!("Export to {path} stopped: {err:#}"));
+    }
+    for (mut sink, err) in failed_sinks {
+        let label = sink.label();
+        let _ = sink.abort();
+        emit_error(app, &format!("Audio sink {label} stopped: {err:#}"));
+    }
+}
+
+/// Completes the job's export file, if it has one, with whatever audio was
+/// played; a canceled read leaves a shorter but valid file. Closes the job's
+/// audio sinks too: a canceled job's sinks are aborted, and sinks still
+/// playing are kept until they are done.
+fn finish_job_tees(app: &AppHandle, state: &Arc<Mutex<EngineState>>, job_id: &str) {
+    let (writer, sinks, canceled) = match state.lock() {
+        Ok(mut guard) => (
+            guard.export_tees.remove(job_id),
+            guard.job_sinks.remove(job_id),
+            guard.suppressed_jobs.is_suppressed(job_id),
+        ),
+        Err(_) => return,
+    };
+    let mut playing = Vec::new();
+    for mut sink in sinks.unwrap_or_default() {
+        let label = sink.label();
+        let result = if canceled { sink.abort() } else { sink.finish() };
+        if let Err(err) = result {
+            emit_error(app, &format!("Failed to finish audio sink {label}: {err:#}"));
+        }
+        if sink.is_playing() {
+            playing.push((job_id.to_string(), sink));
+        }
+    }
+    if let Ok(mut guard) = state.lock() {
+        guard.draining_sinks.retain(|(_, sink)| sink.is_playing());
+        guard.draining_sinks.extend(playing);
+    }
+    let Some(writer) = writer else {
+        return;
+    };
+    let payload = ExportFinishedPayload {
+        job_id: job_id.to_string(),
+        path: writer.path().to_string_lossy().to_string(),
+        duration_ms: writer.duration_ms(),
+        tagged_synthetic: writer.tagged_synthetic(),
+    };
+    match writer.finish() {
+        Ok(()) => {
+            let _ = app.emit_routed("voicereader:export-finished", payload);
+        }
+        Err(err) => emit_error(app, &format!("Failed to finish export to {}: {err:#}", payload.path)),
+    }
+}
+
+/// Stops the audio sinks of `job_id`, or of every job, whether the job is
+/// still generating or its sinks are playing out what they queued.
+fn abort_job_sinks(app: &AppHandle, job_id: Option<&str>) {
+    let Some(state) = app.try_state::<SharedState>() else {
+        return;
+    };
+    let sinks = {
+        let Ok(mut guard) = state.inner.lock() else {
+            return;
+        };
+        let mut sinks: Vec<Box<dyn AudioSink>> = match job_id {
+            Some(job_id) => guard.job_sinks.remove(job_id).unwrap_or_default(),
+            None => guard.job_sinks.drain().flat_map(|(_, sinks)| sinks).collect(),
+        };
+        let (aborted, kept) = std::mem::take(&mut guard.draining_sinks)
+            .into_iter()
+            .partition(|(owner, _)| job_id.is_none_or(|job_id| owner == job_id));
+        guard.draining_sinks = kept;
+        sinks.extend(aborted.into_iter().map(|(_, sink)| sink));
+        sinks
+    };
+    for mut sink in sinks {
+        if let Err(err) = sink.abort() {
+            emit_error(app, &format!("Failed to stop audio sink {}: {err:#}", sink.label()));
+        }
+    }
+}
+
+/// Text sources that commands and hotkeys can read from.
+fn text_source_registry() -> TextSourceRegistry {
+    let mut registry = TextSourceRegistry::with_standard_sources();
+    registry.register(SelectionSource);
+    registry.register(WindowOcrSource);
+    registry
+}
+
+/// Takes text from the named source; `None` when it had nothing to read.
+async fn acquire_text(app: &AppHandle, source: &str, argument: Option<&str>) -> Result<Option<AcquiredText>> {
+    app.state::<TextSourceRegistry>().acquire(app, source, argument).await
+}
+
+/// The selection of the focused app, through UI Automation on Windows, the
+/// PRIMARY selection on Linux, or else a simulated copy.
+struct SelectionSource;
+
+impl TextSource for SelectionSource {
+    fn name(&self) -> &'static str {
+        SELECTION_SOURCE
+    }
+
+    fn acquire<'a>(&'a self, app: &'a AppHandle, _argument: Option<&'a str>) -> BoxFuture<'a, Result<Option<AcquiredText>>> {
+        Box::pin(async move {
+            Ok(capture_selection_or_notify(app)
+                .await
+                .map(|(text, origin)| AcquiredText { text, origin }))
+        })
+    }
+}
+
+/// The main content of the focused window, recognized from a screenshot.
+struct WindowOcrSource;
+
+impl TextSource for WindowOcrSource {
+    fn name(&self) -> &'static str {
+        WINDOW_OCR_SOURCE
+    }
+
+    fn acquire<'a>(&'a self, app: &'a AppHandle, _argument: Option<&'a str>) -> BoxFuture<'a, Result<Option<AcquiredText>>> {
+        Box::pin(async move {
+            let origin = foreground_origin(CaptureMethod::Ocr);
+            let window = tauri::async_runtime::spawn_blocking(recognize_focused_window)
+                .await
+                .map_err(|err| anyhow!("Window OCR task failed: {err}"))??;
+            let Some(text) = window.main_content() else {
+                let _ = app.emit_routed(
+                    "voicereader:selection-empty",
+                    json!({ "reason": "no_window_text_recognized" }),
+                );
+                return Ok(None);
+            };
+            Ok(Some(AcquiredText { text, origin }))
+        })
+    }
+}
+
+/// Captures the current selection along with where it came from, or
+/// emits `voicereader:selection-empty` and returns `None`. Which methods
+/// were tried and whether they found text goes into the capture stats.
+///
+/// Nothing is captured from a focused password field: no method runs, so
+/// the clipboard probe does not copy it either, and
+/// `voicereader:secure-field` is emitted instead.
+async fn capture_selection_or_notify(app: &AppHandle) -> Option<(String, JobOrigin)> {
+    let app_name = get_foreground_app_name();
+    if focus_is_secure().await {
+        let _ = app.emit_routed(
+            "voicereader:secure-field",
+            json!({ "reason": "secure_field", "app_name": app_name }),
+        );
+        return None;
+    }
+    let methods = match app.state::<SharedState>().inner.lock() {
+        Ok(guard) => guard.capture_overrides.methods_for(app_name.as_deref()),
+        Err(_) => default_capture_order(),
+    };
+    let mut attempts = Vec::new();
+    let captured = capture_selection(app, &methods, &mut attempts).await;
+    if let Ok(mut guard) = app.state::<SharedState>().inner.lock() {
+        guard.capture_stats.record(app_name.as_deref(), &attempts);
+    }
+    if captured.is_none() {
+        let _ = app.emit_routed(
+            "voicereader:selection-empty",
+            json!({ "reason": "no_selection_detected" }),
+        );
+    }
+    captured
+}
+
+/// Tries `methods` in order until one finds text, noting in `attempts` the
+/// ones tried and whether each did.
+async fn capture_selection(
+    app: &AppHandle,
+    methods: &[CaptureMethod],
+    attempts: &mut Vec<(CaptureMethod, bool)>,
+) -> Option<(String, JobOrigin)> {
+    for method in methods {
+        // Capture source window before simulated Ctrl+C changes focus state.
+        let origin = foreground_origin(*method);
+        let text = capture_selection_with(app, *method).await;
+        attempts.push((*method, text.is_some()));
+        if let Some(text) = text {
+            return Some((text, origin));
+        }
+    }
+    None
+}
+
+/// The selection as one capture method sees it. By default, on Linux the
+/// selection is usually already in PRIMARY and on Windows UI Automation reads
+/// it without touching the clipboard or racing the hotkey's modifiers; the
+/// clipboard probe covers apps that lack either.
+async fn capture_selection_with(app: &AppHandle, method: CaptureMethod) -> Option<String> {
+    match method {
+        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+        CaptureMethod::PrimarySelection => tauri::async_runtime::spawn_blocking(read_primary_selection)
+            .await
+            .ok()
+            .flatten(),
+        #[cfg(target_os = "windows")]
+        CaptureMethod::Accessibility => tokio::time::timeout(
+            Duration::from_millis(SELECTION_ACCESSIBILITY_TIMEOUT_MS),
+            tauri::async_runtime::spawn_blocking(focused_selection),
+        )
+        .await
+        .ok()
+        .and_then(Result::ok)
+        .flatten(),
+        CaptureMethod::ClipboardProbe => capture_selected_text_from_active_app(app).await,
+        _ => None,
+    }
+}
+
+/// A target app that does not answer in time counts as not secure, like a
+/// platform without the accessibility API.
+async fn focus_is_secure() -> bool {
+    tokio::time::timeout(
+        Duration::from_millis(SELECTION_ACCESSIBILITY_TIMEOUT_MS),
+        tauri::async_runtime::spawn_blocking(focused_is_secure),
+    )
+    .await
+    .ok()
+    .and_then(Result::ok)
+    .unwrap_or(false)
+}
+
+/// The foreground window and application, as the source of captured text.
+fn foreground_origin(capture_method: CaptureMethod) -> JobOrigin {
+    JobOrigin {
+        window_title: get_foreground_window_title().unwrap_or_default(),
+        app_name: get_foreground_app_name(),
+        capture_method,
+        location: None,
+    }
+}
+
+async fn announce_focus_inner(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    ensure_engine_ready(app, state).await?;
+
+    let origin = foreground_origin(CaptureMethod::Accessibility);
+    let context = query_focus_context(Some(origin.window_title.clone()).filter(|title| !title.is_empty()));
+    let summary = context
+        .spoken_summary()
+        .ok_or_else(|| anyhow!("No focused window or control information is available"))?;
+
+    let _ = enqueue_read(
+        app,
+        state,
+        QueuedRead {
+            text: summary,
+            priority: ReadPriority::Interactive,
+            source: "hotkey_focus_summary".to_string(),
+            origin,
+            voice_id: None,
+            rate: None,
+            preprocessed: false,
+            expand_placeholders: false,
+            reading_order: false,
+        },
+    )
+    .await?;
+    Ok(())
+}
+
+/// Reads the main content of the focused window, recognized from a
+/// screenshot, for apps that expose neither a selection nor accessible text.
+/// Speaks a summary of the selection from the configured summary provider.
+/// Selections shorter than the summary threshold are read in full.
+async fn summarize_selection_inner(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    let summary = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.summary.clone()
+    };
+    if !summary.is_enabled() {
+        return Err(anyhow!("Summaries need a summary provider. Configure one in summary settings."));
+    }
+    ensure_engine_ready(app, state).await?;
+
+    let Some(AcquiredText { text, origin }) = acquire_text(app, SELECTION_SOURCE, None).await? else {
+        return Ok(());
+    };
+    let (text, source) = match summarize_text(&summary, &text).await? {
+        Some(summarized) => (summarized, "hotkey_summary"),
+        None => (text, "hotkey_selection_capture"),
+    };
+
+    let _ = speak_and_stream(app, state, text, source, &origin).await?;
+    Ok(())
+}
+
+async fn read_clipboard_inner(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    ensure_engine_ready(app, state).await?;
+
+    let acquired = acquire_text(app, CLIPBOARD_SOURCE, None).await?;
+    let Some(AcquiredText { text, origin }) = acquired.filter(|acquired| !acquired.text.trim().is_empty()) else {
+        let _ = app.emit_routed(
+            "voicereader:selection-empty",
+            json!({ "reason": "clipboard_empty" }),
+        );
+        return Ok(());
+    };
+
+    let _ = speak_and_stream(app, state, text, "hotkey_read_clipboard", &origin).await?;
+    Ok(())
+}
+
+async fn read_window_inner(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    ensure_engine_ready(app, state).await?;
+
+    let Some(AcquiredText { text, origin }) = acquire_text(app, WINDOW_OCR_SOURCE, None).await? else {
+        return Ok(());
+    };
+
+    let _ = speak_and_stream(app, state, text, "hotkey_read_window", &origin).await?;
+    Ok(())
+}
+
+async fn capture_selected_text_from_active_app(app: &AppHandle) -> Option<String> {
+    let previous_clipboard = app.clipboard_manager().read_text().ok().flatten();
+    let probe_clipboard_value = build_selection_probe_value();
+    let probe_set = app
+        .clipboard_manager()
+        .write_text(probe_clipboard_value.clone())
+        .is_ok();
+
+    // Hotkey callback can run while Ctrl/Shift is still physically down.
+    // Wait briefly so simulated copy does not become Ctrl+Shift+C in target apps.
+    wait_for_hotkey_modifiers_release().await;
+
+    if !trigger_system_copy_shortcut() {
+        restore_clipboard_text(app, previous_clipboard, probe_set);
+        return None;
+    }
+
+    let previous_trimmed = previous_clipboard.as_deref().map(str::trim);
+    let started = Instant::now();
+    let mut captured: Option<String> = None;
+
+    while started.elapsed() < Duration::from_millis(SELECTION_COPY_TIMEOUT_MS) {
+        let current_clipboard = app.clipboard_manager().read_text().ok().flatten();
+        let normalized_current = normalized_clipboard_text(current_clipboard);
+        if let Some(current_text) = normalized_current {
+            let changed = if probe_set {
+                current_text != probe_clipboard_value
+            } else {
+                previous_trimmed.map_or(true, |prev| current_text != prev)
+            };
+            if changed {
+                captured = Some(current_text);
+                break;
+            }
+        }
+        sleep(Duration::from_millis(SELECTION_COPY_POLL_MS)).await;
+    }
+
+    restore_clipboard_text(app, previous_clipboard, probe_set);
+
+    captured
+}
+
+fn normalized_clipboard_text(raw: Option<String>) -> Option<String> {
+    raw.map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn restore_clipboard_text(app: &AppHandle, previous_clipboard: Option<String>, probe_set: bool) {
+    if let Some(previous_text) = previous_clipboard {
+        let _ = app.clipboard_manager().write_text(previous_text);
+    } else if probe_set {
+        // We temporarily set a probe value; clear it back to empty when clipboard had no text before.
+        let _ = app.clipboard_manager().write_text(String::new());
+    }
+}
+
+fn build_selection_probe_value() -> String {
+    let suffix: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(12)
+        .map(char::from)
+        .collect();
+    format!("__voicereader_selection_probe_{suffix}__")
+}
+
+async fn wait_for_hotkey_modifiers_release() {
+    let started = Instant::now();
+    while started.elapsed() < Duration::from_millis(HOTKEY_MODIFIER_RELEASE_TIMEOUT_MS) {
+        if !hotkey_modifiers_pressed() {
+            return;
+        }
+        sleep(Duration::from_millis(HOTKEY_MODIFIER_RELEASE_POLL_MS)).await;
+    }
+}
+
+fn hotkey_modifiers_pressed() -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        return hotkey_modifiers_pressed_windows();
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        return hotkey_modifiers_pressed_macos();
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    {
+        false
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn hotkey_modifiers_pressed_windows() -> bool {
+    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{
+        GetAsyncKeyState, VK_CONTROL, VK_LWIN, VK_MENU, VK_RWIN, VK_SHIFT,
+    };
+
+    fn is_pressed(vk: i32) -> bool {
+        unsafe { (GetAsyncKeyState(vk) as u16 & 0x8000) != 0 }
+    }
+
+    is_pressed(VK_CONTROL as i32)
+        || is_pressed(VK_SHIFT as i32)
+        || is_pressed(VK_MENU as i32)
+        || is_pressed(VK_LWIN as i32)
+        || is_pressed(VK_RWIN as i32)
+}
+
+fn trigger_system_copy_shortcut() -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        return trigger_copy_shortcut_windows();
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        return trigger_copy_shortcut_macos();
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    {
+        send_copy_shortcut()
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn trigger_copy_shortcut_windows() -> bool {
+    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{
+        SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP, VK_CONTROL,
+    };
+
+    const KEY_C: u16 = 0x43;
+
+    fn keyboard_input(vk: u16, flags: u32) -> INPUT {
+        INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT {
+                    wVk: vk,
+                    wScan: 0,
+                    dwFlags: flags,
+                    time: 0,
+                    dwExtraInfo: 0,
+                },
+            },
+        }
+    }
+
+    let inputs = [
+        keyboard_input(VK_CONTROL as u16, 0),
+        keyboard_input(KEY_C, 0),
+        keyboard_input(KEY_C, KEYEVENTF_KEYUP),
+        keyboard_input(VK_CONTROL as u16, KEYEVENTF_KEYUP),
+    ];
+
+    let sent = unsafe {
+        SendInput(
+            inputs.len() as u32,
+            inputs.as_ptr(),
+            std::mem::size_of::<INPUT>() as i32,
+        )
+    };
+
+    sent == inputs.len() as u32
+}
+
+#[cfg(target_os = "macos")]
+fn hotkey_modifiers_pressed_macos() -> bool {
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+    use core_graphics::event::CGEventFlags;
+
+    let source_state = CGEventSourceStateID::CombinedSessionState;
+    let flags = CGEventSource::flags_state(source_state);
+
+    flags.contains(CGEventFlags::CGEventFlagCommand)
+        || flags.contains(CGEventFlags::CGEventFlagShift)
+        || flags.contains(CGEventFlags::CGEventFlagControl)
+        || flags.contains(CGEventFlags::CGEventFlagAlternate)
+}
+
+#[cfg(target_os = "macos")]
+fn trigger_copy_shortcut_macos() -> bool {
+    use core_graphics::event::{CGEvent, CGEventFlags, CGEventTapLocation, CGKeyCode};
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    // Virtual key code for 'C' on macOS.
+    const VK_C: CGKeyCode = 0x08;
+
+    let source = match CGEventSource::new(CGEventSourceStateID::HIDSystemState) {
+        Ok(src) => src,
+        Err(_) => return false,
+    };
+
+    let key_down = match CGEvent::new_keyboard_event(source.clone(), VK_C, true) {
+        Ok(evt) => evt,
+        Err(_) => return false,
+    };
+    let key_up = match CGEvent::new_keyboard_event(source, VK_C, false) {
+        Ok(evt) => evt,
+        Err(_) => return false,
+    };
+
+    // Hold Cmd while pressing C (simulates Cmd+C).
+    key_down.set_flags(CGEventFlags::CGEventFlagCommand);
+    key_up.set_flags(CGEventFlags::CGEventFlagCommand);
+
+    key_down.post(CGEventTapLocation::HID);
+    key_up.post(CGEventTapLocation::HID);
+
+    true
+}
+
+#[cfg(target_os = "macos")]
+fn get_frontmost_app_name_macos() -> Option<String> {
+    use objc::{class, msg_send, sel, sel_impl};
+    use objc::runtime::Object;
+    use core_foundation::string::CFString;
+    use core_foundation::base::{CFType, TCFType};
+
+    unsafe {
+        let workspace: *mut Object = msg_send![class!(NSWorkspace), sharedWorkspace];
+        if workspace.is_null() {
+            return None;
+        }
+        let app: *mut Object = msg_send![workspace, frontmostApplication];
+        if app.is_null() {
+            return None;
+        }
+        let name: *mut Object = msg_send![app, localizedName];
+        if name.is_null() {
+            return None;
+        }
+
+        // NSString -> CFString -> Rust String
+        let cf_str = CFString::wrap_under_get_rule(name as *const _);
+        let result = cf_str.to_string();
+        if result.is_empty() {
+            None
+        } else {
+            Some(result)
+        }
+    }
+}
+
+/// Runs maintenance once in the background after the engine first starts.
+fn spawn_startup_maintenance(state: Arc<Mutex<EngineState>>) {
+    let Some(data_dir) = state
+        .lock()
+        .ok()
+        .map(|guard| PathBuf::from(&guard.data_dir))
+        .filter(|dir| !dir.as_os_str().is_empty())
+    else {
+        return;
+    };
+    tauri::async_runtime::spawn_blocking(move || match run_storage_maintenance(&data_dir) {
+        Ok(report) if report.removed_count() > 0 || !report.errors.is_empty() => eprintln!(
+            "Startup maintenance removed {} item(s) under {}; {} could not be removed",
+            report.removed_count(),
+            data_dir.display(),
+            report.errors.len()
+        ),
+        Ok(_) => {}
+        Err(err) => eprintln!("Startup maintenance failed: {err:#}"),
+    });
+}
+
+fn storage_target_path(state: &EngineState, target: StorageTarget) -> PathBuf {
+    // Empty until the engine has started; keep it empty rather than relative.
+    let under_data_dir = |name: &str| {
+        if state.data_dir.is_empty() {
+            PathBuf::new()
+        } else {
+            PathBuf::from(&state.data_dir).join(name)
+        }
+    };
+    match target {
+        StorageTarget::DataDir => PathBuf::from(&state.data_dir),
+        StorageTarget::ModelsDir => PathBuf::from(&state.models_dir),
+        StorageTarget::HfCacheDir => PathBuf::from(&state.hf_cache_dir),
+        StorageTarget::VoicesDir => under_data_dir("voices"),
+        StorageTarget::Logs => under_data_dir("logs"),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn reveal_in_file_manager(path: &Path) -> Result<()> {
+    // explorer.exe exits with 1 even on success, so only a spawn failure counts.
+    std::process::Command::new("explorer")
+        .arg(path)
+        .spawn()
+        .context("Failed to launch Explorer")?;
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn reveal_in_file_manager(path: &Path) -> Result<()> {
+    let status = std::process::Command::new("open")
+        .arg(path)
+        .status()
+        .context("Failed to run open")?;
+    if !status.success() {
+        return Err(anyhow!("open exited with {status} for {}", path.display()));
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn reveal_in_file_manager(path: &Path) -> Result<()> {
+    // xdg-open may block until the file manager exits, so do not wait on it.
+    std::process::Command::new("xdg-open")
+        .arg(path)
+        .spawn()
+        .context("Failed to launch xdg-open")?;
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn get_foreground_window_title() -> Option<String> {
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        GetForegroundWindow, GetWindowTextLengthW, GetWindowTextW,
+    };
+
+    let hwnd = unsafe { GetForegroundWindow() };
+    if hwnd.is_null() {
+        return None;
+    }
+
+    let title_len = unsafe { GetWindowTextLengthW(hwnd) };
+    if title_len <= 0 {
+        return None;
+    }
+
+    let mut title_buf = vec![0u16; title_len as usize + 1];
+    let written = unsafe { GetWindowTextW(hwnd, title_buf.as_mut_ptr(), title_buf.len() as i32) };
+    if written <= 0 {
+        return None;
+    }
+
+    let title = String::from_utf16_lossy(&title_buf[..written as usize])
+        .trim()
+        .to_string();
+    if title.is_empty() {
+        return None;
+    }
+    Some(title)
+}
+
+#[cfg(target_os = "macos")]
+fn get_foreground_window_title() -> Option<String> {
+    get_frontmost_app_name_macos()
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn get_foreground_window_title() -> Option<String> {
+    None
+}
+
+/// Executable name (without extension) of the process owning the foreground window.
+#[cfg(target_os = "windows")]
+fn get_foreground_app_name() -> Option<String> {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+    use windows_sys::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId};
+
+    let hwnd = unsafe { GetForegroundWindow() };
+    if hwnd.is_null() {
+        return None;
+    }
+    let mut pid = 0u32;
+    unsafe { GetWindowThreadProcessId(hwnd, &mut pid) };
+    if pid == 0 {
+        return None;
+    }
+    let process = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid) };
+    if process.is_null() {
+        return None;
+    }
+    let mut path_buf = vec![0u16; 1024];
+    let mut path_len = path_buf.len() as u32;
+    let queried = unsafe { QueryFullProcessImageNameW(process, PROCESS_NAME_WIN32, path_buf.as_mut_ptr(), &mut path_len) };
+    unsafe { CloseHandle(process) };
+    if queried == 0 {
+        return None;
+    }
+    let path = String::from_utf16_lossy(&path_buf[..path_len as usize]);
+    Path::new(&path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+#[cfg(target_os = "macos")]
+fn get_foreground_app_name() -> Option<String> {
+    get_frontmost_app_name_macos()
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn get_foreground_app_name() -> Option<String> {
+    None
+}
+
+async fn speak_and_stream(
+    app: &AppHandle,
+    state: &Arc<Mutex<EngineState>>,
+    text: String,
+    source: &str,
+    origin: &JobOrigin,
+) -> Result<String> {
+    speak_and_stream_with_overrides(app, state, text, source, origin, &SpeakOverrides::default()).await
+}
+
+/// Same as `speak_and_stream`, but `overrides` replace the configured voice,
+/// rate and volume for this job only (skim reads, scripted calls).
+async fn speak_and_stream_with_overrides(
+    app: &AppHandle,
+    state: &Arc<Mutex<EngineState>>,
+    text: String,
+    source: &str,
+    origin: &JobOrigin,
+    overrides: &SpeakOverrides,
+) -> Result<String> {
+    let capture = origin.metadata(&text);
+    let mut trimmed = text.trim().to_string();
+    if overrides.expand_placeholders {
+        let clipboard = trimmed
+            .contains(CLIPBOARD_PLACEHOLDER)
+            .then(|| app.clipboard_manager().read_text().ok().flatten())
+            .flatten();
+        trimmed = expand_placeholders(&trimmed, &chrono::Local::now(), clipboard.as_deref())
+            .trim()
+            .to_string();
+    }
+    if trimmed.is_empty() {
+        return Err(anyhow!("Speak text cannot be empty"));
+    }
+    if source.starts_with(HOTKEY_SOURCE_PREFIX) {
+        apply_quiet_hours(app, state, &trimmed, source, origin, overrides)?;
+    }
+
+    let (voice_id, selected_model, mut settings, reading_order, translation, dialogue, mut number_format, acronyms, lexicon) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (
+            guard.selected_voice_id.clone(),
+            guard.selected_model.clone(),
+            guard.speak_settings.clone(),
+            guard.reading_order,
+            guard.translation.clone(),
+            guard.dialogue_settings.clone(),
+            guard.number_format.clone(),
+            guard.acronyms.clone(),
+            guard.lexicon.clone(),
+        )
+    };
+    overrides
+        .validate(&selected_model)
+        .map_err(|err| err.context(CodedError::new(ErrorCode::InvalidArgument, "Invalid speak options")))?;
+    let mut language = overrides
+        .language
+        .as_deref()
+        .map(str::trim)
+        .filter(|language| !language.is_empty())
+        .map(str::to_string);
+    let hinted_numbers = language.as_ref().map(|language| NumberSettings {
+        locale: Some(language.clone()),
+        ..number_format.clone()
+    });
+    if let Some(hinted_numbers) = hinted_numbers.filter(|settings| settings.validate().is_ok()) {
+        number_format = hinted_numbers;
+    }
+    let (trimmed, voice_id) = if overrides.preprocessed {
+        (trimmed, voice_id)
+    } else {
+        // Numbers are read in the source text's conventions, so before translation.
+        let trimmed = normalize_numbers(&trimmed, &number_format);
+        let (translated, voice_id) = translate_for_speech(&translation, trimmed.clone(), voice_id).await?;
+        // Translated text is no longer in the hinted language.
+        if translated != trimmed {
+            language = None;
+        }
+        let ordered = if overrides.reading_order {
+            apply_reading_order(&translated, reading_order)
+        } else {
+            translated
+        };
+        let respelled = apply_lexicon(&ordered, &lexicon, respelling_backend(&selected_model));
+        (spell_acronyms(&respelled, &acronyms), voice_id)
+    };
+    // An explicit voice override reads everything with that voice.
+    let dialogue_voice_id = dialogue
+        .dialogue_voice_id
+        .filter(|_| dialogue.enabled && overrides.voice_id.is_none());
+    let voice_id = overrides
+        .voice_id
+        .as_deref()
+        .map(|id| id.trim().to_string())
+        .unwrap_or(voice_id);
+    if let Some(rate) = overrides.rate {
+        settings.rate = clamp_speak_rate(rate);
+    }
+    if let Some(volume) = overrides.volume {
+        settings.volume = volume;
+    }
+
+    if selected_model != MODEL_CUSTOM && selected_model != MODEL_KYUTAI {
+        return Err(anyhow!(
+            "Current model mode ({selected_model}) is not enabled for read-aloud yet. Switch to qwen_custom_voice or kyutai_pocket_tts."
+        ));
+    }
+    // Opened before the job starts so a bad path fails the request instead of the job.
+    let export_tee = overrides
+        .export_tee
+        .as_ref()
+        .map(|tee| {
+            let provenance = tee
+                .tag_synthetic
+                .then(|| SyntheticProvenance::new(&selected_model, &voice_id));
+            StreamingWavWriter::create(&tee.path, provenance)
+        })
+        .transpose()?;
+    stop_active_echo_job(app, state)?;
+
+    if let Some(dialogue_voice_id) = dialogue_voice_id {
+        let segments = split_dialogue(&trimmed);
+        if segments.iter().any(|segment| segment.role == SpeakerRole::Dialogue) {
+            let dialogue = DialogueSegments {
+                segments,
+                narration_voice_id: dialogue.narration_voice_id.unwrap_or(voice_id),
+                dialogue_voice_id,
+                export_tee,
+            };
+            return speak_dialogue(app, state, dialogue, settings, source, origin, capture).await;
+        }
+    }
+
+    #[cfg(feature = "build-base")]
+    {
+        if selected_model != MODEL_KYUTAI {
+            return Err(anyhow!(
+                "Base build supports Kyutai Pocket TTS only. Switch model to kyutai_pocket_tts."
+            ));
+        }
+
+        let local_runtime = {
+            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            guard
+                .local_kyutai
+                .clone()
+                .ok_or_else(|| anyhow!("Kyutai Rust runtime is not initialized"))?
+        };
+        let selected_preset = {
+            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            guard.selected_kyutai_voice.clone()
+        };
+
+        preempt_active_local_job(app, state).await?;
+
+        let job_id = Uuid::new_v4().to_string();
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let active_rate_steps = Arc::new(AtomicU32::new(rate_to_steps(settings.rate)));
+        let stream_settings = local_stream_settings(&settings, language);
+        {
+            let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            guard.last_job_id = Some(job_id.clone());
+            guard.active_cancel_flag = Some(cancel_flag.clone());
+            guard.active_rate_steps = Some(active_rate_steps.clone());
+            guard.active_volume = Some(stream_settings.volume.clone());
+            guard.active_pause_flag = Some(stream_settings.paused.clone());
+            guard.read_progress = Some(ReadProgress::new(
+                &job_id,
+                &trimmed,
+                &voice_id,
+                overrides.rate,
+                source,
+                origin,
+            ));
+            guard.job_registry.register(&job_id, &trimmed, &voice_id, overrides.rate, origin);
+            guard.suppressed_jobs.remove(&job_id);
+            if let Some(writer) = export_tee {
+                guard.export_tees.insert(job_id.clone(), writer);
+            }
+        }
+
+        emit_job_started(
+            app,
+            JobStartedPayload {
+                job_id: job_id.clone(),
+                ws_url: format!("local://stream/{job_id}"),
+                source: source.to_string(),
+                source_window: origin.window_title.clone(),
+                rate: settings.rate,
+                capture,
+            },
+            &trimmed,
+        );
+
+        let app_clone = app.clone();
+        let state_clone = state.clone();
+        let job_id_clone = job_id.clone();
+        let owned_cancel_flag = cancel_flag.clone();
+        let job_task = tauri::async_runtime::spawn_blocking(move || {
+            let stream_result: Result<bool> = (|| {
+                let mut runtime = local_runtime
+                    .lock()
+                    .map_err(|_| anyhow!("Kyutai runtime lock poisoned"))?;
+                let _ = app_clone.emit_routed(
+                    "voicereader:ws-event",
+                    json!({
+                        "type": "JOB_STARTED",
+                        "job_id": job_id_clone.clone(),
+                    }),
+                );
+
+                // had_audio is now returned from stream_synthesize rather than
+                // captured via &mut in the closure, so the closure is Fn + Send + 'static.
+                // The 'static bound requires the closure to own its captures, so we move
+                // dedicated clones in rather than borrowing the outer locals.
+                let app_for_chunk = app_clone.clone();
+                let state_for_chunk = state_clone.clone();
+                let job_id_for_chunk = job_id_clone.clone();
+                let cancel_for_chunk = cancel_flag.clone();
+                let held_for_chunk = stream_settings.held_micros.clone();
+                let started = Instant::now();
+                let emitted_samples = Arc::new(AtomicU64::new(0));
+                let emitted_rate = Arc::new(AtomicU32::new(0));
+                let (samples_for_chunk, rate_for_chunk) = (emitted_samples.clone(), emitted_rate.clone());
+                let (stream_end, had_audio) = runtime.stream_synthesize(
+                    &voice_id,
+                    &selected_preset,
+                    &trimmed,
+                    &stream_settings,
+                    &cancel_flag,
+                    &active_rate_steps,
+                    move |chunk_index, text_offset, pcm, sample_rate| {
+                        samples_for_chunk.fetch_add(pcm.len() as u64, Ordering::Relaxed);
+                        rate_for_chunk.store(sample_rate, Ordering::Relaxed);
+                        pace_teleprompter(&state_for_chunk, &job_id_for_chunk, text_offset);
+                        record_read_progress(&state_for_chunk, &job_id_for_chunk, text_offset, pcm.len(), sample_rate);
+                        emit_audio_chunk(&app_for_chunk, &job_id_for_chunk, chunk_index, pcm, sample_rate);
+                        tee_job_audio(&app_for_chunk, &state_for_chunk, &job_id_for_chunk, pcm, sample_rate);
+                        record_audio_emitted(&state_for_chunk, &job_id_for_chunk, pcm.len(), sample_rate);
+                        let waited = Instant::now();
+                        wait_for_audio_credit_blocking(&state_for_chunk, &job_id_for_chunk, &cancel_for_chunk);
+                        held_for_chunk.fetch_add(waited.elapsed().as_micros() as u64, Ordering::Relaxed);
+                        Ok(())
+                    },
+                )?;
+
+                let sample_rate = emitted_rate.load(Ordering::Relaxed);
+                if matches!(stream_end, LocalJobEndState::Done) && sample_rate > 0 {
+                    let audio_secs = emitted_samples.load(Ordering::Relaxed) as f64 / sample_rate as f64
+                        * settings.rate as f64;
+                    // Time held by pauses or waiting on playback is not synthesis time.
+                    let held = Duration::from_micros(stream_settings.held_micros.load(Ordering::Relaxed));
+                    if let Ok(mut guard) = state_clone.lock() {
+                        guard
+                            .synthesis_metrics
+                            .record(MODEL_KYUTAI, started.elapsed().saturating_sub(held).as_secs_f64(), audio_secs);
+                    }
+                }
+
+                let terminal = match stream_end {
+                    LocalJobEndState::Done => "JOB_DONE",
+                    LocalJobEndState::Canceled => "JOB_CANCELED",
+                };
+
+                let mut event = json!({
+                    "type": terminal,
+                    "job_id": job_id_clone.clone(),
+                    "had_audio": had_audio,
+                });
+                let limiter_report = stream_settings.limiter_report.lock().ok().and_then(|report| *report);
+                if let (LocalJobEndState::Done, Some(report)) = (&stream_end, limiter_report) {
+                    event["metrics"] = json!({ "limiter": report });
+                }
+                emit_terminal_event(&app_clone, event);
+                Ok(matches!(stream_end, LocalJobEndState::Done))
+            })();
+
+            let completed = stream_result.as_ref().is_ok_and(|done| *done);
+            if let Err(err) = stream_result {
+                emit_terminal_event(
+                    &app_clone,
+                    json!({
+                        "type": "JOB_ERROR",
+                        "job_id": job_id_clone.clone(),
+                        "error": err.to_string(),
+                    }),
+                );
+                emit_error(&app_clone, &format!("Local Kyutai stream failed: {err:#}"));
+            }
+            finish_job_tees(&app_clone, &state_clone, &job_id_clone);
+
+            // The worker has returned, so generation has stopped for a canceled job.
+            let cancel_requested = state_clone
+                .lock()
+                .is_ok_and(|guard| guard.suppressed_jobs.is_suppressed(&job_id_clone));
+            if cancel_requested {
+                emit_job_cancel_confirmed(&app_clone, &job_id_clone, false);
+            }
+
+            if let Ok(mut guard) = state_clone.lock() {
+                if guard.last_job_id.as_deref() == Some(job_id_clone.as_str()) {
+                    guard.last_job_id = None;
+                }
+                // Only release the live controls if a newer job has not already replaced them.
+                let owns_controls = guard
+                    .active_cancel_flag
+                    .as_ref()
+                    .is_some_and(|flag| Arc::ptr_eq(flag, &owned_cancel_flag));
+                if owns_controls {
+                    guard.active_cancel_flag = None;
+                    guard.active_rate_steps = None;
+                    guard.active_volume = None;
+                    guard.active_pause_flag = None;
+                }
+                guard.suppressed_jobs.job_finished(&job_id_clone);
+                guard.audio_credits.remove(&job_id_clone);
+            }
+            if completed {
+                tauri::async_runtime::spawn(advance_read_queue(app_clone, state_clone, job_id_clone));
+            }
+        });
+
+        {
+            let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            guard.active_job_task = Some(job_task);
+        }
+
+        return Ok(job_id);
+    }
+
+    #[cfg(feature = "build-full")]
+    let (base_url, token) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (guard.base_url.clone(), guard.token.clone())
+    };
+
+    #[cfg(feature = "build-full")]
+    {
+    let speak_body = json!({
+        "voice_id": voice_id,
+        "text": trimmed,
+        "language": language,
+        "settings": {
+            "rate": settings.rate,
+            "volume": settings.volume,
+            "chunking": {
+                "max_chars": settings.chunk_max_chars,
+            },
+            "silence_trim": settings.silence_trim,
+            "peak_limiter": settings.peak_limiter,
+        }
+    });
+
+    let speak_payload = request_json(
+        Method::POST,
+        &format!("{base_url}/v1/speak"),
+        &token,
+        Some(speak_body),
+    )
+    .await?;
+
+    let speak_response: SpeakHttpResponse = serde_json::from_value(speak_payload)
+        .context("Invalid /v1/speak response shape")?;
+
+    let pause_flag = Arc::new(AtomicBool::new(false));
+    {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.last_job_id = Some(speak_response.job_id.clone());
+        guard.active_pause_flag = Some(pause_flag.clone());
+        guard.read_progress = Some(ReadProgress::new(
+            &speak_response.job_id,
+            &trimmed,
+            &voice_id,
+            overrides.rate,
+            source,
+            origin,
+        ));
+        guard.job_registry.register(&speak_response.job_id, &trimmed, &voice_id, overrides.rate, origin);
+        guard.suppressed_jobs.remove(&speak_response.job_id);
+        if let Some(writer) = export_tee {
+            guard.export_tees.insert(speak_response.job_id.clone(), writer);
+        }
+    }
+
+    emit_job_started(
+        app,
+        JobStartedPayload {
+            job_id: speak_response.job_id.clone(),
+            ws_url: speak_response.ws_url.clone(),
+            source: source.to_string(),
+            source_window: origin.window_title.clone(),
+            rate: settings.rate,
+            capture,
+        },
+        &trimmed,
+    );
+
+    let app_clone = app.clone();
+    let state_clone = state.clone();
+    let token_clone = token.clone();
+    let ws_url = speak_response.ws_url.clone();
+    let job_id = speak_response.job_id.clone();
+    let rate = settings.rate;
+    tauri::async_runtime::spawn(async move {
+        let relayed = relay_ws_events(&app_clone, &state_clone, &ws_url, &token_clone, &job_id, rate, &pause_flag).await;
+        finish_job_tees(&app_clone, &state_clone, &job_id);
+        if let Ok(mut guard) = state_clone.lock() {
+            if guard
+                .active_pause_flag
+                .as_ref()
+                .is_some_and(|flag| Arc::ptr_eq(flag, &pause_flag))
+            {
+                guard.active_pause_flag = None;
+            }
+        }
+        if let Err(err) = relayed {
+            emit_error(&app_clone, &format!("WS relay failed: {err:#}"));
+        }
+    });
+
+    Ok(speak_response.job_id)
+    }
+
+    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
+    {
+        Err(anyhow!("Unsupported build variant for speak and stream"))
+    }
+}
+
+/// During quiet hours, holds a hotkey read for later or refuses it, per the
+/// configured mode. A held read fails with `HeldForQuietHours` so the caller
+/// does not start it.
+fn apply_quiet_hours(
+    app: &AppHandle,
+    state: &Arc<Mutex<EngineState>>,
+    text: &str,
+    source: &str,
+    origin: &JobOrigin,
+    overrides: &SpeakOverrides,
+) -> Result<()> {
+    let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+    let Some(until) = guard.quiet_hours.active_until(chrono::Local::now()) else {
+        return Ok(());
+    };
+    match guard.quiet_hours.mode() {
+        QuietHoursMode::Reject => Err(anyhow!(
+            "Quiet hours are on until {}; the read was not started",
+            until.format("%H:%M")
+        )),
+        QuietHoursMode::Queue => {
+            // Placeholders are already expanded, so the read keeps the capture's time and clipboard.
+            let held = guard.quiet_hours.hold(QueuedRead {
+                text: text.to_string(),
+                priority: ReadPriority::Normal,
+                source: source.to_string(),
+                origin: origin.clone(),
+                voice_id: overrides.voice_id.clone(),
+                rate: overrides.rate,
+                preprocessed: overrides.preprocessed,
+                expand_placeholders: false,
+                reading_order: overrides.reading_order,
+            })?;
+            drop(guard);
+            let _ = app.emit_routed(
+                "voicereader:quiet-hours-held",
+                QuietHoursHeldPayload {
+                    held,
+                    until: until.to_rfc3339(),
+                },
+            );
+            Err(HeldForQuietHours.into())
+        }
+    }
+}
+
+/// Starts `item` right away when nothing is playing and queues it otherwise.
+/// An interactive item over a normal read instead interrupts that read, puts
+/// its unread remainder at the front of the queue, and starts immediately.
+/// Returns the job id when `item` started.
+async fn enqueue_read(app: &AppHandle, state: &Arc<Mutex<EngineState>>, item: QueuedRead) -> Result<Option<String>> {
+    let interrupted = {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        let playing_priority = playing_read(&guard).map(|progress| progress.priority);
+        let interrupt = playing_priority == Some(ReadPriority::Normal) && item.priority == ReadPriority::Interactive;
+        if playing_priority.is_some() && !interrupt {
+            guard.read_queue.push(item);
+            drop(guard);
+            emit_read_queue_changed(app, state);
+            return Ok(None);
+        }
+        if interrupt {
+            let current = guard.read_progress.take();
+            if let Some(remainder) = current.as_ref().and_then(ReadProgress::remainder) {
+                guard.read_queue.push_front(remainder);
+            }
+            current.map(|progress| progress.job_id)
+        } else {
+            None
+        }
+    };
+
+    if let Some(job_id) = interrupted {
+        stop_interrupted_read(app, state, &job_id).await?;
+        emit_read_queue_changed(app, state);
+    }
+    start_queued_read(app, state, item).await.map(Some)
+}
+
+/// The latest read while it is generating or still has audio to play.
+fn playing_read(guard: &EngineState) -> Option<&ReadProgress> {
+    guard.read_progress.as_ref().filter(|progress| {
+        guard.last_job_id.as_deref() == Some(progress.job_id.as_str()) || progress.remaining_playback_secs() > 0.0
+    })
+}
+
+/// Whether any job is generating or a read is still playing.
+fn is_speaking(guard: &EngineState) -> bool {
+    guard.last_job_id.is_some() || playing_read(guard).is_some()
+}
+
+/// Flushes an interrupted read's queued audio and, if it is still
+/// generating, stops the job.
+async fn stop_interrupted_read(app: &AppHandle, state: &Arc<Mutex<EngineState>>, job_id: &str) -> Result<()> {
+    {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.suppressed_jobs.suppress(job_id);
+        let echo_cancel = guard
+            .active_echo_job
+            .take_if(|(echo_job_id, _)| echo_job_id == job_id)
+            .map(|(_, cancel)| cancel);
+        if let Some(cancel) = echo_cancel {
+            cancel.store(true, Ordering::SeqCst);
+            guard.last_job_id = None;
+        }
+    }
+    emit_job_cancel_requested(app, job_id);
+
+    // A following speak job preempts the local runtime too, but `cancel_job`
+    // may leave nothing to follow.
+    #[cfg(feature = "build-base")]
+    {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        if guard.last_job_id.as_deref() == Some(job_id) {
+            if let Some(cancel_flag) = guard.active_cancel_flag.as_ref() {
+                cancel_flag.store(true, Ordering::SeqCst);
+            }
+            guard.active_rate_steps = None;
+            guard.active_volume = None;
+            guard.active_pause_flag = None;
+            guard.last_job_id = None;
+        }
+    }
+
+    #[cfg(feature = "build-full")]
+    {
+        let (base_url, token, generating) = {
+            let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            let generating = guard.last_job_id.as_deref() == Some(job_id);
+            if generating {
+                guard.last_job_id = None;
+            }
+            (guard.base_url.clone(), guard.token.clone(), generating)
+        };
+        if generating {
+            let _ = request_json(
+                Method::POST,
+                &format!("{base_url}/v1/cancel"),
+                &token,
+                Some(json!({ "job_id": job_id })),
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+async fn start_queued_read(app: &AppHandle, state: &Arc<Mutex<EngineState>>, item: QueuedRead) -> Result<String> {
+    let overrides = SpeakOverrides {
+        voice_id: item.voice_id,
+        rate: item.rate,
+        preprocessed: item.preprocessed,
+        expand_placeholders: item.expand_placeholders,
+        reading_order: item.reading_order,
+        ..SpeakOverrides::default()
+    };
+    let job_id =
+        speak_and_stream_with_overrides(app, state, item.text, &item.source, &item.origin, &overrides).await?;
+    let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+    if let Some(progress) = guard.read_progress.as_mut().filter(|progress| progress.job_id == job_id) {
+        progress.priority = item.priority;
+    }
+    Ok(job_id)
+}
+
+/// Starts the next queued read once the finished one has played out, so the
+/// frontend never drops its tail when the next job starts. Boxed because the
+/// speak path spawns it and it speaks again; the cycle needs an explicit `Send`.
+fn advance_read_queue(
+    app: AppHandle,
+    state: Arc<Mutex<EngineState>>,
+    finished_job_id: String,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>> {
+    Box::pin(async move {
+        // Load the next read's voice during this one's tail, so a voice switch
+        // does not add seconds of silence between them.
+        if let Some(voice_id) = next_voice_switch(&state, &finished_job_id) {
+            let state = state.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(err) = preload_voice_state(&state, voice_id).await {
+                    eprintln!("Voice preload for the next queued read failed: {err:#}");
+                }
+            });
+        }
+        loop {
+            // None once the read was replaced by a newer one or canceled.
+            let Some(wait_secs) = remaining_playback_secs(&state, &finished_job_id) else {
+                return;
+            };
+            if wait_secs <= 0.0 {
+                break;
+            }
+            // Re-check periodically: a pause stretches the remaining time.
+            sleep(Duration::from_secs_f64(wait_secs.min(1.0))).await;
+        }
+
+        let (next, raised_rate) = {
+            let Ok(mut guard) = state.lock() else {
+                return;
+            };
+            let still_current = guard
+                .read_progress
+                .as_ref()
+                .is_some_and(|progress| progress.job_id == finished_job_id);
+            if !still_current || guard.last_job_id.is_some() {
+                return;
+            }
+            let listened_secs = guard.read_progress.take().map_or(0.0, |progress| progress.emitted_secs());
+            let raised_rate = step_speed_training(&mut guard, listened_secs);
+            (guard.read_queue.pop(), raised_rate)
+        };
+        if let Some(rate) = raised_rate {
+            persist_speak_state(&app, &state);
+            let _ = app.emit_routed("voicereader:rate-updated", RateUpdatedPayload { rate });
+        }
+        let Some(next) = next else {
+            return;
+        };
+        emit_read_queue_changed(&app, &state);
+        if let Err(err) = start_queued_read(&app, &state, next).await {
+            emit_error(&app, &format!("Queued read failed to start: {err:#}"));
+        }
+    })
+}
+
+/// Counts a read that played to the end toward speed training. When a step
+/// is due, raises the rate for the reads after it and queues the
+/// announcement first; returns the new rate.
+fn step_speed_training(guard: &mut EngineState, listened_secs: f64) -> Option<f32> {
+    let rate = guard
+        .speed_training
+        .record_listened(listened_secs, guard.speak_settings.rate)?;
+    guard.speak_settings.rate = rate;
+    if let Some(announcement) = guard.speed_training.announcement(rate) {
+        guard.read_queue.push_front(QueuedRead {
+            text: announcement,
+            priority: ReadPriority::Normal,
+            source: "speed_training".to_string(),
+            origin: JobOrigin::direct(),
+            voice_id: None,
+            rate: None,
+            preprocessed: false,
+            expand_placeholders: false,
+            reading_order: false,
+        });
+    }
+    Some(rate)
+}
+
+/// The voice of the next queued read, if it differs from the one `job_id`
+/// was read with.
+fn next_voice_switch(state: &Arc<Mutex<EngineState>>, job_id: &str) -> Option<String> {
+    let guard = state.lock().ok()?;
+    let current_voice_id = guard
+        .read_progress
+        .as_ref()
+        .filter(|progress| progress.job_id == job_id)?
+        .voice_id();
+    let next_voice_id = guard
+        .read_queue
+        .peek()?
+        .voice_id
+        .clone()
+        .unwrap_or_else(|| guard.selected_voice_id.clone());
+    (next_voice_id != current_voice_id).then_some(next_voice_id)
+}
+
+/// Loads a voice into the engine's voice state cache ahead of its first job.
+async fn preload_voice_state(state: &Arc<Mutex<EngineState>>, voice_id: String) -> Result<()> {
+    #[cfg(feature = "build-base")]
+    {
+        let (runtime, selected_preset) = {
+            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            let runtime = guard
+                .local_kyutai
+                .clone()
+                .ok_or_else(|| anyhow!("Kyutai Rust runtime is not initialized"))?;
+            (runtime, guard.selected_kyutai_voice.clone())
+        };
+        tauri::async_runtime::spawn_blocking(move || {
+            let mut runtime = runtime
+                .lock()
+                .map_err(|_| anyhow!("Kyutai runtime lock poisoned"))?;
+            runtime.preload_voice(&voice_id, &selected_preset)
+        })
+        .await
+        .map_err(|err| anyhow!("Voice preload task failed: {err}"))?
+    }
+
+    #[cfg(feature = "build-full")]
+    {
+        let (base_url, token) = {
+            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            (guard.base_url.clone(), guard.token.clone())
+        };
+        request_json_long(
+            Method::POST,
+            &format!("{base_url}/v1/voices/{voice_id}/preload"),
+            &token,
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+fn remaining_playback_secs(state: &Arc<Mutex<EngineState>>, job_id: &str) -> Option<f64> {
+    let guard = state.lock().ok()?;
+    guard
+        .read_progress
+        .as_ref()
+        .filter(|progress| progress.job_id == job_id)
+        .map(ReadProgress::remaining_playback_secs)
+}
+
+/// Steers the rate of a teleprompter job back onto its schedule before the
+/// chunk at `text_offset` is recorded.
+fn pace_teleprompter(state: &Arc<Mutex<EngineState>>, job_id: &str, text_offset: usize) {
+    let Ok(mut guard) = state.lock() else {
+        return;
+    };
+    let emitted_secs = guard
+        .read_progress
+        .as_ref()
+        .filter(|progress| progress.job_id == job_id)
+        .map_or(0.0, ReadProgress::emitted_secs);
+    let Some(job) = guard.teleprompter.as_mut().filter(|job| job.job_id == job_id) else {
+        return;
+    };
+    let rate = clamp_speak_rate(job.plan.rate_at(text_offset, emitted_secs));
+    if (rate - job.rate).abs() < TELEPROMPTER_RATE_STEP {
+        return;
+    }
+    job.rate = rate;
+
+    #[cfg(feature = "build-base")]
+    if let Some(active_steps) = guard.active_rate_steps.as_ref() {
+        active_steps.store(rate_to_steps(rate), Ordering::SeqCst);
+    }
+    #[cfg(feature = "build-full")]
+    {
+        drop(guard);
+        let state = state.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(err) = update_active_job_playback_full(&state, json!({ "rate": rate })).await {
+                eprintln!("Teleprompter rate update failed: {err:#}");
+            }
+        });
+    }
+}
+
+fn record_read_progress(
+    state: &Arc<Mutex<EngineState>>,
+    job_id: &str,
+    text_offset: usize,
+    samples: usize,
+    sample_rate: u32,
+) {
+    if let Ok(mut guard) = state.lock() {
+        if let Some(progress) = guard.read_progress.as_mut().filter(|progress| progress.job_id == job_id) {
+            progress.record_chunk(text_offset, samples, sample_rate);
+        }
+        guard.job_registry.record_chunk(job_id, text_offset);
+    }
+}
+
+/// Sends the queue's `JOB_QUEUED` and `JOB_DEQUEUED` events since the last
+/// call, then the pending count.
+fn emit_read_queue_changed(app: &AppHandle, state: &Arc<Mutex<EngineState>>) {
+    let (events, pending) = state
+        .lock()
+        .map(|mut guard| (guard.read_queue.take_events(), guard.read_queue.len()))
+        .unwrap_or_default();
+    for event in events {
+        let _ = app.emit_routed("voicereader:ws-event", event);
+    }
+    let _ = app.emit_routed("voicereader:read-queue-changed", ReadQueueChangedPayload { pending });
+}
+
+/// Stops the running local job before a new one starts: the frontend is told to
+/// flush the old job's queued audio, the worker is signalled, and we wait for it
+/// to finish tearing down so its last chunks cannot interleave with the new job.
+#[cfg(feature = "build-base")]
+async fn preempt_active_local_job(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    let (previous_job_id, previous_cancel, previous_task) = {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (
+            guard.last_job_id.clone(),
+            guard.active_cancel_flag.clone(),
+            guard.active_job_task.take(),
+        )
+    };
+
+    if let Some(job_id) = previous_job_id {
+        {
+            let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            guard.suppressed_jobs.suppress(&job_id);
+        }
+        emit_job_cancel_requested(app, &job_id);
+    }
+    if let Some(flag) = previous_cancel {
+        flag.store(true, Ordering::SeqCst);
+    }
+
+    if let Some(task) = previous_task {
+        if tokio::time::timeout(Duration::from_millis(PREEMPT_TEARDOWN_TIMEOUT_MS), task)
+            .await
+            .is_err()
+        {
+            eprintln!("Previous local job did not stop within {PREEMPT_TEARDOWN_TIMEOUT_MS}ms; starting new job anyway");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "build-full")]
+async fn relay_ws_events(
+    app: &AppHandle,
+    state: &Arc<Mutex<EngineState>>,
+    ws_url: &str,
+    token: &str,
+    job_id: &str,
+    rate: f32,
+    paused: &AtomicBool,
+) -> Result<()> {
+    let mut socket = connect_job_stream(ws_url, token).await?;
+    let started = Instant::now();
+    // Time the relay spent waiting on playback or a pause, which holds the
+    // engine too once the socket backs up; left out of the synthesis timing.
+    let mut held = Duration::ZERO;
+    let mut audio_samples = 0u64;
+    let mut sample_rate = 0u64;
+    let mut completed = false;
+    let mut cancel_requested = false;
+    let mut last_seq: Option<u64> = None;
+    let mut reconnect_attempts = 0u32;
+    let mut lost_reason: Option<String> = None;
+
+    'relay: loop {
+        let mut drop_reason = "stream closed before the job finished".to_string();
+        while let Some(message) = socket.next().await {
+            if is_job_suppressed(state, job_id) {
+                cancel_requested = true;
+                break 'relay;
+            }
+            match message {
+                Ok(Message::Text(text)) => {
+                    let parsed: Value = serde_json::from_str(&text)
+                        .unwrap_or_else(|_| json!({ "type": "RAW_TEXT", "raw": text }));
+
+                    if is_job_suppressed(state, job_id) {
+                        cancel_requested = true;
+                        break 'relay;
+                    }
+
+                    let kind = parsed.get("type").and_then(Value::as_str);
+                    let seq = parsed.get("seq").and_then(Value::as_u64);
+                    // A resumed stream can replay chunks that were already relayed.
+                    if kind == Some("AUDIO_CHUNK") && seq.zip(last_seq).is_some_and(|(seq, last)| seq <= last) {
+                        continue;
+                    }
+                    reconnect_attempts = 0;
+
+                    // With native playback the audio is played here instead of sent on.
+                    let play_natively = kind == Some("AUDIO_CHUNK") && has_native_player(state);
+                    if !play_natively {
+                        let mut outgoing = parsed.clone();
+                        if kind == Some("AUDIO_CHUNK") {
+                            stream_audio_format(app).convert_event(&mut outgoing);
+                            stamp_audio_chunk(app, job_id, &mut outgoing);
+                        }
+                        let _ = app.emit_routed("voicereader:ws-event", outgoing);
+                    }
+
+                    if let Some(kind) = kind {
+                        if kind == "AUDIO_CHUNK" {
+                            last_seq = seq.or(last_seq);
+                            // Decoded size from the base64 length; avoids decoding twice.
+                            let encoded = parsed
+                                .pointer("/audio/data_base64")
+                                .and_then(Value::as_str)
+                                .unwrap_or_default();
+                            let chunk_samples = (encoded.len() as u64 * 3 / 4) / 2;
+                            audio_samples += chunk_samples;
+                            if let Some(rate) = parsed.pointer("/audio/sample_rate").and_then(Value::as_u64) {
+                                sample_rate = rate;
+                            }
+                            let start_char = parsed
+                                .pointer("/text_range/start_char")
+                                .and_then(Value::as_u64)
+                                .unwrap_or(0);
+                            pace_teleprompter(state, job_id, start_char as usize);
+                            record_read_progress(
+                                state,
+                                job_id,
+                                start_char as usize,
+                                chunk_samples as usize,
+                                sample_rate as u32,
+                            );
+                            if play_natively || has_audio_tee(state, job_id) {
+                                let pcm = BASE64_STANDARD
+                                    .decode(encoded)
+                                    .map(|bytes| {
+                                        bytes
+                                            .chunks_exact(2)
+                                            .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+                                            .collect::<Vec<_>>()
+                                    })
+                                    .unwrap_or_default();
+                                if play_natively {
+                                    with_native_player(app, |player| player.play(job_id, &pcm, sample_rate as u32));
+                                }
+                                tee_job_audio(app, state, job_id, &pcm, sample_rate as u32);
+                            }
+                            record_audio_emitted(state, job_id, chunk_samples as usize, sample_rate as u32);
+                            let waited = Instant::now();
+                            wait_for_audio_credit(state, job_id).await;
+                            wait_while_paused(state, job_id, paused).await;
+                            held += waited.elapsed();
+                        }
+                        completed = kind == "JOB_DONE";
+                        if TERMINAL_EVENTS.contains(&kind) {
+                            notify_job_finished(app, &parsed);
+                            with_native_player(app, |player| player.end_job(job_id));
+                            break 'relay;
+                        }
+                    }
+                }
+                Ok(Message::Close(_)) => break,
+                Ok(_) => {}
+                Err(err) => {
+                    drop_reason = format!("WS stream read error: {err}");
+                    break;
+                }
+            }
+        }
+
+        // The stream ended without a terminal event: reconnect and resume
+        // after the last relayed chunk.
+        socket = loop {
+            if is_job_suppressed(state, job_id) {
+                cancel_requested = true;
+                break 'relay;
+            }
+            if reconnect_attempts >= RELAY_RECONNECT_ATTEMPTS {
+                lost_reason = Some(drop_reason);
+                break 'relay;
+            }
+            reconnect_attempts += 1;
+            let delay_ms = RELAY_RECONNECT_BASE_DELAY_MS << (reconnect_attempts - 1);
+            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+            let resume_url = match last_seq {
+                Some(seq) => format!("{ws_url}?after_seq={seq}"),
+                None => ws_url.to_string(),
+            };
+            match connect_job_stream(&resume_url, token).await {
+                Ok(next) => break next,
+                Err(err) => drop_reason = format!("{err:#}"),
+            }
+        };
+    }
+
+    if let Some(reason) = lost_reason.as_deref() {
+        emit_terminal_event(
+            app,
+            json!({
+                "type": "JOB_ERROR",
+                "job_id": job_id,
+                "error": {
+                    "code": "STREAM_LOST",
+                    "message": format!("Lost the job stream after {RELAY_RECONNECT_ATTEMPTS} reconnect attempts: {reason}"),
+                    "details": {},
+                },
+            }),
+        );
+    }
+
+    // The socket can also close right after a cancel, before the loop saw it.
+    if cancel_requested || is_job_suppressed(state, job_id) {
+        let confirmed = tokio::time::timeout(
+            Duration::from_millis(CANCEL_CONFIRM_TIMEOUT_MS),
+            drain_canceled_stream(&mut socket),
+        )
+        .await
+        .is_ok();
+        emit_job_cancel_confirmed(app, job_id, !confirmed);
+        // A canceled job's own terminal event is drained, not relayed.
+        notify_job_finished(app, &json!({ "type": "JOB_CANCELED", "job_id": job_id }));
+    }
+
+    {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        if completed && sample_rate > 0 {
+            let audio_secs = audio_samples as f64 / sample_rate as f64 * rate as f64;
+            let model = guard.selected_model.clone();
+            guard
+                .synthesis_metrics
+                .record(&model, started.elapsed().saturating_sub(held).as_secs_f64(), audio_secs);
+        }
+        if guard.last_job_id.as_deref() == Some(job_id) {
+            guard.last_job_id = None;
+        }
+        guard.suppressed_jobs.job_finished(job_id);
+        guard.audio_credits.remove(job_id);
+    }
+    if completed {
+        tauri::async_runtime::spawn(advance_read_queue(app.clone(), state.clone(), job_id.to_string()));
+    }
+    if let Some(reason) = lost_reason {
+        return Err(anyhow!("WS stream lost after {RELAY_RECONNECT_ATTEMPTS} reconnect attempts: {reason}"));
+    }
+    Ok(())
+}
+
+/// Reads a canceled job's stream without forwarding it, until the sidecar
+/// sends a terminal event or closes the socket.
+#[cfg(feature = "build-full")]
+async fn drain_canceled_stream<S, E>(socket: &mut S)
+where
+    S: futures_util::Stream<Item = std::result::Result<Message, E>> + Unpin,
+{
+    while let Some(Ok(message)) = socket.next().await {
+        let Message::Text(text) = message else {
+            if matches!(message, Message::Close(_)) {
+                return;
+            }
+            continue;
+        };
+        let kind = serde_json::from_str::<Value>(&text)
+            .ok()
+            .and_then(|parsed| parsed.get("type").and_then(Value::as_str).map(str::to_string));
+        if kind.is_some_and(|kind| TERMINAL_EVENTS.contains(&kind.as_str())) {
+            return;
+        }
+    }
+}
+
+/// Tells the player to drop `job_id`'s queued audio; the native player drops
+/// it here.
+fn emit_job_cancel_requested(app: &AppHandle, job_id: &str) {
+    with_native_player(app, |player| player.stop_job(job_id));
+    abort_job_sinks(app, Some(job_id));
+    let _ = app.emit_routed(
+        "voicereader:job-cancel-requested",
+        JobCancelRequestedPayload {
+            job_id: job_id.to_string(),
+        },
+    );
+}
+
+fn emit_job_cancel_confirmed(app: &AppHandle, job_id: &str, timed_out: bool) {
+    let _ = app.emit_routed(
+        "voicereader:job-cancel-confirmed",
+        JobCancelConfirmedPayload {
+            job_id: job_id.to_string(),
+            timed_out,
+        },
+    );
+}
+
+#[cfg(feature = "build-full")]
+async fn connect_job_stream(
+    ws_url: &str,
+    token: &str,
+) -> Result<
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+> {
+    let protocol_header = format!("auth.bearer.v1, {token}");
+    let mut request = ws_url
+        .into_client_request()
+        .context("Failed to construct WS request")?;
+    request
+        .headers_mut()
+        .insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_str(&protocol_header)?);
+
+    let (socket, _) = tokio_tungstenite::connect_async(request)
+        .await
+        .context("Failed to connect WS stream")?;
+    Ok(socket)
+}
+
+/// Runs a synthesis job without playback and returns the concatenated PCM16
+/// samples plus their sample rate. Used by file export.
+/// `voice_id` overrides the selected voice, and any voice translation picks.
+async fn synthesize_to_pcm(
+    state: &Arc<Mutex<EngineState>>,
+    text: String,
+    voice_id: Option<String>,
+) -> Result<(Vec<i16>, u32)> {
+    let trimmed = text.trim().to_string();
+    if trimmed.is_empty() {
+        return Err(anyhow!("Speak text cannot be empty"));
+    }
+
+    let (selected_voice_id, selected_model, settings, translation, number_format, acronyms, lexicon) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (
+            guard.selected_voice_id.clone(),
+            guard.selected_model.clone(),
+            guard.speak_settings.clone(),
+            guard.translation.clone(),
+            guard.number_format.clone(),
+            guard.acronyms.clone(),
+            guard.lexicon.clone(),
+        )
+    };
+    let trimmed = normalize_numbers(&trimmed, &number_format);
+    let (trimmed, translated_voice_id) = translate_for_speech(&translation, trimmed, selected_voice_id).await?;
+    let voice_id = voice_id.unwrap_or(translated_voice_id);
+    let trimmed = apply_lexicon(&trimmed, &lexicon, respelling_backend(&selected_model));
+    let trimmed = spell_acronyms(&trimmed, &acronyms);
+
+    if selected_model != MODEL_CUSTOM && selected_model != MODEL_KYUTAI {
+        return Err(anyhow!(
+            "Current model mode ({selected_model}) is not enabled for synthesis. Switch to qwen_custom_voice or kyutai_pocket_tts."
+        ));
+    }
+
+    synthesize_voice_pcm(state, trimmed, voice_id, settings, Arc::new(AtomicBool::new(false)), None).await
+}
+
+/// Synthesizes `text` with an explicit voice and collects the PCM instead of
+/// streaming it to the frontend. Setting `cancel` stops synthesis early;
+/// `first_chunk_at`, when given, is set when the first audio arrives.
+async fn synthesize_voice_pcm(
+    state: &Arc<Mutex<EngineState>>,
+    text: String,
+    voice_id: String,
+    settings: SpeakSettingsState,
+    cancel: Arc<AtomicBool>,
+    first_chunk_at: Option<Arc<OnceLock<Instant>>>,
+) -> Result<(Vec<i16>, u32)> {
+    #[cfg(feature = "build-base")]
+    {
+        let (local_runtime, selected_preset) = {
+            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            (
+                guard
+                    .local_kyutai
+                    .clone()
+                    .ok_or_else(|| anyhow!("Kyutai Rust runtime is not initialized"))?,
+                guard.selected_kyutai_voice.clone(),
+            )
+        };
+
+        let collected: Arc<Mutex<(Vec<i16>, u32)>> = Arc::new(Mutex::new((Vec::new(), 0)));
+        let collected_for_chunk = collected.clone();
+        tauri::async_runtime::spawn_blocking(move || -> Result<()> {
+            let rate_steps = AtomicU32::new(rate_to_steps(settings.rate));
+            let mut runtime = local_runtime
+                .lock()
+                .map_err(|_| anyhow!("Kyutai runtime lock poisoned"))?;
+            runtime.stream_synthesize(
+                &voice_id,
+                &selected_preset,
+                &text,
+                &local_stream_settings(&settings, None),
+                &cancel,
+                &rate_steps,
+                move |_chunk_index, _text_offset, pcm, sample_rate| {
+                    if let Some(first_chunk_at) = first_chunk_at.as_ref() {
+                        let _ = first_chunk_at.set(Instant::now());
+                    }
+                    let mut guard = collected_for_chunk
+                        .lock()
+                        .map_err(|_| anyhow!("Synthesis buffer lock poisoned"))?;
+                    guard.0.extend_from_slice(pcm);
+                    guard.1 = sample_rate;
+                    Ok(())
+                },
+            )?;
+            Ok(())
+        })
+        .await
+        .map_err(|err| anyhow!("Synthesis task failed: {err}"))??;
+
+        let mut guard = collected.lock().map_err(|_| anyhow!("Synthesis buffer lock poisoned"))?;
+        return Ok(std::mem::take(&mut *guard));
+    }
+
+    #[cfg(feature = "build-full")]
+    {
+    let (base_url, token) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (guard.base_url.clone(), guard.token.clone())
+    };
+
+    let speak_body = json!({
+        "voice_id": voice_id,
+        "text": text,
+        "settings": {
+            "rate": settings.rate,
+            "volume": settings.volume,
+            "chunking": {
+                "max_chars": settings.chunk_max_chars,
+            },
+            "silence_trim": settings.silence_trim,
+            "peak_limiter": settings.peak_limiter,
+        }
+    });
+    let speak_payload = request_json(
+        Method::POST,
+        &format!("{base_url}/v1/speak"),
+        &token,
+        Some(speak_body),
+    )
+    .await?;
+    let speak_response: SpeakHttpResponse = serde_json::from_value(speak_payload)
+        .context("Invalid /v1/speak response shape")?;
+
+    let mut socket = connect_job_stream(&speak_response.ws_url, &token).await?;
+    let mut pcm: Vec<i16> = Vec::new();
+    let mut sample_rate: u32 = 0;
+    while let Some(message) = socket.next().await {
+        if cancel.load(Ordering::SeqCst) {
+            let _ = request_json(
+                Method::POST,
+                &format!("{base_url}/v1/cancel"),
+                &token,
+                Some(json!({ "job_id": speak_response.job_id })),
+            )
+            .await;
+            return Err(anyhow!("Synthesis was canceled"));
+        }
+        let text = match message {
+            Ok(Message::Text(text)) => text,
+            Ok(Message::Close(_)) => break,
+            Ok(_) => continue,
+            Err(err) => return Err(anyhow!("WS stream read error: {err}")),
+        };
+        let parsed: Value = serde_json::from_str(&text).context("Invalid WS event payload")?;
+        match parsed.get("type").and_then(Value::as_str) {
+            Some("AUDIO_CHUNK") => {
+                if let Some(first_chunk_at) = first_chunk_at.as_ref() {
+                    let _ = first_chunk_at.set(Instant::now());
+                }
+                let audio = parsed
+                    .get("audio")
+                    .ok_or_else(|| anyhow!("AUDIO_CHUNK event is missing audio"))?;
+                if let Some(rate) = audio.get("sample_rate").and_then(Value::as_u64) {
+                    sample_rate = rate as u32;
+                }
+                let encoded = audio.get("data_base64").and_then(Value::as_str).unwrap_or_default();
+                let bytes = BASE64_STANDARD
+                    .decode(encoded)
+                    .context("Invalid base64 audio in AUDIO_CHUNK event")?;
+                pcm.extend(
+                    bytes
+                        .chunks_exact(2)
+                        .map(|pair| i16::from_le_bytes([pair[0], pair[1]])),
+                );
+            }
+            Some("JOB_DONE") => break,
+            // An export needs all of the text; a part-way stop is not a result.
+            Some("JOB_PARTIAL_DONE") => return Err(anyhow!("Synthesis job stopped part-way")),
+            Some("JOB_CANCELED") => return Err(anyhow!("Synthesis job was canceled")),
+            Some("JOB_ERROR") => {
+                let detail = parsed.get("error").map(Value::to_string).unwrap_or_default();
+                return Err(anyhow!("Synthesis job failed: {detail}"));
+            }
+            _ => {}
+        }
+    }
+
+    Ok((pcm, sample_rate))
+    }
+
+    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
+    {
+        Err(anyhow!("Unsupported build variant for synthesis"))
+    }
+}
+
+#[cfg(feature = "build-full")]
+fn is_job_suppressed(state: &Arc<Mutex<EngineState>>, job_id: &str) -> bool {
+    match state.lock() {
+        Ok(guard) => guard.suppressed_jobs.is_suppressed(job_id),
+        Err(_) => false,
+    }
+}
+
+async fn restart_engine_inner(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    shutdown_engine(state).await;
+    initialize_engine_if_needed(app, state).await?;
+
+    let selected_model = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.selected_model.clone()
+    };
+
+    if selected_model == MODEL_CUSTOM {
+        let _ = apply_custom_model_activation(state).await;
+    } else if selected_model == MODEL_KYUTAI {
+        let _ = apply_kyutai_model_activation(state).await;
+    }
+    Ok(())
+}
+
+async fn ensure_engine_ready(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    let running = {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        runtime_snapshot(&mut guard).0
+    };
+
+    if running {
+        return Ok(());
+    }
+
+    initialize_engine_if_needed(app, state)
+        .await
+        .map_err(|err| err.context(CodedError::new(ErrorCode::EngineNotReady, "Engine is not ready")))
+}
+
+async fn initialize_engine_if_needed(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+    let (init_gate, generation_before) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (guard.init_gate.clone(), guard.init_generation)
+    };
+
+    // Only one initialization runs at a time; callers racing on a cold start
+    // wait here and then reuse whatever the winning attempt produced.
+    let _init_guard = init_gate.lock().await;
+
+    let (running, generation_now, last_init_error) = {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (
+            runtime_snapshot(&mut guard).0,
+            guard.init_generation,
+            guard.last_init_error.clone(),
+        )
+    };
+    if running {
+        return Ok(());
+    }
+    if generation_now != generation_before {
+        if let Some(err) = last_init_error {
+            return Err(anyhow!(err));
+        }
+    }
+
+    let result = initialize_engine_locked(app, state).await;
+    {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.init_generation += 1;
+        guard.last_init_error = result.as_ref().err().map(|err| format!("{err:#}"));
+    }
+    result
+}
+
+async fn initialize_engine_locked(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
+
+    #[cfg(feature = "build-base")]
+    {
+        let engine_root = find_engine_root().ok();
+        let data_dir = resolve_engine_data_dir(app, engine_root.as_deref())?;
+        std::fs::create_dir_all(&data_dir).context("Failed to create engine data dir")?;
+        let models_dir = data_dir.join("models");
+        let hf_cache_dir = data_dir.join("hf-cache");
+        std::fs::create_dir_all(&models_dir).context("Failed to create models dir")?;
+        std::fs::create_dir_all(&hf_cache_dir).context("Failed to create hf-cache dir")?;
+
+        let model_dir = resolve_bundled_kyutai_model_dir(app)
+            .or_else(|| {
+                let candidate = models_dir.join("Verylicious").join("pocket-tts-ungated");
+                if is_kyutai_model_dir(&candidate) {
+                    Some(candidate)
+                } else {
+                    None
+                }
+            })
+            .ok_or_else(|| {
+                anyhow!(
+                    "Bundled Kyutai model directory not found. Expected resources/models/Verylicious/pocket-tts-ungated"
+                )
+            })?;
+
+        let runtime = LocalKyutaiRuntime::new(&model_dir, &data_dir, KYUTAI_REPO, "alba")?;
+        {
+            let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            guard.local_kyutai = Some(Arc::new(Mutex::new(runtime)));
+            guard.base_url = "local://kyutai".to_string();
+            guard.token.clear();
+            guard.port = 0;
+            guard.data_dir = data_dir.to_string_lossy().to_string();
+            guard.models_dir = models_dir.to_string_lossy().to_string();
+            guard.hf_cache_dir = hf_cache_dir.to_string_lossy().to_string();
+            guard.last_job_id = None;
+            guard.active_cancel_flag = None;
+            guard.active_rate_steps = None;
+            guard.active_volume = None;
+            guard.active_pause_flag = None;
+            guard.suppressed_jobs.clear();
+            guard.selected_model = MODEL_KYUTAI.to_string();
+        }
+
+        let health = engine_health_inner(state).await?;
+        let _ = app.emit_routed("voicereader:engine-ready", health);
+
+        if let Ok(mut guard) = state.lock() {
+            guard.startup_error = None;
+        }
+        return Ok(());
+    }
+
+    #[cfg(feature = "build-full")]
+    {
+    let engine_root = find_engine_root().ok();
+
+    let token = generate_token();
+    let data_dir = resolve_engine_data_dir(app, engine_root.as_deref())?;
+    std::fs::create_dir_all(&data_dir).context("Failed to create engine data dir")?;
+    let models_dir = data_dir.join("models");
+    let hf_cache_dir = data_dir.join("hf-cache");
+
+    // The port picked here can be grabbed by another process before the sidecar
+    // binds it. A bind failure makes the sidecar exit during health polling, so
+    // retry with a fresh port when its stderr says so, and keep a trail for the
+    // error report.
+    let mut failed_attempts: Vec<String> = Vec::new();
+    let mut startup_health: Option<Value> = None;
+    let profile_port = active_engine_profile(app).and_then(|profile| profile.port);
+    for attempt in 1..=ENGINE_STARTUP_PORT_ATTEMPTS {
+        // A profile's fixed port is tried first; if it is taken, the retries
+        // fall back to free ports rather than failing on the same one.
+        let port = match profile_port.filter(|_| attempt == 1) {
+            Some(port) => port,
+            None => portpicker::pick_unused_port().ok_or_else(|| anyhow!("Failed to find a free localhost port"))?,
+        };
+        let SpawnedEngine {
+            child,
+            job,
+            stderr_tail,
+            stderr_reader,
+        } = spawn_engine_sidecar(app, engine_root.as_deref(), port, &data_dir, &token)?;
+
+        {
+            let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            guard.child = Some(child);
+            guard.engine_job = job;
+            guard.engine_stderr_tail = stderr_tail.clone();
+            guard.engine_exit_expected = false;
+            guard.pending_crash = None;
+            guard.token = token.clone();
+            guard.port = port;
+            guard.base_url = format!("http://127.0.0.1:{port}");
+            guard.data_dir = data_dir.to_string_lossy().to_string();
+            guard.models_dir = models_dir.to_string_lossy().to_string();
+            guard.hf_cache_dir = hf_cache_dir.to_string_lossy().to_string();
+            guard.last_job_id = None;
+            guard.suppressed_jobs.clear();
+        }
+
+        match wait_for_engine_health(app, state).await {
+            Ok(payload) => {
+                startup_health = Some(payload);
+                break;
+            }
+            Err(err) => {
+                failed_attempts.push(format!("attempt {attempt} on port {port}: {err:#}"));
+                let exited = {
+                    let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+                    !runtime_snapshot(&mut guard).0
+                };
+                if !exited {
+                    // Still alive but never healthy: not a port conflict, so retrying won't help.
+                    break;
+                }
+                // The bind error may still be in the pipe when the exit is
+                // noticed; let the reader drain it first.
+                if let Some(reader) = stderr_reader.as_ref() {
+                    let settle_deadline = Instant::now() + Duration::from_millis(ENGINE_STDERR_SETTLE_MS);
+                    while !reader.is_finished() && Instant::now() < settle_deadline {
+                        sleep(Duration::from_millis(20)).await;
+                    }
+                }
+                let bind_failed = stderr_tail.lock().is_ok_and(|tail| is_port_bind_failure(tail.iter()));
+                if !bind_failed {
+                    // Crashed for another reason (missing module, bad model),
+                    // which a fresh port would only repeat.
+                    break;
+                }
+            }
+        }
+    }
+
+    let Some(health) = startup_health else {
+        return Err(anyhow!(
+            "Engine failed to start after {} attempt(s): {}",
+            failed_attempts.len(),
+            failed_attempts.join("; ")
+        ));
+    };
+    let _ = app.emit_routed("voicereader:engine-ready", health);
+
+    if let Ok(mut guard) = state.lock() {
+        guard.startup_error = None;
+        if let Some(pid) = guard.child.as_ref().map(Child::id) {
+            spawn_engine_exit_monitor(app.clone(), state.clone(), pid);
+            spawn_engine_heartbeat(app.clone(), state.clone(), pid);
+        }
+    }
+
+    let selected_model = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        guard.selected_model.clone()
+    };
+    if selected_model == MODEL_CUSTOM {
+        let _ = apply_custom_model_activation(state).await;
+    } else if selected_model == MODEL_KYUTAI {
+        let _ = apply_kyutai_model_activation(state).await;
+    }
+
+    Ok(())
+    }
+
+    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
+    {
+        Err(anyhow!("Unsupported build variant for engine initialization"))
+    }
+}
+
+async fn shutdown_engine(state: &Arc<Mutex<EngineState>>) {
+    #[cfg(feature = "build-base")]
+    {
+        let mut guard = match state.lock() {
+            Ok(v) => v,
+            Err(_) => return,
+        };
+        if let Some(cancel_flag) = guard.active_cancel_flag.as_ref() {
+            cancel_flag.store(true, Ordering::SeqCst);
+        }
+        guard.active_cancel_flag = None;
+        guard.active_rate_steps = None;
+        guard.active_volume = None;
+        guard.active_pause_flag = None;
+        guard.local_kyutai = None;
+        guard.last_job_id = None;
+        guard.suppressed_jobs.clear();
+        return;
+    }
+
+    #[cfg(feature = "build-full")]
+    {
+    let (base_url, token) = {
+        let mut guard = match state.lock() {
+            Ok(v) => v,
+            Err(_) => return,
+        };
+        guard.engine_exit_expected = true;
+        (guard.base_url.clone(), guard.token.clone())
+    };
+
+    if !base_url.is_empty() && !token.is_empty() {
+        let _ = request_json(Method::POST, &format!("{base_url}/v1/quit"), &token, Some(json!({}))).await;
+        sleep(Duration::from_millis(400)).await;
+    }
+
+    let mut guard = match state.lock() {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+
+    if let Some(child) = guard.child.as_mut() {
+        match child.try_wait() {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                let _ = child.kill();
+            }
+            Err(_) => {
+                let _ = child.kill();
+            }
+        }
+    }
+    guard.child = None;
+    guard.engine_job = None;
+    guard.last_job_id = None;
+    guard.suppressed_jobs.clear();
+    }
+}
+
+#[cfg(feature = "build-full")]
+fn active_engine_profile(app: &AppHandle) -> Option<EngineProfile> {
+    load_app_settings(app)
+        .and_then(|settings| settings.engine_profiles)
+        .and_then(|profiles| profiles.active_profile().cloned())
+}
+
+#[cfg(feature = "build-full")]
+struct SpawnedEngine {
+    child: Child,
+    job: Option<EngineJob>,
+    stderr_tail: Arc<Mutex<VecDeque<String>>>,
+    /// Finishes once stderr closes and its last line is in `stderr_tail`.
+    stderr_reader: Option<std::thread::JoinHandle<()>>,
+}
+
+#[cfg(feature = "build-full")]
+fn spawn_engine_sidecar(
+    app: &AppHandle,
+    engine_root: Option<&Path>,
+    port: u16,
+    data_dir: &Path,
+    token: &str,
+) -> Result<SpawnedEngine> {
+    let (mut command, launch_target) = build_engine_launch_command(app, engine_root, port, data_dir)?;
+    let kyutai_model_setting = resolve_bundled_kyutai_model_dir(app)
+        .map(|path| path.to_string_lossy().to_string())
+        .unwrap_or_else(|| KYUTAI_REPO.to_string());
+    command
+        .env("SPEAK_SELECTION_ENGINE_TOKEN", token)
+        .env("VOICEREADER_SYNTH_BACKEND", "auto")
+        .env("VOICEREADER_KYUTAI_MODEL", kyutai_model_setting)
+        .env("VOICEREADER_KYUTAI_VOICE_PROMPT", "alba")
+        .env("VOICEREADER_QWEN_MODEL", QWEN_CUSTOM_REPO)
+        .env("VOICEREADER_QWEN_SPEAKER", "Ryan");
+
+    if cfg!(target_os = "windows") {
+        // FlashAttention2 is often unavailable on Windows; use SDPA directly for stable startup.
+        command.env("VOICEREADER_QWEN_ATTN_IMPLEMENTATION", "sdpa");
+    }
+
+    // User overrides win over the defaults above.
+    let overrides = load_app_settings(app)
+        .and_then(|settings| settings.engine_env_overrides)
+        .unwrap_or_default();
+    for (name, value) in [
+        ("VOICEREADER_SYNTH_BACKEND", overrides.synth_backend),
+        ("VOICEREADER_QWEN_ATTN_IMPLEMENTATION", overrides.qwen_attn_implementation),
+        ("VOICEREADER_QWEN_SPEAKER", overrides.qwen_speaker),
+        ("VOICEREADER_QWEN_DEVICE_MAP", overrides.qwen_device_map),
+        ("VOICEREADER_QWEN_DTYPE", overrides.qwen_dtype),
+    ] {
+        if let Some(value) = value {
+            command.env(name, value);
+        }
+    }
+    // The active engine profile wins over both.
+    if let Some(profile) = active_engine_profile(app) {
+        for (name, value) in [
+            ("VOICEREADER_SYNTH_BACKEND", profile.model),
+            ("VOICEREADER_QWEN_DEVICE_MAP", profile.device),
+        ] {
+            if let Some(value) = value {
+                command.env(name, value);
+            }
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    // stderr is always captured so crash reports can include its tail; debug
+    // builds still echo it to the console.
+    if cfg!(debug_assertions) {
+        command.stdout(Stdio::inherit()).stderr(Stdio::piped());
+    } else {
+        command.stdout(Stdio::null()).stderr(Stdio::piped());
+    }
+
+    let limits = load_app_settings(app)
+        .and_then(|settings| settings.engine_resource_limits)
+        .filter(|limits| limits.validate().is_ok())
+        .unwrap_or_default();
+    apply_before_spawn(&mut command, &limits);
+
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("Failed to launch engine sidecar via {launch_target}"))?;
+    let job = match assign_engine_job(&child, &limits) {
+        Ok(job) => Some(job),
+        Err(err) => {
+            emit_error(app, &format!("Engine started without resource limits: {err:#}"));
+            None
+        }
+    };
+    if let Err(err) = apply_after_spawn(&child, &limits) {
+        emit_error(app, &format!("Engine started without resource limits: {err:#}"));
+    }
+
+    let stderr_tail = Arc::new(Mutex::new(VecDeque::new()));
+    let stderr_reader = child.stderr.take().map(|stderr| {
+        let tail = stderr_tail.clone();
+        std::thread::spawn(move || {
+            use std::io::BufRead;
+            for line in std::io::BufReader::new(stderr).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if cfg!(debug_assertions) {
+                    eprintln!("{line}");
+                }
+                if let Ok(mut tail) = tail.lock() {
+                    if tail.len() >= ENGINE_STDERR_TAIL_LINES {
+                        tail.pop_front();
+                    }
+                    tail.push_back(line);
+                }
+            }
+        })
+    });
+    Ok(SpawnedEngine {
+        child,
+        job,
+        stderr_tail,
+        stderr_reader,
+    })
+}
+
+#[cfg(feature = "build-full")]
+async fn wait_for_engine_health(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<Value> {
+    for _ in 0..100 {
+        let (base_url, token, running, crash) = {
+            let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            let (running, _) = runtime_snapshot(&mut guard);
+            (guard.base_url.clone(), guard.token.clone(), running, guard.pending_crash.take())
+        };
+
+        if !running {
+            let Some(crash) = crash else {
+                return Err(anyhow!("Engine process exited during startup"));
+            };
+            let detail = crash
+                .stderr_tail
+                .last()
+                .map(|line| format!(": {line}"))
+                .unwrap_or_default();
+            let exit = crash
+                .exit_code
+                .map(|code| format!("exit code {code}"))
+                .unwrap_or_else(|| "no exit code".to_string());
+            let crash_id = crash.crash_id.clone();
+            report_engine_crash(app, state, crash);
+            return Err(anyhow!("Engine process exited during startup ({exit}, crash {crash_id}){detail}"));
+        }
+
+        match request_json(Method::GET, &format!("{base_url}/v1/health"), &token, None).await {
+            Ok(payload) => return Ok(payload),
+            Err(_) => sleep(Duration::from_millis(200)).await,
+        }
+    }
+
+    Err(anyhow!("Engine did not become healthy within startup timeout"))
+}
+
+async fn apply_custom_model_activation(state: &Arc<Mutex<EngineState>>) -> Result<Value> {
+    if !qwen_modes_enabled() {
+        return Err(anyhow!("Qwen activation is available in Full build only."));
+    }
+
+    let (base_url, token, speaker) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (
+            guard.base_url.clone(),
+            guard.token.clone(),
+            guard.selected_qwen_speaker.clone(),
+        )
+    };
+
+    let payload = json!({
+        "synth_backend": "qwen",
+        "active_model_id": "qwen3-tts-12hz-0.6b-customvoice",
+        "qwen_model_name": QWEN_CUSTOM_REPO,
+        "qwen_default_speaker": speaker,
+        "warmup_wait": true,
+        "warmup_force": true,
+        "reason": "app_custom_voice_activation",
+    });
+
+    request_json_long(
+        Method::POST,
+        &format!("{base_url}/v1/models/activate"),
+        &token,
+        Some(payload),
+    )
+    .await
+}
+
+async fn apply_kyutai_model_activation(state: &Arc<Mutex<EngineState>>) -> Result<Value> {
+    #[cfg(feature = "build-base")]
+    {
+        let selected_voice = {
+            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            guard.selected_kyutai_voice.clone()
+        };
+        if !KYUTAI_VOICE_PRESETS.iter().any(|row| row.id == selected_voice) {
+            return Err(anyhow!("Unsupported Kyutai preset voice: {selected_voice}"));
+        }
+        return engine_health_inner(state).await;
+    }
+
+    #[cfg(feature = "build-full")]
+    {
+    let (base_url, token, voice_prompt) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (
+            guard.base_url.clone(),
+            guard.token.clone(),
+            guard.selected_kyutai_voice.clone(),
+        )
+    };
+
+    let payload = json!({
+        "synth_backend": "kyutai",
+        "active_model_id": "kyutai-pocket-tts-ungated",
+        "kyutai_model_name": KYUTAI_REPO,
+        "kyutai_voice_prompt": voice_prompt,
+        "warmup_wait": true,
+        "warmup_force": true,
+        "reason": "app_kyutai_activation",
+    });
+
+    request_json_long(
+        Method::POST,
+        &format!("{base_url}/v1/models/activate"),
+        &token,
+        Some(payload),
+    )
+    .await
+    }
+
+    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
+    {
+        Err(anyhow!("Unsupported build variant for Kyutai model activation"))
+    }
+}
+
+async fn engine_health_inner(state: &Arc<Mutex<EngineState>>) -> Result<Value> {
+    #[cfg(feature = "build-base")]
+    {
+        let (runtime, selected_preset) = {
+            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            (
+                guard.local_kyutai.clone().ok_or_else(|| anyhow!("Kyutai Rust runtime is not initialized"))?,
+                guard.selected_kyutai_voice.clone(),
+            )
+        };
+        let runtime_guard = runtime
+            .lock()
+            .map_err(|_| anyhow!("Kyutai runtime lock poisoned"))?;
+        let preset_languages = preset_language_codes(&KYUTAI_VOICE_PRESETS);
+        return Ok(runtime_guard.health_payload(&selected_preset, &preset_languages));
+    }
+
+    #[cfg(feature = "build-full")]
+    {
+    let (base_url, token) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (guard.base_url.clone(), guard.token.clone())
+    };
+
+    request_json(Method::GET, &format!("{base_url}/v1/health"), &token, None).await
+    }
+
+    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
+    {
+        Err(anyhow!("Unsupported build variant for engine health"))
+    }
+}
+
+async fn engine_list_voices_inner(state: &Arc<Mutex<EngineState>>) -> Result<Value> {
+    #[cfg(feature = "build-base")]
+    {
+        let runtime = {
+            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+            guard
+                .local_kyutai
+                .clone()
+                .ok_or_else(|| anyhow!("Kyutai Rust runtime is not initialized"))?
+        };
+        let runtime_guard = runtime
+            .lock()
+            .map_err(|_| anyhow!("Kyutai runtime lock poisoned"))?;
+        return runtime_guard.list_voices_payload();
+    }
+
+    #[cfg(feature = "build-full")]
+    {
+    let (base_url, token) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (guard.base_url.clone(), guard.token.clone())
+    };
+
+    request_json(Method::GET, &format!("{base_url}/v1/voices"), &token, None).await
+    }
+
+    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
+    {
+        Err(anyhow!("Unsupported build variant for voice list"))
+    }
+}
+
+#[cfg(feature = "build-full")]
+async fn update_active_job_playback_full(state: &Arc<Mutex<EngineState>>, playback: Value) -> Result<()> {
+    let (base_url, token, job_id) = {
+        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (
+            guard.base_url.clone(),
+            guard.token.clone(),
+            guard.last_job_id.clone(),
+        )
+    };
+
+    let Some(job_id) = job_id else {
+        return Ok(());
+    };
+    if base_url.is_empty() || token.is_empty() {
+        return Ok(());
+    }
+
+    let _ = request_json(
+        Method::POST,
+        &format!("{base_url}/v1/jobs/{job_id}/playback"),
+        &token,
+        Some(playback),
+    )
+    .await?;
+
+    Ok(())
+}
+
+async fn request_json(method: Method, url: &str, token: &str, body: Option<Value>) -> Result<Value> {
+    tracked_request(method, url, token, body, true).await
+}
+
+/// For engine calls that can run for minutes (model downloads, activation,
+/// voice preload): only the connect timeout applies.
+async fn request_json_long(method: Method, url: &str, token: &str, body: Option<Value>) -> Result<Value> {
+    tracked_request(method, url, token, body, false).await
+}
+
+async fn tracked_request(method: Method, url: &str, token: &str, body: Option<Value>, bounded: bool) -> Result<Value> {
+    let label = format!("{method} {}", url.split_once("/v1/").map_or(url, |(_, path)| path));
+    if let Ok(mut in_flight) = ENGINE_REQUEST_IN_FLIGHT.lock() {
+        *in_flight = Some(label.clone());
+    }
+    let result = send_engine_request(method, url, token, body, bounded).await;
+    if let Ok(mut in_flight) = ENGINE_REQUEST_IN_FLIGHT.lock() {
+        if in_flight.as_deref() == Some(label.as_str()) {
+            *in_flight = None;
+        }
+    }
+    result
+}
+
+async fn send_json_request(method: Method, url: &str, token: &str, body: Option<Value>) -> Result<Value> {
+    send_engine_request(method, url, token, body, true).await
+}
+
+/// Sends one engine request, retrying failures that may be transient. With
+/// `bounded`, the whole request is limited to the configured timeout.
+async fn send_engine_request(
+    method: Method,
+    url: &str,
+    token: &str,
+    body: Option<Value>,
+    bounded: bool,
+) -> Result<Value> {
+    let settings = engine_http_settings();
+    let client = engine_http_client(&settings)?;
+    let mut attempt = 0;
+    let response = loop {
+        let mut request = client
+            .request(method.clone(), url)
+            .header("Authorization", format!("Bearer {token}"));
+        if bounded {
+            request = request.timeout(settings.request_timeout());
+        }
+        if let Some(payload) = body.as_ref() {
+            request = request.json(payload);
+        }
+
+        match request.send().await {
+            Ok(response) => break response,
+            Err(err) if attempt < settings.max_retries && is_retryable(&err, &method) => {
+                attempt += 1;
+                sleep(retry_delay(attempt)).await;
+            }
+            Err(err) if err.is_timeout() && !err.is_connect() => {
+                return Err(anyhow!(CodedError::new(
+                    ErrorCode::EngineRequestFailed,
+                    format!("Engine did not answer {url} within {} ms", settings.request_timeout_ms)
+                )))
+            }
+            Err(err) => {
+                return Err(err)
+                    .context(CodedError::new(ErrorCode::EngineNotReady, "Engine is not reachable"))
+                    .with_context(|| format!("Request failed for {url}"))
+            }
+        }
+    };
+    let status = response.status();
+    if !status.is_success() {
+        let body_text = response.text().await.unwrap_or_else(|_| String::new());
+        return Err(anyhow!(engine_response_error(url, status, body_text)));
+    }
+
+    response
+        .json::<Value>()
+        .await
+        .with_context(|| format!("Failed to decode JSON response for {url}"))
+}
+
+/// Codes a failed engine response by the `error.code` of its JSON body,
+/// keeping the body as detail.
+fn engine_response_error(url: &str, status: StatusCode, body_text: String) -> CodedError {
+    let code = serde_json::from_str::<Value>(&body_text)
+        .ok()
+        .and_then(|body| body.pointer("/error/code").and_then(Value::as_str).map(ErrorCode::from_engine))
+        .unwrap_or(ErrorCode::EngineRequestFailed);
+    CodedError::new(code, format!("Request to {url} failed with status {status}: {body_text}")).with_detail(body_text)
+}
+
+fn engine_http_settings() -> EngineHttpSettings {
+    ENGINE_HTTP_SETTINGS
+        .lock()
+        .ok()
+        .and_then(|settings| settings.clone())
+        .unwrap_or_default()
+}
+
+fn apply_engine_http_settings(settings: EngineHttpSettings) {
+    if let Ok(mut current) = ENGINE_HTTP_SETTINGS.lock() {
+        let reconnect = current
+            .as_ref()
+            .is_none_or(|current| current.connect_timeout_ms != settings.connect_timeout_ms);
+        if reconnect {
+            if let Ok(mut client) = ENGINE_HTTP_CLIENT.lock() {
+                *client = None;
+            }
+        }
+        *current = Some(settings);
+    }
+}
+
+fn engine_http_client(settings: &EngineHttpSettings) -> Result<Client> {
+    let mut shared = ENGINE_HTTP_CLIENT
+        .lock()
+        .map_err(|_| anyhow!("Engine HTTP client lock poisoned"))?;
+    if let Some(client) = shared.as_ref() {
+        return Ok(client.clone());
+    }
+    let client = settings.build_client()?;
+    *shared = Some(client.clone());
+    Ok(client)
+}
+
+fn build_variant_name() -> &'static str {
+    BUILD_VARIANT
+}
+
+fn qwen_modes_enabled() -> bool {
+    cfg!(feature = "build-full")
+}
+
+fn model_options() -> Vec<ModelOption> {
+    let mut options = vec![ModelOption {
+        id: MODEL_KYUTAI.to_string(),
+        label: "Kyutai Pocket TTS".to_string(),
+        status: "ready".to_string(),
+        notes: format!("Main read-aloud path ({KYUTAI_REPO})"),
+    }];
+
+    if qwen_modes_enabled() {
+        options.push(ModelOption {
+            id: MODEL_CUSTOM.to_string(),
+            label: "Qwen CustomVoice (preset speakers)".to_string(),
+            status: "ready".to_string(),
+            notes: format!("Secondary path ({QWEN_CUSTOM_REPO})"),
+        });
+        options.push(ModelOption {
+            id: MODEL_BASE.to_string(),
+            label: "Qwen Base (clone path)".to_string(),
+            status: "planned".to_string(),
+            notes: format!("Model repo prefetched: {QWEN_BASE_REPO}"),
+        });
+    }
+
+    options
+}
+
+fn speaker_presets(model: &str) -> Vec<SpeakerPreset> {
+    let presets: &[SpeakerPresetRow] = match model {
+        MODEL_KYUTAI => &KYUTAI_VOICE_PRESETS,
+        _ if qwen_modes_enabled() => &QWEN_SPEAKER_PRESETS,
+        _ => &KYUTAI_VOICE_PRESETS,
+    };
+
+    presets
+        .iter()
+        .map(|row| SpeakerPreset {
+            id: row.id.to_string(),
+            description: row.description.to_string(),
+            native_language: row.native_language.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(feature = "build-base")]
+fn preset_language_codes(presets: &[SpeakerPresetRow]) -> Vec<String> {
+    presets
+        .iter()
+        .map(|row| language_code(row.native_language))
+        .collect()
+}
+
+/// Maps a preset's display language (e.g. "Chinese (Sichuan Dialect)") to the
+/// short code used by `language_hint`.
+#[cfg(feature = "build-base")]
+fn language_code(native_language: &str) -> String {
+    let base = native_language
+        .split('(')
+        .next()
+        .unwrap_or(native_language)
+        .trim()
+        .to_ascii_lowercase();
+    match base.as_str() {
+        "english" => "en",
+        "chinese" => "zh",
+        "japanese" => "ja",
+        "korean" => "ko",
+        "german" => "de",
+        "french" => "fr",
+        "spanish" => "es",
+        "portuguese" => "pt",
+        "russian" => "ru",
+        "italian" => "it",
+        _ => return base,
+    }
+    .to_string()
+}
+
+fn active_speaker_for_model(state: &EngineState) -> String {
+    match state.selected_model.as_str() {
+        MODEL_KYUTAI => state.selected_kyutai_voice.clone(),
+        _ => state.selected_qwen_speaker.clone(),
+    }
+}
+
+fn default_hotkey() -> String {
+    #[cfg(target_os = "macos")]
+    {
+        return "Option+S".to_string();
+    }
+    #[cfg(target_os = "windows")]
+    {
+        return "Alt+S".to_string();
+    }
+    #[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
+    {
+        "Ctrl+Shift+S".to_string()
+    }
+}
+
+fn normalize_optional_text(value: Option<String>) -> Option<String> {
+    value.and_then(|raw| {
+        let normalized = raw.trim().to_string();
+        if normalized.is_empty() {
+            None
+        } else {
+            Some(normalized)
+        }
+    })
+}
+
+/// The read hotkey with Shift (or Alt, if Shift is taken) added. `None` when
+/// it already uses both.
+fn queue_hotkey_variant(hotkey: &str) -> Option<String> {
+    let used: Vec<String> = hotkey
+        .split('+')
+        .map(|part| match part.to_ascii_lowercase().as_str() {
+            "option" => "alt".to_string(),
+            other => other.to_string(),
+        })
+        .collect();
+    QUEUE_HOTKEY_MODIFIERS
+        .iter()
+        .find(|modifier| !used.contains(&modifier.to_ascii_lowercase()))
+        .map(|modifier| format!("{modifier}+{hotkey}"))
+}
+
+fn normalize_hotkey(value: &str) -> Result<String> {
+    let normalized = value.trim().replace(' ', "");
+    if normalized.is_empty() {
+        return Err(anyhow!("Hotkey cannot be empty"));
+    }
+    Ok(normalized)
+}
+
+fn clamp_speak_rate(rate: f32) -> f32 {
+    rate.clamp(0.25, 4.0)
+}
+
+/// Which respellings the selected model gets from the lexicon.
+fn respelling_backend(selected_model: &str) -> RespellingBackend {
+    if selected_model == MODEL_KYUTAI {
+        RespellingBackend::Kyutai
+    } else {
+        RespellingBackend::Qwen
+    }
+}
+
+/// Runs the optional translation stage. Translated text is spoken with the
+/// configured target voice when one is set.
+async fn translate_for_speech(
+    translation: &TranslationSettings,
+    text: String,
+    voice_id: String,
+) -> Result<(String, String)> {
+    let Some(translated) = translate_text(translation, &text).await? else {
+        return Ok((text, voice_id));
+    };
+    let voice_id = translation
+        .target_voice_id
+        .clone()
+        .filter(|id| !id.trim().is_empty())
+        .unwrap_or(voice_id);
+    Ok((translated, voice_id))
+}
+
+#[cfg(feature = "build-base")]
+fn local_stream_settings(settings: &SpeakSettingsState, language: Option<String>) -> StreamSettings {
+    StreamSettings {
+        chunk_max_chars: settings.chunk_max_chars,
+        language,
+        silence_trim: settings.silence_trim,
+        peak_limiter: settings.peak_limiter,
+        limiter_report: Arc::new(Mutex::new(None)),
+        volume: Arc::new(AtomicU32::new(settings.volume.to_bits())),
+        prefetch_depth: settings.prefetch_depth as usize,
+        paused: Arc::new(AtomicBool::new(false)),
+        held_micros: Arc::new(AtomicU64::new(0)),
+    }
+}
+
+#[cfg(feature = "build-base")]
+fn rate_to_steps(rate: f32) -> u32 {
+    (clamp_speak_rate(rate) * 4.0).round().clamp(1.0, 16.0) as u32
+}
+
+fn is_hotkey_os_reserved(hotkey: &str) -> bool {
+    let normalized = hotkey.trim().to_lowercase().replace(' ', "");
+    matches!(
+        normalized.as_str(),
+        "alt+space" | "cmd+space" | "command+space" | "meta+space" | "super+space"
+    )
+}
+
+fn load_app_settings(app: &AppHandle) -> Option<AppSettingsFile> {
+    let path = app_settings_path(app)?;
+    let body = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&body).ok()
+}
+
+/// The saved hotkey map, or one made from the `hotkey` and
+/// `clipboard_hotkey` entries of older settings files. Invalid, reserved and
+/// duplicate accelerators are dropped; read-selection falls back to the
+/// default.
+fn load_saved_hotkeys(app: &AppHandle) -> Option<HotkeyMap> {
+    let settings = load_app_settings(app)?;
+    let saved: Vec<(BindableAction, String)> = match settings.hotkeys {
+        Some(hotkeys) => hotkeys
+            .bindings()
+            .map(|(action, hotkey)| (action, hotkey.to_string()))
+            .collect(),
+        None => [
+            (BindableAction::ReadSelection, settings.hotkey),
+            (BindableAction::ReadClipboard, settings.clipboard_hotkey),
+        ]
+        .into_iter()
+        .filter_map(|(action, hotkey)| Some((action, hotkey?)))
+        .collect(),
+    };
+    let mut hotkeys = HotkeyMap::new(default_hotkey());
+    for (action, candidate) in saved {
+        let Ok(normalized) = normalize_hotkey(&candidate) else {
+            continue;
+        };
+        let free = hotkeys.action_for(&normalized).is_none_or(|bound| bound == action);
+        if free && !is_hotkey_os_reserved(&normalized) {
+            hotkeys.set(action, Some(normalized));
+        }
+    }
+    Some(hotkeys)
+}
+
+fn load_saved_orientation_hotkey(app: &AppHandle) -> Option<String> {
+    let candidate = load_app_settings(app)?.orientation_hotkey?;
+    let normalized = normalize_hotkey(&candidate).ok()?;
+    if is_hotkey_os_reserved(&normalized) {
+        return None;
+    }
+    Some(normalized)
+}
+
+fn load_saved_read_window_hotkey(app: &AppHandle) -> Option<String> {
+    let candidate = load_app_settings(app)?.read_window_hotkey?;
+    let normalized = normalize_hotkey(&candidate).ok()?;
+    if is_hotkey_os_reserved(&normalized) {
+        return None;
+    }
+    Some(normalized)
+}
+
+fn load_saved_summary_hotkey(app: &AppHandle) -> Option<String> {
+    let candidate = load_app_settings(app)?.summary_hotkey?;
+    let normalized = normalize_hotkey(&candidate).ok()?;
+    if is_hotkey_os_reserved(&normalized) {
+        return None;
+    }
+    Some(normalized)
+}
+
+fn load_saved_fallback_hotkeys(app: &AppHandle) -> Option<Vec<String>> {
+    let candidates = load_app_settings(app)?.fallback_hotkeys?;
+    let mut hotkeys: Vec<String> = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        let Ok(normalized) = normalize_hotkey(&candidate) else {
+            continue;
+        };
+        if !is_hotkey_os_reserved(&normalized) && !hotkeys.contains(&normalized) {
+            hotkeys.push(normalized);
+        }
+    }
+    Some(hotkeys)
+}
+
+fn load_saved_translation_settings(app: &AppHandle) -> Option<TranslationSettings> {
+    let settings = load_app_settings(app)?.translation?;
+    settings.validate().ok()?;
+    Some(settings)
+}
+
+fn load_saved_summary_settings(app: &AppHandle) -> Option<SummarySettings> {
+    let settings = load_app_settings(app)?.summary?;
+    settings.validate().ok()?;
+    Some(settings)
+}
+
+fn load_saved_number_format(app: &AppHandle) -> Option<NumberSettings> {
+    let settings = load_app_settings(app)?.number_format?;
+    settings.validate().ok()?;
+    Some(settings)
+}
+
+fn load_saved_acronym_settings(app: &AppHandle) -> Option<AcronymSettings> {
+    let settings = load_app_settings(app)?.acronyms?;
+    settings.validate().ok()?;
+    Some(settings)
+}
+
+fn load_saved_lexicon(app: &AppHandle) -> Option<PronunciationLexicon> {
+    let lexicon = load_app_settings(app)?.lexicon?;
+    lexicon.validate().ok()?;
+    Some(lexicon)
+}
+
+fn load_saved_readback_settings(app: &AppHandle) -> Option<ReadbackSettings> {
+    let settings = load_app_settings(app)?.readback?;
+    settings.validate().ok()?;
+    Some(settings)
+}
+
+fn load_saved_webhooks(app: &AppHandle) -> Option<WebhookSettings> {
+    let settings = load_app_settings(app)?.webhooks?;
+    settings.validate().ok()?;
+    Some(settings)
+}
+
+fn load_saved_audio_sinks(app: &AppHandle) -> Option<AudioSinkSettings> {
+    let settings = load_app_settings(app)?.audio_sinks?;
+    settings.validate().ok()?;
+    Some(settings)
+}
+
+fn load_saved_cast_target(app: &AppHandle) -> Option<CastTarget> {
+    load_app_settings(app)?.cast_target
+}
+
+fn load_saved_playback_mode(app: &AppHandle) -> Option<PlaybackMode> {
+    load_app_settings(app)?.playback_mode
+}
+
+fn load_saved_chunk_pacing(app: &AppHandle) -> Option<ChunkPacing> {
+    load_app_settings(app)?.chunk_pacing.filter(|pacing| pacing.validate().is_ok())
+}
+
+fn load_saved_speed_training(app: &AppHandle) -> Option<SpeedTrainingSettings> {
+    load_app_settings(app)?.speed_training.filter(|training| training.validate().is_ok())
+}
+
+fn load_saved_queue_policy(app: &AppHandle) -> Option<QueuePolicy> {
+    load_app_settings(app)?.queue_policy
+}
+
+fn load_saved_quiet_hours(app: &AppHandle) -> Option<QuietHoursSettings> {
+    load_app_settings(app)?.quiet_hours.filter(|quiet_hours| quiet_hours.validate().is_ok())
+}
+
+fn load_saved_peak_limiter(app: &AppHandle) -> Option<PeakLimiter> {
+    load_app_settings(app)?.peak_limiter.filter(|limiter| limiter.validate().is_ok())
+}
+
+fn load_saved_capture_overrides(app: &AppHandle) -> Option<CaptureOverrides> {
+    load_app_settings(app)?.capture_overrides.filter(|overrides| overrides.validate().is_ok())
+}
+
+/// Restores what `persist_speak_state` saved. Values out of range, unknown
+/// presets and models this build does not have keep their defaults.
+fn load_saved_speak_state(app: &AppHandle, guard: &mut EngineState) {
+    let Some(settings) = load_app_settings(app) else {
+        return;
+    };
+    if let Some(rate) = settings.rate.filter(|rate| (0.25..=4.0).contains(rate)) {
+        guard.speak_settings.rate = rate;
+    }
+    if let Some(volume) = settings.volume.filter(|volume| (0.0..=2.0).contains(volume)) {
+        guard.speak_settings.volume = volume;
+    }
+    if let Some(chunk_max_chars) = settings.chunk_max_chars.filter(|chars| (100..=2000).contains(chars)) {
+        guard.speak_settings.chunk_max_chars = chunk_max_chars;
+    }
+    #[cfg(feature = "build-base")]
+    if let Some(depth) = settings.prefetch_depth.filter(|depth| *depth as usize <= MAX_PREFETCH_DEPTH) {
+        guard.speak_settings.prefetch_depth = depth;
+    }
+    if settings.silence_trim.as_ref().is_none_or(|trim| trim.validate().is_ok()) {
+        guard.speak_settings.silence_trim = settings.silence_trim;
+    }
+    if let Some(model) = settings.selected_model.filter(|model| {
+        model == MODEL_KYUTAI || (qwen_modes_enabled() && (model == MODEL_CUSTOM || model == MODEL_BASE))
+    }) {
+        guard.selected_model = model;
+    }
+    if let Some(voice_id) = settings.selected_voice_id.map(|id| id.trim().to_string()).filter(|id| !id.is_empty()) {
+        guard.selected_voice_id = voice_id;
+    }
+    if let Some(speaker) = settings
+        .qwen_speaker
+        .filter(|speaker| QWEN_SPEAKER_PRESETS.iter().any(|row| row.id == speaker))
+    {
+        guard.selected_qwen_speaker = speaker;
+    }
+    if let Some(preset) = settings
+        .kyutai_preset
+        .filter(|preset| KYUTAI_VOICE_PRESETS.iter().any(|row| row.id == preset))
+    {
+        guard.selected_kyutai_voice = preset;
+    }
+}
+
+fn load_saved_virtual_mic_device(app: &AppHandle) -> Option<String> {
+    let device = load_app_settings(app)?.virtual_mic_device?;
+    virtual_mic::validate_device(&device).ok()?;
+    Some(device)
 }
 
-#[cfg(feature = "build-full")]
-async fn relay_ws_events(
-    app: &AppHandle,
-    state: &Arc<Mutex<EngineState>>,
-    ws_url: &str,
-    token: &str,
-    job_id: &str,
-) -> Result<()> {
-    let protocol_header = format!("auth.bearer.v1, {token}");
-    let mut request = ws_url
-        .into_client_request()
-        .context("Failed to construct WS request")?;
-    request
-        .headers_mut()
-        .insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_str(&protocol_header)?);
+fn load_saved_pipe_endpoint(app: &AppHandle) -> Option<PipeEndpointSettings> {
+    load_app_settings(app)?.pipe_endpoint
+}
 
-    let (mut socket, _) = tokio_tungstenite::connect_async(request)
-        .await
-        .context("Failed to connect WS stream")?;
+fn load_saved_engine_http(app: &AppHandle) -> Option<EngineHttpSettings> {
+    let settings = load_app_settings(app)?.engine_http?;
+    settings.validate().ok()?;
+    Some(settings)
+}
 
-    while let Some(message) = socket.next().await {
-        if is_job_suppressed(state, job_id) {
-            break;
-        }
-        match message {
-            Ok(Message::Text(text)) => {
-                let parsed: Value = serde_json::from_str(&text)
-                    .unwrap_or_else(|_| json!({ "type": "RAW_TEXT", "raw": text }));
+fn persist_hotkeys(app: &AppHandle, hotkeys: &HotkeyMap) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.hotkeys = Some(hotkeys.clone());
+        settings.hotkey = None;
+        settings.clipboard_hotkey = None;
+    })
+}
 
-                if is_job_suppressed(state, job_id) {
-                    break;
-                }
+fn persist_fallback_hotkeys(app: &AppHandle, hotkeys: &[String]) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.fallback_hotkeys = Some(hotkeys.to_vec());
+    })
+}
 
-                let _ = app.emit_all("voicereader:ws-event", parsed.clone());
+fn persist_orientation_hotkey(app: &AppHandle, hotkey: Option<&str>) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.orientation_hotkey = hotkey.map(str::to_string);
+    })
+}
 
-                if let Some(kind) = parsed.get("type").and_then(Value::as_str) {
-                    if TERMINAL_EVENTS.contains(&kind) {
-                        break;
-                    }
-                }
-            }
-            Ok(Message::Close(_)) => break,
-            Ok(_) => {}
-            Err(err) => {
-                return Err(anyhow!("WS stream read error: {err}"));
-            }
-        }
-    }
+fn persist_read_window_hotkey(app: &AppHandle, hotkey: Option<&str>) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.read_window_hotkey = hotkey.map(str::to_string);
+    })
+}
 
-    let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-    if guard.last_job_id.as_deref() == Some(job_id) {
-        guard.last_job_id = None;
-    }
-    guard.suppressed_job_ids.remove(job_id);
-    Ok(())
+fn persist_summary_hotkey(app: &AppHandle, hotkey: Option<&str>) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.summary_hotkey = hotkey.map(str::to_string);
+    })
 }
 
-#[cfg(feature = "build-full")]
-fn is_job_suppressed(state: &Arc<Mutex<EngineState>>, job_id: &str) -> bool {
-    match state.lock() {
-        Ok(guard) => guard.suppressed_job_ids.contains(job_id),
-        Err(_) => false,
-    }
+fn persist_translation_settings(app: &AppHandle, translation: &TranslationSettings) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.translation = Some(translation.clone());
+    })
 }
 
-async fn ensure_engine_ready(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
-    let running = {
-        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-        runtime_snapshot(&mut guard).0
-    };
+fn persist_summary_settings(app: &AppHandle, summary: &SummarySettings) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.summary = Some(summary.clone());
+    })
+}
 
-    if running {
-        return Ok(());
-    }
+fn persist_number_format(app: &AppHandle, number_format: &NumberSettings) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.number_format = Some(number_format.clone());
+    })
+}
 
-    initialize_engine_if_needed(app, state).await
+fn persist_acronym_settings(app: &AppHandle, acronyms: &AcronymSettings) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.acronyms = Some(acronyms.clone());
+    })
 }
 
-async fn initialize_engine_if_needed(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<()> {
-    let running = {
-        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-        runtime_snapshot(&mut guard).0
-    };
-    if running {
-        return Ok(());
-    }
+fn persist_lexicon(app: &AppHandle, lexicon: &PronunciationLexicon) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.lexicon = Some(lexicon.clone());
+    })
+}
 
-    #[cfg(feature = "build-base")]
-    {
-        let engine_root = find_engine_root().ok();
-        let data_dir = resolve_engine_data_dir(app, engine_root.as_deref())?;
-        std::fs::create_dir_all(&data_dir).context("Failed to create engine data dir")?;
-        let models_dir = data_dir.join("models");
-        let hf_cache_dir = data_dir.join("hf-cache");
-        std::fs::create_dir_all(&models_dir).context("Failed to create models dir")?;
-        std::fs::create_dir_all(&hf_cache_dir).context("Failed to create hf-cache dir")?;
+fn persist_readback_settings(app: &AppHandle, readback: &ReadbackSettings) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.readback = Some(readback.clone());
+    })
+}
 
-        let model_dir = resolve_bundled_kyutai_model_dir(app)
-            .or_else(|| {
-                let candidate = models_dir.join("Verylicious").join("pocket-tts-ungated");
-                if is_kyutai_model_dir(&candidate) {
-                    Some(candidate)
-                } else {
-                    None
-                }
-            })
-            .ok_or_else(|| {
-                anyhow!(
-                    "Bundled Kyutai model directory not found. Expected resources/models/Verylicious/pocket-tts-ungated"
-                )
-            })?;
+fn persist_webhooks(app: &AppHandle, webhooks: &WebhookSettings) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.webhooks = Some(webhooks.clone());
+    })
+}
 
-        let runtime = LocalKyutaiRuntime::new(&model_dir, &data_dir, KYUTAI_REPO, "alba")?;
-        {
-            let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-            guard.local_kyutai = Some(Arc::new(Mutex::new(runtime)));
-            guard.base_url = "local://kyutai".to_string();
-            guard.token.clear();
-            guard.port = 0;
-            guard.data_dir = data_dir.to_string_lossy().to_string();
-            guard.models_dir = models_dir.to_string_lossy().to_string();
-            guard.hf_cache_dir = hf_cache_dir.to_string_lossy().to_string();
-            guard.last_job_id = None;
-            guard.active_cancel_flag = None;
-            guard.active_rate_steps = None;
-            guard.suppressed_job_ids.clear();
-            guard.selected_model = MODEL_KYUTAI.to_string();
-        }
+fn persist_audio_sinks(app: &AppHandle, audio_sinks: &AudioSinkSettings) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.audio_sinks = Some(audio_sinks.clone());
+    })
+}
 
-        let health = engine_health_inner(state).await?;
-        let _ = app.emit_all("voicereader:engine-ready", health);
+fn persist_cast_target(app: &AppHandle, cast_target: Option<&CastTarget>) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.cast_target = cast_target.cloned();
+    })
+}
 
-        if let Ok(mut guard) = state.lock() {
-            guard.startup_error = None;
-        }
-        return Ok(());
-    }
+fn persist_playback_mode(app: &AppHandle, mode: PlaybackMode) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.playback_mode = Some(mode);
+    })
+}
 
-    #[cfg(feature = "build-full")]
-    {
-    let engine_root = find_engine_root().ok();
+fn persist_chunk_pacing(app: &AppHandle, pacing: ChunkPacing) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.chunk_pacing = Some(pacing);
+    })
+}
 
-    let token = generate_token();
-    let port = portpicker::pick_unused_port().ok_or_else(|| anyhow!("Failed to find a free localhost port"))?;
-    let base_url = format!("http://127.0.0.1:{port}");
-    let data_dir = resolve_engine_data_dir(app, engine_root.as_deref())?;
-    std::fs::create_dir_all(&data_dir).context("Failed to create engine data dir")?;
-    let models_dir = data_dir.join("models");
-    let hf_cache_dir = data_dir.join("hf-cache");
+fn persist_queue_policy(app: &AppHandle, policy: QueuePolicy) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.queue_policy = Some(policy);
+    })
+}
 
-    let (mut command, launch_target) = build_engine_launch_command(app, engine_root.as_deref(), port, &data_dir)?;
-    let kyutai_model_setting = resolve_bundled_kyutai_model_dir(app)
-        .map(|path| path.to_string_lossy().to_string())
-        .unwrap_or_else(|| KYUTAI_REPO.to_string());
-    command
-        .env("SPEAK_SELECTION_ENGINE_TOKEN", &token)
-        .env("VOICEREADER_SYNTH_BACKEND", "auto")
-        .env("VOICEREADER_KYUTAI_MODEL", kyutai_model_setting)
-        .env("VOICEREADER_KYUTAI_VOICE_PROMPT", "alba")
-        .env("VOICEREADER_QWEN_MODEL", QWEN_CUSTOM_REPO)
-        .env("VOICEREADER_QWEN_SPEAKER", "Ryan");
+fn persist_speed_training(app: &AppHandle, training: SpeedTrainingSettings) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.speed_training = Some(training);
+    })
+}
 
-    if cfg!(target_os = "windows") {
-        // FlashAttention2 is often unavailable on Windows; use SDPA directly for stable startup.
-        command.env("VOICEREADER_QWEN_ATTN_IMPLEMENTATION", "sdpa");
-    }
+fn persist_quiet_hours(app: &AppHandle, quiet_hours: &QuietHoursSettings) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.quiet_hours = Some(quiet_hours.clone());
+    })
+}
 
-    #[cfg(target_os = "windows")]
-    {
-        use std::os::windows::process::CommandExt;
-        const CREATE_NO_WINDOW: u32 = 0x08000000;
-        command.creation_flags(CREATE_NO_WINDOW);
-    }
+fn persist_peak_limiter(app: &AppHandle, limiter: PeakLimiter) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.peak_limiter = Some(limiter);
+    })
+}
 
-    if cfg!(debug_assertions) {
-        command.stdout(Stdio::inherit()).stderr(Stdio::inherit());
-    } else {
-        command.stdout(Stdio::null()).stderr(Stdio::null());
+fn persist_capture_overrides(app: &AppHandle, overrides: &CaptureOverrides) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.capture_overrides = Some(overrides.clone());
+    })
+}
+
+/// Saves the speak settings (including prefetch depth on Base builds and
+/// silence trimming) and the model, voice and preset selection, so
+/// the next launch starts with them. A failed write is reported without
+/// undoing the change.
+fn persist_speak_state(app: &AppHandle, state: &Mutex<EngineState>) {
+    let Ok(guard) = state.lock() else {
+        return;
+    };
+    let speak = guard.speak_settings.clone();
+    let selected_model = guard.selected_model.clone();
+    let selected_voice_id = guard.selected_voice_id.clone();
+    let qwen_speaker = guard.selected_qwen_speaker.clone();
+    let kyutai_preset = guard.selected_kyutai_voice.clone();
+    drop(guard);
+
+    let result = persist_app_settings(app, |settings| {
+        settings.rate = Some(speak.rate);
+        settings.volume = Some(speak.volume);
+        settings.chunk_max_chars = Some(speak.chunk_max_chars);
+        if cfg!(feature = "build-base") {
+            settings.prefetch_depth = Some(speak.prefetch_depth);
+        }
+        settings.silence_trim = speak.silence_trim;
+        settings.selected_model = Some(selected_model);
+        settings.selected_voice_id = Some(selected_voice_id);
+        settings.qwen_speaker = Some(qwen_speaker);
+        settings.kyutai_preset = Some(kyutai_preset);
+    });
+    if let Err(err) = result {
+        emit_error(app, &format!("Speak settings updated but could not persist settings: {err:#}"));
     }
+}
 
-    let child = command
-        .spawn()
-        .with_context(|| format!("Failed to launch engine sidecar via {launch_target}"))?;
+fn persist_virtual_mic_device(app: &AppHandle, device: &str) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.virtual_mic_device = Some(device.to_string());
+    })
+}
 
-    {
-        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-        guard.child = Some(child);
-        guard.token = token;
-        guard.port = port;
-        guard.base_url = base_url;
-        guard.data_dir = data_dir.to_string_lossy().to_string();
-        guard.models_dir = models_dir.to_string_lossy().to_string();
-        guard.hf_cache_dir = hf_cache_dir.to_string_lossy().to_string();
-        guard.last_job_id = None;
-        guard.suppressed_job_ids.clear();
-    }
+fn persist_pipe_endpoint(app: &AppHandle, pipe_endpoint: &PipeEndpointSettings) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.pipe_endpoint = Some(pipe_endpoint.clone());
+    })
+}
 
-    let health = wait_for_engine_health(state).await?;
-    let _ = app.emit_all("voicereader:engine-ready", health);
+fn persist_engine_http(app: &AppHandle, engine_http: &EngineHttpSettings) -> Result<()> {
+    persist_app_settings(app, |settings| {
+        settings.engine_http = Some(engine_http.clone());
+    })
+}
 
-    if let Ok(mut guard) = state.lock() {
-        guard.startup_error = None;
+fn persist_app_settings(app: &AppHandle, update: impl FnOnce(&mut AppSettingsFile)) -> Result<()> {
+    let path = app_settings_path(app).ok_or_else(|| anyhow!("Unable to resolve app settings path"))?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).with_context(|| {
+            format!("Failed to create app settings directory {}", parent.display())
+        })?;
     }
 
-    let selected_model = {
-        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-        guard.selected_model.clone()
+    // Only a missing file starts from defaults: merging onto defaults after a
+    // parse failure would overwrite every setting the user had.
+    let mut settings: AppSettingsFile = match std::fs::read_to_string(&path) {
+        Ok(body) => serde_json::from_str(&body).with_context(|| {
+            format!("{} could not be parsed and is left unchanged; fix or remove it to save settings", path.display())
+        })?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => AppSettingsFile::default(),
+        Err(err) => return Err(err).with_context(|| format!("Failed to read app settings file {}", path.display())),
     };
-    if selected_model == MODEL_CUSTOM {
-        let _ = apply_custom_model_activation(state).await;
-    } else if selected_model == MODEL_KYUTAI {
-        let _ = apply_kyutai_model_activation(state).await;
+    if settings.schema_version > SETTINGS_SCHEMA_VERSION {
+        return Err(anyhow!(
+            "{} was written by a newer version of the app (schema {}) and is left unchanged",
+            path.display(),
+            settings.schema_version
+        ));
     }
-
-    Ok(())
+    update(&mut settings);
+    settings.schema_version = SETTINGS_SCHEMA_VERSION;
+    let serialized = serde_json::to_string_pretty(&settings)?;
+    // Written aside and renamed, so the settings watcher never reads half a file.
+    let temp_path = path.with_extension(format!("json.{:08x}.tmp", rand::random::<u32>()));
+    let written = std::fs::write(&temp_path, serialized).and_then(|()| std::fs::rename(&temp_path, &path));
+    if let Err(err) = written {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(err).with_context(|| format!("Failed to write app settings file {}", path.display()));
     }
+    Ok(())
+}
 
-    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
-    {
-        Err(anyhow!("Unsupported build variant for engine initialization"))
-    }
+/// Checks `schedules.json` for due reads. Each run is recorded before the
+/// read is queued, so a failing target does not fire again on every poll.
+fn spawn_schedule_runner(app: AppHandle, state: Arc<Mutex<EngineState>>) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(SCHEDULE_POLL_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            let due = match load_schedules(&app) {
+                Ok(mut store) => {
+                    let due = store.take_due(chrono::Local::now());
+                    if due.is_empty() {
+                        continue;
+                    }
+                    if let Err(err) = save_schedules(&app, &store) {
+                        emit_error(&app, &format!("Failed to update schedules: {err:#}"));
+                        continue;
+                    }
+                    due
+                }
+                Err(err) => {
+                    emit_error(&app, &format!("Failed to load schedules: {err:#}"));
+                    continue;
+                }
+            };
+
+            for schedule in due {
+                match run_scheduled_read(&app, &state, &schedule).await {
+                    Ok(job_id) => {
+                        let _ = app.emit_routed(
+                            "voicereader:schedule-fired",
+                            ScheduleFiredPayload {
+                                id: schedule.id,
+                                label: schedule.label,
+                                job_id,
+                            },
+                        );
+                    }
+                    Err(err) => emit_error(&app, &format!("Scheduled read \"{}\" failed: {err:#}", schedule.label)),
+                }
+            }
+        }
+    });
 }
 
-async fn shutdown_engine(state: &Arc<Mutex<EngineState>>) {
-    #[cfg(feature = "build-base")]
-    {
-        let mut guard = match state.lock() {
-            Ok(v) => v,
-            Err(_) => return,
-        };
-        if let Some(cancel_flag) = guard.active_cancel_flag.as_ref() {
-            cancel_flag.store(true, Ordering::SeqCst);
+/// Queues the reads held during quiet hours once they end, in the order they
+/// were captured.
+fn spawn_quiet_hours_release(app: AppHandle, state: Arc<Mutex<EngineState>>) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(QUIET_HOURS_POLL_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            let held = match state.lock() {
+                Ok(mut guard) => guard.quiet_hours.release_due(chrono::Local::now()),
+                Err(_) => continue,
+            };
+            if held.is_empty() {
+                continue;
+            }
+            let _ = app.emit_routed(
+                "voicereader:quiet-hours-released",
+                QuietHoursReleasedPayload { released: held.len() },
+            );
+            if let Err(err) = ensure_engine_ready(&app, &state).await {
+                emit_error(&app, &format!("{} reads held for quiet hours were dropped: {err:#}", held.len()));
+                continue;
+            }
+            for read in held {
+                if let Err(err) = enqueue_read(&app, &state, read).await {
+                    emit_error(&app, &format!("Read held for quiet hours failed to start: {err:#}"));
+                }
+            }
         }
-        guard.active_cancel_flag = None;
-        guard.active_rate_steps = None;
-        guard.local_kyutai = None;
-        guard.last_job_id = None;
-        guard.suppressed_job_ids.clear();
-        return;
-    }
+    });
+}
 
-    #[cfg(feature = "build-full")]
-    {
-    let (base_url, token) = {
-        let guard = match state.lock() {
-            Ok(v) => v,
-            Err(_) => return,
+/// Polls `settings.json` and applies edits made outside the app.
+///
+/// Only fields whose value changed in the file are considered, and each is
+/// applied only if it differs from the live state, so the app's own writes
+/// are not re-applied.
+fn spawn_settings_watcher(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let Some(path) = app_settings_path(&app) else {
+            return;
         };
-        (guard.base_url.clone(), guard.token.clone())
-    };
+        let mut last_body = std::fs::read_to_string(&path).ok();
+        let mut last_settings: AppSettingsFile = last_body
+            .as_deref()
+            .and_then(|body| serde_json::from_str(body).ok())
+            .unwrap_or_default();
+        let mut interval = tokio::time::interval(Duration::from_millis(SETTINGS_WATCH_INTERVAL_MS));
+        loop {
+            interval.tick().await;
+            let body = std::fs::read_to_string(&path).ok();
+            if body == last_body {
+                continue;
+            }
+            last_body = body.clone();
+            // A deleted file keeps the current settings until it is recreated.
+            let Some(body) = body else {
+                continue;
+            };
+            let parsed: AppSettingsFile = match serde_json::from_str(&body) {
+                Ok(parsed) => parsed,
+                Err(err) => {
+                    emit_error(&app, &format!("Ignoring invalid {SETTINGS_FILE_NAME} edit: {err}"));
+                    continue;
+                }
+            };
 
-    if !base_url.is_empty() && !token.is_empty() {
-        let _ = request_json(Method::POST, &format!("{base_url}/v1/quit"), &token, Some(json!({}))).await;
-        sleep(Duration::from_millis(400)).await;
-    }
+            let changed = apply_external_settings(&app, &last_settings, &parsed);
+            last_settings = parsed;
+            if !changed.is_empty() {
+                let _ = app.emit_routed(
+                    "voicereader:settings-changed",
+                    SettingsChangedPayload { changed },
+                );
+            }
+        }
+    });
+}
 
-    let mut guard = match state.lock() {
-        Ok(v) => v,
-        Err(_) => return,
+fn apply_external_settings(app: &AppHandle, previous: &AppSettingsFile, next: &AppSettingsFile) -> Vec<String> {
+    let state = app.state::<SharedState>();
+    let mut changed = Vec::new();
+    let Ok(current) = state.inner.lock().map(|guard| {
+        (
+            guard.hotkeys.clone(),
+            guard.orientation_hotkey.clone(),
+            guard.translation.clone(),
+            guard.speak_settings.clone(),
+            guard.fallback_hotkeys.clone(),
+            guard.read_window_hotkey.clone(),
+            guard.summary_hotkey.clone(),
+            guard.summary.clone(),
+            guard.number_format.clone(),
+            guard.acronyms.clone(),
+            guard.lexicon.clone(),
+            guard.readback.clone(),
+            guard.webhooks.clone(),
+            guard.audio_sinks.clone(),
+            guard.cast_target.clone(),
+            guard.pipe_endpoint.clone(),
+            guard.virtual_mic_device.clone(),
+            guard.playback_mode,
+            guard.audio_credits.pacing(),
+            guard.speed_training.settings(),
+            guard.queue_policy,
+            guard.quiet_hours.settings(),
+            guard.capture_overrides.clone(),
+        )
+    }) else {
+        return changed;
     };
-
-    if let Some(child) = guard.child.as_mut() {
-        match child.try_wait() {
-            Ok(Some(_)) => {}
-            Ok(None) => {
-                let _ = child.kill();
+    let (
+        current_hotkeys,
+        current_orientation,
+        current_translation,
+        current_speak,
+        current_fallbacks,
+        current_read_window,
+        current_summary_hotkey,
+        current_summary,
+        current_number_format,
+        current_acronyms,
+        current_lexicon,
+        current_readback,
+        current_webhooks,
+        current_audio_sinks,
+        current_cast_target,
+        current_pipe_endpoint,
+        current_virtual_mic_device,
+        current_playback_mode,
+        current_chunk_pacing,
+        current_speed_training,
+        current_queue_policy,
+        current_quiet_hours,
+        current_capture_overrides,
+    ) = current;
+
+    if next.hotkeys != previous.hotkeys {
+        if let Some(hotkeys) = next.hotkeys.as_ref() {
+            let mut applied = false;
+            for action in BindableAction::ALL {
+                let hotkey = hotkeys.get(action);
+                // Read-selection cannot be cleared; leaving it out keeps it.
+                if hotkey.is_none() && action == BindableAction::ReadSelection {
+                    continue;
+                }
+                let differs = match hotkey.map(normalize_hotkey) {
+                    Some(Ok(candidate)) => current_hotkeys.get(action) != Some(candidate.as_str()),
+                    Some(Err(_)) => true,
+                    None => current_hotkeys.get(action).is_some(),
+                };
+                if differs {
+                    match set_hotkey_binding(app.clone(), app.state::<SharedState>(), action, hotkey.map(str::to_string)) {
+                        Ok(_) => applied = true,
+                        Err(err) => emit_error(
+                            app,
+                            &format!("Settings file {} hotkey not applied: {err}", action.label()),
+                        ),
+                    }
+                }
             }
-            Err(_) => {
-                let _ = child.kill();
+            if applied {
+                changed.push("hotkeys".to_string());
             }
         }
     }
-    guard.child = None;
-    guard.last_job_id = None;
-    guard.suppressed_job_ids.clear();
+
+    if next.fallback_hotkeys != previous.fallback_hotkeys {
+        if let Some(hotkeys) = next.fallback_hotkeys.clone().filter(|hotkeys| *hotkeys != current_fallbacks) {
+            match set_fallback_hotkeys(app.clone(), app.state::<SharedState>(), hotkeys) {
+                Ok(_) => changed.push("fallback_hotkeys".to_string()),
+                Err(err) => emit_error(app, &format!("Settings file fallback hotkeys not applied: {err}")),
+            }
+        }
     }
-}
 
-#[cfg(feature = "build-full")]
-async fn wait_for_engine_health(state: &Arc<Mutex<EngineState>>) -> Result<Value> {
-    for _ in 0..100 {
-        let (base_url, token, running) = {
-            let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-            let (running, _) = runtime_snapshot(&mut guard);
-            (guard.base_url.clone(), guard.token.clone(), running)
+    if next.orientation_hotkey != previous.orientation_hotkey {
+        let normalized = next.orientation_hotkey.as_deref().map(normalize_hotkey);
+        let differs = match normalized {
+            Some(Ok(candidate)) => current_orientation.as_deref() != Some(candidate.as_str()),
+            Some(Err(_)) => true,
+            None => current_orientation.is_some(),
         };
+        if differs {
+            match set_orientation_hotkey(app.clone(), app.state::<SharedState>(), next.orientation_hotkey.clone()) {
+                Ok(_) => changed.push("orientation_hotkey".to_string()),
+                Err(err) => emit_error(app, &format!("Settings file orientation hotkey not applied: {err}")),
+            }
+        }
+    }
 
-        if !running {
-            return Err(anyhow!("Engine process exited during startup"));
+    if next.read_window_hotkey != previous.read_window_hotkey {
+        let normalized = next.read_window_hotkey.as_deref().map(normalize_hotkey);
+        let differs = match normalized {
+            Some(Ok(candidate)) => current_read_window.as_deref() != Some(candidate.as_str()),
+            Some(Err(_)) => true,
+            None => current_read_window.is_some(),
+        };
+        if differs {
+            match set_read_window_hotkey(app.clone(), app.state::<SharedState>(), next.read_window_hotkey.clone()) {
+                Ok(_) => changed.push("read_window_hotkey".to_string()),
+                Err(err) => emit_error(app, &format!("Settings file read-window hotkey not applied: {err}")),
+            }
         }
+    }
 
-        match request_json(Method::GET, &format!("{base_url}/v1/health"), &token, None).await {
-            Ok(payload) => return Ok(payload),
-            Err(_) => sleep(Duration::from_millis(200)).await,
+    if next.summary_hotkey != previous.summary_hotkey {
+        let normalized = next.summary_hotkey.as_deref().map(normalize_hotkey);
+        let differs = match normalized {
+            Some(Ok(candidate)) => current_summary_hotkey.as_deref() != Some(candidate.as_str()),
+            Some(Err(_)) => true,
+            None => current_summary_hotkey.is_some(),
+        };
+        if differs {
+            match set_summary_hotkey(app.clone(), app.state::<SharedState>(), next.summary_hotkey.clone()) {
+                Ok(_) => changed.push("summary_hotkey".to_string()),
+                Err(err) => emit_error(app, &format!("Settings file summary hotkey not applied: {err}")),
+            }
         }
     }
 
-    Err(anyhow!("Engine did not become healthy within startup timeout"))
-}
+    if next.translation != previous.translation {
+        let translation = next.translation.clone().unwrap_or_default();
+        if translation != current_translation {
+            match translation.validate() {
+                Ok(()) => {
+                    if let Ok(mut guard) = state.inner.lock() {
+                        guard.translation = translation;
+                        changed.push("translation".to_string());
+                    }
+                }
+                Err(err) => emit_error(app, &format!("Settings file translation not applied: {err:#}")),
+            }
+        }
+    }
 
-async fn apply_custom_model_activation(state: &Arc<Mutex<EngineState>>) -> Result<Value> {
-    if !qwen_modes_enabled() {
-        return Err(anyhow!("Qwen activation is available in Full build only."));
+    if next.summary != previous.summary {
+        let summary = next.summary.clone().unwrap_or_default();
+        if summary != current_summary {
+            match summary.validate() {
+                Ok(()) => {
+                    if let Ok(mut guard) = state.inner.lock() {
+                        guard.summary = summary;
+                        changed.push("summary".to_string());
+                    }
+                }
+                Err(err) => emit_error(app, &format!("Settings file summary not applied: {err:#}")),
+            }
+        }
     }
 
-    let (base_url, token, speaker) = {
-        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-        (
-            guard.base_url.clone(),
-            guard.token.clone(),
-            guard.selected_qwen_speaker.clone(),
-        )
-    };
+    if next.number_format != previous.number_format {
+        let number_format = next.number_format.clone().unwrap_or_default();
+        if number_format != current_number_format {
+            match number_format.validate() {
+                Ok(()) => {
+                    if let Ok(mut guard) = state.inner.lock() {
+                        guard.number_format = number_format;
+                        changed.push("number_format".to_string());
+                    }
+                }
+                Err(err) => emit_error(app, &format!("Settings file number format not applied: {err:#}")),
+            }
+        }
+    }
 
-    let payload = json!({
-        "synth_backend": "qwen",
-        "active_model_id": "qwen3-tts-12hz-0.6b-customvoice",
-        "qwen_model_name": QWEN_CUSTOM_REPO,
-        "qwen_default_speaker": speaker,
-        "warmup_wait": true,
-        "warmup_force": true,
-        "reason": "app_custom_voice_activation",
-    });
+    if next.acronyms != previous.acronyms {
+        let acronyms = next.acronyms.clone().unwrap_or_default();
+        if acronyms != current_acronyms {
+            match acronyms.validate() {
+                Ok(()) => {
+                    if let Ok(mut guard) = state.inner.lock() {
+                        guard.acronyms = acronyms;
+                        changed.push("acronyms".to_string());
+                    }
+                }
+                Err(err) => emit_error(app, &format!("Settings file acronyms not applied: {err:#}")),
+            }
+        }
+    }
 
-    request_json(
-        Method::POST,
-        &format!("{base_url}/v1/models/activate"),
-        &token,
-        Some(payload),
-    )
-    .await
-}
+    if next.lexicon != previous.lexicon {
+        let lexicon = next.lexicon.clone().unwrap_or_default();
+        if lexicon != current_lexicon {
+            match lexicon.validate() {
+                Ok(()) => {
+                    if let Ok(mut guard) = state.inner.lock() {
+                        guard.lexicon = lexicon;
+                        changed.push("lexicon".to_string());
+                    }
+                }
+                Err(err) => emit_error(app, &format!("Settings file lexicon not applied: {err:#}")),
+            }
+        }
+    }
 
-async fn apply_kyutai_model_activation(state: &Arc<Mutex<EngineState>>) -> Result<Value> {
-    #[cfg(feature = "build-base")]
-    {
-        let selected_voice = {
-            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-            guard.selected_kyutai_voice.clone()
-        };
-        if !KYUTAI_VOICE_PRESETS.iter().any(|row| row.id == selected_voice) {
-            return Err(anyhow!("Unsupported Kyutai preset voice: {selected_voice}"));
+    if next.readback != previous.readback {
+        let readback = next.readback.clone().unwrap_or_default();
+        if readback != current_readback {
+            match readback.validate() {
+                Ok(()) => {
+                    if let Ok(mut guard) = state.inner.lock() {
+                        guard.readback = readback;
+                        changed.push("readback".to_string());
+                    }
+                }
+                Err(err) => emit_error(app, &format!("Settings file read-back not applied: {err:#}")),
+            }
         }
-        return engine_health_inner(state).await;
     }
 
-    #[cfg(feature = "build-full")]
-    {
-    let (base_url, token, voice_prompt) = {
-        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-        (
-            guard.base_url.clone(),
-            guard.token.clone(),
-            guard.selected_kyutai_voice.clone(),
-        )
-    };
+    if next.webhooks != previous.webhooks {
+        let webhooks = next.webhooks.clone().unwrap_or_default();
+        if webhooks != current_webhooks {
+            match webhooks.validate() {
+                Ok(()) => {
+                    if let Ok(mut guard) = state.inner.lock() {
+                        guard.webhooks = webhooks;
+                        changed.push("webhooks".to_string());
+                    }
+                }
+                Err(err) => emit_error(app, &format!("Settings file webhooks not applied: {err:#}")),
+            }
+        }
+    }
 
-    let payload = json!({
-        "synth_backend": "kyutai",
-        "active_model_id": "kyutai-pocket-tts-ungated",
-        "kyutai_model_name": KYUTAI_REPO,
-        "kyutai_voice_prompt": voice_prompt,
-        "warmup_wait": true,
-        "warmup_force": true,
-        "reason": "app_kyutai_activation",
-    });
+    if next.audio_sinks != previous.audio_sinks {
+        let audio_sinks = next.audio_sinks.clone().unwrap_or_default();
+        if audio_sinks != current_audio_sinks {
+            match audio_sinks.validate() {
+                Ok(()) => {
+                    if let Ok(mut guard) = state.inner.lock() {
+                        guard.audio_sinks = audio_sinks;
+                        changed.push("audio_sinks".to_string());
+                    }
+                }
+                Err(err) => emit_error(app, &format!("Settings file audio sinks not applied: {err:#}")),
+            }
+        }
+    }
 
-    request_json(
-        Method::POST,
-        &format!("{base_url}/v1/models/activate"),
-        &token,
-        Some(payload),
-    )
-    .await
+    if next.cast_target != previous.cast_target && next.cast_target != current_cast_target {
+        if let Ok(mut guard) = state.inner.lock() {
+            guard.cast_target = next.cast_target.clone();
+            changed.push("cast_target".to_string());
+        }
     }
 
-    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
-    {
-        Err(anyhow!("Unsupported build variant for Kyutai model activation"))
+    if next.virtual_mic_device != previous.virtual_mic_device {
+        if let Some(device) = next
+            .virtual_mic_device
+            .as_deref()
+            .map(str::trim)
+            .filter(|device| *device != current_virtual_mic_device)
+        {
+            // Only the device; turning the microphone on always goes through consent.
+            match virtual_mic::validate_device(device) {
+                Ok(()) => {
+                    if let Ok(mut guard) = state.inner.lock() {
+                        guard.virtual_mic_device = device.to_string();
+                        if guard.virtual_mic.is_some() {
+                            guard.virtual_mic = Some(device.to_string());
+                        }
+                        changed.push("virtual_mic_device".to_string());
+                    }
+                }
+                Err(err) => emit_error(app, &format!("Settings file virtual microphone not applied: {err:#}")),
+            }
+        }
     }
-}
 
-async fn engine_health_inner(state: &Arc<Mutex<EngineState>>) -> Result<Value> {
-    #[cfg(feature = "build-base")]
-    {
-        let (runtime, selected_preset) = {
-            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-            (
-                guard.local_kyutai.clone().ok_or_else(|| anyhow!("Kyutai Rust runtime is not initialized"))?,
-                guard.selected_kyutai_voice.clone(),
-            )
-        };
-        let runtime_guard = runtime
-            .lock()
-            .map_err(|_| anyhow!("Kyutai runtime lock poisoned"))?;
-        return Ok(runtime_guard.health_payload(&selected_preset));
+    if next.playback_mode != previous.playback_mode {
+        if let Some(mode) = next.playback_mode.filter(|mode| *mode != current_playback_mode) {
+            match set_playback_mode(app.clone(), app.state::<SharedState>(), mode) {
+                Ok(_) => changed.push("playback_mode".to_string()),
+                Err(err) => emit_error(app, &format!("Settings file playback mode not applied: {err}")),
+            }
+        }
     }
 
-    #[cfg(feature = "build-full")]
-    {
-    let (base_url, token) = {
-        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-        (guard.base_url.clone(), guard.token.clone())
-    };
+    if next.chunk_pacing != previous.chunk_pacing {
+        let pacing = next.chunk_pacing.unwrap_or_default();
+        if pacing != current_chunk_pacing {
+            match set_chunk_pacing(app.clone(), app.state::<SharedState>(), pacing.lookahead_ms) {
+                Ok(_) => changed.push("chunk_pacing".to_string()),
+                Err(err) => emit_error(app, &format!("Settings file chunk pacing not applied: {err}")),
+            }
+        }
+    }
 
-    request_json(Method::GET, &format!("{base_url}/v1/health"), &token, None).await
+    if next.speed_training != previous.speed_training {
+        let training = next.speed_training.unwrap_or_default();
+        if training != current_speed_training {
+            match set_speed_training(app.clone(), app.state::<SharedState>(), training) {
+                Ok(_) => changed.push("speed_training".to_string()),
+                Err(err) => emit_error(app, &format!("Settings file speed training not applied: {err}")),
+            }
+        }
     }
 
-    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
-    {
-        Err(anyhow!("Unsupported build variant for engine health"))
+    if next.queue_policy != previous.queue_policy {
+        let policy = next.queue_policy.unwrap_or_default();
+        if policy != current_queue_policy {
+            match set_queue_policy(app.clone(), app.state::<SharedState>(), policy) {
+                Ok(_) => changed.push("queue_policy".to_string()),
+                Err(err) => emit_error(app, &format!("Settings file queue policy not applied: {err}")),
+            }
+        }
     }
-}
 
-async fn engine_list_voices_inner(state: &Arc<Mutex<EngineState>>) -> Result<Value> {
-    #[cfg(feature = "build-base")]
-    {
-        let runtime = {
-            let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-            guard
-                .local_kyutai
-                .clone()
-                .ok_or_else(|| anyhow!("Kyutai Rust runtime is not initialized"))?
-        };
-        let runtime_guard = runtime
-            .lock()
-            .map_err(|_| anyhow!("Kyutai runtime lock poisoned"))?;
-        return runtime_guard.list_voices_payload();
+    if next.quiet_hours != previous.quiet_hours {
+        let quiet_hours = next.quiet_hours.clone().unwrap_or_default();
+        if quiet_hours != current_quiet_hours {
+            match set_quiet_hours(app.clone(), app.state::<SharedState>(), quiet_hours) {
+                Ok(_) => changed.push("quiet_hours".to_string()),
+                Err(err) => emit_error(app, &format!("Settings file quiet hours not applied: {err}")),
+            }
+        }
     }
 
-    #[cfg(feature = "build-full")]
-    {
-    let (base_url, token) = {
-        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-        (guard.base_url.clone(), guard.token.clone())
-    };
+    if next.peak_limiter != previous.peak_limiter {
+        let limiter = next.peak_limiter.unwrap_or_default();
+        if limiter != current_speak.peak_limiter {
+            match set_peak_limiter(app.clone(), app.state::<SharedState>(), limiter) {
+                Ok(_) => changed.push("peak_limiter".to_string()),
+                Err(err) => emit_error(app, &format!("Settings file peak limiter not applied: {err}")),
+            }
+        }
+    }
 
-    request_json(Method::GET, &format!("{base_url}/v1/voices"), &token, None).await
+    if next.silence_trim != previous.silence_trim && next.silence_trim != current_speak.silence_trim {
+        match set_silence_trim(app.clone(), app.state::<SharedState>(), next.silence_trim) {
+            Ok(_) => changed.push("silence_trim".to_string()),
+            Err(err) => emit_error(app, &format!("Settings file silence trimming not applied: {err}")),
+        }
     }
 
-    #[cfg(not(any(feature = "build-base", feature = "build-full")))]
-    {
-        Err(anyhow!("Unsupported build variant for voice list"))
+    if next.capture_overrides != previous.capture_overrides {
+        let overrides = next.capture_overrides.clone().unwrap_or_default();
+        if overrides != current_capture_overrides {
+            match set_capture_overrides(app.clone(), app.state::<SharedState>(), overrides) {
+                Ok(_) => changed.push("capture_overrides".to_string()),
+                Err(err) => emit_error(app, &format!("Settings file capture overrides not applied: {err}")),
+            }
+        }
     }
-}
 
-#[cfg(feature = "build-full")]
-async fn update_active_job_playback_rate_full(state: &Arc<Mutex<EngineState>>, rate: f32) -> Result<()> {
-    let (base_url, token, job_id) = {
-        let guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
-        (
-            guard.base_url.clone(),
-            guard.token.clone(),
-            guard.last_job_id.clone(),
-        )
-    };
+    if next.pipe_endpoint != previous.pipe_endpoint {
+        let pipe_endpoint = next.pipe_endpoint.clone().unwrap_or_default();
+        if pipe_endpoint != current_pipe_endpoint {
+            match set_pipe_endpoint(app.clone(), app.state::<SharedState>(), pipe_endpoint) {
+                Ok(_) => changed.push("pipe_endpoint".to_string()),
+                Err(err) => emit_error(app, &format!("Settings file pipe endpoint not applied: {err}")),
+            }
+        }
+    }
 
-    let Some(job_id) = job_id else {
-        return Ok(());
-    };
-    if base_url.is_empty() || token.is_empty() {
-        return Ok(());
+    if next.engine_http != previous.engine_http {
+        let engine_http = next.engine_http.clone().unwrap_or_default();
+        if engine_http != engine_http_settings() {
+            match engine_http.validate() {
+                Ok(()) => {
+                    apply_engine_http_settings(engine_http);
+                    changed.push("engine_http".to_string());
+                }
+                Err(err) => emit_error(app, &format!("Settings file engine timeouts not applied: {err:#}")),
+            }
+        }
     }
 
-    let _ = request_json(
-        Method::POST,
-        &format!("{base_url}/v1/jobs/{job_id}/playback"),
-        &token,
-        Some(json!({ "rate": clamp_speak_rate(rate) })),
-    )
-    .await?;
+    let rate = next
+        .rate
+        .filter(|rate| next.rate != previous.rate && *rate != current_speak.rate);
+    let volume = next
+        .volume
+        .filter(|volume| next.volume != previous.volume && *volume != current_speak.volume);
+    let chunk_max_chars = next
+        .chunk_max_chars
+        .filter(|chars| next.chunk_max_chars != previous.chunk_max_chars && *chars != current_speak.chunk_max_chars);
+    if rate.is_some() || volume.is_some() || chunk_max_chars.is_some() {
+        let result = set_speak_settings(
+            app.clone(),
+            app.state::<SharedState>(),
+            rate.unwrap_or(current_speak.rate),
+            volume.unwrap_or(current_speak.volume),
+            chunk_max_chars.unwrap_or(current_speak.chunk_max_chars),
+            None,
+        );
+        match result {
+            Ok(_) => {
+                for (name, applied) in [
+                    ("rate", rate.is_some()),
+                    ("volume", volume.is_some()),
+                    ("chunk_max_chars", chunk_max_chars.is_some()),
+                ] {
+                    if applied {
+                        changed.push(name.to_string());
+                    }
+                }
+            }
+            Err(err) => emit_error(app, &format!("Settings file speak settings not applied: {err}")),
+        }
+    }
 
-    Ok(())
+    changed
 }
 
-async fn request_json(method: Method, url: &str, token: &str, body: Option<Value>) -> Result<Value> {
-    let client = Client::new();
-    let mut request = client
-        .request(method, url)
-        .header("Authorization", format!("Bearer {token}"));
+fn phrases_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver()
+        .app_config_dir()
+        .map(|path| path.join(PHRASES_FILE_NAME))
+}
 
-    if let Some(payload) = body {
-        request = request.json(&payload);
-    }
+fn load_phrases(app: &AppHandle) -> Result<PhraseStore> {
+    let path = phrases_path(app).ok_or_else(|| anyhow!("Unable to resolve phrases path"))?;
+    PhraseStore::load(&path)
+}
 
-    let response = request.send().await.with_context(|| format!("Request failed for {url}"))?;
-    let status = response.status();
-    if !status.is_success() {
-        let body_text = response.text().await.unwrap_or_else(|_| String::new());
-        return Err(anyhow!("Request to {url} failed with status {status}: {body_text}"));
-    }
+/// Feeds keep read state, which is data rather than configuration.
+fn feeds_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver()
+        .app_data_dir()
+        .map(|path| path.join(FEEDS_FILE_NAME))
+}
 
-    response
-        .json::<Value>()
-        .await
-        .with_context(|| format!("Failed to decode JSON response for {url}"))
+fn load_feeds(app: &AppHandle) -> Result<FeedStore> {
+    let path = feeds_path(app).ok_or_else(|| anyhow!("Unable to resolve feeds path"))?;
+    FeedStore::load(&path)
 }
 
-fn build_variant_name() -> &'static str {
-    BUILD_VARIANT
+fn save_feeds(app: &AppHandle, store: &FeedStore) -> Result<()> {
+    let path = feeds_path(app).ok_or_else(|| anyhow!("Unable to resolve feeds path"))?;
+    store.save(&path)
 }
 
-fn qwen_modes_enabled() -> bool {
-    cfg!(feature = "build-full")
+fn schedules_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver()
+        .app_config_dir()
+        .map(|path| path.join(SCHEDULES_FILE_NAME))
 }
 
-fn model_options() -> Vec<ModelOption> {
-    let mut options = vec![ModelOption {
-        id: MODEL_KYUTAI.to_string(),
-        label: "Kyutai Pocket TTS".to_string(),
-        status: "ready".to_string(),
-        notes: format!("Main read-aloud path ({KYUTAI_REPO})"),
-    }];
+fn load_schedules(app: &AppHandle) -> Result<ScheduleStore> {
+    let path = schedules_path(app).ok_or_else(|| anyhow!("Unable to resolve schedules path"))?;
+    ScheduleStore::load(&path)
+}
 
-    if qwen_modes_enabled() {
-        options.push(ModelOption {
-            id: MODEL_CUSTOM.to_string(),
-            label: "Qwen CustomVoice (preset speakers)".to_string(),
-            status: "ready".to_string(),
-            notes: format!("Secondary path ({QWEN_CUSTOM_REPO})"),
-        });
-        options.push(ModelOption {
-            id: MODEL_BASE.to_string(),
-            label: "Qwen Base (clone path)".to_string(),
-            status: "planned".to_string(),
-            notes: format!("Model repo prefetched: {QWEN_BASE_REPO}"),
+fn save_schedules(app: &AppHandle, store: &ScheduleStore) -> Result<()> {
+    let path = schedules_path(app).ok_or_else(|| anyhow!("Unable to resolve schedules path"))?;
+    store.save(&path)
+}
+
+fn benchmarks_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver()
+        .app_config_dir()
+        .map(|path| path.join(BENCHMARKS_FILE_NAME))
+}
+
+fn job_history_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver()
+        .app_config_dir()
+        .map(|path| path.join(JOB_HISTORY_FILE_NAME))
+}
+
+/// Announces a started job and records it, with `text`, in the job history.
+fn emit_job_started(app: &AppHandle, payload: JobStartedPayload, text: &str) {
+    let entry = JobHistoryEntry {
+        job_id: payload.job_id.clone(),
+        started_at: chrono::Local::now().to_rfc3339(),
+        source: payload.source.clone(),
+        text: text.to_string(),
+        capture: payload.capture.clone(),
+    };
+    notify_job_started(app, &payload, &entry);
+    open_job_sinks(app, &payload.job_id);
+    let _ = app.emit_routed("voicereader:job-started", payload);
+    let saved = job_history_path(app)
+        .ok_or_else(|| anyhow!("Unable to resolve history path"))
+        .and_then(|path| {
+            let mut history = JobHistory::load(&path)?;
+            history.push(entry);
+            history.save(&path)
         });
+    if let Err(err) = saved {
+        emit_error(app, &format!("Job started but could not be added to history: {err:#}"));
     }
-
-    options
 }
 
-fn speaker_presets(model: &str) -> Vec<SpeakerPreset> {
-    let presets: &[SpeakerPresetRow] = match model {
-        MODEL_KYUTAI => &KYUTAI_VOICE_PRESETS,
-        _ if qwen_modes_enabled() => &QWEN_SPEAKER_PRESETS,
-        _ => &KYUTAI_VOICE_PRESETS,
+/// Fires `job.started` webhooks and keeps the job's metadata for its
+/// terminal one.
+fn notify_job_started(app: &AppHandle, payload: &JobStartedPayload, entry: &JobHistoryEntry) {
+    let state = app.state::<SharedState>();
+    let Ok(mut guard) = state.inner.lock() else {
+        return;
     };
+    if guard.webhooks.hooks.is_empty() {
+        return;
+    }
+    let job = json!({
+        "job_id": entry.job_id,
+        "source": entry.source,
+        "source_window": payload.source_window,
+        "started_at": entry.started_at,
+        "rate": payload.rate,
+        "capture": entry.capture,
+        "text": guard.webhooks.include_text.then(|| entry.text.clone()),
+    });
+    guard.webhook_jobs.insert(entry.job_id.clone(), job.clone());
+    let urls = guard.webhooks.urls_for(JobEventKind::Started);
+    drop(guard);
+    send_webhooks(urls, webhook_body(JobEventKind::Started, &job, None));
+}
 
-    presets
-        .iter()
-        .map(|row| SpeakerPreset {
-            id: row.id.to_string(),
-            description: row.description.to_string(),
-            native_language: row.native_language.to_string(),
-        })
-        .collect()
+/// Fires the terminal webhook for the job of `event`, a `JOB_DONE`,
+/// `JOB_PARTIAL_DONE`, `JOB_CANCELED` or `JOB_ERROR` stream event. Only the
+/// first terminal event of a job is sent.
+fn notify_job_finished(app: &AppHandle, event: &Value) {
+    let Some(kind) = event.get("type").and_then(Value::as_str).and_then(JobEventKind::from_engine_event) else {
+        return;
+    };
+    let Some(job_id) = event.get("job_id").and_then(Value::as_str) else {
+        return;
+    };
+    let state = app.state::<SharedState>();
+    let Ok(mut guard) = state.inner.lock() else {
+        return;
+    };
+    let Some(job) = guard.webhook_jobs.remove(job_id) else {
+        return;
+    };
+    let urls = guard.webhooks.urls_for(kind);
+    drop(guard);
+    send_webhooks(urls, webhook_body(kind, &job, Some(event)));
 }
 
-fn active_speaker_for_model(state: &EngineState) -> String {
-    match state.selected_model.as_str() {
-        MODEL_KYUTAI => state.selected_kyutai_voice.clone(),
-        _ => state.selected_qwen_speaker.clone(),
+fn send_webhooks(urls: Vec<String>, body: Value) {
+    if urls.is_empty() {
+        return;
     }
+    // Logged rather than surfaced: an automation endpoint being down should
+    // not raise an error on every read.
+    tauri::async_runtime::spawn(async move {
+        for failure in deliver(&urls, &body).await {
+            eprintln!("{failure}");
+        }
+    });
 }
 
-fn default_hotkey() -> String {
-    #[cfg(target_os = "macos")]
-    {
-        return "Option+S".to_string();
+/// Emits a job's terminal stream event and fires its webhook.
+fn emit_terminal_event(app: &AppHandle, event: Value) {
+    notify_job_finished(app, &event);
+    if let Some(job_id) = event.get("job_id").and_then(Value::as_str) {
+        with_native_player(app, |player| player.end_job(job_id));
     }
-    #[cfg(target_os = "windows")]
-    {
-        return "Alt+S".to_string();
+    let _ = app.emit_routed("voicereader:ws-event", event);
+}
+
+/// Stops the running pipe listener and starts a new one if the endpoint is
+/// enabled. Returns where it listens.
+fn apply_pipe_endpoint(app: &AppHandle, state: &Arc<Mutex<EngineState>>) -> Result<Option<PathBuf>> {
+    let (enabled, running) = {
+        let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+        (guard.pipe_endpoint.enabled, guard.pipe_listener.take())
+    };
+    if let Some(listener) = running {
+        listener.stop();
     }
-    #[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
-    {
-        "Ctrl+Shift+S".to_string()
+    if !enabled {
+        return Ok(None);
     }
-}
 
-fn normalize_optional_text(value: Option<String>) -> Option<String> {
-    value.and_then(|raw| {
-        let normalized = raw.trim().to_string();
-        if normalized.is_empty() {
-            None
-        } else {
-            Some(normalized)
+    let path = endpoint_path();
+    let app_clone = app.clone();
+    let state_clone = state.clone();
+    let listener = PipeEndpoint::start(path.clone(), move |message| {
+        let (text, source, priority) = match message {
+            PipeMessage::Read(text) => (text, "pipe_endpoint", ReadPriority::Normal),
+            PipeMessage::Notify(text) => (text, "notify", ReadPriority::Interactive),
+        };
+        let result = tauri::async_runtime::block_on(read_piped_text(&app_clone, &state_clone, text, source, priority));
+        if let Err(err) = result {
+            emit_error(&app_clone, &format!("Pipe endpoint read failed: {err:#}"));
         }
-    })
+    })?;
+    let mut guard = state.lock().map_err(|_| anyhow!("State lock poisoned"))?;
+    guard.pipe_listener = Some(listener);
+    Ok(Some(path))
 }
 
-fn normalize_hotkey(value: &str) -> Result<String> {
-    let normalized = value.trim().replace(' ', "");
-    if normalized.is_empty() {
-        return Err(anyhow!("Hotkey cannot be empty"));
-    }
-    Ok(normalized)
+/// Queues text that arrived on the pipe endpoint or stdin. Notifications go
+/// in the interactive lane, over a normal read that then resumes.
+async fn read_piped_text(
+    app: &AppHandle,
+    state: &Arc<Mutex<EngineState>>,
+    text: String,
+    source: &str,
+    priority: ReadPriority,
+) -> Result<Option<String>> {
+    ensure_engine_ready(app, state).await?;
+    enqueue_read(
+        app,
+        state,
+        QueuedRead {
+            text,
+            priority,
+            source: source.to_string(),
+            origin: JobOrigin::direct(),
+            voice_id: None,
+            rate: None,
+            preprocessed: false,
+            expand_placeholders: false,
+            reading_order: false,
+        },
+    )
+    .await
 }
 
-fn clamp_speak_rate(rate: f32) -> f32 {
-    rate.clamp(0.25, 4.0)
-}
+/// `voicereader --pipe`: speaks stdin line by line as it arrives, as in
+/// `tail -f build.log | voicereader --pipe`. Lines that arrive while earlier
+/// ones are being queued are read together as one chunk.
+fn spawn_stdin_reader(app: AppHandle, state: Arc<Mutex<EngineState>>) {
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<String>();
+    spawn_stdin_lines(move |line| sender.send(line).is_ok());
 
-#[cfg(feature = "build-base")]
-fn rate_to_steps(rate: f32) -> u32 {
-    (clamp_speak_rate(rate) * 4.0).round().clamp(1.0, 16.0) as u32
+    tauri::async_runtime::spawn(async move {
+        while let Some(first) = receiver.recv().await {
+            let chunk = join_stdin_lines(first, || receiver.try_recv().ok());
+            if chunk.trim().is_empty() {
+                continue;
+            }
+            if let Err(err) = read_piped_text(&app, &state, chunk, "stdin_pipe", ReadPriority::Normal).await {
+                emit_error(&app, &format!("Reading from stdin failed: {err:#}"));
+            }
+        }
+    });
 }
 
-fn is_hotkey_os_reserved(hotkey: &str) -> bool {
-    let normalized = hotkey.trim().to_lowercase().replace(' ', "");
-    matches!(
-        normalized.as_str(),
-        "alt+space" | "cmd+space" | "command+space" | "meta+space" | "super+space"
-    )
+/// Hands stdin to `on_line` line by line on a thread of its own, until stdin
+/// ends or `on_line` returns false.
+fn spawn_stdin_lines(on_line: impl Fn(String) -> bool + Send + 'static) {
+    std::thread::spawn(move || {
+        use std::io::BufRead;
+
+        let mut stdin = std::io::stdin().lock();
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            match stdin.read_until(b'\n', &mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    if !on_line(String::from_utf8_lossy(&line).trim_end().to_string()) {
+                        break;
+                    }
+                }
+            }
+        }
+    });
 }
 
-fn load_saved_hotkey(app: &AppHandle) -> Option<String> {
-    let path = app_settings_path(app)?;
-    let body = std::fs::read_to_string(path).ok()?;
-    let parsed: AppSettingsFile = serde_json::from_str(&body).ok()?;
-    let candidate = parsed.hotkey?;
-    let normalized = normalize_hotkey(&candidate).ok()?;
-    if is_hotkey_os_reserved(&normalized) {
-        return None;
+/// Joins the lines already waiting onto `chunk`, up to `STDIN_CHUNK_MAX_BYTES`.
+fn join_stdin_lines(mut chunk: String, mut waiting: impl FnMut() -> Option<String>) -> String {
+    while chunk.len() < STDIN_CHUNK_MAX_BYTES {
+        let Some(next) = waiting() else {
+            break;
+        };
+        chunk.push('\n');
+        chunk.push_str(&next);
     }
-    Some(normalized)
+    chunk
 }
 
-fn persist_hotkey(app: &AppHandle, hotkey: &str) -> Result<()> {
-    let path = app_settings_path(app).ok_or_else(|| anyhow!("Unable to resolve app settings path"))?;
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent).with_context(|| {
-            format!("Failed to create app settings directory {}", parent.display())
-        })?;
-    }
-
-    let settings = AppSettingsFile {
-        hotkey: Some(hotkey.to_string()),
-    };
-    let serialized = serde_json::to_string_pretty(&settings)?;
-    std::fs::write(&path, serialized)
-        .with_context(|| format!("Failed to write app settings file {}", path.display()))?;
-    Ok(())
+fn append_benchmark(app: &AppHandle, result: &BenchmarkResult) -> Result<()> {
+    let path = benchmarks_path(app).ok_or_else(|| anyhow!("Unable to resolve benchmarks path"))?;
+    let mut history = BenchmarkHistory::load(&path)?;
+    history.push(result.clone());
+    history.save(&path)
 }
 
 fn app_settings_path(app: &AppHandle) -> Option<PathBuf> {
@@ -2729,8 +11518,11 @@ fn runtime_snapshot(state: &mut EngineState) -> (bool, Option<u32>) {
 fn child_runtime_snapshot(state: &mut EngineState) -> (bool, Option<u32>) {
     if let Some(child) = state.child.as_mut() {
         match child.try_wait() {
-            Ok(Some(_)) => {
+            Ok(Some(status)) => {
                 state.child = None;
+                if !state.engine_exit_expected {
+                    state.pending_crash = Some(build_crash_report(state, status.code()));
+                }
                 (false, None)
             }
             Ok(None) => (true, Some(child.id())),
@@ -2744,6 +11536,163 @@ fn child_runtime_snapshot(state: &mut EngineState) -> (bool, Option<u32>) {
     }
 }
 
+fn build_crash_report(state: &EngineState, exit_code: Option<i32>) -> EngineCrashReport {
+    let occurred_at_unix = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let stderr_tail = state
+        .engine_stderr_tail
+        .lock()
+        .map(|tail| tail.iter().cloned().collect())
+        .unwrap_or_default();
+    let in_flight_request = ENGINE_REQUEST_IN_FLIGHT.lock().ok().and_then(|guard| guard.clone());
+    EngineCrashReport {
+        crash_id: format!("{occurred_at_unix}-{:08x}", rand::random::<u32>()),
+        occurred_at_unix,
+        exit_code,
+        in_flight_request,
+        stderr_tail,
+    }
+}
+
+/// Writes the crash report next to the engine data and announces it.
+#[cfg(feature = "build-full")]
+fn report_engine_crash(app: &AppHandle, state: &Arc<Mutex<EngineState>>, crash: EngineCrashReport) {
+    let data_dir = state.lock().map(|guard| guard.data_dir.clone()).unwrap_or_default();
+    let report_path = if data_dir.is_empty() {
+        None
+    } else {
+        let dir = PathBuf::from(data_dir).join(CRASH_REPORT_DIR_NAME);
+        let path = dir.join(format!("{}.json", crash.crash_id));
+        let written = std::fs::create_dir_all(&dir)
+            .map_err(anyhow::Error::from)
+            .and_then(|_| Ok(serde_json::to_string_pretty(&crash)?))
+            .and_then(|body| Ok(std::fs::write(&path, body)?));
+        match written {
+            Ok(()) => Some(path.to_string_lossy().to_string()),
+            Err(err) => {
+                eprintln!("Failed to write engine crash report: {err:#}");
+                None
+            }
+        }
+    };
+
+    let exit = crash
+        .exit_code
+        .map(|code| format!("exit code {code}"))
+        .unwrap_or_else(|| "terminated by signal".to_string());
+    let message = match crash.in_flight_request.as_deref() {
+        Some(request) => format!("Engine exited unexpectedly ({exit}) during {request}"),
+        None => format!("Engine exited unexpectedly ({exit})"),
+    };
+    let _ = app.emit_routed(
+        "voicereader:engine-crashed",
+        EngineCrashedPayload {
+            crash_id: crash.crash_id,
+            exit_code: crash.exit_code,
+            report_path,
+            message,
+        },
+    );
+}
+
+/// Watches a running sidecar and reports it if it exits on its own. Stops once
+/// the child is replaced or shut down.
+#[cfg(feature = "build-full")]
+fn spawn_engine_exit_monitor(app: AppHandle, state: Arc<Mutex<EngineState>>, pid: u32) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            sleep(Duration::from_millis(ENGINE_EXIT_POLL_MS)).await;
+            let crash = {
+                let Ok(mut guard) = state.lock() else {
+                    return;
+                };
+                if guard.child.as_ref().map(Child::id) != Some(pid) {
+                    return;
+                }
+                let _ = child_runtime_snapshot(&mut guard);
+                if guard.child.is_some() {
+                    continue;
+                }
+                guard.pending_crash.take()
+            };
+            if let Some(crash) = crash {
+                report_engine_crash(&app, &state, crash);
+            }
+            return;
+        }
+    });
+}
+
+/// Pings a running sidecar so a process that is alive but no longer answering
+/// gets restarted in the background instead of stalling the next hotkey press.
+/// Emits `voicereader:engine-status` only when the status changes.
+#[cfg(feature = "build-full")]
+fn spawn_engine_heartbeat(app: AppHandle, state: Arc<Mutex<EngineState>>, pid: u32) {
+    tauri::async_runtime::spawn(async move {
+        let mut misses = 0u32;
+        loop {
+            sleep(Duration::from_millis(ENGINE_HEARTBEAT_INTERVAL_MS)).await;
+            let still_current = state
+                .lock()
+                .map(|guard| guard.child.as_ref().map(Child::id) == Some(pid))
+                .unwrap_or(false);
+            if !still_current {
+                return;
+            }
+
+            let ping = tokio::time::timeout(
+                Duration::from_millis(ENGINE_HEARTBEAT_TIMEOUT_MS),
+                engine_health_inner(&state),
+            )
+            .await;
+            if matches!(ping, Ok(Ok(_))) {
+                if misses > 0 {
+                    emit_engine_status(&app, "healthy", "Engine is responding again");
+                }
+                misses = 0;
+                continue;
+            }
+
+            misses += 1;
+            if misses == 1 {
+                emit_engine_status(&app, "unresponsive", "Engine missed a health check");
+            }
+            if misses < ENGINE_HEARTBEAT_MAX_MISSES {
+                continue;
+            }
+
+            emit_engine_status(
+                &app,
+                "restarting",
+                &format!("Engine missed {misses} health checks; restarting"),
+            );
+            // The replacement sidecar gets its own heartbeat from startup.
+            match restart_engine_inner(&app, &state).await {
+                Ok(()) => emit_engine_status(&app, "restarted", "Engine restarted after it stopped responding"),
+                Err(err) => {
+                    let message = format!("Engine restart failed: {err:#}");
+                    emit_engine_status(&app, "restart_failed", &message);
+                    emit_error(&app, &message);
+                }
+            }
+            return;
+        }
+    });
+}
+
+#[cfg(feature = "build-full")]
+fn emit_engine_status(app: &AppHandle, status: &'static str, message: &str) {
+    let _ = app.emit_routed(
+        "voicereader:engine-status",
+        EngineStatusPayload {
+            status,
+            message: message.to_string(),
+        },
+    );
+}
+
 fn find_engine_root() -> Result<PathBuf> {
     fn is_engine_root(path: &Path) -> bool {
         path.join("src").join("tts_engine").join("main.py").exists()
@@ -2824,6 +11773,22 @@ fn resolve_engine_data_dir(_app: &AppHandle, _engine_root: Option<&Path>) -> Res
     }
 }
 
+/// Where the engine serves its API besides TCP, if anywhere.
+fn engine_api_socket() -> Option<PathBuf> {
+    #[cfg(all(unix, feature = "build-full"))]
+    return Some(crate::pipe_endpoint::api_socket_path());
+    #[cfg(not(all(unix, feature = "build-full")))]
+    return None;
+}
+
+/// Whether the sidecar's stderr shows it could not bind its TCP port, the one
+/// startup failure a fresh port fixes. asyncio words every bind error this
+/// way, address in use and Windows' reserved port ranges alike.
+#[cfg(feature = "build-full")]
+fn is_port_bind_failure<'a>(mut stderr_tail: impl Iterator<Item = &'a String>) -> bool {
+    stderr_tail.any(|line| line.to_ascii_lowercase().contains("error while attempting to bind on address"))
+}
+
 #[cfg(feature = "build-full")]
 fn build_engine_launch_command(
     app: &AppHandle,
@@ -2841,7 +11806,18 @@ fn build_engine_launch_command(
                 .to_str()
                 .ok_or_else(|| anyhow!("Invalid engine data dir path"))?;
             let mut command = Command::new(&sidecar_path);
-            command.args(["--server", "--port", &port.to_string(), "--data-dir", data_dir_str]);
+            command.args([
+                "--server",
+                "--port",
+                &port.to_string(),
+                "--data-dir",
+                data_dir_str,
+                "--parent-pid",
+                &std::process::id().to_string(),
+            ]);
+            if let Some(socket) = engine_api_socket() {
+                command.arg("--uds").arg(socket);
+            }
             return Ok((command, sidecar_path.to_string_lossy().to_string()));
         }
     }
@@ -2861,9 +11837,14 @@ fn build_engine_launch_command(
             &port.to_string(),
             "--data-dir",
             data_dir_str,
+            "--parent-pid",
+            &std::process::id().to_string(),
         ])
         .current_dir(root)
         .env("PYTHONPATH", root.join("src"));
+    if let Some(socket) = engine_api_socket() {
+        command.arg("--uds").arg(socket);
+    }
     Ok((command, python_executable))
 }
 
@@ -3126,7 +12107,7 @@ fn generate_token() -> String {
 }
 
 fn emit_error(app: &AppHandle, message: &str) {
-    let _ = app.emit_all(
+    let _ = app.emit_routed(
         "voicereader:error",
         ErrorPayload {
             message: message.to_string(),
@@ -3134,8 +12115,13 @@ fn emit_error(app: &AppHandle, message: &str) {
     );
 }
 
-fn to_cmd_error(err: anyhow::Error) -> String {
-    format!("{err:#}")
+fn to_cmd_error(err: anyhow::Error) -> CommandError {
+    err.into()
+}
+
+/// For errors from checking the caller's arguments, which carry no code.
+fn to_invalid_error(err: anyhow::Error) -> CommandError {
+    CommandError::invalid(format!("{err:#}"))
 }
 
 impl Drop for SharedState {
@@ -3166,6 +12152,11 @@ pub fn handle_run_event(app: &AppHandle, event: &RunEvent) {
         RunEvent::ExitRequested { .. } | RunEvent::Exit => {
             if let Some(state) = app.try_state::<SharedState>() {
                 let state = state.inner.clone();
+                // Removes the FIFO, so `notify` sees at once that nothing listens.
+                let pipe_listener = state.lock().ok().and_then(|mut guard| guard.pipe_listener.take());
+                if let Some(listener) = pipe_listener {
+                    listener.stop();
+                }
                 tauri::async_runtime::block_on(async {
                     shutdown_engine(&state).await;
                 });
@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+const WEBHOOK_TIMEOUT_SECS: u64 = 10;
+const MAX_WEBHOOKS: usize = 10;
+
+/// Job lifecycle points a webhook can fire on.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobEventKind {
+    Started,
+    /// `JOB_DONE`, and `JOB_PARTIAL_DONE` for a job stopped part-way.
+    Done,
+    Canceled,
+    Error,
+}
+
+impl JobEventKind {
+    /// The kind of a terminal engine event, by its `type`.
+    pub fn from_engine_event(kind: &str) -> Option<Self> {
+        match kind {
+            "JOB_DONE" | "JOB_PARTIAL_DONE" => Some(Self::Done),
+            "JOB_CANCELED" => Some(Self::Canceled),
+            "JOB_ERROR" => Some(Self::Error),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Started => "job.started",
+            Self::Done => "job.done",
+            Self::Canceled => "job.canceled",
+            Self::Error => "job.error",
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct Webhook {
+    pub url: String,
+    /// Events posted to `url`; empty means all of them.
+    #[serde(default)]
+    pub events: Vec<JobEventKind>,
+}
+
+/// Outbound webhooks for automation tools. Each job event is POSTed as JSON
+/// to every hook that wants it; delivery is best effort and never delays
+/// speech.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WebhookSettings {
+    pub hooks: Vec<Webhook>,
+    /// Include the job's text in the metadata. Off by default, since the
+    /// text is whatever the user selected.
+    #[serde(default)]
+    pub include_text: bool,
+}
+
+impl WebhookSettings {
+    pub fn validate(&self) -> Result<()> {
+        if self.hooks.len() > MAX_WEBHOOKS {
+            return Err(anyhow!("At most {MAX_WEBHOOKS} webhooks can be configured"));
+        }
+        for hook in &self.hooks {
+            let url = hook.url.trim();
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return Err(anyhow!("Webhook URL {url:?} must start with http:// or https://"));
+            }
+        }
+        Ok(())
+    }
+
+    /// URLs of the hooks that fire on `kind`.
+    pub fn urls_for(&self, kind: JobEventKind) -> Vec<String> {
+        self.hooks
+            .iter()
+            .filter(|hook| hook.events.is_empty() || hook.events.contains(&kind))
+            .map(|hook| hook.url.trim().to_string())
+            .collect()
+    }
+}
+
+/// The JSON body posted for one job event. `job` is the metadata recorded
+/// when the job started; `engine_event` is the terminal `voicereader:ws-event`
+/// payload, absent for `job.started`.
+pub fn webhook_body(kind: JobEventKind, job: &Value, engine_event: Option<&Value>) -> Value {
+    json!({
+        "event": kind.name(),
+        "sent_at": chrono::Local::now().to_rfc3339(),
+        "job": job,
+        "engine_event": engine_event,
+    })
+}
+
+/// POSTs `body` to each of `urls`, returning the failures.
+pub async fn deliver(urls: &[String], body: &Value) -> Vec<String> {
+    let client = Client::new();
+    let mut failures = Vec::new();
+    for url in urls {
+        if let Err(err) = post(&client, url, body).await {
+            failures.push(format!("{err:#}"));
+        }
+    }
+    failures
+}
+
+async fn post(client: &Client, url: &str, body: &Value) -> Result<()> {
+    let response = client
+        .post(url)
+        .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECS))
+        .json(body)
+        .send()
+        .await
+        .with_context(|| format!("Webhook request failed for {url}"))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(anyhow!("Webhook {url} answered with status {status}"));
+    }
+    Ok(())
+}
@@ -0,0 +1,355 @@
+use std::process::{Command, Stdio};
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+
+/// Bands at the top and bottom of a window that hold title bars, tabs,
+/// toolbars and status bars, as fractions of the window height.
+const TOP_CHROME_FRACTION: f32 = 0.08;
+const BOTTOM_CHROME_FRACTION: f32 = 0.06;
+/// Lines shorter than this without sentence punctuation read as buttons,
+/// menu items and labels rather than content.
+const MIN_CONTENT_WORDS: usize = 3;
+/// A vertical gap over this many line heights starts a new paragraph.
+const PARAGRAPH_GAP_LINES: f32 = 0.9;
+
+/// One line of recognized text, in window pixels from the top-left corner.
+#[derive(Deserialize)]
+pub struct OcrLine {
+    pub text: String,
+    pub left: f32,
+    pub top: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl OcrLine {
+    fn right(&self) -> f32 {
+        self.left + self.width
+    }
+
+    fn bottom(&self) -> f32 {
+        self.top + self.height
+    }
+}
+
+/// Everything the OCR engine recognized in a screenshot of a window.
+#[derive(Deserialize)]
+pub struct WindowText {
+    pub width: f32,
+    pub height: f32,
+    pub lines: Vec<OcrLine>,
+}
+
+impl WindowText {
+    /// The main content of the window as paragraphs, with window chrome and
+    /// scattered UI labels left out; `None` when nothing reads as content.
+    ///
+    /// Lines in the top and bottom chrome bands, lines that are mostly not
+    /// letters and short lines without sentence punctuation are dropped.
+    /// Of what remains, the column holding the most text is kept, so
+    /// sidebars and panels beside an article are not read.
+    pub fn main_content(&self) -> Option<String> {
+        let top_limit = self.height * TOP_CHROME_FRACTION;
+        let bottom_limit = self.height * (1.0 - BOTTOM_CHROME_FRACTION);
+        let mut lines: Vec<&OcrLine> = self
+            .lines
+            .iter()
+            .filter(|line| line.top >= top_limit && line.bottom() <= bottom_limit)
+            .filter(|line| looks_like_content(&line.text))
+            .collect();
+        if lines.is_empty() {
+            return None;
+        }
+
+        // Seed the column with the line whose horizontal span overlaps the most text.
+        let overlapping_chars = |seed: &OcrLine| -> usize {
+            lines
+                .iter()
+                .filter(|line| overlaps(seed, line))
+                .map(|line| line.text.chars().count())
+                .sum()
+        };
+        let seed = *lines.iter().max_by_key(|line| overlapping_chars(line))?;
+        lines.retain(|line| overlaps(seed, line));
+        lines.sort_by(|a, b| a.top.total_cmp(&b.top).then(a.left.total_cmp(&b.left)));
+
+        let mut heights: Vec<f32> = lines.iter().map(|line| line.height).collect();
+        heights.sort_by(f32::total_cmp);
+        let line_height = heights[heights.len() / 2].max(1.0);
+
+        let mut content = String::new();
+        let mut previous_bottom: Option<f32> = None;
+        for line in lines {
+            let text = line.text.trim();
+            match previous_bottom {
+                None => {}
+                Some(bottom) if line.top - bottom > line_height * PARAGRAPH_GAP_LINES => content.push_str("\n\n"),
+                // Rejoin words hyphenated across a line break.
+                Some(_) if content.ends_with('-') && text.starts_with(char::is_lowercase) => {
+                    content.pop();
+                }
+                Some(_) => content.push(' '),
+            }
+            content.push_str(text);
+            previous_bottom = Some(line.bottom());
+        }
+        Some(content)
+    }
+}
+
+fn overlaps(a: &OcrLine, b: &OcrLine) -> bool {
+    a.left < b.right() && b.left < a.right()
+}
+
+fn looks_like_content(text: &str) -> bool {
+    let text = text.trim();
+    let chars = text.chars().filter(|ch| !ch.is_whitespace()).count();
+    let letters = text.chars().filter(|ch| ch.is_alphabetic()).count();
+    if letters < 2 || letters * 2 < chars {
+        return false;
+    }
+    text.split_whitespace().count() >= MIN_CONTENT_WORDS || text.ends_with(['.', '!', '?', ':', '\u{3002}'])
+}
+
+/// Screenshots the foreground window and runs it through the platform's
+/// OCR engine: Windows.Media.Ocr on Windows, Vision on macOS and
+/// tesseract elsewhere. Blocks until recognition finishes.
+pub fn recognize_focused_window() -> Result<WindowText> {
+    run_platform_ocr()
+}
+
+#[cfg(target_os = "windows")]
+fn run_platform_ocr() -> Result<WindowText> {
+    use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+    use base64::Engine as _;
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+    // -EncodedCommand takes UTF-16LE, which keeps the script clear of quoting rules.
+    let encoded: Vec<u8> = WINDOWS_OCR_SCRIPT.encode_utf16().flat_map(u16::to_le_bytes).collect();
+    let mut command = Command::new("powershell");
+    command
+        .args(["-NoProfile", "-NonInteractive", "-ExecutionPolicy", "Bypass", "-EncodedCommand"])
+        .arg(BASE64_STANDARD.encode(encoded))
+        .creation_flags(CREATE_NO_WINDOW);
+    parse_script_output(&run_capture_command(&mut command, "PowerShell")?)
+}
+
+#[cfg(target_os = "macos")]
+fn run_platform_ocr() -> Result<WindowText> {
+    let mut command = Command::new("osascript");
+    command.args(["-l", "JavaScript", "-e", MACOS_OCR_SCRIPT]);
+    parse_script_output(&run_capture_command(&mut command, "osascript")?)
+}
+
+#[cfg(any(target_os = "windows", target_os = "macos"))]
+fn parse_script_output(output: &str) -> Result<WindowText> {
+    serde_json::from_str(output).context("OCR script returned malformed output")
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn run_platform_ocr() -> Result<WindowText> {
+    let window_id = run_capture_command(Command::new("xdotool").arg("getactivewindow"), "xdotool")?;
+    let path = std::env::temp_dir().join(format!("voicereader-ocr-{}.png", std::process::id()));
+    let captured = run_capture_command(
+        Command::new("import").args(["-window", window_id.trim()]).arg(&path),
+        "ImageMagick import",
+    );
+    let recognized = captured.and_then(|_| {
+        run_capture_command(Command::new("tesseract").arg(&path).args(["stdout", "tsv"]), "tesseract")
+    });
+    let _ = std::fs::remove_file(&path);
+    parse_tesseract_tsv(&recognized?)
+}
+
+/// Groups tesseract's word rows into lines. The page row carries the image size.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn parse_tesseract_tsv(tsv: &str) -> Result<WindowText> {
+    const PAGE_LEVEL: &str = "1";
+    const WORD_LEVEL: &str = "5";
+
+    let mut window = WindowText {
+        width: 0.0,
+        height: 0.0,
+        lines: Vec::new(),
+    };
+    let mut current_key: Option<(String, String, String)> = None;
+    for row in tsv.lines().skip(1) {
+        let fields: Vec<&str> = row.split('\t').collect();
+        if fields.len() < 12 {
+            continue;
+        }
+        let number = |index: usize| fields[index].parse::<f32>().unwrap_or(0.0);
+        let (left, top, width, height) = (number(6), number(7), number(8), number(9));
+        if fields[0] == PAGE_LEVEL {
+            window.width = width;
+            window.height = height;
+            continue;
+        }
+        let text = fields[11].trim();
+        if fields[0] != WORD_LEVEL || text.is_empty() {
+            continue;
+        }
+        let key = (fields[2].to_string(), fields[3].to_string(), fields[4].to_string());
+        match window.lines.last_mut() {
+            Some(line) if current_key.as_ref() == Some(&key) => {
+                let right = line.right().max(left + width);
+                let bottom = line.bottom().max(top + height);
+                line.left = line.left.min(left);
+                line.top = line.top.min(top);
+                line.width = right - line.left;
+                line.height = bottom - line.top;
+                line.text.push(' ');
+                line.text.push_str(text);
+            }
+            _ => {
+                window.lines.push(OcrLine {
+                    text: text.to_string(),
+                    left,
+                    top,
+                    width,
+                    height,
+                });
+                current_key = Some(key);
+            }
+        }
+    }
+    if window.width <= 0.0 || window.height <= 0.0 {
+        return Err(anyhow!("tesseract did not report the image size"));
+    }
+    Ok(window)
+}
+
+/// Runs `command` and returns its stdout, turning a missing tool or a
+/// failed run into an error that names `tool`.
+fn run_capture_command(command: &mut Command, tool: &str) -> Result<String> {
+    let output = command
+        .stdin(Stdio::null())
+        .output()
+        .with_context(|| format!("Failed to run {tool}; reading a window needs it installed"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!("{tool} failed to read the window: {}", stderr.trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Captures the foreground window with GDI and recognizes it with the OCR
+/// languages of the user profile. Prints `WindowText` as JSON.
+#[cfg(target_os = "windows")]
+const WINDOWS_OCR_SCRIPT: &str = r#"
+$ErrorActionPreference = 'Stop'
+[Console]::OutputEncoding = [System.Text.Encoding]::UTF8
+Add-Type -AssemblyName System.Drawing
+Add-Type -AssemblyName System.Runtime.WindowsRuntime
+Add-Type @'
+using System;
+using System.Runtime.InteropServices;
+public static class VoiceReaderWindow {
+    [StructLayout(LayoutKind.Sequential)]
+    public struct Rect { public int Left, Top, Right, Bottom; }
+    [DllImport("user32.dll")] public static extern bool SetProcessDPIAware();
+    [DllImport("user32.dll")] public static extern IntPtr GetForegroundWindow();
+    [DllImport("user32.dll")] public static extern bool GetWindowRect(IntPtr hwnd, out Rect rect);
+}
+'@
+[void][VoiceReaderWindow]::SetProcessDPIAware()
+$rect = New-Object VoiceReaderWindow+Rect
+if (-not [VoiceReaderWindow]::GetWindowRect([VoiceReaderWindow]::GetForegroundWindow(), [ref]$rect)) {
+    throw 'No foreground window'
+}
+$width = $rect.Right - $rect.Left
+$height = $rect.Bottom - $rect.Top
+if ($width -le 0 -or $height -le 0) { throw 'The foreground window has no visible area' }
+
+$path = Join-Path ([System.IO.Path]::GetTempPath()) ("voicereader-ocr-" + [guid]::NewGuid() + ".png")
+$bitmap = New-Object System.Drawing.Bitmap $width, $height
+$graphics = [System.Drawing.Graphics]::FromImage($bitmap)
+$graphics.CopyFromScreen($rect.Left, $rect.Top, 0, 0, $bitmap.Size)
+$bitmap.Save($path, [System.Drawing.Imaging.ImageFormat]::Png)
+$graphics.Dispose()
+$bitmap.Dispose()
+
+$asTask = [System.WindowsRuntimeSystemExtensions].GetMethods() | Where-Object {
+    $_.Name -eq 'AsTask' -and $_.GetParameters().Count -eq 1 -and
+    $_.GetParameters()[0].ParameterType.Name -eq 'IAsyncOperation`1'
+} | Select-Object -First 1
+function Await($operation, [Type]$resultType) {
+    $task = $asTask.MakeGenericMethod($resultType).Invoke($null, @($operation))
+    [void]$task.Wait(-1)
+    $task.Result
+}
+
+try {
+    [void][Windows.Storage.StorageFile, Windows.Storage, ContentType = WindowsRuntime]
+    [void][Windows.Media.Ocr.OcrEngine, Windows.Foundation, ContentType = WindowsRuntime]
+    [void][Windows.Graphics.Imaging.BitmapDecoder, Windows.Graphics, ContentType = WindowsRuntime]
+    $engine = [Windows.Media.Ocr.OcrEngine]::TryCreateFromUserProfileLanguages()
+    if ($null -eq $engine) { throw 'No OCR language is installed for the user profile languages' }
+    $file = Await ([Windows.Storage.StorageFile]::GetFileFromPathAsync($path)) ([Windows.Storage.StorageFile])
+    $stream = Await ($file.OpenAsync([Windows.Storage.FileAccessMode]::Read)) ([Windows.Storage.Streams.IRandomAccessStream])
+    $decoder = Await ([Windows.Graphics.Imaging.BitmapDecoder]::CreateAsync($stream)) ([Windows.Graphics.Imaging.BitmapDecoder])
+    $image = Await ($decoder.GetSoftwareBitmapAsync()) ([Windows.Graphics.Imaging.SoftwareBitmap])
+    $result = Await ($engine.RecognizeAsync($image)) ([Windows.Media.Ocr.OcrResult])
+    $stream.Dispose()
+    $lines = @(foreach ($line in $result.Lines) {
+        $boxes = @($line.Words | ForEach-Object { $_.BoundingRect })
+        $left = ($boxes | ForEach-Object { $_.X } | Measure-Object -Minimum).Minimum
+        $top = ($boxes | ForEach-Object { $_.Y } | Measure-Object -Minimum).Minimum
+        $right = ($boxes | ForEach-Object { $_.X + $_.Width } | Measure-Object -Maximum).Maximum
+        $bottom = ($boxes | ForEach-Object { $_.Y + $_.Height } | Measure-Object -Maximum).Maximum
+        @{ text = $line.Text; left = $left; top = $top; width = $right - $left; height = $bottom - $top }
+    })
+    @{ width = $width; height = $height; lines = $lines } | ConvertTo-Json -Depth 4 -Compress
+} finally {
+    Remove-Item -LiteralPath $path -ErrorAction SilentlyContinue
+}
+"#;
+
+/// Captures the front window of the frontmost app with `screencapture` and
+/// recognizes it with Vision. Vision boxes are normalized with the origin
+/// at the bottom-left; they are converted to window points. Prints
+/// `WindowText` as JSON.
+#[cfg(target_os = "macos")]
+const MACOS_OCR_SCRIPT: &str = r#"
+ObjC.import('Foundation');
+ObjC.import('Vision');
+const app = Application.currentApplication();
+app.includeStandardAdditions = true;
+const frontmost = Application('System Events').processes.whose({ frontmost: true })[0];
+const frontWindow = frontmost.windows[0];
+const [x, y] = frontWindow.position();
+const [width, height] = frontWindow.size();
+const path = $.NSTemporaryDirectory().js + 'voicereader-ocr-' + $.NSUUID.UUID.UUIDString.js + '.png';
+app.doShellScript(`screencapture -x -R${x},${y},${width},${height} '${path}'`);
+
+const request = $.VNRecognizeTextRequest.alloc.init;
+request.recognitionLevel = $.VNRequestTextRecognitionLevelAccurate;
+request.usesLanguageCorrection = true;
+const handler = $.VNImageRequestHandler.alloc.initWithURLOptions($.NSURL.fileURLWithPath(path), $({}));
+const recognized = handler.performRequestsError($([request]), null);
+$.NSFileManager.defaultManager.removeItemAtPathError(path, null);
+if (!recognized) {
+  throw new Error('Text recognition failed');
+}
+
+const lines = [];
+const results = request.results;
+for (let idx = 0; idx < results.count; idx += 1) {
+  const observation = results.objectAtIndex(idx);
+  const candidates = observation.topCandidates(1);
+  if (candidates.count === 0) {
+    continue;
+  }
+  const box = observation.boundingBox;
+  lines.push({
+    text: candidates.objectAtIndex(0).string.js,
+    left: box.origin.x * width,
+    top: (1 - box.origin.y - box.size.height) * height,
+    width: box.size.width * width,
+    height: box.size.height * height,
+  });
+}
+JSON.stringify({ width, height, lines });
+"#;
@@ -0,0 +1,45 @@
+import { listen } from "@tauri-apps/api/event";
+import "./caption.css";
+
+type CaptionPayload = {
+  job_id: string;
+  sentence: string;
+  word_index: number;
+};
+
+const app = document.querySelector<HTMLDivElement>("#caption-app");
+if (!app) {
+  throw new Error("Missing caption app root");
+}
+
+app.innerHTML = `<div id="caption-line" class="caption-line hidden"></div>`;
+const line = document.querySelector<HTMLDivElement>("#caption-line")!;
+
+let renderedSentence = "";
+
+function renderCaption(payload: CaptionPayload): void {
+  if (payload.sentence !== renderedSentence) {
+    line.replaceChildren(
+      ...payload.sentence.split(/\s+/).filter(Boolean).map((word) => {
+        const span = document.createElement("span");
+        span.className = "caption-word";
+        span.textContent = `${word} `;
+        return span;
+      }),
+    );
+    renderedSentence = payload.sentence;
+  }
+  line.querySelectorAll(".caption-word").forEach((word, index) => {
+    word.classList.toggle("caption-word-current", index === payload.word_index);
+  });
+  line.classList.remove("hidden");
+}
+
+function clearCaption(): void {
+  line.classList.add("hidden");
+  line.replaceChildren();
+  renderedSentence = "";
+}
+
+void listen<CaptionPayload>("voicereader:caption", ({ payload }) => renderCaption(payload));
+void listen("voicereader:caption-cleared", () => clearCaption());
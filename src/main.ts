@@ -19,9 +19,11 @@ type ModelOption = {
 
 type BootstrapPayload = {
   hotkey: string;
+  hotkeys_enabled: boolean;
   selected_voice_id: string;
   selected_model: string;
   selected_speaker: string;
+  speak_settings: SpeakSettings;
   startup_error?: string | null;
   build_variant: string;
   qwen_enabled: boolean;
@@ -53,12 +55,36 @@ type JobCancelRequestedPayload = {
   job_id: string;
 };
 
+type JobCancelConfirmedPayload = {
+  job_id: string;
+  timed_out: boolean;
+};
+
 type JobStartedPayload = {
   job_id: string;
   ws_url: string;
   source: string;
   source_window?: string;
   rate?: number;
+  capture?: CaptureMetadata;
+};
+
+type CaptureMetadata = {
+  app_name?: string | null;
+  window_title: string;
+  capture_method:
+    | "clipboard_probe"
+    | "accessibility"
+    | "primary_selection"
+    | "ocr"
+    | "clipboard"
+    | "file"
+    | "url"
+    | "stdin"
+    | "extension"
+    | "direct";
+  location?: string | null;
+  text_length: number;
 };
 
 type ToolbarActionPayload = {
@@ -87,6 +113,14 @@ type HotkeyResult = {
   hotkey: string;
 };
 
+// Rejection value of every Tauri command.
+type CommandError = {
+  code: string;
+  message: string;
+  recoverable: boolean;
+  detail: string | null;
+};
+
 type StoredVoice = {
   voice_id: string;
   display_name: string;
@@ -109,6 +143,45 @@ type EngineStoragePathsPayload = {
   hf_cache_dir: string;
 };
 
+type EngineHttpSettings = {
+  connect_timeout_ms: number;
+  request_timeout_ms: number;
+  max_retries: number;
+};
+
+type SpeakSettings = {
+  rate: number;
+  volume: number;
+  chunk_max_chars: number;
+};
+
+type PeakLimiter = {
+  enabled: boolean;
+  ceiling_db: number;
+};
+
+type SilenceTrim = {
+  threshold: number;
+  max_keep_ms: number;
+};
+
+type PlaybackMode = "webview" | "native";
+
+type QueuePolicy = "interrupt" | "enqueue";
+
+type PlaybackStatus = {
+  mode: PlaybackMode;
+  sox_found: boolean;
+};
+
+type VirtualMicStatus = {
+  enabled: boolean;
+  device: string;
+  consent_needed: boolean;
+  consent_notice: string;
+  sox_found: boolean;
+};
+
 type PrefetchModelsResult = {
   ok: boolean;
   message: string;
@@ -119,8 +192,23 @@ type PrefetchModelsResult = {
   hf_cache_dir: string;
 };
 
+type QueuedAudio = {
+  buffer: AudioBuffer;
+  // Arrival order within the job, acknowledged via ack_audio once played.
+  ordinal: number;
+};
+
+// Where a job's AUDIO_CHUNK sequence stands; chunks carry `chunk_seq` and
+// `stream_epoch`, and `resync_audio` resends them under a new epoch.
+type ChunkStream = {
+  epoch: number;
+  // Next `chunk_seq` to play; null takes whatever arrives next.
+  expected: number | null;
+  resyncing: boolean;
+};
+
 type QueuedPlayback = {
-  buffers: AudioBuffer[];
+  buffers: QueuedAudio[];
   bufferedSeconds: number;
   started: boolean;
   terminal: boolean;
@@ -128,9 +216,16 @@ type QueuedPlayback = {
 
 const VOICE_ORDINAL_STORAGE_KEY = "voicereader.saved_voice_ordinals.v1";
 const THEME_STORAGE_KEY = "voicereader.theme.v1";
+const OUTPUT_KEEPALIVE_STORAGE_KEY = "voicereader.output_keepalive.v1";
+const STREAM_FORMAT_STORAGE_KEY = "voicereader.stream_format.v1";
+const NATIVE_SKIP_MS = 5000;
 
 type ThemeMode = "dark" | "light";
 
+// Bluetooth sinks power down after a short idle and clip the first ~300 ms
+// when they wake; keep them awake or give them time to wake up.
+type OutputKeepalive = "off" | "preroll" | "comfort_noise";
+
 function readThemePreference(): ThemeMode {
   try {
     const raw = window.localStorage.getItem(THEME_STORAGE_KEY);
@@ -140,6 +235,33 @@ function readThemePreference(): ThemeMode {
   }
 }
 
+// Encoding and rate of AUDIO_CHUNK audio; see `audio_formats` in get_capabilities.
+type StreamFormat = "pcm_s16le" | "pcm_f32le" | "pcm_s16le_16k";
+
+const STREAM_FORMATS: Record<StreamFormat, { encoding: string; sample_rate: number | null }> = {
+  pcm_s16le: { encoding: "pcm_s16le", sample_rate: null },
+  pcm_f32le: { encoding: "pcm_f32le", sample_rate: null },
+  pcm_s16le_16k: { encoding: "pcm_s16le", sample_rate: 16000 },
+};
+
+function readStreamFormatPreference(): StreamFormat {
+  try {
+    const raw = window.localStorage.getItem(STREAM_FORMAT_STORAGE_KEY);
+    return raw === "pcm_f32le" || raw === "pcm_s16le_16k" ? raw : "pcm_s16le";
+  } catch {
+    return "pcm_s16le";
+  }
+}
+
+function readOutputKeepalivePreference(): OutputKeepalive {
+  try {
+    const raw = window.localStorage.getItem(OUTPUT_KEEPALIVE_STORAGE_KEY);
+    return raw === "preroll" || raw === "comfort_noise" ? raw : "off";
+  } catch {
+    return "off";
+  }
+}
+
 let currentTheme: ThemeMode = readThemePreference();
 document.documentElement.setAttribute("data-theme", currentTheme);
 
@@ -178,6 +300,7 @@ app.innerHTML = `
           <div class="inline-row hotkey-row">
             <div class="hotkey" id="hotkey-pill">Loading hotkey...</div>
             <button id="hotkey-edit-btn">Edit</button>
+            <button id="hotkey-toggle-btn">Disable</button>
           </div>
           <div class="row">
             <div class="inline-row hotkey-capture is-hidden" id="hotkey-capture-row">
@@ -204,6 +327,41 @@ app.innerHTML = `
               <label>Rate <input id="rate" type="number" min="0.25" max="4" step="0.05" value="1.5" /></label>
               <label>Volume <input id="volume" type="number" min="0" max="2" step="0.05" value="1" /></label>
               <label>Chunk Max Chars <input id="chunk-max" type="number" min="100" max="200" step="10" value="200" /></label>
+              <label><input id="peak-limiter-enabled" type="checkbox" checked /> Limit output peaks</label>
+              <label>Peak Ceiling (dBTP) <input id="peak-limiter-ceiling" type="number" min="-12" max="0" step="0.5" value="-1" /></label>
+              <label><input id="silence-trim-enabled" type="checkbox" /> Trim silence between chunks</label>
+              <label>Silence Kept (ms) <input id="silence-trim-keep" type="number" min="0" max="2000" step="50" value="150" /></label>
+              <label>Bluetooth Keepalive
+                <select id="output-keepalive">
+                  <option value="off">Off</option>
+                  <option value="preroll">Pre-roll silence</option>
+                  <option value="comfort_noise">Comfort noise</option>
+                </select>
+              </label>
+              <label>Playback
+                <select id="playback-mode">
+                  <option value="webview">In the app window</option>
+                  <option value="native">Native (SoX, supports seeking)</option>
+                </select>
+              </label>
+              <label>Selection While Reading
+                <select id="queue-policy">
+                  <option value="interrupt">Interrupt (hotkey stops reading)</option>
+                  <option value="enqueue">Queue after the current read</option>
+                </select>
+              </label>
+              <label>Stream Format
+                <select id="stream-format">
+                  <option value="pcm_s16le">16-bit PCM</option>
+                  <option value="pcm_f32le">32-bit float PCM</option>
+                  <option value="pcm_s16le_16k">16-bit PCM, 16 kHz (low bandwidth)</option>
+                </select>
+              </label>
+              <label>Engine Connect Timeout (ms) <input id="engine-connect-timeout" type="number" min="100" max="60000" step="100" value="2000" /></label>
+              <label>Engine Request Timeout (ms) <input id="engine-request-timeout" type="number" min="1000" max="600000" step="1000" value="60000" /></label>
+              <label>Engine Retries <input id="engine-max-retries" type="number" min="0" max="5" step="1" value="2" /></label>
+              <label>Virtual Mic Device <input id="virtual-mic-device" type="text" /></label>
+              <label><input id="virtual-mic-enabled" type="checkbox" /> Also play reads into the virtual mic</label>
             </div>
           </details>
 
@@ -283,7 +441,9 @@ app.innerHTML = `
             <button id="download-qwen-custom-btn">Download Qwen CustomVoice</button>
             <button id="download-qwen-base-btn">Download Qwen Base</button>
             <button id="download-qwen-all-btn" class="accent">Download Both Qwen Models</button>
+            <button id="cancel-download-btn" disabled>Cancel Download</button>
           </div>
+          <label><input id="announce-downloads" type="checkbox" /> Announce download progress aloud</label>
           <p class="hint" id="model-download-status">No download in progress.</p>
         </article>
         <article class="card">
@@ -311,6 +471,7 @@ const themeToggleBtn = document.querySelector<HTMLButtonElement>("#theme-toggle-
 const runtimePill = document.querySelector<HTMLButtonElement>("#runtime-pill")!;
 const hotkeyInput = document.querySelector<HTMLInputElement>("#hotkey-input")!;
 const hotkeyEditBtn = document.querySelector<HTMLButtonElement>("#hotkey-edit-btn")!;
+const hotkeyToggleBtn = document.querySelector<HTMLButtonElement>("#hotkey-toggle-btn")!;
 const hotkeyCancelBtn = document.querySelector<HTMLButtonElement>("#cancel-hotkey-btn")!;
 const setHotkeyBtn = document.querySelector<HTMLButtonElement>("#set-hotkey-btn")!;
 const modelSelect = document.querySelector<HTMLSelectElement>("#model-select")!;
@@ -333,10 +494,25 @@ const modelDownloadStatus = document.querySelector<HTMLParagraphElement>("#model
 const downloadQwenCustomBtn = document.querySelector<HTMLButtonElement>("#download-qwen-custom-btn")!;
 const downloadQwenBaseBtn = document.querySelector<HTMLButtonElement>("#download-qwen-base-btn")!;
 const downloadQwenAllBtn = document.querySelector<HTMLButtonElement>("#download-qwen-all-btn")!;
+const cancelDownloadBtn = document.querySelector<HTMLButtonElement>("#cancel-download-btn")!;
+const announceDownloadsInput = document.querySelector<HTMLInputElement>("#announce-downloads")!;
 
 const rateInput = document.querySelector<HTMLInputElement>("#rate")!;
 const volumeInput = document.querySelector<HTMLInputElement>("#volume")!;
 const chunkMaxInput = document.querySelector<HTMLInputElement>("#chunk-max")!;
+const peakLimiterEnabledInput = document.querySelector<HTMLInputElement>("#peak-limiter-enabled")!;
+const peakLimiterCeilingInput = document.querySelector<HTMLInputElement>("#peak-limiter-ceiling")!;
+const silenceTrimEnabledInput = document.querySelector<HTMLInputElement>("#silence-trim-enabled")!;
+const silenceTrimKeepInput = document.querySelector<HTMLInputElement>("#silence-trim-keep")!;
+const outputKeepaliveSelect = document.querySelector<HTMLSelectElement>("#output-keepalive")!;
+const streamFormatSelect = document.querySelector<HTMLSelectElement>("#stream-format")!;
+const playbackModeSelect = document.querySelector<HTMLSelectElement>("#playback-mode")!;
+const queuePolicySelect = document.querySelector<HTMLSelectElement>("#queue-policy")!;
+const engineConnectTimeoutInput = document.querySelector<HTMLInputElement>("#engine-connect-timeout")!;
+const engineRequestTimeoutInput = document.querySelector<HTMLInputElement>("#engine-request-timeout")!;
+const engineMaxRetriesInput = document.querySelector<HTMLInputElement>("#engine-max-retries")!;
+const virtualMicDeviceInput = document.querySelector<HTMLInputElement>("#virtual-mic-device")!;
+const virtualMicEnabledInput = document.querySelector<HTMLInputElement>("#virtual-mic-enabled")!;
 
 const refreshBtn = document.querySelector<HTMLButtonElement>("#refresh-btn")!;
 const restartBtn = document.querySelector<HTMLButtonElement>("#restart-btn")!;
@@ -350,9 +526,14 @@ let runtimeWasDown = false;
 const activeAudioSources = new Set<AudioBufferSourceNode>();
 const suppressedJobIds = new Set<string>();
 const playbackChunkCounts = new Map<string, number>();
+const receivedChunkCounts = new Map<string, number>();
+const chunkStreams = new Map<string, ChunkStream>();
 const queuedPlaybackByJob = new Map<string, QueuedPlayback>();
 let hasOutputPrimed = false;
 let hasStartupSilenceInjected = false;
+let outputKeepalive: OutputKeepalive = readOutputKeepalivePreference();
+let streamFormat: StreamFormat = readStreamFormatPreference();
+let comfortNoiseSource: AudioBufferSourceNode | null = null;
 let currentPresetSpeakers: SpeakerPreset[] = [];
 let currentSelectedSpeaker = "";
 let currentSelectedModel = "";
@@ -362,8 +543,11 @@ const presetDescriptionOverrides = new Map<string, string>();
 let savedVoiceOrdinals = loadSavedVoiceOrdinals();
 let qwenEnabled = true;
 let pendingHotkeyCapture = "";
+let hotkeysEnabled = true;
 let cloneStatusTimeoutId: number | null = null;
 let toolbarPaused = false;
+// Audio plays in Rust and AUDIO_CHUNK events carry none; the toolbar follows PLAYBACK_DONE.
+let nativePlayback = false;
 let activeJobSourceWindow = "";
 let activeToolbarJobId = "";
 
@@ -376,6 +560,13 @@ function log(message: string, level: "info" | "error" = "info"): void {
   logEl.prepend(line);
 }
 
+function errorMessage(error: unknown): string {
+  if (typeof error === "object" && error !== null && "message" in error) {
+    return String((error as CommandError).message);
+  }
+  return String(error);
+}
+
 function showCloneStatus(message: string, level: "info" | "success" | "error", autoHideMs = 6000): void {
   if (cloneStatusTimeoutId !== null) {
     window.clearTimeout(cloneStatusTimeoutId);
@@ -418,6 +609,12 @@ function setHotkeyDisplay(value: string): void {
   hotkeyInput.value = value;
 }
 
+function setHotkeysEnabledDisplay(enabled: boolean): void {
+  hotkeysEnabled = enabled;
+  hotkeyToggleBtn.textContent = enabled ? "Disable" : "Enable";
+  hotkeyPill.classList.toggle("is-disabled", !enabled);
+}
+
 function setHotkeyEditMode(enabled: boolean): void {
   hotkeyEditBtn.classList.toggle("is-hidden", enabled);
   hotkeyCaptureRow.classList.toggle("is-hidden", !enabled);
@@ -706,10 +903,67 @@ function ensureAudioContext(): AudioContext {
   if (!audioContext) {
     audioContext = new AudioContext();
     playbackCursor = audioContext.currentTime;
+    syncComfortNoise();
   }
   return audioContext;
 }
 
+function applyOutputKeepalive(mode: OutputKeepalive): void {
+  outputKeepalive = mode;
+  outputKeepaliveSelect.value = mode;
+  syncComfortNoise();
+  try {
+    window.localStorage.setItem(OUTPUT_KEEPALIVE_STORAGE_KEY, mode);
+  } catch {
+    // Ignore storage write errors.
+  }
+}
+
+async function applyStreamFormat(format: StreamFormat): Promise<void> {
+  await invoke("set_audio_format", { format: STREAM_FORMATS[format] });
+  streamFormat = format;
+  streamFormatSelect.value = format;
+  try {
+    window.localStorage.setItem(STREAM_FORMAT_STORAGE_KEY, format);
+  } catch {
+    // Ignore storage write errors.
+  }
+}
+
+function syncComfortNoise(): void {
+  // About -80 dBFS: inaudible, but enough signal that the sink never idles.
+  const COMFORT_NOISE_AMPLITUDE = 0.0001;
+  const COMFORT_NOISE_LOOP_SECONDS = 1;
+
+  if (outputKeepalive !== "comfort_noise") {
+    if (comfortNoiseSource) {
+      try {
+        comfortNoiseSource.stop(0);
+      } catch {
+        // no-op: source may have already ended
+      }
+      comfortNoiseSource.disconnect();
+      comfortNoiseSource = null;
+    }
+    return;
+  }
+  // Started with the first playback so the app does not open the output early.
+  if (!audioContext || comfortNoiseSource) {
+    return;
+  }
+  const frames = Math.round(audioContext.sampleRate * COMFORT_NOISE_LOOP_SECONDS);
+  const buffer = audioContext.createBuffer(1, frames, audioContext.sampleRate);
+  const samples = buffer.getChannelData(0);
+  for (let idx = 0; idx < frames; idx += 1) {
+    samples[idx] = (Math.random() * 2 - 1) * COMFORT_NOISE_AMPLITUDE;
+  }
+  comfortNoiseSource = audioContext.createBufferSource();
+  comfortNoiseSource.buffer = buffer;
+  comfortNoiseSource.loop = true;
+  comfortNoiseSource.connect(audioContext.destination);
+  comfortNoiseSource.start();
+}
+
 function selectedRateSetting(): number {
   const parsed = Number(rateInput.value);
   if (!Number.isFinite(parsed)) {
@@ -750,18 +1004,19 @@ function hideToolbar(): void {
 }
 
 async function togglePlaybackPause(): Promise<void> {
+  await setPlaybackPaused(!toolbarPaused);
+}
+
+async function setPlaybackPaused(paused: boolean): Promise<void> {
   const context = ensureAudioContext();
-  if (toolbarPaused) {
-    if (context.state === "suspended") {
-      await context.resume();
-    }
-    toolbarPaused = false;
-  } else {
+  if (paused) {
     if (context.state !== "suspended") {
       await context.suspend();
     }
-    toolbarPaused = true;
+  } else if (context.state === "suspended") {
+    await context.resume();
   }
+  toolbarPaused = paused;
   void emit("voicereader:toolbar-paused", { paused: toolbarPaused } satisfies ToolbarPausePayload);
 }
 
@@ -820,7 +1075,7 @@ function nextChunkLeadSeconds(jobId: string): number {
   return lead;
 }
 
-function scheduleAudioBuffer(jobId: string, buffer: AudioBuffer): void {
+function scheduleAudioBuffer(jobId: string, buffer: AudioBuffer, ordinal?: number): void {
   const context = ensureAudioContext();
   const source = context.createBufferSource();
   source.buffer = buffer;
@@ -828,6 +1083,9 @@ function scheduleAudioBuffer(jobId: string, buffer: AudioBuffer): void {
   activeAudioSources.add(source);
   source.onended = () => {
     activeAudioSources.delete(source);
+    if (jobId && ordinal !== undefined) {
+      void invoke("ack_audio", { jobId, chunkIndex: ordinal }).catch(() => undefined);
+    }
     if (jobId !== activeToolbarJobId) {
       return;
     }
@@ -859,12 +1117,12 @@ function flushQueuedPlayback(jobId: string, forceStart: boolean): void {
   }
 
   while (queued.buffers.length > 0) {
-    const buffer = queued.buffers.shift();
-    if (!buffer) {
+    const next = queued.buffers.shift();
+    if (!next) {
       continue;
     }
-    scheduleAudioBuffer(jobId, buffer);
-    queued.bufferedSeconds = Math.max(0, queued.bufferedSeconds - buffer.duration);
+    scheduleAudioBuffer(jobId, next.buffer, next.ordinal);
+    queued.bufferedSeconds = Math.max(0, queued.bufferedSeconds - next.buffer.duration);
   }
 
   if (queued.terminal && queued.buffers.length === 0) {
@@ -899,6 +1157,15 @@ function decodePcm16Base64ToFloat32(base64Data: string): Float32Array {
   return output;
 }
 
+function decodePcmF32Base64(base64Data: string): Float32Array {
+  const binary = atob(base64Data);
+  const bytes = new Uint8Array(binary.length);
+  for (let idx = 0; idx < binary.length; idx += 1) {
+    bytes[idx] = binary.charCodeAt(idx);
+  }
+  return new Float32Array(bytes.buffer, 0, Math.floor(bytes.byteLength / 4));
+}
+
 async function fileToBase64(file: File): Promise<string> {
   const arrayBuffer = await file.arrayBuffer();
   const bytes = new Uint8Array(arrayBuffer);
@@ -909,6 +1176,62 @@ async function fileToBase64(file: File): Promise<string> {
   return btoa(binary);
 }
 
+// Decides whether a chunk plays now. Stale epochs and duplicates are dropped;
+// a gap drops the chunk and asks the backend to resend from the missing one.
+function acceptChunkInSequence(jobId: string, eventPayload: Record<string, unknown>): boolean {
+  const seq = Number(eventPayload.chunk_seq ?? NaN);
+  const epoch = Number(eventPayload.stream_epoch ?? NaN);
+  if (!jobId || !Number.isInteger(seq) || !Number.isInteger(epoch)) {
+    return true;
+  }
+  const stream = chunkStreams.get(jobId);
+  // A job first seen mid-stream, e.g. after a reload, starts where it is.
+  if (!stream) {
+    chunkStreams.set(jobId, { epoch, expected: seq + 1, resyncing: false });
+    return true;
+  }
+  if (epoch < stream.epoch) {
+    return false;
+  }
+  if (epoch > stream.epoch) {
+    stream.epoch = epoch;
+    stream.resyncing = false;
+    // A resend may start later than asked when the backend no longer holds
+    // the missing chunks; play on from there.
+    if (stream.expected !== null && seq < stream.expected) {
+      return false;
+    }
+    stream.expected = seq + 1;
+    return true;
+  }
+  if (stream.expected === null || seq === stream.expected) {
+    stream.expected = seq + 1;
+    return true;
+  }
+  if (seq < stream.expected) {
+    return false;
+  }
+  if (!stream.resyncing) {
+    stream.resyncing = true;
+    const fromSeq = stream.expected;
+    log(`Audio chunks ${fromSeq}-${seq - 1} of job ${jobId} are missing; asking for them again`);
+    invoke<{ ok: boolean; message: string }>("resync_audio", { jobId, fromSeq })
+      .then((result) => {
+        if (!result.ok) {
+          log(`Skipping missing audio: ${result.message}`, "error");
+          stream.resyncing = false;
+          stream.expected = null;
+        }
+      })
+      .catch((error) => {
+        log(`Audio resync failed: ${errorMessage(error)}`, "error");
+        stream.resyncing = false;
+        stream.expected = null;
+      });
+  }
+  return false;
+}
+
 async function enqueueAudioChunk(eventPayload: Record<string, unknown>): Promise<void> {
   const audio = eventPayload.audio as Record<string, unknown> | undefined;
   if (!audio) {
@@ -932,8 +1255,21 @@ async function enqueueAudioChunk(eventPayload: Record<string, unknown>): Promise
     await context.resume();
   }
 
-  let samples = decodePcm16Base64ToFloat32(dataBase64);
-  if (!hasStartupSilenceInjected) {
+  const KEEPALIVE_PREROLL_MS = 300;
+
+  const jobId = String(eventPayload.job_id ?? "");
+  const format = String(audio.format ?? "pcm_s16le");
+  if (format !== "pcm_s16le" && format !== "pcm_f32le") {
+    log(`Received unsupported audio format ${format}`, "error");
+    return;
+  }
+  let samples = format === "pcm_f32le" ? decodePcmF32Base64(dataBase64) : decodePcm16Base64ToFloat32(dataBase64);
+  const idleOutput = activeAudioSources.size === 0;
+  if (outputKeepalive === "preroll" && jobId && !receivedChunkCounts.has(jobId) && idleOutput) {
+    // Gives a sink that went idle since the last job time to wake up.
+    samples = prependSilence(samples, sampleRate, KEEPALIVE_PREROLL_MS);
+    hasStartupSilenceInjected = true;
+  } else if (!hasStartupSilenceInjected) {
     // The first device wake-up can clip a short prefix; prepend silence once.
     samples = prependSilence(samples, sampleRate, 160);
     hasStartupSilenceInjected = true;
@@ -942,14 +1278,18 @@ async function enqueueAudioChunk(eventPayload: Record<string, unknown>): Promise
   const channelSamples = new Float32Array(samples);
   const buffer = context.createBuffer(1, samples.length, sampleRate);
   buffer.copyToChannel(channelSamples, 0, 0);
-  const jobId = String(eventPayload.job_id ?? "");
   if (!jobId) {
     scheduleAudioBuffer(jobId, buffer);
     return;
   }
 
+  const received = receivedChunkCounts.get(jobId) ?? 0;
+  receivedChunkCounts.set(jobId, received + 1);
+  // Acks count chunks in the order they were sent, which `chunk_seq` is.
+  const seq = Number(eventPayload.chunk_seq ?? NaN);
+  const ordinal = Number.isInteger(seq) ? seq : received;
   const queued = ensureQueuedPlayback(jobId);
-  queued.buffers.push(buffer);
+  queued.buffers.push({ buffer, ordinal });
   queued.bufferedSeconds += buffer.duration;
   flushQueuedPlayback(jobId, false);
 }
@@ -1025,7 +1365,7 @@ async function refreshEngineStoragePaths(): Promise<void> {
     const paths = await invoke<EngineStoragePathsPayload>("engine_storage_paths");
     modelStoragePaths.textContent = `Data: ${paths.data_dir} | Models: ${paths.models_dir} | HF cache: ${paths.hf_cache_dir}`;
   } catch (error) {
-    modelStoragePaths.textContent = `Storage: unavailable (${String(error)})`;
+    modelStoragePaths.textContent = `Storage: unavailable (${errorMessage(error)})`;
   }
 }
 
@@ -1044,6 +1384,7 @@ function setModelDownloadBusy(isBusy: boolean): void {
   downloadQwenCustomBtn.disabled = isBusy;
   downloadQwenBaseBtn.disabled = isBusy;
   downloadQwenAllBtn.disabled = isBusy;
+  cancelDownloadBtn.disabled = !isBusy;
 }
 
 function renderModelOptions(models: ModelOption[], selectedModel: string): void {
@@ -1168,7 +1509,7 @@ function renderVoicesTable(): void {
         await refreshHealthAndVoices();
         log(`Saved voice updated: ${displayName}`);
       } catch (error) {
-        log(`Failed to update voice ${voice.voice_id}: ${String(error)}`, "error");
+        log(`Failed to update voice ${voice.voice_id}: ${errorMessage(error)}`, "error");
       } finally {
         saveBtn.disabled = false;
       }
@@ -1189,7 +1530,7 @@ function renderVoicesTable(): void {
         await refreshHealthAndVoices();
         log(`Deleted saved voice: ${voiceLabel}`);
       } catch (error) {
-        log(`Failed to delete voice ${voice.voice_id}: ${String(error)}`, "error");
+        log(`Failed to delete voice ${voice.voice_id}: ${errorMessage(error)}`, "error");
       } finally {
         deleteBtn.disabled = false;
       }
@@ -1213,12 +1554,118 @@ async function applySpeakSettings(): Promise<void> {
   });
 }
 
+function setSpeakSettingsDisplay(settings: SpeakSettings): void {
+  rateInput.value = String(settings.rate);
+  volumeInput.value = String(settings.volume);
+  chunkMaxInput.value = String(settings.chunk_max_chars);
+}
+
+async function refreshPeakLimiter(): Promise<void> {
+  const limiter = await invoke<PeakLimiter>("get_peak_limiter");
+  peakLimiterEnabledInput.checked = limiter.enabled;
+  peakLimiterCeilingInput.value = String(limiter.ceiling_db);
+  peakLimiterCeilingInput.disabled = !limiter.enabled;
+}
+
+async function applyPeakLimiter(): Promise<void> {
+  const limiter: PeakLimiter = {
+    enabled: peakLimiterEnabledInput.checked,
+    ceiling_db: Number(peakLimiterCeilingInput.value),
+  };
+  const result = await invoke<{ message: string }>("set_peak_limiter", { limiter });
+  log(result.message);
+}
+
+// The threshold has no control of its own; it is kept as last set.
+let silenceTrimThreshold = 0.01;
+
+async function refreshSilenceTrim(): Promise<void> {
+  const trim = await invoke<SilenceTrim | null>("get_silence_trim");
+  silenceTrimEnabledInput.checked = trim !== null;
+  if (trim) {
+    silenceTrimThreshold = trim.threshold;
+    silenceTrimKeepInput.value = String(trim.max_keep_ms);
+  }
+  silenceTrimKeepInput.disabled = trim === null;
+}
+
+async function applySilenceTrim(): Promise<void> {
+  const trim: SilenceTrim | null = silenceTrimEnabledInput.checked
+    ? { threshold: silenceTrimThreshold, max_keep_ms: Number(silenceTrimKeepInput.value) }
+    : null;
+  const result = await invoke<{ message: string }>("set_silence_trim", { trim });
+  log(result.message);
+}
+
+async function refreshEngineHttpSettings(): Promise<void> {
+  const settings = await invoke<EngineHttpSettings>("get_engine_http_settings");
+  engineConnectTimeoutInput.value = String(settings.connect_timeout_ms);
+  engineRequestTimeoutInput.value = String(settings.request_timeout_ms);
+  engineMaxRetriesInput.value = String(settings.max_retries);
+}
+
+async function applyEngineHttpSettings(): Promise<void> {
+  const settings: EngineHttpSettings = {
+    connect_timeout_ms: Number(engineConnectTimeoutInput.value),
+    request_timeout_ms: Number(engineRequestTimeoutInput.value),
+    max_retries: Number(engineMaxRetriesInput.value),
+  };
+  const result = await invoke<{ message: string }>("set_engine_http_settings", { settings });
+  log(result.message);
+}
+
+async function refreshPlaybackMode(): Promise<void> {
+  const status = await invoke<PlaybackStatus>("get_playback_mode");
+  nativePlayback = status.mode === "native";
+  playbackModeSelect.value = status.mode;
+  const nativeOption = playbackModeSelect.querySelector<HTMLOptionElement>('option[value="native"]');
+  if (nativeOption) {
+    nativeOption.disabled = !status.sox_found && !nativePlayback;
+  }
+}
+
+async function refreshQueuePolicy(): Promise<void> {
+  queuePolicySelect.value = await invoke<QueuePolicy>("get_queue_policy");
+}
+
+async function refreshVirtualMic(): Promise<void> {
+  const status = await invoke<VirtualMicStatus>("get_virtual_mic");
+  virtualMicDeviceInput.value = status.device;
+  virtualMicEnabledInput.checked = status.enabled;
+  virtualMicEnabledInput.disabled = !status.sox_found && !status.enabled;
+  virtualMicEnabledInput.title = status.sox_found ? "" : "Needs SoX";
+}
+
+async function applyVirtualMic(): Promise<void> {
+  const enabled = virtualMicEnabledInput.checked;
+  let consent = false;
+  if (enabled) {
+    // Asked once per session, before anything reaches another app's microphone.
+    const status = await invoke<VirtualMicStatus>("get_virtual_mic");
+    if (status.consent_needed) {
+      consent = window.confirm(status.consent_notice);
+      if (!consent) {
+        virtualMicEnabledInput.checked = false;
+        return;
+      }
+    }
+  }
+  const result = await invoke<{ message: string }>("set_virtual_mic", {
+    enabled,
+    device: virtualMicDeviceInput.value,
+    consent,
+  });
+  log(result.message);
+}
+
 async function bootstrap(): Promise<void> {
   const payload = await invoke<BootstrapPayload>("app_bootstrap");
 
   applyBuildCapabilities(payload);
   setHotkeyDisplay(payload.hotkey);
+  setHotkeysEnabledDisplay(payload.hotkeys_enabled);
   setHotkeyEditMode(false);
+  setSpeakSettingsDisplay(payload.speak_settings);
   renderModelOptions(payload.models, payload.selected_model);
   currentPresetSpeakers = payload.preset_speakers;
   currentSelectedSpeaker = payload.selected_speaker;
@@ -1244,6 +1691,12 @@ async function bootstrap(): Promise<void> {
 
   await pollRuntimeStatus();
   await refreshEngineStoragePaths();
+  await refreshEngineHttpSettings();
+  await refreshPeakLimiter();
+  await refreshSilenceTrim();
+  await refreshVirtualMic();
+  await refreshPlaybackMode();
+  await refreshQueuePolicy();
 }
 
 async function bindActions(): Promise<void> {
@@ -1270,6 +1723,15 @@ async function bindActions(): Promise<void> {
     setHotkeyEditMode(true);
   });
 
+  hotkeyToggleBtn.addEventListener("click", async () => {
+    try {
+      const result = await invoke<{ message?: string }>("set_hotkey_enabled", { enabled: !hotkeysEnabled });
+      log(result.message ?? "Global hotkeys updated");
+    } catch (error) {
+      log(`Failed to toggle hotkeys: ${errorMessage(error)}`, "error");
+    }
+  });
+
   hotkeyCancelBtn.addEventListener("click", () => {
     setHotkeyEditMode(false);
   });
@@ -1303,7 +1765,7 @@ async function bindActions(): Promise<void> {
       setHotkeyEditMode(false);
       log(result.message ?? `Hotkey set to ${result.hotkey}`);
     } catch (error) {
-      log(`Failed to update hotkey: ${String(error)}`, "error");
+      log(`Failed to update hotkey: ${errorMessage(error)}`, "error");
     }
   });
 
@@ -1330,6 +1792,49 @@ async function bindActions(): Promise<void> {
     log(`Selected saved voice ${selected.label}`);
   });
 
+  outputKeepaliveSelect.value = outputKeepalive;
+  outputKeepaliveSelect.addEventListener("change", () => {
+    const mode = outputKeepaliveSelect.value as OutputKeepalive;
+    applyOutputKeepalive(mode);
+    log(`Bluetooth keepalive: ${mode}`);
+  });
+
+  streamFormatSelect.value = streamFormat;
+  streamFormatSelect.addEventListener("change", async () => {
+    const format = streamFormatSelect.value as StreamFormat;
+    try {
+      await applyStreamFormat(format);
+      log(`Stream format: ${format}`);
+    } catch (error) {
+      streamFormatSelect.value = streamFormat;
+      log(`Failed to set stream format: ${errorMessage(error)}`, "error");
+    }
+  });
+  if (streamFormat !== "pcm_s16le") {
+    applyStreamFormat(streamFormat).catch((error) => log(`Failed to set stream format: ${errorMessage(error)}`, "error"));
+  }
+
+  playbackModeSelect.addEventListener("change", async () => {
+    try {
+      const result = await invoke<{ message: string }>("set_playback_mode", { mode: playbackModeSelect.value });
+      stopAllPlayback();
+      log(result.message);
+    } catch (error) {
+      log(`Failed to set playback mode: ${errorMessage(error)}`, "error");
+    }
+    await refreshPlaybackMode();
+  });
+
+  queuePolicySelect.addEventListener("change", async () => {
+    try {
+      const result = await invoke<{ message: string }>("set_queue_policy", { policy: queuePolicySelect.value });
+      log(result.message);
+    } catch (error) {
+      log(`Failed to set queue policy: ${errorMessage(error)}`, "error");
+    }
+    await refreshQueuePolicy();
+  });
+
   [rateInput, volumeInput, chunkMaxInput].forEach((input) => {
     input.addEventListener("change", async () => {
       await applySpeakSettings();
@@ -1337,6 +1842,50 @@ async function bindActions(): Promise<void> {
     });
   });
 
+  [peakLimiterEnabledInput, peakLimiterCeilingInput].forEach((input) => {
+    input.addEventListener("change", async () => {
+      try {
+        await applyPeakLimiter();
+      } catch (error) {
+        log(`Failed to set peak limiter: ${errorMessage(error)}`, "error");
+      }
+      await refreshPeakLimiter();
+    });
+  });
+
+  [silenceTrimEnabledInput, silenceTrimKeepInput].forEach((input) => {
+    input.addEventListener("change", async () => {
+      try {
+        await applySilenceTrim();
+      } catch (error) {
+        log(`Failed to set silence trimming: ${errorMessage(error)}`, "error");
+      }
+      await refreshSilenceTrim();
+    });
+  });
+
+  [engineConnectTimeoutInput, engineRequestTimeoutInput, engineMaxRetriesInput].forEach((input) => {
+    input.addEventListener("change", async () => {
+      try {
+        await applyEngineHttpSettings();
+      } catch (error) {
+        log(`Failed to set engine timeouts: ${errorMessage(error)}`, "error");
+        await refreshEngineHttpSettings();
+      }
+    });
+  });
+
+  [virtualMicDeviceInput, virtualMicEnabledInput].forEach((input) => {
+    input.addEventListener("change", async () => {
+      try {
+        await applyVirtualMic();
+      } catch (error) {
+        log(`Failed to set virtual microphone: ${errorMessage(error)}`, "error");
+        await refreshVirtualMic();
+      }
+    });
+  });
+
   refreshBtn.addEventListener("click", async () => {
     await refreshHealthAndVoices();
     await refreshEngineStoragePaths();
@@ -1390,8 +1939,8 @@ async function bindActions(): Promise<void> {
       showCloneStatus(successMessage, "success");
       log(result.message || `Cloned voice saved: ${result.voice_id}`);
     } catch (error) {
-      showCloneStatus(`Clone failed: ${String(error)}`, "error");
-      log(`Clone failed: ${String(error)}`, "error");
+      showCloneStatus(`Clone failed: ${errorMessage(error)}`, "error");
+      log(`Clone failed: ${errorMessage(error)}`, "error");
     } finally {
       cloneVoiceBtn.disabled = false;
     }
@@ -1414,13 +1963,16 @@ async function bindActions(): Promise<void> {
     setModelDownloadBusy(true);
     modelDownloadStatus.textContent = `Downloading models (${mode})... this can take several minutes.`;
     try {
-      const result = await invoke<PrefetchModelsResult>("prefetch_models", { mode });
+      const result = await invoke<PrefetchModelsResult>("prefetch_models", {
+        mode,
+        announce: announceDownloadsInput.checked,
+      });
       modelDownloadStatus.textContent = `Download complete: ${result.downloaded.join(", ")}`;
       modelStoragePaths.textContent = `Data: ${result.data_dir} | Models: ${result.models_dir} | HF cache: ${result.hf_cache_dir}`;
       log(result.message || `Model prefetch complete (${mode})`);
     } catch (error) {
-      modelDownloadStatus.textContent = `Download failed: ${String(error)}`;
-      log(`Model prefetch failed (${mode}): ${String(error)}`, "error");
+      modelDownloadStatus.textContent = `Download failed: ${errorMessage(error)}`;
+      log(`Model prefetch failed (${mode}): ${errorMessage(error)}`, "error");
     } finally {
       setModelDownloadBusy(false);
     }
@@ -1435,6 +1987,14 @@ async function bindActions(): Promise<void> {
   downloadQwenAllBtn.addEventListener("click", async () => {
     await runModelPrefetch("qwen_all");
   });
+  cancelDownloadBtn.addEventListener("click", async () => {
+    try {
+      const result = await invoke<Record<string, unknown>>("cancel_prefetch");
+      log(String(result.message ?? "Model download canceled"));
+    } catch (error) {
+      log(`Cancel download failed: ${errorMessage(error)}`, "error");
+    }
+  });
 
   readBtn.addEventListener("click", async () => {
     await applySpeakSettings();
@@ -1444,8 +2004,18 @@ async function bindActions(): Promise<void> {
 
   cancelBtn.addEventListener("click", async () => {
     stopAllPlayback();
-    const response = await invoke<Record<string, unknown>>("cancel_active_job");
-    log(String(response.message ?? "Cancel requested"));
+    // Re-enabled by voicereader:job-cancel-confirmed once generation has stopped.
+    speakBtn.disabled = true;
+    try {
+      const response = await invoke<Record<string, unknown>>("cancel_active_job");
+      log(String(response.message ?? "Cancel requested"));
+      if (String(response.message ?? "").startsWith("No active job")) {
+        speakBtn.disabled = false;
+      }
+    } catch (error) {
+      speakBtn.disabled = false;
+      log(`Cancel failed: ${errorMessage(error)}`, "error");
+    }
   });
 
   speakBtn.addEventListener("click", async () => {
@@ -1463,10 +2033,15 @@ async function bindEvents(): Promise<void> {
       try {
         await togglePlaybackPause();
       } catch (error) {
-        log(`Pause/resume failed: ${String(error)}`, "error");
+        log(`Pause/resume failed: ${errorMessage(error)}`, "error");
       }
       return;
     }
+    if (nativePlayback && (action === "skip-forward" || action === "skip-back")) {
+      const offsetMs = action === "skip-forward" ? NATIVE_SKIP_MS : -NATIVE_SKIP_MS;
+      invoke("seek_playback", { offsetMs }).catch((error) => log(`Seek failed: ${errorMessage(error)}`, "error"));
+      return;
+    }
     if (action === "skip-forward") {
       skipPlaybackForward();
       return;
@@ -1480,7 +2055,7 @@ async function bindEvents(): Promise<void> {
         await toolbarStopPlayback();
         log("Playback stopped from toolbar");
       } catch (error) {
-        log(`Toolbar stop failed: ${String(error)}`, "error");
+        log(`Toolbar stop failed: ${errorMessage(error)}`, "error");
       }
     }
   });
@@ -1505,7 +2080,9 @@ async function bindEvents(): Promise<void> {
     log(`ws_event=${eventType}`);
 
     if (eventType === "AUDIO_CHUNK") {
-      await enqueueAudioChunk(payload);
+      if (acceptChunkInSequence(jobId, payload)) {
+        await enqueueAudioChunk(payload);
+      }
       return;
     }
 
@@ -1513,6 +2090,8 @@ async function bindEvents(): Promise<void> {
       if (jobId) {
         suppressedJobIds.delete(jobId);
         playbackChunkCounts.delete(jobId);
+        receivedChunkCounts.delete(jobId);
+        chunkStreams.delete(jobId);
         queuedPlaybackByJob.delete(jobId);
       }
       stopAllPlayback();
@@ -1522,10 +2101,48 @@ async function bindEvents(): Promise<void> {
       return;
     }
 
-    if (eventType === "JOB_DONE" || eventType === "JOB_ERROR") {
+    // Sent by pause_active_job/resume_active_job; the player follows so
+    // audio already queued stops along with synthesis.
+    if (eventType === "JOB_PAUSED" || eventType === "JOB_RESUMED") {
+      try {
+        await setPlaybackPaused(eventType === "JOB_PAUSED");
+      } catch (error) {
+        log(`Pause/resume failed: ${errorMessage(error)}`, "error");
+      }
+      return;
+    }
+
+    if (eventType === "PLAYBACK_DONE") {
+      if (jobId === activeToolbarJobId) {
+        hideToolbar();
+      }
+      return;
+    }
+
+    if (eventType === "JOB_PARTIAL_DONE") {
+      const resumeOffset = Number(payload.resume_offset ?? NaN);
+      const reason = String(payload.reason ?? "unknown");
+      log(
+        Number.isFinite(resumeOffset)
+          ? `Job ${jobId} stopped early (${reason}); resume at character ${resumeOffset}`
+          : `Job ${jobId} stopped early (${reason})`,
+      );
+    }
+
+    if (eventType === "JOB_DONE") {
+      const limiter = (payload.metrics as JsonValue | undefined)?.limiter as JsonValue | undefined;
+      const reduction = Number(limiter?.max_gain_reduction_db ?? 0);
+      if (reduction > 0) {
+        log(`Job ${jobId}: peak limiter reduced gain by up to ${reduction.toFixed(1)} dB for ${Number(limiter?.limited_ms ?? 0)} ms`);
+      }
+    }
+
+    if (eventType === "JOB_DONE" || eventType === "JOB_PARTIAL_DONE" || eventType === "JOB_ERROR") {
       if (jobId) {
         suppressedJobIds.delete(jobId);
         playbackChunkCounts.delete(jobId);
+        receivedChunkCounts.delete(jobId);
+        chunkStreams.delete(jobId);
         const queued = queuedPlaybackByJob.get(jobId);
         if (queued) {
           queued.terminal = true;
@@ -1534,7 +2151,7 @@ async function bindEvents(): Promise<void> {
       }
       if (activeAudioSources.size === 0 && queuedPlaybackByJob.size === 0) {
         resetPlaybackCursor();
-        if (!jobId || jobId === activeToolbarJobId) {
+        if (!nativePlayback && (!jobId || jobId === activeToolbarJobId)) {
           hideToolbar();
         }
       }
@@ -1559,7 +2176,12 @@ async function bindEvents(): Promise<void> {
       }
     }
     showToolbar(sourceWindow, toolbarRate);
-    log(`job_started id=${jobId}`);
+    const capture = payload.capture;
+    log(
+      capture
+        ? `job_started id=${jobId} via=${capture.capture_method} app=${capture.app_name ?? "?"} chars=${capture.text_length}`
+        : `job_started id=${jobId}`,
+    );
   });
 
   await listen<Record<string, unknown>>("voicereader:hotkey-updated", ({ payload }) => {
@@ -1570,6 +2192,39 @@ async function bindEvents(): Promise<void> {
     }
   });
 
+  await listen<Record<string, unknown>>("voicereader:hotkeys-enabled-changed", ({ payload }) => {
+    setHotkeysEnabledDisplay(payload.enabled !== false);
+  });
+
+  await listen<Record<string, unknown>>("voicereader:settings-changed", async ({ payload }) => {
+    const changed = Array.isArray(payload.changed) ? payload.changed.map(String) : [];
+    if (changed.length > 0) {
+      log(`settings_reloaded=${changed.join(",")}`);
+    }
+    if (changed.some((name) => ["rate", "volume", "chunk_max_chars"].includes(name))) {
+      setSpeakSettingsDisplay(await invoke<SpeakSettings>("get_speak_settings"));
+    }
+    if (changed.includes("queue_policy")) {
+      await refreshQueuePolicy();
+    }
+    if (changed.includes("silence_trim")) {
+      await refreshSilenceTrim();
+    }
+  });
+
+  await listen<Record<string, unknown>>("voicereader:engine-status", ({ payload }) => {
+    log(`engine_status=${String(payload.status ?? "")} ${String(payload.message ?? "")}`);
+  });
+
+  await listen<Record<string, unknown>>("voicereader:engine-crashed", ({ payload }) => {
+    const reportPath = payload.report_path ? ` report=${String(payload.report_path)}` : "";
+    log(`engine_crashed=${String(payload.crash_id ?? "")} ${String(payload.message ?? "")}${reportPath}`);
+  });
+
+  await listen<Record<string, unknown>>("voicereader:read-queue-changed", ({ payload }) => {
+    log(`read_queue_pending=${String(payload.pending ?? 0)}`);
+  });
+
   await listen<JobCancelRequestedPayload>("voicereader:job-cancel-requested", ({ payload }) => {
     const jobId = String(payload.job_id ?? "");
     if (jobId) {
@@ -1584,10 +2239,36 @@ async function bindEvents(): Promise<void> {
     log(`playback_stop job_id=${jobId || "unknown"}`);
   });
 
-  await listen<Record<string, unknown>>("voicereader:selection-empty", () => {
+  await listen<JobCancelConfirmedPayload>("voicereader:job-cancel-confirmed", ({ payload }) => {
+    speakBtn.disabled = false;
+    log(`job_cancel_confirmed job_id=${payload.job_id}${payload.timed_out ? " (timed out)" : ""}`);
+  });
+
+  await listen<Record<string, unknown>>("voicereader:selection-empty", ({ payload }) => {
+    if (payload.reason === "clipboard_empty") {
+      log("The clipboard has no text to read.", "error");
+      return;
+    }
     log("No selection was detected. Highlight text and try the hotkey again.", "error");
   });
 
+  await listen<Record<string, unknown>>("voicereader:secure-field", ({ payload }) => {
+    const appName = payload.app_name ? ` in ${String(payload.app_name)}` : "";
+    log(`The focused field${appName} is a password field; its content was not read.`, "error");
+  });
+
+  await listen<Record<string, unknown>>("voicereader:quiet-hours-held", ({ payload }) => {
+    const until = new Date(String(payload.until ?? ""));
+    const untilLabel = Number.isNaN(until.getTime())
+      ? "quiet hours end"
+      : until.toLocaleTimeString([], { hour: "2-digit", minute: "2-digit" });
+    log(`Quiet hours: read held until ${untilLabel} (${Number(payload.held ?? 1)} waiting).`);
+  });
+
+  await listen<Record<string, unknown>>("voicereader:quiet-hours-released", ({ payload }) => {
+    log(`Quiet hours ended; reading ${Number(payload.released ?? 0)} held capture(s).`);
+  });
+
   await listen<Record<string, unknown>>("voicereader:error", ({ payload }) => {
     log(String(payload.message ?? "Unknown engine/app error"), "error");
   });
@@ -1604,10 +2285,10 @@ bootstrap()
   .then(async () => {
     setInterval(() => {
       pollRuntimeStatus().catch((error) => {
-        log(`Runtime monitor error: ${String(error)}`, "error");
+        log(`Runtime monitor error: ${errorMessage(error)}`, "error");
       });
     }, 5000);
   })
   .catch((error) => {
-    log(`Bootstrap failed: ${String(error)}`, "error");
+    log(`Bootstrap failed: ${errorMessage(error)}`, "error");
   });
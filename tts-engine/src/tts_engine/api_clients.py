@@ -0,0 +1,83 @@
+from __future__ import annotations
+
+from datetime import datetime, timezone
+import hashlib
+import hmac
+import json
+from pathlib import Path
+import secrets
+import threading
+import uuid
+
+from .schemas import ApiClientSummary
+
+
+API_CLIENTS_FILE_NAME = "api_clients.json"
+
+
+def _hash_token(token: str) -> str:
+    return hashlib.sha256(token.encode("utf-8")).hexdigest()
+
+
+class ApiClientStore:
+    """Tokens issued to other local apps, each limited to some scopes.
+
+    Only token hashes are stored; the token itself is returned once, when the
+    client is created. The app's own launch token is not listed here and
+    always has every scope.
+    """
+
+    def __init__(self, data_dir: Path) -> None:
+        self._path = data_dir / API_CLIENTS_FILE_NAME
+        self._lock = threading.Lock()
+
+    def list_clients(self) -> list[ApiClientSummary]:
+        with self._lock:
+            return [ApiClientSummary.model_validate(entry) for entry in self._load()]
+
+    def create_client(self, name: str, scopes: list[str]) -> tuple[ApiClientSummary, str]:
+        token = secrets.token_urlsafe(32)
+        entry = {
+            "client_id": str(uuid.uuid4()),
+            "name": name,
+            "scopes": scopes,
+            "created_at": datetime.now(timezone.utc).isoformat(),
+            "token_sha256": _hash_token(token),
+        }
+        with self._lock:
+            entries = self._load()
+            entries.append(entry)
+            self._save(entries)
+        return ApiClientSummary.model_validate(entry), token
+
+    def revoke_client(self, client_id: str) -> bool:
+        with self._lock:
+            entries = self._load()
+            remaining = [entry for entry in entries if entry.get("client_id") != client_id]
+            if len(remaining) == len(entries):
+                return False
+            self._save(remaining)
+            return True
+
+    def scopes_for_token(self, token: str) -> set[str] | None:
+        """Scopes of the client holding ``token``, or None if no client does."""
+        token_hash = _hash_token(token)
+        with self._lock:
+            entries = self._load()
+        for entry in entries:
+            if hmac.compare_digest(str(entry.get("token_sha256", "")), token_hash):
+                return set(entry.get("scopes") or [])
+        return None
+
+    def _load(self) -> list[dict]:
+        try:
+            payload = json.loads(self._path.read_text(encoding="utf-8"))
+        except (OSError, json.JSONDecodeError):
+            return []
+        return [entry for entry in payload.get("clients", []) if isinstance(entry, dict)]
+
+    def _save(self, entries: list[dict]) -> None:
+        self._path.parent.mkdir(parents=True, exist_ok=True)
+        temp_path = self._path.with_name(f"{self._path.name}.tmp")
+        temp_path.write_text(json.dumps({"clients": entries}, indent=2), encoding="utf-8")
+        temp_path.replace(self._path)
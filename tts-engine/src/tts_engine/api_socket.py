@@ -0,0 +1,57 @@
+from __future__ import annotations
+
+import os
+import socket
+import stat
+from pathlib import Path
+
+# Connections waiting to be accepted on the API socket.
+API_SOCKET_BACKLOG = 64
+
+
+def bind_api_socket(path: Path) -> socket.socket:
+    """Binds a Unix socket at `path` that only this user can connect to.
+
+    A socket left behind by an earlier run of this user's is replaced. Anything
+    else at the path is refused: without a runtime directory the path is in a
+    shared temp directory, where another user may have made it first.
+    """
+    try:
+        info = os.lstat(path)
+    except FileNotFoundError:
+        pass
+    else:
+        if not stat.S_ISSOCK(info.st_mode):
+            raise RuntimeError(f"{path} exists and is not a socket")
+        if info.st_uid != os.getuid():
+            raise RuntimeError(f"Socket {path} belongs to another user")
+        path.unlink()
+    path.parent.mkdir(parents=True, exist_ok=True)
+
+    sock = socket.socket(socket.AF_UNIX, socket.SOCK_STREAM)
+    # Owner-only from the moment it exists, not after a chmod.
+    previous_umask = os.umask(0o177)
+    try:
+        sock.bind(str(path))
+        sock.listen(API_SOCKET_BACKLOG)
+    except OSError:
+        sock.close()
+        raise
+    finally:
+        os.umask(previous_umask)
+    return sock
+
+
+def remove_api_socket(path: Path, bound: os.stat_result) -> None:
+    """Removes the socket at `path` if it is still the one that was bound.
+
+    `bound` is its `lstat` from right after binding. A later engine that
+    replaced it as a stale socket keeps its own; the change time tells them
+    apart even if the filesystem reused the inode number.
+    """
+    try:
+        info = os.lstat(path)
+    except FileNotFoundError:
+        return
+    if (info.st_dev, info.st_ino, info.st_ctime_ns) == (bound.st_dev, bound.st_ino, bound.st_ctime_ns):
+        path.unlink(missing_ok=True)
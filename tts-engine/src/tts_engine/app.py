@@ -6,23 +6,32 @@ import binascii
 from dataclasses import replace
 from datetime import datetime, timezone
 from pathlib import Path
+import threading
 import time
 from uuid import UUID
 
 from fastapi import APIRouter, Depends, FastAPI, Request, WebSocket
 from starlette.websockets import WebSocketDisconnect, WebSocketState
 
-from .auth import verify_http_request, verify_websocket
+from .api_clients import ApiClientStore
+from .audio_cache import AUDIO_CACHE_DIR_NAME, AudioCache
+from .auth import require_scope, verify_http_request, verify_websocket
 from .config import EngineConfig
-from .constants import DEFAULT_VOICE_ID
+from .constants import DEFAULT_VOICE_ID, SCOPE_ADMIN, SCOPE_MANAGE_VOICES, SCOPE_SPEAK
 from .errors import EngineError, install_exception_handlers
 from .jobs import JobManager, TERMINAL_EVENT_TYPES
 from .model_store import (
+    KNOWN_MODEL_REPOS,
     KYUTAI_POCKET_MODEL_REPO,
     QWEN_BASE_MODEL_REPO,
     QWEN_CUSTOM_MODEL_REPO,
+    PrefetchCanceled,
     configure_hf_cache,
     download_repo_to_local_dir,
+    load_prefetch_states,
+    local_dir_bytes,
+    repo_id_to_local_dir,
+    repo_total_bytes,
 )
 from .schemas import (
     ActivateModelRequest,
@@ -31,14 +40,22 @@ from .schemas import (
     CancelResponse,
     CloneVoiceRequest,
     CloneVoiceResponse,
+    CreateApiClientRequest,
+    CreateApiClientResponse,
     HealthCapabilities,
     HealthResponse,
+    ListApiClientsResponse,
+    ModelDownloadState,
+    ModelDownloadsResponse,
     ListVoicesResponse,
+    RevokeApiClientResponse,
     RuntimeStatus,
     SpeakRequest,
     SpeakResponse,
+    PrefetchCancelResponse,
     PrefetchModelsRequest,
     PrefetchModelsResponse,
+    PrefetchProgressResponse,
     UpdatePlaybackRequest,
     UpdatePlaybackResponse,
     VoiceSummary,
@@ -47,7 +64,7 @@ from .schemas import (
     WarmupResponse,
     WarmupStatus,
 )
-from .synth import create_synthesizer
+from .synth import BaseSynthesizer, create_synthesizer
 from .voices import VoiceStore
 
 
@@ -59,7 +76,7 @@ def create_app(config: EngineConfig) -> FastAPI:
     synthesizer = create_synthesizer(engine_config)
     runtime_model_id = _resolve_runtime_model_id(engine_config, synthesizer.status.backend)
     voice_store = VoiceStore(engine_config.data_dir, runtime_model_id)
-    jobs = JobManager(synthesizer)
+    jobs = _create_job_manager(engine_config, synthesizer, runtime_model_id)
 
     app.state.config = engine_config
     app.state.synthesizer = synthesizer
@@ -68,6 +85,9 @@ def create_app(config: EngineConfig) -> FastAPI:
     app.state.runtime_model_id = runtime_model_id
     app.state.warmup_state = _new_warmup_state()
     app.state.warmup_task = None
+    app.state.prefetch_progress = None
+    # Set while a prefetch runs; setting the event cancels it.
+    app.state.prefetch_cancel = None
     runtime_lock = asyncio.Lock()
 
     def _warmup_snapshot() -> WarmupStatus:
@@ -151,8 +171,16 @@ def create_app(config: EngineConfig) -> FastAPI:
         if engine_config.warmup_on_startup:
             await trigger_warmup(wait=False, force=False, reason="startup")
 
+    api_clients = ApiClientStore(engine_config.data_dir)
+
     def _require_http_auth(request: Request) -> None:
-        verify_http_request(request, engine_config.token)
+        request.state.scopes = verify_http_request(request, engine_config.token, api_clients)
+
+    def _scope(scope: str) -> list:
+        def _require(request: Request) -> None:
+            require_scope(request.state.scopes, scope)
+
+        return [Depends(_require)]
 
     router = APIRouter(prefix="/v1", dependencies=[Depends(_require_http_auth)])
 
@@ -171,11 +199,11 @@ def create_app(config: EngineConfig) -> FastAPI:
             runtime=_runtime_snapshot(),
         )
 
-    @router.get("/voices", response_model=ListVoicesResponse)
+    @router.get("/voices", response_model=ListVoicesResponse, dependencies=_scope(SCOPE_SPEAK))
     async def list_voices() -> ListVoicesResponse:
         return ListVoicesResponse(voices=voice_store.list_voices())
 
-    @router.post("/voices/clone", response_model=CloneVoiceResponse)
+    @router.post("/voices/clone", response_model=CloneVoiceResponse, dependencies=_scope(SCOPE_MANAGE_VOICES))
     async def clone_voice(payload: CloneVoiceRequest) -> CloneVoiceResponse:
         if not synthesizer.status.supports_voice_clone:
             raise EngineError(
@@ -217,7 +245,7 @@ def create_app(config: EngineConfig) -> FastAPI:
             ) from exc
         return CloneVoiceResponse.model_validate(voice.model_dump())
 
-    @router.delete("/voices/{voice_id}")
+    @router.delete("/voices/{voice_id}", dependencies=_scope(SCOPE_MANAGE_VOICES))
     async def delete_voice(voice_id: str) -> dict[str, bool]:
         normalized_voice_id = voice_id.strip()
         if normalized_voice_id == DEFAULT_VOICE_ID:
@@ -244,7 +272,32 @@ def create_app(config: EngineConfig) -> FastAPI:
         synthesizer.forget_voice(str(cloned_voice_id))
         return {"deleted": True}
 
-    @router.patch("/voices/{voice_id}", response_model=VoiceSummary)
+    @router.post("/voices/{voice_id}/preload", dependencies=_scope(SCOPE_SPEAK))
+    async def preload_voice(voice_id: str) -> dict[str, bool]:
+        normalized_voice_id = voice_id.strip()
+        try:
+            voice_exists = voice_store.voice_exists(normalized_voice_id)
+        except ValueError:
+            voice_exists = False
+        if not voice_exists:
+            raise EngineError(
+                code="VOICE_NOT_FOUND",
+                message=f"Voice {normalized_voice_id} was not found",
+                status_code=404,
+            )
+        if not synthesizer.supports_voice_id(normalized_voice_id):
+            return {"preloaded": False}
+        try:
+            await asyncio.to_thread(synthesizer.preload_voice, normalized_voice_id)
+        except Exception as exc:
+            raise EngineError(
+                code="VOICE_PRELOAD_FAILED",
+                message=f"Failed to preload voice {normalized_voice_id}: {exc}",
+                status_code=500,
+            ) from exc
+        return {"preloaded": True}
+
+    @router.patch("/voices/{voice_id}", response_model=VoiceSummary, dependencies=_scope(SCOPE_MANAGE_VOICES))
     async def update_voice(voice_id: str, payload: UpdateVoiceRequest) -> VoiceSummary:
         normalized_voice_id = voice_id.strip()
         if normalized_voice_id == DEFAULT_VOICE_ID:
@@ -278,7 +331,7 @@ def create_app(config: EngineConfig) -> FastAPI:
 
         return updated
 
-    @router.post("/speak", response_model=SpeakResponse)
+    @router.post("/speak", response_model=SpeakResponse, dependencies=_scope(SCOPE_SPEAK))
     async def speak(payload: SpeakRequest, request: Request) -> SpeakResponse:
         async with runtime_lock:
             text = payload.text.strip()
@@ -309,14 +362,21 @@ def create_app(config: EngineConfig) -> FastAPI:
                 rate=payload.settings.rate,
                 pitch=payload.settings.pitch,
                 volume=payload.settings.volume,
+                silence_trim=payload.settings.silence_trim,
+                peak_limiter=payload.settings.peak_limiter,
             )
 
+            stream_path = f"/v1/stream/{job.job_id}"
+            if request.scope.get("server") is None:
+                # Came in over the Unix socket, which has no host or port to
+                # name: the stream is on that same socket, at this path.
+                return SpeakResponse(job_id=job.job_id, ws_url=stream_path)
             ws_scheme = "wss" if request.url.scheme == "https" else "ws"
             port = request.url.port or engine_config.port
-            ws_url = f"{ws_scheme}://127.0.0.1:{port}/v1/stream/{job.job_id}"
+            ws_url = f"{ws_scheme}://127.0.0.1:{port}{stream_path}"
             return SpeakResponse(job_id=job.job_id, ws_url=ws_url)
 
-    @router.post("/cancel", response_model=CancelResponse)
+    @router.post("/cancel", response_model=CancelResponse, dependencies=_scope(SCOPE_SPEAK))
     async def cancel(payload: CancelRequest) -> CancelResponse:
         async with runtime_lock:
             if not await jobs.cancel_job(payload.job_id):
@@ -327,7 +387,7 @@ def create_app(config: EngineConfig) -> FastAPI:
                 )
             return CancelResponse(canceled=True)
 
-    @router.post("/jobs/{job_id}/playback", response_model=UpdatePlaybackResponse)
+    @router.post("/jobs/{job_id}/playback", response_model=UpdatePlaybackResponse, dependencies=_scope(SCOPE_SPEAK))
     async def update_job_playback(job_id: UUID, payload: UpdatePlaybackRequest) -> UpdatePlaybackResponse:
         async with runtime_lock:
             updated = await jobs.update_job_playback(
@@ -344,7 +404,7 @@ def create_app(config: EngineConfig) -> FastAPI:
                 )
             return UpdatePlaybackResponse(updated=True)
 
-    @router.post("/models/activate", response_model=ActivateModelResponse)
+    @router.post("/models/activate", response_model=ActivateModelResponse, dependencies=_scope(SCOPE_ADMIN))
     async def activate_model(payload: ActivateModelRequest | None = None) -> ActivateModelResponse:
         nonlocal engine_config, synthesizer, runtime_model_id, voice_store, jobs
         request_payload = payload or ActivateModelRequest()
@@ -403,7 +463,7 @@ def create_app(config: EngineConfig) -> FastAPI:
             synthesizer = next_synthesizer
             runtime_model_id = _resolve_runtime_model_id(engine_config, synthesizer.status.backend)
             voice_store = VoiceStore(engine_config.data_dir, runtime_model_id)
-            jobs = JobManager(synthesizer)
+            jobs = _create_job_manager(engine_config, synthesizer, runtime_model_id)
             _sync_runtime_state()
 
             app.state.warmup_state = _new_warmup_state()
@@ -421,16 +481,42 @@ def create_app(config: EngineConfig) -> FastAPI:
                 runtime=_runtime_snapshot(),
             )
 
-    @router.post("/models/prefetch", response_model=PrefetchModelsResponse)
+    @router.post("/models/prefetch", response_model=PrefetchModelsResponse, dependencies=_scope(SCOPE_ADMIN))
     async def prefetch_models(payload: PrefetchModelsRequest | None = None) -> PrefetchModelsResponse:
         request_payload = payload or PrefetchModelsRequest()
-        repos = _resolve_prefetch_repos(request_payload.mode)
+        repos = request_payload.repos or _resolve_prefetch_repos(request_payload.mode)
+        if app.state.prefetch_cancel is not None:
+            raise EngineError(code="PREFETCH_BUSY", message="A model prefetch is already running", status_code=409)
         cache_paths = configure_hf_cache(engine_config.data_dir)
 
+        cancel_event = threading.Event()
+        app.state.prefetch_cancel = cancel_event
         saved_to: dict[str, str] = {}
-        for repo_id in repos:
-            local_dir = await asyncio.to_thread(download_repo_to_local_dir, repo_id, engine_config.data_dir)
-            saved_to[repo_id] = str(local_dir)
+        try:
+            for repo_index, repo_id in enumerate(repos):
+                app.state.prefetch_progress = {
+                    "repo_id": repo_id,
+                    "repo_index": repo_index,
+                    "repo_count": len(repos),
+                    "total_bytes": await asyncio.to_thread(repo_total_bytes, repo_id),
+                }
+                local_dir = await asyncio.to_thread(
+                    download_repo_to_local_dir,
+                    repo_id,
+                    engine_config.data_dir,
+                    cancel_event,
+                )
+                saved_to[repo_id] = str(local_dir)
+        except PrefetchCanceled as exc:
+            raise EngineError(
+                code="PREFETCH_CANCELED",
+                message="Model prefetch canceled; partial files resume on the next prefetch",
+                status_code=409,
+                details={"downloaded": list(saved_to)},
+            ) from exc
+        finally:
+            app.state.prefetch_progress = None
+            app.state.prefetch_cancel = None
 
         return PrefetchModelsResponse(
             mode=request_payload.mode,
@@ -441,7 +527,44 @@ def create_app(config: EngineConfig) -> FastAPI:
             hf_cache_dir=str(cache_paths.cache_root),
         )
 
-    @router.post("/warmup", response_model=WarmupResponse)
+    @router.post("/models/prefetch/cancel", response_model=PrefetchCancelResponse, dependencies=_scope(SCOPE_ADMIN))
+    async def cancel_prefetch() -> PrefetchCancelResponse:
+        cancel_event = app.state.prefetch_cancel
+        if cancel_event is None:
+            return PrefetchCancelResponse(canceled=False)
+        cancel_event.set()
+        return PrefetchCancelResponse(canceled=True)
+
+    @router.get("/models/downloads", response_model=ModelDownloadsResponse, dependencies=_scope(SCOPE_ADMIN))
+    async def model_downloads() -> ModelDownloadsResponse:
+        states = load_prefetch_states(engine_config.data_dir)
+        repos: list[ModelDownloadState] = []
+        for repo_id in KNOWN_MODEL_REPOS:
+            local_dir = repo_id_to_local_dir(engine_config.data_dir, repo_id)
+            downloaded_bytes = await asyncio.to_thread(local_dir_bytes, local_dir) if local_dir.exists() else 0
+            state = states.get(repo_id, {})
+            status = state.get("status") or ("present" if downloaded_bytes else "missing")
+            repos.append(
+                ModelDownloadState(
+                    repo_id=repo_id,
+                    status=status,
+                    downloaded_bytes=downloaded_bytes,
+                    total_bytes=state.get("total_bytes"),
+                    updated_at=state.get("updated_at"),
+                )
+            )
+        return ModelDownloadsResponse(repos=repos)
+
+    @router.get("/models/prefetch/progress", response_model=PrefetchProgressResponse, dependencies=_scope(SCOPE_ADMIN))
+    async def prefetch_progress() -> PrefetchProgressResponse:
+        progress = app.state.prefetch_progress
+        if progress is None:
+            return PrefetchProgressResponse(active=False)
+        local_dir = repo_id_to_local_dir(engine_config.data_dir, progress["repo_id"])
+        downloaded_bytes = await asyncio.to_thread(local_dir_bytes, local_dir)
+        return PrefetchProgressResponse(active=True, downloaded_bytes=downloaded_bytes, **progress)
+
+    @router.post("/warmup", response_model=WarmupResponse, dependencies=_scope(SCOPE_ADMIN))
     async def warmup(payload: WarmupRequest | None = None) -> WarmupResponse:
         request_payload = payload or WarmupRequest()
         accepted = await trigger_warmup(
@@ -451,21 +574,43 @@ def create_app(config: EngineConfig) -> FastAPI:
         )
         return WarmupResponse(accepted=accepted, warmup=_warmup_snapshot())
 
-    @router.post("/quit")
+    @router.post("/quit", dependencies=_scope(SCOPE_ADMIN))
     async def quit_engine() -> dict[str, bool]:
         request_shutdown = getattr(app.state, "request_shutdown", None)
         if callable(request_shutdown):
             request_shutdown()
         return {"quitting": True}
 
+    @router.get("/clients", response_model=ListApiClientsResponse, dependencies=_scope(SCOPE_ADMIN))
+    async def list_api_clients() -> ListApiClientsResponse:
+        return ListApiClientsResponse(clients=api_clients.list_clients())
+
+    @router.post("/clients", response_model=CreateApiClientResponse, dependencies=_scope(SCOPE_ADMIN))
+    async def create_api_client(payload: CreateApiClientRequest) -> CreateApiClientResponse:
+        client, token = api_clients.create_client(payload.name.strip(), payload.scopes)
+        return CreateApiClientResponse(**client.model_dump(), token=token)
+
+    @router.delete("/clients/{client_id}", response_model=RevokeApiClientResponse, dependencies=_scope(SCOPE_ADMIN))
+    async def revoke_api_client(client_id: str) -> RevokeApiClientResponse:
+        if not api_clients.revoke_client(client_id):
+            raise EngineError(
+                code="CLIENT_NOT_FOUND",
+                message=f"API client {client_id} does not exist",
+                status_code=404,
+            )
+        return RevokeApiClientResponse(revoked=True)
+
     app.include_router(router)
 
     @app.websocket("/v1/stream/{job_id}")
-    async def stream_job(websocket: WebSocket, job_id: UUID) -> None:
-        authorized, subprotocol = await verify_websocket(websocket, engine_config.token)
-        if not authorized:
+    async def stream_job(websocket: WebSocket, job_id: UUID, after_seq: int | None = None) -> None:
+        scopes, subprotocol = await verify_websocket(websocket, engine_config.token, api_clients)
+        if scopes is None:
             await websocket.close(code=4401)
             return
+        if SCOPE_SPEAK not in scopes and SCOPE_ADMIN not in scopes:
+            await websocket.close(code=4403)
+            return
 
         queue = None
         try:
@@ -478,6 +623,9 @@ def create_app(config: EngineConfig) -> FastAPI:
 
         try:
             for event in history:
+                # A reconnecting client resumes after the last chunk it received.
+                if after_seq is not None and _already_delivered(event, after_seq):
+                    continue
                 await websocket.send_json(event)
                 if event.get("type") in TERMINAL_EVENT_TYPES:
                     return
@@ -500,6 +648,13 @@ def create_app(config: EngineConfig) -> FastAPI:
     return app
 
 
+def _already_delivered(event: dict[str, object], after_seq: int) -> bool:
+    if event.get("type") == "JOB_STARTED":
+        return True
+    seq = event.get("seq")
+    return event.get("type") == "AUDIO_CHUNK" and isinstance(seq, int) and seq <= after_seq
+
+
 def _new_warmup_state() -> dict[str, object]:
     return {
         "status": "not_started",
@@ -550,6 +705,20 @@ def _prepare_reference_audio_source(
     raise EngineError(code="INVALID_AUDIO", message="No reference audio provided", status_code=400)
 
 
+def _create_job_manager(config: EngineConfig, synthesizer: BaseSynthesizer, runtime_model_id: str) -> JobManager:
+    backend = synthesizer.status.backend
+    # Mock tones are cheap to make and not worth disk space.
+    if backend == "mock":
+        return JobManager(synthesizer)
+    try:
+        audio_cache = AudioCache(config.data_dir / AUDIO_CACHE_DIR_NAME)
+    except OSError:
+        return JobManager(synthesizer)
+    # The default voice depends on the configured speaker or voice prompt.
+    default_voice = config.qwen_default_speaker if backend == "qwen_custom_voice" else config.kyutai_voice_prompt
+    return JobManager(synthesizer, audio_cache, f"{backend}:{runtime_model_id}:{default_voice}")
+
+
 def _resolve_runtime_model_id(config: EngineConfig, backend: str) -> str:
     if backend == "qwen_custom_voice":
         return config.qwen_model_name
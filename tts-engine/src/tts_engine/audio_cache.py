@@ -0,0 +1,106 @@
+from __future__ import annotations
+
+import hashlib
+import os
+from pathlib import Path
+import struct
+import threading
+import uuid
+
+from .synth import SynthesizedAudio
+
+
+AUDIO_CACHE_DIR_NAME = "audio-cache"
+# About 90 minutes of 24 kHz mono speech.
+DEFAULT_AUDIO_CACHE_MAX_BYTES = 256 * 1024 * 1024
+CACHE_FILE_SUFFIX = ".s16"
+# sample_rate, channels
+_HEADER = struct.Struct("<IH")
+
+
+class AudioCache:
+    """Synthesized PCM per chunk on disk, keyed by model, voice, language and
+    chunk text, so re-reading the same sentence skips synthesis. Least recently
+    used entries are evicted once the cache grows past ``max_bytes``.
+
+    Audio is cached before playback controls, so one entry serves every rate,
+    pitch and volume. Safe to use from the synthesis worker threads.
+    """
+
+    def __init__(self, cache_dir: Path, max_bytes: int = DEFAULT_AUDIO_CACHE_MAX_BYTES) -> None:
+        self._dir = cache_dir
+        self._max_bytes = max_bytes
+        self._lock = threading.Lock()
+        self._dir.mkdir(parents=True, exist_ok=True)
+        self._total_bytes = sum(size for _, size, _ in self._entries())
+
+    @staticmethod
+    def key(model_id: str, voice_id: str, language: str | None, text: str) -> str:
+        material = "\x1f".join((model_id, voice_id, language or "auto", text.strip()))
+        return hashlib.sha256(material.encode("utf-8")).hexdigest()
+
+    def get(self, key: str) -> SynthesizedAudio | None:
+        path = self._path_for(key)
+        try:
+            data = path.read_bytes()
+        except OSError:
+            return None
+        if len(data) < _HEADER.size:
+            return None
+        sample_rate, channels = _HEADER.unpack_from(data)
+        try:
+            os.utime(path)
+        except OSError:
+            pass
+        return SynthesizedAudio(pcm_s16le=data[_HEADER.size :], sample_rate=sample_rate, channels=channels)
+
+    def put(self, key: str, audio: SynthesizedAudio) -> None:
+        """Stores ``audio``; failures are ignored since the cache only saves time."""
+        if not audio.pcm_s16le:
+            return
+        data = _HEADER.pack(audio.sample_rate, audio.channels) + audio.pcm_s16le
+        if len(data) > self._max_bytes:
+            return
+        path = self._path_for(key)
+        # Written aside and renamed so a concurrent read never sees half a file.
+        temp_path = path.with_name(f"{path.name}.{uuid.uuid4().hex[:8]}.tmp")
+        try:
+            previous_size = path.stat().st_size if path.exists() else 0
+            temp_path.write_bytes(data)
+            os.replace(temp_path, path)
+        except OSError:
+            temp_path.unlink(missing_ok=True)
+            return
+
+        with self._lock:
+            self._total_bytes += len(data) - previous_size
+            if self._total_bytes > self._max_bytes:
+                self._total_bytes = self._evict()
+
+    def _evict(self) -> int:
+        # Down to 90% so the next few writes do not each trigger a scan.
+        entries = sorted(self._entries(), key=lambda entry: entry[2])
+        total = sum(size for _, size, _ in entries)
+        target = self._max_bytes // 10 * 9
+        for path, size, _ in entries:
+            if total <= target:
+                break
+            try:
+                path.unlink()
+            except OSError:
+                continue
+            total -= size
+        return total
+
+    def _entries(self) -> list[tuple[Path, int, float]]:
+        entries = []
+        for path in self._dir.glob(f"*{CACHE_FILE_SUFFIX}"):
+            try:
+                stat = path.stat()
+            except OSError:
+                continue
+            entries.append((path, stat.st_size, stat.st_mtime))
+        return entries
+
+    def _path_for(self, key: str) -> Path:
+        return self._dir / f"{key}{CACHE_FILE_SUFFIX}"
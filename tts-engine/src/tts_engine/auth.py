@@ -2,7 +2,8 @@ from __future__ import annotations
 
 from fastapi import Request, WebSocket
 
-from .constants import WS_AUTH_SUBPROTOCOL
+from .api_clients import ApiClientStore
+from .constants import API_SCOPES, SCOPE_ADMIN, WS_AUTH_SUBPROTOCOL
 from .errors import EngineError
 
 
@@ -25,24 +26,59 @@ def _split_subprotocol_header(raw_header: str | None) -> list[str]:
     return [segment.strip() for segment in raw_header.split(",") if segment.strip()]
 
 
-def verify_http_request(request: Request, expected_token: str) -> None:
+def _scopes_for_token(token: str | None, expected_token: str, clients: ApiClientStore | None) -> set[str] | None:
+    """Scopes granted to ``token``: all of them for the app's launch token,
+    the client's for an issued token, None for anything else."""
+    if not token:
+        return None
+    if token == expected_token:
+        return set(API_SCOPES)
+    if clients is None:
+        return None
+    return clients.scopes_for_token(token)
+
+
+def verify_http_request(request: Request, expected_token: str, clients: ApiClientStore | None = None) -> set[str]:
     provided = _parse_bearer_token(request.headers.get("authorization"))
-    if provided != expected_token:
+    scopes = _scopes_for_token(provided, expected_token, clients)
+    if scopes is None:
         raise EngineError(
             code="UNAUTHORIZED",
             message="Missing or invalid bearer token",
             status_code=401,
         )
+    return scopes
+
+
+def require_scope(granted: set[str], scope: str) -> None:
+    if scope in granted or SCOPE_ADMIN in granted:
+        return
+    raise EngineError(
+        code="FORBIDDEN",
+        message=f'This token does not have the "{scope}" scope',
+        status_code=403,
+        details={"required_scope": scope},
+    )
 
 
-async def verify_websocket(websocket: WebSocket, expected_token: str) -> tuple[bool, str | None]:
+async def verify_websocket(
+    websocket: WebSocket,
+    expected_token: str,
+    clients: ApiClientStore | None = None,
+) -> tuple[set[str] | None, str | None]:
+    """Scopes of the connecting client and the subprotocol to accept with,
+    or ``(None, None)`` when it is not authorized."""
     provided = _parse_bearer_token(websocket.headers.get("authorization"))
-    if provided == expected_token:
-        return True, None
+    scopes = _scopes_for_token(provided, expected_token, clients)
+    if scopes is not None:
+        return scopes, None
 
     offered = _split_subprotocol_header(websocket.headers.get("sec-websocket-protocol"))
     for index, protocol in enumerate(offered[:-1]):
-        if protocol == WS_AUTH_SUBPROTOCOL and offered[index + 1] == expected_token:
-            return True, WS_AUTH_SUBPROTOCOL
+        if protocol != WS_AUTH_SUBPROTOCOL:
+            continue
+        scopes = _scopes_for_token(offered[index + 1], expected_token, clients)
+        if scopes is not None:
+            return scopes, WS_AUTH_SUBPROTOCOL
 
-    return False, None
+    return None, None
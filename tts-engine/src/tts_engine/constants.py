@@ -1,2 +1,7 @@
 DEFAULT_VOICE_ID = "0"
 WS_AUTH_SUBPROTOCOL = "auth.bearer.v1"
+
+SCOPE_SPEAK = "speak"
+SCOPE_MANAGE_VOICES = "manage_voices"
+SCOPE_ADMIN = "admin"
+API_SCOPES = (SCOPE_SPEAK, SCOPE_MANAGE_VOICES, SCOPE_ADMIN)
@@ -14,15 +14,20 @@ from uuid import UUID, uuid4
 
 import numpy as np
 
+from .audio_cache import AudioCache
 from .chunking import split_text_into_chunks
+from .limiter import TruePeakLimiter
+from .schemas import PeakLimiterSettings, SilenceTrimSettings
 from .synth import BaseSynthesizer, SynthesizedAudio
 
 
-TERMINAL_EVENT_TYPES = {"JOB_DONE", "JOB_CANCELED", "JOB_ERROR"}
+TERMINAL_EVENT_TYPES = {"JOB_DONE", "JOB_PARTIAL_DONE", "JOB_CANCELED", "JOB_ERROR"}
 _LIBROSA_MODULE = None
 _LIBROSA_IMPORT_ATTEMPTED = False
 _SOX_PATH = None
 _SOX_LOOKUP_ATTEMPTED = False
+# Length of the gain ramp applied when the volume changes between chunks.
+VOLUME_RAMP_MS = 20
 
 
 @dataclass(slots=True)
@@ -35,6 +40,8 @@ class JobState:
     rate: float
     pitch: float
     volume: float
+    silence_trim: SilenceTrimSettings | None = None
+    peak_limiter: PeakLimiterSettings | None = None
     created_at: datetime = field(default_factory=lambda: datetime.now(timezone.utc))
     cancel_event: asyncio.Event = field(default_factory=asyncio.Event)
     done_event: asyncio.Event = field(default_factory=asyncio.Event)
@@ -44,8 +51,16 @@ class JobState:
 
 
 class JobManager:
-    def __init__(self, synthesizer: BaseSynthesizer) -> None:
+    def __init__(
+        self,
+        synthesizer: BaseSynthesizer,
+        audio_cache: AudioCache | None = None,
+        cache_scope: str = "",
+    ) -> None:
         self._synthesizer = synthesizer
+        self._audio_cache = audio_cache
+        # Model, backend and default voice; part of every audio cache key.
+        self._cache_scope = cache_scope
         self._jobs: dict[UUID, JobState] = {}
         self._active_job_id: UUID | None = None
         self._lock = asyncio.Lock()
@@ -59,6 +74,8 @@ class JobManager:
         rate: float,
         pitch: float,
         volume: float,
+        silence_trim: SilenceTrimSettings | None = None,
+        peak_limiter: PeakLimiterSettings | None = None,
     ) -> JobState:
         async with self._lock:
             if self._active_job_id is not None:
@@ -75,6 +92,8 @@ class JobManager:
                 rate=rate,
                 pitch=pitch,
                 volume=volume,
+                silence_trim=silence_trim,
+                peak_limiter=peak_limiter,
             )
             self._jobs[job.job_id] = job
             self._active_job_id = job.job_id
@@ -117,7 +136,10 @@ class JobManager:
             job = self._jobs.get(job_id)
             if not job:
                 raise KeyError(job_id)
-            queue: asyncio.Queue[dict[str, Any] | None] = asyncio.Queue(maxsize=128)
+            # Unbounded: the app may pause reading while the player catches up, and
+            # dropping a full queue would strand the stream. history already holds
+            # every event, so queued events cost no extra memory.
+            queue: asyncio.Queue[dict[str, Any] | None] = asyncio.Queue()
             job.subscribers.add(queue)
             return queue, list(job.history)
 
@@ -137,6 +159,18 @@ class JobManager:
                 return False
             return not active_job.done_event.is_set()
 
+    def _synthesize_chunk(self, chunk_text: str, voice_id: str, language: str | None) -> SynthesizedAudio:
+        """Runs in the executor; repeated chunks come from the audio cache."""
+        if self._audio_cache is None:
+            return self._synthesizer.synthesize_chunk(chunk_text, voice_id, language)
+        key = AudioCache.key(self._cache_scope, voice_id, language, chunk_text)
+        cached = self._audio_cache.get(key)
+        if cached is not None:
+            return cached
+        synthesized = self._synthesizer.synthesize_chunk(chunk_text, voice_id, language)
+        self._audio_cache.put(key, synthesized)
+        return synthesized
+
     async def _run_job(self, job: JobState) -> None:
         # Holds the Future for the next chunk's synthesis so we can pre-start it
         # before awaiting the current chunk's SoX processing.
@@ -172,12 +206,14 @@ class JobManager:
 
             sequence = 1
             loop = asyncio.get_running_loop()
+            applied_volume = job.volume
+            limiter: TruePeakLimiter | None = None
 
             # Pre-submit synthesis of the first chunk so it starts running
             # immediately, before we enter the loop.
             next_synth_future = loop.run_in_executor(
                 None,
-                self._synthesizer.synthesize_chunk,
+                self._synthesize_chunk,
                 chunks[0].text,
                 job.voice_id,
                 job.language,
@@ -214,7 +250,7 @@ class JobManager:
                 if i + 1 < len(chunks):
                     next_synth_future = loop.run_in_executor(
                         None,
-                        self._synthesizer.synthesize_chunk,
+                        self._synthesize_chunk,
                         chunks[i + 1].text,
                         job.voice_id,
                         job.language,
@@ -222,13 +258,29 @@ class JobManager:
 
                 # Apply SoX/controls in the thread pool so the event loop stays
                 # responsive and SoX runs concurrently with chunk i+1 synthesis.
+                if job.silence_trim is not None:
+                    synthesized = _trim_silence(
+                        synthesized,
+                        threshold=job.silence_trim.threshold,
+                        max_keep_ms=job.silence_trim.max_keep_ms,
+                    )
+                    if not synthesized.pcm_s16le:
+                        continue
+
+                if limiter is None and job.peak_limiter is not None and job.peak_limiter.enabled:
+                    limiter = TruePeakLimiter(job.peak_limiter.ceiling_db, synthesized.sample_rate)
+
+                volume = job.volume
                 synthesized = await asyncio.to_thread(
                     _apply_playback_controls,
                     synthesized,
                     job.rate,
                     job.pitch,
-                    job.volume,
+                    volume,
+                    applied_volume,
+                    limiter,
                 )
+                applied_volume = volume
 
                 if job.cancel_event.is_set():
                     await _cancel_next_synth()
@@ -271,14 +323,13 @@ class JobManager:
                     terminal=True,
                 )
             else:
-                await self._publish(
-                    job,
-                    {
-                        "type": "JOB_DONE",
-                        "job_id": str(job.job_id),
-                    },
-                    terminal=True,
-                )
+                done_event: dict[str, Any] = {
+                    "type": "JOB_DONE",
+                    "job_id": str(job.job_id),
+                }
+                if limiter is not None:
+                    done_event["metrics"] = {"limiter": limiter.report()}
+                await self._publish(job, done_event, terminal=True)
         except asyncio.CancelledError:
             await _cancel_next_synth()
             if not self._has_terminal_event(job):
@@ -353,9 +404,14 @@ def _apply_playback_controls(
     rate: float,
     pitch: float,
     volume: float,
+    previous_volume: float | None = None,
+    limiter: TruePeakLimiter | None = None,
 ) -> SynthesizedAudio:
+    if previous_volume is None:
+        previous_volume = volume
+
     # Keep a fast path for default settings.
-    if rate == 1.0 and pitch == 1.0 and volume == 1.0:
+    if rate == 1.0 and pitch == 1.0 and volume == 1.0 and previous_volume == 1.0 and limiter is None:
         return audio
 
     samples = np.frombuffer(audio.pcm_s16le, dtype=np.int16).astype(np.float32)
@@ -369,9 +425,20 @@ def _apply_playback_controls(
     # Reserved for future model-aware pitch handling.
     _ = pitch
 
-    if volume != 1.0:
+    if previous_volume != volume:
+        # Ramp from the gain the previous chunk ended on so a live volume
+        # change does not step mid-waveform and click.
+        ramp_len = min(samples.shape[0], max(1, audio.sample_rate * VOLUME_RAMP_MS // 1000))
+        gains = np.full(samples.shape[0], volume, dtype=np.float32)
+        gains[:ramp_len] = np.linspace(previous_volume, volume, num=ramp_len, endpoint=False, dtype=np.float32)
+        samples *= gains
+    elif volume != 1.0:
         samples *= volume
 
+    # Limit after the gain, so a boosted volume cannot push a hot voice into clipping.
+    if limiter is not None:
+        samples = limiter.process(samples)
+
     np.clip(samples, -32768.0, 32767.0, out=samples)
     return SynthesizedAudio(
         pcm_s16le=samples.astype(np.int16).tobytes(),
@@ -380,6 +447,32 @@ def _apply_playback_controls(
     )
 
 
+def _trim_silence(audio: SynthesizedAudio, threshold: float, max_keep_ms: int) -> SynthesizedAudio:
+    """Drop leading/trailing silence beyond `max_keep_ms` at each end of a chunk.
+
+    `threshold` is a peak amplitude relative to full scale; an all-silent chunk
+    becomes empty.
+    """
+    samples = np.frombuffer(audio.pcm_s16le, dtype=np.int16)
+    if samples.size == 0:
+        return audio
+
+    sound = np.flatnonzero(np.abs(samples.astype(np.int32)) > int(threshold * 32767))
+    if sound.size == 0:
+        return SynthesizedAudio(pcm_s16le=b"", sample_rate=audio.sample_rate, channels=audio.channels)
+
+    keep = audio.sample_rate * max_keep_ms // 1000
+    start = max(0, int(sound[0]) - keep)
+    end = min(samples.shape[0], int(sound[-1]) + 1 + keep)
+    if start == 0 and end == samples.shape[0]:
+        return audio
+    return SynthesizedAudio(
+        pcm_s16le=samples[start:end].tobytes(),
+        sample_rate=audio.sample_rate,
+        channels=audio.channels,
+    )
+
+
 def _resample_linear(samples: np.ndarray, target_len: int) -> np.ndarray:
     if target_len <= 1:
         return np.asarray([samples[0]], dtype=np.float32)
@@ -0,0 +1,94 @@
+from __future__ import annotations
+
+import math
+from typing import Any
+
+import numpy as np
+
+
+FULL_SCALE = 32767.0
+# Gain is pulled down this long before a peak so it never steps.
+ATTACK_MS = 2
+# Time constant of the recovery back to unity gain after a peak.
+RELEASE_MS = 80.0
+# Gain above this counts as unity: it is not reported, and the release snaps
+# to 1.0 so the exponential tail ends.
+UNITY_GAIN = 0.9999
+
+
+def _catmull_rom_weights(t: float) -> list[float]:
+    return [
+        0.5 * (-t + 2 * t * t - t**3),
+        0.5 * (2 - 5 * t * t + 3 * t**3),
+        0.5 * (t + 4 * t * t - 3 * t**3),
+        0.5 * (-t * t + t**3),
+    ]
+
+
+# Points a quarter, half and three quarters of the way between two samples.
+_INTERPOLATION = np.array([_catmull_rom_weights(t) for t in (0.25, 0.5, 0.75)], dtype=np.float32)
+
+
+class TruePeakLimiter:
+    """Keeps the true peak of a job's audio under a ceiling.
+
+    Runs after the volume gain on float samples on the int16 scale. State
+    carries across chunks, so a job gets one continuous gain curve.
+    """
+
+    def __init__(self, ceiling_db: float, sample_rate: int) -> None:
+        self.ceiling_db = ceiling_db
+        self._ceiling = FULL_SCALE * 10 ** (ceiling_db / 20)
+        self._sample_rate = max(1, sample_rate)
+        self._attack_samples = max(1, self._sample_rate * ATTACK_MS // 1000)
+        self._release = math.exp(-1000.0 / (RELEASE_MS * self._sample_rate))
+        self._gain = 1.0
+        # Last two input samples of the previous chunk, for the peak between chunks.
+        self._history = np.zeros(2, dtype=np.float32)
+        self._min_gain = 1.0
+        self._limited_samples = 0
+
+    def process(self, samples: np.ndarray) -> np.ndarray:
+        count = samples.shape[0]
+        if count == 0:
+            return samples
+
+        # Neighbours of each sample: the two before it and the one after.
+        padded = np.concatenate([self._history, samples, samples[-1:]])
+        windows = np.stack([padded[offset : offset + count] for offset in range(4)], axis=1)
+        between = np.abs(windows @ _INTERPOLATION.T).max(axis=1)
+        peaks = np.maximum(np.abs(samples), between)
+        required = np.minimum(1.0, self._ceiling / np.maximum(peaks, 1e-9))
+
+        # Walking back from each peak, let the gain rise by at most one attack
+        # step per sample, so it is already down when the peak arrives.
+        step = 1.0 / self._attack_samples
+        ramp = np.arange(count, dtype=np.float64) * step
+        envelope = np.minimum.accumulate((required + ramp)[::-1])[::-1] - ramp
+        np.minimum(envelope, 1.0, out=envelope)
+
+        self._history = np.array(padded[-3:-1], dtype=np.float32)
+        if self._gain == 1.0 and envelope.min() >= 1.0:
+            return np.clip(samples, -self._ceiling, self._ceiling)
+
+        gains = np.empty(count, dtype=np.float32)
+        gain = self._gain
+        for index, target in enumerate(envelope.tolist()):
+            gain = target if target < gain else target - (target - gain) * self._release
+            if gain > UNITY_GAIN:
+                gain = 1.0
+            else:
+                self._limited_samples += 1
+            gains[index] = gain
+        self._gain = gain
+        self._min_gain = min(self._min_gain, float(gains.min()))
+        return np.clip(samples * gains, -self._ceiling, self._ceiling)
+
+    def report(self) -> dict[str, Any]:
+        """How hard the job was limited, for the `JOB_DONE` metrics."""
+        reduction = -20 * math.log10(self._min_gain) if self._min_gain < 1.0 else 0.0
+        return {
+            "ceiling_db": self.ceiling_db,
+            "max_gain_reduction_db": round(reduction, 2),
+            "limited_ms": self._limited_samples * 1000 // self._sample_rate,
+        }
@@ -1,13 +1,19 @@
 from __future__ import annotations
 
 import argparse
+import asyncio
 import importlib.util
 import json
+import os
+import socket
+import sys
+from pathlib import Path
 from typing import Any
 
 import uvicorn
 from uvicorn.server import Server
 
+from .api_socket import bind_api_socket, remove_api_socket
 from .app import create_app
 from .config import (
     DEFAULT_TOKEN_ENV,
@@ -18,6 +24,11 @@ from .config import (
     resolve_data_dir,
 )
 from .model_store import configure_hf_cache
+from .parent_watch import exit_engine, start_parent_watchdog
+
+# The app keeps connections open for up to 60 s between requests; uvicorn's
+# default of 5 s would close them under it.
+ENGINE_KEEP_ALIVE_SECS = 75
 
 
 def main() -> int:
@@ -32,6 +43,17 @@ def main() -> int:
         help=f"Environment variable name for token (default: {DEFAULT_TOKEN_ENV})",
     )
     parser.add_argument("--data-dir", default=None, help="Engine data directory")
+    parser.add_argument(
+        "--uds",
+        default=None,
+        help="Also serve the API on this Unix socket, for local clients that avoid TCP",
+    )
+    parser.add_argument(
+        "--parent-pid",
+        type=int,
+        default=None,
+        help="Exit when this process (the app) is no longer running",
+    )
     parser.add_argument(
         "--bootstrap-stdin",
         action="store_true",
@@ -89,17 +111,75 @@ def main() -> int:
         warmup_language=load_env_config_value("VOICEREADER_WARMUP_LANGUAGE", "auto"),
     )
 
+    if args.parent_pid is not None:
+        start_parent_watchdog(args.parent_pid, exit_engine)
+
     app = create_app(config)
-    uvicorn_config = uvicorn.Config(app, host=config.host, port=config.port, log_level="info")
+    uvicorn_config = uvicorn.Config(
+        app,
+        host=config.host,
+        port=config.port,
+        log_level="info",
+        timeout_keep_alive=ENGINE_KEEP_ALIVE_SECS,
+    )
     server = uvicorn.Server(uvicorn_config)
-    app.state.request_shutdown = lambda: _request_shutdown(server)
-    server.run()
+    api_socket = _open_api_socket(args.uds)
+    if api_socket is None:
+        app.state.request_shutdown = lambda: _request_shutdown(server)
+        server.run()
+        return 0
+
+    api_socket_path = Path(args.uds)
+    bound = os.lstat(api_socket_path)
+
+    # Same app, so jobs and voices are shared; only the TCP server runs the
+    # lifespan (warmup, shutdown hooks).
+    socket_server = uvicorn.Server(
+        uvicorn.Config(
+            app,
+            log_level="info",
+            timeout_keep_alive=ENGINE_KEEP_ALIVE_SECS,
+            lifespan="off",
+        )
+    )
+
+    def request_shutdown() -> None:
+        _request_shutdown(server)
+        _request_shutdown(socket_server)
+
+    app.state.request_shutdown = request_shutdown
+    try:
+        asyncio.run(_serve_together(server, socket_server, api_socket))
+    finally:
+        api_socket.close()
+        remove_api_socket(api_socket_path, bound)
     return 0
 
 
-def _load_bootstrap_payload() -> dict[str, Any]:
-    import sys
+def _open_api_socket(path: str | None) -> socket.socket | None:
+    if not path or sys.platform == "win32":
+        return None
+    try:
+        return bind_api_socket(Path(path))
+    except (OSError, RuntimeError) as exc:
+        # The app talks to the engine over TCP, so it can do without the socket.
+        print(f"Engine API socket not served: {exc}", file=sys.stderr)
+        return None
+
+
+async def _serve_together(server: Server, socket_server: Server, api_socket: socket.socket) -> None:
+    tasks = [
+        asyncio.create_task(server.serve()),
+        asyncio.create_task(socket_server.serve(sockets=[api_socket])),
+    ]
+    await asyncio.wait(tasks, return_when=asyncio.FIRST_COMPLETED)
+    # Either one stopping (a quit request, a signal) stops both.
+    _request_shutdown(server)
+    _request_shutdown(socket_server)
+    await asyncio.gather(*tasks)
 
+
+def _load_bootstrap_payload() -> dict[str, Any]:
     payload = sys.stdin.read().strip()
     if not payload:
         return {}
@@ -1,13 +1,30 @@
 from __future__ import annotations
 
 from dataclasses import dataclass
+from datetime import datetime, timezone
+import json
 import os
 from pathlib import Path
+import threading
+import urllib.error
+from urllib.parse import urlparse
+import urllib.request
 
 
 QWEN_CUSTOM_MODEL_REPO = "Qwen/Qwen3-TTS-12Hz-0.6B-CustomVoice"
 QWEN_BASE_MODEL_REPO = "Qwen/Qwen3-TTS-12Hz-0.6B-Base"
 KYUTAI_POCKET_MODEL_REPO = "Verylicious/pocket-tts-ungated"
+KNOWN_MODEL_REPOS = (QWEN_CUSTOM_MODEL_REPO, QWEN_BASE_MODEL_REPO, KYUTAI_POCKET_MODEL_REPO)
+
+PREFETCH_STATE_FILE_NAME = "prefetch_state.json"
+PARTIAL_SUFFIX = ".part"
+DOWNLOAD_CHUNK_BYTES = 1024 * 1024
+DOWNLOAD_TIMEOUT_SECONDS = 60
+_prefetch_state_lock = threading.Lock()
+
+
+class PrefetchCanceled(Exception):
+    """A download was canceled; its partial files resume on the next run."""
 
 
 @dataclass(frozen=True, slots=True)
@@ -72,14 +89,133 @@ def repo_id_to_local_dir(data_dir: Path, repo_id: str) -> Path:
     return (data_dir / "models" / Path(*segments)).resolve()
 
 
-def download_repo_to_local_dir(repo_id: str, data_dir: Path) -> Path:
+def download_repo_to_local_dir(
+    repo_id: str,
+    data_dir: Path,
+    cancel_event: threading.Event | None = None,
+) -> Path:
+    """Downloads the repo file by file into ``data_dir/models``.
+
+    Files already present at their published size are skipped and partial
+    ``.part`` files are resumed, so a canceled or interrupted prefetch picks up
+    where it stopped. Setting ``cancel_event`` stops within one chunk.
+    """
     # Imported lazily so engine startup doesn't require hub import overhead.
-    from huggingface_hub import snapshot_download
+    from huggingface_hub import HfApi, hf_hub_url
+    from huggingface_hub.utils import build_hf_headers
 
     target_dir = repo_id_to_local_dir(data_dir, repo_id)
     target_dir.mkdir(parents=True, exist_ok=True)
-    snapshot_download(
-        repo_id=repo_id,
-        local_dir=str(target_dir),
-    )
+    info = HfApi().model_info(repo_id, files_metadata=True)
+    files = [(sibling.rfilename, sibling.size) for sibling in info.siblings or []]
+    total_bytes = sum(size or 0 for _, size in files) or None
+    record_prefetch_state(data_dir, repo_id, "partial", total_bytes)
+
+    headers = build_hf_headers()
+    for filename, size in files:
+        if cancel_event is not None and cancel_event.is_set():
+            raise PrefetchCanceled(repo_id)
+        destination = (target_dir / filename).resolve()
+        if not destination.is_relative_to(target_dir):
+            raise ValueError(f"Repo file escapes the model directory: {filename}")
+        if destination.exists() and (size is None or destination.stat().st_size == size):
+            continue
+        url = hf_hub_url(repo_id, filename, revision=info.sha)
+        _download_file(url, destination, headers, cancel_event)
+
+    record_prefetch_state(data_dir, repo_id, "complete", total_bytes)
     return target_dir
+
+
+class _DropAuthOnCrossHostRedirect(urllib.request.HTTPRedirectHandler):
+    # Hub files redirect to a CDN that rejects a second auth mechanism.
+    def redirect_request(self, req, fp, code, msg, headers, newurl):  # type: ignore[no-untyped-def]
+        redirected = super().redirect_request(req, fp, code, msg, headers, newurl)
+        if redirected is not None and urlparse(newurl).netloc != urlparse(req.full_url).netloc:
+            redirected.remove_header("Authorization")
+        return redirected
+
+
+def _download_file(
+    url: str,
+    destination: Path,
+    headers: dict[str, str],
+    cancel_event: threading.Event | None,
+) -> None:
+    destination.parent.mkdir(parents=True, exist_ok=True)
+    partial = destination.with_name(destination.name + PARTIAL_SUFFIX)
+    offset = partial.stat().st_size if partial.exists() else 0
+    request_headers = dict(headers)
+    if offset:
+        request_headers["Range"] = f"bytes={offset}-"
+    opener = urllib.request.build_opener(_DropAuthOnCrossHostRedirect)
+    try:
+        response = opener.open(urllib.request.Request(url, headers=request_headers), timeout=DOWNLOAD_TIMEOUT_SECONDS)
+    except urllib.error.HTTPError as exc:
+        # The partial file already holds every byte.
+        if exc.code == 416 and offset:
+            partial.replace(destination)
+            return
+        raise
+
+    with response:
+        # A server that ignores the range sends the whole file again.
+        mode = "ab" if offset and response.status == 206 else "wb"
+        with partial.open(mode) as output:
+            while chunk := response.read(DOWNLOAD_CHUNK_BYTES):
+                if cancel_event is not None and cancel_event.is_set():
+                    raise PrefetchCanceled(url)
+                output.write(chunk)
+    partial.replace(destination)
+
+
+def _prefetch_state_path(data_dir: Path) -> Path:
+    return data_dir / "models" / PREFETCH_STATE_FILE_NAME
+
+
+def record_prefetch_state(data_dir: Path, repo_id: str, status: str, total_bytes: int | None) -> None:
+    with _prefetch_state_lock:
+        states = load_prefetch_states(data_dir)
+        states[repo_id] = {
+            "status": status,
+            "total_bytes": total_bytes,
+            "updated_at": datetime.now(timezone.utc).isoformat(),
+        }
+        path = _prefetch_state_path(data_dir)
+        path.parent.mkdir(parents=True, exist_ok=True)
+        temp_path = path.with_name(f"{path.name}.tmp")
+        temp_path.write_text(json.dumps(states, indent=2), encoding="utf-8")
+        temp_path.replace(path)
+
+
+def load_prefetch_states(data_dir: Path) -> dict[str, dict]:
+    """Per-repo download status recorded by ``download_repo_to_local_dir``."""
+    try:
+        payload = json.loads(_prefetch_state_path(data_dir).read_text(encoding="utf-8"))
+    except (OSError, json.JSONDecodeError):
+        return {}
+    return {repo_id: state for repo_id, state in payload.items() if isinstance(state, dict)}
+
+
+def repo_total_bytes(repo_id: str) -> int | None:
+    """Size of the repo's files according to the Hub, or None if unknown."""
+    try:
+        from huggingface_hub import HfApi
+
+        info = HfApi().model_info(repo_id, files_metadata=True, timeout=10)
+    except Exception:
+        return None
+    total = sum(sibling.size or 0 for sibling in info.siblings or [])
+    return total or None
+
+
+def local_dir_bytes(path: Path) -> int:
+    """Bytes under ``path``, including the partial files of a running download."""
+    total = 0
+    for file in path.rglob("*"):
+        try:
+            if file.is_file():
+                total += file.stat().st_size
+        except OSError:
+            continue
+    return total
@@ -0,0 +1,70 @@
+from __future__ import annotations
+
+import os
+import sys
+import threading
+from typing import Callable
+
+# How often the engine checks that the app that launched it is still running.
+PARENT_POLL_SECS = 1.0
+
+
+def is_process_alive(pid: int) -> bool:
+    if pid <= 0:
+        return False
+    if sys.platform == "win32":
+        return _is_process_alive_windows(pid)
+    try:
+        # Signal 0 only checks that the process exists.
+        os.kill(pid, 0)
+    except ProcessLookupError:
+        return False
+    except PermissionError:
+        # Exists, but belongs to another user.
+        return True
+    return True
+
+
+def _is_process_alive_windows(pid: int) -> bool:
+    import ctypes
+
+    synchronize = 0x00100000
+    wait_timeout = 0x00000102
+    kernel32 = ctypes.windll.kernel32
+    handle = kernel32.OpenProcess(synchronize, False, pid)
+    if not handle:
+        return False
+    try:
+        # A process handle is signaled once the process has exited.
+        return kernel32.WaitForSingleObject(handle, 0) == wait_timeout
+    finally:
+        kernel32.CloseHandle(handle)
+
+
+def start_parent_watchdog(
+    parent_pid: int,
+    on_parent_exit: Callable[[], None],
+    interval_secs: float = PARENT_POLL_SECS,
+) -> threading.Thread:
+    """Calls `on_parent_exit` once `parent_pid` is gone.
+
+    The app also kills the engine itself on exit, but not when it is killed or
+    crashes; then this keeps the engine from holding its port and model memory.
+    """
+
+    def watch() -> None:
+        stopped = threading.Event()
+        while not stopped.wait(interval_secs):
+            if not is_process_alive(parent_pid):
+                on_parent_exit()
+                return
+
+    thread = threading.Thread(target=watch, name="parent-watchdog", daemon=True)
+    thread.start()
+    return thread
+
+
+def exit_engine() -> None:
+    # The app is gone, so there is no one to report to; skip the graceful
+    # shutdown, which could wait on a job that is mid-synthesis.
+    os._exit(0)
@@ -6,7 +6,8 @@ from uuid import UUID
 
 from pydantic import BaseModel, Field, field_validator, model_validator
 
-from .constants import DEFAULT_VOICE_ID
+from .constants import API_SCOPES, DEFAULT_VOICE_ID
+from .model_store import KNOWN_MODEL_REPOS
 
 
 class ErrorBody(BaseModel):
@@ -149,11 +150,23 @@ class ChunkingSettings(BaseModel):
     max_chars: int = Field(default=200, ge=100, le=2000)
 
 
+class SilenceTrimSettings(BaseModel):
+    threshold: float = Field(default=0.01, gt=0.0, le=0.5)
+    max_keep_ms: int = Field(default=150, ge=0, le=2000)
+
+
+class PeakLimiterSettings(BaseModel):
+    enabled: bool = True
+    ceiling_db: float = Field(default=-1.0, ge=-12.0, le=0.0)
+
+
 class SpeakSettings(BaseModel):
     rate: float = Field(default=1.0, ge=0.25, le=4.0)
     pitch: float = Field(default=1.0, ge=0.5, le=2.0)
     volume: float = Field(default=1.0, ge=0.0, le=2.0)
     chunking: ChunkingSettings = Field(default_factory=ChunkingSettings)
+    silence_trim: SilenceTrimSettings | None = None
+    peak_limiter: PeakLimiterSettings | None = None
 
 
 class SpeakRequest(BaseModel):
@@ -263,6 +276,8 @@ class ActivateModelResponse(BaseModel):
 
 class PrefetchModelsRequest(BaseModel):
     mode: str = Field(default="qwen_all")
+    # Overrides ``mode`` with an explicit selection of repos.
+    repos: list[str] | None = None
 
     @field_validator("mode")
     @classmethod
@@ -272,6 +287,19 @@ class PrefetchModelsRequest(BaseModel):
             raise ValueError("mode must be one of: qwen_custom, qwen_base, qwen_all, all")
         return normalized
 
+    @field_validator("repos")
+    @classmethod
+    def validate_repos(cls, value: list[str] | None) -> list[str] | None:
+        if value is None:
+            return None
+        selected = list(dict.fromkeys(repo.strip() for repo in value))
+        unknown = [repo for repo in selected if repo not in KNOWN_MODEL_REPOS]
+        if unknown:
+            raise ValueError(f"unknown model repos: {', '.join(unknown)}")
+        if not selected:
+            raise ValueError("repos must not be empty")
+        return selected
+
 
 class PrefetchModelsResponse(BaseModel):
     ok: bool = True
@@ -281,3 +309,64 @@ class PrefetchModelsResponse(BaseModel):
     data_dir: str
     models_dir: str
     hf_cache_dir: str
+
+
+class PrefetchProgressResponse(BaseModel):
+    active: bool
+    repo_id: str | None = None
+    repo_index: int = 0
+    repo_count: int = 0
+    downloaded_bytes: int = 0
+    total_bytes: int | None = None
+
+
+class PrefetchCancelResponse(BaseModel):
+    canceled: bool
+
+
+class ModelDownloadState(BaseModel):
+    repo_id: str
+    # "complete", "partial" (canceled or interrupted; resumes on the next
+    # prefetch), "present" (files from before tracking) or "missing".
+    status: str
+    downloaded_bytes: int
+    total_bytes: int | None = None
+    updated_at: str | None = None
+
+
+class ModelDownloadsResponse(BaseModel):
+    repos: list[ModelDownloadState]
+
+
+class ApiClientSummary(BaseModel):
+    client_id: str
+    name: str
+    scopes: list[str]
+    created_at: datetime
+
+
+class ListApiClientsResponse(BaseModel):
+    clients: list[ApiClientSummary]
+
+
+class CreateApiClientRequest(BaseModel):
+    name: str = Field(min_length=1, max_length=80)
+    scopes: list[str] = Field(min_length=1)
+
+    @field_validator("scopes")
+    @classmethod
+    def validate_scopes(cls, value: list[str]) -> list[str]:
+        normalized = sorted({scope.strip().lower() for scope in value})
+        unknown = [scope for scope in normalized if scope not in API_SCOPES]
+        if unknown:
+            raise ValueError(f"Unknown scopes {unknown}; use any of: {', '.join(API_SCOPES)}")
+        return normalized
+
+
+class CreateApiClientResponse(ApiClientSummary):
+    # Shown once; only its hash is stored.
+    token: str
+
+
+class RevokeApiClientResponse(BaseModel):
+    revoked: bool
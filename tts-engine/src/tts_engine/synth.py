@@ -70,6 +70,9 @@ class BaseSynthesizer(Protocol):
     def forget_voice(self, voice_id: str) -> None:
         raise NotImplementedError
 
+    def preload_voice(self, voice_id: str) -> None:
+        raise NotImplementedError
+
     def synthesize_chunk(self, chunk_text: str, voice_id: str, language: str | None = None) -> SynthesizedAudio:
         raise NotImplementedError
 
@@ -102,6 +105,9 @@ class MockSynthesizer:
     def forget_voice(self, voice_id: str) -> None:
         _ = voice_id
 
+    def preload_voice(self, voice_id: str) -> None:
+        _ = voice_id
+
     def synthesize_chunk(self, chunk_text: str, voice_id: str, language: str | None = None) -> SynthesizedAudio:
         duration_seconds = max(0.18, min(1.2, len(chunk_text) / 90.0))
         sample_count = int(duration_seconds * self._sample_rate)
@@ -201,6 +207,10 @@ class QwenCustomVoiceSynthesizer:
     def forget_voice(self, voice_id: str) -> None:
         _ = voice_id
 
+    def preload_voice(self, voice_id: str) -> None:
+        # The only voice is loaded with the model.
+        _ = voice_id
+
     def synthesize_chunk(self, chunk_text: str, voice_id: str, language: str | None = None) -> SynthesizedAudio:
         if voice_id != DEFAULT_VOICE_ID:
             raise RuntimeError('Qwen custom-voice backend currently supports only voice_id "0"')
@@ -361,6 +371,10 @@ class PocketTtsSynthesizer:
             return
         self._voice_state_cache.pop(voice_id, None)
 
+    def preload_voice(self, voice_id: str) -> None:
+        """Loads a voice's prompt state ahead of its first chunk."""
+        self._resolve_voice_state(voice_id)
+
     def synthesize_chunk(self, chunk_text: str, voice_id: str, language: str | None = None) -> SynthesizedAudio:
         voice_state = self._resolve_voice_state(voice_id)
         generated = self._generate_audio(voice_state=voice_state, chunk_text=chunk_text, language=language)
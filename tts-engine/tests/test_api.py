@@ -10,6 +10,7 @@ from fastapi.testclient import TestClient
 from tts_engine.app import create_app
 from tts_engine.config import EngineConfig
 from tts_engine.constants import WS_AUTH_SUBPROTOCOL
+from tts_engine.model_store import PrefetchCanceled, record_prefetch_state
 from tts_engine.synth import MockSynthesizer
 
 
@@ -182,7 +183,7 @@ def test_edit_or_delete_default_voice_is_forbidden(tmp_path: Path) -> None:
 def test_prefetch_models_endpoint_reports_storage_paths(tmp_path: Path) -> None:
     client = _make_client(tmp_path)
 
-    def _fake_download(repo_id: str, data_dir: Path) -> Path:
+    def _fake_download(repo_id: str, data_dir: Path, cancel_event=None) -> Path:
         target = data_dir / "models" / Path(*repo_id.split("/"))
         target.mkdir(parents=True, exist_ok=True)
         return target
@@ -204,6 +205,112 @@ def test_prefetch_models_endpoint_reports_storage_paths(tmp_path: Path) -> None:
     assert "Qwen/Qwen3-TTS-12Hz-0.6B-Base" in payload["downloaded"]
 
 
+def test_prefetch_progress_reports_bytes_of_the_repo_in_flight(tmp_path: Path) -> None:
+    client = _make_client(tmp_path)
+    idle = client.get("/v1/models/prefetch/progress", headers=_auth_headers())
+    assert idle.status_code == 200
+    assert idle.json()["active"] is False
+
+    progress_payloads = []
+
+    def _fake_download(repo_id: str, data_dir: Path, cancel_event=None) -> Path:
+        target = data_dir / "models" / Path(*repo_id.split("/"))
+        target.mkdir(parents=True, exist_ok=True)
+        (target / "model.safetensors").write_bytes(b"x" * 300)
+        progress_payloads.append(client.get("/v1/models/prefetch/progress", headers=_auth_headers()).json())
+        return target
+
+    with (
+        patch("tts_engine.app.download_repo_to_local_dir", side_effect=_fake_download),
+        patch("tts_engine.app.repo_total_bytes", return_value=1200),
+    ):
+        response = client.post("/v1/models/prefetch", headers=_auth_headers(), json={"mode": "qwen_custom"})
+
+    assert response.status_code == 200
+    assert progress_payloads == [
+        {
+            "active": True,
+            "repo_id": "Qwen/Qwen3-TTS-12Hz-0.6B-CustomVoice",
+            "repo_index": 0,
+            "repo_count": 1,
+            "downloaded_bytes": 300,
+            "total_bytes": 1200,
+        }
+    ]
+    done = client.get("/v1/models/prefetch/progress", headers=_auth_headers())
+    assert done.json()["active"] is False
+
+
+def test_prefetch_models_downloads_selected_repos_only(tmp_path: Path) -> None:
+    client = _make_client(tmp_path)
+    downloaded = []
+
+    def _fake_download(repo_id: str, data_dir: Path, cancel_event=None) -> Path:
+        downloaded.append(repo_id)
+        return data_dir / "models" / Path(*repo_id.split("/"))
+
+    with (
+        patch("tts_engine.app.download_repo_to_local_dir", side_effect=_fake_download),
+        patch("tts_engine.app.repo_total_bytes", return_value=None),
+    ):
+        response = client.post(
+            "/v1/models/prefetch",
+            headers=_auth_headers(),
+            json={"repos": ["Verylicious/pocket-tts-ungated", "Verylicious/pocket-tts-ungated"]},
+        )
+        unknown = client.post("/v1/models/prefetch", headers=_auth_headers(), json={"repos": ["someone/else"]})
+
+    assert response.status_code == 200
+    assert response.json()["downloaded"] == ["Verylicious/pocket-tts-ungated"]
+    assert downloaded == ["Verylicious/pocket-tts-ungated"]
+    assert unknown.status_code == 400
+    assert unknown.json()["error"]["code"] == "INVALID_REQUEST"
+
+
+def test_prefetch_cancel_stops_the_running_download(tmp_path: Path) -> None:
+    client = _make_client(tmp_path)
+    idle_cancel = client.post("/v1/models/prefetch/cancel", headers=_auth_headers())
+    assert idle_cancel.json() == {"canceled": False}
+
+    def _fake_download(repo_id: str, data_dir: Path, cancel_event=None) -> Path:
+        cancel = client.post("/v1/models/prefetch/cancel", headers=_auth_headers())
+        assert cancel.json() == {"canceled": True}
+        assert cancel_event is not None and cancel_event.is_set()
+        raise PrefetchCanceled(repo_id)
+
+    with (
+        patch("tts_engine.app.download_repo_to_local_dir", side_effect=_fake_download),
+        patch("tts_engine.app.repo_total_bytes", return_value=None),
+    ):
+        response = client.post("/v1/models/prefetch", headers=_auth_headers(), json={"mode": "qwen_all"})
+
+    assert response.status_code == 409
+    assert response.json()["error"]["code"] == "PREFETCH_CANCELED"
+    assert response.json()["error"]["details"] == {"downloaded": []}
+
+
+def test_model_downloads_reports_recorded_and_untracked_repos(tmp_path: Path) -> None:
+    client = _make_client(tmp_path)
+    data_dir = tmp_path / "data"
+    record_prefetch_state(data_dir, "Qwen/Qwen3-TTS-12Hz-0.6B-Base", "partial", 1000)
+    partial_dir = data_dir / "models" / "Qwen" / "Qwen3-TTS-12Hz-0.6B-Base"
+    partial_dir.mkdir(parents=True, exist_ok=True)
+    (partial_dir / "model.safetensors.part").write_bytes(b"x" * 250)
+    kyutai_dir = data_dir / "models" / "Verylicious" / "pocket-tts-ungated"
+    kyutai_dir.mkdir(parents=True, exist_ok=True)
+    (kyutai_dir / "config.json").write_bytes(b"{}")
+
+    response = client.get("/v1/models/downloads", headers=_auth_headers())
+
+    assert response.status_code == 200
+    repos = {entry["repo_id"]: entry for entry in response.json()["repos"]}
+    assert repos["Qwen/Qwen3-TTS-12Hz-0.6B-Base"]["status"] == "partial"
+    assert repos["Qwen/Qwen3-TTS-12Hz-0.6B-Base"]["downloaded_bytes"] == 250
+    assert repos["Qwen/Qwen3-TTS-12Hz-0.6B-Base"]["total_bytes"] == 1000
+    assert repos["Qwen/Qwen3-TTS-12Hz-0.6B-CustomVoice"]["status"] == "missing"
+    assert repos["Verylicious/pocket-tts-ungated"]["status"] == "present"
+
+
 def test_default_voice_available_and_speak_without_clone(tmp_path: Path) -> None:
     client = _make_client(tmp_path)
 
@@ -227,6 +334,45 @@ def test_default_voice_available_and_speak_without_clone(tmp_path: Path) -> None
                 break
 
 
+def test_ws_resume_skips_delivered_chunks(tmp_path: Path) -> None:
+    client = _make_client(tmp_path)
+    speak_resp = client.post(
+        "/v1/speak",
+        headers=_auth_headers(),
+        json={
+            "voice_id": "0",
+            "text": "First sentence here. Second sentence here. Third sentence here.",
+            "settings": {"chunking": {"max_chars": 30}},
+        },
+    )
+    assert speak_resp.status_code == 200
+    job_id = speak_resp.json()["job_id"]
+
+    full_events = []
+    with client.websocket_connect(f"/v1/stream/{job_id}", headers=_auth_headers()) as websocket:
+        while True:
+            event = websocket.receive_json()
+            full_events.append(event)
+            if event["type"] in {"JOB_DONE", "JOB_CANCELED", "JOB_ERROR"}:
+                break
+    chunk_seqs = [event["seq"] for event in full_events if event["type"] == "AUDIO_CHUNK"]
+    assert len(chunk_seqs) >= 2
+
+    resumed_events = []
+    with client.websocket_connect(
+        f"/v1/stream/{job_id}?after_seq={chunk_seqs[0]}", headers=_auth_headers()
+    ) as websocket:
+        while True:
+            event = websocket.receive_json()
+            resumed_events.append(event)
+            if event["type"] in {"JOB_DONE", "JOB_CANCELED", "JOB_ERROR"}:
+                break
+
+    assert "JOB_STARTED" not in {event["type"] for event in resumed_events}
+    resumed_seqs = [event["seq"] for event in resumed_events if event["type"] == "AUDIO_CHUNK"]
+    assert resumed_seqs == chunk_seqs[1:]
+
+
 def test_ws_subprotocol_auth_fallback(tmp_path: Path) -> None:
     client = _make_client(tmp_path)
     speak_resp = client.post(
@@ -391,3 +537,77 @@ def test_cancel_unknown_job_returns_404(tmp_path: Path) -> None:
     )
     assert response.status_code == 404
     assert response.json()["error"]["code"] == "JOB_NOT_FOUND"
+
+
+def test_preload_voice(tmp_path: Path) -> None:
+    client = _make_client(tmp_path)
+
+    default_resp = client.post("/v1/voices/0/preload", headers=_auth_headers())
+    assert default_resp.status_code == 200
+    assert default_resp.json()["preloaded"] is True
+
+    missing_resp = client.post(
+        "/v1/voices/00000000-0000-0000-0000-000000000001/preload",
+        headers=_auth_headers(),
+    )
+    assert missing_resp.status_code == 404
+    assert missing_resp.json()["error"]["code"] == "VOICE_NOT_FOUND"
+
+    invalid_resp = client.post("/v1/voices/not-a-voice/preload", headers=_auth_headers())
+    assert invalid_resp.status_code == 404
+
+
+def test_api_client_scopes_and_revocation(tmp_path: Path) -> None:
+    client = _make_client(tmp_path)
+
+    create_resp = client.post(
+        "/v1/clients",
+        headers=_auth_headers(),
+        json={"name": "Notes app", "scopes": ["speak"]},
+    )
+    assert create_resp.status_code == 200
+    created = create_resp.json()
+    assert created["scopes"] == ["speak"]
+    client_headers = {"Authorization": f"Bearer {created['token']}"}
+
+    assert client.get("/v1/voices", headers=client_headers).status_code == 200
+    speak_resp = client.post(
+        "/v1/speak",
+        headers=client_headers,
+        json={"voice_id": "0", "text": "Scoped client"},
+    )
+    assert speak_resp.status_code == 200
+    with client.websocket_connect(
+        f"/v1/stream/{speak_resp.json()['job_id']}",
+        subprotocols=[WS_AUTH_SUBPROTOCOL, created["token"]],
+    ) as websocket:
+        assert websocket.receive_json()["type"] in {"JOB_STARTED", "AUDIO_CHUNK"}
+
+    forbidden_resp = client.delete("/v1/voices/0", headers=client_headers)
+    assert forbidden_resp.status_code == 403
+    assert forbidden_resp.json()["error"]["code"] == "FORBIDDEN"
+    assert client.post("/v1/quit", headers=client_headers, json={}).status_code == 403
+    assert client.get("/v1/clients", headers=client_headers).status_code == 403
+
+    list_resp = client.get("/v1/clients", headers=_auth_headers())
+    assert list_resp.status_code == 200
+    listed = list_resp.json()["clients"]
+    assert [entry["client_id"] for entry in listed] == [created["client_id"]]
+    assert "token" not in listed[0]
+
+    revoke_resp = client.delete(f"/v1/clients/{created['client_id']}", headers=_auth_headers())
+    assert revoke_resp.status_code == 200
+    assert client.get("/v1/voices", headers=client_headers).status_code == 401
+    assert client.delete(f"/v1/clients/{created['client_id']}", headers=_auth_headers()).status_code == 404
+
+
+def test_api_client_rejects_unknown_scope(tmp_path: Path) -> None:
+    client = _make_client(tmp_path)
+
+    response = client.post(
+        "/v1/clients",
+        headers=_auth_headers(),
+        json={"name": "Bad", "scopes": ["root"]},
+    )
+    assert response.status_code == 400
+    assert response.json()["error"]["code"] == "INVALID_REQUEST"
@@ -0,0 +1,65 @@
+from __future__ import annotations
+
+import os
+import socket
+import stat
+import sys
+from pathlib import Path
+
+import pytest
+
+from tts_engine.api_socket import bind_api_socket, remove_api_socket
+
+pytestmark = pytest.mark.skipif(sys.platform == "win32", reason="Unix sockets only")
+
+
+def test_bind_api_socket_is_owner_only(tmp_path: Path) -> None:
+    path = tmp_path / "api.sock"
+    sock = bind_api_socket(path)
+    try:
+        assert stat.S_ISSOCK(os.lstat(path).st_mode)
+        assert stat.S_IMODE(os.lstat(path).st_mode) & 0o077 == 0
+    finally:
+        sock.close()
+
+
+def test_bind_api_socket_replaces_a_stale_socket(tmp_path: Path) -> None:
+    path = tmp_path / "api.sock"
+    bind_api_socket(path).close()
+    sock = bind_api_socket(path)
+    try:
+        client = socket.socket(socket.AF_UNIX, socket.SOCK_STREAM)
+        client.connect(str(path))
+        client.close()
+    finally:
+        sock.close()
+
+
+def test_bind_api_socket_refuses_other_files(tmp_path: Path) -> None:
+    path = tmp_path / "api.sock"
+    path.write_text("not a socket", encoding="utf-8")
+    with pytest.raises(RuntimeError, match="not a socket"):
+        bind_api_socket(path)
+    assert path.read_text(encoding="utf-8") == "not a socket"
+
+
+def test_remove_api_socket_removes_its_own_socket(tmp_path: Path) -> None:
+    path = tmp_path / "api.sock"
+    sock = bind_api_socket(path)
+    bound = os.lstat(path)
+    sock.close()
+    remove_api_socket(path, bound)
+    assert not path.exists()
+
+
+def test_remove_api_socket_leaves_a_replacement(tmp_path: Path) -> None:
+    path = tmp_path / "api.sock"
+    first = bind_api_socket(path)
+    bound = os.lstat(path)
+    second = bind_api_socket(path)
+    try:
+        remove_api_socket(path, bound)
+        assert stat.S_ISSOCK(os.lstat(path).st_mode)
+    finally:
+        first.close()
+        second.close()
@@ -0,0 +1,57 @@
+from __future__ import annotations
+
+import os
+from pathlib import Path
+
+from tts_engine.audio_cache import AudioCache
+from tts_engine.synth import SynthesizedAudio
+
+
+def _audio(size: int, sample_rate: int = 24_000) -> SynthesizedAudio:
+    return SynthesizedAudio(pcm_s16le=b"\x01\x00" * (size // 2), sample_rate=sample_rate)
+
+
+def test_audio_cache_round_trips_pcm_and_format(tmp_path: Path) -> None:
+    cache = AudioCache(tmp_path / "cache")
+    key = AudioCache.key("kyutai", "0", None, "Hello there.")
+    assert cache.get(key) is None
+
+    cache.put(key, _audio(64, sample_rate=16_000))
+    cached = cache.get(key)
+    assert cached is not None
+    assert cached.pcm_s16le == b"\x01\x00" * 32
+    assert cached.sample_rate == 16_000
+    assert cached.channels == 1
+
+
+def test_audio_cache_key_covers_model_voice_language_and_text() -> None:
+    base = AudioCache.key("kyutai", "0", "en", "Hello there.")
+    assert base == AudioCache.key("kyutai", "0", "en", "  Hello there.  ")
+    assert base != AudioCache.key("qwen", "0", "en", "Hello there.")
+    assert base != AudioCache.key("kyutai", "voice-1", "en", "Hello there.")
+    assert base != AudioCache.key("kyutai", "0", "de", "Hello there.")
+    assert base != AudioCache.key("kyutai", "0", "en", "Hello, there.")
+
+
+def test_audio_cache_evicts_least_recently_used(tmp_path: Path) -> None:
+    cache = AudioCache(tmp_path / "cache", max_bytes=1_000)
+    keys = [AudioCache.key("m", "0", None, f"sentence {index}") for index in range(4)]
+    for index, key in enumerate(keys[:3]):
+        cache.put(key, _audio(300))
+        path = tmp_path / "cache" / f"{key}.s16"
+        os.utime(path, (index, index))
+
+    # Reading the oldest entry makes it the most recently used.
+    assert cache.get(keys[0]) is not None
+    cache.put(keys[3], _audio(300))
+
+    assert cache.get(keys[1]) is None
+    assert cache.get(keys[0]) is not None
+    assert cache.get(keys[3]) is not None
+
+
+def test_audio_cache_skips_entries_larger_than_the_cache(tmp_path: Path) -> None:
+    cache = AudioCache(tmp_path / "cache", max_bytes=100)
+    key = AudioCache.key("m", "0", None, "long")
+    cache.put(key, _audio(400))
+    assert cache.get(key) is None
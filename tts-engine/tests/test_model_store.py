@@ -1,11 +1,18 @@
 from __future__ import annotations
 
+from http.server import BaseHTTPRequestHandler, HTTPServer
 import os
 from pathlib import Path
+import threading
+
+import pytest
 
 from tts_engine.model_store import (
     QWEN_CUSTOM_MODEL_REPO,
+    PrefetchCanceled,
+    _download_file,
     configure_hf_cache,
+    local_dir_bytes,
     repo_id_to_local_dir,
     resolve_model_source,
 )
@@ -47,3 +54,66 @@ def test_configure_hf_cache_defaults_to_data_dir(monkeypatch, tmp_path: Path) ->
     assert os.environ["HF_HUB_CACHE"] == str(paths.hub_cache)
     assert os.environ["HUGGINGFACE_HUB_CACHE"] == str(paths.hub_cache)
     assert "TRANSFORMERS_CACHE" not in os.environ
+
+
+def test_local_dir_bytes_counts_partial_downloads(tmp_path: Path) -> None:
+    (tmp_path / "config.json").write_bytes(b"{}")
+    partial_dir = tmp_path / ".cache" / "huggingface" / "download"
+    partial_dir.mkdir(parents=True)
+    (partial_dir / "model.safetensors.incomplete").write_bytes(b"x" * 100)
+    assert local_dir_bytes(tmp_path) == 102
+
+
+class _RangeHandler(BaseHTTPRequestHandler):
+    body = b"0123456789" * 1000
+    ranges: list[str | None] = []
+
+    def do_GET(self) -> None:  # noqa: N802
+        range_header = self.headers.get("Range")
+        _RangeHandler.ranges.append(range_header)
+        start = int(range_header.removeprefix("bytes=").rstrip("-")) if range_header else 0
+        payload = self.body[start:]
+        self.send_response(206 if range_header else 200)
+        self.send_header("Content-Length", str(len(payload)))
+        self.end_headers()
+        self.wfile.write(payload)
+
+    def log_message(self, *args: object) -> None:
+        pass
+
+
+def test_download_file_resumes_a_partial_file(tmp_path: Path) -> None:
+    server = HTTPServer(("127.0.0.1", 0), _RangeHandler)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    try:
+        url = f"http://127.0.0.1:{server.server_port}/model.bin"
+        destination = tmp_path / "model.bin"
+        (tmp_path / "model.bin.part").write_bytes(_RangeHandler.body[:4000])
+        _RangeHandler.ranges = []
+
+        _download_file(url, destination, {}, None)
+
+        assert destination.read_bytes() == _RangeHandler.body
+        assert not (tmp_path / "model.bin.part").exists()
+        assert _RangeHandler.ranges == ["bytes=4000-"]
+    finally:
+        server.shutdown()
+
+
+def test_download_file_keeps_the_partial_file_when_canceled(tmp_path: Path) -> None:
+    server = HTTPServer(("127.0.0.1", 0), _RangeHandler)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    try:
+        url = f"http://127.0.0.1:{server.server_port}/model.bin"
+        cancel_event = threading.Event()
+        cancel_event.set()
+
+        with pytest.raises(PrefetchCanceled):
+            _download_file(url, tmp_path / "model.bin", {}, cancel_event)
+
+        assert not (tmp_path / "model.bin").exists()
+        assert (tmp_path / "model.bin.part").exists()
+    finally:
+        server.shutdown()
@@ -0,0 +1,34 @@
+from __future__ import annotations
+
+import os
+import subprocess
+import sys
+import threading
+
+from tts_engine.parent_watch import is_process_alive, start_parent_watchdog
+
+
+def _exited_pid() -> int:
+    process = subprocess.Popen([sys.executable, "-c", "pass"])
+    process.wait()
+    return process.pid
+
+
+def test_is_process_alive_tells_running_from_exited_processes() -> None:
+    assert is_process_alive(os.getpid())
+    assert not is_process_alive(_exited_pid())
+    assert not is_process_alive(0)
+
+
+def test_parent_watchdog_fires_once_the_parent_is_gone() -> None:
+    fired = threading.Event()
+    watchdog = start_parent_watchdog(_exited_pid(), fired.set, interval_secs=0.01)
+    assert fired.wait(timeout=5)
+    watchdog.join(timeout=5)
+    assert not watchdog.is_alive()
+
+
+def test_parent_watchdog_keeps_waiting_while_the_parent_runs() -> None:
+    fired = threading.Event()
+    start_parent_watchdog(os.getpid(), fired.set, interval_secs=0.01)
+    assert not fired.wait(timeout=0.2)
@@ -0,0 +1,66 @@
+from __future__ import annotations
+
+import numpy as np
+
+from tts_engine.jobs import _apply_playback_controls, _trim_silence
+from tts_engine.limiter import TruePeakLimiter
+from tts_engine.synth import SynthesizedAudio
+
+
+def _constant_audio(value: int, samples: int, sample_rate: int = 1000) -> SynthesizedAudio:
+    pcm = np.full(samples, value, dtype=np.int16).tobytes()
+    return SynthesizedAudio(pcm_s16le=pcm, sample_rate=sample_rate, channels=1)
+
+
+def test_volume_change_ramps_from_previous_gain() -> None:
+    audio = _constant_audio(1000, samples=100)
+    result = _apply_playback_controls(audio, rate=1.0, pitch=1.0, volume=0.5, previous_volume=1.0)
+    samples = np.frombuffer(result.pcm_s16le, dtype=np.int16)
+    # 20 ms at 1 kHz is a 20-sample ramp starting at the previous gain.
+    assert samples[0] == 1000
+    assert np.all(np.diff(samples[:20]) <= 0)
+    assert np.all(samples[20:] == 500)
+
+
+def test_unchanged_volume_applies_flat_gain() -> None:
+    audio = _constant_audio(1000, samples=50)
+    result = _apply_playback_controls(audio, rate=1.0, pitch=1.0, volume=0.5, previous_volume=0.5)
+    samples = np.frombuffer(result.pcm_s16le, dtype=np.int16)
+    assert np.all(samples == 500)
+
+
+
+def test_limiter_holds_boosted_audio_under_ceiling() -> None:
+    tone = (20000 * np.sin(np.arange(2400) * 2 * np.pi * 440 / 24000)).astype(np.int16)
+    audio = SynthesizedAudio(pcm_s16le=tone.tobytes(), sample_rate=24000, channels=1)
+    limiter = TruePeakLimiter(ceiling_db=-1.0, sample_rate=24000)
+    result = _apply_playback_controls(audio, rate=1.0, pitch=1.0, volume=2.0, limiter=limiter)
+    samples = np.frombuffer(result.pcm_s16le, dtype=np.int16)
+    ceiling = 32767 * 10 ** (-1.0 / 20)
+    assert np.abs(samples.astype(np.int32)).max() <= ceiling
+    report = limiter.report()
+    assert report["max_gain_reduction_db"] > 2.0
+    assert report["limited_ms"] > 0
+
+
+def test_limiter_leaves_quiet_audio_alone() -> None:
+    audio = _constant_audio(1000, samples=100)
+    limiter = TruePeakLimiter(ceiling_db=-1.0, sample_rate=1000)
+    result = _apply_playback_controls(audio, rate=1.0, pitch=1.0, volume=1.0, limiter=limiter)
+    assert np.all(np.frombuffer(result.pcm_s16le, dtype=np.int16) == 1000)
+    assert limiter.report()["max_gain_reduction_db"] == 0.0
+
+def test_trim_silence_keeps_limited_padding() -> None:
+    pcm = np.concatenate(
+        [np.zeros(500, dtype=np.int16), np.full(100, 8000, dtype=np.int16), np.zeros(500, dtype=np.int16)]
+    )
+    audio = SynthesizedAudio(pcm_s16le=pcm.tobytes(), sample_rate=1000, channels=1)
+    result = _trim_silence(audio, threshold=0.01, max_keep_ms=50)
+    samples = np.frombuffer(result.pcm_s16le, dtype=np.int16)
+    assert samples.shape[0] == 200
+    assert np.all(samples[50:150] == 8000)
+
+
+def test_trim_silence_empties_silent_chunk() -> None:
+    audio = _constant_audio(0, samples=300)
+    assert _trim_silence(audio, threshold=0.01, max_keep_ms=50).pcm_s16le == b""
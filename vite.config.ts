@@ -12,6 +12,7 @@ export default defineConfig({
       input: {
         main: resolve(__dirname, "index.html"),
         toolbar: resolve(__dirname, "toolbar.html"),
+        caption: resolve(__dirname, "caption.html"),
       },
     },
   },